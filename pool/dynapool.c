@@ -35,6 +35,7 @@ extern int distNumb;
 
 extern Stop *stops;
 extern int stopsNumb;
+extern int boardWait;
 
 extern Order *demand;
 extern int demandNumb;
@@ -46,10 +47,16 @@ extern Branch *retNode;
 extern int retCount, retNumb; // number of branches returned to Rust
 
 //inline would cause linker problems while running 'cargo build'
+// row: from, col: to - travel times may differ in both directions
 short dist(int row, int col) {
   return *(distance + (row * distNumb) + col);
 }
 
+// how long a cab stands at 'from' before it goes to 'to', only boarding takes time if it does not move
+int stopWait(int from, int to, int boarding) {
+  return (from == to ? 0 : stops[from].dwell) + boarding * boardWait;
+}
+
 /// adding an order to a pool
 ///  b is existing Branch in lev+1
 /// 
@@ -75,7 +82,7 @@ void storeBranch(int thread, char action, int lev, int ordId, Branch *b, int inP
     //sprintf (ptr->key, "%d%c%s", ordId, action, b->key);
     short from = action == 'i' ? demand[ordId].fromStand : demand[ordId].toStand;
     short to = b->ordActions[0] == 'i' ? demand[b->ordIDs[0]].fromStand : demand[b->ordIDs[0]].toStand;
-    ptr->cost = b->cost + dist(from, to) + (from == to ? 0 : STOP_WAIT);
+    ptr->cost = b->cost + dist(from, to) + stopWait(from, to, action == 'i' ? demand[ordId].passengers : 0);
     if (action == 'o') {
       ptr->outs = b->outs + 1;
       ptr->parity = b->parity + 1;
@@ -106,7 +113,7 @@ boolean isTooLong(int ordId, char oper, int wait, Branch *b) {
         return true;
       from = b->ordActions[i] == 'i' ? demand[b->ordIDs[i]].fromStand : demand[b->ordIDs[i]].toStand;
       to = b->ordActions[i + 1] == 'i' ? demand[b->ordIDs[i + 1]].fromStand : demand[b->ordIDs[i + 1]].toStand;
-      if (from != to) wait += dist(from, to) + STOP_WAIT;
+      wait += dist(from, to) + stopWait(from, to, b->ordActions[i] == 'i' ? demand[b->ordIDs[i]].passengers : 0);
   }
   // just check the last 'o', if it is the OUT of the order that we are checking now (with IN) we have to check max loss
   if (ordId == b->ordIDs[b->ordNumb - 1] && oper == 'i' &&
@@ -140,7 +147,7 @@ void storeBranchIfNotFoundDeeperAndNotTooLong(int thread, int lev, int ordId, in
                     ? demand[ptr->ordIDs[0]].fromStand : demand[ptr->ordIDs[0]].toStand;
     if (outFound) {
       if (!isTooLong(ordId, 'i', dist(demand[ordId].fromStand, nextStop) 
-                                  + (demand[ordId].fromStand != nextStop ? STOP_WAIT : 0), ptr)
+                                  + stopWait(demand[ordId].fromStand, nextStop, demand[ordId].passengers), ptr)
         // TASK? if the next stop is OUT of passenger 'c' - we might allow bigger angle
         && (dist(demand[ordId].fromStand, nextStop) > MAXANGLEDIST 
             || bearingDiff(stops[demand[ordId].fromStand].bearing, stops[nextStop].bearing) < MAXANGLE)
@@ -151,7 +158,7 @@ void storeBranchIfNotFoundDeeperAndNotTooLong(int thread, int lev, int ordId, in
     else if (lev > 0 // the first stop cannot be OUT
         && ptr->outs < inPool // numb OUT must be numb IN
         && !isTooLong(ordId, 'o', dist(demand[ordId].toStand, nextStop)
-                                + (demand[ordId].toStand != nextStop ? STOP_WAIT : 0), ptr)
+                                + stopWait(demand[ordId].toStand, nextStop, 0), ptr)
         && (dist(demand[ordId].toStand, nextStop) > MAXANGLEDIST 
            || bearingDiff(stops[demand[ordId].toStand].bearing, stops[nextStop].bearing) < MAXANGLE)
         // this is OUT, if the node under is in parity (INNs==OUTs), that would imply an empty leg
@@ -183,7 +190,7 @@ void *iterate(void *arguments) {
   int stop = (ar->i + 1) * ar->chunk;
   if (stop > demandNumb) stop = demandNumb;
   for (int ordId = ar->i * ar->chunk; ordId < stop; ordId++) 
-   if (demand[ordId].id != -1) { // not allocated in previous search (inPool+1)
+   if (demand[ordId].id != -1 && demand[ordId].shared) { // not allocated in previous search (inPool+1), solo trips never pooled
     for (int b = 0; b < nodeSize; b++) 
         // we iterate over product of the stage further in the tree: +1
         storeBranchIfNotFoundDeeperAndNotTooLong(ar->i, ar->lev, ordId, b, ar->inPool);
@@ -197,7 +204,7 @@ void addLeaf(int id1, int id2, char dir1, int outs, int lev) {
     }
     Branch *ptr = node[nodeSize];
     int from_stand = dir1 == 'i' ? demand[id1].fromStand : demand[id1].toStand;
-    ptr->cost = dist(from_stand, demand[id2].toStand) + (from_stand == demand[id2].toStand ? 0 : STOP_WAIT);
+    ptr->cost = dist(from_stand, demand[id2].toStand) + stopWait(from_stand, demand[id2].toStand, dir1 == 'i' ? demand[id1].passengers : 0);
     ptr->outs = outs;
     ptr->ordIDs[0] = id1;
     ptr->ordIDs[1] = id2;
@@ -214,9 +221,9 @@ void addLeaf(int id1, int id2, char dir1, int outs, int lev) {
 void storeLeaves(int lev) {
   nodeSize = 0;
   for (int c = 0; c < demandNumb; c++)
-    if (demand[c].id != -1) // assigned in inPool=4 while looking for inPool=3
+    if (demand[c].id != -1 && demand[c].shared) // assigned in inPool=4 while looking for inPool=3; solo trips are not pooled
       for (int d = 0; d < demandNumb; d++)
-        if (demand[d].id != -1) {
+        if (demand[d].id != -1 && demand[d].shared) {
           // to situations: <1in, 1out>, <1out, 2out>
           if (c == d) {
             // 'bearing' checks if stops are in line, it promotes straight paths to avoid unlife solutions
@@ -358,18 +365,26 @@ void showBranch(int no, Branch *ptr) {
   printf("}\n");   
 }
 
+// seats needed - the most passengers in the cab at a time, an order can be a group
 int countPassengers(Branch *ptr) {
   int curr_count = 0;
   int max_count = 0;
   for (int i = 0; i < ptr->ordNumb; i++) {
     if (ptr->ordActions[i] == 'i') {
-      curr_count++;
+      curr_count += demand[ptr->ordIDs[i]].passengers;
       if (curr_count > max_count) max_count = curr_count; // max_count++ would be the same; which one is faster?
-    } else curr_count --; // 'o'
+    } else curr_count -= demand[ptr->ordIDs[i]].passengers; // 'o'
   }
   return max_count;
 }
 
+// features the cab must have - all that any of the orders requires
+int requiredFeatures(Branch *ptr) {
+  int required = 0;
+  for (int i = 0; i < ptr->ordNumb; i++) required |= demand[ptr->ordIDs[i]].required;
+  return required;
+}
+
 boolean waitTimeExceeded(int wait, Branch *b) {
   int from, to;
   for (int i = 0; i < b->ordNumb - 1; i++) {
@@ -378,7 +393,7 @@ boolean waitTimeExceeded(int wait, Branch *b) {
         return true;
       from = b->ordActions[i] == 'i' ? demand[b->ordIDs[i]].fromStand : demand[b->ordIDs[i]].toStand;
       to = b->ordActions[i + 1] == 'i' ? demand[b->ordIDs[i + 1]].fromStand : demand[b->ordIDs[i + 1]].toStand;
-      if (from != to) wait += dist(from, to) + STOP_WAIT;
+      wait += dist(from, to) + stopWait(from, to, b->ordActions[i] == 'i' ? demand[b->ordIDs[i]].passengers : 0);
   }
   return false;
 }
@@ -409,7 +424,11 @@ void rmDuplicatesAndFindCab(int inPool) {
       ptr = node[i];
       if (ptr->cost == -1) continue; // not dropped earlier, but was there any such possibility? TODO: check it
       from = demand[ptr->ordIDs[0]].fromStand;
-      cabIdx = findNearestCab(from, countPassengers(ptr));
+      cabIdx = findNearestCab(from, countPassengers(ptr), requiredFeatures(ptr));
+      if (cabIdx < 0) { // no cab for this pool, big or equipped enough
+        ptr->cost = -1;
+        continue;
+      }
       distCab = dist(supply[cabIdx].location, from) + supply[cabIdx].dist;
       if (distCab > 0 && waitTimeExceeded(distCab, ptr))  {
         ptr->cost == -1; // maybe a big value would be better, -1 will come first after sort, TODO
@@ -423,21 +442,21 @@ void rmDuplicatesAndFindCab(int inPool) {
       ptr = node[i];
       if (ptr->cost == -1) continue; // not dropped earlier or (!) later below
       from = demand[ptr->ordIDs[0]].fromStand;
-      cabIdx = findNearestCab(from, countPassengers(ptr));
+      cabIdx = findNearestCab(from, countPassengers(ptr), requiredFeatures(ptr));
       if (cabIdx == -1) { // no more cabs
         // mark th rest of pools as dead
         // TASK: why? we won't use this information, node[0] will be garbage-collected
         printf("NO CAB\n");
         for (int j = i + 1; j < size; j++) node[j]->cost = -1;
         break;
-      } else if (cabIdx == -2) { // there is no cab for so many passengers
+      } else if (cabIdx == -2) { // there is no cab for so many passengers or with the features needed
         ptr->cost = -1;
         continue;
       }
       distCab = dist(supply[cabIdx].location, from) + supply[cabIdx].dist;
       if (distCab == 0 // constraints inside pool are checked while "diving" in recursion
-              || constraintsMet(i, ptr, distCab + STOP_WAIT)
-            ) { // for the first passenger STOP_WAIT is wrong, but it will concern the others
+              || constraintsMet(i, ptr, distCab + stops[from].dwell)
+            ) { // for the first passenger the dwell time is wrong, but it will concern the others
         // hipi! we have a pool
         ptr->cab = cabIdx; // not supply[cabIdx].id as it is faster to reference it in Boot (than finding IDs)
         // mark cab and order ass allocated
@@ -471,7 +490,7 @@ boolean constraintsMet(int idx, Branch *el, int distCab) {
     o2 = &demand[el->ordIDs[i + 1]];
     from = el->ordActions[i] == 'i' ? o->fromStand : o->toStand;
     to = el->ordActions[i + 1] == 'i' ? o2->fromStand : o2->toStand;
-    if (from != to) dst += dist(from, to) + STOP_WAIT;
+    dst += dist(from, to) + stopWait(from, to, el->ordActions[i] == 'i' ? o->passengers : 0);
   }
   // we don't need to check the last leg as it does not concern "loss", this has been check earlier 
   return true;
@@ -489,9 +508,7 @@ int sumDetour(Branch *el) {
         o2 = &demand[el->ordIDs[j]];
         from = el->ordActions[j - 1] == 'i' ? o->fromStand : o->toStand;
         to = el->ordActions[j] == 'i' ? o2->fromStand : o2->toStand;
-        if (from != to) { 
-          dst += dist(from, to) + STOP_WAIT;
-        }
+        dst += dist(from, to) + stopWait(from, to, el->ordActions[j - 1] == 'i' ? o->passengers : 0);
         if (el->ordIDs[j] == el->ordIDs[i]) { // you don't need to check 'o', it has to be it
           sum += (dst - o->distance); // actual distance - distance without pool
           break;
@@ -517,7 +534,7 @@ int countDistanceWithoutPassengers(Branch *el) {
       from = el->ordActions[i] == 'i' ? o->fromStand : o->toStand;
       to = el->ordActions[i + 1] == 'i' ? o2->fromStand : o2->toStand;
       if (from != to) { 
-        dst += dist(from, to) + STOP_WAIT;
+        dst += dist(from, to) + stopWait(from, to, 0); // the cab is empty
       }
     }
   }
@@ -534,22 +551,23 @@ boolean isFound(Branch *br1, Branch *br2, int size) {
     return false;
 }
 
-int findNearestCab(int from, int pass_count) {
-    int dst = 10000; // big enough
+int findNearestCab(int from, int pass_count, int required) {
+    int dst = 100000; // big enough, seconds
     int nearest = -1;
     int found_any = 0;
     for (int i = 0; i < cabsNumb; i++) {
       if (supply[i].id == -1) // allocated earlier to a pool
         continue;
       found_any = 1;
-      if (dist(supply[i].location, from) + supply[i].dist < dst && supply[i].seats >= pass_count) {
+      if (dist(supply[i].location, from) + supply[i].dist < dst && supply[i].seats >= pass_count
+          && (supply[i].features & required) == required) {
         // supply[i].dist is  time left on last leg
         dst = dist(supply[i].location, from) + supply[i].dist;
         nearest = i;
       }
     }
     if (!found_any) return -1; // no cabs at all
-    else if (nearest == -1) return -2;  // there are some cabs available but none with so many seats
+    else if (nearest == -1) return -2;  // there are some cabs available but none with so many seats or features
     return nearest;
 }
 
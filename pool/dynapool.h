@@ -12,8 +12,7 @@
 #define MAXNODEMEM MAXTHREADMEM * NUMBTHREAD
 
 #define MAXANGLE 120.0
-#define MAXANGLEDIST 1
-#define STOP_WAIT 1 // minute, how long it takes at a bus stop
+#define MAXANGLEDIST 60 // secs
 
 #define true 1
 #define false 0
@@ -28,8 +27,10 @@ void findPool(int, int);
 struct Stop {
     long id;  // int
     int bearing; // short
-    double longitude;
     double latitude;
+    double longitude;
+    short capacity;
+    short dwell; // secs, how long it takes at the stop
 };
 typedef struct Stop Stop;
 
@@ -40,6 +41,9 @@ struct Order {
     int maxWait;
     int maxLoss;
     int distance;
+    int passengers; // seats taken, more than one for group bookings
+    int required; // features the cab must have
+    int shared; // 0 - solo trip, never pooled
 };
 typedef struct Order Order;
 
@@ -48,6 +52,7 @@ struct Cab {
     int location; // short
     short dist; // time left to completion of last leg in a route
     int seats;
+    int features; // bits: 1 wheelchair, 2 luggage, 4 child seat
 };
 typedef struct Cab Cab;
 
@@ -56,7 +61,7 @@ enum FileType {
 };
 
 struct Branch {
-  short cost;
+  int cost; // seconds
   unsigned char outs; // BYTE, number of OUT nodes, so that we can guarantee enough IN nodes
   short ordNumb; // it is in fact ord number *2; length of vectors below - INs & OUTs
   // ordIDs does not need to be int, short is enough as we NEVER are going to solve pools with 64k orders as input
@@ -70,7 +75,7 @@ typedef struct Branch Branch;
 
 void dynapool(int, int[MAXINPOOL - 1],
     short *, int,
-    Stop *, int,
+    Stop *, int, int,
     Order *, int, 
     Cab *, int, 
     Branch *, int, 
@@ -28,6 +28,7 @@ int distNumb;
 
 Stop *stops;
 int stopsNumb;
+int boardWait; // secs per boarding passenger
 
 Order *demand;
 int demandNumb;
@@ -89,7 +90,7 @@ extern short dist(int row, int col);
 
 void dynapool(int numbThreads, int poolsize[MAXINPOOL - 1],
               short *dista, int distSize,
-              Stop *stands, int stopsSize,
+              Stop *stands, int stopsSize, int boardSecs,
               Order *orders, int ordersSize, 
               Cab *cabs, int cabsSize, 
               Branch *ret, int retSize, 
@@ -108,6 +109,7 @@ void dynapool(int numbThreads, int poolsize[MAXINPOOL - 1],
     demand = orders;
     supply = cabs;
     stops = stands;
+    boardWait = boardSecs;
     retNode = ret;
 
     retCount = 0; // surprise - static variables keep value between calls, like a daemon
@@ -160,7 +162,9 @@ void c_lcm(short *dista, int distSize,
       }
       for (int d = 0; d < ordersSize; d++) {
         ord = orders_cpy + d;
-        if ((*ord).id != -1 && dis(dista, distSize, (*cab).location, (*ord).fromStand) < lcm_min_val) {
+        if ((*ord).id != -1 && (*ord).passengers <= (*cab).seats // a group does not fit in a small cab
+            && ((*cab).features & (*ord).required) == (*ord).required
+            && dis(dista, distSize, (*cab).location, (*ord).fromStand) < lcm_min_val) {
           lcm_min_val = dis(dista, distSize, (*cab).location, (*ord).fromStand);
           smin = s;
           dmin = d;
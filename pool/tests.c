@@ -22,6 +22,8 @@ void get_stops(float step, int size) {
             stops[c].bearing = 0;
             stops[c].latitude = 49.0 + step * i;
             stops[c].longitude = 19.000 + step * j;
+            stops[c].capacity = 10;
+            stops[c].dwell = 60;
             c++;
         }
 }
@@ -61,9 +63,9 @@ void init_distance(int size) {
         set_dist(i, i, 0);
         for (int j= i+1; j<size; j++) {
             float d = count_dist(stops[i].latitude, stops[i].longitude, stops[j].latitude, stops[j].longitude)
-                         * (60.0 / CAB_SPEED);
-            if (((int) d) == 0) { d = 1.0; } // a transfer takes at least one minute. 
-            set_dist(stops[i].id, stops[j].id, (short) d); // TASK: we might need a better precision - meters/seconds
+                         * (3600.0 / CAB_SPEED);
+            if (((int) d) == 0) { d = 1.0; } // a transfer takes at least one second. 
+            set_dist(stops[i].id, stops[j].id, (short) d); // seconds
             set_dist(stops[j].id, stops[i].id, get_dist(stops[i].id, stops[j].id));
         }
     }
@@ -77,9 +79,12 @@ void get_orders(int size, int stops)  {
         demand[i].id = i;
         demand[i].fromStand = from;
         demand[i].toStand = to;
-        demand[i].maxWait = 15;
+        demand[i].maxWait = 15 * 60; // secs
         demand[i].maxLoss = 70;
         demand[i].distance = dst;
+        demand[i].passengers = 1;
+        demand[i].required = 0;
+        demand[i].shared = 1;
     }
 }
 
@@ -88,6 +93,7 @@ void get_cabs(int size) {
         supply[i].id = i;
         supply[i].location =  i % 2400;
         supply[i].seats = 10;
+        supply[i].features = 0;
     }
 }
 
@@ -120,7 +126,7 @@ int main() {
     
     dynapool(numbThreads, poolsize, 
             dist, distSize, 
-            stops, stopsSize,
+            stops, stopsSize, 0,
             demand, ordersSize, 
             supply, cabsSize, 
             ret, retSize, 
@@ -6,6 +6,7 @@ CREATE TABLE cab (
     name VARCHAR(255),
     status INTEGER NOT NULL,
     seats INTEGER NOT NULL,
+    features integer DEFAULT 0 NOT NULL, -- bits: 1 wheelchair, 2 luggage, 4 child seat
     PRIMARY KEY (ID)
 );
 INSERT INTO cab (id, location, status, seats) with digit as (
@@ -45,6 +46,8 @@ CREATE TABLE taxi_order (
     max_wait integer NOT NULL,
     received timestamp,
     shared boolean NOT NULL,
+    passengers integer DEFAULT 1 NOT NULL, -- seats taken, a group booking takes more
+    required integer DEFAULT 0 NOT NULL, -- features the cab must have, bits as in cab.features
     started timestamp,
     status integer,
     to_stand integer NOT NULL,
@@ -52,6 +55,9 @@ CREATE TABLE taxi_order (
     customer_id bigint,
     leg_id bigint,
     route_id bigint,
+    prev_id bigint, -- ride transfer: the segment before the cab change
+    next_id bigint, -- the segment after it
+    released timestamp NULL, -- put back into demand when its cab went out of service
     PRIMARY KEY (id),
     FOREIGN KEY (cab_id) REFERENCES cab(id),
     FOREIGN KEY (customer_id) REFERENCES customer(id),
@@ -60,7 +66,6 @@ CREATE TABLE taxi_order (
 );
 
 -- ORDER FROM FREE CAB
--- TODO: number of passengers missing !!
 DROP TABLE freetaxi_order CASCADE;
 CREATE TABLE freetaxi_order (
     id bigint NOT NULL auto_increment,
@@ -69,6 +74,7 @@ CREATE TABLE freetaxi_order (
     max_loss integer NOT NULL,
     received timestamp,
     shared boolean NOT NULL,
+    passengers integer DEFAULT 1 NOT NULL, -- seats taken, a group booking takes more
     cab_id bigint,
     customer_id bigint,
     PRIMARY KEY (id)
@@ -139,6 +145,48 @@ INSERT INTO stat (name, int_val) VALUES
     ('TotalLcmUsed', 0),
     ('TotalPickupDistance', 0);
 
+-- ID BLOCK
+-- next free ids of routes and legs; kern reserves blocks of them in its transactions, other writers of these tables have to do the same
+DROP TABLE id_block CASCADE;
+CREATE TABLE id_block (
+    name VARCHAR(32) NOT NULL,
+    next_id BIGINT NOT NULL,
+    PRIMARY KEY (name)
+);
+INSERT INTO id_block (name, next_id) SELECT 'route', COALESCE(MAX(id), -1) + 1 FROM route;
+INSERT INTO id_block (name, next_id) SELECT 'leg', COALESCE(MAX(id), -1) + 1 FROM leg;
+INSERT INTO id_block (name, next_id) VALUES ('event', 1);
+
+-- OUTBOX
+-- decisions of the dispatcher for Kapir, Kab and Kavla, read in the order of 'seq'; -1 means 'not applicable'
+DROP TABLE outbox CASCADE;
+CREATE TABLE outbox (
+    seq bigint NOT NULL,
+    kind character varying(32) NOT NULL, -- ORDER_ASSIGNED, ROUTE_CREATED, LEG_INSERTED, ORDER_REFUSED, CAB_RELOCATED, ORDER_ABANDONED, LEG_REMOVED,
+                                         -- ROUTE_ABANDONED, ORDER_RELEASED
+    order_id bigint NOT NULL,
+    route_id bigint NOT NULL,
+    leg_id bigint NOT NULL,
+    cab_id bigint NOT NULL,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    place integer NOT NULL,
+    eta integer NOT NULL, -- minutes
+    created timestamp NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (seq)
+);
+
+-- SCHEMA VERSION
+-- the last migration in sql/migrations this script is equal to, kern refuses to start with another one
+DROP TABLE schema_version CASCADE;
+CREATE TABLE schema_version (
+    version INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    applied TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (version)
+);
+INSERT INTO schema_version (version, name) VALUES (1, 'baseline'), (2, 'dwell'), (3, 'id_block'), (4, 'outbox'), (5, 'passengers'), (6, 'features'), (7, 'transfers'), (8, 'breakdown');
+
 -- STOP
 DROP TABLE stop CASCADE;
 CREATE TABLE stop (
@@ -150,6 +198,7 @@ CREATE TABLE stop (
     no character varying(255),
     type character varying(255),
     capacity integer NOT NULL,
+    dwell integer, -- secs at the stop, 'stop_wait' from kern.toml if NULL
     PRIMARY KEY (id)
 );
 -- GRANT FILE on kabina.stop to kabina@'localhost'
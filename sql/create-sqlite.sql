@@ -0,0 +1,5389 @@
+-- sqlite3 kabina.db < create-sqlite.sql
+-- then start kern with db_conn = "sqlite://kabina.db"
+PRAGMA journal_mode=WAL;
+
+-- CAB
+DROP TABLE IF EXISTS cab;
+CREATE TABLE cab (
+    id BIGINT NOT NULL,
+    location INTEGER NOT NULL,
+    name VARCHAR(255),
+    status INTEGER NOT NULL,
+    seats INTEGER NOT NULL,
+    features integer DEFAULT 0 NOT NULL, -- bits: 1 wheelchair, 2 luggage, 4 child seat
+    PRIMARY KEY (ID)
+);
+INSERT INTO cab (id, location, status, seats) WITH RECURSIVE seq(num) AS (
+    SELECT 0 UNION ALL SELECT num + 1 FROM seq WHERE num < 19999
+) SELECT num, 0, 2, 10 FROM seq;
+
+-- CUSTOMER
+DROP TABLE IF EXISTS customer;
+CREATE TABLE customer (id bigint NOT NULL, PRIMARY KEY (id));
+INSERT INTO customer (id) WITH RECURSIVE seq(num) AS (
+    SELECT 0 UNION ALL SELECT num + 1 FROM seq WHERE num < 199999
+) SELECT num FROM seq;
+
+-- ORDER
+-- timestamps are stored as text 'YYYY-MM-DD HH:MM:SS', as CURRENT_TIMESTAMP writes them
+DROP TABLE IF EXISTS taxi_order;
+CREATE TABLE taxi_order (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    at_time timestamp,
+    completed timestamp,
+    distance integer NOT NULL,
+    eta integer,
+    from_stand integer NOT NULL,
+    in_pool boolean,
+    max_loss integer NOT NULL,
+    max_wait integer NOT NULL,
+    received timestamp,
+    shared boolean NOT NULL,
+    passengers integer DEFAULT 1 NOT NULL, -- seats taken, a group booking takes more
+    required integer DEFAULT 0 NOT NULL, -- features the cab must have, bits as in cab.features
+    started timestamp,
+    status integer,
+    to_stand integer NOT NULL,
+    cab_id bigint,
+    customer_id bigint,
+    leg_id bigint,
+    route_id bigint,
+    prev_id bigint, -- ride transfer: the segment before the cab change
+    next_id bigint, -- the segment after it
+    released timestamp, -- put back into demand when its cab went out of service
+    FOREIGN KEY (cab_id) REFERENCES cab(id),
+    FOREIGN KEY (customer_id) REFERENCES customer(id),
+    FOREIGN KEY (leg_id) REFERENCES leg(id),
+    FOREIGN KEY (route_id) REFERENCES route(id)
+);
+
+-- ORDER FROM FREE CAB
+DROP TABLE IF EXISTS freetaxi_order;
+CREATE TABLE freetaxi_order (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    max_loss integer NOT NULL,
+    received timestamp,
+    shared boolean NOT NULL,
+    passengers integer DEFAULT 1 NOT NULL, -- seats taken, a group booking takes more
+    cab_id bigint,
+    customer_id bigint
+);
+
+-- ROUTE
+DROP TABLE IF EXISTS route;
+CREATE TABLE route (
+    id bigint NOT NULL,
+    status integer NOT NULL,
+    cab_id bigint NOT NULL,
+    locked boolean, -- the route should not be extended, cab assigned to a new route
+    PRIMARY KEY (id),
+    FOREIGN KEY (cab_id) REFERENCES cab(id)
+);
+
+-- LEG
+DROP TABLE IF EXISTS leg;
+CREATE TABLE leg (
+    id bigint NOT NULL,
+    completed timestamp,
+    distance integer NOT NULL,
+    from_stand integer NOT NULL,
+    place integer NOT NULL,
+    started timestamp,
+    status integer NOT NULL,
+    reserve integer NOT NULL,
+    passengers integer NOT NULL,
+    to_stand integer NOT NULL,
+    route_id bigint NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (route_id) REFERENCES route(id)
+);
+
+-- STAT
+DROP TABLE IF EXISTS stat;
+CREATE TABLE stat (
+    name character varying(255) NOT NULL,
+    int_val integer NOT NULL,
+    PRIMARY KEY (name)
+);
+INSERT INTO stat (name, int_val) VALUES
+    ('AvgExtenderTime', 0),
+    ('AvgPoolTime', 0),
+    ('AvgPool3Time', 0),
+    ('AvgPool4Time', 0),
+    ('AvgPool5Time', 0),
+    ('AvgLcmTime', 0),
+    ('AvgSolverTime', 0),
+    ('AvgShedulerTime', 0),
+    ('MaxExtenderTime', 0),
+    ('MaxPoolTime', 0),
+    ('MaxPool3Time', 0),
+    ('MaxPool4Time', 0),
+    ('MaxPool5Time', 0),
+    ('MaxLcmTime', 0),
+    ('MaxSolverTime', 0),
+    ('MaxShedulerTime', 0),
+    ('AvgDemandSize', 0),
+    ('AvgPoolDemandSize', 0),  
+    ('AvgSolverDemandSize', 0),
+    ('MaxDemandSize', 0), 
+    ('MaxPoolDemandSize', 0),
+    ('MaxSolverDemandSize', 0),
+    ('AvgOrderAssignTime', 0),
+    ('AvgOrderPickupTime', 0),
+    ('AvgOrderCompleteTime', 0),
+    ('TotalLcmUsed', 0),
+    ('TotalPickupDistance', 0);
+
+-- ID BLOCK
+-- next free ids of routes and legs; kern reserves blocks of them in its transactions, other writers of these tables have to do the same
+DROP TABLE IF EXISTS id_block;
+CREATE TABLE id_block (
+    name VARCHAR(32) NOT NULL,
+    next_id BIGINT NOT NULL,
+    PRIMARY KEY (name)
+);
+INSERT INTO id_block (name, next_id) SELECT 'route', COALESCE(MAX(id), -1) + 1 FROM route;
+INSERT INTO id_block (name, next_id) SELECT 'leg', COALESCE(MAX(id), -1) + 1 FROM leg;
+INSERT INTO id_block (name, next_id) VALUES ('event', 1);
+
+-- OUTBOX
+-- decisions of the dispatcher for Kapir, Kab and Kavla, read in the order of 'seq'; -1 means 'not applicable'
+DROP TABLE IF EXISTS outbox;
+CREATE TABLE outbox (
+    seq bigint NOT NULL,
+    kind character varying(32) NOT NULL, -- ORDER_ASSIGNED, ROUTE_CREATED, LEG_INSERTED, ORDER_REFUSED, CAB_RELOCATED, ORDER_ABANDONED, LEG_REMOVED,
+                                         -- ROUTE_ABANDONED, ORDER_RELEASED
+    order_id bigint NOT NULL,
+    route_id bigint NOT NULL,
+    leg_id bigint NOT NULL,
+    cab_id bigint NOT NULL,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    place integer NOT NULL,
+    eta integer NOT NULL, -- minutes
+    created timestamp DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (seq)
+);
+
+-- SCHEMA VERSION
+-- the last migration in sql/migrations this script is equal to, kern refuses to start with another one
+DROP TABLE IF EXISTS schema_version;
+CREATE TABLE schema_version (
+    version INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    applied TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (version)
+);
+INSERT INTO schema_version (version, name) VALUES (1, 'baseline'), (2, 'dwell'), (3, 'id_block'), (4, 'outbox'), (5, 'passengers'), (6, 'features'), (7, 'transfers'), (8, 'breakdown');
+
+-- STOP
+DROP TABLE IF EXISTS stop;
+CREATE TABLE stop (
+    id bigint NOT NULL,
+    bearing integer,
+    latitude double precision NOT NULL,
+    longitude double precision NOT NULL,
+    name character varying(255),
+    no character varying(255),
+    type character varying(255),
+    capacity integer NOT NULL DEFAULT 10,
+    dwell integer, -- secs at the stop, 'stop_wait' from kern.toml if NULL
+    PRIMARY KEY (id)
+);
+BEGIN;
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (0, 180, 47.507803, 19.235276, 'Erdei bekötőút', 'F03337', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1, -180, 47.49069, 19.10891, 'Hidegkuti Nándor Stadion', '8262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2, -180, 47.500001, 19.023809, 'Déli pályaudvar M', 'F00023', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3, -180, 47.455157, 19.033232, 'Csurgói út', 'F01964', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4, -179, 47.500687, 19.054694, 'Szent István Bazilika', '48505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5, -179, 47.33859, 19.035687, 'Váci Mihály utca (József Attila-telep H)', '64185', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (6, -179, 47.49054, 19.030395, 'Zsolt utca', 'F00003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (7, -179, 47.496088, 19.023479, 'Királyhágó utca', 'F00017', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (8, -179, 47.495669, 19.023612, 'Királyhágó utca', 'F00018', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (9, -179, 47.497944, 19.047022, 'Eötvös tér', 'F00976', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (10, -179, 47.492855, 19.10876, 'Ciprus utca', 'F01277', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (11, -179, 47.495706, 19.109078, 'Hős utca', 'F01329', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (12, -179, 47.458278, 19.033324, 'Bornemissza tér', 'F01962', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (13, -179, 47.468595, 19.052979, 'Budafoki út / Dombóvári út', 'F01984', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (14, -179, 47.504881, 19.136419, 'Örs vezér tere M+H', 'F02757', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (15, -179, 47.485457, 19.243291, 'Rétsár utca', 'F03454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (16, -179, 47.408128, 19.090668, 'Szarka utca', 'F04254', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (17, -178, 47.498503, 19.008136, 'Szent Orbán tér', '43333', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (18, -178, 47.341072, 19.035806, 'Jókai utca', '64184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (19, -178, 47.464025, 18.900557, 'Raktárbázis', '98538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (20, -178, 47.500848, 19.024021, 'Déli pályaudvar M', 'F00024', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (21, -178, 47.595419, 19.045317, 'Zemplén Győző utca', 'F00455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (22, -178, 47.580321, 19.081462, 'Béla utca', 'F00868', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (23, -178, 47.502881, 19.054734, 'Arany János utca M', 'F00943', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (24, -178, 47.454785, 19.018579, 'Őrmezei út', 'F01917', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (25, -178, 47.46038, 19.033388, 'Puskás Tivadar utca', 'F02213', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (26, -178, 47.424198, 19.08391, 'József Attila utca', 'F04179', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (27, -178, 47.424044, 19.071927, 'Görgey Artúr tér', 'F04195', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (28, -178, 47.434658, 19.070606, 'Csepel Kossuth Lajos utca', 'F04314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (29, -178, 47.48009, 19.343101, 'Árpád utca', 'F04643', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (30, -177, 47.584794, 19.081689, 'Rév utca', '9576', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (31, -177, 47.359457, 19.047263, 'Bánki Donát utca', '64317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (32, -177, 47.578998, 19.048961, 'Czetz János köz', 'F00492', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (33, -177, 47.582375, 19.081555, 'Fóti út', 'F00697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (34, -177, 47.581898, 19.116665, 'Járműtelep utca', 'F00737', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (35, -177, 47.553015, 19.091687, 'Angyalföld kocsiszín', 'F00822', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (36, -177, 47.506101, 19.054854, 'Báthory utca / Bajcsy-Zsilinszky út', 'F00941', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (37, -177, 47.510769, 19.056301, 'Nyugati pályaudvar M', 'F01028', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (38, -177, 47.462029, 19.114915, 'Lobogó utca', 'F01470', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (39, -177, 47.442548, 19.045261, 'Vegyész utca', 'F01972', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (40, -177, 47.426094, 19.078171, 'Kolozsvári utca', 'F04186', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (41, -177, 47.426186, 19.067104, 'Karácsony Sándor utca H', 'F04267', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (42, -177, 47.425916, 19.069927, 'Karácsony Sándor utca', 'F04278', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (43, -177, 47.427013, 19.07218, 'Deák tér', 'F04296', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (44, -177, 47.429802, 19.067397, 'Szent Imre tér H', 'F04318', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (45, -177, 47.431222, 19.070327, 'Szent Imre tér', 'F04323', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (46, -177, 47.430781, 19.070512, 'Csepel Szent Imre tér', 'F04327', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (47, -176, 47.587313, 19.08189, 'Ungvári utca', '9575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (48, -176, 47.424034, 19.077997, 'Görögkatolikus templom', '44638', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (49, -176, 47.585689, 19.051181, 'Bercsényi utca', '62950', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (50, -176, 47.493114, 19.043161, 'Várkert Bazár', 'F00101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (51, -176, 47.598063, 19.045569, 'Hősök tere', 'F00453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (52, -176, 47.550541, 19.042066, 'Óbuda Bogdáni út', 'F00574', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (53, -176, 47.585068, 19.064953, 'Mátyás király út', 'F00659', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (54, -176, 47.572266, 19.080764, 'Tímár utca', 'F00700', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (55, -176, 47.556902, 19.088488, 'Tél utca / Pozsonyi utca', 'F00862', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (56, -176, 47.576205, 19.081112, 'Tungsram', 'F00870', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (57, -176, 47.492662, 19.070936, 'Rákóczi tér M', 'F01203', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (58, -176, 47.464205, 19.033561, 'Bikás park M', 'F01959', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (59, -176, 47.424018, 19.069754, 'Széchenyi István utca', 'F04191', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (60, -176, 47.420057, 19.083561, 'Szebeni utca', 'F04213', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (61, -176, 47.430772, 19.070976, 'Csepel Szent Imre tér', 'F04332', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (62, -175, 47.430889, 19.0703, 'Szent Imre tér', '8067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (63, -175, 47.476836, 19.047188, 'Móricz Zsigmond körtér M', '8593', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (64, -175, 47.591508, 19.357892, 'Gödöllő H', '110870', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (65, -175, 47.498029, 19.023858, 'Nagyenyed utca', 'F00019', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (66, -175, 47.505885, 19.038023, 'Batthyány tér M+H', 'F00059', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (67, -175, 47.589969, 19.065513, 'Sinkovits Imre utca', 'F00657', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (68, -175, 47.570219, 19.061599, 'Nánási út', 'F00664', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (69, -175, 47.489991, 19.070563, 'Harminckettesek tere', 'F01198', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (70, -175, 47.492383, 19.071016, 'Rákóczi tér M', 'F01201', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (71, -175, 47.455407, 19.051598, 'Galvani utca', 'F01980', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (72, -175, 47.464485, 19.052793, 'Kelenföldi Erőmű', 'F02234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (73, -175, 47.459233, 19.052095, 'Hengermalom út', 'F02236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (74, -175, 47.547923, 19.14021, 'Nyírpalota út', 'F03166', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (75, -175, 47.459674, 19.126397, 'Corvin körút', 'F03924', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (76, -175, 47.429324, 19.072367, 'Károli Gáspár utca', 'F04182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (77, -174, 47.405702, 19.014155, 'Budatétény vasútállomás (Campona)', '7999', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (78, -174, 47.464364, 19.018786, 'Kelenföld vasútállomás M', '61222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (79, -174, 47.511776, 19.039296, 'Bem József tér', '69093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (80, -174, 47.470912, 18.901412, 'Budaörs Gábor Dénes körút', '98535', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (81, -174, 47.519037, 19.009634, 'Lóczy Lajos utca', 'F00296', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (82, -174, 47.573661, 19.102987, 'Iglói utca', 'F00751', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (83, -174, 47.508853, 19.046584, 'Országház látogatóközpont', 'F00969', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (84, -174, 47.489559, 19.070589, 'Harminckettesek tere', 'F01197', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (85, -174, 47.447422, 19.037504, 'Albertfalva utca', 'F02021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (86, -174, 47.443483, 19.036709, 'Fonyód utca', 'F02025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (87, -174, 47.441414, 19.036378, 'Budafok kocsiszín', 'F02031', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (88, -174, 47.466328, 19.033866, 'Tétényi út 30.', 'F02033', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (89, -174, 47.545153, 19.139873, 'Vásárcsarnok', 'F03158', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (90, -173, 47.458756, 19.177096, 'Felsőcsatári köz', '8692', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (91, -173, 47.463657, 19.177904, 'Árpád utca', 'F01549', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (92, -173, 47.44762, 19.037371, 'Albertfalva utca', 'F02020', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (93, -173, 47.443267, 19.036829, 'Fonyód utca', 'F02027', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (94, -173, 47.441324, 19.036498, 'Budafok kocsiszín', 'F02030', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (95, -173, 47.533622, 19.066849, 'Göncz Árpád városközpont M', 'F02601', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (96, -172, 47.429436, 19.020658, 'Regényes utca', '8793', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (97, -172, 47.413107, 19.118352, 'Gombosszeg köz', '8955', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (98, -172, 47.454806, 19.007542, 'Keserűvíztelep (Bobpálya)', '9429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (99, -172, 47.445631, 19.005613, 'Régi vám', '62726', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (100, -172, 47.520539, 18.938771, 'Szanatórium utca (Vadaspark)', 'F00115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (101, -172, 47.527687, 19.037474, 'Kolosy tér', 'F00136', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (102, -172, 47.564732, 19.030742, 'Magnólia utca', 'F00690', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (103, -172, 47.479824, 19.195969, 'Ezüstfa utca', 'F01874', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (104, -171, 47.551863, 19.039634, 'Búza utca', '11321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (105, -171, 47.36686, 19.048032, 'Bányató út', '64316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (106, -171, 47.507989, 19.034133, 'Fazekas utca', 'F00035', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (107, -171, 47.526913, 19.038204, 'Kolosy tér', 'F00138', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (108, -171, 47.548409, 19.043102, 'Bogdáni út', 'F00577', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (109, -171, 47.57879, 19.063728, 'Szent János utca', 'F00661', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (110, -171, 47.560838, 19.02984, 'Csillaghegyi út / Bojtár utca', 'F00671', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (111, -171, 47.582001, 19.064487, 'Szamos utca', 'F00677', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (112, -171, 47.487176, 19.068213, 'Üllői út', 'F01185', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (113, -171, 47.484998, 19.131569, 'Szent László tér', 'F01649', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (114, -171, 47.520786, 19.227037, 'Cinkota Lassú utca', 'F03330', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (115, -171, 47.452885, 19.229036, 'Orgoványi utca', 'F03564', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (116, -171, 47.434083, 19.182904, 'Varjú utca', 'F03634', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (117, -171, 47.438151, 19.183979, 'Wlassics Gyula utca', 'F03684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (118, -170, 47.491598, 19.259995, 'XVII. utca', '9706', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (119, -170, 47.468484, 19.116055, 'Pöttyös utca M', '44115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (120, -170, 47.524053, 19.037421, 'Zsigmond tér', 'F00246', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (121, -170, 47.562026, 19.035513, 'Áldomás utca', 'F00548', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (122, -170, 47.544713, 19.042212, 'Raktár utca', 'F00583', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (123, -170, 47.575822, 19.06269, 'Nimród utca', 'F00662', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (124, -170, 47.577716, 19.115915, 'Óceán-árok utca', 'F00744', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (125, -170, 47.596181, 19.121067, 'Székpatak utca', 'F00906', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (126, -170, 47.463167, 19.072701, 'Helyi kikötő út', 'F01411', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (127, -170, 47.476872, 19.053383, 'Budafoki út / Karinthy Frigyes út', 'F02152', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (128, -170, 47.590073, 19.122428, 'Rákospalota Csömöri-patak', 'F02995', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (129, -170, 47.48917, 19.259374, 'Hősök tere', 'F03446', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (130, -169, 47.562796, 19.078314, 'Újpest Árpád út hajóállomás', '44557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (131, -169, 47.463988, 19.02311, 'Kelenföld vasútállomás M', '99633', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (132, -169, 47.520338, 19.036612, 'Komjádi Béla utca', 'F00242', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (133, -169, 47.486186, 19.070057, 'Corvin-negyed M', 'F01194', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (134, -169, 47.474361, 19.052621, 'Budafoki út / Szerémi sor', 'F01986', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (135, -169, 47.455482, 19.039558, 'Andor utca', 'F02015', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (136, -169, 47.492273, 19.016461, 'Kiss János altábornagy utca', 'F02306', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (137, -169, 47.4865, 19.2587, 'Rákosliget vasútállomás', 'F03459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (138, -169, 47.435953, 19.183412, 'Dobozi utca', 'F03686', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (139, -168, 47.436409, 19.013419, 'Szabina út', '8098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (140, -168, 47.527615, 19.040037, 'Szépvölgyi út H', '8544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (141, -168, 47.452693, 19.038524, 'Albertfalva kitérő', '8684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (142, -168, 47.462344, 19.148344, 'Kőbánya-Kispest M', '38671', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (143, -168, 47.466536, 19.04957, 'Hauszmann Alajos utca / Szerémi út', '66455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (144, -168, 47.462066, 19.048336, 'Hengermalom út / Szerémi út', '66457', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (145, -168, 47.584799, 19.05594, 'Hunyadi utca', 'F00498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (146, -168, 47.539244, 19.040407, 'Kiscelli utca', 'F00607', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (147, -168, 47.562815, 19.090594, 'Szent István tér (Újpesti piac)', 'F00795', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (148, -168, 47.559825, 19.089407, 'Újpest-központ M', 'F00806', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (149, -168, 47.559114, 19.08914, 'Újpest-központ M (Munkásotthon utca)', 'F00815', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (150, -168, 47.471473, 19.033466, 'Karolina út', 'F02044', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (151, -168, 47.516167, 19.059621, 'Lehel tér M', 'F02619', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (152, -168, 47.441016, 19.184804, 'Szarvas csárda tér', 'F03720', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (153, -167, 47.432914, 19.011403, 'Hír utca', '8127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (154, -167, 47.47007, 19.044795, 'Csonka János tér', '8486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (155, -167, 47.47286, 19.045954, 'Újbuda-központ', '56219', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (156, -167, 47.476629, 19.047227, 'Móricz Zsigmond körtér', '56221', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (157, -167, 47.515779, 19.043743, 'Margitsziget / Margit híd', 'F00144', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (158, -167, 47.560362, 19.035009, 'Kunigunda útja', 'F00516', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (159, -167, 47.565566, 19.079192, 'Károlyi István utca', 'F00704', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (160, -167, 47.560643, 19.089833, 'Újpest-központ M', 'F00798', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (161, -167, 47.563247, 19.099588, 'Deák Ferenc utca / Rózsa utca', 'F00877', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (162, -167, 47.511893, 19.050513, 'Szent István körút', 'F00931', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (163, -167, 47.508817, 19.049518, 'Markó utca', 'F00991', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (164, -167, 47.486753, 19.179234, 'Kozma utca', 'F01603', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (165, -167, 47.485277, 19.131424, 'Szent László tér', 'F01648', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (166, -167, 47.484442, 19.178459, 'Meténg utca', 'F01813', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (167, -167, 47.474467, 19.046478, 'Újbuda-központ M', 'F01997', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (168, -167, 47.461715, 19.041758, 'Etele út / Fehérvári út', 'F02008', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (169, -167, 47.462639, 19.042274, 'Etele út / Fehérvári út', 'F02010', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (170, -167, 47.459178, 19.041003, 'Kalotaszeg utca', 'F02240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (171, -167, 47.493752, 19.002473, 'Tamási Áron utca', 'F02280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (172, -167, 47.496979, 18.998077, 'Tamási Áron utca / Thomán István utca', 'F02437', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (173, -167, 47.517595, 19.060285, 'Lehel tér', 'F02614', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (174, -166, 47.577663, 19.032065, 'Ürömhegyi lejtő', '8409', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (175, -166, 47.572512, 19.030128, 'Aranyhegyi lejtő', '8411', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (176, -166, 47.474683, 19.046385, 'Újbuda-központ M', '8740', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (177, -166, 47.458582, 19.11445, 'Napfény utca', '44129', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (178, -166, 47.509726, 19.05492, 'Nyugati pályaudvar M', '71659', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (179, -166, 47.554966, 19.040643, 'Szőlőkert utca', 'F00440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (180, -166, 47.560974, 19.04714, 'Záhony utca', 'F00507', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (181, -166, 47.557565, 19.041626, 'Kaszásdűlő utca', 'F00512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (182, -166, 47.541987, 19.04119, 'Flórián tér', 'F00612', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (183, -166, 47.546546, 19.097473, 'Madridi utca', 'F00691', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (184, -166, 47.567904, 19.079991, 'Zsilip utca', 'F00702', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (185, -166, 47.58521, 19.117197, 'Bőröndös utca', 'F00827', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (186, -166, 47.561062, 19.098868, 'Árpád üzletház', 'F00833', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (187, -166, 47.586961, 19.117655, 'Bőrfestő utca', 'F00902', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (188, -166, 47.561488, 19.089913, 'Újpest-központ M', 'F00910', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (189, -166, 47.437886, 18.979177, 'Idősek Otthona', 'F01884', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (190, -166, 47.467813, 19.043972, 'Hauszmann Alajos utca', 'F02002', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (191, -166, 47.467345, 19.043933, 'Hauszmann Alajos utca', 'F02004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (192, -166, 47.456129, 19.039889, 'Andor utca', 'F02013', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (193, -166, 47.482393, 19.052569, 'Szent Gellért tér - Műegyetem M', 'F02145', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (194, -166, 47.522623, 19.045096, 'Parkmozi köz', 'F02506', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (195, -166, 47.460577, 19.071904, 'Csepeli híd', 'F04297', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (196, -165, 47.57218, 19.11415, 'Atlétikai stadion', '9155', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (197, -165, 47.566389, 19.111644, 'Rákospalota-Újpest vasútállomás', '9515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (198, -165, 47.459214, 19.040857, 'Kalotaszeg utca', '49186', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (199, -165, 47.431783, 19.004926, 'Balatoni út / Háros utca', '103389', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (200, -165, 47.552039, 18.964107, 'Kossuth Lajos utca', 'F00361', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (201, -165, 47.556567, 19.045533, 'Kaszásdűlő H', 'F00509', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (202, -165, 47.530133, 19.039386, 'Galagonya utca', 'F00602', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (203, -165, 47.58504, 19.116838, 'Bőröndös utca', 'F00824', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (204, -165, 47.587422, 19.118064, 'Bőrfestő utca', 'F00905', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (205, -165, 47.485385, 19.06962, 'Corvin-negyed M', 'F01195', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (206, -165, 47.444422, 19.012579, 'Kelenvölgy-Péterhegy', 'F01897', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (207, -165, 47.455281, 19.024042, 'Hajtány sor', 'F01947', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (208, -165, 47.469783, 19.044821, 'Csonka János tér', 'F02238', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (209, -165, 47.519862, 19.044101, 'Hajós Alfréd uszoda', 'F02493', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (210, -165, 47.548964, 19.073125, 'Gyöngyösi utca M', 'F02536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (211, -165, 47.543576, 19.078701, 'Fiastyúk utca', 'F02561', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (212, -165, 47.545889, 19.072008, 'Fiastyúk utca', 'F02563', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (213, -165, 47.548374, 19.073019, 'Gyöngyösi utca', 'F02685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (214, -165, 47.553321, 19.111801, 'Szerencs utca', 'F03066', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (215, -165, 47.475445, 19.23893, 'Szürkebegy utca', 'F03556', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (216, -165, 47.421788, 19.032129, 'Mező utca', 'F04413', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (217, -165, 47.58231, 18.939042, 'Anna kápolna', 'F04697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (218, -164, 47.531904, 19.066158, 'Göncz Árpád városközpont M', '8900', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (219, -164, 47.558092, 19.076836, 'Újpest-városkapu M (Váci út)', '8945', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (220, -164, 47.43189, 19.004674, 'Balatoni út / Háros utca', '103388', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (221, -164, 47.554961, 19.104615, 'Elem utca', 'F00830', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (222, -164, 47.563269, 19.110338, 'Árpád út', 'F00842', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (223, -164, 47.565841, 19.111604, 'Rákospalota-Újpest vasútállomás', 'F00846', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (224, -164, 47.554585, 19.101546, 'Berlini utca', 'F00890', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (225, -164, 47.489078, 19.009536, 'Vas Gereben utca', 'F02296', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (226, -164, 47.512586, 19.000156, 'Virányos út', 'F02350', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (227, -164, 47.532171, 19.066471, 'Göncz Árpád városközpont', 'F02638', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (228, -164, 47.517763, 19.077678, 'Állatkert', 'F02692', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (229, -164, 47.5618, 19.115399, 'Hubay Jenő tér', 'F03055', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (230, -164, 47.555614, 19.112747, 'Arany János utca', 'F03069', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (231, -164, 47.553326, 19.118391, 'Rákos úti szakrendelő', 'F03071', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (232, -164, 47.55085, 19.107401, 'Pázmány Péter utca', 'F03074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (233, -164, 47.550785, 19.110722, 'Dugonics utca', 'F03076', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (234, -164, 47.557313, 19.113493, 'Rädda Barnen utca', 'F03180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (235, -164, 47.419101, 19.00658, 'Aradi utca', 'F04440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (236, -163, 47.48257, 19.068477, 'Mester utca / Ferenc körút', '7888', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (237, -163, 47.545898, 19.068806, 'Úszódaru utca', '49183', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (238, -163, 47.451007, 19.016194, 'Bolygó utca', '61272', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (239, -163, 47.514717, 19.035936, 'Margit híd budai hídfő H', '69091', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (240, -163, 47.519609, 19.02862, 'Mandula utca', 'F00224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (241, -163, 47.593204, 19.119973, 'Káposztásmegyer Megyeri út', 'F00723', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (242, -163, 47.557929, 19.105881, 'Ősz utca', 'F00887', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (243, -163, 47.482184, 19.068463, 'Mester utca / Ferenc körút', 'F01378', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (244, -163, 47.533641, 19.059107, 'Sporttelep', 'F02569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (245, -163, 47.539475, 19.076826, 'Rozsnyay utca', 'F02664', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (246, -163, 47.536336, 19.075377, 'Frangepán utca', 'F02665', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (247, -163, 47.524358, 19.063088, 'Dózsa György út', 'F02681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (248, -163, 47.538163, 19.069055, 'Forgách utca', 'F02683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (249, -163, 47.554621, 19.118951, 'Rákos úti szakrendelő', 'F03070', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (250, -163, 47.541389, 19.120939, 'Rákospalota MÁV-telep', 'F03103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (251, -163, 47.549828, 19.116898, 'Wesselényi utca', 'F03181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (252, -162, 47.514834, 19.03583, 'Margit híd budai hídfő H', '9031', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (253, -162, 47.611492, 19.062341, 'Budakalász Auchan áruház', '9630', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (254, -162, 47.477351, 19.255241, 'Akácvirág utca', '9685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (255, -162, 47.465707, 19.115411, 'Valéria tér', '44128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (256, -162, 47.436388, 19.006937, 'Antalháza', '61234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (257, -162, 47.45332, 19.021537, 'Gépész utca', 'F01945', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (258, -162, 47.483923, 19.005374, 'Farkasréti temető', 'F02100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (259, -162, 47.527732, 19.047247, 'Palatinus fürdő', 'F02495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (260, -162, 47.547613, 19.080524, 'Gyöngyösi utca', 'F02538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (261, -162, 47.529176, 19.079941, 'Béke tér', 'F02586', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (262, -162, 47.482101, 19.233395, 'Újlak utca lakótelep', 'F03595', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (263, -162, 47.433395, 19.174344, 'Szent Lőrinc-telep', 'F03620', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (264, -162, 47.402329, 18.990815, 'Damjanich utca', 'F04477', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (265, -161, 47.5391, 19.061698, 'Dagály fürdő', '49180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (266, -161, 47.555674, 18.965935, 'Kölcsey utca', 'F00407', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (267, -161, 47.524161, 19.058109, 'Tisza utca', 'F02504', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (268, -161, 47.530718, 19.057765, 'Viza utca', 'F02508', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (269, -161, 47.536878, 19.060542, 'Népfürdő utca / Árpád híd', 'F02567', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (270, -161, 47.533945, 19.082177, 'Frangepán utca', 'F02674', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (271, -161, 47.55864, 19.120697, 'Illyés Gyula utca', 'F03058', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (272, -160, 47.550037, 19.090853, 'Angyalföld vasútállomás', '91821', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (273, -160, 47.599989, 19.065117, 'Bálint György utca', 'F00646', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (274, -160, 47.481197, 19.0047, 'Süveg utca', 'F02099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (275, -160, 47.500315, 18.964734, 'Csillagvizsgáló', 'F02451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (276, -160, 47.547062, 19.089251, 'Gyöngyösi utca', 'F02542', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (277, -160, 47.545902, 19.088745, 'Gyöngyösi utca', 'F02545', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (278, -160, 47.540159, 19.085595, 'Fiastyúk utca', 'F02553', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (279, -160, 47.523891, 19.055785, 'Vág utca', 'F02573', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (280, -160, 47.529376, 19.080195, 'Béke tér', 'F02585', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (281, -160, 47.523918, 19.062809, 'Dózsa György út M', 'F02611', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (282, -160, 47.542075, 19.086513, 'Rokolya utca', 'F02662', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (283, -160, 47.480073, 19.256804, 'Városközpont (Ferihegyi út)', 'F03605', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (284, -160, 47.425624, 19.063008, 'Mansfeld Péter utca', 'F04269', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (285, -160, 47.405716, 19.053252, 'Csepeli temető', 'F04285', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (286, -159, 47.584781, 19.041862, 'Csillaghegy H', '8405', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (287, -159, 47.61476, 19.108789, 'Székesdűlő ipartelep', '8650', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (288, -159, 47.549906, 19.090953, 'Angyalföld vasútállomás', '91817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (289, -159, 47.505315, 19.136268, 'Örs vezér tere M+H', '102825', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (290, -159, 47.54946, 19.098658, 'Brüsszeli utca', 'F00863', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (291, -159, 47.481296, 19.004593, 'Süveg utca', 'F02098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (292, -159, 47.501797, 19.015553, 'Goldmark Károly utca', 'F02404', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (293, -159, 47.497571, 19.020891, 'Kék Golyó utca', 'F02408', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (294, -159, 47.520564, 19.054072, 'Ipoly utca', 'F02521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (295, -159, 47.540426, 19.08562, 'Fiastyúk utca', 'F02555', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (296, -159, 47.528653, 19.084137, 'Szegedi út', 'F02645', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (297, -159, 47.54456, 19.093433, 'Kucsma utca', 'F02658', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (298, -159, 47.542701, 19.086976, 'Rokolya utca', 'F02660', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (299, -159, 47.534356, 19.082255, 'Frangepán utca', 'F02675', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (300, -159, 47.436741, 19.222565, 'Ferihegy vasútállomás', 'F03810', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (301, -159, 47.589925, 18.936022, 'Bajcsy-Zsilinszky utca', 'F04797', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (302, -158, 47.523865, 19.093761, 'Teleki Blanka utca', '8242', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (303, -158, 47.516133, 18.92257, 'Zichy Péter utca', '9440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (304, -158, 47.478941, 19.256176, 'Városközpont Posta', '9684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (305, -158, 47.495593, 19.077229, 'II. János Pál pápa tér', '56231', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (306, -158, 47.600123, 19.046354, 'Templom utca', 'F00446', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (307, -158, 47.5856, 19.094053, 'Megyer Szondi utca', 'F00717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (308, -158, 47.578358, 19.115752, 'Óceán-árok utca', 'F00745', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (309, -158, 47.497293, 19.020652, 'Kék Golyó utca', 'F02409', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (310, -158, 47.495521, 19.019711, 'Királyhágó tér', 'F02414', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (311, -158, 47.52999, 19.048349, 'Szabadtéri Színpad', 'F02497', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (312, -158, 47.518335, 19.051456, 'Szent István park', 'F02512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (313, -158, 47.537978, 19.089589, 'Futár utca', 'F02550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (314, -158, 47.543107, 19.081942, 'Násznagy utca', 'F02558', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (315, -158, 47.526336, 19.078348, 'Lehel utca / Róbert Károly körút', 'F02598', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (316, -158, 47.531279, 19.08568, 'Petneházy utca', 'F02635', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (317, -158, 47.541719, 19.091757, 'Rokolya utca', 'F02656', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (318, -158, 47.534435, 19.087515, 'Fáy utca', 'F02671', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (319, -158, 47.447251, 19.17024, 'Üllői út', 'F03750', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (320, -158, 47.432729, 18.913967, 'Harangláb', 'F04757', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (321, -157, 47.591374, 19.045384, 'Kert sor', '8403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (322, -157, 47.598851, 18.962065, 'Solymár Auchan áruház', '44601', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (323, -157, 47.602723, 19.051234, 'Táncsics Mihály utca', 'F00437', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (324, -157, 47.564923, 19.111018, 'Rákospalota-Újpest vasútállomás', 'F00847', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (325, -157, 47.522799, 19.080721, 'Vágány utca / Róbert Károly körút', 'F02629', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (326, -157, 47.568176, 19.116776, 'Pozsony utca', 'F03021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (327, -157, 47.54008, 19.12876, 'Sztárai Mihály tér', 'F03096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (328, -157, 47.433425, 19.220659, 'Duna utca', 'F03784', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (329, -157, 47.439856, 19.211861, 'Szent László utca', 'F03809', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (330, -157, 47.451682, 19.172968, 'Lakatos úti lakótelep', 'F03840', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (331, -156, 47.4301, 19.003628, 'Budatétény benzinkút', '8918', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (332, -156, 47.485792, 19.057571, 'Fővám tér', '56225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (333, -156, 47.45842, 19.023497, 'Borszéki utca', '62512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (334, -156, 47.545042, 18.952482, 'Villám utca', 'F00353', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (335, -156, 47.598146, 19.055822, 'Békásmegyer H', 'F00469', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (336, -156, 47.446617, 19.013771, 'Kápolna út', 'F01898', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (337, -156, 47.452172, 19.038418, 'Albertfalva kitérő', 'F02016', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (338, -156, 47.53705, 19.126657, 'Emlék tér', 'F03185', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (339, -156, 47.433179, 19.207227, 'Üllői út', 'F03883', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (340, -156, 47.409997, 19.055491, 'Tejút utca', 'F04284', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (341, -155, 47.471697, 19.220067, 'Újmajori utca', '9137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (342, -155, 47.379529, 19.104152, 'Millenniumtelep H', '9358', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (343, -155, 47.531435, 19.10066, 'Rákospatak utca', '9495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (344, -155, 47.460867, 19.025127, 'Than Károly utca', '62511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (345, -155, 47.428518, 18.91297, 'Művelődési Ház', '73427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (346, -155, 47.498312, 19.039816, 'Clark Ádám tér', 'F00047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (347, -155, 47.601213, 19.057923, 'Szolgáltatóház', 'F00463', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (348, -155, 47.594683, 19.052777, 'Madzsar József utca / Pünkösdfürdő utca', 'F00476', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (349, -155, 47.597156, 19.063308, 'Hatvany Lajos utca / Juhász Gyula utca', 'F00649', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (350, -155, 47.497398, 18.989331, 'Svábhegy', 'F02316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (351, -155, 47.534264, 19.124741, 'Pestújhelyi tér', 'F03095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (352, -155, 47.524712, 19.229467, 'Ilonatelep H (Georgina utca)', 'F03332', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (353, -155, 47.473459, 19.253634, 'Széchenyi utca', 'F03516', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (354, -155, 47.452025, 19.194795, 'Fedezék utca', 'F03697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (355, -155, 47.447406, 19.191614, 'Lőrinci temető', 'F03700', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (356, -155, 47.443488, 19.189071, 'Gárdonyi Géza utca', 'F03703', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (357, -155, 47.443505, 19.186521, 'Regény utca', 'F03705', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (358, -155, 47.449554, 19.193118, 'Kosztolányi Dezső utca', 'F03830', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (359, -155, 47.391841, 19.106351, 'Szent István út', 'F04231', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (360, -155, 47.414855, 19.004238, 'Nyél utca', 'F04442', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (361, -154, 47.444924, 18.894036, 'Határ utca', '8331', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (362, -154, 47.652067, 19.071331, 'Egyetem', '8439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (455, -147, 47.582644, 19.105908, 'Gimnázium', 'F00731', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (363, -154, 47.595527, 19.054026, 'Madzsar József utca / Pünkösdfürdő utca', '44131', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (364, -154, 47.466517, 19.126213, 'Kőér utcai uszoda', 'F01463', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (365, -154, 47.478271, 19.025398, 'Fehérló utca', 'F02114', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (366, -154, 47.479991, 19.051428, 'Gárdonyi tér', 'F02148', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (367, -154, 47.517793, 19.054762, 'Victor Hugo utca', 'F02514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (368, -154, 47.51586, 19.049783, 'Radnóti Miklós utca', 'F02650', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (369, -154, 47.51725, 19.222881, 'Cinke utca', 'F03386', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (370, -154, 47.471258, 19.251994, 'Bulyovszky utca', 'F03587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (371, -154, 47.436262, 19.209357, 'Selmecbánya utca', 'F03852', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (372, -153, 47.416184, 19.116209, 'Alsó határút', '9243', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (373, -153, 47.391055, 19.114151, 'Szent István utca H', '9339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (374, -153, 47.594962, 19.051673, 'Pünkösdfürdő utca', 'F00458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (375, -153, 47.489219, 19.061248, 'Kálvin tér M', 'F01005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (376, -153, 47.486149, 19.107166, 'Kőbányai út / Könyves Kálmán körút', 'F01268', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (377, -153, 47.449307, 19.015214, 'Olajfa utca', 'F01901', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (378, -153, 47.535944, 19.052374, 'Zenélőkút', 'F02501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (379, -153, 47.515644, 19.053195, 'Radnóti Miklós utca', 'F02523', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (380, -153, 47.525833, 19.078188, 'Lehel utca / Róbert Károly körút', 'F02593', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (381, -153, 47.524016, 19.098116, 'Kassai tér', 'F02899', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (382, -153, 47.534238, 19.10644, 'Sárrét park', 'F02954', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (383, -153, 47.429588, 19.218274, 'Pestszentlőrinc Béke tér', 'F03789', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (384, -152, 47.481389, 19.103174, 'Vajda Péter utca', '8146', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (385, -152, 47.485742, 19.107077, 'Kőbányai út / Könyves Kálmán körút', '8148', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (386, -152, 47.476345, 19.098937, 'Népliget M', '8287', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (387, -152, 47.597856, 19.053867, 'Békásmegyer H', '44192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (388, -152, 47.456585, 19.019811, 'Igmándi utca', '61271', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (389, -152, 47.602534, 19.048003, 'Gulácsy Lajos utca', 'F00439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (390, -152, 47.601572, 19.036089, 'Pince köz', 'F00451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (391, -152, 47.481004, 19.10268, 'Vajda Péter utca', 'F01257', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (392, -152, 47.48168, 18.999021, 'Márton Áron tér', 'F02082', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (393, -152, 47.496929, 19.007367, 'Vöröskő utca', 'F02429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (394, -152, 47.497524, 18.989742, 'Svábhegy', 'F02442', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (395, -152, 47.529512, 19.102706, 'Pándorfalu utca', 'F02903', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (396, -152, 47.527477, 19.101107, 'Nezsider park', 'F02952', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (397, -152, 47.540945, 19.139162, 'Fő tér', 'F03151', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (398, -152, 47.520403, 19.218485, 'Cinkota H', 'F03304', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (399, -152, 47.402476, 18.943997, 'Gyár utca', 'F04771', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (400, -151, 47.388344, 19.103486, 'Csepel Horgásztanya', '9369', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (401, -151, 47.418464, 19.032657, 'Széchenyi utca', '9473', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (402, -151, 47.471087, 19.10912, 'Ecseri út M', 'F01491', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (403, -151, 47.503118, 18.989736, 'Adonis utca', 'F02320', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (404, -151, 47.539458, 19.069627, 'Forgách utca M', 'F02678', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (405, -151, 47.5228, 19.101992, 'Nagy Lajos király útja / Czobor utca', 'F02865', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (406, -151, 47.440325, 19.186557, 'Szarvas csárda tér', 'F03716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (407, -151, 47.438126, 19.157108, 'Kolozsvár utca (temető)', 'F03726', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (408, -151, 47.436814, 19.155992, 'Kassa utca', 'F03997', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (409, -151, 47.422102, 19.068441, 'Szent István út', 'F04193', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (410, -151, 47.421824, 19.065751, 'Csepel H', 'F04294', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (411, -150, 47.470487, 19.108771, 'Ecseri út M', '8500', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (412, -150, 47.421339, 19.035137, 'Komló utca', '9474', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (413, -150, 47.573309, 19.121115, 'Kovácsi Kálmán tér', '9504', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (414, -150, 47.467691, 19.106513, 'Közterületfenntartó Zrt.', 'F01482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (415, -150, 47.470616, 19.019684, 'Dayka Gábor utca', 'F02053', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (416, -150, 47.489203, 19.007081, 'Boldog Teréz anya tér', 'F02286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (417, -150, 47.526272, 19.104973, 'Fűrész utca', 'F02947', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (418, -150, 47.58247, 19.123419, 'Bútorraktár', 'F03001', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (419, -149, 47.467861, 19.106659, 'Közterületfenntartó Zrt.', '8499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (420, -149, 47.483337, 19.053086, 'Szent Gellért tér - Műegyetem M', '8590', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (421, -149, 47.469552, 19.227168, 'Ősrepülő utca', '9140', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (422, -149, 47.386335, 19.110172, 'Orbánhegyi dűlő', '9337', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (423, -149, 47.50214, 18.916538, 'Szőlőskert utca', '47985', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (424, -149, 47.490163, 18.909369, 'Farkashegyi repülőtér', '98531', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (425, -149, 47.581642, 19.054544, 'Huba utca', 'F00496', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (426, -149, 47.475879, 19.098907, 'Népliget M', 'F01286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (427, -149, 47.465158, 19.152296, 'Felüljáró', 'F01583', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (428, -149, 47.529228, 19.107563, 'Rákospatak utca', 'F02861', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (429, -149, 47.573938, 19.121674, 'Kovácsi Kálmán tér', 'F03017', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (430, -149, 47.473864, 19.241802, 'Bulyovszky utca', 'F03535', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (431, -149, 47.458884, 19.235011, 'Rákóczi Ferenc utca', 'F03596', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (432, -149, 47.443278, 19.175945, 'Baross utca', 'F03769', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (433, -149, 47.418989, 19.2085, 'Kétújfalu utca', 'F03798', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (434, -149, 47.416707, 19.061336, 'Erdősor utca', 'F04349', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (435, -149, 47.415326, 19.012466, 'Őszibarack utca', 'F04447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (436, -149, 47.379465, 19.00907, 'Lacházi fogadó', 'F04680', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (437, -148, 47.526789, 18.948279, 'Laktanya', 'F00181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (438, -148, 47.578003, 19.106647, 'Erdősor út', 'F00749', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (439, -148, 47.45028, 19.019589, 'Torma utca', 'F01939', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (440, -148, 47.533254, 19.05066, 'Szállodák (Hotels)', 'F02499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (441, -148, 47.534721, 19.112695, 'Öv utca', 'F02858', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (442, -148, 47.532527, 19.110648, 'Miskolci utca', 'F02909', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (443, -148, 47.583709, 19.130488, 'Hulladékhasznosító Mű', 'F03005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (444, -148, 47.577021, 19.124496, 'Árokhát út', 'F03170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (445, -148, 47.57961, 19.126786, 'Rákospalota Székely Elek út', 'F03175', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (446, -148, 47.425784, 19.21472, 'Beszterce utca', 'F03846', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (447, -148, 47.422744, 19.211943, 'Halomi út', 'F03848', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (448, -148, 47.432469, 19.165924, 'Csíky utca', 'F03894', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (449, -148, 47.461958, 19.149597, 'Kőbánya-Kispest M', 'F03940', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (450, -148, 47.412228, 19.057334, 'Vas Gereben utca', 'F04274', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (451, -147, 47.4861, 19.007031, 'Liptó utca', '8050', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (452, -147, 47.600323, 19.093374, 'Vízművek', '8645', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (453, -147, 47.659618, 19.074522, 'Szentendre H', '9119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (454, -147, 47.606849, 19.098223, 'Ezred utca', '9422', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (456, -147, 47.574485, 19.09833, 'Nádor utca', 'F00759', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (457, -147, 47.571975, 19.095847, 'Vécsey Károly utca', 'F00772', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (458, -147, 47.569935, 19.085494, 'Tinódi utca', 'F00896', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (459, -147, 47.476846, 19.244745, 'Árpád fejedelem utca', 'F03537', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (460, -147, 47.399762, 19.050907, 'Fácánhegyi utca', 'F04287', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (461, -147, 47.390919, 18.981251, 'Angeli utca / Nagytétényi út', 'F04499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (462, -147, 47.591669, 18.937233, 'Solymár községháza', 'F04701', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (463, -146, 47.533299, 19.043582, 'Tímár utca H', '8519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (464, -146, 47.461923, 19.149332, 'Kőbánya-Kispest M', '43176', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (465, -146, 47.344797, 19.04225, 'Városi Könyvtár', '64088', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (466, -146, 47.579396, 19.087494, 'Megyeri út / Fóti út', 'F00713', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (467, -146, 47.567148, 19.082662, 'Langlet Waldemár utca', 'F00893', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (468, -146, 47.452769, 18.990932, 'Vitorlázó út', 'F01937', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (469, -146, 47.503046, 18.989882, 'Adonis utca', 'F02387', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (470, -146, 47.462925, 19.238991, 'Melczer utca', 'F03523', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (471, -146, 47.461371, 19.23746, 'Vörösmarty utca', 'F03592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (472, -146, 47.435883, 19.169193, 'Iker utca', 'F03722', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (473, -146, 47.413188, 19.201433, 'Alacskai úti lakótelep', 'F03861', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (474, -145, 47.394732, 19.116579, 'Gyáli-patak', '8960', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (475, -145, 47.495675, 19.029451, 'Ág utca', 'F00097', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (476, -145, 47.579645, 19.052948, 'Attila utca', 'F00494', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (477, -145, 47.576042, 19.083943, 'Szusza Ferenc Stadion', 'F00767', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (478, -145, 47.575653, 19.09128, 'Irányi Dániel utca / Baross utca', 'F00769', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (479, -145, 47.572435, 19.088008, 'Mildenberger utca', 'F00848', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (480, -145, 47.584694, 19.107864, 'Dunakeszi utca', 'F00872', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (481, -145, 47.511012, 19.056487, 'Nyugati pályaudvar M', 'F01027', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (482, -145, 47.45037, 19.019682, 'Torma utca', 'F01938', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (483, -145, 47.481835, 19.038293, 'Ménesi út', 'F02255', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (484, -145, 47.498515, 19.016948, 'Istenhegyi út', 'F02402', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (485, -145, 47.456181, 19.232336, 'Bocskai István utca', 'F03562', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (486, -145, 47.465717, 19.24176, 'Ady Endre utca', 'F03589', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (487, -145, 47.439397, 19.172343, 'Margó Tivadar utca', 'F03762', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (488, -145, 47.458247, 19.145757, 'Kispest Kossuth tér', 'F03945', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (489, -145, 47.51349, 18.93244, 'Gyógyszertár', 'F04684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (490, -144, 47.582507, 19.090754, 'Megyeri temető', 'F00709', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (491, -144, 47.577829, 19.093501, 'Fóti út', 'F00753', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (492, -144, 47.579897, 19.095749, 'Izzó utca', 'F00884', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (493, -144, 47.506441, 19.024775, 'Széll Kálmán tér M', 'F02462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (494, -144, 47.530452, 19.121335, 'Telepes utca', 'F02850', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (495, -143, 47.470028, 19.157173, 'Gőzmozdony utca', '8863', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (496, -143, 47.588027, 19.0967, 'Szilaspatak sor', '70128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (497, -143, 47.466648, 19.016251, 'Sasadi út', '82617', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (498, -143, 47.403146, 19.010169, 'Rózsakert utca / Minta utca', '102568', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (499, -143, 47.508114, 19.026739, 'Széna tér', 'F00307', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (500, -143, 47.455118, 18.993184, 'Örsöddűlő', 'F02233', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (501, -143, 47.526776, 19.11724, 'Szentes utca', 'F02913', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (502, -143, 47.447568, 19.158533, 'Kispest vasútállomás', 'F03732', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (503, -143, 47.416583, 19.072203, 'Völgy utca', 'F04208', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (504, -143, 47.404901, 19.012155, 'Budatétény vasútállomás (Növény utca)', 'F04457', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (505, -142, 47.51926, 19.126312, 'Egressy út / Vezér utca', '8844', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (506, -142, 47.349535, 19.025706, 'Nap utca (óvoda)', '64090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (507, -142, 47.347331, 19.023126, 'Miklós tér', '64181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (508, -142, 47.467854, 19.154808, 'Gergely utca (Sibrik Miklós út)', 'F01694', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (509, -142, 47.471843, 19.159273, 'Szövőszék utca', 'F01697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (510, -142, 47.488602, 19.135237, 'Ónodi utca', 'F01827', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (511, -142, 47.494783, 19.018848, 'Királyhágó tér', 'F02420', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (512, -142, 47.523307, 19.113372, 'Fűrész utca', 'F02852', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (513, -142, 47.520074, 19.119223, 'Fűrész utca / Szugló utca', 'F02943', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (514, -142, 47.571884, 19.126948, 'Közvágóhíd utca', 'F03013', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (515, -142, 47.471046, 19.239047, 'Bánya part', 'F03533', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (516, -142, 47.444567, 19.155185, 'Bozsik Stadion', 'F03730', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (517, -141, 47.508484, 19.026989, 'Széna tér', 'F00306', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (518, -141, 47.569525, 19.099182, 'Vécsey Károly utca', 'F00775', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (519, -141, 47.458268, 18.996894, 'Keserűvíz-forrás', 'F01935', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (520, -141, 47.525992, 19.12028, 'Miskolci utca / Csömöri út', 'F02847', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (521, -141, 47.569492, 19.126878, 'Csobogós utca', 'F03025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (522, -141, 47.562182, 19.123625, 'Epres sor', 'F03038', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (523, -141, 47.536946, 19.134639, 'Apolló utca', 'F03137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (524, -141, 47.413585, 19.069047, 'Dr. Koncz János tér', 'F04211', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (525, -140, 47.599639, 19.112654, 'Káposztásmegyer Aquaworld', '43481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (526, -140, 47.491037, 19.138015, 'Élessarok', 'F01623', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (527, -140, 47.516627, 19.12291, 'Turán utca', 'F02801', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (528, -140, 47.524595, 19.124514, 'Miskolci utca / Szugló utca', 'F02941', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (529, -140, 47.547779, 19.115076, 'Szent Korona útja', 'F03203', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (530, -140, 47.517136, 18.936282, 'Erkel Ferenc utca', 'F04682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (531, -140, 47.410608, 18.956343, 'Diósd törökbálinti elágazás', 'F04767', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (532, -139, 47.590199, 19.083142, 'Bagaria utca', '9572', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (533, -139, 47.355356, 19.045862, 'Temető utca', '64318', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (534, -139, 47.507822, 19.087004, 'Ötvenhatosok tere', '90482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (535, -139, 47.349496, 19.053633, 'Dunaharaszti utca', '101184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (536, -139, 47.565716, 19.088322, 'Illek Vince utca', 'F00782', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (537, -139, 47.467215, 19.016596, 'Sasadi út', 'F01926', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (538, -139, 47.480557, 19.036674, 'Szüret utca', 'F02129', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (539, -139, 47.50808, 19.094252, 'Stefánia út / Thököly út', 'F02722', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (540, -139, 47.520258, 19.11293, 'Bosnyák tér', 'F02827', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (541, -139, 47.520096, 19.112731, 'Bosnyák tér', 'F02828', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (542, -139, 47.519782, 19.112319, 'Bosnyák tér', 'F02829', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (543, -139, 47.546848, 19.127101, 'Szerencs utca / Bánkút utca', 'F03110', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (544, -139, 47.549694, 19.12223, 'Szerencs utca', 'F03112', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (545, -139, 47.548835, 19.12338, 'Szerencs utca', 'F03121', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (546, -139, 47.538752, 19.136408, 'Madách utca', 'F03138', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (547, -139, 47.419048, 19.065511, 'Völgy utca', 'F04292', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (548, -139, 47.41653, 19.062264, 'Erdősor utca', 'F04366', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (549, -138, 47.383056, 19.201876, 'Erdősor utca', '8552', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (550, -138, 47.430977, 18.916119, 'Munkácsy Mihály utca (hősi emlékmű)', '73473', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (551, -138, 47.488238, 19.037084, 'Szirtes út', 'F00011', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (552, -138, 47.533557, 19.073742, 'Pap Károly utca', 'F02575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (553, -138, 47.510421, 19.090419, 'Zichy Géza utca', 'F02711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (554, -138, 47.505868, 19.09133, 'Cházár András utca', 'F02717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (555, -138, 47.51172, 19.099153, 'Zugló vasútállomás', 'F02730', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (556, -138, 47.517486, 19.116193, 'Szugló utca / Nagy Lajos király útja', 'F02813', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (557, -138, 47.520474, 19.113209, 'Bosnyák tér', 'F02826', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (558, -138, 47.563645, 19.112916, 'Fő út', 'F03051', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (559, -138, 47.539969, 19.119303, 'Vág utca', 'F03101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (560, -138, 47.542463, 19.121157, 'Vasutastelep utca', 'F03105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (561, -138, 47.55194, 19.133667, 'Rákospalotai köztemető', 'F03127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (562, -138, 47.533558, 19.130251, 'Molnár Viktor utca', 'F03196', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (563, -137, 47.545721, 19.112403, 'Széchenyi út', '9652', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (564, -137, 47.56572, 19.022755, 'Óbudai temető', 'F00524', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (565, -137, 47.504241, 19.089298, 'Reiner Frigyes park', 'F01145', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (566, -137, 47.480895, 18.99268, 'Eper utca', 'F02080', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (567, -137, 47.427282, 18.979257, 'Dűlő út', 'F02161', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (568, -137, 47.489157, 18.958834, 'KFKI étterem', 'F02270', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (569, -137, 47.506818, 19.024895, 'Széll Kálmán tér', 'F02480', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (570, -137, 47.51255, 19.09326, 'Vakok Intézete', 'F02714', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (571, -137, 47.507333, 19.140578, 'Álmos vezér útja', 'F02765', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (572, -137, 47.524158, 19.132719, 'Rákosmezei tér', 'F02806', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (573, -137, 47.543051, 19.108548, 'Karatna tér', 'F03174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (574, -137, 47.53828, 19.117003, 'Tóth István utca', 'F03205', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (575, -137, 47.495779, 19.260729, 'Ároktő út', 'F03572', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (576, -137, 47.408167, 19.015956, 'Lépcsős utca', 'F04456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (577, -137, 47.376028, 19.223359, 'Kőrösi út', 'F04604', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (578, -137, 47.378622, 19.226983, 'Szent István utca', 'F04647', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (579, -136, 47.536842, 19.114916, 'Kolozsvár utcai piac', '8245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (580, -136, 47.53208, 19.155893, 'József utca', '9379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (581, -136, 47.511107, 19.146099, 'Csertő utca', '9498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (582, -136, 47.416015, 19.2435, 'Lincoln út', '50173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (583, -136, 47.504796, 19.062525, 'Oktogon M', 'F01088', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (584, -136, 47.482529, 19.158114, 'Kada utca / Maglódi út', 'F01677', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (585, -136, 47.535232, 19.115591, 'Árvavár utca', 'F02687', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (586, -136, 47.52272, 19.130858, 'Kála utca', 'F02805', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (587, -136, 47.524769, 19.133504, 'Rákosmezei tér', 'F02807', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (588, -136, 47.524356, 19.118207, 'Rákospatak utca / Csömöri út', 'F02844', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (589, -136, 47.522264, 19.092857, 'Amerikai út (Mexikói út M)', 'F02974', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (590, -136, 47.567236, 19.124575, 'Rákospalota Kossuth utca', 'F03030', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (591, -136, 47.544217, 19.114885, 'Széchenyi út', 'F03086', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (592, -136, 47.40898, 19.193869, 'Kisfaludy utca', 'F03650', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (593, -136, 47.454662, 19.14091, 'Ady Endre út (Báthory utca)', 'F03956', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (594, -136, 47.452181, 19.135435, 'Áchim András utca', 'F03975', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (595, -136, 47.380893, 19.23022, 'Gyál Vecsési út', 'F04603', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (596, -135, 47.507404, 19.066482, 'Vörösmarty utca M', '7979', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (597, -135, 47.404222, 19.186489, 'Pestszentimre vasútállomás', '8399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (598, -135, 47.531561, 19.070886, 'Honvédkórház', '9420', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (599, -135, 47.572507, 19.010551, 'Borsmenta utca', '56123', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (600, -135, 47.488008, 19.30077, 'Ecsedháza köz', '66686', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (601, -135, 47.565357, 18.950452, 'Gyarmati Dezső Uszoda', 'F00393', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (602, -135, 47.499841, 19.055259, 'Bajcsy-Zsilinszky út', 'F00996', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (603, -135, 47.502197, 19.058761, 'Opera', 'F01079', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (604, -135, 47.504923, 19.062823, 'Oktogon', 'F01085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (605, -135, 47.507149, 19.066118, 'Vörösmarty utca', 'F01092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (606, -135, 47.509508, 19.069576, 'Kodály körönd M', 'F01094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (607, -135, 47.509175, 19.069116, 'Kodály körönd', 'F01095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (608, -135, 47.510875, 19.071674, 'Bajza utca M', 'F01099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (609, -135, 47.511237, 19.072271, 'Bajza utca', 'F01100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (610, -135, 47.514055, 19.076439, 'Hősök tere', 'F01102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (611, -135, 47.446197, 19.100297, 'Ősz utca', 'F01443', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (612, -135, 47.471632, 19.141848, 'Gyömrői út', 'F01579', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (613, -135, 47.508145, 19.008248, 'Szent János Kórház', 'F02328', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (614, -135, 47.510813, 19.097825, 'Zugló vasútállomás (Hermina út)', 'F02726', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (615, -135, 47.544152, 19.123577, 'Wesselényi utca', 'F03190', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (616, -135, 47.449291, 19.131222, 'Hunyadi tér', 'F03971', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (617, -135, 47.449765, 19.145781, 'Kossuth Lajos utca', 'F03977', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (618, -135, 47.444555, 19.138082, 'Nádasdy utca', 'F03982', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (619, -135, 47.447582, 19.149364, 'Vas Gereben utca (Ady Endre út)', 'F04002', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (620, -135, 47.447385, 19.14225, 'Áchim András utca', 'F04027', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (621, -135, 47.444043, 19.143868, 'Mészáros Lőrinc utca', 'F04035', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (622, -135, 47.440575, 19.11807, 'Jókai Mór utca', 'F04104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (623, -135, 47.442999, 19.11163, 'Tátra tér', 'F04136', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (624, -134, 47.56985, 19.272388, 'Szilasliget Posta', '9008', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (625, -134, 47.521143, 19.011996, 'Endrődi Sándor utca', '9512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (626, -134, 47.355831, 19.027066, 'Ady Endre utca', '64170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (627, -134, 47.486224, 19.297962, 'Csabamező utca / Göcsej utca', '66684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (628, -134, 47.514067, 19.076454, 'Hősök tere M', 'F01050', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (629, -134, 47.503169, 19.060096, 'Opera M', 'F01077', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (630, -134, 47.454618, 19.113036, 'Nagykőrösi út / Határ út', 'F01370', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (631, -134, 47.444507, 19.09775, 'Gubacsi út / Határ út', 'F01438', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (632, -134, 47.44901, 19.104503, 'Jókai Mór utca / Határ út', 'F01444', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (633, -134, 47.455443, 19.114062, 'Nagykőrösi út / Határ út', 'F01452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (634, -134, 47.4593, 19.120044, 'Mészáros Lőrinc utca', 'F01456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (635, -134, 47.459714, 19.120363, 'Mészáros Lőrinc utca', 'F01458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (636, -134, 47.474062, 19.116058, 'Balkán utca', 'F01531', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (637, -134, 47.476066, 19.119165, 'Fertő utca / Bihari utca', 'F01535', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (638, -134, 47.478717, 19.12348, 'Kocsis Sándor Sportközpont', 'F01575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (639, -134, 47.479359, 19.153464, 'Kada utca / Mádi utca', 'F01681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (640, -134, 47.521761, 19.092006, 'Mexikói út', 'F02895', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (641, -134, 47.519951, 19.097727, 'Laky Adolf utca', 'F02963', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (642, -134, 47.564783, 19.121077, 'Széchenyi tér', 'F03045', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (643, -134, 47.525883, 19.228515, 'Ilonatelep H', 'F03334', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (644, -134, 47.530593, 19.235875, 'Cinkotai köztemető', 'F03406', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (645, -134, 47.405855, 19.189024, 'Ady Endre utca', 'F03663', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (646, -134, 47.399113, 19.178607, 'Lajos utca', 'F03676', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (647, -134, 47.401169, 19.181805, 'Szélső utca', 'F03820', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (648, -134, 47.445086, 19.124931, 'Nagykőrösi út', 'F04042', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (649, -134, 47.432881, 19.136949, 'Szilágyság utca', 'F04049', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (650, -134, 47.442687, 19.121215, 'Mártírok útja', 'F04098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (651, -134, 47.440545, 19.107875, 'Szent Erzsébet tér', 'F04133', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (652, -134, 47.510655, 18.929291, 'Budakeszi városháza', 'F04708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (653, -133, 47.409242, 19.003434, 'Gyula vezér út', '8922', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (654, -133, 47.421187, 19.126871, 'Szent László utca', '8965', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (655, -133, 47.516355, 19.003967, 'Pasaréti út', '9511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (656, -133, 47.547898, 19.263369, 'Zsófialiget H', '9764', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (657, -133, 47.416403, 19.205417, 'Tölgy utca', '22005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (658, -133, 47.568005, 19.026037, 'Óbudai autóbuszgarázs', 'F00679', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (659, -133, 47.588837, 19.114104, 'Szíjgyártó utca', 'F00727', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (660, -133, 47.507304, 19.072123, 'Lövölde tér', 'F01045', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (661, -133, 47.511695, 19.062594, 'Ferdinánd híd (Izabella utca)', 'F01069', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (662, -133, 47.480323, 19.066048, 'Boráros tér H', 'F01375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (663, -133, 47.452039, 19.109095, 'Mártírok útja / Határ út', 'F01447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (664, -133, 47.475865, 19.148071, 'Kemence utca', 'F01688', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (665, -133, 47.491236, 19.009429, 'Bürök utca', 'F02287', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (666, -133, 47.507204, 18.994364, 'Erdei iskola', 'F02322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (667, -133, 47.511865, 19.115416, 'Róna utca', 'F02915', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (668, -133, 47.5372, 19.162956, 'Szentmihályi út', 'F02985', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (669, -133, 47.567442, 19.124841, 'Rákospalota Kossuth utca', 'F03029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (670, -133, 47.522796, 19.223777, 'Műkő utca', 'F03398', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (671, -133, 47.396787, 19.175078, 'Pestszentimre Ültetvény utca', 'F03659', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (672, -133, 47.431181, 19.124937, 'Eperjes utca', 'F04069', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (673, -133, 47.435544, 19.109142, 'Török Flóris utca', 'F04115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (674, -133, 47.43431, 19.139139, 'Vaskapu utca', 'F04167', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (675, -133, 47.413907, 18.996341, 'XVI. utca', 'F04513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (676, -132, 47.501104, 19.082712, 'Keleti pályaudvar M', '7950', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (677, -132, 47.408, 19.001541, 'Terv utca', '8952', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (678, -132, 47.545584, 19.259693, 'Kistarcsa H', '9001', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (679, -132, 47.551324, 19.268897, 'Kerepes H', '9003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (680, -132, 47.515203, 19.002149, 'Szilágyi Erzsébet fasor', '9540', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (681, -132, 47.376792, 19.006348, 'Lakihegy Cseresznyés utca', '37748', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (682, -132, 47.587327, 19.111629, 'Kordován tér', 'F00901', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (683, -132, 47.500363, 19.055901, 'Bajcsy-Zsilinszky út M', 'F00945', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (684, -132, 47.479495, 19.067865, 'Boráros tér H', 'F01515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (685, -132, 47.476728, 18.98747, 'Irhás árok', 'F02076', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (686, -132, 47.481923, 18.998861, 'Márton Áron tér', 'F02084', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (687, -132, 47.507174, 18.988152, 'Regőczi István tér', 'F02372', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (688, -132, 47.513353, 19.136061, 'Vezér utca / Fogarasi út', 'F02789', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (689, -132, 47.5143, 19.119229, 'Egressy tér', 'F02798', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (690, -132, 47.536254, 19.244727, 'Raktárak', 'F03285', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (691, -132, 47.446001, 19.146921, 'Szegfű utca', 'F04036', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (692, -132, 47.431329, 19.101529, 'Attila utca', 'F04155', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (693, -132, 47.411873, 18.993004, 'XIII. utca / Dózsa György út', 'F04474', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (694, -132, 47.41868, 19.122574, 'Erzsébeti temető', 'F04565', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (695, -132, 47.509131, 18.926755, 'Erdő utca', 'F04733', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (696, -131, 47.417266, 19.0224, 'Gyűszű utca', '9451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (697, -131, 47.505425, 19.069083, 'Izabella utca / Király utca', 'F01041', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (698, -131, 47.472525, 19.095428, 'Albert Flórián út', 'F01500', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (699, -131, 47.501747, 19.115376, 'Várna utca', 'F01737', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (700, -131, 47.503499, 19.118511, 'Pillangó utca', 'F02928', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (701, -131, 47.514264, 19.103391, 'Amerikai út', 'F02960', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (702, -131, 47.411116, 19.197147, 'Damjanich utca', 'F03654', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (703, -131, 47.403503, 19.185428, 'Pestszentimre vasútállomás (Dózsa Gy.u.)', 'F03672', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (704, -131, 47.432893, 19.104221, 'Baross utca', 'F04041', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (705, -131, 47.410061, 19.072372, 'Erdősor utca', 'F04232', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (706, -130, 47.482635, 19.054014, 'Szent Gellért tér - Műegyetem', '56223', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (707, -130, 47.545841, 18.992119, 'Szépvölgyi dűlő', '64215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (708, -130, 47.416264, 19.143255, 'Köves út / Szentlőrinci út', '75655', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (709, -130, 47.5235, 18.992834, 'Pasaréti tér', 'F00278', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (710, -130, 47.509276, 19.058597, 'Nyugati pályaudvar M (Podmaniczky utca)', 'F01031', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (711, -130, 47.503573, 19.06595, 'Király utca / Erzsébet körút', 'F01034', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (712, -130, 47.500928, 19.069307, 'Wesselényi utca / Erzsébet körút', 'F01115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (713, -130, 47.482795, 19.130358, 'Liget tér', 'F01641', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (714, -130, 47.511224, 19.132301, 'Mályva utca', 'F02937', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (715, -130, 47.528586, 19.150547, 'Rigó utca', 'F02991', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (716, -130, 47.456343, 19.201531, 'Frangepán utca', 'F03427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (717, -130, 47.434313, 19.106649, 'Vörösmarty utca', 'F04116', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (718, -130, 47.437643, 19.100872, 'Pesterzsébet városközpont', 'F04123', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (719, -130, 47.438003, 19.101536, 'Pesterzsébet városközpont', 'F04124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (720, -130, 47.438883, 19.103154, 'Ady Endre utca (Topánka utca)', 'F04127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (721, -130, 47.410069, 19.062898, 'Szabadság utca', 'F04281', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (722, -130, 47.417142, 19.135863, 'Dinnyehegyi út', 'F04525', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (723, -130, 47.420747, 19.126128, 'Szent László utca', 'F04572', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (724, -130, 47.415651, 19.133276, 'Szent László utca', 'F04575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (725, -129, 47.433275, 18.919546, 'Bartók Béla utca', '8317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (726, -129, 47.581363, 19.037795, 'Forrásliget lakópark', '8407', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (727, -129, 47.560766, 19.2837, 'Szilasligeti elágazás', '9006', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (728, -129, 47.471748, 19.29995, 'Zimonyi utca', '66969', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (729, -129, 47.432683, 19.261152, 'Liszt Ferenc Airport 2', '94505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (730, -129, 47.518494, 19.064667, 'Dévai utca', 'F01061', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (731, -129, 47.518703, 19.064853, 'Dévai utca', 'F01063', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (732, -129, 47.498554, 19.06551, 'Nyár utca', 'F01109', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (733, -129, 47.502546, 19.072161, 'Almássy tér', 'F01118', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (734, -129, 47.466209, 19.102134, 'Füleki utca', 'F01478', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (735, -129, 47.505738, 19.021762, 'Maros utca', 'F02335', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (736, -129, 47.495169, 19.015235, 'Agárdi út', 'F02422', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (737, -129, 47.520706, 19.068611, 'Lehel utca / Dózsa György út', 'F02625', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (738, -129, 47.524185, 19.075211, 'Hun utca', 'F02667', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (739, -129, 47.523907, 19.074521, 'Hun utca', 'F02669', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (740, -129, 47.506194, 19.12332, 'Róna utca', 'F02746', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (741, -129, 47.509096, 19.128501, 'Pongrátz Gergely tér', 'F02750', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (742, -129, 47.515051, 19.139038, 'Fischer István utca', 'F02784', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (743, -129, 47.528466, 19.123409, 'Cinkotai út', 'F02849', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (744, -129, 47.507946, 19.126455, 'Kaffka Margit utca', 'F02930', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (745, -129, 47.453471, 19.19647, 'Gyömrői út', 'F03694', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (746, -129, 47.42049, 19.119448, 'Előd utca', 'F04078', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (747, -128, 47.497476, 19.049623, 'Dorottya utca (Vörösmarty tér M)', '7885', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (748, -128, 47.412824, 18.929984, 'Tátika utca', '9102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (749, -128, 47.405402, 18.94778, 'Gárdonyi Géza utca', '9301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (750, -128, 47.378957, 19.129179, 'BILK bekötőút', '9311', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (751, -128, 47.518254, 19.007497, 'Orló utca', '9508', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (752, -128, 47.488903, 19.061022, 'Kálvin tér', '56227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (753, -128, 47.500116, 19.080722, 'Keleti pályaudvar', '56233', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (754, -128, 47.491018, 19.114222, 'Kőbányai garázs', '74021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (755, -128, 47.352092, 19.056415, 'Sport utca', '101183', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (756, -128, 47.522366, 18.990764, 'Kelemen László utca', 'F00415', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (757, -128, 47.516046, 19.074517, 'Honvédkórház (Hősök tere M)', 'F01056', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (758, -128, 47.514904, 19.072299, 'Rippl-Rónai utca', 'F01074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (759, -128, 47.513169, 19.065037, 'Szinyei Merse utca', 'F01106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (760, -128, 47.467837, 19.163416, 'Mélytó utca', 'F01695', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (761, -128, 47.448017, 19.046334, 'Építész utca', 'F01974', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (762, -128, 47.449438, 19.049052, 'Kondorosi út', 'F01976', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (763, -128, 47.476672, 19.058963, 'Petőfi híd budai hídfő', 'F02224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (764, -128, 47.521014, 19.069289, 'Lehel utca / Dózsa György út', 'F02621', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (765, -128, 47.414204, 19.201926, 'Alacskai úti lakótelep', 'F03859', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (766, -128, 47.460064, 18.934007, 'Alcsiki dűlő', 'F04829', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (767, -127, 47.424625, 19.157703, 'Szentlőrinci út', '8267', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (768, -127, 47.486916, 19.05724, 'Fővám tér M', 'F00921', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (769, -127, 47.485548, 19.06593, 'Ráday utca', 'F01339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (770, -127, 47.480125, 19.066075, 'Boráros tér H', 'F01373', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (771, -127, 47.47239, 19.095534, 'Albert Flórián út', 'F01498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (772, -127, 47.51136, 18.992669, 'Zirzen Janka utca', 'F02359', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (773, -127, 47.508425, 18.990447, 'Felső Svábhegyi út', 'F02370', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (774, -127, 47.504785, 19.100806, 'Egressy út / Stefánia út', 'F02734', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (775, -127, 47.516394, 19.107097, 'Tisza István tér', 'F02823', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (776, -127, 47.510203, 19.112214, 'Posta Járműtelep', 'F02957', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (777, -127, 47.53268, 19.176306, 'Mátyás király utca', 'F03224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (778, -127, 47.41148, 19.065522, 'Iskola tér', 'F04334', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (779, -126, 47.402248, 18.974427, 'Barackos út / Angeli utca', '8111', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (780, -126, 47.402114, 19.015257, 'Növény utca', '8337', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (781, -126, 47.514609, 19.152837, 'Körvasút sor', '9501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (782, -126, 47.465434, 19.162927, 'Bányató utca', '42797', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (783, -126, 47.502152, 19.062963, 'Akácfa utca', 'F01033', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (784, -126, 47.500277, 19.080999, 'Keleti pályaudvar M', 'F01314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (785, -126, 47.512023, 19.216334, 'Farkashalom utca', 'F03385', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (786, -126, 47.486869, 19.286224, 'Csaba vezér tér', 'F03483', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (787, -126, 47.429485, 19.167661, 'Ipacsfa utca', 'F03622', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (788, -126, 47.430145, 19.169047, 'Ipacsfa utca', 'F03623', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (789, -126, 47.4326, 19.127352, 'Pesterzsébet Ady Endre tér', 'F04045', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (790, -126, 47.393762, 19.039899, 'Almafa utca', 'F04290', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (791, -126, 47.395526, 19.043542, 'Szilvafa utca', 'F04351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (792, -126, 47.412284, 19.006492, 'Park utca', 'F04444', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (793, -125, 47.436368, 18.927095, 'Tükörhegy', '8324', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (794, -125, 47.47678, 19.058883, 'Petőfi híd budai hídfő', '9179', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (795, -125, 47.404076, 18.996178, 'Botond utca', '9446', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (796, -125, 47.412771, 18.989465, 'Török utca', '62515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (797, -125, 47.602812, 19.066434, 'Békásmegyer Újmegyeri tér', 'F00644', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (798, -125, 47.505936, 19.079239, 'Nefelejcs utca', 'F01128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (799, -125, 47.507922, 19.083646, 'Dembinszky utca', 'F01139', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (800, -125, 47.503703, 19.085939, 'Cserhát utca', 'F01143', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (801, -125, 47.500853, 19.082181, 'Keleti pályaudvar M', 'F01160', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (802, -125, 47.468083, 19.168683, 'Újhegyi út Sportliget', 'F01588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (803, -125, 47.473875, 19.052847, 'Budafoki út / Szerémi sor', 'F01990', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (804, -125, 47.472723, 19.033028, 'Karolina út', 'F02045', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (805, -125, 47.519039, 19.08117, 'Széchenyi fürdő M', 'F02695', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (806, -125, 47.5156, 19.090554, 'Ciklámen utca', 'F02703', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (807, -125, 47.50793, 19.107672, 'Törökőr utca', 'F02917', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (808, -125, 47.530832, 19.172291, 'György utca', 'F03221', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (809, -125, 47.526309, 19.162454, 'József utca', 'F03260', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (810, -125, 47.518431, 19.161229, 'Batthyány utca', 'F03266', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (811, -125, 47.516098, 19.156139, 'Rózsa utca', 'F03283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (812, -125, 47.520116, 19.219146, 'Vidámvásár utca', 'F03306', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (813, -125, 47.510508, 19.21305, 'Papír utca', 'F03382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (814, -125, 47.489453, 19.289697, 'Czeglédi Mihály utca', 'F03439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (815, -125, 47.428152, 19.164921, 'Besence utca', 'F03625', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (816, -125, 47.391522, 19.035701, 'Vízművek lakótelep', 'F04353', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (817, -125, 47.399546, 19.003827, 'Dózsa György út', 'F04467', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (818, -124, 47.526052, 19.177918, 'Jávorfa utca', '9382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (819, -124, 47.523533, 19.172315, 'János utca', '9383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (820, -124, 47.416159, 19.020426, 'Húr utca', '9452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (821, -124, 47.579129, 19.034311, 'Mészkő utca', '9564', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (822, -124, 47.602606, 19.065995, 'Békásmegyer Újmegyeri tér', 'F00642', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (823, -124, 47.596795, 19.116028, 'Homoktövis iskola', 'F00856', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (824, -124, 47.50768, 19.078176, 'Nefelejcs utca / Damjanich utca', 'F01126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (825, -124, 47.509433, 19.082014, 'Damjanich utca / Dózsa György út', 'F01135', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (826, -124, 47.470264, 19.17333, 'Maglódi út', 'F01591', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (827, -124, 47.564491, 19.127455, 'Juhos utca', 'F03036', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (828, -124, 47.527539, 19.181273, 'Mátyás király utca', 'F03227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (829, -124, 47.523652, 19.156593, 'Batthyány utca', 'F03264', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (830, -124, 47.513233, 19.219099, 'Anilin utca', 'F03326', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (831, -124, 47.530095, 19.187017, 'Szilas-patak', 'F03351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (832, -124, 47.39346, 18.989619, 'Petőfi Sándor utca (Kastélymúzeum)', 'F04495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (833, -123, 47.414086, 18.932618, 'Valéria utca', '9104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (834, -123, 47.491097, 19.281623, 'Rákoscsaba vasútállomás', '9483', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (835, -123, 47.405382, 18.999105, 'Szent László utca', '62815', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (836, -123, 47.527406, 18.979431, 'Tárogató út', 'F00322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (837, -123, 47.479344, 19.089731, 'Nagyvárad tér M', 'F01245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (838, -123, 47.478868, 19.090326, 'Nagyvárad tér M', 'F01248', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (839, -123, 47.483912, 19.062454, 'Közraktár utca', 'F01340', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (840, -123, 47.468566, 19.023744, 'Szent Gellért-templom', 'F02038', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (841, -123, 47.472696, 19.033201, 'Karolina út', 'F02047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (842, -123, 47.490409, 18.960981, 'Magas út', 'F02273', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (843, -123, 47.520917, 19.166638, 'József utca', 'F03254', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (844, -123, 47.528965, 19.168197, 'János utca', 'F03257', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (845, -123, 47.52192, 19.152858, 'Pálya utca', 'F03277', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (846, -123, 47.506823, 19.204942, 'Diósy Lajos utca', 'F03314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (847, -123, 47.508769, 19.209142, 'Bökényföldi út', 'F03319', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (848, -123, 47.419583, 19.18284, 'Kettős-Körös utca', 'F03644', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (849, -123, 47.414386, 19.038518, 'Vágóhíd utca', 'F04436', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (850, -122, 47.589986, 19.100345, 'Óceán-árok utca', '8283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (851, -122, 47.581449, 18.92642, 'Solymár Kerekhegy', '44372', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (852, -122, 47.590798, 19.049851, 'Szent István utca', 'F00482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (853, -122, 47.503733, 19.074444, 'Rózsa utca', 'F01120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (854, -122, 47.469151, 19.024898, 'Szent Gellért-templom', 'F02037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (855, -122, 47.470742, 19.028569, 'Csóka utca', 'F02041', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (856, -122, 47.51937, 19.217473, 'Cinkota H', 'F03379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (857, -122, 47.395225, 18.993723, 'Bartók Béla út', 'F04480', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (858, -122, 47.397404, 18.998781, 'Tenkes utca', 'F04509', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (859, -121, 47.441443, 18.938007, 'Raktárváros', '8318', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (860, -121, 47.498194, 19.237086, 'Cinkotai autóbuszgarázs', '8569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (861, -121, 47.522736, 18.941568, 'Országos Korányi Intézet', 'F00183', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (862, -121, 47.518807, 19.072898, 'Vágány utca / Dózsa György út', 'F01057', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (863, -121, 47.464794, 19.011477, 'Nagyszeben út', 'F01928', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (864, -121, 47.470698, 19.028585, 'Csóka utca', 'F02042', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (865, -121, 47.510323, 19.084059, 'Dvořák sétány', 'F02707', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (866, -121, 47.50704, 19.10568, 'Egressy út / Hungária körút', 'F02740', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (867, -120, 47.426707, 19.161776, 'Dél-pesti autóbuszgarázs', '8093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (868, -120, 47.49439, 19.272268, 'Diadal utca (Tura utca)', '9705', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (869, -120, 47.489632, 19.035717, 'Sánc utca', 'F00008', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (870, -120, 47.515223, 19.185301, 'Futórózsa utca', 'F03245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (871, -120, 47.496224, 19.231997, 'Injekcióüzem', 'F03291', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (872, -120, 47.491445, 19.264758, 'Ároktő út', 'F03464', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (873, -120, 47.497472, 19.264359, 'Bártfai utca', 'F03577', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (874, -120, 47.407402, 18.951592, 'Diósd Sashegyi út', 'F04770', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (875, -119, 47.476984, 19.075559, 'Ferencvárosi rendelőintézet', '8501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (876, -119, 47.610958, 19.103571, 'Székesdűlő', '8643', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (877, -119, 47.38651, 19.02263, 'Áruházi bekötőút', '8646', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (878, -119, 47.592582, 19.10665, 'Íves út', '8842', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (879, -119, 47.510778, 19.031462, 'Mechwart liget', 'F00198', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (880, -119, 47.562408, 18.945732, 'Máriaremetei kegytemplom', 'F00381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (881, -119, 47.496671, 19.050193, 'Vörösmarty tér', 'F00964', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (882, -119, 47.492907, 19.054534, 'Ferenciek tere M', 'F01004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (883, -119, 47.46275, 19.005922, 'Gazdagréti út', 'F01931', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (884, -119, 47.532188, 19.189147, 'Szlovák út', 'F03216', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (885, -119, 47.535424, 19.198027, 'Mazsola utca', 'F03374', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (886, -119, 47.493235, 19.269317, 'Bártfai utca', 'F03463', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (887, -119, 47.435006, 19.083173, 'Papírgyár', 'F04307', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (888, -119, 47.410129, 19.020207, 'Jókai Mór utca', 'F04453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (889, -119, 47.385565, 19.020115, 'Háros', 'F04639', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (890, -118, 47.499926, 19.083216, 'Keleti pályaudvar M', '9162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (891, -118, 47.473677, 19.052807, 'Budafoki út / Szerémi sor', 'F01989', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (892, -118, 47.47499, 19.039382, 'Kosztolányi Dezső tér', 'F02181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (893, -118, 47.477554, 19.04636, 'Móricz Zsigmond körtér M', 'F02193', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (894, -118, 47.552281, 19.083157, 'Szekszárdi utca', 'F02533', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (895, -118, 47.536428, 19.200793, 'Árpádföld Dezsőfia utca', 'F03292', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (896, -118, 47.517175, 19.19066, 'Margit utca', 'F03340', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (897, -118, 47.38858, 19.028179, 'Hárosi Csárda', 'F04265', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (898, -118, 47.413499, 19.009817, 'Aszály utca', 'F04445', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (899, -117, 47.42563, 18.911984, 'Dióskert', '8447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (900, -117, 47.4892, 19.276864, 'Tura utca', '9484', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (901, -117, 47.348252, 19.049714, 'Sport Vendéglő', '101185', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (902, -117, 47.559146, 19.079567, 'Újpest-városkapu', 'F00898', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (903, -117, 47.485907, 19.130668, 'Szent László tér', 'F01652', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (904, -117, 47.444777, 18.986905, 'Repülőtér', 'F01889', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (905, -117, 47.477374, 19.046161, 'Móricz Zsigmond körtér M', 'F02202', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (906, -117, 47.563941, 19.115667, 'Deák utca', 'F03049', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (907, -117, 47.541151, 19.151023, 'Erdőkerülő utca', 'F03148', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (908, -117, 47.53399, 19.194119, 'Rozsos utca', 'F03375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (909, -117, 47.392468, 18.984123, 'Szabadság utca', 'F04496', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (910, -116, 47.487887, 19.294135, 'Jókai Mór Általános Iskola', '66682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (911, -116, 47.510534, 19.030548, 'Mechwart liget', 'F00197', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (912, -116, 47.470693, 19.090147, 'Ferencváros vá. - Málenkij Robot Eh.', 'F01504', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (913, -116, 47.48731, 19.02355, 'BAH-csomópont', 'F02164', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (914, -116, 47.540011, 19.14778, 'Erdőkerülő utca 28.', 'F03201', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (915, -116, 47.4856, 19.282567, 'Óvónő utca', 'F03492', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (916, -116, 47.43071, 19.115486, 'Székelyhíd utca', 'F04061', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (917, -116, 47.401326, 19.140778, 'Telefondűlő út', 'F04559', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (918, -115, 47.499864, 19.104836, 'Puskás Ferenc Stadion M', '8725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (919, -115, 47.487039, 19.291504, 'Pallós utca', '66680', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (920, -115, 47.511604, 19.193566, 'Mátyásföld repülőtér H', 'F03308', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (921, -115, 47.514479, 19.202973, 'Bökényföldi út', 'F03322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (922, -115, 47.516207, 19.187936, 'Centenáriumi lakótelep', 'F03341', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (923, -115, 47.482998, 19.274225, 'Szabadság sugárút', 'F03476', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (924, -114, 47.484011, 19.127574, 'Kőbánya alsó vasútállomás', '8381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (925, -114, 47.487235, 19.270088, 'Robogó utca', '9544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (926, -114, 47.493445, 19.032478, 'Naphegy tér', 'F00100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (927, -114, 47.561402, 19.103785, 'Árpád Kórház', 'F00885', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (928, -114, 47.516793, 18.979763, 'Lóvasút', 'F02384', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (929, -114, 47.515626, 19.206694, 'Csinszka utca', 'F03394', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (930, -114, 47.513745, 19.200581, 'Mátyásföld Imre utca H', 'F03397', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (931, -114, 47.389793, 18.978206, 'Akó utca', 'F04502', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (932, -114, 47.383036, 19.013296, 'Gát utca', 'F04641', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (933, -113, 47.486549, 19.271041, 'Rákoscsaba-Újtelep vasútállomás', '66711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (934, -113, 47.474462, 19.284123, 'Nyeremény utca', '66722', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (935, -113, 47.497519, 19.071508, 'Blaha Lujza tér M', 'F01166', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (936, -113, 47.48889, 19.085146, 'Kálvária tér', 'F01223', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (937, -113, 47.478151, 18.990865, 'Oltvány köz', 'F02078', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (938, -113, 47.512499, 19.08556, 'Olof Palme sétány', 'F02706', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (939, -113, 47.484553, 19.279181, 'Lemberg utca', 'F03481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (940, -113, 47.480628, 19.280849, 'Lemberg utca', 'F03486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (941, -113, 47.481629, 19.284317, 'Óvónő utca', 'F03566', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (942, -112, 47.519325, 19.008996, 'Bimbó út', '9513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (943, -112, 47.507193, 18.922432, 'Dózsa György tér', '44380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (944, -112, 47.49564, 19.064965, 'Uránia', 'F01171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (945, -112, 47.496643, 19.068847, 'Blaha Lujza tér', 'F01291', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (946, -112, 47.491422, 19.12805, 'III. kapu', 'F01724', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (947, -112, 47.388296, 18.972672, 'Nagytétény Erdélyi utca', 'F04504', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (948, -112, 47.491617, 19.31569, 'Pécel Pesti út 110.', 'F04578', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (949, -111, 47.500543, 19.04669, 'Széchenyi István tér', '8238', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (950, -111, 47.464881, 19.032345, 'Bikás park', '56217', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (951, -111, 47.522453, 19.031803, 'Sarolta utca', 'F00225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (952, -111, 47.529027, 19.002548, 'Verecke lépcső', 'F00266', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (953, -111, 47.482969, 19.289322, 'Alsódabas utca', 'F03489', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (954, -111, 47.39025, 18.963093, 'Bányalég utca', 'F04505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (955, -110, 47.469596, 19.083728, 'Mester utca / Könyves Kálmán körút', '8152', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (956, -110, 47.528476, 18.996294, 'Nagybányai út', '8260', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (957, -110, 47.488137, 19.27329, 'Rákoscsaba-Újtelep vasútállomás', '9485', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (958, -110, 47.499199, 19.07772, 'Huszár utca', 'F01161', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (959, -110, 47.503178, 19.002916, 'Nógrádi utca', 'F02393', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (960, -110, 47.522187, 19.081531, 'Vágány utca / Róbert Károly körút', 'F02627', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (961, -109, 47.433123, 19.176437, 'Margó Tivadar utca', '9551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (962, -109, 47.55983, 19.026718, 'Kisbojtár utca', 'F00681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (963, -109, 47.562273, 19.10667, 'Víztorony', 'F00837', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (964, -109, 47.494606, 19.060718, 'Astoria M', 'F01017', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (965, -109, 47.473299, 19.177383, 'Új köztemető', 'F01594', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (966, -109, 47.509544, 18.997521, 'Tarpatak utca', 'F02427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (967, -109, 47.480964, 19.265353, 'Szárny utca', 'F03474', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (968, -108, 47.434256, 19.093472, 'Zamárdi utca', '9717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (969, -108, 47.467689, 19.109816, 'Aranyvirág sétány', 'F01368', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (970, -108, 47.436335, 19.090226, 'Sósfürdő', 'F04148', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (971, -107, 47.496844, 19.069066, 'Blaha Lujza tér M', '8782', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (972, -107, 47.490182, 19.046863, 'Döbrentei tér', 'F00113', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (973, -107, 47.523213, 19.017398, 'Pusztaszeri körönd', 'F00213', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (974, -107, 47.494594, 19.060933, 'Astoria', 'F01018', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (975, -107, 47.501051, 19.118839, 'Pillangó utca', 'F01742', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (976, -107, 47.474475, 19.024776, 'Ajnácskő utca', 'F02052', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (977, -107, 47.475655, 19.030401, 'Hollókő utca', 'F02262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (978, -107, 47.449705, 18.969607, 'Kinizsi utca', 'F04731', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (979, -106, 47.425255, 19.157029, 'Nagykőrösi út', '8095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (980, -106, 47.400994, 19.002739, 'I. utca', '8168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (981, -106, 47.462796, 19.14879, 'Kőbánya-Kispest M', '38681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (982, -106, 47.467563, 19.109219, 'Aranyvirág sétány', 'F01369', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (983, -106, 47.469921, 19.084469, 'Mester utca / Könyves Kálmán körút', 'F01508', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (984, -106, 47.500696, 19.11219, 'Őrnagy utca', 'F01735', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (985, -106, 47.506832, 19.167151, 'Nagyicce H', 'F01778', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (986, -106, 47.459918, 18.921895, 'Csiki csárda', 'F04801', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (987, -105, 47.447968, 19.000851, 'Tippan utca', '9353', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (988, -105, 47.473243, 19.02959, 'Kelenföldi autóbuszgarázs', '9427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (989, -105, 47.418055, 19.027015, 'Promontor utca', '9472', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (990, -105, 47.464016, 19.317704, 'Zsigmond utca', '9729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (991, -105, 47.595001, 18.940212, 'Solymár temető', '44603', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (992, -105, 47.496899, 19.032742, 'Krisztina tér', 'F00087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (993, -105, 47.555615, 19.090294, 'Tél utca / Pozsonyi utca', 'F00816', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (994, -105, 47.489473, 19.124517, 'Őrház', 'F01561', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (995, -105, 47.491828, 19.138287, 'Élessarok', 'F01625', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (996, -105, 47.496198, 18.983838, 'Művész út', 'F02314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (997, -105, 47.510085, 19.185597, 'Fuvallat utca', 'F03236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (998, -105, 47.510773, 19.189515, 'Jókai Mór utca (Rendőrség)', 'F03392', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (999, -105, 47.439666, 19.164151, 'Vörösmarty Mihály utca', 'F03759', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1000, -105, 47.41238, 19.032622, 'Háros vasútállomás', 'F04433', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1001, -104, 47.567706, 18.93626, 'Budaliget Géza fejedelem útja', 'F00387', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1002, -104, 47.45424, 19.096221, 'Külső Mester utca', 'F01425', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1003, -104, 47.492023, 19.130466, 'IV. kapu', 'F01556', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1004, -104, 47.490942, 19.133158, 'Kőbánya felső vasútállomás', 'F01727', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1005, -104, 47.490163, 19.128618, 'Halom utca', 'F01729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1006, -104, 47.510875, 18.97865, 'Hunyad lejtő', 'F02486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1007, -104, 47.509165, 19.180364, 'Sashalom H', 'F03243', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1008, -104, 47.507869, 19.17306, 'Thököly út', 'F03275', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1009, -104, 47.432034, 19.184812, 'Gilice tér', 'F03637', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1010, -104, 47.434488, 19.20058, 'Honvéd utca', 'F03801', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1011, -104, 47.399921, 19.134143, 'Szamaránszki dűlő', 'F04557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1012, -103, 47.400093, 18.997693, 'Mátra utca', '8119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1013, -103, 47.456472, 19.111009, 'Gyáli út', '8587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1014, -103, 47.427563, 19.039417, 'Budafoki Szomszédok Piaca', '83777', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1015, -103, 47.517504, 19.021143, 'Tapolcsányi utca', 'F00207', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1016, -103, 47.492543, 19.133824, 'Kőbánya felső vasútállomás', 'F01555', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1017, -103, 47.510424, 18.975876, 'Béla király út', 'F02378', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1018, -103, 47.511435, 18.981716, 'Hunyad köz', 'F02484', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1019, -103, 47.456723, 18.946595, 'Ifjúság utca', 'F04726', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1020, -102, 47.502111, 19.135697, 'Örs vezér tere M+H', '8029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1021, -102, 47.486369, 19.265893, 'II. utca', '9486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1022, -102, 47.488606, 19.031564, 'Mészáros utca', 'F00005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1023, -102, 47.527839, 19.036876, 'Kolosy tér', 'F00133', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1024, -102, 47.543506, 19.031466, 'Váradi utca', 'F00592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1025, -102, 47.49772, 19.05367, 'Deák Ferenc tér', 'F00962', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1026, -102, 47.476021, 19.076355, 'Haller utca / Mester utca', 'F01390', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1027, -102, 47.476946, 19.08259, 'Balázs Béla utca', 'F01511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1028, -102, 47.502506, 19.136269, 'Örs vezér tere M+H', 'F01752', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1029, -102, 47.491456, 19.243393, 'Cinkotai út', 'F03451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1030, -102, 47.492692, 19.251572, 'Tarack utca', 'F03471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1031, -102, 47.432686, 19.189069, 'Sas utca', 'F03858', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1032, -102, 47.458758, 18.9141, 'Csiki tanya', 'F04803', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1033, -101, 47.492725, 19.071361, 'Rákóczi tér', '56229', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1034, -101, 47.515149, 19.037569, 'Margit híd budai hídfő H', '69089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1035, -101, 47.523591, 19.020359, 'Vend utca', 'F00217', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1036, -101, 47.52861, 19.021645, 'Zöldmáli lejtő', 'F00257', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1037, -101, 47.489191, 19.066807, 'Szentkirályi utca', 'F01175', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1038, -101, 47.470172, 19.088529, 'Ferencváros vá. - Málenkij Robot Eh.', 'F01503', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1039, -101, 47.505527, 19.198553, 'Pilóta utca', 'F03313', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1040, -101, 47.433488, 19.194506, 'Dalmady Győző utca', 'F03639', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1041, -101, 47.388915, 18.958155, 'Nagytétény ipartelep', 'F04508', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1042, -101, 47.46176, 18.969304, 'Aradi utca', 'F04735', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1043, -100, 47.497895, 19.019895, 'Kék Golyó utca', '11495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1044, -100, 47.504751, 19.030324, 'Bécsi kapu tér', 'F00070', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1045, -100, 47.498978, 19.050884, 'Hild tér', 'F00981', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1046, -100, 47.490192, 19.109669, 'Hidegkuti Nándor Stadion', 'F01274', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1047, -100, 47.503968, 19.142178, 'Sarkantyú utca', 'F01772', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1048, -100, 47.50428, 19.188582, 'Ballada utca', 'F03238', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1049, -100, 47.460256, 19.306991, 'Erzsébet körút', 'F03570', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1050, -100, 47.41147, 19.025944, 'Háros utca', 'F04432', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1051, -100, 47.456043, 18.940404, 'Budaörsi lakótelep', 'F04724', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1052, -99, 47.430369, 19.036792, 'Savoyai Jenő tér (Törley tér)', '7931', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1053, -99, 47.495234, 19.025205, 'Győri út', 'F00095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1054, -99, 47.507063, 19.05427, 'Báthory utca / Bajcsy-Zsilinszky út', 'F00938', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1055, -99, 47.489514, 19.071306, 'Harminckettesek tere', 'F01204', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1056, -99, 47.500213, 19.104767, 'Puskás Ferenc Stadion', 'F01324', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1057, -99, 47.490712, 19.114248, 'Kőbányai garázs', 'F01798', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1058, -99, 47.502309, 19.147517, 'Gépmadár utca', 'F01850', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1059, -99, 47.502234, 19.160697, 'Pilisi utca', 'F01852', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1060, -99, 47.506473, 19.147186, 'Ond vezér park', 'F02769', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1061, -99, 47.504984, 19.194371, 'Jókai Mór utca', 'F03389', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1062, -99, 47.39891, 19.126538, 'Vecsés út sorompó', 'F04555', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1063, -99, 47.460653, 18.956866, 'Károly király utca', 'F04721', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1064, -99, 47.462356, 18.973945, 'Tulipán utca', 'F04791', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1065, -99, 47.463161, 18.980881, 'Felsőhatár utca', 'F04795', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1066, -98, 47.490648, 19.114424, 'Kőbányai garázs', '8916', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1067, -98, 47.491881, 19.051747, 'Március 15. tér', '62518', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1068, -98, 47.506003, 19.049645, 'Kossuth Lajos tér M', '99343', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1069, -98, 47.505732, 19.046836, 'Kossuth Lajos tér M', 'F00971', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1070, -98, 47.501657, 19.141556, 'Gyakorló köz', 'F01769', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1071, -98, 47.504414, 19.146214, 'Gépmadár park', 'F01854', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1072, -98, 47.503693, 19.183629, 'Galgahévíz utca', 'F03240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1073, -98, 47.416615, 19.131038, 'Szentlőrinci úti lakótelep', 'F04577', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1074, -97, 47.430333, 19.036354, 'Savoyai Jenő tér (Törley tér)', '7930', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1075, -97, 47.500105, 19.107013, 'Puskás Ferenc Stadion M', '8545', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1076, -97, 47.48486, 19.012485, 'Korompai utca', '8559', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1077, -97, 47.499212, 19.053658, 'Deák Ferenc tér M', '11732', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1078, -97, 47.478057, 19.04571, 'Móricz Zsigmond körtér M', 'F02196', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1079, -97, 47.459624, 19.29976, 'Schell Gyuláné tér', 'F03506', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1080, -96, 47.436343, 19.092559, 'Csepeli átjáró', '8893', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1081, -96, 47.345047, 19.028076, 'Tamási Áron utca', '64103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1082, -96, 47.495566, 19.023015, 'Királyhágó utca', 'F00016', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1083, -96, 47.528558, 18.999495, 'Vöröstorony utca', 'F00423', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1084, -96, 47.505975, 19.049332, 'Kossuth Lajos tér M', 'F00970', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1085, -96, 47.45537, 19.10325, 'Táblás utca', 'F01474', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1086, -96, 47.488923, 19.218437, 'Határmalom utca', 'F01717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1087, -96, 47.504985, 19.151458, 'Rákosfalva H', 'F01790', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1088, -96, 47.484644, 19.009871, 'Hegytető utca', 'F02105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1089, -96, 47.485392, 19.016943, 'Sion lépcső', 'F02107', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1090, -96, 47.490669, 19.305294, 'Színes utca', 'F03445', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1091, -96, 47.576253, 18.889358, 'Diófa utca (Posta)', 'F04825', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1092, -95, 47.433454, 19.095738, 'Pesterzsébet vasútállomás', '9716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1093, -95, 47.499809, 19.083839, 'Keleti pályaudvar M', '61351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1094, -95, 47.505716, 19.159025, 'Egyenes utcai lakótelep', 'F01775', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1095, -95, 47.46205, 19.016317, 'Kérő utca', 'F01923', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1096, -95, 47.51653, 18.976179, 'Zugliget Libegő', 'F02382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1097, -95, 47.520017, 19.148088, 'Rákosszentmihály vasútállomás', 'F03281', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1098, -95, 47.418112, 19.253447, 'Vecsés-nyugat', 'F04597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1099, -94, 47.405214, 18.982168, 'Szilvafa utca', '8113', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1100, -94, 47.468737, 19.072756, 'Közvágóhíd H', '8155', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1101, -94, 47.44819, 18.964863, 'Budaörs vasútállomás', '9085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1102, -94, 47.499926, 19.083587, 'Keleti pályaudvar M', '9163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1103, -94, 47.463191, 19.146979, 'Kőbánya-Kispest M', '38675', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1104, -94, 47.518072, 18.983878, 'Szarvas Gábor út', 'F02385', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1105, -94, 47.456096, 19.136643, 'Hungária út', 'F03958', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1106, -94, 47.410438, 19.060646, 'Csepel Csillagtelep', 'F04277', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1107, -94, 47.461709, 18.964364, 'Templom tér', 'F04809', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1108, -93, 47.529481, 19.016412, 'Csalit utca', 'F00124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1109, -93, 47.514717, 19.042521, 'Margitsziget / Margit híd', 'F00141', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1110, -93, 47.526579, 19.030224, 'Felhévízi utca', 'F00249', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1111, -93, 47.489445, 19.090494, 'Orczy tér', 'F01187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1112, -93, 47.436871, 19.097438, 'Pesterzsébet Baross utca', 'F04144', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1113, -92, 47.445299, 18.999585, 'Kőérberek', '8788', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1114, -92, 47.509962, 19.009919, 'Szent János Kórház', '9558', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1115, -92, 47.499809, 19.083229, 'Keleti pályaudvar M', '61350', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1116, -92, 47.507773, 19.033443, 'Fazekas utca', 'F00036', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1117, -92, 47.514654, 19.036653, 'Margit híd budai hídfő H', 'F00191', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1118, -92, 47.514618, 19.038193, 'Margit híd budai hídfő H', 'F00192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1119, -92, 47.528791, 19.02442, 'Szemlő-hegyi-barlang', 'F00255', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1120, -92, 47.565181, 19.100533, 'Újpesti rendelőintézet', 'F00790', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1121, -92, 47.56534, 19.106434, 'Szülőotthon', 'F00881', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1122, -92, 47.489383, 19.089551, 'Orczy tér', 'F01186', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1123, -92, 47.491293, 19.119782, 'Pongrácz úti lakótelep', 'F01857', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1124, -92, 47.511814, 18.986952, 'Galgóczy utca', 'F02374', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1125, -92, 47.482212, 19.219238, '501. utca', 'F03551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1126, -92, 47.45591, 19.132505, 'Gutenberg körút', 'F03963', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1127, -92, 47.460391, 18.926483, 'Ibolya utca', 'F04710', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1128, -92, 47.453204, 18.958436, 'Budaörs benzinkút', 'F04738', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1129, -91, 47.399613, 18.990911, 'Barosstelep vasútállomás', '8110', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1130, -91, 47.499926, 19.084198, 'Keleti pályaudvar M', '9321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1131, -91, 47.388088, 18.970461, 'Nagytétény Erdélyi utca', '9585', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1132, -91, 47.428786, 19.035467, 'Savoyai Jenő tér (Plébánia utca)', '83331', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1133, -91, 47.456524, 19.071027, 'Wein János út', '102283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1134, -91, 47.580363, 19.099139, 'Ugró Gyula sor', 'F00747', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1135, -91, 47.565003, 19.094022, 'Kiss Ernő utca', 'F00786', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1136, -91, 47.499688, 19.095784, 'Gumigyár', 'F01182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1137, -91, 47.468917, 19.073738, 'Közvágóhíd H', 'F01527', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1138, -91, 47.506713, 19.002501, 'Orgonás', 'F02326', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1139, -91, 47.562673, 19.114669, 'Hubay Jenő tér', 'F03053', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1140, -91, 47.490139, 19.331705, 'Orvosi rendelő', 'F04580', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1141, -90, 47.464407, 19.312714, 'Szigetcsép utca', '9625', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1142, -90, 47.509837, 19.009411, 'Szent János Kórház', 'F00411', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1143, -90, 47.540207, 19.045998, 'Szentlélek tér H', 'F00621', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1144, -90, 47.501875, 19.090278, 'Verseny utca', 'F01151', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1145, -90, 47.490096, 19.105038, 'Asztalos Sándor út', 'F01278', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1146, -90, 47.48726, 19.088979, 'Kőris utca', 'F01332', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1147, -90, 47.486714, 19.203211, 'Aluljáró (Rézvirág utca)', 'F01864', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1148, -90, 47.44543, 18.991497, 'Fülőke utca', 'F01890', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1149, -90, 47.456464, 19.125265, 'Kós Károly tér', 'F03923', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1150, -90, 47.441958, 19.083788, 'Szállító utca 6.', 'F04299', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1151, -90, 47.441942, 19.076642, 'Közterületfenntartó Zrt.', 'F04300', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1152, -90, 47.441942, 19.076854, 'Közterületfenntartó Zrt.', 'F04301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1153, -90, 47.441959, 19.080341, 'Vámhivatal', 'F04355', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1154, -90, 47.441959, 19.080553, 'Vámhivatal', 'F04356', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1155, -90, 47.441958, 19.083562, 'Szállító utca 6.', 'F04357', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1156, -89, 47.598633, 19.054086, 'Békásmegyer H', '8940', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1157, -89, 47.469059, 19.295984, 'Kucorgó tér', '9482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1158, -89, 47.46346, 19.147245, 'Kőbánya-Kispest M', '38686', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1159, -89, 47.578737, 19.05013, 'Czetz János köz', 'F00491', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1160, -89, 47.604009, 19.06335, 'Hadrianus utca', 'F00638', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1161, -89, 47.499778, 19.081282, 'Keleti pályaudvar', 'F01335', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1162, -89, 47.486386, 19.199309, 'Legényrózsa utca', 'F01715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1163, -89, 47.499645, 18.972592, 'Fülemile út', 'F02447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1164, -89, 47.419741, 19.08694, 'Kikötő utca', 'F04216', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1165, -89, 47.592825, 18.931495, 'Dózsa György utca', 'F04704', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1166, -88, 47.447079, 18.98673, 'Budaörsi repülőtér', '9349', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1167, -88, 47.469872, 19.062941, 'Infopark (Pázmány Péter sétány)', '62607', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1168, -88, 47.377069, 19.031955, 'Leshegy utca', '64240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1169, -88, 47.604018, 19.060836, 'Madzsar József utca / Hadrianus utca', 'F00637', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1170, -88, 47.555171, 19.09966, 'Újpesti Erőmű', 'F00828', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1171, -88, 47.485734, 19.215879, '501. utca', 'F01719', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1172, -88, 47.486265, 19.202373, 'Rézvirág utca', 'F01846', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1173, -88, 47.435406, 19.035717, 'Budafoki elágazás', 'F01902', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1174, -88, 47.48377, 19.006223, 'Farkasréti temető', 'F02102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1175, -88, 47.517508, 19.08098, 'Széchenyi fürdő', 'F02696', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1176, -88, 47.506343, 19.141213, 'Füredi utca / Ond vezér útja', 'F02767', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1177, -88, 47.426335, 19.082067, 'Komáromi utca', 'F04188', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1178, -88, 47.595103, 18.935311, 'Pilisvörösvári utca', 'F04800', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1179, -87, 47.596321, 18.973591, 'Solymár Szarvas', '9065', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1180, -87, 47.494732, 19.03772, 'Dózsa György tér', 'F00077', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1181, -87, 47.560437, 19.08976, 'Újpest-központ', 'F00913', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1182, -87, 47.463163, 18.985059, 'Rupphegyi út', 'F02217', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1183, -87, 47.511417, 19.000608, 'Kútvölgyi lejtő Traumatológia', 'F02357', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1184, -87, 47.49954, 18.976428, 'Őzike köz', 'F02445', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1185, -87, 47.519592, 19.090573, 'Mexikói út', 'F02887', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1186, -87, 47.432906, 19.128426, 'Ady Endre tér', 'F04048', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1187, -87, 47.432732, 19.132813, 'Pesterzsébet Mátyás király tér', 'F04154', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1188, -87, 47.422938, 19.072669, 'Görgey Artúr tér', 'F04194', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1189, -87, 47.422829, 19.0752, 'Bajcsy-Zsilinszky út', 'F04197', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1190, -87, 47.426946, 19.066389, 'Karácsony Sándor utca H', 'F04268', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1191, -87, 47.4298, 19.021443, 'Regényes utca', 'F04421', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1192, -86, 47.464515, 19.019618, 'Kelenföld vasútállomás', '56215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1193, -86, 47.507979, 19.029261, 'Széna tér', 'F00032', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1194, -86, 47.499708, 19.089851, 'Arena Mall bevásárlóközpont', 'F01250', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1195, -86, 47.499819, 19.103708, 'Puskás Ferenc Stadion M', 'F01316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1196, -86, 47.487051, 19.192716, 'Túzok utca', 'F01609', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1197, -86, 47.490106, 19.154852, 'Orion', 'F01825', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1198, -86, 47.486012, 19.209442, 'Dombhát utca', 'F01848', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1199, -86, 47.4905, 19.014857, 'Apor Vilmos tér', 'F02300', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1200, -86, 47.422412, 19.087048, 'Kikötő utca', 'F04198', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1201, -86, 47.435008, 19.076399, 'Védgát utca', 'F04316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1202, -86, 47.430359, 19.071241, 'Kossuth Lajos utca', 'F04325', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1203, -86, 47.493467, 19.339122, 'Pécel vasútállomás', 'F04645', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1204, -85, 47.507673, 19.024894, 'Széll Kálmán tér M', '9174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1205, -85, 47.469171, 19.059717, 'Infopark', '66451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1206, -85, 47.507691, 19.024629, 'Széll Kálmán tér M', 'F00412', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1207, -85, 47.595277, 19.054425, 'Madzsar József utca / Pünkösdfürdő utca', 'F00473', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1208, -85, 47.555379, 19.094811, 'Nap utca', 'F00878', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1209, -85, 47.422639, 19.080461, 'Rákóczi tér', 'F04337', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1210, -84, 47.508464, 19.023646, 'Fény utcai piac', '8087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1211, -84, 47.446377, 19.003968, 'Régi vám (Kánai út)', '9355', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1212, -84, 47.527678, 19.036823, 'Kolosy tér', '9693', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1213, -84, 47.451047, 19.037609, 'Nyéki Imre Uszoda', '78064', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1214, -84, 47.507709, 19.024363, 'Széll Kálmán tér M', '81565', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1215, -84, 47.575751, 19.056723, 'Dósa utca', 'F00500', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1216, -84, 47.594809, 19.060568, 'Boglár utca', 'F00653', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1217, -84, 47.594439, 19.065793, 'Pünkösdfürdő', 'F00654', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1218, -84, 47.578773, 19.112377, 'Hajló utca', 'F00739', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1219, -84, 47.493549, 19.082787, 'Teleki László tér', 'F01331', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1220, -83, 47.45645, 19.11466, 'Nagykőrösi út / Határ út', '8377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1221, -83, 47.446339, 18.998545, 'Kőérberek', '9351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1222, -83, 47.490555, 19.018489, 'Sirály utca', '9522', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1223, -83, 47.464205, 19.028906, 'Bártfai utca', '99631', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1224, -83, 47.597174, 19.05876, 'Hímző utca', 'F00464', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1225, -83, 47.585509, 19.051447, 'Bercsényi utca', 'F00682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1226, -83, 47.560616, 19.088238, 'Újpest-központ M', 'F00799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1227, -83, 47.489278, 19.079639, 'Muzsikus cigányok parkja', 'F01176', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1228, -83, 47.483628, 19.196245, 'Bogáncsvirág utca', 'F01553', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1229, -83, 47.482464, 19.128487, 'Kőbánya alsó vasútállomás', 'F01645', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1230, -83, 47.489787, 19.158965, 'Téglavető utca', 'F01823', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1231, -82, 47.467934, 19.244806, 'Rákoshegy vasútállomás', '8577', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1232, -82, 47.49017, 19.023466, 'Csörsz utca', '9520', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1233, -82, 47.550028, 19.041176, 'Óbuda Bogdáni út', '9554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1234, -82, 47.505965, 19.028598, 'Mátray utca', 'F00030', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1235, -82, 47.594115, 19.048442, 'Nád utca', 'F00457', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1236, -82, 47.553289, 19.017407, 'Körtvélyes köz', 'F00556', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1237, -82, 47.489594, 19.076707, 'Horváth Mihály tér', 'F01207', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1238, -82, 47.482879, 19.125715, 'Kőbánya alsó vasútállomás (Mázsa tér)', 'F01571', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1239, -82, 47.486626, 19.187221, 'Kossuth Nyomda', 'F01605', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1240, -82, 47.489412, 19.164691, 'Tárna utca', 'F01819', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1241, -82, 47.443568, 19.012407, 'Kelenvölgy-Péterhegy', 'F01894', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1242, -82, 47.499646, 19.009608, 'Szent Orbán tér', 'F02397', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1243, -82, 47.507358, 19.024496, 'Széll Kálmán tér M', 'F02476', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1244, -82, 47.461391, 18.946308, 'Budaörs városháza', 'F04717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1245, -82, 47.576066, 18.884042, 'Nagykovácsi községháza', 'F04827', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1246, -81, 47.430338, 19.016061, 'Szebeni utca', '8115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1247, -81, 47.486311, 19.259071, 'Rákosliget vasútállomás', '9487', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1248, -81, 47.471587, 19.282412, 'Kisvárda utca', '43192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1249, -81, 47.485447, 19.221662, 'Akadémiaújtelep vasútállomás', '116272', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1250, -81, 47.529543, 19.011127, 'Zöldkert út', 'F00259', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1251, -81, 47.595079, 19.056766, 'Medgyessy Ferenc utca', 'F00467', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1252, -81, 47.56046, 19.031023, 'Csillaghegyi út / Bojtár utca', 'F00670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1253, -81, 47.598483, 19.121269, 'Káposztásmegyer Mogyoródi-patak', 'F00722', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1254, -81, 47.491919, 19.089102, 'Magdolna utca', 'F01184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1255, -81, 47.483195, 19.123384, 'Mázsa utca', 'F01567', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1256, -81, 47.47312, 19.177277, 'Új köztemető', 'F01593', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1257, -81, 47.490455, 19.149107, 'Maglódi út', 'F01615', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1258, -81, 47.47372, 19.171309, 'Sírkert út', 'F01711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1259, -81, 47.473828, 19.171309, 'Sírkert út', 'F01713', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1260, -81, 47.479054, 19.200332, 'Tűzoltóság', 'F01869', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1261, -81, 47.483957, 19.192531, 'Kollégium', 'F01872', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1262, -81, 47.461886, 19.047037, 'Hengermalom út / Szerémi út', 'F02259', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1263, -81, 47.454821, 19.181529, 'Pestszentlőrinc vasútállomás', 'F03776', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1264, -81, 47.591465, 18.929264, 'Templom tér', 'F04707', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1265, -81, 47.460719, 18.93997, 'Gimnázium', 'F04714', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1266, -81, 47.459613, 18.930609, 'Lejtő utca', 'F04837', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1267, -80, 47.430738, 19.012823, 'Zöldike utca', '8124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1268, -80, 47.474163, 19.04705, 'Újbuda-központ M', '9570', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1269, -80, 47.468505, 19.058444, 'BudaPart', '71262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1270, -80, 47.485594, 19.039551, 'Búsuló Juhász (Citadella)', 'F00012', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1271, -80, 47.565219, 19.05797, 'Pók utca', 'F00499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1272, -80, 47.55993, 19.035594, 'Kunigunda útja', 'F00517', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1273, -80, 47.56069, 19.083016, 'Újpest-városkapu M', 'F00810', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1274, -80, 47.488985, 19.095841, 'Kőbányai út 21.', 'F01180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1275, -80, 47.491026, 19.096334, 'Salgótarjáni utca temető', 'F01280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1276, -80, 47.478074, 19.044899, 'Móricz Zsigmond körtér M', 'F02197', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1277, -80, 47.465166, 19.022619, 'Kelenföld vasútállomás M', 'F02210', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1278, -80, 47.490383, 19.011818, 'Zólyomi lépcső', 'F02298', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1279, -80, 47.508269, 19.003509, 'Rőzse köz', 'F02344', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1280, -80, 47.518485, 19.225994, 'Vidámvásár utca (Georgina utca)', 'F03327', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1281, -80, 47.490901, 19.302376, 'Ebergény utca', 'F03433', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1282, -80, 47.49146, 19.29727, 'Pöröly utca', 'F03435', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1283, -80, 47.492156, 19.259758, 'XVII. utca', 'F03466', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1284, -80, 47.491491, 19.323112, 'Határ utca', 'F04585', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1285, -79, 47.514029, 18.923053, 'Budakeszi temető', '47670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1286, -79, 47.457144, 19.029107, 'Pajkos utca', '61232', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1287, -79, 47.462389, 19.042594, 'Etele út / Fehérvári út', '66459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1288, -79, 47.463737, 19.032434, 'Bikás park M', '99629', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1289, -79, 47.508723, 19.018044, 'Nyúl utca', 'F00425', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1290, -79, 47.509002, 19.017008, 'Nyúl utca', 'F00427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1291, -79, 47.5594, 19.039899, 'Bojtár utca 49.', 'F00443', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1292, -79, 47.559931, 19.093818, 'Erzsébet utca', 'F00855', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1293, -79, 47.505808, 19.044242, 'Kossuth Lajos tér', 'F00958', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1294, -79, 47.461274, 19.051692, 'Hengermalom út', 'F01982', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1295, -79, 47.462695, 19.040922, 'Etele út / Fehérvári út', 'F02006', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1296, -79, 47.464286, 19.029131, 'Bártfai utca', 'F02216', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1297, -79, 47.462178, 18.993097, 'Madárhegy', 'F02266', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1298, -79, 47.49539, 19.004237, 'Szendrő utca', 'F02431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1299, -79, 47.467515, 19.247948, 'Szabadság utca', 'F03519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1300, -79, 47.452177, 19.195339, 'Pestszentlőrinc Fedezék utca', 'F03699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1301, -79, 47.422912, 19.030299, 'Víg utca (Sporttelep)', 'F04415', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1302, -79, 47.423811, 19.025064, 'Lőcsei utca', 'F04416', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1303, -79, 47.424996, 19.017058, 'Kazinczy utca', 'F04426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1304, -78, 47.519544, 18.97806, 'Bíróság', '7877', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1305, -78, 47.484963, 19.225442, '509. utca', '11715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1306, -78, 47.484645, 19.22775, 'Keresztúri út', '11773', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1307, -78, 47.463037, 19.147748, 'Kőbánya-Kispest M', '38678', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1308, -78, 47.338114, 19.038215, 'József Attila utca', '44034', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1309, -78, 47.517664, 18.99511, 'Szépilona', 'F00168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1310, -78, 47.518462, 18.985346, 'Kuruclesi út', 'F00171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1311, -78, 47.524594, 18.958747, 'Irén utca', 'F00177', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1312, -78, 47.58611, 19.045371, 'Csillaghegy H', 'F00479', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1313, -78, 47.461354, 18.999172, 'Poprádi út', 'F01932', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1314, -78, 47.463783, 19.032872, 'Bikás park M', 'F01956', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1315, -78, 47.422436, 19.035879, 'Kereszt utca', 'F04411', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1316, -77, 47.49837, 19.015183, 'Galántai utca', '9698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1317, -77, 47.463307, 19.147921, 'Kőbánya-Kispest M', '38687', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1318, -77, 47.500739, 18.967441, 'Normafa látogatóközpont', '110321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1319, -77, 47.521633, 18.968511, 'Dénes utca', 'F00149', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1320, -77, 47.52017, 18.973333, 'Vízművek', 'F00173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1321, -77, 47.565032, 19.090527, 'Csokonai utca', 'F00784', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1322, -77, 47.563081, 19.087987, 'Újpesti piac', 'F00793', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1323, -77, 47.560145, 19.09803, 'Árpád üzletház', 'F00835', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1324, -77, 47.481424, 19.135663, 'Kápolna tér', 'F01661', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1325, -77, 47.478111, 19.045272, 'Móricz Zsigmond körtér M', 'F02198', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1326, -77, 47.424394, 19.020558, 'Arató utca', 'F04424', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1327, -76, 47.596035, 18.949797, 'Solymár vasútállomás', '73308', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1328, -76, 47.353773, 19.059324, 'Teleki utca', '101182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1329, -76, 47.518988, 18.981633, 'Labanc út', 'F00147', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1330, -76, 47.530037, 19.008683, 'Pitypang utca', 'F00261', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1331, -76, 47.454819, 19.087509, 'Kén utca H (Illatos út)', 'F01417', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1332, -76, 47.462465, 19.165951, 'Hangár utca', 'F01587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1333, -76, 47.487509, 19.178599, 'Kozma utca', 'F01602', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1334, -76, 47.490929, 19.168549, 'Keresztúri út 102.', 'F01611', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1335, -76, 47.488296, 19.174156, 'Rákos vasútállomás', 'F01613', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1336, -76, 47.488331, 19.18363, 'Díszítő üzem', 'F01816', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1337, -76, 47.484232, 19.233694, 'Borsó utca', 'F03546', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1338, -76, 47.425642, 19.012737, 'Karácsony utca', 'F04427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1339, -75, 47.561294, 19.079376, 'Váci út', '8941', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1340, -75, 47.512067, 18.928677, 'Széchenyi utca (gimnázium)', '9442', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1341, -75, 47.478911, 19.236966, 'Füstifecske utca', '9491', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1342, -75, 47.462929, 19.148252, 'Kőbánya-Kispest M', '38680', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1343, -75, 47.469808, 19.053655, 'Budafoki út / Dombóvári út', '66453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1344, -75, 47.454332, 19.090121, 'Gubacsi út', 'F01422', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1345, -75, 47.489354, 19.177754, 'Fővárosi Kertészet', 'F01814', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1346, -75, 47.548711, 19.077788, 'Cziffra György park', 'F02540', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1347, -75, 47.537112, 19.066054, 'Karikás Frigyes utca', 'F02571', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1348, -75, 47.423021, 19.03405, 'Budafoki temető', 'F04412', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1349, -75, 47.592154, 18.937099, 'Solymár községháza', 'F04700', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1350, -74, 47.479619, 19.232961, 'Uszoda', '9493', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1351, -74, 47.478138, 19.241221, 'Madárdomb köz', '9549', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1352, -74, 47.489544, 19.090388, 'Orczy tér (Baross kocsiszín)', '47875', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1353, -74, 47.509568, 19.014008, 'Városmajor', 'F00155', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1354, -74, 47.509595, 19.013212, 'Városmajor', 'F00156', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1355, -74, 47.597426, 19.056447, 'Békásmegyer H', 'F00468', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1356, -74, 47.594008, 19.113778, 'Homoktövis utca', 'F00720', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1357, -74, 47.505566, 18.99268, 'Istenhegyi lejtő', 'F02389', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1358, -74, 47.482272, 19.243864, 'Bakancsos utca', 'F03541', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1359, -74, 47.482087, 19.241621, 'Rákoskeresztúr Kis utcai lakótelep', 'F03543', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1360, -74, 47.591438, 18.929464, 'Templom tér', 'F04706', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1361, -73, 47.436278, 19.035478, 'Ady Endre út', '9541', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1362, -73, 47.53785, 19.062428, 'Dagály fürdő', '62861', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1363, -73, 47.541088, 19.00194, 'Szépvölgyi köz', '64208', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1364, -73, 47.539593, 19.045207, 'Szentlélek tér H', 'F00626', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1365, -73, 47.593332, 19.117141, 'Sárpatak utca', 'F00908', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1366, -73, 47.496151, 19.071256, 'Blaha Lujza tér M (Népszínház utca)', 'F01294', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1367, -73, 47.466285, 19.124821, 'Határ út', 'F01541', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1368, -73, 47.509131, 18.999858, 'Józsa Béla köz', 'F02346', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1369, -73, 47.536294, 19.058868, 'Népfürdő utca / Árpád híd', 'F02527', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1370, -73, 47.531039, 19.07556, 'Hajdú utca', 'F02583', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1371, -73, 47.524817, 19.062438, 'Dózsa György út M', 'F02608', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1372, -73, 47.554883, 19.115895, 'Beller Imre utca', 'F03184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1373, -73, 47.442382, 19.018056, 'Pék utca', 'F04376', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1374, -72, 47.442184, 19.018652, 'Pék utca', '8100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1375, -72, 47.518001, 18.985232, 'Kuruclesi út', '9588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1376, -72, 47.49349, 19.032783, 'Naphegy tér', '16729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1377, -72, 47.505964, 19.0252, 'Széll Kálmán tér M', '73037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1378, -72, 47.513933, 19.027387, 'Füge utca', 'F00201', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1379, -72, 47.571911, 19.106591, 'Zombori utca', 'F00874', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1380, -72, 47.496088, 19.071534, 'Blaha Lujza tér M (Népszínház utca)', 'F01296', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1381, -72, 47.494666, 19.077599, 'II. János Pál pápa tér M', 'F01301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1382, -72, 47.464933, 19.116099, 'Valéria tér', 'F01468', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1383, -72, 47.450923, 19.01647, 'Bolygó utca', 'F01914', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1384, -72, 47.475665, 19.039952, 'Kosztolányi Dezső tér', 'F02182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1385, -72, 47.518227, 19.055824, 'Victor Hugo utca', 'F02517', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1386, -72, 47.546207, 19.0901, 'Gyöngyösi utca', 'F02543', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1387, -72, 47.460281, 18.952808, 'Kisfaludy utca', 'F04719', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1388, -72, 47.460917, 18.949518, 'Kötő utca', 'F04807', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1389, -71, 47.478849, 19.040827, 'Tas vezér utca', '8041', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1390, -71, 47.495999, 19.071283, 'Blaha Lujza tér M (Népszínház utca)', '9753', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1391, -71, 47.54037, 19.005647, 'Fenyőgyöngye', '64210', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1392, -71, 47.539299, 18.970955, 'Heinrich István utca', 'F00345', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1393, -71, 47.54646, 18.960477, 'Bükkfa utca', 'F00356', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1394, -71, 47.539802, 19.04633, 'Szentlélek tér H', 'F00620', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1395, -71, 47.476663, 19.03572, 'Vincellér utca', 'F02123', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1396, -71, 47.491022, 19.014087, 'Apor Vilmos tér', 'F02292', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1397, -71, 47.49849, 18.98057, 'Ordas út', 'F02443', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1398, -71, 47.481165, 19.249511, 'Szent kereszt tér', 'F03594', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1399, -71, 47.455751, 19.185403, 'Vajk utca', 'F03843', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1400, -71, 47.490799, 19.326692, 'Faiskola utcai átjáró', 'F04587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1401, -71, 47.458333, 18.906106, 'Gyár utca', 'F04739', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1402, -70, 47.570877, 19.11133, 'Szilágyi utca', '8289', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1403, -70, 47.570571, 19.112699, 'Szilágyi utca', '9555', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1404, -70, 47.511829, 18.997249, 'Fészek utca', 'F02355', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1405, -70, 47.537679, 19.053728, 'Margitsziget / Árpád híd', 'F02503', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1406, -70, 47.525897, 19.057817, 'Dráva utca', 'F02509', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1407, -70, 47.536348, 19.059466, 'Népfürdő utca / Árpád híd', 'F02525', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1408, -70, 47.454576, 19.190135, 'Csaba utca', 'F03826', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1409, -70, 47.448651, 19.185752, 'Thököly út', 'F03827', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1410, -70, 47.456339, 19.182938, 'Pestszentlőrinc vasútállomás (átjáró)', 'F03845', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1411, -69, 47.43828, 19.030491, 'Rózsavölgy alsó', '8103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1412, -69, 47.512852, 18.995009, 'Dániel út', '8736', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1413, -69, 47.486079, 19.034561, 'Mihály utca', 'F00001', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1414, -69, 47.489273, 19.046916, 'Rudas Gyógyfürdő', 'F00111', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1415, -69, 47.539883, 19.045892, 'Szentlélek tér H', 'F00619', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1416, -69, 47.48674, 19.106971, 'Kőbányai út / Könyves Kálmán körút', 'F01267', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1417, -69, 47.496071, 19.071336, 'Blaha Lujza tér M (Népszínház utca)', 'F01297', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1418, -69, 47.485009, 19.113882, 'Eiffel Műhelyház', 'F01731', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1419, -69, 47.503336, 19.103698, 'Szobránc köz', 'F02737', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1420, -69, 47.522818, 19.101156, 'Nagy Lajos király útja / Czobor utca', 'F02868', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1421, -69, 47.473827, 19.273136, 'Sági utca', 'F03437', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1422, -69, 47.4496, 19.182041, 'Csörötnek utca', 'F03832', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1423, -69, 47.408324, 19.062275, 'Jupiter utca', 'F04282', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1424, -68, 47.487461, 19.104252, 'Kőbányai út 31.', '8036', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1425, -68, 47.435847, 19.042385, 'Savoya Park', '8802', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1426, -68, 47.570755, 19.027745, 'Óbuda vasútállomás (Aranyhegyi út)', '76397', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1427, -68, 47.389241, 19.100066, 'Vihorlát út', '109518', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1428, -68, 47.528172, 19.033304, 'Ürömi utca', 'F00130', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1429, -68, 47.564538, 18.960194, 'Templom utca (Kultúrkúria)', 'F00400', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1430, -68, 47.531479, 19.020342, 'Szélvész utca', 'F00634', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1431, -68, 47.487632, 19.10412, 'Kőbányai út 31.', 'F01264', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1432, -68, 47.486524, 19.108205, 'Kőbányai út / Könyves Kálmán körút', 'F01265', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1433, -68, 47.484126, 19.116668, 'Egészségház', 'F01563', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1434, -68, 47.491941, 19.144346, 'Sörgyár', 'F01620', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1435, -68, 47.494246, 19.140449, 'Terebesi utca', 'F01781', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1436, -68, 47.444573, 19.005406, 'Balatoni út', 'F01892', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1437, -68, 47.473656, 19.025517, 'Ajnácskő utca', 'F02049', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1438, -68, 47.476025, 19.053457, 'Budafoki út / Karinthy Frigyes út', 'F02154', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1439, -68, 47.526763, 19.136628, 'Öv utca / Egressy út', 'F02808', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1440, -68, 47.521278, 19.106266, 'Kerékgyártó utca', 'F02967', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1441, -68, 47.523693, 19.208176, 'Ostoros út 61.', 'F03300', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1442, -68, 47.474461, 19.270897, 'Oroszvár utca', 'F03512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1443, -67, 47.521341, 19.105562, 'Kerékgyártó utca', '9481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1444, -67, 47.46421, 19.145941, 'Kőbánya-Kispest P+R', '9592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1445, -67, 47.479815, 19.255331, 'Rákoskeresztúr városközpont', '11725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1446, -67, 47.571696, 19.006817, 'Citronella utca', '56124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1447, -67, 47.547714, 18.954457, 'Széchenyi utca', 'F00368', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1448, -67, 47.604054, 19.052418, 'Égető utca', 'F00436', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1449, -67, 47.572752, 19.103172, 'Iglói utca', 'F00760', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1450, -67, 47.513099, 19.048177, 'Jászai Mari tér', 'F00925', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1451, -67, 47.478818, 19.040577, 'Tas vezér utca', 'F02147', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1452, -67, 47.516628, 18.990066, 'Moholy-Nagy Művészeti Egyetem', 'F02352', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1453, -67, 47.522386, 19.102443, 'Nagy Lajos király útja / Czobor utca', 'F02869', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1454, -67, 47.523539, 19.098753, 'Kassai tér', 'F02901', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1455, -67, 47.422921, 19.067633, 'II. Rákóczi Ferenc út', 'F04185', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1456, -66, 47.405972, 19.014129, 'Budatétény vasútállomás (Campona)', '7997', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1457, -66, 47.403236, 19.009361, 'Rózsakert utca / Minta utca', '82623', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1458, -66, 47.516919, 19.027798, 'Szemlőhegy utca', 'F00228', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1459, -66, 47.539694, 19.045773, 'Szentlélek tér H', 'F00627', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1460, -66, 47.46393, 19.147412, 'Kőbánya-Kispest', 'F01543', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1461, -66, 47.42483, 19.230107, 'Kupeczky János utca', 'F03796', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1462, -66, 47.429442, 19.129932, 'Eperjes utca', 'F04070', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1463, -66, 47.438409, 19.03036, 'Rózsavölgy alsó', 'F04379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1464, -65, 47.430006, 19.215624, 'Béke tér', '8951', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1465, -65, 47.463226, 19.148266, 'Kőbánya-Kispest M', '38688', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1466, -65, 47.475479, 19.099291, 'Népliget', 'F01282', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1467, -65, 47.491551, 19.123909, 'II. kapu', 'F01557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1468, -65, 47.485136, 19.112914, 'Eiffel Műhelyház', 'F01733', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1469, -65, 47.458019, 19.176418, 'Felsőcsatári út', 'F01870', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1470, -65, 47.491139, 19.013556, 'Apor Vilmos tér', 'F02293', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1471, -65, 47.517271, 19.06063, 'Lehel tér M', 'F02617', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1472, -65, 47.519701, 19.111681, 'Bosnyák tér', 'F02837', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1473, -65, 47.539822, 19.128991, 'Sztárai Mihály tér', 'F03099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1474, -65, 47.469134, 19.294512, 'Kucorgó tér', 'F03499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1475, -65, 47.470547, 19.289664, 'Vecsey Ferenc utca', 'F03508', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1476, -65, 47.483315, 19.238918, 'Kis utca', 'F03544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1477, -65, 47.468084, 19.30163, 'Olcsva utca', 'F03574', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1478, -65, 47.452922, 19.196787, 'Csévéző utca', 'F03693', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1479, -65, 47.456346, 19.1766, 'Felsőcsatári köz', 'F03746', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1480, -65, 47.587733, 18.992841, 'Solymár téglagyári bekötőút', 'F04817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1481, -64, 47.484694, 19.096209, 'Delej utca', '8269', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1482, -64, 47.471018, 19.221893, 'Csillagmotor köz', '9138', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1483, -64, 47.378287, 19.121299, 'Soroksár BILK', '42934', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1484, -64, 47.582575, 19.026017, 'Cserszömörce utca [4]', '56168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1485, -64, 47.344229, 19.033185, 'Akácfa körút', '64093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1486, -64, 47.492492, 19.042355, 'Szarvas tér', 'F00102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1487, -64, 47.530635, 19.027062, 'Virág Benedek utca', 'F00126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1488, -64, 47.524228, 19.013255, 'Baba utca', 'F00151', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1489, -64, 47.523334, 19.002248, 'Fenyves utca', 'F00290', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1490, -64, 47.53669, 18.969139, 'Csibor utca', 'F00331', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1491, -64, 47.598101, 19.043988, 'Hősök tere', 'F00447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1492, -64, 47.483497, 19.099737, 'Bláthy Ottó utca', 'F01240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1493, -64, 47.478759, 19.113662, 'Sporttelep', 'F01533', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1494, -64, 47.441603, 19.034191, 'Albertfalva vasútállomás', 'F01970', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1495, -64, 47.504642, 18.997964, 'Óra út', 'F02391', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1496, -64, 47.500027, 19.020717, 'Ráth György utca', 'F02406', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1497, -64, 47.538251, 19.133897, 'Thököly út', 'F03134', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1498, -64, 47.47236, 19.278304, 'Tápióbicske utca', 'F03510', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1499, -64, 47.469986, 19.237304, 'Lőrinci út (Szabadság utca)', 'F03529', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1500, -64, 47.436417, 19.196966, 'Iparvasút', 'F03690', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1501, -64, 47.433185, 19.206501, 'Bajcsy-Zsilinszky út', 'F03884', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1502, -64, 47.422405, 18.975725, 'Dózsa György út / Kamaraerdei út', 'F04392', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1503, -63, 47.422104, 19.238366, 'Pestszentlőrinc sorompó', '50176', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1504, -63, 47.507412, 19.036739, 'Batthyány tér', 'F00062', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1505, -63, 47.523625, 18.962055, 'Bölcsőde', 'F00175', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1506, -63, 47.511353, 19.032247, 'Mechwart liget', 'F00195', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1507, -63, 47.529019, 19.004288, 'Verecke lépcső', 'F00264', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1508, -63, 47.564018, 19.082314, 'Attila utca', 'F00851', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1509, -63, 47.490109, 19.060783, 'Kálvin tér M', 'F01011', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1510, -63, 47.484081, 19.117318, 'Egészségház', 'F01565', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1511, -63, 47.473762, 19.135266, 'Kőér utca', 'F01577', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1512, -63, 47.467152, 19.153022, 'Sibrik Miklós út', 'F01806', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1513, -63, 47.475512, 19.003882, 'Nagyszalonta köz', 'F02091', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1514, -63, 47.48686, 18.954419, 'Csillebérc KFKI', 'F02269', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1515, -63, 47.497192, 19.014146, 'Galántai utca', 'F02423', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1516, -63, 47.535273, 19.120771, 'Ady Endre utca', 'F03188', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1517, -63, 47.473344, 19.230883, 'Barátka utca', 'F03558', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1518, -63, 47.436341, 19.197042, 'Iparvasút', 'F03689', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1519, -63, 47.434747, 19.201893, 'Honvéd utca', 'F03805', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1520, -63, 47.437835, 19.192781, 'Madarász utca', 'F03822', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1521, -63, 47.43784, 19.192591, 'Madarász utca', 'F03824', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1522, -63, 47.431225, 19.212261, 'Ungvár utca', 'F03854', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1523, -63, 47.596171, 18.931261, 'Váci Mihály utca', 'F04832', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1524, -62, 47.4793, 19.211468, '526. sor', '7914', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1525, -62, 47.465339, 19.127838, 'Határ út M', '40762', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1526, -62, 47.466764, 19.123039, 'Száva kocsiszín', '44126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1527, -62, 47.529466, 19.030077, 'Folyondár utca', 'F00128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1528, -62, 47.525387, 19.009988, 'Móricz Zsigmond Gimnázium', 'F00269', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1529, -62, 47.519642, 19.010466, 'Gábor Áron utca', 'F00294', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1530, -62, 47.602488, 19.056793, 'Pusztadombi utca', 'F00435', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1531, -62, 47.512532, 19.050115, 'Jászai Mari tér', 'F00929', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1532, -62, 47.4688, 19.117431, 'Pöttyös utca M', 'F01465', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1533, -62, 47.471127, 19.110331, 'Ecseri út', 'F01493', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1534, -62, 47.463169, 19.163472, 'Gyömrői út 105.', 'F01547', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1535, -62, 47.482334, 19.20337, 'Hungaropharma', 'F01859', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1536, -62, 47.468557, 19.117532, 'Pöttyös utca', 'F01878', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1537, -62, 47.535636, 19.105701, 'Róna park', 'F02906', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1538, -62, 47.428895, 19.218855, 'Pestszentlőrinc Béke tér', 'F03787', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1539, -62, 47.435036, 19.200806, 'Honvéd utca', 'F03806', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1540, -62, 47.430972, 19.196364, 'Gyékény tér', 'F03817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1541, -62, 47.431518, 19.211331, 'Ungvár utca', 'F03856', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1542, -62, 47.433153, 19.206498, 'Bajcsy-Zsilinszky út', 'F03886', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1543, -62, 47.571604, 18.89885, 'Sebestyéndomb', 'F04784', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1544, -61, 47.592911, 19.284773, 'EGIS-telep (Szentjakab H)', '9014', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1545, -61, 47.520032, 19.22058, 'Cinkota H', '44125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1546, -61, 47.574337, 19.016474, 'Aranyvölgy vasútállomás', '76394', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1547, -61, 47.515607, 19.031714, 'Zivatar utca', 'F00230', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1548, -61, 47.527784, 19.02847, 'Pusztaszeri út 25.', 'F00251', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1549, -61, 47.547259, 18.938264, 'Adyliget', 'F00351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1550, -61, 47.482334, 19.080113, 'Semmelweis Klinikák M', 'F01234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1551, -61, 47.482443, 19.10284, 'Vajda Péter utca (Villám utca)', 'F01258', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1552, -61, 47.470872, 19.111862, 'Ecseri út M', 'F01496', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1553, -61, 47.470562, 19.143843, 'Diósgyőri utca', 'F01582', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1554, -61, 47.465396, 19.157601, 'Újhegyi út', 'F01585', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1555, -61, 47.509812, 18.994574, 'Hadik András utca', 'F02366', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1556, -61, 47.526862, 19.127523, 'Szugló utca / Cinkotai út', 'F02810', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1557, -61, 47.56858, 19.117919, 'Géza fejedelem tér', 'F03020', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1558, -61, 47.521794, 19.215712, 'Csókakő utca', 'F03303', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1559, -61, 47.477037, 19.264182, 'Mezőtárkány utca', 'F03514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1560, -61, 47.440218, 19.185416, 'Szarvas csárda tér', 'F03713', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1561, -61, 47.454673, 19.168254, 'Tinódi utca', 'F03841', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1562, -61, 47.427793, 19.221873, 'Rába utca', 'F03868', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1563, -61, 47.477653, 19.343128, 'Pécel Kun József utca', 'F04593', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1564, -60, 47.572096, 19.021853, 'Aranyvölgy utca 14.', '76399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1565, -60, 47.56925, 18.948757, 'Szarvashegy utca', 'F00396', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1566, -60, 47.599558, 19.041169, 'Tamás utca', 'F00449', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1567, -60, 47.472329, 19.139137, 'Gyógyszergyár', 'F01797', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1568, -60, 47.447733, 18.976817, 'Repülőgépes Szolgálat', 'F01909', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1569, -60, 47.4841, 19.027041, 'Budaörsi út / Villányi út', 'F02170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1570, -60, 47.572182, 19.124901, 'Közvágóhíd tér', 'F03016', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1571, -60, 47.434951, 18.90683, 'Ady Endre utca', 'F04814', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1572, -59, 47.450468, 19.16141, 'Lajosmizsei sorompó', '8047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1573, -59, 47.44112, 19.022627, 'Rózsavölgy felső', '8102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1574, -59, 47.404326, 18.990746, 'Mátyás király utca', '8114', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1575, -59, 47.435283, 19.008714, 'Tanító utca', '9462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1576, -59, 47.43343, 19.025932, 'Alkotmány utca', '9469', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1577, -59, 47.433937, 18.924939, 'Diósdi út', '48303', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1578, -59, 47.583753, 19.023743, 'Mácsonya utca [5]', '56173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1579, -59, 47.45859, 19.018802, 'Zelk Zoltán út / Neszmélyi út', '61224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1580, -59, 47.522826, 19.017452, 'Pusztaszeri körönd', 'F00215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1581, -59, 47.566764, 18.955273, 'Solymári elágazás', 'F00394', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1582, -59, 47.579782, 19.109254, 'Művelődési Központ', 'F00732', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1583, -59, 47.468993, 19.148005, 'Vasgyár utca', 'F01804', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1584, -59, 47.506789, 19.015112, 'Temes utca', 'F02339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1585, -59, 47.508712, 19.009721, 'Szent János Kórház', 'F02341', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1586, -59, 47.511457, 18.989841, 'György Aladár utca', 'F02360', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1587, -59, 47.466726, 19.30547, 'Nyomdok utca', 'F03568', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1588, -59, 47.440489, 19.18498, 'Szarvas csárda tér', 'F03718', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1589, -59, 47.449728, 19.163145, 'Tinódi utca', 'F03740', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1590, -59, 47.448127, 19.166821, 'Margó Tivadar utca', 'F03752', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1591, -59, 47.439151, 19.166642, 'Kondor Béla sétány', 'F03760', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1592, -59, 47.426113, 19.226228, 'Gyergyó utca', 'F03793', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1593, -59, 47.446053, 19.171669, 'Kemény Zsigmond utca', 'F03836', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1594, -59, 47.461998, 19.134504, 'Corvin körút', 'F03900', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1595, -59, 47.459196, 19.15798, 'Bocskai utca', 'F04005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1596, -59, 47.452622, 19.156547, 'Villanytelep', 'F04031', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1597, -59, 47.400763, 18.973979, 'Barackos út / Angeli utca', 'F04486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1598, -58, 47.451162, 19.176781, 'Mikszáth Kálmán utca', '8850', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1599, -58, 47.464751, 19.017791, 'Kelenföld vasútállomás M', '61278', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1600, -58, 47.480143, 19.140716, 'Óhegy park', 'F01666', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1601, -58, 47.448485, 18.975165, 'Repülőgépes Szolgálat', 'F01910', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1602, -58, 47.482941, 19.03009, 'Alsóhegy utca', 'F02115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1603, -58, 47.48284, 19.030635, 'Alsóhegy utca', 'F02117', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1604, -58, 47.480185, 19.036537, 'Szüret utca', 'F02127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1605, -58, 47.479955, 19.037364, 'Szüret utca', 'F02128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1606, -58, 47.481864, 19.032616, 'Pető Intézet (Villányi út)', 'F02252', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1607, -58, 47.567292, 19.120974, 'Dal utca', 'F03023', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1608, -58, 47.561159, 19.118853, 'Beller Imre utca', 'F03057', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1609, -58, 47.534814, 19.181876, 'Csömöri út', 'F03212', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1610, -58, 47.536708, 19.17775, 'Kisszentmihály Baross utca', 'F03214', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1611, -58, 47.533149, 19.185692, 'Péterke utca', 'F03347', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1612, -58, 47.526356, 19.201305, 'Felsőmalom utca', 'F03377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1613, -58, 47.480427, 19.227923, '513. utca', 'F03553', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1614, -58, 47.44259, 19.180066, 'Thököly út', 'F03710', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1615, -58, 47.450561, 19.161323, 'Lajosmizsei sorompó', 'F03734', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1616, -58, 47.452271, 19.174197, 'Lakatos úti lakótelep', 'F03749', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1617, -58, 47.447883, 19.167603, 'Margó Tivadar utca', 'F03756', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1618, -58, 47.436554, 19.172654, 'Fiatalság utca', 'F03765', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1619, -58, 47.444475, 19.175603, 'Baross utca', 'F03771', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1620, -58, 47.444349, 19.175696, 'Baross utca', 'F03772', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1621, -58, 47.446067, 19.171902, 'Kemény Zsigmond utca', 'F03834', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1622, -58, 47.46232, 19.133621, 'Nyáry Pál utca', 'F03902', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1623, -58, 47.454806, 19.151374, 'Árpád utca', 'F03904', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1624, -58, 47.46514, 19.127219, 'Határ út M', 'F03929', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1625, -58, 47.459824, 19.139493, 'Lehel utca', 'F03934', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1626, -58, 47.457745, 19.144483, 'Kossuth tér', 'F03946', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1627, -58, 47.457727, 19.144298, 'Kossuth tér', 'F03949', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1628, -58, 47.456627, 19.14712, 'Fő utca', 'F03954', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1629, -58, 47.43649, 19.156243, 'Kassa utca', 'F03999', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1630, -57, 47.609007, 19.101441, 'Ezred utca lakótelep', '9747', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1631, -57, 47.579647, 19.088983, 'Megyeri út', 'F00715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1632, -57, 47.494687, 19.140349, 'Terebesi utca', 'F01783', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1633, -57, 47.540264, 19.084783, 'Fiastyúk utca', 'F02551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1634, -57, 47.529885, 19.148963, 'Késmárk utca', 'F03142', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1635, -57, 47.529171, 19.194832, 'Állás utca', 'F03296', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1636, -57, 47.524957, 19.204501, 'Alsómalom utca', 'F03390', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1637, -57, 47.497081, 19.224542, 'EGIS Gyógyszergyár', 'F03403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1638, -57, 47.471763, 19.235335, 'Rákoskeresztúr Aranylúd utca', 'F03557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1639, -57, 47.44305, 19.178755, 'Thököly út', 'F03707', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1640, -57, 47.437898, 19.169516, 'Barta Lajos utca', 'F03761', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1641, -57, 47.434164, 19.161593, 'Karton utca', 'F03890', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1642, -57, 47.454906, 19.150936, 'Árpád utca', 'F03906', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1643, -57, 47.459312, 19.140826, 'Lehel utca', 'F03932', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1644, -57, 47.436112, 19.151741, 'Ipolyság utca', 'F03996', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1645, -57, 47.452661, 19.156209, 'Villanytelep', 'F04029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1646, -57, 47.567007, 18.909824, '13-as kilométerkő', 'F04782', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1647, -56, 47.460801, 19.013867, 'Neszmélyi út', '61223', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1648, -56, 47.543118, 18.996373, 'Selyemakác utca', '64212', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1649, -56, 47.521685, 19.02048, 'Vend utca', 'F00211', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1650, -56, 47.577568, 19.010637, 'Ürömhegyi út', 'F00530', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1651, -56, 47.510274, 19.056168, 'Nyugati pályaudvar M', 'F00933', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1652, -56, 47.492051, 19.144505, 'Sörgyár', 'F01622', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1653, -56, 47.4463, 18.975818, 'Vasút utca', 'F01906', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1654, -56, 47.483292, 19.040535, 'Iglói utca', 'F02131', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1655, -56, 47.553852, 19.131656, 'Pöltenberg Ernő utca', 'F03064', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1656, -56, 47.542035, 19.15135, 'Újpalota Szentmihályi út', 'F03144', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1657, -56, 47.531586, 19.189238, 'Timur utca', 'F03217', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1658, -56, 47.465497, 19.308356, 'Nyomdász utca', 'F03500', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1659, -56, 47.469683, 19.236813, 'Lőrinci út', 'F03532', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1660, -56, 47.444145, 19.155344, 'Bozsik Stadion', 'F03731', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1661, -56, 47.44984, 19.163032, 'Tinódi utca', 'F03741', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1662, -56, 47.456437, 19.164314, 'Hőerőmű', 'F03742', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1663, -56, 47.456546, 19.147094, 'Fő utca', 'F03952', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1664, -56, 47.419396, 18.983428, 'Balatoni út / Dózsa György út', 'F04390', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1665, -55, 47.405711, 19.014354, 'Budatétény vasútállomás (Campona)', '7998', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1666, -55, 47.486357, 19.06885, 'Corvin-negyed M', '62521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1667, -55, 47.499502, 18.915257, 'Budakeszi Tesco áruház', '99012', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1668, -55, 47.498365, 19.028997, 'Mikó utca', 'F00089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1669, -55, 47.577842, 19.10573, 'Újpest Erdősor út', 'F00750', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1670, -55, 47.572093, 19.088419, 'Mildenberger utca', 'F00849', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1671, -55, 47.511961, 19.003999, 'Pető Intézet', 'F02348', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1672, -55, 47.511176, 19.00568, 'Rendelőintézet', 'F02482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1673, -55, 47.519395, 19.127215, 'Egressy út / Vezér utca', 'F02804', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1674, -55, 47.415237, 19.080378, 'Nyárfás utca', 'F04249', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1675, -54, 47.526625, 19.09622, 'Szőnyi út', '8241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1676, -54, 47.416726, 19.251851, 'Vecsés Market Central Ferihegy', '50185', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1677, -54, 47.55796, 18.940333, 'Szirom utca', 'F00376', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1678, -54, 47.557758, 19.015544, 'Laborc utca', 'F00539', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1679, -54, 47.549016, 19.014833, 'Királylaki út', 'F00561', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1680, -54, 47.577226, 19.09378, 'Baross utca', 'F00756', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1681, -54, 47.515032, 19.139741, 'Fischer István utca', 'F02785', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1682, -54, 47.521304, 19.123275, 'Komócsy utca', 'F02811', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1683, -54, 47.532298, 19.117487, 'Kerékgyártó utca', 'F02946', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1684, -54, 47.535325, 19.204853, 'Szabadságtelep H', 'F03373', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1685, -54, 47.457666, 19.161405, 'Csillag utca', 'F03745', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1686, -54, 47.419153, 19.066333, 'Kossuth Lajos utca (Völgy utca)', 'F04291', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1687, -54, 47.558727, 18.921366, 'Mészégető', 'F04823', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1688, -53, 47.468754, 19.154451, 'Gergely utca', '9289', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1689, -53, 47.49744, 19.055729, 'Deák Ferenc tér M', '9309', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1690, -53, 47.507124, 19.023155, 'Széll Kálmán tér M', '78004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1691, -53, 47.580964, 19.018281, 'Kőpor köz / Jutas utca [7]', '100837', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1692, -53, 47.491737, 19.043373, 'Döbrentei tér', 'F00105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1693, -53, 47.531354, 18.997248, 'Vöröstorony lépcső', 'F00271', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1694, -53, 47.558945, 19.013152, 'Jablonka út 53.', 'F00541', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1695, -53, 47.575003, 19.098363, 'Bucka utca', 'F00757', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1696, -53, 47.461508, 19.169172, 'Gyula utca', 'F01658', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1697, -53, 47.486489, 18.992236, 'Hó utca', 'F02276', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1698, -53, 47.572034, 19.130842, 'Kosd utca', 'F03011', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1699, -53, 47.450886, 19.22468, 'Bélatelepi út', 'F03431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1700, -53, 47.439338, 19.137941, 'Vas Gereben utca', 'F03912', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1701, -53, 47.417956, 19.068665, 'Béke tér', 'F04205', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1702, -53, 47.441043, 19.023094, 'Rózsavölgy felső', 'F04377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1703, -52, 47.482256, 19.130424, 'Kápolna utca', 'F01647', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1704, -52, 47.473935, 18.995871, 'Telekes utca', 'F02072', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1705, -52, 47.495745, 19.018994, 'Királyhágó tér', 'F02413', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1706, -52, 47.456108, 19.21507, 'Homokiszőlők', 'F03600', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1707, -52, 47.416025, 19.072494, 'Kölcsey utca', 'F04209', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1708, -52, 47.408037, 19.002216, 'Terv utca', 'F04463', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1709, -51, 47.595557, 19.271513, 'Mogyoród H', '9022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1710, -51, 47.471898, 19.148435, 'Noszlopy utca', '9225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1711, -51, 47.466591, 19.15844, 'Rózsaliget', '16725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1712, -51, 47.58102, 19.030165, 'Öregbükk utca [3]', '56165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1713, -51, 47.584391, 19.022254, 'Saroglya utca [6]', '56175', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1714, -51, 47.459205, 19.332057, 'Maglód Auchan áruház', '61379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1715, -51, 47.496621, 19.032847, 'Krisztina tér', 'F00086', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1716, -51, 47.513548, 19.003093, 'Nagyajtai utca', 'F00160', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1717, -51, 47.541664, 19.040088, 'Flórián tér', 'F00613', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1718, -51, 47.465248, 19.003555, 'Regős utca', 'F02062', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1719, -51, 47.46683, 19.000662, 'Törökugrató utca', 'F02064', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1720, -51, 47.46924, 18.996233, 'Szent Angyalok-templom', 'F02066', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1721, -51, 47.489755, 19.018002, 'Sirály utca', 'F02302', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1722, -51, 47.505099, 19.01863, 'Barabás Villa', 'F02338', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1723, -51, 47.504768, 19.099664, 'Egressy út / Stefánia út', 'F02733', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1724, -51, 47.519152, 19.112637, 'Bosnyák tér', 'F02832', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1725, -51, 47.582659, 19.121877, 'Kanizsai Dorottya utca', 'F02999', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1726, -51, 47.570332, 19.134281, 'Rákospalota Bogáncs utca', 'F03012', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1727, -51, 47.549432, 19.131591, 'Bánkút utca', 'F03125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1728, -51, 47.531552, 19.145593, 'Késmárk utca 20.', 'F03197', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1729, -51, 47.453141, 19.220537, 'Lőrinci út', 'F03429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1730, -51, 47.493839, 19.278065, 'Aranykút utca', 'F03443', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1731, -51, 47.438571, 19.147437, 'Hofherr Albert utca', 'F03993', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1732, -51, 47.413307, 19.077436, 'Martinász utca', 'F04239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1733, -50, 47.432765, 19.028517, 'Galamb utca', '9467', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1734, -50, 47.352892, 19.038688, 'Lehel utca', '64187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1735, -50, 47.501071, 19.024671, 'Déli pályaudvar M', 'F00027', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1736, -50, 47.499787, 19.03533, 'Dísz tér', 'F00073', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1737, -50, 47.512896, 19.017019, 'Rókushegyi lépcső', 'F00302', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1738, -50, 47.550188, 19.025805, 'Táborhegyi út', 'F00564', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1739, -50, 47.541517, 19.03996, 'Flórián tér', 'F00614', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1740, -50, 47.590085, 19.117283, 'Homoktövis utca (Óvoda)', 'F00729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1741, -50, 47.479367, 19.154831, 'Kada utca', 'F01683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1742, -50, 47.484174, 19.127349, 'Kőbánya alsó vasútállomás', 'F01800', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1743, -50, 47.475329, 19.142199, 'Szlávy utca', 'F01840', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1744, -50, 47.47256, 18.998419, 'Kaptárkő utca', 'F02060', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1745, -50, 47.483418, 19.043467, 'Rezeda utca', 'F02136', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1746, -50, 47.484109, 19.026812, 'Budaörsi út / Villányi út', 'F02171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1747, -50, 47.467971, 18.998565, 'Torbágy utca', 'F02245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1748, -50, 47.49454, 19.020547, 'Márvány utca', 'F02415', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1749, -50, 47.500391, 18.970653, 'Normafa Gyermekvasút', 'F02449', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1750, -50, 47.506863, 19.024178, 'Széll Kálmán tér M', 'F02460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1751, -50, 47.529688, 19.081203, 'Béke tér', 'F02589', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1752, -50, 47.542184, 19.140997, 'Fő tér', 'F03156', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1753, -50, 47.54782, 19.134139, 'Sárfű utca', 'F03191', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1754, -50, 47.534138, 19.141055, 'Fázis utca', 'F03199', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1755, -50, 47.427428, 19.203233, 'Nagyenyed utca', 'F03866', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1756, -50, 47.436428, 19.020101, 'Lomnici utca', 'F04374', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1757, -50, 47.403332, 18.969127, 'Diósdi utca', 'F04516', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1758, -49, 47.440072, 18.895693, 'Törökbálint Sportközpont', '8328', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1759, -49, 47.509771, 19.128546, 'Pongrátz Gergely tér', '8919', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1760, -49, 47.474086, 19.144346, 'Gergely utca 80.', '9631', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1761, -49, 47.495409, 19.275378, 'Jászivány utca', '9704', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1762, -49, 47.579492, 19.034353, 'Mészkő utca [2]', '56162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1763, -49, 47.432072, 18.914048, 'Harangláb', '73472', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1764, -49, 47.53316, 19.016503, 'Pál-völgyi cseppkőbarlang', 'F00122', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1765, -49, 47.511242, 19.019875, 'Garas utca', 'F00154', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1766, -49, 47.514589, 19.024294, 'Rét utca', 'F00203', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1767, -49, 47.581522, 19.002246, 'Üröm vasúti megállóhely', 'F00532', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1768, -49, 47.55041, 19.015987, 'Iskola', 'F00559', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1769, -49, 47.569303, 19.09364, 'Nádor utca', 'F00776', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1770, -49, 47.488852, 19.137427, 'Kőrösi Csoma Sándor út', 'F01630', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1771, -49, 47.477175, 19.139032, 'Kőér utca', 'F01664', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1772, -49, 47.475069, 19.162039, 'Mádi utca', 'F01705', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1773, -49, 47.484179, 19.020632, 'Breznó lépcső', 'F02108', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1774, -49, 47.500321, 18.989659, 'Városkút', 'F02441', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1775, -49, 47.506647, 19.023912, 'Széll Kálmán tér M', 'F02473', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1776, -49, 47.522541, 19.066527, 'Kassák Lajos utca', 'F02639', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1777, -49, 47.515691, 19.09001, 'Ciklámen utca', 'F02702', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1778, -49, 47.509167, 19.129536, 'Pongrátz Gergely tér', 'F02752', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1779, -49, 47.50933, 19.129449, 'Pongrátz Gergely tér', 'F02753', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1780, -49, 47.51456, 19.12061, 'Egressy tér', 'F02797', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1781, -49, 47.517054, 19.116192, 'Szugló utca / Nagy Lajos király útja', 'F02817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1782, -49, 47.51681, 19.091387, 'Erzsébet királyné útja aluljáró', 'F02924', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1783, -49, 47.506942, 19.133449, 'Tihamér utca', 'F02969', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1784, -49, 47.511553, 19.125703, 'Jeszenák János utca', 'F02972', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1785, -49, 47.511436, 19.12573, 'Jeszenák János utca', 'F02973', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1786, -49, 47.557364, 19.125602, 'Fazekas sor', 'F03063', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1787, -49, 47.55078, 19.119782, 'Klapka György utca', 'F03115', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1788, -49, 47.548439, 19.123751, 'Bercsényi Miklós utca', 'F03123', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1789, -49, 47.537591, 19.137376, 'Nyírpalota út', 'F03140', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1790, -49, 47.545028, 19.138863, 'Vásárcsarnok', 'F03162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1791, -49, 47.54095, 19.143279, 'Zsókavár utca', 'F03209', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1792, -49, 47.447556, 19.207644, 'Billentyű utca', 'F03814', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1793, -49, 47.442685, 19.158085, 'Kispesti temető', 'F03838', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1794, -49, 47.425093, 19.20719, 'Tarkő utca', 'F03864', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1795, -49, 47.592631, 18.983529, 'Kövesbérci utca', 'F04819', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1796, -49, 47.457828, 18.903519, 'Tetra Pak', 'F04834', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1797, -48, 47.617063, 19.051534, 'Budakalász Budai út', '8428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1798, -48, 47.540469, 19.109767, 'Irány utca', '9538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1799, -48, 47.424622, 19.058674, 'Csepeli Kórház', '41092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1800, -48, 47.559188, 19.122464, 'Régi Fóti út', '43342', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1801, -48, 47.494435, 19.037944, 'Dózsa György tér', 'F00078', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1802, -48, 47.513548, 19.002854, 'Nagyajtai utca', 'F00161', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1803, -48, 47.521309, 19.068598, 'Lehel utca / Dózsa György út', 'F02620', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1804, -48, 47.529816, 19.071509, 'Honvédkórház', 'F02641', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1805, -48, 47.513523, 19.137123, 'Fogarasi út', 'F02787', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1806, -48, 47.515317, 19.119097, 'Egressy tér', 'F02795', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1807, -48, 47.517144, 19.116219, 'Szugló utca / Nagy Lajos király útja', 'F02818', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1808, -48, 47.514503, 19.111157, 'Szugló utca / Róna utca', 'F02820', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1809, -48, 47.515352, 19.133728, 'Gödöllői utca', 'F02938', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1810, -48, 47.553973, 19.119415, 'Rákos úti szakrendelő', 'F03073', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1811, -48, 47.537247, 19.115023, 'Kolozsvár utcai piac', 'F03092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1812, -48, 47.54231, 19.14097, 'Fő tér', 'F03154', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1813, -48, 47.545487, 19.138293, 'Vásárcsarnok', 'F03159', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1814, -48, 47.547307, 19.135187, 'Sárfű utca', 'F03193', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1815, -48, 47.498784, 19.269831, 'Almásháza utca', 'F03579', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1816, -48, 47.496591, 19.273432, 'Jászágó utca', 'F03581', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1817, -48, 47.444211, 19.213256, 'Szemeretelep vasútállomás', 'F03870', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1818, -48, 47.429709, 19.199369, 'Olt utca', 'F03878', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1819, -48, 47.448706, 19.131645, 'Hunyadi tér', 'F03974', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1820, -48, 47.401075, 19.094676, 'Királyerdei Művelődési Ház', 'F04259', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1821, -48, 47.404734, 18.9667, 'Németh-villa', 'F04484', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1822, -48, 47.575359, 18.893082, 'Teleki-Tisza-kastély', 'F04786', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1823, -47, 47.598679, 19.356116, 'Gödöllő Palotakert H', '9020', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1824, -47, 47.386832, 19.222956, 'Dobó Katica utca', '9198', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1825, -47, 47.394883, 19.210398, 'Mátyás király utca', '9200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1826, -47, 47.416612, 19.241461, 'Lőrinci utca', '50174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1827, -47, 47.427631, 19.265759, 'Nemzetközi Posta Kicserélő Központ', '110452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1828, -47, 47.493698, 19.038463, 'Dózsa György tér', 'F00081', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1829, -47, 47.479503, 19.07223, 'Bokréta utca', 'F01381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1830, -47, 47.476907, 19.158952, 'Lavotta utca', 'F01703', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1831, -47, 47.526137, 19.077337, 'Lehel utca / Róbert Károly körút', 'F02594', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1832, -47, 47.5262, 19.076952, 'Lehel utca / Róbert Károly körút', 'F02597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1833, -47, 47.524139, 19.080496, 'Vágány utca / Róbert Károly körút', 'F02634', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1834, -47, 47.530449, 19.070169, 'Honvédkórház', 'F02642', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1835, -47, 47.511875, 19.139829, 'Tihamér utca', 'F02932', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1836, -47, 47.506826, 19.133475, 'Tihamér utca', 'F02970', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1837, -47, 47.541955, 19.107457, 'Rákos út', 'F03088', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1838, -47, 47.546404, 19.127534, 'Bánkút utca', 'F03106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1839, -47, 47.550236, 19.125747, 'Kozák tér', 'F03120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1840, -47, 47.443805, 19.130723, 'Batthyány utca', 'F03895', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1841, -47, 47.452826, 19.117436, 'Gomb utca', 'F03898', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1842, -47, 47.441149, 19.134895, 'Zrínyi utca', 'F03913', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1843, -47, 47.420581, 19.072734, 'Sporttelep', 'F04203', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1844, -46, 47.390177, 19.217708, 'Gyál Ady Endre utca', '9195', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1845, -46, 47.383397, 19.228295, 'Bocskai István utca', '9197', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1846, -46, 47.392177, 19.214604, 'Tulipán utca', '9202', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1847, -46, 47.423173, 19.061198, 'Déli utca', '44076', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1848, -46, 47.412085, 19.005088, 'Bíbic utca', '61236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1849, -46, 47.360751, 19.027739, 'Massányi úti lakópark', '64074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1850, -46, 47.346953, 19.023219, 'Miklós tér', '64104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1851, -46, 47.51033, 19.008406, 'Szent János Kórház', 'F00159', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1852, -46, 47.516038, 18.999533, 'Budagyöngye', 'F00164', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1853, -46, 47.516802, 19.024293, 'Ady Endre utca', 'F00205', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1854, -46, 47.521794, 18.997536, 'Harangvirág utca', 'F00280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1855, -46, 47.534771, 18.973917, 'Nyéki út', 'F00329', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1856, -46, 47.553488, 19.021858, 'Királyhelmec utca', 'F00536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1857, -46, 47.556113, 19.018256, 'Judit utca', 'F00537', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1858, -46, 47.548659, 19.027812, 'Bécsi út / Vörösvári út', 'F00566', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1859, -46, 47.479837, 19.000721, 'Zajzon utca', 'F02086', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1860, -46, 47.532219, 19.0673, 'Göncz Árpád városközpont M', 'F02578', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1861, -46, 47.523617, 19.080987, 'Vágány utca / Róbert Károly körút', 'F02631', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1862, -46, 47.509822, 19.143197, 'Vezér utca', 'F02773', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1863, -46, 47.518236, 19.08968, 'Kacsóh Pongrác út', 'F02891', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1864, -46, 47.519064, 19.088114, 'Kacsóh Pongrác út', 'F02893', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1865, -46, 47.513809, 19.095757, 'Ajtósi Dürer sor', 'F02980', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1866, -46, 47.548826, 19.107013, 'Istvántelek vasútállomás (Wysocki utca)', 'F03078', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1867, -46, 47.546216, 19.111009, 'Wysocki utca / Rákos út', 'F03084', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1868, -46, 47.53875, 19.1127, 'Perczel Mór utca', 'F03090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1869, -46, 47.435591, 19.145464, 'Használtcikk piac', 'F03908', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1870, -46, 47.446272, 19.126934, 'Hunyadi utca', 'F03916', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1871, -46, 47.423522, 19.131831, 'Jahn Ferenc Kórház', 'F04083', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1872, -46, 47.439865, 19.118003, 'Nagysándor József utca', 'F04102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1873, -46, 47.432264, 19.11908, 'Székelyhíd utca', 'F04110', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1874, -46, 47.412671, 19.067152, 'Kölcsey utca (Erdősor utca)', 'F04202', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1875, -46, 47.392022, 19.105755, 'Csepel Hollandi út', 'F04228', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1876, -46, 47.410861, 19.076149, 'Posztó utca', 'F04237', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1877, -45, 47.40563, 19.013784, 'Budatétény vasútállomás (Campona)', '8120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1878, -45, 47.495704, 19.160464, 'Kabai utca', '8923', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1879, -45, 47.381846, 19.230727, 'Széchenyi István utca', '9196', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1880, -45, 47.354951, 19.035366, 'Kölcsey Ferenc utca', '64188', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1881, -45, 47.519534, 18.993462, 'Akadémia', 'F00429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1882, -45, 47.519292, 18.994126, 'Akadémia', 'F00432', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1883, -45, 47.577783, 19.114861, 'Káposztásmegyer Szilas-patak', 'F00859', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1884, -45, 47.470602, 19.126533, 'Basa utca', 'F01539', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1885, -45, 47.477683, 19.144512, 'Márga utca', 'F01687', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1886, -45, 47.493596, 19.163631, 'MÁV-telep', 'F01786', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1887, -45, 47.484879, 19.15597, 'Kocka utca', 'F01829', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1888, -45, 47.47458, 18.989847, 'Szent Pio atya tér', 'F02073', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1889, -45, 47.509542, 18.993101, 'Lelesz utca', 'F02369', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1890, -45, 47.545441, 19.095333, 'Reitter Ferenc utca', 'F02547', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1891, -45, 47.513549, 19.093168, 'Vakok Intézete', 'F02713', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1892, -45, 47.517405, 19.130359, 'Mogyoródi út', 'F02790', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1893, -45, 47.519944, 19.091261, 'Mexikói út M', 'F02884', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1894, -45, 47.515934, 19.092973, 'Erzsébet királyné útja aluljáró', 'F02921', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1895, -45, 47.514692, 19.094857, 'Ajtósi Dürer sor', 'F02977', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1896, -45, 47.547116, 19.109615, 'Patyolat utca', 'F03080', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1897, -45, 47.542237, 19.117128, 'Opál utca', 'F03168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1898, -45, 47.408846, 19.19298, 'Kisfaludy utca (Nemes utca)', 'F03649', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1899, -45, 47.413951, 19.172628, 'Pestszentimre felső vasútállomás', 'F03674', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1900, -45, 47.450584, 19.120669, 'Újlaki utca', 'F03918', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1901, -45, 47.451524, 19.127671, 'Zoltán utca', 'F03965', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1902, -45, 47.453237, 19.12115, 'Thököly utca', 'F03967', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1903, -45, 47.444642, 19.137517, 'Kossuth Lajos utca', 'F03981', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1904, -45, 47.427196, 19.12631, 'Királyhágó utca', 'F04074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1905, -45, 47.425323, 19.128958, 'Előd utca', 'F04075', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1906, -45, 47.438068, 19.12899, 'Magyar utca', 'F04097', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1907, -45, 47.440369, 19.116638, 'Szabótelep', 'F04106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1908, -45, 47.438001, 19.120599, 'Mézes utca', 'F04168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1909, -45, 47.4404, 19.12568, 'Zobor utca', 'F04170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1910, -45, 47.445716, 19.109034, 'Thököly utca', 'F04172', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1911, -45, 47.415325, 19.062992, 'Technikus utca', 'F04361', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1912, -44, 47.48294, 19.062984, 'Zsil utca', '8150', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1913, -44, 47.573117, 19.269227, 'Szilasliget Wesselényi utca', '9009', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1914, -44, 47.575273, 19.266059, 'Szilasliget Szondi utca', '9011', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1915, -44, 47.505192, 19.057853, 'Zichy Jenő utca', '9025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1916, -44, 47.402451, 18.937042, 'Petőfi Sándor utca', '9094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1917, -44, 47.404562, 18.933977, 'Pacsirta utca', '9095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1918, -44, 47.350638, 19.016265, 'Szebeni utca', '64105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1919, -44, 47.541449, 19.246632, 'Auchan Liget', '74909', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1920, -44, 47.497853, 19.032981, 'Alagút utca', 'F00082', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1921, -44, 47.525781, 18.984479, 'Bölöni György utca', 'F00323', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1922, -44, 47.546735, 19.032621, 'Óbudai rendelőintézet', 'F00584', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1923, -44, 47.482949, 19.062719, 'Zsil utca', 'F01343', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1924, -44, 47.490048, 19.14908, 'Jászberényi út / Maglódi út', 'F01617', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1925, -44, 47.479562, 19.135307, 'Ihász utca', 'F01659', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1926, -44, 47.469234, 19.160501, 'Bányató utca', 'F01844', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1927, -44, 47.481898, 19.042989, 'Balogh Tihamér utca', 'F02133', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1928, -44, 47.507304, 18.997935, 'Esze Tamás iskola', 'F02324', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1929, -44, 47.517922, 19.086693, 'Bethesda utca', 'F02699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1930, -44, 47.509139, 19.208291, 'Újszász utca', 'F03316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1931, -44, 47.460192, 19.132127, 'Corvin körút', 'F03927', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1932, -44, 47.450852, 19.124501, 'Zalaegerszeg utca', 'F03970', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1933, -44, 47.450059, 19.146464, 'Kossuth Lajos utca', 'F03979', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1934, -44, 47.442463, 19.140656, 'Vas Gereben utca', 'F03989', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1935, -44, 47.447684, 19.149835, 'Vas Gereben utca', 'F04004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1936, -44, 47.452826, 19.142459, 'Templom tér', 'F04021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1937, -44, 47.440418, 19.143543, 'Madarassy László utca', 'F04034', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1938, -44, 47.447641, 19.124934, 'Pannónia út', 'F04037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1939, -44, 47.433042, 19.111711, 'Pöltenberg utca', 'F04044', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1940, -44, 47.429149, 19.123542, 'Jósika utca', 'F04065', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1941, -44, 47.423999, 19.131089, 'Jahn Ferenc Kórház', 'F04082', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1942, -44, 47.417534, 19.14013, 'Írisz utca', 'F04086', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1943, -44, 47.436447, 19.131308, 'Mártírok útja', 'F04090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1944, -44, 47.435211, 19.124598, 'Magyar utca', 'F04094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1945, -44, 47.443407, 19.121415, 'Nagysándor József utca', 'F04101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1946, -44, 47.439645, 19.108656, 'Szent Erzsébet tér', 'F04135', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1947, -44, 47.443178, 19.112624, 'Kossuth Lajos utca', 'F04138', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1948, -44, 47.41993, 19.136715, 'Mesgye utca', 'F04165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1949, -44, 47.425962, 18.969053, 'Mechanikai Művek', 'F04393', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1950, -44, 47.436967, 18.901177, 'Őszibarack utca', 'F04759', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1951, -43, 47.438423, 19.222716, 'Ferihegy vasútállomás', '7927', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1952, -43, 47.599243, 19.346771, 'Gödöllő Szabadság tér H', '9017', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1953, -43, 47.410428, 18.926876, 'Katinka utca', '9099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1954, -43, 47.516092, 18.999188, 'Budagyöngye', 'F00165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1955, -43, 47.514609, 19.033215, 'Apostol utca', 'F00233', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1956, -43, 47.521646, 18.990446, 'Kelemen László utca', 'F00311', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1957, -43, 47.52197, 18.990313, 'Kelemen László utca', 'F00312', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1958, -43, 47.544002, 19.036381, 'Vihar utca', 'F00588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1959, -43, 47.565765, 19.099843, 'Újpesti rendelőintézet', 'F00789', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1960, -43, 47.506099, 19.069534, 'Izabella utca / Király utca', 'F01042', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1961, -43, 47.511101, 19.062753, 'Ferdinánd híd (Izabella utca)', 'F01071', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1962, -43, 47.505939, 19.062406, 'Oktogon M', 'F01082', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1963, -43, 47.507449, 19.067716, 'Andrássy út (Vörösmarty utca M)', 'F01091', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1964, -43, 47.44856, 19.10498, 'Jókai Mór utca / Határ út', 'F01446', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1965, -43, 47.475256, 19.120066, 'Fertő utca / Bihari utca', 'F01537', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1966, -43, 47.48371, 19.144981, 'Csősztorony', 'F01670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1967, -43, 47.483703, 18.995861, 'Orvosi rendelő', 'F02365', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1968, -43, 47.50644, 19.024258, 'Széll Kálmán tér M', 'F02470', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1969, -43, 47.533703, 19.065561, 'Göncz Árpád városközpont M', 'F02602', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1970, -43, 47.514581, 19.097364, 'Korong utca', 'F02873', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1971, -43, 47.517821, 19.093052, 'Erzsébet királyné útja aluljáró', 'F02880', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1972, -43, 47.542678, 19.122233, 'Vasutastelep utca', 'F03104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1973, -43, 47.407826, 19.180974, 'Eke utca', 'F03873', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1974, -43, 47.399367, 19.192186, 'Csolt utca', 'F03876', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1975, -43, 47.455758, 19.117586, 'Álmos utca', 'F03919', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1976, -43, 47.455987, 19.138035, 'Hungária út', 'F03961', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1977, -43, 47.452322, 19.14328, 'Templom tér', 'F04020', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1978, -43, 47.434959, 19.108982, 'Nagysándor József utca', 'F04114', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1979, -43, 47.446161, 19.117481, 'Kossuth Lajos utca', 'F04141', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1980, -43, 47.41305, 19.085902, 'Orgonás utca', 'F04251', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1981, -43, 47.398594, 19.149264, 'Kertészeti Egyetem', 'F04561', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1982, -43, 47.389444, 19.161379, 'Víztorony tér', 'F04562', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1983, -43, 47.390705, 19.1597, 'Tangazdaság (Óvoda)', 'F04566', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1984, -43, 47.377886, 19.219989, 'Bocskai István utca', 'F04651', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1985, -43, 47.554669, 18.927381, 'Rácski telep', 'F04778', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1986, -42, 47.51723, 19.10735, 'Tisza István tér', '8458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1987, -42, 47.519822, 19.103742, 'Uzsoki Utcai Kórház', '8459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1988, -42, 47.399559, 18.941035, 'Homokbánya utca', '8479', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1989, -42, 47.376882, 19.124303, 'MNB', '9312', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1990, -42, 47.480202, 19.29187, 'Rés utca', '43556', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1991, -42, 47.411172, 18.993786, 'XIII. utca / Dózsa György út', '62522', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1992, -42, 47.523636, 18.994268, 'Pasaréti tér', 'F00276', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1993, -42, 47.545957, 19.033456, 'Óbudai rendelőintézet', 'F00586', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1994, -42, 47.567889, 19.096828, 'Szent László tér', 'F00781', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1995, -42, 47.505894, 19.062738, 'Oktogon M', 'F01083', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1996, -42, 47.451814, 19.109439, 'Mártírok útja / Határ út', 'F01450', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1997, -42, 47.472267, 19.12424, 'Hízlaló tér', 'F01637', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1998, -42, 47.485907, 19.142079, 'Ihász utca', 'F01668', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (1999, -42, 47.483671, 19.149068, 'Téglavető utca', 'F01672', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2000, -42, 47.487031, 19.153055, 'Gitár utca', 'F01673', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2001, -42, 47.482709, 19.158738, 'Kada utca / Maglódi út', 'F01679', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2002, -42, 47.477404, 19.165878, 'Venyige utca', 'F01708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2003, -42, 47.479656, 19.162725, 'Bajcsy-Zsilinszky Kórház', 'F01834', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2004, -42, 47.479773, 19.162739, 'Bajcsy-Zsilinszky Kórház', 'F01836', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2005, -42, 47.471032, 19.001179, 'Frankhegy utca', 'F02058', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2006, -42, 47.473805, 19.007505, 'Bereck utca', 'F02093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2007, -42, 47.542783, 19.082128, 'Násznagy utca', 'F02557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2008, -42, 47.548968, 19.090355, 'Angyalföld vasútállomás', 'F02654', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2009, -42, 47.508835, 19.094491, 'Stefánia út / Thököly út', 'F02718', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2010, -42, 47.541418, 19.171851, 'Besztercebányai utca', 'F02981', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2011, -42, 47.5161, 19.171354, 'Budapesti út', 'F03250', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2012, -42, 47.405035, 19.198018, 'Szigeti Kálmán utca', 'F03655', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2013, -42, 47.396601, 19.195822, 'Paula utca', 'F03658', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2014, -42, 47.392889, 19.200712, 'Kalász utca', 'F03778', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2015, -42, 47.41051, 19.177377, 'Bethlen Gábor utca', 'F03819', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2016, -42, 47.46144, 19.153037, 'Wesselényi utca', 'F03943', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2017, -42, 47.44656, 19.134763, 'Batthyány utca', 'F04024', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2018, -42, 47.437107, 19.112246, 'Szent Imre herceg utca', 'F04109', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2019, -42, 47.448934, 19.113533, 'Szigligeti utca', 'F04174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2020, -42, 47.560478, 18.918399, 'Erdészház', 'F04780', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2021, -42, 47.550038, 18.933759, 'BM iskola', 'F04821', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2022, -41, 47.414805, 19.134083, 'Sósmocsár út', '9568', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2023, -41, 47.477046, 19.295809, 'Sport tér', '43555', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2024, -41, 47.352943, 19.024778, 'Ősz utca', '64107', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2025, -41, 47.532615, 19.036517, 'Katinyi mártírok parkja', 'F00598', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2026, -41, 47.495371, 19.052782, 'Szervita tér', 'F00982', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2027, -41, 47.472668, 19.16206, 'Sibrik Miklós út', 'F01700', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2028, -41, 47.470813, 19.16451, 'Újhegyi sétány', 'F01842', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2029, -41, 47.471521, 19.010544, 'Előpatak utca', 'F02246', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2030, -41, 47.508374, 19.171854, 'Thököly út', 'F03273', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2031, -41, 47.491708, 19.281891, 'Rákoscsaba vasútállomás', 'F03442', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2032, -41, 47.423353, 19.189477, 'Péterhalmi út', 'F03642', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2033, -41, 47.401647, 19.202512, 'Kapocs utca', 'F03725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2034, -41, 47.417509, 19.168144, 'Zöldségpiac', 'F03872', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2035, -40, 47.548817, 19.029722, 'Bécsi út / Vörösvári út', '3105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2036, -40, 47.543264, 18.963995, 'Hűvösvölgy', '7976', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2037, -40, 47.404303, 19.185682, 'Pestszentimre vasútáll. (Nagykőrösi út)', '8950', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2038, -40, 47.441996, 19.102521, 'János tér', '9582', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2039, -40, 47.475377, 19.297911, 'Enying utca', '43554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2040, -40, 47.51194, 19.008418, 'Vasas sportpálya', 'F00288', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2041, -40, 47.532525, 19.036716, 'Katinyi mártírok parkja', 'F00600', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2042, -40, 47.51441, 19.06829, 'Bajza utca', 'F01104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2043, -40, 47.481834, 19.151492, 'Kocka utca', 'F01837', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2044, -40, 47.469885, 19.012708, 'Sümegvár utca', 'F02094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2045, -40, 47.485258, 18.994028, 'Hajnalka utca', 'F02278', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2046, -40, 47.503497, 19.016043, 'Határőr út', 'F02405', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2047, -40, 47.512067, 19.167476, 'Sasvár utca', 'F03366', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2048, -40, 47.514557, 19.191994, 'Gida utca', 'F03407', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2049, -40, 47.418773, 19.182732, 'Kettős-Körös utca', 'F03643', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2050, -40, 47.419841, 19.165194, 'Közdűlő út', 'F03680', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2051, -40, 47.44237, 18.901561, 'Idősek otthona', 'F04763', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2052, -39, 47.643897, 19.030357, 'Pomáz H', '8434', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2053, -39, 47.417218, 19.131105, 'Szentlőrinci úti lakótelep', '8966', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2054, -39, 47.478899, 19.278173, 'Öntöző utca', '66717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2055, -39, 47.570868, 18.931415, 'Muflon utca', '72046', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2056, -39, 47.49011, 19.047195, 'Rudas Gyógyfürdő', '72934', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2057, -39, 47.537279, 19.014083, 'Nyereg út', 'F00120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2058, -39, 47.560509, 19.010945, 'Jablonka út 73.', 'F00543', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2059, -39, 47.554504, 19.020369, 'Verhovina utca', 'F00674', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2060, -39, 47.510112, 19.062341, 'Ferdinánd híd (Izabella utca)', 'F01068', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2061, -39, 47.477251, 19.165944, 'Venyige utca', 'F01709', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2062, -39, 47.484708, 19.155969, 'Kocka utca', 'F01832', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2063, -39, 47.445586, 19.028223, 'Állvány utca', 'F02265', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2064, -39, 47.487942, 19.004163, 'Kempelen Farkas utca', 'F02284', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2065, -39, 47.499223, 19.009011, 'Szent Orbán tér', 'F02399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2066, -39, 47.507087, 19.104422, 'Egressy út / Hungária körút', 'F02739', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2067, -39, 47.504277, 19.137042, 'Örs vezér tere M+H', 'F02763', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2068, -39, 47.519886, 19.188251, 'Sasvár utca', 'F03233', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2069, -39, 47.511417, 19.176932, 'Margit utca', 'F03248', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2070, -39, 47.517715, 19.190874, 'Margit utca', 'F03339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2071, -39, 47.509591, 19.170457, 'Sashalmi tér', 'F03367', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2072, -39, 47.404483, 19.185841, 'Pestszentimre vasútállomás (Vasút utca)', 'F03665', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2073, -39, 47.422291, 19.162006, 'Kamiontelep', 'F03682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2074, -39, 47.41763, 19.076907, 'Cseh Mihály utca', 'F04340', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2075, -38, 47.46601, 19.103474, 'Gyáli út', '8589', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2076, -38, 47.440692, 19.102002, 'Szakorvosi rendelő', '8898', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2077, -38, 47.348602, 19.044724, 'Szigetszentmiklós városháza', '9292', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2078, -38, 47.41793, 19.017589, 'Római utca', '9456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2079, -38, 47.482631, 19.274063, 'Szabadság sugárút / Péceli út', '66713', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2080, -38, 47.481053, 19.275794, 'Nyitány utca', '66715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2081, -38, 47.501917, 19.027405, 'Korlát utca', 'F00091', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2082, -38, 47.514243, 19.007221, 'Júlia utca', 'F00286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2083, -38, 47.565105, 19.107802, 'Görgey Artúr út', 'F00853', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2084, -38, 47.492934, 19.056007, 'Ferenciek tere M', 'F00983', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2085, -38, 47.509806, 19.057151, 'Nyugati pályaudvar M', 'F01029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2086, -38, 47.515338, 19.060178, 'Csanády utca (Lehel tér M)', 'F01066', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2087, -38, 47.503133, 19.060919, 'Opera M', 'F01075', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2088, -38, 47.490273, 19.090204, 'Orczy tér', 'F01220', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2089, -38, 47.511344, 19.099156, 'Zugló vasútállomás', 'F02732', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2090, -38, 47.51465, 19.145197, 'Újváros park', 'F02934', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2091, -38, 47.539491, 19.174184, 'Gusztáv utca', 'F03210', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2092, -38, 47.51429, 19.173527, 'Sasvár utca', 'F03355', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2093, -38, 47.422865, 19.240636, 'Repülőtéri Rendőr Igazgatóság', 'F03780', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2094, -38, 47.43257, 19.103028, 'Nagysándor József utca', 'F04054', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2095, -38, 47.431154, 19.125666, 'Wesselényi utca', 'F04068', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2096, -38, 47.407672, 18.962853, 'Szerafin-villa', 'F04482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2097, -37, 47.521615, 19.101317, 'Erzsébet királyné útja / Róna utca', '8461', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2098, -37, 47.43551, 19.145159, 'Használtcikk piac', '9074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2099, -37, 47.510038, 19.02176, 'Ezredes utca', '9413', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2100, -37, 47.430794, 19.005802, 'Jégmadár utca', '62513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2101, -37, 47.582618, 19.015383, 'Ürömhegyi út / Jutas utca [8]', '64377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2102, -37, 47.476781, 19.280538, 'Pestvidék utca', '66719', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2103, -37, 47.473524, 19.095031, 'Albert Flórián út', '86857', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2104, -37, 47.512402, 19.0796, 'Benczúr utca', '91338', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2105, -37, 47.486754, 19.056841, 'Fővám tér M', 'F00918', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2106, -37, 47.503249, 19.06583, 'Király utca / Erzsébet körút', 'F01035', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2107, -37, 47.515569, 19.075897, 'Hősök tere M', 'F01051', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2108, -37, 47.518978, 19.072009, 'Vágány utca / Dózsa György út', 'F01059', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2109, -37, 47.503877, 19.072507, 'Dob utca', 'F01119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2110, -37, 47.492873, 19.087298, 'Magdolna utca', 'F01215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2111, -37, 47.496364, 19.083399, 'Dologház utca', 'F01310', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2112, -37, 47.467085, 19.111844, 'Börzsöny utca', 'F01363', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2113, -37, 47.479621, 19.067242, 'Boráros tér H', 'F01376', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2114, -37, 47.444292, 19.09653, 'Gubacsi út / Határ út', 'F01437', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2115, -37, 47.466609, 19.005401, 'Nagyszeben tér', 'F02055', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2116, -37, 47.507177, 19.10405, 'Egressy út / Hungária körút', 'F02743', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2117, -37, 47.566693, 19.128827, 'Kossuth utca lakótelep', 'F03035', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2118, -37, 47.518122, 19.181077, 'Sasvár utca', 'F03354', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2119, -37, 47.425254, 19.158248, 'Szentlőrinci út', 'F03631', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2120, -37, 47.426551, 19.2365, 'Repülőtér D porta', 'F03782', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2121, -37, 47.429087, 19.106099, 'Tinódi utca', 'F04056', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2122, -37, 47.439468, 19.103486, 'Ady Endre utca', 'F04128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2123, -37, 47.424426, 19.111157, 'Akácfa utca', 'F04157', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2124, -37, 47.381717, 19.214241, 'Somogyi Béla utca', 'F04652', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2125, -36, 47.421208, 19.013469, 'Hűség utca', '9458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2126, -36, 47.441881, 19.032202, 'Albertfalva vasútállomás', '9524', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2127, -36, 47.503868, 19.022427, 'Maros utcai rendelőintézet', '72851', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2128, -36, 47.53049, 18.978816, 'Lipótmezei út', 'F00327', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2129, -36, 47.536739, 18.975395, 'Völgy utca', 'F00347', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2130, -36, 47.585487, 18.99759, 'Tücsök utca', 'F00433', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2131, -36, 47.566424, 19.049798, 'Aquincum H', 'F00668', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2132, -36, 47.563422, 19.1097, 'Kiss János utca', 'F00841', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2133, -36, 47.503033, 19.066188, 'Király utca / Erzsébet körút', 'F01038', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2134, -36, 47.509397, 19.083009, 'Damjanich utca / Dózsa György út', 'F01137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2135, -36, 47.50641, 19.08622, 'Ötvenhatosok tere', 'F01140', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2136, -36, 47.480043, 19.066075, 'Boráros tér H', 'F01518', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2137, -36, 47.51583, 19.143725, 'Zsálya utca', 'F02782', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2138, -36, 47.52449, 19.157026, 'Rákóczi út', 'F03265', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2139, -36, 47.520603, 19.158559, 'Szent Korona utca', 'F03364', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2140, -36, 47.432005, 19.16609, 'Csíky utca', 'F03893', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2141, -36, 47.433984, 19.097674, 'Pesterzsébet vasútállomás', 'F04121', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2142, -36, 47.42446, 19.002705, 'Brassói utca', 'F04437', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2143, -36, 47.413142, 19.017993, 'Tűztorony', 'F04451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2144, -36, 47.405897, 19.005292, 'Rákóczi út', 'F04461', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2145, -36, 47.382655, 19.126761, 'Polimer', 'F04553', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2146, -36, 47.388167, 19.207427, 'Gyál felső vasútállomás', 'F04601', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2147, -36, 47.384419, 19.211561, 'Ady Endre utca', 'F04606', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2148, -36, 47.590141, 18.987441, 'Külső Bécsi út 35.', 'F04774', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2149, -35, 47.53094, 19.171561, 'György utca', '43341', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2150, -35, 47.487256, 19.286582, 'Csaba vezér tér', '43548', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2151, -35, 47.517347, 19.012436, 'Detrekő utca', 'F00299', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2152, -35, 47.51536, 19.014522, 'Balogvár utca', 'F00300', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2153, -35, 47.528081, 18.981422, 'Szerb Antal utca', 'F00325', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2154, -35, 47.548119, 18.949719, 'Turul utca', 'F00366', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2155, -35, 47.572851, 19.016226, 'Bóbita utca', 'F00630', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2156, -35, 47.501645, 19.018128, 'Csemegi utca', 'F02488', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2157, -35, 47.532113, 19.176398, 'Rákosszentmihály Csömöri út', 'F03225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2158, -35, 47.528034, 19.180465, 'Mátyás király utca 71.', 'F03228', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2159, -35, 47.517974, 19.161209, 'Rákosi út', 'F03267', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2160, -35, 47.532939, 19.1696, 'Baross utca', 'F03350', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2161, -35, 47.526273, 19.155264, 'Baross utca', 'F03362', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2162, -35, 47.506482, 19.211007, 'Petőfi tér', 'F03400', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2163, -35, 47.427323, 19.108006, 'Klapka utca', 'F04058', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2164, -35, 47.419341, 19.116677, 'Vágóhíd utca', 'F04077', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2165, -35, 47.435098, 19.10026, 'Pesterzsébet Kossuth Lajos utca', 'F04119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2166, -35, 47.421942, 19.113845, 'Lehel utca', 'F04162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2167, -35, 47.408167, 19.082149, 'Varrógép utca', 'F04240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2168, -35, 47.428123, 19.008508, 'Liszt Ferenc út', 'F04429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2169, -35, 47.407054, 18.998189, 'Szent László utca', 'F04470', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2170, -34, 47.50194, 19.136228, 'Örs vezér tere M+H', '8021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2171, -34, 47.525692, 19.169817, 'Szent Korona utca', '9238', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2172, -34, 47.506881, 19.023541, 'Széll Kálmán tér M', '9730', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2173, -34, 47.375244, 19.043158, 'Leshegy Ipari Park', '69195', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2174, -34, 47.500188, 19.079221, 'Munkás utca', 'F01172', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2175, -34, 47.4931, 19.121005, 'Vásárközpont főbejárat', 'F01558', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2176, -34, 47.448158, 19.02508, 'Narancs utca', 'F01944', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2177, -34, 47.528479, 19.153104, 'Rákospalotai határút', 'F02989', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2178, -34, 47.523226, 19.185246, 'Mátyásföldi tér', 'F03230', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2179, -34, 47.520853, 19.167647, 'Rákosi út', 'F03256', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2180, -34, 47.525967, 19.162533, 'Csömöri út', 'F03261', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2181, -34, 47.526017, 19.182451, 'Ida utca', 'F03353', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2182, -34, 47.523509, 19.164984, 'Szent Korona utca', 'F03360', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2183, -34, 47.518744, 19.153224, 'Szent Korona utcai lakótelep', 'F03371', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2184, -34, 47.424265, 19.12335, 'Frangepán utca', 'F04164', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2185, -34, 47.41485, 19.015673, 'Rizling utca (Sportpálya)', 'F04450', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2186, -34, 47.403647, 19.001531, 'Tátra utca', 'F04512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2187, -34, 47.440775, 18.913923, 'Deák Ferenc utca', 'F04750', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2188, -33, 47.473628, 19.079643, 'Vágóhíd utca', '9146', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2189, -33, 47.528249, 19.167313, 'Csömöri út', '9239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2190, -33, 47.430955, 18.92834, 'Liliom utca', '48305', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2191, -33, 47.504372, 19.024988, 'Körmöci utca', 'F00028', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2192, -33, 47.514371, 19.015505, 'Gondozási Központ', 'F00372', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2193, -33, 47.562786, 18.94609, 'Máriaremetei kegytemplom', 'F00380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2194, -33, 47.566112, 18.942934, 'Köztársaság utca', 'F00382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2195, -33, 47.50304, 19.076581, 'Rottenbiller utca / István utca', 'F01122', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2196, -33, 47.507177, 19.077751, 'Nefelejcs utca / Damjanich utca', 'F01124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2197, -33, 47.504532, 19.080365, 'Nefelejcs utca / István utca', 'F01129', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2198, -33, 47.492425, 19.083516, 'Teleki László tér (Szerdahelyi utca)', 'F01211', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2199, -33, 47.473151, 19.080293, 'Vágóhíd utca', 'F01399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2200, -33, 47.468179, 19.101048, 'Merényi Gusztáv Kórház', 'F01477', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2201, -33, 47.470506, 18.994675, 'Tűzkő utca', 'F02068', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2202, -33, 47.537837, 19.16479, 'Rákospalotai határút', 'F02984', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2203, -33, 47.535883, 19.166698, 'Gusztáv utca', 'F03219', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2204, -33, 47.520761, 19.151183, 'Csömöri út', 'F03279', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2205, -33, 47.530195, 19.178305, 'Szent Korona utca', 'F03343', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2206, -33, 47.52409, 19.150326, 'Baross utca', 'F03369', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2207, -33, 47.503122, 19.214395, 'Zsemlékes út', 'F03402', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2208, -33, 47.464809, 19.127094, 'Határ út M', 'F04015', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2209, -33, 47.427982, 19.11971, 'Wesselényi utca', 'F04063', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2210, -33, 47.401354, 19.003838, 'I. utca', 'F04468', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2211, -33, 47.409212, 18.996081, 'Bem tábornok utca', 'F04473', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2212, -33, 47.383681, 19.12569, 'Ócsai úti felüljáró', 'F04571', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2213, -32, 47.410696, 19.020671, 'Jókai Mór utca', '7995', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2214, -32, 47.509134, 19.148798, 'Zsivora park', '40608', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2215, -32, 47.415027, 18.951217, 'Diósd Kaktusz utca', '98518', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2216, -32, 47.501307, 19.036072, 'Donáti utca', 'F00045', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2217, -32, 47.527298, 18.954068, 'Szépjuhászné Gyermekvasút', 'F00179', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2218, -32, 47.519178, 19.00099, 'Virág árok', 'F00282', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2219, -32, 47.526078, 18.985992, 'Zuhatag sor', 'F00314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2220, -32, 47.528541, 18.983692, 'Nagyhíd', 'F00316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2221, -32, 47.552211, 19.023785, 'Zúzmara utca', 'F00672', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2222, -32, 47.507843, 19.076026, 'Reformáció park', 'F01047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2223, -32, 47.4815, 18.998888, 'Márton Áron tér', 'F02083', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2224, -32, 47.51137, 19.099458, 'Zugló vasútállomás', 'F02729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2225, -32, 47.505132, 19.136777, 'Örs vezér tere M+H', 'F02760', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2226, -32, 47.56086, 19.130219, 'Rákosmező utca 39.', 'F03043', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2227, -32, 47.515435, 19.163752, 'Budapesti út', 'F03270', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2228, -32, 47.40126, 19.103261, 'Ladik utca', 'F04224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2229, -32, 47.38945, 19.120281, 'Zsellér dűlő', 'F04550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2230, -32, 47.596405, 18.978165, 'Pilisborosjenő Téglagyári tér', 'F04776', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2231, -31, 47.483885, 19.127207, 'Kőbánya alsó vasútállomás', '8379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2232, -31, 47.501636, 19.077828, 'Péterfy Sándor utca', '62942', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2233, -31, 47.548944, 18.951784, 'Turul utca (kishíd)', 'F00370', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2234, -31, 47.568772, 18.940391, 'Honfoglalás utca', 'F00384', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2235, -31, 47.572304, 18.944432, 'Örökzöld utca', 'F00398', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2236, -31, 47.559154, 18.965664, 'Mikszáth Kálmán utca', 'F00404', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2237, -31, 47.556066, 19.011267, 'Hedvig utca', 'F00551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2238, -31, 47.475906, 19.077636, 'Haller utca / Mester utca', 'F01394', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2239, -31, 47.468587, 19.003357, 'Regős köz', 'F02243', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2240, -31, 47.512878, 18.989733, 'Kiss Áron utca', 'F02362', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2241, -31, 47.507477, 19.150348, 'Ond vezér útja / Szentmihályi út', 'F02771', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2242, -31, 47.405155, 19.085275, 'Tihanyi utca', 'F04243', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2243, -31, 47.393171, 19.094367, 'Csorbatói utca', 'F04346', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2244, -30, 47.515894, 18.999506, 'Budagyöngye', '8003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2245, -30, 47.471354, 19.073301, 'Müpa - Nemzeti Színház H', '8240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2246, -30, 47.64557, 19.027226, 'Pomáz Dózsa György utca', '9170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2247, -30, 47.4626, 19.084411, 'Hentes utca', '40526', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2248, -30, 47.531796, 18.980992, 'Vadaskerti utca', 'F00318', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2249, -30, 47.570567, 19.018314, 'ATI', 'F00617', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2250, -30, 47.489888, 19.085916, 'Kálvária tér', 'F01221', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2251, -30, 47.471255, 19.072969, 'Müpa - Nemzeti Színház H', 'F01397', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2252, -30, 47.510889, 19.147261, 'Füredi utca / Szentmihályi út', 'F02775', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2253, -30, 47.415131, 19.186915, 'Szálfa utca', 'F03646', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2254, -30, 47.413772, 19.188025, 'Törvény utca', 'F03688', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2255, -30, 47.464575, 19.127293, 'Határ út M', 'F04014', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2256, -29, 47.542707, 18.964355, 'Hűvösvölgy', '7978', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2257, -29, 47.593086, 19.107022, 'Íves út', '44135', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2258, -29, 47.417572, 18.985339, 'Bartók Béla út 208.', '83329', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2259, -29, 47.493536, 19.044169, 'Várkert Bazár', 'F00106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2260, -29, 47.527437, 19.007649, 'Tömörkény utca', 'F00267', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2261, -29, 47.490883, 19.051614, 'Március 15. tér', 'F00999', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2262, -29, 47.465989, 19.081497, 'Koppány utca', 'F01342', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2263, -29, 47.455706, 19.113714, 'Nagykőrösi út / Határ út', 'F01453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2264, -29, 47.483922, 19.127534, 'Kőbánya alsó vasútállomás', 'F01803', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2265, -29, 47.525882, 19.229179, 'Ilonatelep H', 'F03333', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2266, -29, 47.430206, 19.182735, 'Obszervatórium', 'F03635', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2267, -29, 47.401485, 19.087617, 'Fátra utca', 'F04245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2268, -29, 47.398285, 19.098476, 'Árnyas utca', 'F04260', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2269, -29, 47.398147, 19.090331, 'Orsova utca', 'F04348', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2270, -28, 47.542968, 18.964195, 'Hűvösvölgy', '7977', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2271, -28, 47.467702, 19.076549, 'Közvágóhíd H', '9703', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2272, -28, 47.391331, 19.118615, 'Zsellér dűlő', '49171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2273, -28, 47.435693, 18.918493, 'Nyár utca', '62604', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2274, -28, 47.553495, 19.013993, 'Erdőalja út 43.', 'F00553', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2275, -28, 47.535745, 19.033674, 'Selmeci utca', 'F00684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2276, -28, 47.500325, 19.068948, 'Wesselényi utca / Erzsébet körút', 'F01111', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2277, -28, 47.503495, 19.089549, 'Reiner Frigyes park', 'F01149', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2278, -28, 47.494099, 19.077373, 'II. János Pál pápa tér M', 'F01302', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2279, -28, 47.49041, 19.12136, 'Pongrácz úti lakótelep', 'F01560', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2280, -28, 47.48664, 19.124062, 'Kistorony park', 'F01726', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2281, -28, 47.410723, 19.190543, 'Vezér utca', 'F03648', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2282, -28, 47.395535, 19.092446, 'Uzsoki utca', 'F04344', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2283, -27, 47.566232, 19.021931, 'Óbudai temető', 'F00522', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2284, -27, 47.500714, 19.046173, 'Széchenyi István tér', 'F00974', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2285, -27, 47.50019, 19.06916, 'Wesselényi utca / Erzsébet körút', 'F01113', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2286, -27, 47.46037, 19.08246, 'Beöthy utca', 'F01351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2287, -27, 47.465426, 19.078392, 'Koppány utca', 'F01356', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2288, -27, 47.467684, 19.076523, 'Közvágóhíd H', 'F01404', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2289, -27, 47.481672, 19.128791, 'Liget tér', 'F01639', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2290, -27, 47.497564, 19.116008, 'Hős utca', 'F01744', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2291, -27, 47.476948, 19.132353, 'Kelemen utca', 'F01794', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2292, -27, 47.477581, 19.002766, 'Tömös utca', 'F02089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2293, -27, 47.500357, 18.989195, 'Városkút', 'F02319', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2294, -27, 47.506728, 19.022811, 'Széll Kálmán tér M (Csaba utca)', 'F02332', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2295, -27, 47.483508, 19.289563, 'Alsódabas utca', 'F03488', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2296, -27, 47.419263, 19.091101, 'Szebeni utca', 'F04217', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2297, -27, 47.408845, 19.078308, 'Fémmű utca', 'F04236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2298, -27, 47.39768, 18.976752, 'Nagytétény-Diósd vasútállomás', 'F04491', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2299, -27, 47.507327, 18.921675, 'Budakeszi Dózsa György tér', 'F04790', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2300, -26, 47.520111, 19.021914, 'Eszter utca', 'F00209', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2301, -26, 47.536311, 19.033395, 'Selmeci utca', 'F00686', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2302, -26, 47.492039, 19.079017, 'Mátyás tér', 'F01210', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2303, -26, 47.483437, 19.126485, 'Kőbánya alsó vasútállomás', 'F01569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2304, -26, 47.484779, 19.21719, 'Jászberényi út', 'F01721', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2305, -26, 47.456334, 19.02379, 'Hajtány sor', 'F01948', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2306, -26, 47.484007, 19.278131, 'Péceli út', 'F03479', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2307, -26, 47.432826, 19.149011, 'Autópiac', 'F03888', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2308, -26, 47.41633, 19.093232, 'Határ utca', 'F04362', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2309, -26, 47.404333, 19.006644, 'Tűzliliom utca', 'F04459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2310, -26, 47.394173, 18.978903, 'Nagytétényi temető', 'F04493', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2311, -26, 47.400818, 19.121886, 'Soroksár vasútállomás', 'F04545', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2312, -25, 47.40572, 19.013837, 'Budatétény vasútállomás (Campona)', '7996', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2313, -25, 47.435802, 19.036578, 'Ady Endre út', '8128', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2314, -25, 47.424593, 19.010866, 'Komáromi út', '9460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2315, -25, 47.562427, 18.963254, 'Községház utca', 'F00402', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2316, -25, 47.475627, 19.069788, 'Haller utca / Soroksári út', 'F01386', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2317, -25, 47.442053, 19.09499, 'Határ út', 'F01440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2318, -25, 47.512176, 19.204251, 'Hunyadvár utca', 'F03320', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2319, -25, 47.390669, 19.096418, 'Csepel Királyerdő út', 'F04247', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2320, -25, 47.39528, 19.100433, 'Sólyom utca', 'F04262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2321, -25, 47.427087, 19.039165, 'Városház tér', 'F04405', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2322, -24, 47.426744, 19.16114, 'Dél-pesti autóbuszgarázs', '8472', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2323, -24, 47.358296, 19.031884, 'Szigetszentmiklós Szabadság utca', '64075', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2324, -24, 47.459683, 19.111743, 'Epreserdő utca', 'F01366', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2325, -24, 47.483442, 19.022848, 'Zólyomi út', 'F02111', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2326, -23, 47.411737, 18.989837, 'Török utca', '9449', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2327, -23, 47.524347, 19.023001, 'Pusztaszeri út', 'F00219', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2328, -23, 47.516193, 19.004525, 'Gábor Áron utca / Pasaréti út', 'F00284', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2329, -23, 47.495569, 19.048402, 'Vigadó tér', 'F00966', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2330, -23, 47.470226, 19.08276, 'Mester utca / Könyves Kálmán körút', 'F01507', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2331, -23, 47.514093, 19.202932, 'Veres Péter út', 'F03323', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2332, -23, 47.454911, 18.908275, 'Légimentők', 'F04742', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2333, -22, 47.407239, 18.930884, 'Nóra utca', '9097', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2334, -22, 47.435414, 18.918706, 'Nyár utca', '75119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2335, -22, 47.57703, 18.938375, 'Kökörcsin utca', 'F00117', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2336, -22, 47.557275, 19.100685, 'Ősz utca', 'F00891', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2337, -22, 47.495319, 19.240607, 'Vidor utca', 'F03449', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2338, -22, 47.490548, 19.243456, 'Gyöngytyúk utca', 'F03453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2339, -22, 47.411831, 19.097892, 'Hollandi csárda', 'F04341', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2340, -21, 47.517118, 19.036533, 'Szent Lukács Gyógyfürdő', 'F00239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2341, -21, 47.494165, 19.06024, 'Astoria M', 'F01015', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2342, -21, 47.511524, 19.056686, 'Nyugati pályaudvar M', 'F01023', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2343, -21, 47.448322, 19.094227, 'Fegyvergyár utca', 'F01358', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2344, -21, 47.44863, 19.090422, 'Soroksári út 158.', 'F01359', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2345, -21, 47.453621, 19.091248, 'Illatos út', 'F01424', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2346, -21, 47.451831, 19.092254, 'Timót utca', 'F01428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2347, -21, 47.468103, 19.108291, 'Közterületfenntartó Zrt.', 'F01486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2348, -21, 47.440582, 19.012568, 'Tordai út', 'F04370', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2349, -21, 47.459493, 18.946683, 'Lévai utca', 'F04805', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2350, -21, 47.444064, 18.910944, 'Jókai Mór utca', 'F04813', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2351, -20, 47.475726, 19.069363, 'Haller utca / Soroksári út', '9041', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2352, -20, 47.508153, 19.192136, 'Hunyadvár utca', '9373', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2353, -20, 47.497665, 19.041541, 'Clark Ádám tér', '9675', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2354, -20, 47.501306, 19.033896, 'Szentháromság tér', 'F00072', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2355, -20, 47.53214, 19.029051, 'Mikoviny utca', 'F00609', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2356, -20, 47.550117, 19.028768, 'Bécsi út / Vörösvári út', 'F00680', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2357, -20, 47.49412, 19.060147, 'Astoria M', 'F01013', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2358, -20, 47.457319, 19.089142, 'Kén utca', 'F01350', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2359, -20, 47.459702, 19.087871, 'Magyar Aszfalt', 'F01413', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2360, -20, 47.454351, 19.087283, 'Kén utca H', 'F01419', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2361, -20, 47.451661, 19.08874, 'Timót utca / Soroksári út', 'F01429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2362, -20, 47.444311, 19.092818, 'Szabadkai út', 'F01431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2363, -20, 47.502828, 19.00585, 'Pethényi út', 'F02395', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2364, -20, 47.423511, 19.104901, 'Torontál utca H', 'F04050', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2365, -20, 47.436593, 19.095158, 'Csepeli átjáró', 'F04147', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2366, -20, 47.487957, 19.341194, 'Kossuth tér', 'F04592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2367, -20, 47.447084, 18.909266, 'Vasút utca', 'F04748', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2368, -19, 47.555809, 19.026945, 'Orbán Balázs út', 'F00518', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2369, -19, 47.482184, 19.030171, 'Alsóhegy utca', 'F02119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2370, -19, 47.506484, 19.181114, 'Garat utca', 'F03345', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2371, -19, 47.4267, 19.039244, 'Városház tér', 'F04406', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2372, -19, 47.588091, 18.937089, 'Munkás utca', 'F04699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2373, -19, 47.455653, 18.9607, 'Csata utca', 'F04728', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2374, -18, 47.628444, 19.043367, 'Szentistvántelep H', '8432', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2375, -18, 47.406773, 18.991631, 'Görgey utca', '9448', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2376, -18, 47.487779, 19.061075, 'Török Pál utca', 'F01400', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2377, -18, 47.508284, 19.180243, 'Sashalom H', 'F03242', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2378, -18, 47.499019, 19.23851, 'Cinkotai autóbuszgarázs', 'F03287', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2379, -18, 47.463578, 19.297577, 'Rózsaszál utca', 'F03585', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2380, -18, 47.388092, 19.103472, 'Csepel Horgásztanya', 'F04180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2381, -17, 47.463444, 19.109691, 'Csengettyű utca', 'F01526', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2382, -17, 47.478443, 19.031832, 'Diószegi út (Vérellátó Szolgálat)', 'F02121', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2383, -17, 47.397661, 19.106622, 'Csepel Soroksári rév', 'F04227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2384, -16, 47.559063, 19.078803, 'Újpest-városkapu M', '11762', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2385, -16, 47.483123, 19.127809, 'Kőbánya alsó vasútállomás', '48277', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2386, -16, 47.562554, 19.023634, 'Kubik utca', 'F00549', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2387, -16, 47.539891, 19.031348, 'Szent Margit Kórház', 'F00596', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2388, -16, 47.567333, 19.049399, 'Aquincum H', 'F00666', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2389, -16, 47.556218, 19.088846, 'Tél utca / Pozsonyi utca', 'F00818', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2390, -16, 47.468472, 19.108092, 'Közterületfenntartó Zrt.', 'F01481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2391, -16, 47.495377, 18.964569, 'Sötétvágás utca', 'F02456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2392, -16, 47.517093, 19.061082, 'Lehel tér M', 'F02618', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2393, -16, 47.504727, 19.136883, 'Örs vezér tere M+H', 'F02761', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2394, -16, 47.477178, 19.292758, 'Regélő utca', 'F03494', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2395, -16, 47.474171, 19.294045, 'Császárfa utca', 'F03603', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2396, -15, 47.419788, 19.155599, 'Soroksár Auchan áruház', '44388', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2397, -15, 47.430124, 18.93517, 'Törökbálint Telenor', '98527', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2398, -15, 47.560099, 19.024711, 'Bojtár utca (Bécsi út)', 'F00520', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2399, -15, 47.540646, 19.03091, 'Szent Margit Kórház', 'F00594', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2400, -15, 47.485108, 19.062308, 'Czuczor utca', 'F01337', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2401, -15, 47.483337, 19.127984, 'Kőbánya alsó vasútállomás', 'F01801', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2402, -15, 47.495806, 19.011071, 'Nárcisz utca', 'F02425', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2403, -15, 47.504556, 19.136936, 'Örs vezér tere M+H', 'F02762', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2404, -15, 47.407486, 19.100114, 'Strandfürdő', 'F04220', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2405, -14, 47.501778, 19.135922, 'Örs vezér tere M+H', '8025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2406, -14, 47.429726, 19.032323, 'Plébánia utca', '9465', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2407, -14, 47.499328, 19.040161, 'Clark Ádám tér', '49785', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2408, -14, 47.493513, 19.011935, 'Németvölgyi út', 'F02291', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2409, -14, 47.563496, 19.115618, 'Hubay Jenő tér', 'F03050', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2410, -14, 47.404013, 19.101502, 'Csepel csónakház', 'F04222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2411, -14, 47.459073, 18.939297, 'Patkó utca', 'F04713', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2412, -13, 47.501742, 19.135604, 'Örs vezér tere M+H', '8030', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2413, -13, 47.434041, 19.021173, 'Tatárka utca', '9445', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2414, -13, 47.47373, 19.063592, 'Egyetemváros - A38 hajóállomás', '62621', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2415, -13, 47.553099, 19.014312, 'Viharhegyi út', 'F00555', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2416, -13, 47.439813, 19.096566, 'János utca', 'F04126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2417, -13, 47.40621, 19.114633, 'Tárcsás utca', 'F04533', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2418, -13, 47.399752, 19.114519, 'Soroksár Hősök tere H', 'F04538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2419, -13, 47.399967, 19.116666, 'Soroksár Hősök tere H', 'F04543', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2420, -12, 47.593416, 19.35762, 'Gödöllő H', '9021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2421, -12, 47.521195, 19.006326, 'Tüske utca', 'F00292', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2422, -12, 47.562612, 18.947587, 'Máriaremetei kegytemplom', 'F00390', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2423, -12, 47.461284, 19.313561, 'Sáránd utca', 'F03505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2424, -12, 47.429614, 19.037879, 'Savoyai Jenő tér', 'F04397', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2425, -12, 47.402711, 19.115782, 'Erzsébet utca', 'F04569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2426, -12, 47.450841, 18.90501, 'Törökbálint vasútállomás', 'F04744', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2427, -11, 47.515122, 19.037158, 'Margit híd budai hídfő H', '69090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2428, -11, 47.515985, 19.039521, 'Margit híd budai hídfő H', 'F00234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2429, -11, 47.553017, 18.949359, 'Kerényi Frigyes utca', 'F00362', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2430, -11, 47.55131, 18.947242, 'Ábránd utca', 'F00364', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2431, -11, 47.554736, 18.946079, 'Arany János utca', 'F00374', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2432, -11, 47.562091, 19.008167, 'Jablonka út', 'F00545', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2433, -11, 47.498843, 19.053605, 'Deák Ferenc tér', 'F00960', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2434, -11, 47.497618, 19.070619, 'Blaha Lujza tér M', 'F01167', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2435, -11, 47.500789, 19.108331, 'Puskás Ferenc Stadion M', 'F01320', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2436, -11, 47.46814, 19.107681, 'Közterületfenntartó Zrt.', 'F01485', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2437, -11, 47.504098, 19.136457, 'Örs vezér tere M+H', 'F01757', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2438, -11, 47.469422, 19.033719, 'Szent Imre Kórház', 'F02034', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2439, -11, 47.429587, 19.037773, 'Savoyai Jenő tér', 'F04398', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2440, -11, 47.410277, 19.11171, 'Soroksár felső H', 'F04531', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2441, -10, 47.543722, 19.030125, 'Váradi utca', '7881', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2442, -10, 47.620304, 19.046374, 'Budakalász Lenfonó H', '8430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2443, -10, 47.396853, 19.208738, 'Kacsóh Pongrác utca', '9193', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2444, -10, 47.546762, 19.029194, 'Bécsi út / Vörösvári út', '82200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2445, -10, 47.54383, 19.029979, 'Váradi utca', 'F00591', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2446, -10, 47.496781, 19.070699, 'Blaha Lujza tér M', 'F01168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2447, -10, 47.50095, 19.108089, 'Puskás Ferenc Stadion M', 'F01323', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2448, -10, 47.500442, 19.115547, 'Kerepesi út', 'F01739', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2449, -10, 47.504287, 19.136192, 'Örs vezér tere M+H', 'F01756', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2450, -10, 47.502519, 19.153245, 'Halas utca', 'F01773', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2451, -10, 47.430826, 18.980206, 'Szép utca', 'F02160', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2452, -10, 47.513003, 19.146468, 'Csertő utca', 'F02778', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2453, -10, 47.416817, 19.11034, 'Festékgyár', 'F04529', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2454, -9, 47.5458, 19.029579, 'Perényi út', '7883', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2455, -9, 47.501517, 19.136333, 'Örs vezér tere M+H', '8022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2456, -9, 47.502448, 19.136843, 'Örs vezér tere M+H', '8382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2457, -9, 47.460313, 18.958511, 'Baross utca', '8598', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2458, -9, 47.40949, 19.119023, 'Csillag utca', '8954', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2459, -9, 47.49062, 19.02465, 'Csörsz utca', '43343', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2460, -9, 47.496785, 19.109305, 'Hős utca', 'F01326', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2461, -9, 47.500495, 19.153719, 'EGIS Gyógyszergyár', 'F01784', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2462, -9, 47.49951, 19.137564, 'Fehér úti ipari park', 'F01863', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2463, -8, 47.473586, 19.178326, 'Új köztemető', '8156', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2464, -8, 47.501784, 19.040028, 'Halász utca', 'F00052', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2465, -8, 47.569797, 19.048509, 'Római tér', 'F00485', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2466, -8, 47.531833, 19.040023, 'Nagyszombat utca', 'F00603', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2467, -8, 47.498645, 19.051535, 'Erzsébet tér', 'F00980', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2468, -8, 47.492401, 19.024264, 'Tartsay Vilmos utca', 'F02311', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2469, -8, 47.490782, 19.024447, 'Csörsz utca', 'F02417', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2470, -8, 47.464147, 19.312607, 'Pesti út', 'F03502', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2471, -8, 47.439139, 19.094179, 'Pesterzsébet felső H', 'F04142', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2472, -8, 47.405698, 19.112672, 'Tárcsás utca', 'F04537', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2473, -8, 47.490821, 19.336234, 'Köztársaság tér', 'F04595', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2474, -7, 47.46331, 18.894407, 'Budaörsi Ipari és Technológiai Park', '11327', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2475, -7, 47.461423, 19.018933, 'Zelk Zoltán út (Menyecske utca)', '61226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2476, -7, 47.502558, 19.039747, 'Halász utca', 'F00053', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2477, -7, 47.511119, 19.03457, 'Horvát utca', 'F00193', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2478, -7, 47.553114, 19.092696, 'Angyalföld kocsiszín', 'F00820', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2479, -7, 47.505148, 19.052398, 'Hold utca (Belvárosi piac)', 'F01022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2480, -7, 47.442968, 18.978905, 'Kamaraerdei út 11.', 'F02069', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2481, -7, 47.504898, 19.136843, 'Örs vezér tere M+H', 'F02759', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2482, -7, 47.410557, 19.088868, 'Tölgyes út', 'F04253', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2483, -6, 47.582863, 19.116848, 'Járműtelep utca', 'F00734', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2484, -6, 47.490541, 19.061858, 'Kálvin tér M', 'F01010', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2485, -6, 47.486423, 19.092866, 'Golgota tér', 'F01226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2486, -6, 47.485469, 19.092919, 'Golgota tér', 'F01229', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2487, -6, 47.440603, 18.979345, 'Kamaraerdei Ifjúsági Park', 'F01886', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2488, -6, 47.432501, 19.037282, 'Leányka utcai lakótelep', 'F04382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2489, -6, 47.432411, 19.037454, 'Leányka utcai lakótelep', 'F04384', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2490, -6, 47.425926, 19.001432, 'Memento Park', 'F04394', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2491, -5, 47.413574, 19.118578, 'Gombosszeg köz', '8956', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2492, -5, 47.408202, 18.991007, 'Dukát utca', '43571', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2493, -5, 47.493851, 19.043373, 'Várkert Bazár', 'F00037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2494, -5, 47.50045, 19.024604, 'Déli pályaudvar', 'F00094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2495, -5, 47.526993, 19.031392, 'Felhévízi utca', 'F00250', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2496, -5, 47.548568, 18.964777, 'Hunyadi János utca', 'F00358', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2497, -5, 47.481763, 19.09346, 'Elnök utca', 'F01237', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2498, -5, 47.499537, 19.137432, 'Fehér úti ipari park', 'F01860', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2499, -5, 47.484547, 19.342376, 'Szent Imre körút', 'F04583', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2500, -5, 47.491885, 19.342457, 'Ráday Gedeon tér', 'F04590', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2501, -4, 47.501355, 19.136028, 'Örs vezér tere M+H', '8026', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2502, -4, 47.447991, 18.947991, 'Méhecske utca', '8847', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2503, -4, 47.490027, 19.303088, 'Ede utca', '66687', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2504, -4, 47.476544, 19.100752, 'Népliget M', '86859', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2505, -4, 47.436562, 18.915588, 'Baross Gábor utca', '98674', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2506, -4, 47.506185, 19.049094, 'Kossuth Lajos tér M', '99342', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2507, -4, 47.49446, 19.023945, 'Királyhágó utca', 'F00014', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2508, -4, 47.494839, 19.023732, 'Királyhágó utca', 'F00015', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2509, -4, 47.558251, 18.948844, 'Csatlós utca', 'F00388', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2510, -4, 47.495504, 19.07103, 'Blaha Lujza tér M (Népszínház utca)', 'F01295', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2511, -4, 47.500098, 19.103522, 'Puskás Ferenc Stadion M', 'F01317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2512, -4, 47.446672, 19.033591, 'Herend utca', 'F02212', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2513, -4, 47.532515, 19.06734, 'Göncz Árpád városközpont M', 'F02579', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2514, -4, 47.405265, 18.975901, 'Szakiskola utca', 'F04487', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2515, -3, 47.505588, 19.039284, 'Batthyány tér M+H', '9629', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2516, -3, 47.47927, 19.067786, 'Boráros tér H', '11765', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2517, -3, 47.506002, 19.039456, 'Batthyány tér M+H', 'F00057', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2518, -3, 47.520903, 19.02514, 'Vérhalom tér', 'F00221', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2519, -3, 47.478911, 19.177808, 'Izraelita temető', 'F01810', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2520, -3, 47.460251, 19.017379, 'Menyecske utca', 'F01921', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2521, -3, 47.443905, 19.033885, 'Fonyód utca / Sáfrány utca', 'F01969', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2522, -3, 47.490631, 19.002303, 'Mártonhegyi út', 'F02281', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2523, -3, 47.511536, 18.984889, 'Zalai út', 'F02376', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2524, -3, 47.49698, 19.000117, 'Sólyom utca', 'F02435', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2525, -3, 47.471786, 19.294975, 'Nagyszentmiklósi út', 'F03496', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2526, -3, 47.448491, 18.90531, 'Kerekdomb utca', 'F04746', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2527, -2, 47.515518, 19.038339, 'Margit híd budai hídfő H', 'F00237', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2528, -2, 47.556839, 19.088873, 'Tél utca / Pozsonyi utca', 'F00861', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2529, -2, 47.497827, 19.047168, 'Eötvös tér', 'F00975', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2530, -2, 47.497724, 18.964075, 'Csillebérc Gyermekvasút', 'F02454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2531, -2, 47.530768, 19.080845, 'Béke tér', 'F02588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2532, -2, 47.516993, 19.23661, 'Nagytarcsai út', 'F03336', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2533, -2, 47.477055, 19.227527, 'Vadkacsa utca', 'F03559', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2534, -2, 47.494594, 19.287082, 'Gőzös utca', 'F03582', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2535, -2, 47.497301, 19.286922, 'Kis Károshíd utca', 'F03598', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2536, -1, 47.445251, 18.894299, 'Határ utca', '8330', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2537, -1, 47.528378, 19.027328, 'Alsó Zöldmáli út', 'F00253', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2538, -1, 47.584901, 19.086143, 'Megyer Julianus barát utca', 'F00707', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2539, -1, 47.491164, 19.108967, 'Hidegkuti Nándor Stadion', 'F01275', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2540, -1, 47.449545, 19.033366, 'Nyírbátor utca', 'F01967', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2541, -1, 47.472823, 19.052568, 'Budafoki út / Szerémi sor', 'F01991', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2542, -1, 47.48719, 19.024696, 'BAH-csomópont', 'F02166', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2543, -1, 47.444593, 19.07195, 'Szabadkikötő H', 'F04359', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2544, 0, 47.548471, 19.02918, 'Bécsi út / Vörösvári út', '8412', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2545, 0, 47.500273, 19.055025, 'Szent István Bazilika', '48506', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2546, 0, 47.437398, 19.013737, 'Szabina út', '61286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2547, 0, 47.498744, 19.054959, 'Deák Ferenc tér M', '74959', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2548, 0, 47.490477, 19.030486, 'Zsolt utca', 'F00002', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2549, 0, 47.521149, 19.03187, 'Kavics utca', 'F00226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2550, 0, 47.524251, 19.03693, 'Zsigmond tér', 'F00243', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2551, 0, 47.582539, 19.117047, 'Járműtelep utca', 'F00735', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2552, 0, 47.491029, 19.109209, 'Hidegkuti Nándor Stadion', 'F01273', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2553, 0, 47.494024, 19.109239, 'Ciprus utca', 'F01276', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2554, 0, 47.496282, 19.109135, 'Hős utca', 'F01328', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2555, 0, 47.475097, 19.178223, 'Új köztemető (Kozma utca)', 'F01866', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2556, 0, 47.469765, 19.053311, 'Budafoki út / Dombóvári út', 'F01985', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2557, 0, 47.48286, 19.053922, 'Szent Gellért tér - Műegyetem M', 'F02143', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2558, 0, 47.486698, 19.024943, 'BAH-csomópont', 'F02169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2559, 0, 47.507587, 19.235395, 'Erdei bekötőút', 'F03338', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2560, 0, 47.485691, 19.243518, 'Rétsár utca', 'F03455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2561, 0, 47.450194, 18.962419, 'Agip utca', 'F04730', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2562, 1, 47.581318, 19.049413, 'Attila utca', '62946', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2563, 1, 47.338455, 19.035846, 'Váci Mihály utca (József Attila-telep H)', '64091', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2564, 1, 47.510526, 19.039256, 'Kacsa utca', '81954', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2565, 1, 47.499686, 19.024208, 'Déli pályaudvar M', 'F00022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2566, 1, 47.552916, 19.091846, 'Angyalföld kocsiszín', 'F00823', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2567, 1, 47.443025, 19.045685, 'Vegyész utca', 'F01973', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2568, 1, 47.460437, 19.033642, 'Puskás Tivadar utca', 'F02214', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2569, 1, 47.426018, 19.023218, 'Árpád utca', 'F04418', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2570, 2, 47.578206, 19.049333, 'Czetz János köz', '62944', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2571, 2, 47.341252, 19.035951, 'Jókai utca', '64092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2572, 2, 47.468757, 19.053324, 'Budafoki út / Dombóvári út', '73108', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2573, 2, 47.457127, 19.033537, 'Andor utca / Tétényi út', '90915', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2574, 2, 47.521409, 19.038272, 'Császár-Komjádi uszoda', 'F00247', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2575, 2, 47.535152, 19.039969, 'Tímár utca', 'F00604', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2576, 2, 47.580747, 19.081673, 'Béla utca', 'F00867', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2577, 2, 47.503304, 19.055105, 'Arany János utca M', 'F00942', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2578, 2, 47.463421, 19.117928, 'Friss utca', 'F01467', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2579, 2, 47.458413, 19.033576, 'Bornemissza tér', 'F01960', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2580, 2, 47.405907, 19.090626, 'Repkény út', 'F04256', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2581, 2, 47.434217, 19.070779, 'Ady Endre út (Kossuth Lajos utca)', 'F04315', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2582, 2, 47.43125, 19.067848, 'Szent Imre tér H', 'F04320', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2583, 2, 47.421546, 19.041578, 'Tóth József utca', 'F04407', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2584, 3, 47.587474, 19.082223, 'Ungvári utca', '9574', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2585, 3, 47.43178, 19.067901, 'Szent Imre tér H', '40609', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2586, 3, 47.517075, 19.033226, 'Mansfeld Péter park', '49903', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2587, 3, 47.360348, 19.047569, 'Bánki Donát utca', '64245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2588, 3, 47.501106, 19.024177, 'Déli pályaudvar M', 'F00025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2589, 3, 47.583544, 19.081928, 'Fóti út', 'F00698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2590, 3, 47.562067, 19.078486, 'Újpest Árpád út hajóállomás', 'F00706', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2591, 3, 47.576547, 19.081338, 'Tungsram', 'F00869', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2592, 3, 47.492707, 19.071267, 'Rákóczi tér M', 'F01202', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2593, 3, 47.453979, 19.033445, 'Csurgói út', 'F01965', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2594, 3, 47.447081, 19.045923, 'Építész utca', 'F01975', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2595, 3, 47.477248, 19.047328, 'Móricz Zsigmond körtér M', 'F02194', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2596, 3, 47.541511, 19.140106, 'Fő tér', 'F03152', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2597, 3, 47.427166, 19.067475, 'Karácsony Sándor utca H', 'F04177', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2598, 3, 47.407813, 19.090774, 'Iszalag utca', 'F04255', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2599, 3, 47.427418, 19.070219, 'Karácsony Sándor utca', 'F04279', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2600, 3, 47.426239, 19.072273, 'Deák tér', 'F04295', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2601, 4, 47.491014, 19.28287, 'Rákoscsaba vasútállomás', '19316', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2602, 4, 47.367661, 19.048324, 'Bányató út', '64246', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2603, 4, 47.512082, 19.039455, 'Bem József tér', '69092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2604, 4, 47.519063, 19.033424, 'Vérhalom utca', 'F00227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2605, 4, 47.544673, 18.963548, 'Bátori László utca', 'F00339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2606, 4, 47.597516, 19.04569, 'Hősök tere', 'F00452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2607, 4, 47.589636, 19.065606, 'Sinkovits Imre utca', 'F00656', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2608, 4, 47.58478, 19.065032, 'Mátyás király út', 'F00658', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2609, 4, 47.572356, 19.080964, 'Tímár utca', 'F00701', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2610, 4, 47.464422, 19.053098, 'Kelenföldi Erőmű', 'F02235', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2611, 4, 47.532084, 19.067991, 'Göncz Árpád városközpont M', 'F02581', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2612, 4, 47.459179, 19.126516, 'Corvin körút', 'F03925', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2613, 4, 47.420156, 19.08376, 'Kassai utca', 'F04214', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2614, 4, 47.431231, 19.070512, 'Szent Imre tér', 'F04324', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2615, 4, 47.479985, 19.343227, 'Árpád utca', 'F04644', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2616, 5, 47.471798, 18.901757, 'Budaörs Gábor Dénes körút', '98536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2617, 5, 47.465083, 18.900849, 'Raktárbázis', '98540', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2618, 5, 47.498335, 19.024011, 'Nagyenyed utca', 'F00020', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2619, 5, 47.512055, 19.039322, 'Bem József tér', 'F00187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2620, 5, 47.596121, 19.04553, 'Zemplén Győző utca', 'F00454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2621, 5, 47.575562, 19.049067, 'Rómaifürdő H', 'F00489', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2622, 5, 47.582526, 19.086021, 'Fiumei út', 'F00711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2623, 5, 47.492851, 19.071188, 'Rákóczi tér M', 'F01200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2624, 5, 47.455374, 19.05197, 'Galvani utca', 'F01981', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2625, 5, 47.466355, 19.034157, 'Tétényi út 30.', 'F02032', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2626, 5, 47.480589, 18.992547, 'Eper utca', 'F02079', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2627, 5, 47.45943, 19.052474, 'Hengermalom út', 'F02237', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2628, 5, 47.49934, 19.008295, 'Szent Orbán tér', 'F02401', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2629, 5, 47.545962, 19.140592, 'Vásárcsarnok', 'F03157', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2630, 6, 47.423117, 19.078036, 'Görögkatolikus templom', '44637', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2631, 6, 47.586373, 19.050011, 'Mátyás király út', '62948', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2632, 6, 47.440398, 19.036339, 'Budafok kocsiszín', '91799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2633, 6, 47.464473, 19.023388, 'Kelenföld vasútállomás M', '99634', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2634, 6, 47.582828, 19.064793, 'Szamos utca', 'F00676', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2635, 6, 47.50915, 19.04677, 'Országház látogatóközpont', 'F00968', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2636, 6, 47.489955, 19.070881, 'Harminckettesek tere', 'F01196', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2637, 6, 47.490332, 19.070815, 'Harminckettesek tere', 'F01199', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2638, 6, 47.464277, 19.033826, 'Bikás park M', 'F01958', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2639, 6, 47.447926, 19.037583, 'Albertfalva utca', 'F02019', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2640, 6, 47.447333, 19.037597, 'Albertfalva utca', 'F02022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2641, 6, 47.443789, 19.036908, 'Fonyód utca', 'F02024', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2642, 6, 47.441783, 19.036577, 'Budafok kocsiszín', 'F02029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2643, 6, 47.450824, 19.125602, 'Pannónia út', 'F03969', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2644, 6, 47.425112, 19.084174, 'József Attila utca', 'F04178', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2645, 7, 47.552138, 18.964307, 'Kossuth Lajos utca', 'F00360', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2646, 7, 47.550154, 19.043979, 'Bogdáni út', 'F00576', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2647, 7, 47.443654, 19.037001, 'Fonyód utca', 'F02026', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2648, 7, 47.520133, 19.091527, 'Mexikói út M', 'F02886', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2649, 7, 47.437965, 19.134359, 'Udvarhely utca', 'F04088', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2650, 7, 47.410168, 19.060487, 'Csepel Csillagtelep', 'F04276', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2651, 8, 47.446818, 19.006063, 'Régi vám', '9431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2652, 8, 47.503557, 18.921167, 'Budakeszi Tiefenweg utca', '44383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2653, 8, 47.486467, 19.070356, 'Corvin-negyed M', 'F01190', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2654, 8, 47.46702, 19.178694, 'Álmos utca', 'F01552', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2655, 8, 47.479941, 19.196129, 'Csillagvirág utca', 'F01875', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2656, 8, 47.496844, 18.998184, 'Tamási Áron utca / Thomán István utca', 'F02438', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2657, 8, 47.546095, 19.072459, 'Fiastyúk utca', 'F02564', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2658, 8, 47.485035, 19.258549, 'Rákosliget vasútállomás', 'F03457', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2659, 8, 47.441136, 19.185048, 'Szarvas csárda tér', 'F03721', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2660, 9, 47.452801, 19.038961, 'Albertfalva kitérő', '8685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2661, 9, 47.45388, 19.007516, 'Keserűvíztelep (Bobpálya)', '9430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2662, 9, 47.434463, 19.091352, 'Vízisport utca', '9718', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2663, 9, 47.464436, 19.018308, 'Kelenföld vasútállomás M', '61280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2664, 9, 47.508268, 19.034372, 'Fazekas utca', 'F00034', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2665, 9, 47.527983, 19.03766, 'Kolosy tér', 'F00135', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2666, 9, 47.579923, 19.0641, 'Szent János utca', 'F00660', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2667, 9, 47.580597, 19.116672, 'Galopp utca', 'F00738', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2668, 9, 47.463464, 19.073086, 'Helyi kikötő út', 'F01412', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2669, 9, 47.464098, 19.17809, 'Árpád utca', 'F01550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2670, 9, 47.435952, 18.978596, 'Kamaraerdő', 'F01880', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2671, 9, 47.460535, 19.246303, 'Rákoshegy Ferihegyi út', 'F03521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2672, 10, 47.577405, 19.114927, 'Káposztásmegyer Szilas-patak', '8426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2673, 10, 47.485549, 19.192668, 'Jászberényi út', '8491', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2674, 10, 47.404443, 18.991581, 'Mátyás király utca', '9447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2675, 10, 47.504553, 19.030683, 'Bécsi kapu tér', 'F00069', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2676, 10, 47.520635, 19.036798, 'Komjádi Béla utca', 'F00241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2677, 10, 47.524991, 19.037951, 'Zsigmond tér', 'F00245', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2678, 10, 47.561909, 19.035606, 'Áldomás utca', 'F00547', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2679, 10, 47.545288, 19.042837, 'Raktár utca', 'F00580', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2680, 10, 47.544947, 19.042757, 'Raktár utca', 'F00581', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2681, 10, 47.575696, 19.062837, 'Nimród utca', 'F00663', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2682, 10, 47.595848, 19.121292, 'Székpatak utca', 'F00907', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2683, 10, 47.460599, 19.114913, 'József Attila ltp. Távíró utca', 'F01408', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2684, 10, 47.440794, 19.036484, 'Budafok kocsiszín', 'F02028', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2685, 10, 47.493446, 19.002553, 'Tamási Áron utca', 'F02279', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2686, 10, 47.490033, 19.259736, 'Hősök tere', 'F03447', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2687, 10, 47.487282, 19.259049, 'IV. utca', 'F03458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2688, 10, 47.463664, 19.247058, 'Kerülő utca', 'F03520', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2689, 10, 47.433293, 19.127021, 'Pesterzsébet Ady Endre tér', 'F04092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2690, 11, 47.462551, 19.048509, 'Hengermalom út / Szerémi út', '66458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2691, 11, 47.550712, 19.041826, 'Óbuda Bogdáni út', 'F00573', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2692, 11, 47.542896, 19.042252, 'Flórián tér', 'F00611', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2693, 11, 47.568812, 19.08047, 'Zsilip utca', 'F00703', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2694, 11, 47.577995, 19.115942, 'Óceán-árok utca', 'F00741', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2695, 11, 47.578088, 19.116151, 'Óceán-árok utca', 'F00743', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2696, 11, 47.564173, 19.10012, 'Deák Ferenc utca / Rózsa utca', 'F00876', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2697, 11, 47.509123, 19.051164, 'Markó utca', 'F00990', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2698, 11, 47.475412, 19.053165, 'Budafoki út / Karinthy Frigyes út', 'F02155', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2699, 11, 47.492498, 19.016647, 'Kiss János altábornagy utca', 'F02307', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2700, 11, 47.497928, 18.989543, 'Svábhegy', 'F02458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2701, 11, 47.52255, 19.231438, 'Rózsalevél utca', 'F03331', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2702, 11, 47.492532, 19.260383, 'XVIII. utca', 'F03469', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2703, 11, 47.437446, 19.183973, 'Wlassics Gyula utca', 'F03683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2704, 12, 47.470484, 19.045299, 'Csonka János tér', '9425', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2705, 12, 47.467013, 19.049756, 'Hauszmann Alajos utca / Szerémi út', '66456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2706, 12, 47.520206, 18.938865, 'Szanatórium utca (Vadaspark)', 'F00116', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2707, 12, 47.584907, 19.056086, 'Hunyadi utca', 'F00497', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2708, 12, 47.562206, 19.048163, 'Záhony utca', 'F00506', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2709, 12, 47.538723, 19.0405, 'Kiscelli utca', 'F00606', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2710, 12, 47.564936, 19.079205, 'Károlyi István utca', 'F00705', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2711, 12, 47.454965, 19.018844, 'Őrmezei út', 'F01916', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2712, 12, 47.451036, 19.022625, 'Duránci utca', 'F01941', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2713, 12, 47.435611, 19.183464, 'Dobozi utca', 'F03685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2714, 12, 47.446552, 19.191346, 'Lőrinci temető', 'F03702', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2715, 12, 47.423604, 19.069767, 'Széchenyi István utca', 'F04192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2716, 13, 47.432896, 19.011523, 'Hír utca', '8126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2717, 13, 47.55341, 19.040272, 'Búza utca', '11320', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2718, 13, 47.458825, 19.041134, 'Kalotaszeg utca', '16764', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2719, 13, 47.435114, 19.013117, 'Jogar utca', '96830', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2720, 13, 47.515986, 19.043995, 'Margitsziget / Margit híd', 'F00143', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2721, 13, 47.593141, 19.120265, 'Megyeri út', 'F00724', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2722, 13, 47.56317, 19.090765, 'Szent István tér (Újpesti piac)', 'F00796', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2723, 13, 47.560157, 19.089739, 'Újpest-központ M', 'F00797', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2724, 13, 47.559213, 19.089566, 'Újpest-központ M (Munkásotthon utca)', 'F00814', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2725, 13, 47.481736, 19.177696, 'Gránátos utca', 'F01601', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2726, 13, 47.484712, 19.178751, 'Meténg utca', 'F01812', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2727, 13, 47.452988, 19.023234, 'Gépész utca', 'F01946', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2728, 13, 47.467615, 19.044291, 'Hauszmann Alajos utca', 'F02005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2729, 13, 47.463398, 19.042572, 'Etele út / Fehérvári út', 'F02009', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2730, 13, 47.455715, 19.039836, 'Andor utca', 'F02014', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2731, 13, 47.452666, 19.038776, 'Albertfalva kitérő', 'F02017', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2732, 13, 47.470259, 19.045087, 'Csonka János tér', 'F02239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2733, 13, 47.494931, 19.003096, 'Pagony utca', 'F02434', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2734, 13, 47.494695, 18.998517, 'Thomán István utca', 'F02439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2735, 13, 47.525204, 19.063792, 'Dózsa György út M', 'F02609', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2736, 13, 47.406848, 19.080228, 'Szent László utcai lakótelep', 'F04235', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2737, 13, 47.460676, 19.072249, 'Csepeli híd', 'F04298', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2738, 13, 47.582136, 18.939193, 'Anna kápolna', 'F04696', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2739, 14, 47.577645, 19.032198, 'Ürömhegyi lejtő', '8408', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2740, 14, 47.572593, 19.030301, 'Aranyhegyi lejtő', '8410', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2741, 14, 47.474649, 19.046771, 'Újbuda-központ M', '16727', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2742, 14, 47.473559, 19.046266, 'Újbuda-központ', '56220', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2743, 14, 47.47731, 19.047645, 'Móricz Zsigmond körtér', '56222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2744, 14, 47.550363, 19.091315, 'Angyalföld vasútállomás', '91816', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2745, 14, 47.528586, 19.039001, 'Kolosy tér', 'F00137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2746, 14, 47.554633, 19.040776, 'Szőlőkert utca', 'F00441', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2747, 14, 47.557098, 19.04617, 'Kaszásdűlő H', 'F00508', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2748, 14, 47.546537, 19.097593, 'Madridi utca', 'F00692', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2749, 14, 47.573085, 19.103, 'Iglói utca', 'F00761', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2750, 14, 47.56102, 19.090218, 'Újpest-központ M', 'F00804', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2751, 14, 47.587791, 19.11817, 'Bőrfestő utca', 'F00903', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2752, 14, 47.482751, 19.068848, 'Mester utca / Ferenc körút', 'F01380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2753, 14, 47.467039, 19.043919, 'Hauszmann Alajos utca', 'F02003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2754, 14, 47.461985, 19.042262, 'Etele út / Fehérvári út', 'F02011', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2755, 14, 47.456399, 19.040234, 'Andor utca', 'F02012', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2756, 14, 47.482482, 19.05294, 'Szent Gellért tér - Műegyetem M', 'F02144', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2757, 14, 47.458827, 19.040976, 'Kalotaszeg utca', 'F02241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2758, 14, 47.507183, 18.988324, 'Regőczi István tér', 'F02373', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2759, 14, 47.522785, 19.045282, 'Parkmozi köz', 'F02505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2760, 14, 47.549394, 19.073575, 'Gyöngyösi utca', 'F02684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2761, 14, 47.43375, 19.182943, 'Varjú utca', 'F03633', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2762, 15, 47.594692, 19.113273, 'Homoktövis utca', '9047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2763, 15, 47.572575, 19.114429, 'Atlétikai stadion', '9154', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2764, 15, 47.566191, 19.111737, 'Rákospalota-Újpest vasútállomás', '9516', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2765, 15, 47.558204, 19.042091, 'Kaszásdűlő utca', 'F00513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2766, 15, 47.585597, 19.11749, 'Bőröndös utca', 'F00826', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2767, 15, 47.548956, 19.098538, 'Brüsszeli utca', 'F00864', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2768, 15, 47.587809, 19.11837, 'Bőrfestő utca', 'F00904', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2769, 15, 47.485476, 19.069898, 'Corvin-negyed M', 'F01191', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2770, 15, 47.481718, 19.127066, 'Kőbánya alsó vasútállomás (Mázsa tér)', 'F01574', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2771, 15, 47.485304, 19.131729, 'Szent László tér', 'F01650', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2772, 15, 47.43546, 19.036433, 'Budafoki elágazás', 'F01905', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2773, 15, 47.4748, 19.046664, 'Újbuda-központ M', 'F01999', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2774, 15, 47.519853, 19.044234, 'Hajós Alfréd uszoda', 'F02492', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2775, 15, 47.586026, 19.120973, 'Visonta utca', 'F02997', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2776, 16, 47.482246, 19.068716, 'Mester utca / Ferenc körút', '7887', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2777, 16, 47.58546, 19.117201, 'Bőröndös utca', 'F00825', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2778, 16, 47.561948, 19.107706, 'Árpád út', 'F00840', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2779, 16, 47.566227, 19.111896, 'Rákospalota-Újpest vasútállomás', 'F00845', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2780, 16, 47.486761, 19.023152, 'BAH-csomópont', 'F02112', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2781, 16, 47.489321, 19.009762, 'Vas Gereben utca', 'F02297', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2782, 16, 47.536887, 19.060834, 'Népfürdő utca / Árpád híd', 'F02568', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2783, 16, 47.533605, 19.059359, 'Sporttelep', 'F02570', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2784, 16, 47.534188, 19.067646, 'Göncz Árpád városközpont M', 'F02603', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2785, 16, 47.533217, 19.067089, 'Göncz Árpád városközpont', 'F02637', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2786, 16, 47.52543, 19.063737, 'Dózsa György út', 'F02680', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2787, 16, 47.53916, 19.069648, 'Forgách utca', 'F02682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2788, 16, 47.517718, 19.077863, 'Állatkert', 'F02693', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2789, 16, 47.552826, 19.111708, 'Szerencs utca', 'F03067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2790, 16, 47.553838, 19.118777, 'Rákos úti szakrendelő', 'F03072', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2791, 16, 47.550985, 19.107614, 'Pázmány Péter utca', 'F03075', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2792, 16, 47.551519, 19.117777, 'Szerencs utca', 'F03116', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2793, 16, 47.557205, 19.11356, 'Rädda Barnen utca', 'F03179', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2794, 16, 47.419056, 19.006712, 'Aradi utca', 'F04439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2795, 17, 47.648965, 19.069449, 'Szentendre Papírgyár', '8436', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2796, 17, 47.556577, 19.076558, 'Újpest-városkapu M (Váci út)', '8944', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2797, 17, 47.462976, 19.148644, 'Kőbánya-Kispest M', '38683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2798, 17, 47.546042, 19.069019, 'Úszódaru utca', '49182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2799, 17, 47.455342, 19.024398, 'Hajtány sor', '61225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2800, 17, 47.519816, 19.028845, 'Vérhalom utca', 'F00223', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2801, 17, 47.483074, 19.131221, 'Liget tér', 'F01642', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2802, 17, 47.484934, 19.131747, 'Szent László tér', 'F01651', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2803, 17, 47.447121, 19.014181, 'Kápolna út', 'F01899', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2804, 17, 47.48135, 19.004766, 'Süveg utca', 'F02096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2805, 17, 47.496668, 19.007341, 'Vöröskő utca', 'F02430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2806, 17, 47.530844, 19.048934, 'Szabadtéri Színpad', 'F02496', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2807, 17, 47.54496, 19.079725, 'Fiastyúk utca', 'F02562', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2808, 17, 47.53524, 19.069958, 'Lomb utca', 'F02572', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2809, 17, 47.529745, 19.080488, 'Béke tér', 'F02587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2810, 17, 47.526244, 19.078506, 'Lehel utca / Róbert Károly körút', 'F02591', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2811, 17, 47.537119, 19.075975, 'Fáy utca', 'F02666', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2812, 17, 47.555811, 19.112973, 'Eötvös utca 74.', 'F03068', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2813, 17, 47.549235, 19.116805, 'Wesselényi utca', 'F03182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2814, 18, 47.477059, 19.04754, 'Móricz Zsigmond körtér M', '8005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2815, 18, 47.557286, 19.105737, 'Ősz utca', 'F00888', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2816, 18, 47.43758, 18.979164, 'Idősek Otthona', 'F01883', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2817, 18, 47.488582, 19.00679, 'Boldog Teréz anya tér', 'F02285', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2818, 18, 47.528074, 19.047606, 'Palatinus fürdő', 'F02494', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2819, 18, 47.530106, 19.057645, 'Viza utca', 'F02507', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2820, 18, 47.518468, 19.056767, 'Victor Hugo utca', 'F02516', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2821, 18, 47.548396, 19.081334, 'Gyöngyösi utca', 'F02539', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2822, 18, 47.523954, 19.056011, 'Vág utca', 'F02574', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2823, 18, 47.534277, 19.074632, 'Röppentyű utca', 'F02577', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2824, 18, 47.523837, 19.059397, 'Tisza utca', 'F02606', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2825, 18, 47.54229, 19.087031, 'Rokolya utca', 'F02661', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2826, 18, 47.540046, 19.07, 'Forgách utca M', 'F02679', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2827, 18, 47.590316, 19.122667, 'Rákospalota Csömöri-patak', 'F02996', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2828, 19, 47.482307, 19.233674, 'Újlak utca lakótelep', '9494', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2829, 19, 47.499281, 19.286785, 'Rákoscsaba-Újtelep Tóalmás utca', '11768', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2830, 19, 47.436488, 19.007202, 'Antalháza', '61233', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2831, 19, 47.533228, 19.02577, 'Kolostor út', 'F00688', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2832, 19, 47.550146, 19.091379, 'Angyalföld vasútállomás', 'F00693', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2833, 19, 47.561008, 19.099134, 'Árpád üzletház', 'F00834', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2834, 19, 47.443721, 19.012407, 'Kelenvölgy-Péterhegy', 'F01896', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2835, 19, 47.533317, 19.050873, 'Szállodák (Hotels)', 'F02498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2836, 19, 47.55909, 19.121069, 'Illyés Gyula utca', 'F03059', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2837, 19, 47.590213, 18.936353, 'Bajcsy-Zsilinszky utca', 'F04798', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2838, 20, 47.584817, 19.042061, 'Csillaghegy H', '8404', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2839, 20, 47.441357, 19.18511, 'Pestszentlőrinc Szarvas csárda tér', '9208', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2840, 20, 47.416544, 19.029864, 'Ostor utca', '9455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2841, 20, 47.450764, 19.016208, 'Bolygó utca', '61273', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2842, 20, 47.433069, 19.005455, 'Balatoni út / Háros utca', '103386', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2843, 20, 47.555269, 18.965923, 'Kölcsey utca', 'F00406', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2844, 20, 47.563402, 19.050874, 'Sujtás utca', 'F00504', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2845, 20, 47.481431, 19.004925, 'Süveg utca', 'F02097', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2846, 20, 47.484282, 19.005679, 'Farkasréti temető', 'F02101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2847, 20, 47.497875, 18.989768, 'Svábhegy', 'F02317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2848, 20, 47.497814, 19.021129, 'Kék Golyó utca', 'F02407', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2849, 20, 47.500135, 18.964748, 'Csillagvizsgáló', 'F02452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2850, 20, 47.520132, 19.05759, 'Gogol utca', 'F02519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2851, 20, 47.519853, 19.053886, 'Ipoly utca', 'F02522', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2852, 20, 47.546217, 19.089038, 'Gyöngyösi utca', 'F02544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2853, 20, 47.54663, 19.089383, 'Gyöngyösi utca', 'F02546', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2854, 20, 47.540537, 19.085874, 'Fiastyúk utca', 'F02554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2855, 20, 47.547118, 19.084057, 'József Attila tér', 'F02560', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2856, 20, 47.540212, 19.077477, 'Rozsnyay utca', 'F02663', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2857, 20, 47.534365, 19.082521, 'Frangepán utca', 'F02673', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2858, 20, 47.45216, 19.19514, 'Fedezék utca', 'F03698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2859, 20, 47.433034, 19.220562, 'Duna utca', 'F03785', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2860, 20, 47.40595, 19.053583, 'Csepeli temető', 'F04286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2861, 20, 47.428393, 19.02388, 'Arany János utca', 'F04420', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2862, 21, 47.431018, 19.004423, 'Budatétény benzinkút', '8943', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2863, 21, 47.536311, 19.065065, 'Dagály utcai lakótelep', '109729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2864, 21, 47.57916, 19.052669, 'Attila utca', 'F00493', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2865, 21, 47.526433, 19.078771, 'Lehel utca / Róbert Károly körút', 'F02592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2866, 21, 47.542539, 19.087016, 'Rokolya utca', 'F02659', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2867, 21, 47.535084, 19.083066, 'Frangepán utca', 'F02676', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2868, 22, 47.402518, 18.991053, 'Damjanich utca', '8109', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2869, 22, 47.615155, 19.109268, 'Székesdűlő ipartelep', '8649', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2870, 22, 47.356175, 19.046921, 'Temető utca', '64243', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2871, 22, 47.600312, 19.04662, 'Templom utca', 'F00445', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2872, 22, 47.581309, 19.05445, 'Huba utca', 'F00495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2873, 22, 47.554297, 19.101506, 'Elem utca', 'F00889', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2874, 22, 47.47633, 19.099513, 'Népliget M', 'F01256', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2875, 22, 47.449577, 19.015532, 'Olajfa utca', 'F01900', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2876, 22, 47.53823, 19.089988, 'Futár utca', 'F02549', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2877, 22, 47.5403, 19.085898, 'Fiastyúk utca', 'F02556', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2878, 22, 47.524516, 19.08197, 'Vágány utca / Róbert Károly körút', 'F02630', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2879, 22, 47.531288, 19.085945, 'Petneházy utca', 'F02636', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2880, 22, 47.527664, 19.083818, 'Szegedi út', 'F02647', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2881, 22, 47.527502, 19.083725, 'Szegedi út', 'F02648', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2882, 22, 47.541746, 19.092049, 'Rokolya utca', 'F02655', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2883, 22, 47.54447, 19.093632, 'Kucsma utca', 'F02657', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2884, 22, 47.534003, 19.087514, 'Fáy utca', 'F02672', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2885, 22, 47.511987, 19.085055, 'Olof Palme sétány', 'F02705', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2886, 22, 47.478132, 19.255934, 'Egészségház', 'F03473', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2887, 22, 47.423843, 19.234636, 'Ganztelep Mednyánszky utca', 'F03795', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2888, 22, 47.414504, 19.004145, 'Nyél utca', 'F04441', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2889, 23, 47.476294, 19.099288, 'Népliget M', '8286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2890, 23, 47.591122, 19.045358, 'Kert sor', '8402', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2891, 23, 47.515575, 18.922359, 'Zichy Péter utca', '9439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2892, 23, 47.435274, 19.009762, 'Tanító utca', '9463', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2893, 23, 47.5948, 19.053707, 'Madzsar József utca / Pünkösdfürdő utca', 'F00475', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2894, 23, 47.533559, 19.067434, 'Göncz Árpád városközpont M', 'F02604', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2895, 23, 47.568581, 19.117162, 'Pozsony utca', 'F03022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2896, 23, 47.448087, 19.170958, 'Lakatos út', 'F03751', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2897, 24, 47.416543, 19.116568, 'Alsó határút', '8958', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2898, 24, 47.392297, 19.115202, 'Szent István utca H', '8962', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2899, 24, 47.418185, 19.032578, 'Széchenyi utca', '9471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2900, 24, 47.597444, 19.055543, 'Békásmegyer H', '9584', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2901, 24, 47.496078, 19.077707, 'II. János Pál pápa tér', '56232', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2902, 24, 47.428626, 18.913182, 'Művelődési Ház', '73426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2903, 24, 47.545204, 18.952734, 'Villám utca', 'F00354', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2904, 24, 47.495295, 19.119695, 'Expó tér', 'F01746', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2905, 24, 47.45851, 19.023749, 'Borszéki utca', 'F01952', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2906, 24, 47.503604, 18.990585, 'Adonis utca', 'F02388', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2907, 24, 47.539882, 19.062375, 'Dagály fürdő', 'F02565', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2908, 24, 47.530967, 19.101071, 'Rákospatak utca', 'F02905', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2909, 24, 47.541635, 19.129984, 'Templom tér', 'F03133', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2910, 24, 47.536772, 19.12663, 'Emlék tér', 'F03186', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2911, 24, 47.519171, 19.230258, 'Batthyány Ilona utca', 'F03329', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2912, 24, 47.479911, 19.256936, 'Városközpont (Ferihegyi út)', 'F03610', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2913, 24, 47.451206, 19.172835, 'Lakatos úti lakótelep', 'F03839', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2914, 24, 47.437928, 19.071603, 'Corvin-csomópont', 'F04312', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2915, 25, 47.486236, 19.107515, 'Kőbányai út / Könyves Kálmán körút', '8149', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2916, 25, 47.443242, 18.887398, 'Törökbálint bevásárlóközpont', '8333', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2917, 25, 47.598408, 19.054259, 'Békásmegyer H', '8939', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2918, 25, 47.601212, 19.058056, 'Szolgáltatóház', 'F00462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2919, 25, 47.592318, 19.052072, 'Szent István utca', 'F00477', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2920, 25, 47.603002, 19.059267, 'Madzsar József utca / Hadrianus utca', 'F00636', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2921, 25, 47.460921, 19.025339, 'Than Károly utca', 'F01951', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2922, 25, 47.480522, 19.051932, 'Gárdonyi tér', 'F02149', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2923, 25, 47.473484, 18.992979, 'Gazdagréti tér', 'F02229', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2924, 25, 47.515491, 19.051044, 'Radnóti Miklós utca', 'F02649', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2925, 25, 47.527397, 19.098651, 'Fűrész utca', 'F02951', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2926, 25, 47.543802, 19.131449, 'Szerencs utca', 'F03130', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2927, 25, 47.473818, 19.25402, 'Széchenyi utca', 'F03517', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2928, 25, 47.449086, 19.192957, 'Kosztolányi Dezső utca', 'F03829', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2929, 25, 47.459126, 19.149287, 'Sós utca', 'F03942', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2930, 26, 47.652306, 19.071738, 'Egyetem', '8438', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2931, 26, 47.595572, 19.054226, 'Madzsar József utca / Pünkösdfürdő utca', '44130', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2932, 26, 47.57922, 19.023466, 'Óarany utca [10]', '56188', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2933, 26, 47.486169, 19.058009, 'Fővám tér', '56226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2934, 26, 47.534267, 19.103451, 'Rákosszeg park', '61136', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2935, 26, 47.59784, 19.054067, 'Békásmegyer H', 'F00461', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2936, 26, 47.599242, 19.064944, 'Bálint György utca', 'F00645', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2937, 26, 47.582698, 19.10624, 'Gimnázium', 'F00730', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2938, 26, 47.512046, 19.052319, 'Szent István körút', 'F00932', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2939, 26, 47.485948, 19.107544, 'Kőbányai út / Könyves Kálmán körút', 'F01269', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2940, 26, 47.466644, 19.1265, 'Kőér utcai uszoda', 'F01464', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2941, 26, 47.478154, 19.025717, 'Fehérló utca', 'F02113', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2942, 26, 47.51774, 19.052625, 'Szent István park', 'F02513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2943, 26, 47.516745, 19.222655, 'Cinke utca', 'F03387', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2944, 26, 47.443151, 19.18896, 'Gárdonyi Géza utca', 'F03704', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2945, 27, 47.391189, 19.114442, 'Szent István utca H', '49170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2946, 27, 47.40997, 19.060354, 'Csepel Csillagtelep', '67622', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2947, 27, 47.594908, 19.051833, 'Pünkösdfürdő utca', 'F00459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2948, 27, 47.598083, 19.054253, 'Békásmegyer H', 'F00460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2949, 27, 47.489461, 19.061486, 'Kálvin tér M', 'F01006', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2950, 27, 47.49773, 18.989397, 'Svábhegy', 'F02459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2951, 27, 47.514538, 19.054124, 'Radnóti Miklós utca', 'F02524', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2952, 27, 47.42948, 19.218393, 'Pestszentlőrinc Béke tér', 'F03788', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2953, 28, 47.560724, 19.090125, 'Újpest-központ M', '8697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2954, 28, 47.386389, 19.11049, 'Orbánhegyi dűlő', '9338', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2955, 28, 47.593723, 19.047254, 'Nád utca', '89725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2956, 28, 47.577718, 19.022364, 'Iglice utca / Jutas utca [9]', '100836', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2957, 28, 47.432125, 19.005257, 'Balatoni út / Háros utca', '103387', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2958, 28, 47.509594, 19.010039, 'Szent János Kórház', 'F00408', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2959, 28, 47.602642, 19.048242, 'Gulácsy Lajos utca', 'F00438', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2960, 28, 47.456639, 19.019984, 'Igmándi utca', 'F01918', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2961, 28, 47.524142, 19.098475, 'Kassai tér', 'F02900', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2962, 28, 47.52977, 19.103062, 'Pándorfalu utca', 'F02904', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2963, 28, 47.533231, 19.105788, 'Sárrét park', 'F02955', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2964, 28, 47.53963, 19.1286, 'Sztárai Mihály tér', 'F03097', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2965, 28, 47.483931, 19.230747, 'Pesti út', 'F03550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2966, 28, 47.470836, 19.25182, 'Bulyovszky utca', 'F03588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2967, 29, 47.580911, 19.02413, 'Muzsla utca [11]', '56187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2968, 29, 47.511228, 19.056461, 'Nyugati pályaudvar M', 'F01025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2969, 29, 47.473613, 19.097127, 'Albert Flórián út', 'F01501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2970, 29, 47.470427, 19.020056, 'Dayka Gábor utca', 'F02054', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2971, 29, 47.440405, 19.186849, 'Szarvas csárda tér', 'F03715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2972, 29, 47.442919, 19.175758, 'Kossuth Lajos utca', 'F03767', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2973, 29, 47.438409, 19.20479, 'Selmecbánya utca', 'F03851', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2974, 29, 47.462138, 19.150062, 'Kőbánya-Kispest M', 'F03941', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2975, 29, 47.391374, 19.106125, 'Szent István út', 'F04230', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2976, 29, 47.399762, 19.051158, 'Fácánhegyi utca', 'F04288', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2977, 29, 47.416869, 19.061787, 'Erdősor utca', 'F04350', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2978, 30, 47.597409, 18.92824, 'Solymár PEMÜ', '44511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2979, 30, 47.348981, 19.045586, 'Szigetszentmiklós városháza', '101178', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2980, 30, 47.455747, 18.94116, 'Budaörsi lakótelep', '103053', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2981, 30, 47.468419, 19.10727, 'Közterületfenntartó Zrt.', 'F01483', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2982, 30, 47.47126, 19.109422, 'Ecseri út M', 'F01495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2983, 30, 47.427678, 18.979985, 'Dűlő út', 'F02162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2984, 30, 47.535071, 19.113227, 'Szuglói körvasút sor', 'F02855', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2985, 30, 47.528617, 19.107111, 'Rákospatak utca', 'F02863', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2986, 30, 47.525785, 19.104691, 'Fűrész utca', 'F02948', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2987, 30, 47.433089, 19.174369, 'Szent Lőrinc-telep', 'F03621', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2988, 30, 47.435273, 19.202182, 'Üllői út', 'F03802', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2989, 30, 47.441814, 19.207651, 'Szent László utca', 'F03807', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2990, 30, 47.436351, 19.155732, 'Kassa utca', 'F03998', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2991, 30, 47.410636, 19.056207, 'Tejút utca', 'F04283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2992, 30, 47.439365, 18.900827, 'Széchenyi tér', 'F04761', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2993, 31, 47.48177, 19.103619, 'Vajda Péter utca', '8147', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2994, 31, 47.443871, 19.181125, 'Szakorvosi rendelőintézet', '8832', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2995, 31, 47.573138, 19.121088, 'Kovácsi Kálmán tér', '9503', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2996, 31, 47.502266, 18.91679, 'Szőlőskert utca', '61378', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2997, 31, 47.477735, 19.091387, 'Nagyvárad tér M', '86856', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2998, 31, 47.489854, 18.909282, 'Farkashegyi repülőtér', '98532', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (2999, 31, 47.558, 19.085313, 'Csányi László utca', 'F00812', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3000, 31, 47.481543, 19.103636, 'Vajda Péter utca', 'F01262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3001, 31, 47.479945, 19.067547, 'Boráros tér H', 'F01514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3002, 31, 47.465382, 19.152827, 'Felüljáró (Gyömrői út)', 'F01584', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3003, 31, 47.526722, 19.100628, 'Nezsider park', 'F02953', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3004, 31, 47.574361, 19.12218, 'Kovácsi Kálmán tér', 'F03018', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3005, 31, 47.425285, 19.214456, 'Beszterce utca', 'F03847', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3006, 31, 47.415452, 19.012731, 'Őszibarack utca', 'F04448', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3007, 31, 47.380058, 19.009762, 'Lacházi fogadó', 'F04681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3008, 32, 47.48601, 19.007071, 'Liptó utca', '8051', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3009, 32, 47.605951, 19.097621, 'Ezred utca', '9421', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3010, 32, 47.605157, 19.103711, 'Európa Center', '9748', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3011, 32, 47.585272, 19.132112, 'Károlyi Sándor út', '47712', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3012, 32, 47.584113, 19.131087, 'Hulladékhasznosító Mű', '63399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3013, 32, 47.351111, 19.04528, 'Wesselényi utca', '64244', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3014, 32, 47.526663, 18.948412, 'Laktanya', 'F00182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3015, 32, 47.578093, 19.106953, 'Erdősor út', 'F00748', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3016, 32, 47.503316, 18.990107, 'Adonis utca', 'F02321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3017, 32, 47.533388, 19.067367, 'Göncz Árpád városközpont M', 'F02605', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3018, 32, 47.534361, 19.112482, 'Öv utca', 'F02857', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3019, 32, 47.532177, 19.110421, 'Miskolci utca', 'F02911', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3020, 32, 47.585758, 19.132552, 'Károlyi Sándor út', 'F03004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3021, 32, 47.577327, 19.124882, 'Árokhát út', 'F03171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3022, 32, 47.579709, 19.127052, 'Székely Elek út', 'F03176', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3023, 32, 47.476055, 19.244172, 'Árpád fejedelem utca', 'F03538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3024, 32, 47.443516, 19.163403, 'Dembinszky utca', 'F03757', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3025, 32, 47.422052, 19.211477, 'Halomi út', 'F03849', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3026, 32, 47.41328, 19.058619, 'Vas Gereben utca', 'F04273', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3027, 32, 47.421572, 19.06575, 'Csepel H', 'F04336', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3028, 32, 47.421663, 19.035773, 'Komló utca', 'F04410', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3029, 32, 47.513697, 18.932931, 'Gyógyszertár', 'F04685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3030, 33, 47.431808, 19.173804, 'Kele utca', '7923', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3031, 33, 47.419483, 19.209137, 'Kétújfalu utca', '8904', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3032, 33, 47.571892, 19.096021, 'Vécsey Károly utca', '9557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3033, 33, 47.44313, 19.184332, 'Lőrinci piac', '50184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3034, 33, 47.580286, 19.088731, 'Megyeri út / Fóti út', 'F00714', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3035, 33, 47.575378, 19.104398, 'Vadgesztenye utca', 'F00752', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3036, 33, 47.572003, 19.087768, 'Mildenberger utca', 'F00850', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3037, 33, 47.570052, 19.085747, 'Perényi Zsigmond utca', 'F00895', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3038, 33, 47.452427, 18.990774, 'Vitorlázó út', 'F01936', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3039, 33, 47.524799, 19.09655, 'Kassai tér', 'F02898', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3040, 33, 47.46754, 19.243703, 'Rákoshegy vasútállomás', 'F03525', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3041, 33, 47.461721, 19.237939, 'Vörösmarty utca', 'F03591', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3042, 33, 47.437752, 19.17108, 'Margó Tivadar utca', 'F03764', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3043, 34, 47.599469, 19.092788, 'Vízművek', '8644', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3044, 34, 47.409629, 19.004123, 'Gyula vezér út', '8971', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3045, 34, 47.342349, 19.045001, 'Kisfaludy utca', '64186', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3046, 34, 47.563986, 19.036284, 'Dinamó utca', 'F00514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3047, 34, 47.574044, 19.089816, 'Irányi Dániel utca / Baross utca', 'F00770', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3048, 34, 47.569777, 19.099594, 'Vécsey Károly utca', 'F00773', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3049, 34, 47.458538, 18.997345, 'Keserűvíz-forrás', 'F01934', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3050, 34, 47.463329, 19.239536, 'Melczer utca', 'F03524', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3051, 34, 47.435497, 19.168953, 'Iker utca', 'F03723', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3052, 34, 47.59249, 18.938223, 'Solymár községháza', 'F04702', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3053, 35, 47.483616, 19.053537, 'Szent Gellért tér - Műegyetem M', '8591', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3054, 35, 47.575692, 19.083757, 'Szusza Ferenc Stadion', '9148', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3055, 35, 47.345454, 19.048205, 'Szigetszentmiklós H', '36236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3056, 35, 47.428215, 19.059125, 'Duna utca', '41093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3057, 35, 47.57702, 19.092809, 'Baross utca / Fóti út', 'F00755', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3058, 35, 47.480718, 19.06768, 'Boráros tér H', 'F01372', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3059, 35, 47.568755, 19.126305, 'Rákospalota Kossuth utca', 'F03026', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3060, 35, 47.456728, 19.233014, 'Bocskai István utca', 'F03563', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3061, 35, 47.453433, 19.229595, 'Orgoványi utca', 'F03565', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3062, 35, 47.466193, 19.242372, 'Ady Endre utca', 'F03590', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3063, 35, 47.459261, 19.235516, 'Rákóczi Ferenc utca', 'F03597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3064, 35, 47.416581, 19.00148, 'Baross Gábor-telep Ispiláng utca', 'F04476', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3065, 36, 47.44845, 19.159667, 'Kispest vasútállomás', '117054', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3066, 36, 47.58141, 19.089955, 'Megyeri temető', 'F00710', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3067, 36, 47.586247, 19.095104, 'Megyer Szondi utca', 'F00718', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3068, 36, 47.566842, 19.082515, 'Langlet Waldemár utca', 'F00894', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3069, 36, 47.468581, 19.155923, 'Gergely utca (Sibrik Miklós út)', 'F01693', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3070, 36, 47.472265, 19.159897, 'Szövőszék utca', 'F01698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3071, 36, 47.519009, 19.126232, 'Egressy út / Vezér utca', 'F02803', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3072, 36, 47.530596, 19.121668, 'Öv utca', 'F02851', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3073, 36, 47.520718, 19.110647, 'Telepes utca', 'F02854', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3074, 36, 47.561651, 19.123225, 'Epres sor', 'F03039', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3075, 36, 47.471574, 19.239725, 'Bánya part', 'F03534', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3076, 37, 47.570602, 19.128267, 'Mogyoród útja', '7968', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3077, 37, 47.597742, 19.091577, 'Vízművek lakótelep', '8708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3078, 37, 47.403776, 19.011043, 'Rózsakert utca / Minta utca', '102569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3079, 37, 47.430323, 19.270282, 'Cargo City', '105507', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3080, 37, 47.514973, 19.014549, 'Ruszti út', 'F00301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3081, 37, 47.508483, 19.027323, 'Széna tér', 'F00308', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3082, 37, 47.508376, 19.027374, 'Széna tér', 'F00309', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3083, 37, 47.576135, 19.132124, 'Szántóföld utca', 'F03008', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3084, 37, 47.447182, 19.15824, 'Kispest vasútállomás', 'F03733', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3085, 37, 47.441258, 19.149908, 'Áchim András utca', 'F03994', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3086, 37, 47.516454, 18.935704, 'Erkel Ferenc utca', 'F04683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3087, 37, 47.431392, 18.916714, 'Munkácsy Mihály utca (hősi emlékmű)', 'F04755', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3088, 38, 47.50671, 19.02524, 'Széll Kálmán tér M', '36678', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3089, 38, 47.377332, 19.007248, 'Lakihegy Cseresznyés utca', '37747', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3090, 38, 47.540451, 19.006603, 'Fenyőgyöngye', '44029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3091, 38, 47.349733, 19.026063, 'Nap utca (óvoda)', '64106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3092, 38, 47.569258, 19.092829, 'Türr István utca / Nádor utca', 'F00777', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3093, 38, 47.597652, 19.09143, 'Vízművek lakótelep', 'F00916', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3094, 38, 47.489707, 19.136824, 'Harmat utca', 'F01629', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3095, 38, 47.489231, 19.136141, 'Ónodi utca', 'F01826', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3096, 38, 47.487715, 19.025553, 'BAH-csomópont', 'F02168', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3097, 38, 47.45492, 18.993158, 'Örsöddűlő', 'F02232', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3098, 38, 47.494972, 19.01926, 'Királyhágó tér', 'F02421', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3099, 38, 47.523603, 19.113864, 'Fűrész utca', 'F02853', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3100, 38, 47.526462, 19.117054, 'Szentes utca', 'F02914', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3101, 38, 47.519688, 19.118944, 'Fűrész utca / Szugló utca', 'F02944', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3102, 38, 47.571821, 19.127001, 'Esthajnal utca', 'F03014', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3103, 38, 47.487873, 19.287742, 'Csaba vezér tér', 'F03485', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3104, 38, 47.408293, 19.016499, 'Lépcsős utca', 'F04455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3105, 38, 47.390955, 18.981715, 'Angeli utca / Nagytétényi út', 'F04498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3106, 39, 47.495451, 19.029425, 'Ág utca', 'F00098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3107, 39, 47.567961, 19.097759, 'Szent László tér', 'F00780', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3108, 39, 47.507147, 19.086459, 'Ötvenhatosok tere', 'F02709', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3109, 39, 47.5087, 19.095394, 'Stefánia út / Thököly út', 'F02723', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3110, 39, 47.520384, 19.113674, 'Bosnyák tér', 'F02836', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3111, 39, 47.574373, 19.130035, 'Vághó Ferenc utca', 'F03010', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3112, 39, 47.56808, 19.125932, 'Rákospalota Kossuth utca', 'F03027', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3113, 39, 47.474125, 19.242228, 'Bulyovszky utca', 'F03536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3114, 40, 47.491495, 19.138759, 'Élessarok', 'F01624', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3115, 40, 47.535692, 19.052506, 'Zenélőkút', 'F02500', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3116, 40, 47.510348, 19.090696, 'Zichy Géza utca', 'F02712', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3117, 40, 47.526716, 19.127133, 'Szugló utca / Cinkotai út', 'F02809', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3118, 40, 47.524617, 19.118844, 'Rákospatak utca / Csömöri út', 'F02845', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3119, 40, 47.521347, 19.092471, 'Amerikai út (Mexikói út M)', 'F02925', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3120, 40, 47.524739, 19.124833, 'Miskolci utca / Szugló utca', 'F02942', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3121, 41, 47.461082, 19.119025, 'Távíró utca', '8575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3122, 41, 47.500234, 18.916821, 'Budakeszi Tesco áruház', '44371', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3123, 41, 47.440533, 18.897203, 'Törökbálint Sportközpont', '74495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3124, 41, 47.565968, 19.088787, 'Illek Vince utca', 'F00783', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3125, 41, 47.472723, 19.096118, 'Albert Flórián út', 'F01499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3126, 41, 47.473429, 19.144517, 'Gergely utca', 'F01691', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3127, 41, 47.481529, 19.038107, 'Ménesi út', 'F02254', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3128, 41, 47.505814, 19.091582, 'Cházár András utca', 'F02716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3129, 41, 47.519835, 19.112956, 'Bosnyák tér', 'F02835', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3130, 41, 47.527214, 19.122035, 'Miskolci utca / Csömöri út', 'F02846', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3131, 41, 47.559231, 19.131279, 'Telek utca', 'F03042', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3132, 41, 47.546521, 19.126857, 'Szerencs utca / Bánkút utca', 'F03111', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3133, 41, 47.549649, 19.122575, 'Szerencs utca', 'F03113', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3134, 41, 47.551768, 19.122401, 'Klapka György utca', 'F03118', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3135, 41, 47.55012, 19.125228, 'Kozák tér', 'F03119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3136, 41, 47.551733, 19.13364, 'Rákospalotai köztemető', 'F03129', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3137, 41, 47.537242, 19.13525, 'Apolló utca', 'F03136', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3138, 41, 47.539093, 19.137697, 'Madách utca', 'F03139', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3139, 41, 47.533531, 19.13049, 'Molnár Viktor utca', 'F03195', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3140, 41, 47.547212, 19.11457, 'Szent Korona útja', 'F03204', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3141, 41, 47.533585, 19.208036, 'Árpádföld Bekecs utca', 'F03299', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3142, 41, 47.438548, 19.157692, 'Kolozsvár utca (temető)', 'F03727', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3143, 41, 47.419066, 19.06571, 'Völgy utca', 'F04293', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3144, 42, 47.507313, 19.026381, 'Széll Kálmán tér M', '8086', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3145, 42, 47.443626, 19.096848, 'Gubacsi út / Határ út', '8440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3146, 42, 47.511873, 19.099618, 'Zugló vasútállomás', '9426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3147, 42, 47.417185, 19.130752, 'Szentlőrinci úti lakótelep', '43331', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3148, 42, 47.394741, 19.116818, 'Gyáli-patak', '49172', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3149, 42, 47.585494, 19.109088, 'Dunakeszi utca', 'F00871', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3150, 42, 47.517303, 19.123964, 'Turán utca', 'F02800', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3151, 42, 47.563825, 19.113289, 'Sződliget utca', 'F03052', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3152, 42, 47.540104, 19.119755, 'Vág utca', 'F03100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3153, 42, 47.548997, 19.123765, 'Bercsényi Miklós utca', 'F03122', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3154, 42, 47.451072, 19.226497, 'Baross utca', 'F03432', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3155, 42, 47.424552, 19.158777, 'Szentlőrinci út (gyorsétterem)', 'F03629', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3156, 42, 47.457455, 19.14687, 'Simonyi Zsigmond utca', 'F03950', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3157, 42, 47.454149, 19.14205, 'Kisfaludy utca', 'F03955', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3158, 42, 47.444619, 19.153548, 'Ady Endre út', 'F04000', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3159, 42, 47.414025, 19.06975, 'Dr. Koncz János tér', 'F04212', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3160, 42, 47.416953, 19.062979, 'Kossuth Lajos utca', 'F04367', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3161, 43, 47.560201, 19.283112, 'Szilasligeti elágazás', '9004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3162, 43, 47.56955, 19.272176, 'Szilasliget Posta', '9007', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3163, 43, 47.415224, 19.022189, 'Dévény utca', '9453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3164, 43, 47.519964, 19.010576, 'Bimbó út', '9514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3165, 43, 47.565891, 19.023273, 'Óbudai temető', 'F00523', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3166, 43, 47.507189, 19.05573, 'Báthory utca / Bajcsy-Zsilinszky út', 'F00940', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3167, 43, 47.489365, 18.959324, 'KFKI étterem', 'F02271', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3168, 43, 47.552415, 19.084459, 'Kámfor utca', 'F02532', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3169, 43, 47.520944, 19.135954, 'Gvadányi utca', 'F02792', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3170, 43, 47.522633, 19.138347, 'Cinkotai út / Mogyoródi út', 'F02940', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3171, 43, 47.537838, 19.164139, 'György utca', 'F02983', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3172, 43, 47.546089, 19.113148, 'Széchenyi út', 'F03082', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3173, 43, 47.542997, 19.108694, 'Karatna tér', 'F03173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3174, 43, 47.537902, 19.116604, 'Tóth István utca', 'F03206', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3175, 43, 47.464222, 19.127748, 'Határ út M', 'F04019', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3176, 43, 47.415518, 19.071645, 'Völgy utca', 'F04210', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3177, 43, 47.387643, 19.215398, 'Rákóczi Ferenc utca', 'F04648', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3178, 44, 47.387982, 18.970812, 'Nagytétény Erdélyi utca', '49787', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3179, 44, 47.355057, 19.025849, 'Ady Endre utca', '64108', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3180, 44, 47.499937, 19.055655, 'Bajcsy-Zsilinszky út', 'F00997', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3181, 44, 47.51154, 19.072961, 'Bajza utca M', 'F01098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3182, 44, 47.443779, 19.096742, 'Gubacsi út / Határ út', 'F01439', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3183, 44, 47.478878, 19.123878, 'Kocsis Sándor Sportközpont', 'F01576', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3184, 44, 47.482305, 19.157968, 'Kada utca / Maglódi út', 'F01678', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3185, 44, 47.508361, 19.008566, 'Szent János Kórház', 'F02329', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3186, 44, 47.511373, 18.985943, 'Galgóczy utca', 'F02375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3187, 44, 47.535474, 19.116136, 'Árvavár utca', 'F02688', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3188, 44, 47.52452, 19.141019, 'Öv utca / Mogyoródi út', 'F02793', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3189, 44, 47.516875, 19.115541, 'Szugló utca / Nagy Lajos király útja', 'F02816', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3190, 44, 47.529718, 19.152688, 'Rákóczi út', 'F02990', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3191, 44, 47.532493, 19.156637, 'József utca', 'F02994', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3192, 44, 47.40897, 19.194107, 'Kisfaludy utca', 'F03651', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3193, 44, 47.448788, 19.130677, 'Hunyadi tér', 'F03973', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3194, 45, 47.396895, 19.175423, 'Ültetvény utca', '7924', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3195, 45, 47.507413, 19.066787, 'Vörösmarty utca M', '7980', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3196, 45, 47.391718, 19.221172, 'Kossuth Lajos utca', '9207', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3197, 45, 47.511591, 19.147375, 'Csertő utca', '9499', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3198, 45, 47.580948, 19.161548, 'Fót Auchan áruház', '45883', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3199, 45, 47.487963, 19.300796, 'Ecsedháza köz', '66685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3200, 45, 47.465452, 19.015814, 'Sasadi út', '75744', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3201, 45, 47.352019, 19.062635, 'Béke utca', '101181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3202, 45, 47.499895, 19.055596, 'Bajcsy-Zsilinszky út M', 'F00944', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3203, 45, 47.502611, 19.059671, 'Opera M', 'F01078', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3204, 45, 47.502305, 19.059161, 'Opera', 'F01080', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3205, 45, 47.505023, 19.063233, 'Oktogon', 'F01086', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3206, 45, 47.507238, 19.066529, 'Vörösmarty utca', 'F01093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3207, 45, 47.509261, 19.069544, 'Kodály körönd', 'F01096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3208, 45, 47.509832, 19.070465, 'Kodály körönd M', 'F01097', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3209, 45, 47.511342, 19.072662, 'Bajza utca', 'F01101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3210, 45, 47.51411, 19.076883, 'Hősök tere', 'F01103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3211, 45, 47.498319, 19.069664, 'Blaha Lujza tér M (Dohány utca)', 'F01116', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3212, 45, 47.488897, 19.091276, 'Orczy út', 'F01334', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3213, 45, 47.448642, 19.104145, 'Jókai Mór utca / Határ út', 'F01445', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3214, 45, 47.455742, 19.114682, 'Nagykőrösi út / Határ út', 'F01454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3215, 45, 47.459749, 19.120986, 'Mészáros Lőrinc utca', 'F01455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3216, 45, 47.459543, 19.120283, 'Mészáros Lőrinc utca', 'F01457', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3217, 45, 47.471722, 19.14218, 'Kada utca', 'F01580', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3218, 45, 47.465155, 19.015363, 'Sasadi út', 'F01927', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3219, 45, 47.47653, 18.987351, 'Irhás árok', 'F02075', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3220, 45, 47.477725, 19.046267, 'Móricz Zsigmond körtér M', 'F02199', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3221, 45, 47.508325, 19.008474, 'Szent János Kórház', 'F02343', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3222, 45, 47.507533, 19.026097, 'Széll Kálmán tér', 'F02481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3223, 45, 47.54374, 19.114925, 'Széchenyi út', 'F03087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3224, 45, 47.544098, 19.12367, 'Wesselényi utca', 'F03189', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3225, 45, 47.411502, 19.197983, 'Damjanich utca', 'F03653', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3226, 45, 47.406313, 19.189953, 'Ady Endre utca', 'F03664', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3227, 45, 47.440907, 19.160673, 'Csapó utca', 'F03729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3228, 45, 47.454562, 19.139152, 'Ady Endre út (Hunyadi utca)', 'F03957', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3229, 45, 47.451723, 19.134983, 'Áchim András utca', 'F03976', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3230, 45, 47.4499, 19.146153, 'Ady Endre út', 'F03978', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3231, 45, 47.444771, 19.138573, 'Kossuth Lajos utca', 'F03984', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3232, 45, 47.440369, 19.117964, 'Lázár utca', 'F04103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3233, 45, 47.446081, 19.116539, 'Mártírok útja', 'F04140', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3234, 46, 47.539377, 19.122388, 'Észak-Pesti Kórház', '8353', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3235, 46, 47.40247, 18.937784, 'Petőfi Sándor utca', '8480', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3236, 46, 47.525831, 19.228646, 'Ilonatelep H', '8775', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3237, 46, 47.590307, 19.083674, 'Bagaria utca', '9573', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3238, 46, 47.486349, 19.298294, 'Csabamező utca / Göcsej utca', '66683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3239, 46, 47.504158, 19.061968, 'Oktogon M', 'F01089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3240, 46, 47.44605, 19.10022, 'Ősz utca', 'F01442', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3241, 46, 47.451841, 19.108975, 'Mártírok útja / Határ út', 'F01448', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3242, 46, 47.479529, 19.153902, 'Kada utca / Mádi utca', 'F01682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3243, 46, 47.478038, 19.151591, 'Kada utca / Harmat utca', 'F01684', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3244, 46, 47.507108, 19.140723, 'Álmos vezér útja', 'F02766', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3245, 46, 47.564491, 19.120815, 'Széchenyi tér', 'F03046', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3246, 46, 47.541542, 19.12159, 'Rákospalota MÁV-telep', 'F03102', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3247, 46, 47.536854, 19.246124, 'Raktárak', 'F03284', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3248, 46, 47.530799, 19.236539, 'Cinkotai köztemető', 'F03405', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3249, 46, 47.425656, 19.173485, 'Ipacsfa utca (Közdűlő út)', 'F03627', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3250, 46, 47.408878, 19.180924, 'Törvény utca', 'F03662', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3251, 46, 47.398951, 19.178541, 'Lajos utca', 'F03677', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3252, 46, 47.413746, 19.201487, 'Alacskai úti lakótelep', 'F03860', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3253, 46, 47.44172, 19.140928, 'Kispest Vas Gereben utca', 'F03990', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3254, 46, 47.447079, 19.141971, 'Áchim András utca', 'F04026', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3255, 46, 47.444861, 19.124798, 'Nagykőrösi út', 'F04043', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3256, 46, 47.443675, 19.122966, 'Zalán utca', 'F04100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3257, 46, 47.438562, 19.115283, 'Vécsey utca', 'F04107', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3258, 46, 47.442603, 19.111285, 'Tátra tér', 'F04137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3259, 46, 47.510682, 18.92957, 'Budakeszi városháza', 'F04709', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3260, 47, 47.552441, 19.270507, 'Kerepes H', '9002', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3261, 47, 47.375229, 19.120911, 'BILK DF épület', '9314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3262, 47, 47.516184, 19.004033, 'Pasaréti út', '9510', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3263, 47, 47.514978, 19.002123, 'Gábor Áron utca', '9581', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3264, 47, 47.548044, 19.263894, 'Zsófialiget H', '9763', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3265, 47, 47.375594, 19.041993, 'Leshegy Ipari Park', '64242', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3266, 47, 47.473748, 19.115739, 'Balkán utca', 'F01532', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3267, 47, 47.475742, 19.118819, 'Fertő utca / Bihari utca', 'F01538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3268, 47, 47.468555, 19.164824, 'Mélytó utca', 'F01696', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3269, 47, 47.50742, 18.994736, 'Erdei iskola', 'F02323', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3270, 47, 47.51412, 19.119215, 'Egressy tér', 'F02799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3271, 47, 47.516951, 19.108478, 'Tisza István tér', 'F02824', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3272, 47, 47.528472, 19.123842, 'Cinkotai út', 'F02848', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3273, 47, 47.522352, 19.101636, 'Nagy Lajos király útja / Czobor utca', 'F02866', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3274, 47, 47.512197, 19.116159, 'Róna utca', 'F02916', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3275, 47, 47.519618, 19.097435, 'Laky Adolf utca', 'F02962', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3276, 47, 47.444119, 19.187428, 'Regény utca', 'F03706', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3277, 47, 47.400855, 19.181473, 'Szélső utca', 'F03821', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3278, 47, 47.41003, 19.020671, 'Jókai Mór utca', 'F04454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3279, 47, 47.413853, 18.996447, 'XVI. utca', 'F04514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3280, 47, 47.539805, 19.252603, 'Kistarcsa kórház H', 'F04584', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3281, 47, 47.407793, 18.946173, 'Kölcsey Ferenc utca', 'F04816', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3282, 48, 47.545503, 19.259839, 'Kistarcsa H', '9000', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3283, 48, 47.59805, 19.349038, 'Gödöllő Szabadság tér H', '9018', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3284, 48, 47.419869, 19.120349, 'Vörösmarty utca', '9147', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3285, 48, 47.404518, 19.187166, 'Pestszentimre vasútállomás (Nemes utca)', '9234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3286, 48, 47.561334, 19.04819, 'Záhony utca', 'F00505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3287, 48, 47.587758, 19.1126, 'Kordován tér', 'F00900', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3288, 48, 47.486952, 19.057611, 'Fővám tér M', 'F00923', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3289, 48, 47.505236, 19.06903, 'Izabella utca / Király utca', 'F01043', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3290, 48, 47.511542, 19.062713, 'Ferdinánd híd (Izabella utca)', 'F01070', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3291, 48, 47.500288, 19.072996, 'Szövetség utca', 'F01173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3292, 48, 47.464706, 19.103857, 'Gyáli út', 'F01479', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3293, 48, 47.501684, 19.115642, 'Várna utca', 'F01738', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3294, 48, 47.481977, 18.999233, 'Márton Áron tér', 'F02085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3295, 48, 47.519648, 19.148326, 'Rákosszentmihály vasútállomás', 'F02690', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3296, 48, 47.504902, 19.10139, 'Egressy út / Stefánia út', 'F02735', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3297, 48, 47.515329, 19.139861, 'Fischer István utca', 'F02783', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3298, 48, 47.513883, 19.137323, 'Vezér utca / Fogarasi út', 'F02786', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3299, 48, 47.517407, 19.094034, 'Erzsébet királyné útja aluljáró', 'F02876', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3300, 48, 47.503472, 19.11883, 'Pillangó utca', 'F02929', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3301, 48, 47.495993, 19.261234, 'Ananász utca', 'F03573', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3302, 48, 47.428207, 19.120015, 'Wesselényi utca', 'F04064', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3303, 48, 47.411927, 18.993295, 'XIII. utca / Dózsa György út', 'F04475', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3304, 48, 47.508816, 18.926543, 'Erdő utca', 'F04734', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3305, 48, 47.40531, 18.942207, 'Csapágygyár', 'F04773', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3306, 49, 47.597226, 19.117132, 'Homoktövis iskola', '8911', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3307, 49, 47.421259, 19.127136, 'Tartsay utca', '8964', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3308, 49, 47.418292, 19.138131, 'Maros utca', '45755', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3309, 49, 47.501817, 19.075598, 'Rózsák tere', '62517', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3310, 49, 47.518906, 19.06096, 'Lehel tér M', '90370', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3311, 49, 47.503123, 19.065419, 'Király utca / Erzsébet körút', 'F01036', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3312, 49, 47.506899, 19.071765, 'Lövölde tér', 'F01044', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3313, 49, 47.513035, 19.065196, 'Szinyei Merse utca', 'F01107', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3314, 49, 47.500466, 19.081955, 'Keleti pályaudvar M', 'F01159', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3315, 49, 47.473371, 19.177835, 'Új köztemető', 'F01596', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3316, 49, 47.45357, 19.196908, 'Csévéző utca', 'F03695', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3317, 49, 47.416941, 19.206506, 'Alacskai út', 'F03799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3318, 49, 47.43234, 19.127073, 'Ady Endre tér', 'F04046', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3319, 49, 47.43354, 19.105548, 'Vörösmarty utca', 'F04117', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3320, 49, 47.431922, 19.102749, 'Attila utca', 'F04156', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3321, 50, 47.417213, 19.158245, 'Soroksár Auchan áruház', '9566', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3322, 50, 47.483039, 19.055036, 'Szent Gellért tér - Műegyetem', '56224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3323, 50, 47.416057, 19.143215, 'Köves út / Szentlőrinci út', '75654', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3324, 50, 47.486932, 19.057648, 'Fővám tér M', '87393', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3325, 50, 47.523519, 18.993126, 'Pasaréti tér', 'F00279', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3326, 50, 47.564925, 18.950028, 'Gyarmati Dezső Uszoda', 'F00392', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3327, 50, 47.589187, 19.114942, 'Szíjgyártó utca', 'F00726', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3328, 50, 47.509123, 19.058664, 'Nyugati pályaudvar M (Podmaniczky utca)', 'F01030', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3329, 50, 47.518775, 19.111282, 'Bosnyák tér', 'F02834', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3330, 50, 47.52316, 19.171835, 'János utca', 'F03252', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3331, 50, 47.456019, 19.201172, 'Frangepán utca', 'F03428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3332, 50, 47.439422, 19.104666, 'Ady Endre utca (Topánka utca)', 'F04129', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3333, 50, 47.420783, 19.126353, 'Szent László utca', 'F04573', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3334, 51, 47.581093, 19.037502, 'Forrásliget lakópark', '8406', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3335, 51, 47.403548, 19.185706, 'Pestszentimre vasútállomás (Dózsa Gy.u.)', '8754', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3336, 51, 47.433265, 19.177803, 'Margó Tivadar utca', '9480', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3337, 51, 47.500485, 19.081704, 'Keleti pályaudvar', '56234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3338, 51, 47.505504, 19.021603, 'Maros utca', '73152', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3339, 51, 47.52224, 18.990804, 'Kelemen László utca', 'F00414', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3340, 51, 47.51871, 19.065238, 'Dévai utca', 'F01062', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3341, 51, 47.518658, 19.065238, 'Dévai utca', 'F01064', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3342, 51, 47.517108, 18.980719, 'Lóvasút', 'F02383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3343, 51, 47.521092, 19.069793, 'Lehel utca / Dózsa György út', 'F02626', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3344, 51, 47.523961, 19.074946, 'Hun utca', 'F02668', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3345, 51, 47.524275, 19.075676, 'Hun utca', 'F02670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3346, 51, 47.511145, 19.098662, 'Zugló vasútállomás', 'F02725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3347, 51, 47.506167, 19.123638, 'Róna utca', 'F02747', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3348, 51, 47.508916, 19.128567, 'Pongrátz Gergely tér', 'F02751', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3349, 51, 47.507865, 19.126667, 'Kaffka Margit utca', 'F02931', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3350, 51, 47.513581, 19.102501, 'Amerikai út', 'F02961', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3351, 51, 47.527159, 19.148088, 'Rákospalotai határút', 'F02992', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3352, 51, 47.42992, 19.168981, 'Ipacsfa utca', 'F03624', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3353, 51, 47.382631, 19.012687, 'Gát utca', 'F04642', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3354, 52, 47.41286, 18.930262, 'Tátika utca', '9101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3355, 52, 47.496737, 19.050593, 'Vörösmarty tér', 'F00965', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3356, 52, 47.515929, 19.074543, 'Honvédkórház (Hősök tere M)', 'F01054', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3357, 52, 47.503468, 19.088169, 'Reiner Frigyes park', 'F01146', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3358, 52, 47.449168, 19.049119, 'Kondorosi út', 'F01977', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3359, 52, 47.508047, 18.989983, 'Felső Svábhegyi út', 'F02371', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3360, 52, 47.520751, 19.069009, 'Lehel utca / Dózsa György út', 'F02624', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3361, 52, 47.511593, 19.133297, 'Mályva utca', 'F02936', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3362, 52, 47.513547, 19.220096, 'Anilin utca', 'F03325', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3363, 52, 47.399054, 19.205588, 'Pestszentimre Benjámin utca', 'F03800', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3364, 52, 47.437553, 19.101283, 'Pesterzsébet városközpont', 'F04122', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3365, 52, 47.415974, 19.134363, 'Dinnyehegyi út', 'F04524', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3366, 52, 47.460145, 18.934511, 'Alcsiki dűlő', 'F04830', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3367, 53, 47.489245, 19.061938, 'Kálvin tér', '56228', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3368, 53, 47.487663, 19.03646, 'Szirtes út', 'F00010', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3369, 53, 47.514851, 19.06829, 'Bajza utca', 'F01105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3370, 53, 47.470805, 19.029113, 'Csóka utca', 'F02043', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3371, 53, 47.486708, 18.954844, 'Csillebérc KFKI', 'F02268', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3372, 53, 47.50819, 19.108442, 'Törökőr utca', 'F02918', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3373, 53, 47.510213, 19.112523, 'Posta Járműtelep', 'F02956', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3374, 53, 47.536661, 19.162251, 'Rákospalotai határút', 'F02986', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3375, 53, 47.521426, 19.15214, 'Pálya utca', 'F03276', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3376, 53, 47.519943, 19.21924, 'Vidámvásár utca', 'F03305', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3377, 53, 47.427276, 19.172852, 'Tövishát utca', 'F03786', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3378, 53, 47.411328, 19.065403, 'Iskola tér', 'F04335', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3379, 54, 47.402042, 19.015774, 'Növény utca', '8336', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3380, 54, 47.611694, 19.105926, 'Székesdűlő', '8642', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3381, 54, 47.588638, 19.097738, 'Szilaspatak sor', '70127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3382, 54, 47.487186, 19.05781, 'Fővám tér M', 'F00922', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3383, 54, 47.502054, 19.062989, 'Akácfa utca', 'F01032', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3384, 54, 47.501824, 19.082036, 'Keleti pályaudvar M (Garay utca)', 'F01131', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3385, 54, 47.480376, 19.066698, 'Boráros tér H', 'F01374', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3386, 54, 47.476933, 19.059586, 'Petőfi híd budai hídfő', 'F02225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3387, 54, 47.520301, 19.179396, 'Budapesti út', 'F03246', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3388, 54, 47.511951, 19.21652, 'Farkashalom utca', 'F03384', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3389, 54, 47.486806, 19.286317, 'Csaba vezér tér', 'F03482', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3390, 54, 47.39378, 19.04027, 'Almafa utca', 'F04289', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3391, 54, 47.395139, 19.043052, 'Szilvafa utca', 'F04352', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3392, 54, 47.41232, 19.006757, 'Park utca', 'F04443', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3393, 54, 47.394676, 18.992863, 'Bartók Béla út', 'F04479', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3394, 54, 47.38888, 18.958553, 'Nagytétény ipartelep', 'F04507', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3395, 55, 47.558551, 19.078245, 'Újpest-városkapu M', '7902', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3396, 55, 47.43617, 18.926976, 'Tükörhegy', '8325', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3397, 55, 47.513666, 19.15103, 'Körvasút sor', '9500', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3398, 55, 47.518119, 19.007404, 'Orló utca', '9509', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3399, 55, 47.466331, 19.165078, 'Bányató utca', '40612', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3400, 55, 47.502824, 19.077404, 'Rottenbiller utca / István utca', 'F01121', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3401, 55, 47.507429, 19.07803, 'Nefelejcs utca / Damjanich utca', 'F01125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3402, 55, 47.506257, 19.084998, 'Ötvenhatosok tere (István utca)', 'F01142', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3403, 55, 47.468305, 19.169341, 'Újhegyi út Sportliget', 'F01589', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3404, 55, 47.46223, 19.008853, 'Jégvirág utca', 'F01929', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3405, 55, 47.474091, 19.053736, 'Budafoki út / Szerémi sor', 'F01992', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3406, 55, 47.47659, 19.059228, 'Petőfi híd budai hídfő', 'F02222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3407, 55, 47.515546, 19.09078, 'Ciklámen utca', 'F02704', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3408, 55, 47.530347, 19.171573, 'Diófa utca', 'F03223', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3409, 55, 47.528139, 19.166747, 'János utca', 'F03259', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3410, 55, 47.51844, 19.161588, 'Batthyány utca', 'F03268', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3411, 55, 47.489837, 19.290787, 'Czeglédi Mihály utca', 'F03440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3412, 55, 47.428089, 19.165106, 'Besence utca', 'F03626', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3413, 55, 47.428595, 19.175401, 'Goroszló utca', 'F03879', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3414, 55, 47.432698, 18.918673, 'Bartók Béla utca', 'F04754', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3415, 55, 47.407385, 18.95191, 'Diósd Sashegyi út', 'F04769', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3416, 56, 47.590345, 19.101781, 'Óceán-árok utca', '8284', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3417, 56, 47.527548, 19.181565, 'Szénás utca', '8946', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3418, 56, 47.414113, 18.93283, 'Valéria utca', '9103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3419, 56, 47.521071, 19.012168, 'Endrődi Sándor utca', 'F00295', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3420, 56, 47.567996, 19.026289, 'Óbudai autóbuszgarázs', 'F00678', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3421, 56, 47.585797, 19.094678, 'Megyer Szondi utca', 'F00716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3422, 56, 47.516832, 19.061852, 'Lehel tér M', 'F01065', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3423, 56, 47.504532, 19.081201, 'Nefelejcs utca / István utca', 'F01130', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3424, 56, 47.50911, 19.081655, 'Damjanich utca / Dózsa György út', 'F01136', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3425, 56, 47.47056, 19.17418, 'Maglódi út', 'F01592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3426, 56, 47.532114, 19.175441, 'Mátyás király utca', 'F03226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3427, 56, 47.52594, 19.161989, 'József utca', 'F03262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3428, 56, 47.524083, 19.157935, 'Rákóczi út', 'F03263', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3429, 56, 47.391217, 19.035343, 'Vízművek lakótelep', 'F04354', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3430, 57, 47.42616, 19.16094, 'Dél-pesti autóbuszgarázs', '8094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3431, 57, 47.441434, 18.938378, 'Raktárváros', '8319', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3432, 57, 47.530956, 19.189157, 'Szlovák út', '8947', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3433, 57, 47.515819, 19.155754, 'Rózsa utca', '9381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3434, 57, 47.479444, 19.090234, 'Nagyvárad tér M', 'F01246', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3435, 57, 47.47939, 19.090406, 'Nagyvárad tér M', 'F01247', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3436, 57, 47.466218, 19.076257, 'Közvágóhíd H (Kvassay Jenő út)', 'F01407', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3437, 57, 47.444609, 18.986738, 'Repülőtér', 'F01888', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3438, 57, 47.46862, 19.024102, 'Szent Gellért-templom', 'F02039', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3439, 57, 47.470851, 19.029195, 'Csóka utca', 'F02040', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3440, 57, 47.472838, 19.033676, 'Karolina út', 'F02046', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3441, 57, 47.520917, 19.16701, 'József utca', 'F03255', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3442, 57, 47.506473, 19.204436, 'Diósy Lajos utca', 'F03315', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3443, 57, 47.508696, 19.209381, 'Bökényföldi út', 'F03318', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3444, 57, 47.525109, 19.17607, 'Diófa utca', 'F03358', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3445, 57, 47.491088, 19.281822, 'Rákoscsaba vasútállomás', 'F03441', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3446, 57, 47.422179, 19.122499, 'Pesterzsébet Pacsirtatelep', 'F04081', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3447, 57, 47.414225, 19.038478, 'Vágóhíd utca', 'F04435', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3448, 57, 47.396882, 18.997974, 'Tenkes utca', 'F04510', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3449, 57, 47.445118, 18.905213, 'Köztársaság tér', 'F04764', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3450, 58, 47.390622, 18.981, 'Angeli utca / Nagytétényi út', '8170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3451, 58, 47.529297, 19.185461, 'Szilas-patak', '9375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3452, 58, 47.522367, 18.941158, 'Országos Korányi Intézet', 'F00184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3453, 58, 47.496853, 19.070168, 'Blaha Lujza tér M', 'F01165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3454, 58, 47.473623, 19.053178, 'Budafoki út / Szerémi sor', 'F01988', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3455, 58, 47.491425, 19.009959, 'Bürök utca', 'F02288', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3456, 58, 47.519927, 19.090478, 'Mexikói út M', 'F02882', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3457, 58, 47.518939, 19.216914, 'Cinkota H', 'F03380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3458, 58, 47.510328, 19.212917, 'Papír utca', 'F03383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3459, 58, 47.399862, 19.004979, 'Dózsa György út', 'F04466', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3460, 59, 47.49154, 19.051335, 'Március 15. tér', '9537', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3461, 59, 47.499032, 19.05403, 'Deák Ferenc tér M', '38717', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3462, 59, 47.469358, 19.025906, 'Szent Gellért-templom', '44178', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3463, 59, 47.561033, 19.102961, 'Árpád Kórház', 'F00886', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3464, 59, 47.518834, 19.073283, 'Vágány utca / Dózsa György út', 'F01058', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3465, 59, 47.478554, 19.088429, 'Nagyvárad tér M', 'F01249', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3466, 59, 47.435415, 19.036207, 'Budafoki elágazás', 'F01903', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3467, 59, 47.510377, 19.084497, 'Dvořák sétány', 'F02708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3468, 59, 47.506906, 19.105547, 'Egressy út / Hungária körút', 'F02741', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3469, 59, 47.492805, 19.268439, 'Bártfai utca', 'F03462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3470, 59, 47.436657, 19.112338, 'Szent Imre herceg utca', 'F04108', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3471, 60, 47.498185, 19.237392, 'Cinkotai autóbuszgarázs', '8535', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3472, 60, 47.592824, 19.107979, 'Íves út', '8841', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3473, 60, 47.515079, 19.185207, 'Margit utca', '9241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3474, 60, 47.489542, 19.035491, 'Sánc utca', 'F00007', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3475, 60, 47.488791, 19.085371, 'Kálvária tér', 'F01224', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3476, 60, 47.482418, 19.064775, 'Lónyay utca', 'F01345', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3477, 60, 47.490373, 18.961127, 'Magas út', 'F02272', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3478, 60, 47.521404, 19.181829, 'Olga utca', 'F03234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3479, 60, 47.491534, 19.265209, 'Ároktő út', 'F03465', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3480, 60, 47.497256, 19.264093, 'Bártfai utca', 'F03576', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3481, 60, 47.435507, 19.1095, 'Török Flóris utca', 'F04113', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3482, 60, 47.459486, 18.921113, 'Csiki csárda', 'F04802', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3483, 61, 47.496754, 19.069849, 'Blaha Lujza tér M', 'F01164', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3484, 61, 47.484428, 19.009288, 'Hegytető utca', 'F02104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3485, 61, 47.519747, 19.090943, 'Mexikói út M', 'F02885', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3486, 61, 47.495937, 19.231557, 'Injekcióüzem', 'F03290', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3487, 61, 47.531616, 19.200938, 'Menyhért utca', 'F03298', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3488, 62, 47.473277, 18.99278, 'Gazdagréti tér', '8567', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3489, 62, 47.541348, 19.15194, 'Szentmihályi út', '8903', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3490, 62, 47.489425, 19.035836, 'Sánc utca', 'F00009', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3491, 62, 47.47517, 19.040178, 'Kosztolányi Dezső tér', 'F02184', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3492, 62, 47.477779, 19.047938, 'Móricz Zsigmond körtér M', 'F02192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3493, 62, 47.529447, 19.19493, 'Állás utca', 'F03297', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3494, 62, 47.532061, 19.204538, 'Muzsika utca', 'F03376', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3495, 62, 47.489182, 19.277089, 'Tura utca', 'F03448', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3496, 62, 47.480552, 19.264476, 'Szárny utca', 'F03475', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3497, 62, 47.387833, 19.02663, 'Hárosi Csárda', 'F04266', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3498, 62, 47.401021, 19.140102, 'Telefondűlő út', 'F04558', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3499, 63, 47.492925, 19.055343, 'Ferenciek tere M', 'F00951', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3500, 63, 47.444364, 19.097233, 'Gubacsi út / Határ út', 'F01434', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3501, 63, 47.485925, 19.131093, 'Szent László tér', 'F01653', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3502, 63, 47.549288, 19.07371, 'Gyöngyösi utca M', 'F02535', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3503, 63, 47.522052, 19.081995, 'Vágány utca / Róbert Károly körút', 'F02628', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3504, 63, 47.540155, 19.148538, 'Erdőkerülő utca 27.', 'F03202', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3505, 63, 47.484249, 19.27857, 'Lemberg utca', 'F03480', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3506, 63, 47.39254, 18.984733, 'Szabadság utca', 'F04497', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3507, 63, 47.400947, 19.121294, 'Soroksár vasútállomás', 'F04544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3508, 64, 47.386519, 19.023121, 'Áruházi bekötőút', '8647', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3509, 64, 47.487985, 19.294719, 'Jókai Mór Általános Iskola', '66681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3510, 64, 47.510939, 19.032114, 'Mechwart liget', 'F00199', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3511, 64, 47.510516, 19.031225, 'Mechwart liget', 'F00200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3512, 64, 47.477491, 19.046864, 'Móricz Zsigmond körtér M', 'F02203', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3513, 64, 47.487243, 18.989793, 'Mindszenty József bíboros tér', 'F02274', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3514, 64, 47.413724, 19.010718, 'Aszály utca', 'F04446', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3515, 64, 47.393237, 18.989336, 'Petőfi Sándor utca (Kastélymúzeum)', 'F04494', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3516, 65, 47.567607, 18.936286, 'Budaliget Géza fejedelem útja', '44375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3517, 65, 47.487056, 19.291849, 'Pallós utca', '66679', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3518, 65, 47.559495, 19.081099, 'Újpest-városkapu', 'F00897', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3519, 65, 47.49701, 19.070279, 'Blaha Lujza tér', 'F01292', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3520, 65, 47.473371, 19.035389, 'Karolina út', 'F02048', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3521, 65, 47.564939, 19.128917, 'Juhos utca', 'F03037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3522, 65, 47.511514, 19.193831, 'Mátyásföld repülőtér H', 'F03309', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3523, 65, 47.485286, 19.281929, 'Óvónő utca', 'F03491', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3524, 65, 47.455692, 18.940895, 'Budaörsi lakótelep', 'F04835', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3525, 66, 47.498562, 19.237779, 'Cinkotai autóbuszgarázs', '8181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3526, 66, 47.474869, 19.283024, 'Derkovits tér', '66720', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3527, 66, 47.496278, 19.065642, 'Nagy Diófa utca', 'F01110', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3528, 66, 47.514192, 19.202521, 'Bökényföldi út', 'F03324', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3529, 66, 47.515347, 19.206268, 'Csinszka utca', 'F03395', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3530, 66, 47.513449, 19.200089, 'Mátyásföld Imre utca H', 'F03396', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3531, 66, 47.389559, 18.977889, 'Akó utca', 'F04501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3532, 66, 47.405129, 19.022303, 'DEXTER', 'F04517', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3533, 67, 47.594948, 18.94093, 'Solymár temető', '44602', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3534, 67, 47.546566, 18.952831, 'Nagyrét utca', 'F00355', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3535, 67, 47.455418, 19.114695, 'Nagykőrösi út / Határ út', 'F01451', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3536, 67, 47.478133, 18.99113, 'Oltvány köz', 'F02077', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3537, 67, 47.498418, 19.237513, 'Cinkotai autóbuszgarázs', 'F03289', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3538, 68, 47.50704, 18.922366, 'Dózsa György tér', '44381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3539, 68, 47.559859, 18.94191, 'Kerekhegyi utca', 'F00378', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3540, 68, 47.560378, 19.099824, 'Árpád üzletház', 'F00836', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3541, 68, 47.499136, 19.078384, 'Huszár utca', 'F01162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3542, 68, 47.546734, 19.142492, 'Újpalota Nyírpalota út', 'F03164', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3543, 68, 47.390378, 18.963761, 'Bányalég utca', 'F04506', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3544, 68, 47.491464, 19.315636, 'Pécel Pesti út 110.', 'F04579', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3545, 69, 47.469723, 19.084482, 'Mester utca / Könyves Kálmán körút', '8153', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3546, 69, 47.497728, 19.054142, 'Deák Ferenc tér', 'F00963', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3547, 69, 47.494408, 19.060983, 'Astoria M', 'F01016', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3548, 69, 47.494825, 19.062457, 'Astoria', 'F01019', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3549, 69, 47.495586, 19.065257, 'Uránia', 'F01170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3550, 69, 47.511005, 18.972058, 'Fácános tér', 'F02380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3551, 69, 47.481789, 19.28522, 'Óvónő utca', 'F03567', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3552, 70, 47.528368, 18.996467, 'Nagybányai út', '8261', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3553, 70, 47.465022, 19.033386, 'Bikás park', '56218', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3554, 70, 47.562093, 19.106869, 'Víztorony', 'F00838', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3555, 70, 47.516523, 18.976598, 'Zugliget Libegő', 'F02381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3556, 70, 47.488118, 19.273595, 'Rákoscsaba-Újtelep vasútállomás', 'F03461', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3557, 70, 47.482578, 19.273226, 'Szabadság sugárút', 'F03477', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3558, 70, 47.388224, 18.973056, 'Nagytétény Erdélyi utca', 'F04503', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3559, 71, 47.457296, 19.295147, 'Rákoskert vasútállomás', '19313', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3560, 71, 47.603028, 19.066315, 'Békásmegyer Újmegyeri tér', 'F00641', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3561, 71, 47.470305, 19.089313, 'Ferencváros vá. - Málenkij Robot Eh.', 'F01502', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3562, 71, 47.47434, 19.025042, 'Ajnácskő utca', 'F02051', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3563, 71, 47.56243, 19.114696, 'Hubay Jenő tér', 'F03054', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3564, 71, 47.541483, 19.151396, 'Újpalota Szentmihályi út', 'F03147', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3565, 72, 47.462694, 19.149021, 'Kőbánya-Kispest M', '38673', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3566, 72, 47.51442, 19.038831, 'Margit híd budai hídfő H', 'F00190', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3567, 72, 47.547895, 19.029645, 'Bécsi út / Vörösvári út', 'F00567', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3568, 72, 47.462111, 19.004888, 'Gazdagréti út', 'F01930', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3569, 72, 47.47534, 19.02983, 'Hollókő utca', 'F02261', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3570, 72, 47.455638, 18.94063, 'Budaörsi lakótelep', 'F04836', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3571, 73, 47.579237, 19.034843, 'Mészkő utca', '9563', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3572, 73, 47.449633, 18.969846, 'Kinizsi utca', 'F04732', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3573, 74, 47.425183, 19.157506, 'Nagykőrösi út', '8096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3574, 74, 47.46973, 19.085041, 'Mester utca / Könyves Kálmán körút', 'F01509', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3575, 74, 47.491948, 19.1402, 'Élessarok', 'F01628', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3576, 74, 47.503486, 19.183071, 'Galgahévíz utca', 'F03239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3577, 74, 47.412479, 19.033735, 'Háros vasútállomás', 'F04434', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3578, 74, 47.400228, 18.999441, 'Mátra utca', 'F04521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3579, 75, 47.447788, 19.000374, 'Tippan utca', '9354', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3580, 75, 47.464014, 19.318341, 'Zsigmond utca', '9728', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3581, 75, 47.43954, 19.164256, 'Vörösmarty Mihály utca', '86874', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3582, 75, 47.488273, 19.031033, 'Mészáros utca', 'F00004', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3583, 75, 47.496899, 19.033406, 'Krisztina tér', 'F00083', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3584, 75, 47.523456, 19.020611, 'Vend utca', 'F00218', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3585, 75, 47.489482, 19.125008, 'Őrház', 'F01562', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3586, 75, 47.447127, 18.985794, 'Budaörsi repülőtér', 'F01913', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3587, 75, 47.497994, 19.020837, 'Kék Golyó utca', 'F02411', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3588, 75, 47.511282, 18.981504, 'Hunyad köz', 'F02485', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3589, 75, 47.508708, 19.178465, 'Sashalom H', 'F03241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3590, 75, 47.461579, 18.968721, 'Aradi utca', 'F04736', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3591, 76, 47.475285, 19.071658, 'Haller utca / Soroksári út', 'F01388', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3592, 76, 47.454338, 19.097441, 'Külső Mester utca', 'F01426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3593, 76, 47.491801, 19.138666, 'Élessarok', 'F01626', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3594, 76, 47.490825, 19.132932, 'Kőbánya felső vasútállomás', 'F01728', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3595, 76, 47.490046, 19.128353, 'Halom utca', 'F01730', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3596, 76, 47.485203, 19.015961, 'Sion lépcső', 'F02106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3597, 76, 47.510849, 18.979088, 'Cinege út', 'F02487', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3598, 76, 47.509879, 19.185065, 'Fuvallat utca', 'F03235', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3599, 76, 47.507394, 19.171042, 'Lándzsa utca', 'F03274', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3600, 76, 47.510906, 19.191015, 'Jókai Mór utca (Rendőrség)', 'F03393', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3601, 76, 47.399802, 19.133979, 'Szamaránszki dűlő', 'F04556', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3602, 77, 47.456293, 19.110359, 'Gyáli út', '8588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3603, 77, 47.426313, 19.04004, 'Városház tér', '81950', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3604, 77, 47.506613, 19.038872, 'Batthyány tér M+H', 'F00066', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3605, 77, 47.500498, 19.1128, 'Őrnagy utca', 'F01736', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3606, 77, 47.504017, 19.136271, 'Örs vezér tere M+H', 'F01755', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3607, 77, 47.506581, 19.166593, 'Nagyicce H', 'F01777', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3608, 77, 47.517201, 19.235933, 'Simongát utca', 'F03335', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3609, 77, 47.432101, 19.185643, 'Gilice tér', 'F03638', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3610, 77, 47.411327, 19.026236, 'Háros utca', 'F04431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3611, 77, 47.417989, 19.254454, 'Vecsés-nyugat', 'F04596', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3612, 78, 47.49276, 19.072489, 'Rákóczi tér', '56230', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3613, 78, 47.35023, 19.057828, 'Föveny utca', '101180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3614, 78, 47.554999, 19.100842, 'Újpesti Erőmű', 'F00829', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3615, 78, 47.476057, 19.076819, 'Haller utca / Mester utca', 'F01391', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3616, 78, 47.476219, 19.078544, 'Haller utca / Mester utca', 'F01395', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3617, 78, 47.455477, 19.104974, 'Táblás utca', 'F01475', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3618, 78, 47.476775, 19.081953, 'Balázs Béla utca', 'F01512', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3619, 78, 47.476668, 19.081661, 'Balázs Béla utca', 'F01513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3620, 78, 47.511607, 18.983946, 'Zalai út', 'F02377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3621, 78, 47.502917, 19.002186, 'Nógrádi utca', 'F02394', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3622, 78, 47.516625, 19.092825, 'Erzsébet királyné útja aluljáró', 'F02920', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3623, 78, 47.562905, 19.117925, 'Hubay Jenő tér', 'F03048', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3624, 78, 47.492628, 19.251838, 'Tarack utca', 'F03472', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3625, 78, 47.428903, 19.037229, 'Savoyai Jenő tér', 'F04400', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3626, 78, 47.456696, 18.947179, 'Ifjúság utca', 'F04725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3627, 79, 47.436351, 19.093381, 'Csepeli átjáró', '9719', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3628, 79, 47.528502, 19.021605, 'Zöldmáli lejtő', 'F00258', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3629, 79, 47.596787, 19.06259, 'Hatvany Lajos utca / Juhász Gyula utca', 'F00650', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3630, 79, 47.473306, 19.030718, 'Kelenföldi autóbuszgarázs', 'F02221', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3631, 79, 47.496244, 18.984223, 'Művész út', 'F02315', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3632, 79, 47.507232, 19.025186, 'Széll Kálmán tér M', 'F02477', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3633, 79, 47.519237, 19.082445, 'Széchenyi fürdő M', 'F02694', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3634, 79, 47.504902, 19.194795, 'Jókai Mór utca (Újszász utca)', 'F03388', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3635, 79, 47.433381, 19.194267, 'Dalmady Győző utca', 'F03640', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3636, 79, 47.432712, 19.189811, 'Sas utca', 'F03857', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3637, 80, 47.523087, 19.01773, 'Pusztaszeri körönd', 'F00214', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3638, 80, 47.502261, 19.051986, 'Hercegprímás utca', 'F01021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3639, 80, 47.500113, 19.109508, 'Puskás Ferenc Stadion M', 'F01321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3640, 80, 47.500174, 19.106381, 'Puskás Ferenc Stadion', 'F01325', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3641, 80, 47.444525, 19.097498, 'Gubacsi út / Határ út', 'F01435', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3642, 80, 47.470062, 19.088676, 'Ferencváros vá. - Málenkij Robot Eh.', 'F01505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3643, 80, 47.501204, 19.120417, 'Pillangó utca', 'F01743', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3644, 80, 47.503832, 19.143306, 'Gyakorló köz', 'F01771', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3645, 80, 47.492193, 19.019367, 'Nagy Jenő utca', 'F02309', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3646, 80, 47.5094, 18.997096, 'Tarpatak utca', 'F02428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3647, 80, 47.50636, 19.142155, 'Füredi utca / Ond vezér útja', 'F02768', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3648, 80, 47.504084, 19.187732, 'Ballada utca', 'F03237', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3649, 80, 47.505294, 19.197889, 'Pilóta utca', 'F03311', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3650, 80, 47.436646, 19.09769, 'Pesterzsébet Baross utca', 'F04145', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3651, 80, 47.462194, 18.973442, 'Tulipán utca', 'F04792', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3652, 81, 47.490255, 19.110147, 'Hidegkuti Nándor Stadion', '8912', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3653, 81, 47.50326, 19.137956, 'Örs vezér tere M+H (Kerepesi út)', '9322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3654, 81, 47.471473, 19.063671, 'Magyar tudósok körútja', '62608', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3655, 81, 47.495198, 19.025855, 'Győri út', 'F00096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3656, 81, 47.489388, 19.071638, 'Harminckettesek tere', 'F01205', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3657, 81, 47.501223, 19.11953, 'Pillangó utca M', 'F01741', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3658, 81, 47.501683, 19.142684, 'Gyakorló köz', 'F01770', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3659, 81, 47.502919, 19.167734, 'Dömsödi utca', 'F01788', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3660, 81, 47.490586, 19.113995, 'Kőbányai garázs', 'F01799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3661, 81, 47.502183, 19.147304, 'Gépmadár utca', 'F01851', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3662, 81, 47.502251, 19.161773, 'Pilisi utca', 'F01853', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3663, 81, 47.460131, 19.306526, 'Erzsébet körút', 'F03571', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3664, 82, 47.490684, 19.113853, 'Kőbányai garázs', '8917', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3665, 82, 47.506596, 19.050619, 'Szemere utca', '99344', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3666, 82, 47.505679, 19.047288, 'Kossuth Lajos tér M', 'F00972', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3667, 82, 47.498726, 19.050168, 'József nádor tér', 'F00979', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3668, 82, 47.504662, 19.150966, 'Rákosfalva H', 'F01791', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3669, 82, 47.504233, 19.146983, 'Gépmadár park', 'F01855', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3670, 82, 47.506222, 19.146164, 'Ond vezér park', 'F02770', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3671, 82, 47.413035, 19.20179, 'Alacskai úti lakótelep', 'F03862', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3672, 82, 47.460255, 18.95432, 'Kisfaludy utca', 'F04720', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3673, 82, 47.463062, 18.981053, 'Felsőhatár utca', 'F04796', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3674, 83, 47.484789, 19.012843, 'Korompai utca', '8560', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3675, 83, 47.399283, 19.116413, 'Soroksár Hősök tere H', '8961', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3676, 83, 47.490074, 19.044767, 'Döbrentei tér', 'F00110', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3677, 83, 47.52845, 18.9998, 'Vöröstorony utca', 'F00424', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3678, 83, 47.461924, 19.015866, 'Kérő utca', 'F01922', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3679, 83, 47.459614, 19.300555, 'Schell Gyuláné tér', 'F03507', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3680, 84, 47.494901, 19.023055, 'Királyhágó utca', 'F00013', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3681, 84, 47.505876, 19.038978, 'Batthyány tér M+H', 'F00067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3682, 84, 47.523199, 19.002421, 'Fenyves utca', 'F00291', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3683, 84, 47.509818, 19.0098, 'Szent János Kórház', 'F00410', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3684, 84, 47.495648, 19.072742, 'Blaha Lujza tér M (Népszínház utca)', 'F01298', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3685, 84, 47.475716, 19.099888, 'Népliget M', 'F01530', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3686, 84, 47.436262, 19.091008, 'Sósfürdő', 'F04149', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3687, 84, 47.398693, 19.125195, 'Vecsés út sorompó', 'F04554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3688, 84, 47.460881, 18.959796, 'Károly király utca', 'F04722', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3689, 85, 47.400904, 19.003494, 'I. utca', '8169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3690, 85, 47.433409, 19.096269, 'Pesterzsébet vasútállomás', '8896', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3691, 85, 47.503216, 19.137571, 'Örs vezér tere M+H (Kerepesi út)', '8942', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3692, 85, 47.345002, 19.028765, 'Tamási Áron utca', '64182', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3693, 85, 47.505849, 19.03854, 'Batthyány tér M+H', '67592', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3694, 85, 47.485161, 19.223005, 'Akadémiaújtelep vasútállomás', '116271', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3695, 85, 47.559437, 19.006721, 'Erdőalja út', 'F00546', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3696, 85, 47.505572, 19.158799, 'Egyenes utcai lakótelep', 'F01776', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3697, 85, 47.493266, 19.336825, 'Pécel vasútállomás', 'F04646', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3698, 85, 47.451423, 18.958479, 'Budaörs benzinkút', 'F04737', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3699, 86, 47.59869, 19.121323, 'Káposztásmegyer Mogyoródi-patak', '9536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3700, 86, 47.498512, 19.048019, 'Eötvös tér', '112711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3701, 86, 47.56486, 19.0926, 'Kiss Ernő utca', 'F00787', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3702, 86, 47.488403, 19.08825, 'Csobánc utca', 'F01225', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3703, 86, 47.481241, 19.045152, 'Kelenhegyi lépcső', 'F02134', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3704, 86, 47.517955, 18.983692, 'Szarvas Gábor út', 'F02386', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3705, 86, 47.49065, 19.306051, 'Színes utca', 'F03444', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3706, 86, 47.456032, 19.137253, 'Ady Endre út (Rendőrség)', 'F03959', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3707, 86, 47.459416, 18.931577, 'Lejtő utca', 'F04838', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3708, 87, 47.338022, 19.039575, 'József Attila utca', '44033', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3709, 87, 47.559705, 19.095306, 'Erzsébet utca', 'F00854', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3710, 87, 47.49004, 19.108249, 'Hidegkuti Nándor Stadion', 'F01271', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3711, 87, 47.486197, 19.199613, 'Legényrózsa utca', 'F01716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3712, 87, 47.488875, 19.220507, 'Határmalom utca', 'F01718', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3713, 87, 47.486075, 19.203793, 'Rézvirág utca', 'F01847', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3714, 87, 47.491221, 19.120286, 'Pongrácz úti lakótelep', 'F01856', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3715, 87, 47.455776, 19.131219, 'Gutenberg körút', 'F03964', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3716, 87, 47.405149, 18.983101, 'Szilvafa utca', 'F04488', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3717, 87, 47.460274, 18.926549, 'Ibolya utca', 'F04711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3718, 87, 47.575818, 18.886794, 'Diófa utca (Posta)', 'F04826', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3719, 88, 47.445218, 19.000036, 'Kőérberek', '8789', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3720, 88, 47.499431, 19.084012, 'Keleti pályaudvar M', '9161', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3721, 88, 47.484291, 19.230112, '513. utca', '9543', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3722, 88, 47.517494, 18.992955, 'Szépilona', 'F00169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3723, 88, 47.514546, 19.038897, 'Margit híd budai hídfő H', 'F00189', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3724, 88, 47.52871, 19.024168, 'Szemlő-hegyi-barlang', 'F00256', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3725, 88, 47.565064, 19.099842, 'Újpesti rendelőintézet', 'F00792', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3726, 88, 47.565232, 19.106008, 'Szülőotthon', 'F00880', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3727, 88, 47.506605, 19.003165, 'Orgonás', 'F02327', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3728, 88, 47.499547, 18.973389, 'Fülemile út', 'F02448', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3729, 88, 47.480709, 19.21983, 'Akadémiaújtelep 525. tér', 'F03552', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3730, 89, 47.468737, 19.073486, 'Közvágóhíd H', '8265', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3731, 89, 47.509728, 19.009813, 'Szent János Kórház', 'F00409', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3732, 89, 47.56494, 19.09462, 'Kiss Ernő utca', 'F00788', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3733, 89, 47.549342, 19.074188, 'Gyöngyösi utca M', 'F02534', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3734, 89, 47.541932, 19.066613, 'Cserhalom utca', 'F02566', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3735, 89, 47.517407, 19.081481, 'Széchenyi fürdő', 'F02697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3736, 89, 47.441726, 19.077795, 'Szállító utca', 'F04302', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3737, 89, 47.399523, 18.990858, 'Barosstelep vasútállomás', 'F04478', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3738, 89, 47.490054, 19.333429, 'Orvosi rendelő', 'F04581', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3739, 90, 47.436482, 19.019517, 'Lomnici utca', '50279', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3740, 90, 47.602194, 19.052845, 'Doboz utca', '109849', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3741, 90, 47.498195, 19.040028, 'Clark Ádám tér', 'F00048', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3742, 90, 47.529338, 19.017116, 'Csalit utca', 'F00125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3743, 90, 47.593989, 19.04912, 'Nád utca', 'F00456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3744, 90, 47.603937, 19.062951, 'Hadrianus utca', 'F00639', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3745, 90, 47.501758, 19.090822, 'Verseny utca', 'F01150', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3746, 90, 47.499447, 19.091311, 'Arena Mall bevásárlóközpont', 'F01251', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3747, 90, 47.490015, 19.105515, 'Asztalos Sándor út', 'F01279', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3748, 90, 47.487152, 19.089616, 'Kőris utca', 'F01333', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3749, 90, 47.444067, 19.096928, 'Gubacsi út / Határ út', 'F01436', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3750, 90, 47.483662, 19.005852, 'Farkasréti temető', 'F02103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3751, 90, 47.405135, 19.012791, 'Budatétény vasútállomás (Növény utca)', 'F04458', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3752, 90, 47.461576, 18.96453, 'Templom tér', 'F04810', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3753, 91, 47.578602, 19.050423, 'Czetz János köz', 'F00490', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3754, 91, 47.499481, 19.096089, 'Gumigyár', 'F01183', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3755, 91, 47.491866, 19.088027, 'Magdolna utca', 'F01214', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3756, 91, 47.499683, 19.082881, 'Keleti pályaudvar', 'F01336', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3757, 91, 47.44534, 18.991471, 'Fülőke utca', 'F01891', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3758, 91, 47.507403, 19.024974, 'Széll Kálmán tér M', 'F02464', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3759, 91, 47.51824, 19.227321, 'Georgina utca', 'F03328', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3760, 91, 47.454728, 19.125568, 'Pannónia út', 'F03921', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3761, 91, 47.430605, 19.005537, 'Jégmadár utca', 'F04395', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3762, 92, 47.471324, 19.283632, 'Kisvárda utca', '43193', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3763, 92, 47.376961, 19.032246, 'Leshegy utca', '64239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3764, 92, 47.520579, 19.024769, 'Vérhalom tér', 'F00222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3765, 92, 47.488975, 19.065852, 'Szentkirályi utca', 'F01174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3766, 92, 47.48913, 19.092218, 'Orczy tér', 'F01218', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3767, 92, 47.594977, 18.935763, 'Pilisvörösvári utca', 'F04799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3768, 92, 47.575922, 18.884401, 'Nagykovácsi községháza', 'F04828', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3769, 93, 47.596178, 18.974455, 'Solymár Szarvas', '9066', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3770, 93, 47.560047, 19.033414, 'Kunigunda útja', 'F00515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3771, 93, 47.485534, 19.217391, '501. utca', 'F01722', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3772, 93, 47.486625, 19.202759, 'Aluljáró (Rézvirág utca)', 'F01865', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3773, 93, 47.463064, 18.98429, 'Rupphegyi út', 'F02218', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3774, 93, 47.511318, 19.000847, 'Kútvölgyi lejtő Traumatológia', 'F02358', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3775, 93, 47.454718, 19.126562, 'Kós Károly tér', 'F03922', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3776, 93, 47.46418, 19.126961, 'Határ út M', 'F04018', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3777, 93, 47.426218, 19.083088, 'Komáromi utca', 'F04189', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3778, 94, 47.446231, 18.998784, 'Kőérberek', '9352', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3779, 94, 47.462912, 19.149201, 'Kőbánya-Kispest M', '38682', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3780, 94, 47.430535, 19.0646, 'Színesfém utca', '41094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3781, 94, 47.578593, 19.112616, 'Hajló utca', 'F00740', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3782, 94, 47.486916, 19.193246, 'Túzok utca', 'F01610', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3783, 94, 47.490344, 19.149684, 'Maglódi út', 'F01616', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3784, 94, 47.485832, 19.209909, 'Dombhát utca', 'F01849', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3785, 94, 47.447338, 18.978064, 'Repülőgépes Szolgálat', 'F01908', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3786, 94, 47.426381, 19.078621, 'Katona József utca', 'F04187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3787, 94, 47.42278, 19.074684, 'Bajcsy-Zsilinszky út', 'F04196', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3788, 94, 47.419678, 19.086344, 'Kikötő utca', 'F04215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3789, 94, 47.434882, 19.076704, 'Védgát utca', 'F04317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3790, 94, 47.431861, 19.071467, 'Kiss János altábornagy utca', 'F04321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3791, 94, 47.461238, 18.946931, 'Budaörs városháza', 'F04716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3792, 95, 47.432046, 19.067318, 'Szent Imre tér H', '41095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3793, 95, 47.489477, 19.090708, 'Orczy tér (Baross kocsiszín)', '44301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3794, 95, 47.450921, 19.03688, 'Nyéki Imre Uszoda', '61238', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3795, 95, 47.59703, 19.059532, 'Hímző utca', 'F00465', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3796, 95, 47.560492, 19.083468, 'Újpest-városkapu M', 'F00809', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3797, 95, 47.55519, 19.095169, 'Nap utca', 'F00879', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3798, 95, 47.461686, 19.115565, 'Lobogó utca', 'F01471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3799, 95, 47.482355, 19.129429, 'Kőbánya alsó vasútállomás', 'F01646', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3800, 95, 47.489596, 19.160418, 'Téglavető utca', 'F01822', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3801, 95, 47.483971, 19.233839, 'Borsó utca', 'F03547', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3802, 95, 47.422286, 19.088108, 'Kikötő utca', 'F04199', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3803, 95, 47.434682, 19.081542, 'Papírgyár', 'F04306', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3804, 95, 47.422589, 19.080105, 'Rákóczi tér', 'F04338', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3805, 95, 47.422858, 19.029716, 'Víg utca (Sporttelep)', 'F04414', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3806, 96, 47.527543, 19.036916, 'Kolosy tér', '8605', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3807, 96, 47.446278, 19.004075, 'Régi vám (Kánai út)', '9356', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3808, 96, 47.594701, 19.060994, 'Boglár utca', '9539', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3809, 96, 47.490376, 19.019617, 'Sirály utca', '9743', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3810, 96, 47.469072, 19.0605, 'Infopark', '66452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3811, 96, 47.488054, 19.244774, 'Liget sor', '103897', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3812, 96, 47.514028, 18.922403, 'Budakeszi temető', '107799', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3813, 96, 47.59489, 19.058162, 'Medgyessy Ferenc utca', 'F00466', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3814, 96, 47.575598, 19.05756, 'Dósa utca', 'F00501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3815, 96, 47.594322, 19.066352, 'Pünkösdfürdő', 'F00655', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3816, 96, 47.560184, 19.090683, 'Újpest-központ M', 'F00801', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3817, 96, 47.489377, 19.163789, 'Tárna utca', 'F01818', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3818, 96, 47.460557, 18.939758, 'Gimnázium', 'F04715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3819, 97, 47.490044, 19.023665, 'Csörsz utca', '9521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3820, 97, 47.529117, 19.003624, 'Verecke lépcső', 'F00265', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3821, 97, 47.573089, 19.050582, 'Monostori út', 'F00486', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3822, 97, 47.585384, 19.051925, 'Bercsényi utca', 'F00683', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3823, 97, 47.483538, 19.196298, 'Csillagvirág utca', 'F01554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3824, 97, 47.48703, 19.180522, 'Athenaeum Nyomda', 'F01604', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3825, 97, 47.489843, 19.156703, 'Orion', 'F01824', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3826, 97, 47.508098, 19.00412, 'Rőzse köz', 'F02345', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3827, 97, 47.519513, 19.091037, 'Mexikói út', 'F02888', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3828, 97, 47.487308, 19.249429, 'Liszt Ferenc utca', 'F03456', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3829, 97, 47.483226, 19.238334, 'Kis utca', 'F03545', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3830, 98, 47.464572, 19.022964, 'Kelenföld vasútállomás M', '7897', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3831, 98, 47.54983, 19.041189, 'Óbuda Bogdáni út', '11322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3832, 98, 47.464743, 19.023003, 'Kelenföld vasútállomás M', '93021', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3833, 98, 47.500578, 18.967972, 'Normafa látogatóközpont', '110322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3834, 98, 47.529381, 19.011074, 'Zöldkert út', 'F00260', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3835, 98, 47.508275, 19.023766, 'Fény utcai piac', 'F00305', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3836, 98, 47.520269, 18.988841, 'Budenz út', 'F00320', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3837, 98, 47.553226, 19.017288, 'Körtvélyes utca', 'F00557', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3838, 98, 47.489377, 19.077397, 'Horváth Mihály tér', 'F01208', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3839, 98, 47.490864, 19.097037, 'Salgótarjáni utca temető', 'F01281', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3840, 98, 47.486537, 19.186651, 'Kossuth Nyomda', 'F01606', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3841, 98, 47.485909, 19.191966, 'Tarkarét utca', 'F01608', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3842, 98, 47.483849, 19.19277, 'Kollégium', 'F01873', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3843, 98, 47.443505, 19.012062, 'Kelenvölgy-Péterhegy', 'F01895', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3844, 98, 47.490402, 19.015268, 'Apor Vilmos tér', 'F02301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3845, 98, 47.507376, 19.025598, 'Széll Kálmán tér M', 'F02466', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3846, 98, 47.491343, 19.297362, 'Pöröly utca', 'F03436', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3847, 98, 47.486313, 19.258103, 'Rákosliget vasútállomás', 'F03604', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3848, 99, 47.430177, 19.016896, 'Szebeni utca', '8116', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3849, 99, 47.520036, 18.97336, 'Vízművek', 'F00174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3850, 99, 47.598635, 19.121695, 'Káposztásmegyer Mogyoródi-patak', 'F00899', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3851, 99, 47.483106, 19.123287, 'Mázsa utca', 'F01568', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3852, 99, 47.473003, 19.177104, 'Új köztemető', 'F01597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3853, 99, 47.473701, 19.171773, 'Sírkert út', 'F01712', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3854, 99, 47.478982, 19.200279, 'Tűzoltóság', 'F01868', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3855, 99, 47.477815, 19.046253, 'Móricz Zsigmond körtér M', 'F02200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3856, 99, 47.490257, 19.012216, 'Zólyomi lépcső', 'F02299', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3857, 99, 47.49203, 19.016833, 'Kiss János altábornagy utca', 'F02305', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3858, 100, 47.464653, 19.023017, 'Kelenföld vasútállomás M', '7898', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3859, 100, 47.462254, 19.043363, 'Etele út / Fehérvári út', '66460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3860, 100, 47.517368, 19.020907, 'Tapolcsányi utca', 'F00208', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3861, 100, 47.559517, 19.038171, 'Bojtár utca 49.', 'F00444', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3862, 100, 47.585779, 19.047126, 'Csillaghegy H', 'F00478', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3863, 100, 47.554539, 19.104243, 'Istvántelki út', 'F00831', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3864, 100, 47.505511, 19.045769, 'Kossuth Lajos tér', 'F00959', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3865, 100, 47.488963, 19.081125, 'Muzsikus cigányok parkja', 'F01177', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3866, 100, 47.488949, 19.095496, 'Kőbányai út 21.', 'F01181', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3867, 100, 47.482564, 19.126059, 'Kőbánya alsó vasútállomás (Mázsa tér)', 'F01572', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3868, 100, 47.473785, 19.047395, 'Újbuda-központ M', 'F01995', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3869, 100, 47.462218, 19.04274, 'Etele út / Fehérvári út', 'F02007', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3870, 100, 47.495255, 19.004316, 'Szendrő utca', 'F02433', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3871, 100, 47.49071, 19.303317, 'Ebergény utca', 'F03434', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3872, 100, 47.491956, 19.260752, 'XVIII. utca', 'F03468', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3873, 100, 47.42381, 19.024388, 'Lőcsei utca', 'F04417', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3874, 100, 47.491475, 19.322435, 'Határ utca', 'F04586', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3875, 100, 47.597218, 18.927753, 'Solymár PEMÜ', 'F04703', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3876, 100, 47.592708, 18.932577, 'Dózsa György utca', 'F04705', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3877, 100, 47.458598, 18.915227, 'Csiki tanya', 'F04804', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3878, 101, 47.598321, 19.121429, 'Káposztásmegyer Mogyoródi-patak', '8910', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3879, 101, 47.463603, 19.033309, 'Bikás park M', '99630', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3880, 101, 47.464088, 19.029635, 'Bártfai utca', '99632', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3881, 101, 47.508669, 19.017872, 'Nyúl utca', 'F00426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3882, 101, 47.595124, 19.054572, 'Madzsar József utca / Pünkösdfürdő utca', 'F00474', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3883, 101, 47.571236, 19.054117, 'Kadosa utca', 'F00502', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3884, 101, 47.53919, 19.04568, 'Szentlélek tér H', 'F00628', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3885, 101, 47.539377, 19.045937, 'Szentlélek tér H', 'F00629', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3886, 101, 47.563198, 19.086725, 'Templom utca', 'F00794', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3887, 101, 47.56106, 19.079522, 'Árpád út', 'F00808', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3888, 101, 47.592854, 19.118576, 'Sárpatak utca', 'F00909', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3889, 101, 47.463558, 19.032845, 'Bikás park M', 'F01955', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3890, 101, 47.464034, 19.029304, 'Bártfai utca', 'F02215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3891, 101, 47.461751, 19.04644, 'Hengermalom út / Szerémi út', 'F02260', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3892, 101, 47.461891, 18.993986, 'Madárhegy', 'F02267', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3893, 101, 47.499423, 18.977225, 'Őzike köz', 'F02446', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3894, 101, 47.467379, 19.248464, 'Ferihegyi út', 'F03518', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3895, 101, 47.425032, 19.016131, 'Kazinczy utca', 'F04425', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3896, 102, 47.519418, 18.978021, 'Bíróság', '7878', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3897, 102, 47.456381, 19.114232, 'Nagykőrösi út / Határ út', '9632', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3898, 102, 47.456866, 19.030526, 'Pajkos utca', '61231', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3899, 102, 47.506703, 19.034797, 'Mária tér', 'F00068', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3900, 102, 47.51897, 18.980982, 'Labanc út', 'F00148', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3901, 102, 47.51752, 18.99527, 'Szépilona', 'F00170', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3902, 102, 47.524468, 18.958734, 'Irén utca', 'F00178', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3903, 102, 47.508625, 19.018867, 'Nyúl utca', 'F00428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3904, 102, 47.495524, 19.060134, 'Károly körút (Astoria M)', 'F01108', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3905, 102, 47.461183, 18.999464, 'Poprádi út', 'F01933', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3906, 102, 47.497291, 19.01327, 'Galántai utca', 'F02424', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3907, 102, 47.468302, 19.245138, 'Rákoshegy vasútállomás', 'F03528', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3908, 102, 47.48481, 19.2258, '509. utca', 'F03549', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3909, 103, 47.512028, 18.927518, 'Széchenyi utca (gimnázium)', '9443', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3910, 103, 47.468442, 19.057157, 'BudaPart', '49597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3911, 103, 47.518432, 18.984581, 'Kuruclesi út', 'F00172', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3912, 103, 47.515041, 19.038937, 'Margit híd budai hídfő H', 'F00236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3913, 103, 47.604207, 19.043933, 'Donát utca', 'F00442', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3914, 103, 47.546552, 19.017185, 'Máramaros út', 'F00563', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3915, 103, 47.593847, 19.113671, 'Megyeri út', 'F00721', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3916, 103, 47.564924, 19.090607, 'Csokonai utca', 'F00785', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3917, 103, 47.481127, 19.136631, 'Kápolna tér', 'F01662', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3918, 103, 47.546842, 19.142293, 'Újpalota Nyírpalota út', 'F03163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3919, 103, 47.468017, 19.243665, 'Rákoshegy vasútállomás', 'F03526', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3920, 104, 47.570831, 19.058436, 'Római úti lakótelep Varsa utca', '7879', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3921, 104, 47.498245, 19.015329, 'Galántai utca', '9699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3922, 104, 47.479536, 19.255542, 'Rákoskeresztúr városközpont', '11723', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3923, 104, 47.469646, 19.054384, 'Budafoki út / Dombóvári út', '66454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3924, 104, 47.50955, 19.013012, 'Városmajor', 'F00157', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3925, 104, 47.454603, 19.087947, 'Kén utca H (Illatos út)', 'F01420', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3926, 104, 47.490712, 19.169225, 'Keresztúri út 102.', 'F01612', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3927, 104, 47.488234, 19.173558, 'Rákos vasútállomás', 'F01614', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3928, 104, 47.488214, 19.183709, 'Díszítő üzem', 'F01817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3929, 105, 47.43827, 19.029987, 'Rózsavölgy alsó', '8104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3930, 105, 47.478776, 19.237124, 'Füstifecske utca', '9492', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3931, 105, 47.482004, 19.130543, 'Kápolna utca', 'F01644', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3932, 105, 47.489382, 19.176958, 'Fővárosi Kertészet', 'F01815', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3933, 105, 47.512623, 19.001085, 'Virányos út', 'F02351', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3934, 105, 47.516475, 18.990199, 'Moholy-Nagy Művészeti Egyetem', 'F02353', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3935, 105, 47.548695, 19.073483, 'Gyöngyösi utca M', 'F02537', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3936, 105, 47.547406, 19.083751, 'József Attila tér', 'F02559', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3937, 105, 47.537767, 19.070291, 'Forgách utca M', 'F02677', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3938, 105, 47.482201, 19.2432, 'Bakancsos utca', 'F03542', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3939, 105, 47.441585, 19.18607, 'Pestszentlőrinc Szarvas csárda tér', 'F03711', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3940, 105, 47.442283, 19.018507, 'Pék utca', 'F04375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3941, 105, 47.424412, 19.020014, 'Arató utca', 'F04423', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3942, 105, 47.425651, 19.012247, 'Karácsony utca', 'F04428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3943, 106, 47.473505, 19.034394, 'Karolina út', '9412', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3944, 106, 47.478014, 19.241141, 'Madárdomb köz', '9550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3945, 106, 47.509505, 19.01369, 'Városmajor', 'F00158', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3946, 106, 47.453864, 19.091924, 'Gubacsi út', 'F01423', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3947, 106, 47.451643, 19.017994, 'Alabástrom utca', 'F01915', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3948, 106, 47.505485, 18.992534, 'Istenhegyi lejtő', 'F02390', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3949, 106, 47.533737, 19.075415, 'Pap Károly utca', 'F02576', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3950, 106, 47.460639, 18.950195, 'Kötő utca', 'F04808', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3951, 107, 47.435991, 19.04151, 'Savoya Park', '8864', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3952, 107, 47.539095, 19.045373, 'Serfőző utca', '9295', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3953, 107, 47.415917, 19.026059, 'Wesselényi utca', '9454', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3954, 107, 47.537697, 19.062787, 'Dagály fürdő', '62756', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3955, 107, 47.513942, 19.02675, 'Füge utca', 'F00202', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3956, 107, 47.555408, 19.090254, 'Tél utca / Pozsonyi utca', 'F00817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3957, 107, 47.532665, 19.0807, 'Béke utca', 'F02584', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3958, 107, 47.519601, 19.060817, 'Lehel tér M (Gogol utca)', 'F02612', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3959, 107, 47.517676, 19.060152, 'Lehel tér M', 'F02616', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3960, 108, 47.477256, 19.048786, 'Móricz Zsigmond körtér M', '8090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3961, 108, 47.442037, 19.018742, 'Pék utca', '8099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3962, 108, 47.536815, 19.067116, 'Karikás Frigyes utca', '62757', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3963, 108, 47.541088, 19.001608, 'Szépvölgyi köz', '64209', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3964, 108, 47.495101, 19.00283, 'Pagony utca', '66856', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3965, 108, 47.462797, 19.148349, 'Kőbánya-Kispest M', '88969', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3966, 108, 47.507782, 19.028184, 'Széna tér', 'F00033', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3967, 108, 47.529803, 19.009042, 'Pitypang utca', 'F00262', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3968, 108, 47.572001, 19.10574, 'Zombori utca', 'F00873', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3969, 108, 47.571451, 19.108238, 'Pajtás utca', 'F00875', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3970, 108, 47.494819, 19.07655, 'II. János Pál pápa tér M', 'F01300', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3971, 108, 47.493604, 19.081884, 'Teleki László tér', 'F01330', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3972, 108, 47.490905, 19.014007, 'Apor Vilmos tér', 'F02294', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3973, 108, 47.49943, 19.010206, 'Szent Orbán tér', 'F02398', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3974, 108, 47.535889, 19.059638, 'Népfürdő utca / Árpád híd', 'F02526', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3975, 108, 47.536087, 19.059532, 'Népfürdő utca / Árpád híd', 'F02528', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3976, 108, 47.548324, 19.079288, 'Cziffra György park', 'F02541', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3977, 108, 47.550551, 19.110376, 'Dugonics utca', 'F03077', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3978, 108, 47.554902, 19.115457, 'Beller Imre utca', 'F03183', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3979, 108, 47.479302, 19.233982, 'Uszoda', 'F03554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3980, 108, 47.480668, 19.250836, 'Szent kereszt tér', 'F03593', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3981, 108, 47.394398, 19.101691, 'Sáska utca', 'F04264', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3982, 109, 47.478606, 19.040827, 'Tas vezér utca', '8040', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3983, 109, 47.520447, 19.05698, 'Gogol utca', '9587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3984, 109, 47.534409, 19.062404, 'Párkány utca', '109728', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3985, 109, 47.539263, 18.970742, 'Heinrich István utca', 'F00346', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3986, 109, 47.546478, 18.960012, 'Bükkfa utca', 'F00357', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3987, 109, 47.535342, 19.027243, 'Remetehegyi út', 'F00608', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3988, 109, 47.484704, 19.114015, 'Eiffel Műhelyház', 'F01734', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3989, 109, 47.474864, 19.042062, 'Kosztolányi Dezső tér', 'F02180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3990, 109, 47.490833, 19.014339, 'Apor Vilmos tér', 'F02295', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3991, 109, 47.436814, 19.014122, 'Szabina út', 'F04373', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3992, 109, 47.458332, 18.905576, 'Gyár utca', 'F04740', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3993, 109, 47.575887, 18.89114, 'Teleki-Tisza-kastély', 'F04785', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3994, 110, 47.608963, 19.117347, 'Dunakeszi Auchan áruház', '8400', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3995, 110, 47.462328, 19.148576, 'Kőbánya-Kispest M', '38670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3996, 110, 47.462974, 19.147562, 'Kőbánya-Kispest M', '38674', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3997, 110, 47.464255, 19.020587, 'Kelenföld vasútállomás', '56216', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3998, 110, 47.571859, 19.022431, 'Aranyvölgy utca 14.', '76398', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (3999, 110, 47.486155, 19.108284, 'Kőbányai út / Könyves Kálmán körút', 'F01270', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4000, 110, 47.457379, 19.177716, 'Felsőcsatári út', 'F01545', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4001, 110, 47.476285, 19.035932, 'Vincellér utca', 'F02124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4002, 110, 47.51174, 18.997209, 'Fészek utca', 'F02356', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4003, 110, 47.49823, 18.981367, 'Ordas út', 'F02444', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4004, 110, 47.537185, 19.054392, 'Margitsziget / Árpád híd', 'F02502', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4005, 110, 47.525726, 19.057844, 'Dráva utca', 'F02510', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4006, 110, 47.540111, 19.084889, 'Fiastyúk utca', 'F02552', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4007, 110, 47.536399, 19.107149, 'Csáktornya park', 'F02908', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4008, 110, 47.447215, 19.190999, 'Lőrinci temető', 'F03701', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4009, 110, 47.448561, 19.185592, 'Thököly út', 'F03828', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4010, 110, 47.456087, 19.183004, 'Pestszentlőrinc vasútállomás (átjáró)', 'F03880', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4011, 110, 47.455328, 19.186105, 'Vajk utca', 'F03881', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4012, 110, 47.454379, 19.189948, 'Csaba utca', 'F03882', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4013, 110, 47.490782, 19.326307, 'Faiskola utcai átjáró', 'F04588', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4014, 111, 47.499449, 19.083401, 'Keleti pályaudvar M', '9160', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4015, 111, 47.343852, 19.034217, 'Akácfa körút', '64183', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4016, 111, 47.521624, 18.96794, 'Dénes utca', 'F00150', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4017, 111, 47.487614, 19.103854, 'Kőbányai út 31.', 'F01263', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4018, 111, 47.507421, 19.024722, 'Széll Kálmán tér M', 'F02465', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4019, 111, 47.503138, 19.103897, 'Szobránc köz', 'F02738', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4020, 111, 47.473502, 19.273745, 'Sági utca', 'F03438', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4021, 111, 47.471981, 19.279098, 'Tápióbicske utca', 'F03511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4022, 111, 47.438193, 19.030705, 'Rózsavölgy alsó', 'F04380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4023, 112, 47.487452, 19.103721, 'Kőbányai út 31.', '8037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4024, 112, 47.523609, 18.994082, 'Pasaréti tér', '48471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4025, 112, 47.464337, 19.018507, 'Kelenföld vasútállomás M', '61275', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4026, 112, 47.570439, 19.028424, 'Óbuda vasútállomás (Aranyhegyi út)', '76396', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4027, 112, 47.389118, 19.100179, 'Vihorlát út', '109519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4028, 112, 47.52801, 19.033357, 'Ürömi utca', 'F00131', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4029, 112, 47.531371, 19.020382, 'Szélvész utca', 'F00635', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4030, 112, 47.570427, 19.112659, 'Szilágyi utca', 'F00764', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4031, 112, 47.512847, 19.048841, 'Jászai Mari tér', 'F00926', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4032, 112, 47.486343, 19.108577, 'Kőbányai út / Könyves Kálmán körút', 'F01266', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4033, 112, 47.465749, 19.126228, 'Határ út', 'F01542', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4034, 112, 47.483676, 19.117463, 'Egészségház', 'F01564', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4035, 112, 47.485136, 19.1131, 'Eiffel Műhelyház', 'F01732', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4036, 112, 47.444402, 19.005671, 'Balatoni út', 'F01893', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4037, 112, 47.461031, 19.051798, 'Hengermalom út', 'F01983', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4038, 112, 47.473665, 19.025132, 'Ajnácskő utca', 'F02050', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4039, 112, 47.478854, 19.040156, 'Tas vezér utca', 'F02146', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4040, 112, 47.51389, 19.049372, 'Jászai Mari tér', 'F02511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4041, 112, 47.522125, 19.102589, 'Nagy Lajos király útja / Czobor utca', 'F02867', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4042, 112, 47.521296, 19.105828, 'Kerékgyártó utca', 'F02966', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4043, 112, 47.523187, 19.209688, 'Ostoros út 40.', 'F03301', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4044, 112, 47.469527, 19.238405, 'Lőrinci út (Szabadság utca)', 'F03530', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4045, 112, 47.449718, 19.181219, 'Csörötnek utca', 'F03831', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4046, 113, 47.465069, 19.128275, 'Határ út M', '40763', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4047, 113, 47.547535, 18.95463, 'Széchenyi utca', 'F00369', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4048, 113, 47.564591, 18.959649, 'Templom utca (Kultúrkúria)', 'F00401', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4049, 113, 47.597804, 19.044679, 'Hősök tere', 'F00448', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4050, 113, 47.509929, 18.99399, 'Hadik András utca', 'F02367', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4051, 114, 47.52217, 19.102815, 'Nagy Lajos király útja / Czobor utca', '8209', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4052, 114, 47.568485, 19.125534, 'Rákospalota Kossuth utca', '48519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4053, 114, 47.418379, 19.249419, 'Vecsés Fő út', '50187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4054, 114, 47.53077, 19.026424, 'Szépvölgyi út', 'F00127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4055, 114, 47.516748, 19.02793, 'Rómer Flóris utca', 'F00229', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4056, 114, 47.482389, 19.102495, 'Vajda Péter utca (Villám utca)', 'F01260', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4057, 114, 47.484018, 19.117198, 'Egészségház', 'F01566', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4058, 114, 47.4913, 19.14601, 'Sörgyár', 'F01621', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4059, 114, 47.449183, 19.02073, 'Kecskeméti József utca', 'F01940', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4060, 114, 47.475827, 19.054107, 'Budafoki út / Karinthy Frigyes út', 'F02153', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4061, 114, 47.476169, 19.052582, 'Budafoki út / Karinthy Frigyes út', 'F02156', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4062, 114, 47.534039, 19.124368, 'Pestújhelyi tér', 'F03094', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4063, 114, 47.469017, 19.294153, 'Kucorgó tér', 'F03498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4064, 114, 47.474508, 19.270141, 'Oroszvár utca', 'F03513', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4065, 114, 47.452779, 19.19623, 'Csévéző utca', 'F03696', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4066, 114, 47.424487, 19.230848, 'Kupeczky János utca', 'F03797', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4067, 115, 47.52099, 19.106438, 'Kerékgyártó utca', '40789', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4068, 115, 47.560144, 19.091319, 'Újpest-központ', 'F00912', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4069, 115, 47.515987, 19.058625, 'Csanády utca (Lehel tér M)', 'F01067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4070, 115, 47.474913, 19.100672, 'Népliget', 'F01283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4071, 115, 47.483838, 19.027065, 'Budaörsi út / Villányi út', 'F02173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4072, 115, 47.539949, 19.12834, 'Sztárai Mihály tér', 'F03098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4073, 115, 47.535346, 19.12028, 'Ady Endre utca', 'F03187', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4074, 115, 47.470502, 19.289425, 'Vecsey Ferenc utca', 'F03509', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4075, 115, 47.456211, 19.176745, 'Felsőcsatári köz', 'F03747', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4076, 115, 47.437646, 19.193085, 'Madarász utca', 'F03823', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4077, 116, 47.48446, 19.096407, 'Delej utca', '8270', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4078, 116, 47.435045, 18.906261, 'Ady Endre utca', '8326', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4079, 116, 47.51585, 19.058891, 'Csanády utca (Lehel tér M)', '9039', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4080, 116, 47.524237, 19.012936, 'Baba utca', 'F00152', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4081, 116, 47.564339, 19.110725, 'Rákospalota-Újpest vasútállomás', 'F00843', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4082, 116, 47.496945, 19.055715, 'Deák Ferenc tér M', 'F00950', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4083, 116, 47.491733, 19.14477, 'Sörgyár', 'F01619', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4084, 116, 47.504525, 18.998004, 'Óra út', 'F02392', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4085, 116, 47.517164, 19.054815, 'Hegedűs Gyula utca', 'F02515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4086, 116, 47.519458, 19.111761, 'Bosnyák tér', 'F02831', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4087, 116, 47.567612, 19.127194, 'Rákospalota Kossuth utca', 'F03033', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4088, 116, 47.538152, 19.133897, 'Thököly út', 'F03135', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4089, 116, 47.521966, 19.214996, 'Csókakő utca', 'F03302', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4090, 116, 47.467749, 19.302358, 'Olcsva utca', 'F03575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4091, 116, 47.436522, 19.196233, 'Iparvasút', 'F03691', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4092, 116, 47.422225, 18.97595, 'Dózsa György út / Kamaraerdei út', 'F04391', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4093, 116, 47.426648, 19.022807, 'Árpád utca', 'F04419', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4094, 117, 47.421906, 19.238604, 'Pestszentlőrinc sorompó', '50175', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4095, 117, 47.506826, 19.038103, 'Batthyány tér', 'F00063', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4096, 117, 47.523661, 18.961524, 'Bölcsőde', 'F00176', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4097, 117, 47.577279, 19.114435, 'Káposztásmegyer Szilas-patak', 'F00746', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4098, 117, 47.570038, 19.099462, 'Leiningen Károly utca', 'F00774', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4099, 117, 47.563901, 19.082354, 'Attila utca', 'F00852', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4100, 117, 47.512478, 19.049558, 'Jászai Mari tér', 'F00930', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4101, 117, 47.485368, 19.070946, 'Corvin-negyed M', 'F01192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4102, 117, 47.482262, 19.079741, 'Semmelweis Klinikák M', 'F01235', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4103, 117, 47.483605, 19.098914, 'Bláthy Ottó utca', 'F01241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4104, 117, 47.47089, 19.111079, 'Ecseri út M', 'F01497', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4105, 117, 47.463443, 19.148764, 'Kőbánya-Kispest', 'F01544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4106, 117, 47.473663, 19.135133, 'Kőér utca', 'F01578', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4107, 117, 47.469084, 19.147382, 'Vasgyár utca', 'F01805', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4108, 117, 47.475252, 19.004373, 'Nagyszalonta köz', 'F02090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4109, 117, 47.519476, 19.111336, 'Bosnyák tér', 'F02830', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4110, 117, 47.541258, 19.151741, 'Újpalota Szentmihályi út', 'F03145', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4111, 117, 47.436689, 19.195933, 'Iparvasút', 'F03692', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4112, 117, 47.439483, 19.187452, 'Szarvas csárda tér', 'F03714', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4113, 117, 47.428688, 19.219013, 'Pestszentlőrinc Béke tér', 'F03790', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4114, 117, 47.434801, 19.201495, 'Honvéd utca', 'F03803', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4115, 117, 47.434346, 19.202617, 'Honvéd utca', 'F03804', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4116, 117, 47.437813, 19.192353, 'Madarász utca', 'F03825', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4117, 117, 47.431216, 19.212049, 'Ungvár utca', 'F03853', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4118, 117, 47.431031, 19.212417, 'Ungvár utca', 'F03855', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4119, 117, 47.43295, 19.206964, 'Bajcsy-Zsilinszky út', 'F03885', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4120, 117, 47.595658, 18.932397, 'Váci Mihály utca', 'F04831', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4121, 118, 47.479281, 19.211332, '526. sor', '7913', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4122, 118, 47.516074, 18.998922, 'Budagyöngye', '8731', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4123, 118, 47.46642, 19.123095, 'Száva kocsiszín', '44127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4124, 118, 47.433749, 18.925165, 'Diósdi út', '48302', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4125, 118, 47.514439, 19.044327, 'Margitsziget / Margit híd', 'F00142', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4126, 118, 47.599351, 19.041488, 'Tamás utca', 'F00450', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4127, 118, 47.468629, 19.117178, 'Pöttyös utca M', 'F01466', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4128, 118, 47.470529, 19.111646, 'Ecseri út', 'F01494', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4129, 118, 47.463566, 19.162107, 'Gyömrői út 132.', 'F01548', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4130, 118, 47.481836, 19.20452, 'Hungaropharma', 'F01858', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4131, 118, 47.467939, 19.118838, 'Pöttyös utca', 'F01879', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4132, 118, 47.491444, 19.011724, 'Szendi árok', 'F02289', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4133, 118, 47.510395, 18.975073, 'Csillagvölgyi út', 'F02379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4134, 118, 47.537214, 19.136858, 'Késmárk utca', 'F03141', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4135, 118, 47.439975, 19.186158, 'Szarvas csárda tér', 'F03719', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4136, 118, 47.430909, 19.196271, 'Gyékény tér', 'F03816', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4137, 118, 47.432928, 19.206815, 'Bajcsy-Zsilinszky út', 'F03887', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4138, 118, 47.464728, 19.127638, 'Határ út M', 'F03928', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4139, 118, 47.571669, 18.898343, 'Sebestyéndomb', 'F04783', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4140, 119, 47.592534, 19.285513, 'EGIS-telep (Szentjakab H)', '9015', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4141, 119, 47.47999, 19.095703, 'Szenes Iván tér', '22006', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4142, 119, 47.520006, 19.220341, 'Cinkota H', '50003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4143, 119, 47.423195, 19.23478, 'Ganztelep Mednyánszky utca', '50177', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4144, 119, 47.529215, 19.030475, 'Folyondár utca (Szépvölgyi út)', 'F00129', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4145, 119, 47.515715, 19.03117, 'Zivatar utca', 'F00231', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4146, 119, 47.546963, 18.93873, 'Adyliget', 'F00352', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4147, 119, 47.478921, 19.112867, 'Sporttelep', 'F01534', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4148, 119, 47.4704, 19.143856, 'Diósgyőri utca', 'F01581', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4149, 119, 47.4672, 19.152406, 'Sibrik Miklós út', 'F01807', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4150, 119, 47.458266, 19.01936, 'Zelk Zoltán út / Neszmélyi út', 'F01919', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4151, 119, 47.477192, 19.263453, 'Mezőtárkány utca', 'F03515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4152, 119, 47.427794, 19.221502, 'Rába utca', 'F03867', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4153, 119, 47.438872, 19.071616, 'Corvin-csomópont', 'F04311', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4154, 119, 47.421195, 19.040849, 'Tóth József utca', 'F04409', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4155, 119, 47.587257, 18.993932, 'Solymár téglagyári bekötőút', 'F04818', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4156, 120, 47.598734, 19.355678, 'Gödöllő Palotakert H', '9019', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4157, 120, 47.496881, 19.032117, 'Krisztina tér', 'F00085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4158, 120, 47.53607, 18.970574, 'Csibor utca', 'F00332', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4159, 120, 47.569241, 18.948558, 'Szarvashegy utca', 'F00397', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4160, 120, 47.556453, 19.084395, 'Aradi utca', 'F00813', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4161, 120, 47.461699, 19.167024, 'Hangár utca', 'F01546', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4162, 120, 47.464675, 19.159085, 'Újhegyi út', 'F01586', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4163, 120, 47.472428, 19.138434, 'Gyógyszergyár', 'F01796', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4164, 120, 47.572273, 19.124436, 'Közvágóhíd tér', 'F03015', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4165, 120, 47.465099, 19.309083, 'Nyomdász utca', 'F03501', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4166, 120, 47.445377, 19.173006, 'Kemény Zsigmond utca', 'F03835', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4167, 120, 47.460558, 19.154375, 'Wesselényi utca', 'F03944', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4168, 120, 47.458139, 19.143163, 'Kispest Kossuth tér', 'F03948', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4169, 121, 47.441102, 19.022349, 'Rózsavölgy felső', '8101', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4170, 121, 47.547013, 19.142015, 'Újpalota Nyírpalota út', '9376', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4171, 121, 47.433385, 19.025919, 'Alkotmány utca', '9470', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4172, 121, 47.441995, 19.18098, 'Thököly út', '9569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4173, 121, 47.483103, 19.128249, 'Kőbánya alsó vasútállomás', '38791', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4174, 121, 47.496405, 19.057068, 'Deák Ferenc tér M', '44554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4175, 121, 47.582485, 19.025937, 'Cserszömörce utca [4]', '56169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4176, 121, 47.573789, 19.01766, 'Aranyvölgy vasútállomás', '76395', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4177, 121, 47.522682, 19.017478, 'Pusztaszeri körönd', 'F00216', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4178, 121, 47.5668, 18.954915, 'Solymári elágazás', 'F00395', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4179, 121, 47.584234, 19.090436, 'Gárdonyi Géza utca', 'F00708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4180, 121, 47.470404, 19.111026, 'Ecseri út M', 'F01489', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4181, 121, 47.470494, 19.110787, 'Ecseri út M', 'F01490', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4182, 121, 47.483526, 19.039739, 'Iglói utca', 'F02130', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4183, 121, 47.506663, 19.015138, 'Temes utca', 'F02340', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4184, 121, 47.523512, 19.097863, 'Kassai tér', 'F02902', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4185, 121, 47.466573, 19.305562, 'Nyomdok utca', 'F03569', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4186, 121, 47.42624, 19.225566, 'Gyergyó utca', 'F03794', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4187, 121, 47.443151, 19.212205, 'Szemeretelep vasútállomás', 'F03808', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4188, 121, 47.454248, 19.152221, 'Árpád utca', 'F03905', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4189, 121, 47.456105, 19.147875, 'Fő utca', 'F03951', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4190, 121, 47.452454, 19.156673, 'Villanytelep', 'F04030', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4191, 122, 47.486933, 19.067178, 'Köztelek utca', '8694', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4192, 122, 47.454622, 19.181767, 'Pestszentlőrinc vasútállomás', '8772', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4193, 122, 47.539901, 19.042054, 'Flórián tér', '9691', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4194, 122, 47.438745, 19.167224, 'Kondor Béla sétány', '86875', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4195, 122, 47.447904, 19.159393, 'Kispest vasútállomás', '88741', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4196, 122, 47.530905, 18.998032, 'Vöröstorony lépcső', 'F00272', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4197, 122, 47.521805, 18.984949, 'Széher út 16.', 'F00416', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4198, 122, 47.575003, 19.098084, 'Nádor utca', 'F00758', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4199, 122, 47.509914, 19.056354, 'Nyugati pályaudvar M', 'F00934', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4200, 122, 47.460265, 19.170482, 'Gyömrői út 156.', 'F01723', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4201, 122, 47.482702, 19.030463, 'Alsóhegy utca', 'F02116', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4202, 122, 47.482679, 19.030252, 'Alsóhegy utca', 'F02118', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4203, 122, 47.480341, 19.035772, 'Szüret utca', 'F02125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4204, 122, 47.480283, 19.036129, 'Szüret utca', 'F02126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4205, 122, 47.51152, 18.989403, 'György Aladár utca', 'F02361', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4206, 122, 47.568337, 19.118105, 'Géza fejedelem tér', 'F03019', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4207, 122, 47.534589, 19.182048, 'Csömöri út (Szlovák út)', 'F03213', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4208, 122, 47.524579, 19.205111, 'Alsómalom utca', 'F03391', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4209, 122, 47.442726, 19.17951, 'Thököly út', 'F03708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4210, 122, 47.451499, 19.158673, 'Lajosmizsei sorompó', 'F03735', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4211, 122, 47.450994, 19.160104, 'Lajosmizsei sorompó', 'F03737', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4212, 122, 47.449287, 19.163953, 'Tinódi utca', 'F03738', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4213, 122, 47.449542, 19.163509, 'Tinódi utca', 'F03739', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4214, 122, 47.457252, 19.1622, 'Csillag utca', 'F03744', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4215, 122, 47.452082, 19.174343, 'Lakatos úti lakótelep', 'F03748', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4216, 122, 47.447267, 19.168683, 'Margó Tivadar utca', 'F03754', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4217, 122, 47.444511, 19.175285, 'Baross utca', 'F03768', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4218, 122, 47.444075, 19.176167, 'Baross utca', 'F03770', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4219, 122, 47.429257, 19.217504, 'Béke tér', 'F03792', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4220, 122, 47.445783, 19.172318, 'Kemény Zsigmond utca', 'F03833', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4221, 122, 47.434327, 19.160918, 'Karton utca', 'F03891', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4222, 122, 47.462079, 19.13412, 'Corvin körút', 'F03899', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4223, 122, 47.461665, 19.134835, 'Nyáry Pál utca', 'F03901', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4224, 122, 47.454554, 19.151731, 'Árpád utca', 'F03903', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4225, 122, 47.459475, 19.140256, 'Lehel utca', 'F03931', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4226, 122, 47.459499, 19.140009, 'Lehel utca', 'F03933', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4227, 122, 47.456321, 19.14761, 'Fő utca', 'F03953', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4228, 122, 47.567101, 18.909322, '13-as kilométerkő', 'F04781', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4229, 123, 47.481671, 19.068782, 'Mester utca / Ferenc körút', '7890', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4230, 123, 47.437131, 19.171052, 'Fiatalság utca', '86876', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4231, 123, 47.478347, 19.090724, 'Nagyvárad tér M', '86885', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4232, 123, 47.525522, 19.00931, 'Móricz Zsigmond Gimnázium', 'F00270', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4233, 123, 47.557682, 18.940706, 'Szirom utca', 'F00377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4234, 123, 47.57295, 19.102375, 'Leiningen Károly utca', 'F00762', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4235, 123, 47.460127, 19.015088, 'Neszmélyi út', 'F01924', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4236, 123, 47.512084, 19.003502, 'Pető Intézet', 'F02349', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4237, 123, 47.530129, 19.148166, 'Késmárk utca 11.', 'F03143', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4238, 123, 47.53632, 19.17836, 'Baross utca', 'F03215', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4239, 123, 47.529388, 19.194076, 'Állás utca', 'F03295', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4240, 123, 47.525968, 19.201941, 'Felsőmalom utca', 'F03378', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4241, 123, 47.496377, 19.225878, 'EGIS Gyógyszergyár', 'F03404', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4242, 123, 47.462996, 19.239562, 'Baross utca', 'F03522', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4243, 123, 47.442776, 19.179174, 'Thököly út', 'F03709', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4244, 123, 47.455951, 19.165135, 'Hőerőmű', 'F03743', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4245, 123, 47.44791, 19.167324, 'Margó Tivadar utca', 'F03755', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4246, 123, 47.450802, 19.177337, 'Mikszáth Kálmán utca', 'F03774', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4247, 123, 47.453832, 19.170156, 'Tinódi utca', 'F03842', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4248, 123, 47.457501, 19.144841, 'Kossuth tér', 'F03947', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4249, 123, 47.452517, 19.156289, 'Villanytelep', 'F04028', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4250, 123, 47.404272, 18.99068, 'Mátyás király utca', 'F04489', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4251, 124, 47.469097, 19.226094, 'Dedics Ferenc utca', '9139', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4252, 124, 47.479729, 19.066486, 'Boráros tér H', '9359', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4253, 124, 47.543064, 18.996307, 'Selyemakác utca', '64214', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4254, 124, 47.506092, 19.028928, 'Mátray utca', '69428', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4255, 124, 47.516649, 18.997979, 'Budagyöngye', 'F00166', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4256, 124, 47.511464, 19.008896, 'Vasas sportpálya', 'F00289', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4257, 124, 47.521779, 19.005569, 'Bimbó köz', 'F00293', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4258, 124, 47.577738, 19.010013, 'Ürömhegyi út', 'F00531', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4259, 124, 47.5488, 19.015098, 'Királylaki út', 'F00562', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4260, 124, 47.579639, 19.109041, 'Művelődési Központ', 'F00733', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4261, 124, 47.445931, 18.976362, 'Vasút utca', 'F01907', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4262, 124, 47.481659, 19.032915, 'Pető Intézet (Villányi út)', 'F02253', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4263, 124, 47.531487, 19.189132, 'Timur utca', 'F03218', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4264, 124, 47.469467, 19.237012, 'Lőrinci út', 'F03531', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4265, 124, 47.437654, 19.140655, 'Alvinc utca', 'F03909', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4266, 124, 47.458764, 19.158709, 'Bocskai utca', 'F04006', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4267, 124, 47.419207, 18.983495, 'Balatoni út / Dózsa György út', 'F04389', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4268, 125, 47.512834, 18.994743, 'Dániel út', '8737', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4269, 125, 47.426582, 19.161021, 'Dél-pesti autóbuszgarázs', '8921', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4270, 125, 47.578226, 19.261133, 'Szilasliget Kemping', '9013', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4271, 125, 47.511236, 19.032074, 'Mechwart liget', 'F00196', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4272, 125, 47.524096, 18.980577, 'Bölöni György utca / Széher út', 'F00417', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4273, 125, 47.550195, 19.016267, 'Iskola', 'F00560', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4274, 125, 47.577577, 19.09281, 'Baross utca / Fóti út', 'F00754', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4275, 125, 47.497089, 19.05541, 'Deák Ferenc tér M', 'F00946', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4276, 125, 47.489074, 19.062694, 'Kálvin tér M', 'F01007', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4277, 125, 47.475218, 19.157091, 'Lavotta utca', 'F01701', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4278, 125, 47.486986, 19.023046, 'BAH-csomópont', 'F02163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4279, 125, 47.510969, 19.005852, 'Rendelőintézet', 'F02483', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4280, 125, 47.534217, 19.113239, 'Öv utca', 'F02859', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4281, 125, 47.536523, 19.106801, 'Csáktornya park', 'F02907', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4282, 125, 47.562074, 19.123199, 'Régi Fóti út', 'F03040', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4283, 125, 47.541712, 19.151097, 'Újpalota Szentmihályi út', 'F03146', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4284, 125, 47.533227, 19.185219, 'Péterke utca', 'F03348', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4285, 125, 47.432007, 19.149792, 'Autópiac', 'F03889', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4286, 125, 47.439015, 19.13802, 'Kéreg utca', 'F03911', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4287, 125, 47.441025, 19.022882, 'Rózsavölgy felső', 'F04378', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4288, 125, 47.401715, 18.971964, 'Barackos út / Angeli utca', 'F04485', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4289, 125, 47.558915, 18.920635, 'Mészégető', 'F04824', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4290, 126, 47.464634, 19.018494, 'Kelenföld vasútállomás M', '61277', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4291, 126, 47.491243, 19.043612, 'Döbrentei tér', 'F00104', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4292, 126, 47.552823, 19.022735, 'Királyhelmec utca', 'F00534', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4293, 126, 47.557633, 19.015638, 'Laborc utca', 'F00540', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4294, 126, 47.579636, 19.088653, 'Megyeri út / Fóti út', 'F00712', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4295, 126, 47.577004, 19.086655, 'Irányi Dániel utca', 'F00768', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4296, 126, 47.52161, 19.122439, 'Komócsy utca', 'F02812', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4297, 126, 47.532533, 19.11677, 'Kerékgyártó utca', 'F02945', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4298, 126, 47.498956, 19.269208, 'Rákoscsaba-Újtelep Naplás út', 'F03578', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4299, 126, 47.432307, 19.16554, 'Csíky utca', 'F03892', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4300, 126, 47.416345, 19.071606, 'Kölcsey utca', 'F04207', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4301, 127, 47.488256, 19.064419, 'Kálvin tér M', '9308', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4302, 127, 47.580892, 19.018215, 'Kőpor köz / Jutas utca [7]', '100838', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4303, 127, 47.498374, 19.028747, 'Mikó utca', 'F00090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4304, 127, 47.506506, 19.055716, 'Báthory utca / Bajcsy-Zsilinszky út', 'F00939', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4305, 127, 47.464942, 19.003926, 'Regős utca', 'F02061', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4306, 127, 47.486417, 18.992156, 'Hó utca', 'F02275', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4307, 127, 47.563349, 19.120694, 'Rákospalota Széchenyi tér', 'F03047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4308, 127, 47.545109, 19.112482, 'Széchenyi út', 'F03083', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4309, 127, 47.465476, 19.234145, 'Melczer utca', 'F03561', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4310, 127, 47.418694, 19.066876, 'Völgy utca', 'F04200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4311, 128, 47.642594, 19.031459, 'Pomáz H', '8435', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4312, 128, 47.480157, 19.291817, 'Rés utca', '66697', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4313, 128, 47.567996, 19.027538, 'Óbudai autóbuszgarázs', '75220', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4314, 128, 47.532881, 19.016755, 'Pál-völgyi cseppkőbarlang', 'F00123', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4315, 128, 47.514665, 19.006491, 'Júlia utca', 'F00287', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4316, 128, 47.448254, 18.975278, 'Repülőgépes Szolgálat', 'F01911', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4317, 128, 47.471841, 18.999401, 'Kaptárkő utca', 'F02059', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4318, 128, 47.473611, 18.996136, 'Telekes utca', 'F02071', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4319, 128, 47.48944, 19.018201, 'Sirály utca', 'F02303', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4320, 128, 47.529373, 19.081416, 'Béke tér', 'F02590', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4321, 128, 47.509482, 19.128993, 'Pongrátz Gergely tér', 'F02748', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4322, 128, 47.514317, 19.12061, 'Egressy tér', 'F02794', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4323, 128, 47.585599, 19.124607, 'Kanizsai Dorottya utca', 'F03000', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4324, 128, 47.572775, 19.138485, 'Régi Fóti út', 'F03009', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4325, 128, 47.574693, 19.134966, 'Sportcentrum', 'F03032', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4326, 128, 47.559441, 19.127692, 'Cserba Elemér út', 'F03041', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4327, 128, 47.561556, 19.117498, 'Beller Imre utca', 'F03056', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4328, 128, 47.417407, 19.06946, 'Bánya utca', 'F04206', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4329, 128, 47.408767, 18.948754, 'Diósd városháza', 'F04772', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4330, 129, 47.57759, 19.129699, 'Mélyfúró utca', '9659', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4331, 129, 47.493794, 19.278001, 'Aranykút utca', '9707', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4332, 129, 47.580966, 19.030085, 'Öregbükk utca [3]', '56166', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4333, 129, 47.429484, 19.168656, 'Ipacsfa utca', '56189', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4334, 129, 47.582663, 19.082353, 'Újpest Fóti út', 'F00699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4335, 129, 47.475757, 19.147607, 'Kemence utca', 'F01689', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4336, 129, 47.471781, 19.148448, 'Noszlopy utca', 'F01692', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4337, 129, 47.47356, 19.160391, 'Sibrik Miklós út', 'F01699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4338, 129, 47.466614, 19.000847, 'Törökugrató utca', 'F02063', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4339, 129, 47.468863, 18.996592, 'Szent Angyalok-templom', 'F02065', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4340, 129, 47.504865, 19.01879, 'Barabás Villa', 'F02337', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4341, 129, 47.506611, 19.02439, 'Széll Kálmán tér M', 'F02461', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4342, 129, 47.528167, 19.083593, 'Szegedi út', 'F02646', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4343, 129, 47.504525, 19.099823, 'Egressy út / Stefánia út', 'F02736', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4344, 129, 47.587042, 19.121865, 'Visonta utca', 'F02998', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4345, 129, 47.452889, 19.220815, 'Lőrinci út', 'F03430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4346, 129, 47.455603, 19.215824, 'Homokiszőlők', 'F03601', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4347, 129, 47.429952, 19.1988, 'Olt utca', 'F03877', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4348, 129, 47.413541, 19.0768, 'Festő utca', 'F04238', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4349, 130, 47.495421, 19.018729, 'Királyhágó tér', '8344', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4350, 130, 47.432711, 19.028438, 'Galamb utca', '9468', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4351, 130, 47.479373, 19.256098, 'Rákoskeresztúr városközpont', '11724', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4352, 130, 47.352883, 19.03849, 'Lehel utca', '64087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4353, 130, 47.49377, 19.038224, 'Dózsa György tér', 'F00080', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4354, 130, 47.512555, 19.017444, 'Lorántffy Zsuzsanna út', 'F00303', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4355, 130, 47.545467, 19.033618, 'Óbudai rendelőintézet', 'F00585', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4356, 130, 47.509896, 19.056659, 'Nyugati pályaudvar M', 'F00935', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4357, 130, 47.469565, 19.169158, 'Dolomit utca', 'F01590', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4358, 130, 47.484287, 19.143855, 'Ihász utca', 'F01669', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4359, 130, 47.474966, 18.989051, 'Szent Pio atya tér', 'F02074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4360, 130, 47.467809, 18.998644, 'Torbágy utca', 'F02244', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4361, 130, 47.507098, 18.998267, 'Esze Tamás iskola', 'F02325', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4362, 130, 47.500157, 18.97084, 'Normafa Gyermekvasút', 'F02450', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4363, 130, 47.523999, 19.063752, 'Dózsa György út M', 'F02610', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4364, 130, 47.549997, 19.120937, 'Damjanich János utca', 'F03114', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4365, 130, 47.552082, 19.122428, 'Klapka György utca', 'F03117', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4366, 130, 47.541599, 19.141633, 'Fő tér', 'F03153', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4367, 130, 47.532273, 19.14412, 'Késmárk utca 9.', 'F03198', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4368, 130, 47.533741, 19.141546, 'Fázis utca', 'F03200', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4369, 130, 47.427365, 19.203167, 'Nagyenyed utca', 'F03865', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4370, 130, 47.411376, 19.087954, 'Pinty utca', 'F04252', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4371, 130, 47.403332, 18.968955, 'Diósdi utca', 'F04515', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4372, 130, 47.410627, 18.95731, 'Diósd törökbálinti elágazás', 'F04768', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4373, 130, 47.457451, 18.903852, 'Tetra Pak', 'F04833', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4374, 131, 47.482716, 19.062997, 'Zsil utca', '9365', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4375, 131, 47.579402, 19.034313, 'Mészkő utca [2]', '56163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4376, 131, 47.479495, 19.066751, 'Boráros tér H', '73505', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4377, 131, 47.499404, 18.915098, 'Budakeszi Tesco áruház', '98882', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4378, 131, 47.4426, 19.158102, 'Kispesti temető', '117053', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4379, 131, 47.500801, 19.02499, 'Déli pályaudvar M', 'F00026', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4380, 131, 47.49386, 19.037494, 'Dózsa György tér', 'F00079', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4381, 131, 47.511548, 19.019184, 'Garas utca', 'F00153', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4382, 131, 47.513548, 19.002642, 'Nagyajtai utca', 'F00163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4383, 131, 47.51046, 19.021043, 'Ezredes utca', 'F00304', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4384, 131, 47.581684, 19.001794, 'Üröm vasúti megállóhely', 'F00533', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4385, 131, 47.490237, 19.148629, 'Jászberényi út / Maglódi út', 'F01618', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4386, 131, 47.473933, 19.144399, 'Kada utca', 'F01690', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4387, 131, 47.500114, 18.989766, 'Városkút', 'F02440', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4388, 131, 47.531454, 19.068084, 'Göncz Árpád városközpont M', 'F02599', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4389, 131, 47.522199, 19.066739, 'Kassák Lajos utca', 'F02640', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4390, 131, 47.514875, 19.119827, 'Egressy tér', 'F02796', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4391, 131, 47.517568, 19.115104, 'Szugló utca / Nagy Lajos király útja', 'F02815', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4392, 131, 47.50706, 19.133051, 'Tihamér utca', 'F02968', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4393, 131, 47.511733, 19.125199, 'Jeszenák János utca', 'F02971', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4394, 131, 47.556833, 19.126292, 'Bezerédj Pál utca', 'F03062', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4395, 131, 47.548178, 19.12403, 'Bercsényi Miklós utca', 'F03124', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4396, 131, 47.548595, 19.132453, 'Páskomliget utca', 'F03126', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4397, 131, 47.54418, 19.131091, 'Pestújhelyi út', 'F03131', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4398, 131, 47.539636, 19.14495, 'Erdőkerülő utca', 'F03150', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4399, 131, 47.541941, 19.141235, 'Fő tér', 'F03155', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4400, 131, 47.5452, 19.138212, 'Vásárcsarnok', 'F03160', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4401, 131, 47.544821, 19.139035, 'Vásárcsarnok', 'F03161', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4402, 131, 47.546533, 19.135943, 'Sárfű utca', 'F03194', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4403, 131, 47.441253, 19.160442, 'Csapó utca', 'F03728', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4404, 131, 47.425102, 19.207017, 'Tarkő utca', 'F03863', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4405, 131, 47.443987, 19.213043, 'Szemeretelep vasútállomás', 'F03869', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4406, 131, 47.4364, 19.150946, 'Ajtósi utca', 'F03995', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4407, 131, 47.412023, 19.06804, 'Kölcsey utca', 'F04201', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4408, 131, 47.406651, 19.07722, 'Csille utca', 'F04234', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4409, 131, 47.401345, 19.094014, 'Királyerdei Művelődési Ház', 'F04258', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4410, 131, 47.407749, 19.002468, 'Terv utca', 'F04464', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4411, 131, 47.59193, 18.984473, 'Kövesbérci utca', 'F04820', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4412, 132, 47.43773, 18.899544, 'Károlyi utca', '8323', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4413, 132, 47.440269, 18.895215, 'Törökbálint Sportközpont', '8329', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4414, 132, 47.372917, 19.216769, 'Gyál Bem József utca', '8548', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4415, 132, 47.491486, 19.043705, 'Döbrentei tér', 'F00103', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4416, 132, 47.51664, 19.024186, 'Ady Endre utca', 'F00206', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4417, 132, 47.516869, 19.008918, 'Hankóczy Jenő utca', 'F00297', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4418, 132, 47.488384, 19.137877, 'Harmat utca', 'F01631', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4419, 132, 47.477608, 19.138064, 'Kőér utca', 'F01665', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4420, 132, 47.475743, 19.141284, 'Szlávy utca', 'F01839', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4421, 132, 47.484134, 19.026586, 'Budaörsi út / Villányi út', 'F02172', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4422, 132, 47.523869, 19.080244, 'Vágány utca / Róbert Károly körút', 'F02633', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4423, 132, 47.530044, 19.070328, 'Honvédkórház', 'F02644', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4424, 132, 47.509761, 19.128316, 'Pongrátz Gergely tér', 'F02749', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4425, 132, 47.566437, 19.122501, 'Fő út', 'F03024', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4426, 132, 47.55917, 19.122185, 'Szentmihályi út', 'F03060', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4427, 132, 47.539245, 19.111731, 'Perczel Mór utca', 'F03091', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4428, 132, 47.548045, 19.133581, 'Sárfű utca', 'F03192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4429, 132, 47.442946, 19.157555, 'Kispesti temető', 'F03837', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4430, 132, 47.449265, 19.130705, 'Hunyadi tér', 'F03972', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4431, 132, 47.438464, 19.146138, 'Hofherr Albert utca', 'F03992', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4432, 132, 47.420051, 19.073357, 'Sporttelep', 'F04204', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4433, 132, 47.409189, 19.072981, 'Csikó sétány', 'F04233', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4434, 132, 47.414479, 19.064079, 'Technikus utca', 'F04360', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4435, 132, 47.404797, 18.966409, 'Németh-villa', 'F04483', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4436, 132, 47.389267, 19.21899, 'Széchenyi István utca', 'F04602', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4437, 133, 47.377334, 19.210016, 'Toldi Miklós utca', '8550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4438, 133, 47.379533, 19.206672, 'Bacsó Béla utca', '8551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4439, 133, 47.39471, 19.210533, 'Mátyás király utca', '9199', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4440, 133, 47.392574, 19.213824, 'Tulipán utca', '9201', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4441, 133, 47.389747, 19.228502, 'Dobó Katica utca', '9205', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4442, 133, 47.359933, 19.028746, 'Massányi úti lakópark', '64067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4443, 133, 47.515584, 19.011535, 'Zilah utca', 'F00298', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4444, 133, 47.525983, 18.97687, 'Szent Ferenc Kórház', 'F00321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4445, 133, 47.534507, 18.97399, 'Nyéki út', 'F00330', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4446, 133, 47.56969, 19.09287, 'Türr István utca / Nádor utca', 'F00778', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4447, 133, 47.479116, 19.072655, 'Bokréta utca', 'F01382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4448, 133, 47.481887, 18.998171, 'Márton Áron tér', 'F02081', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4449, 133, 47.483256, 19.043547, 'Rezeda utca', 'F02137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4450, 133, 47.485172, 19.040614, 'Búsuló Juhász (Citadella)', 'F02138', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4451, 133, 47.499657, 19.017611, 'Tóth Lőrinc utca', 'F02403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4452, 133, 47.524895, 19.078598, 'Lehel utca / Róbert Károly körút', 'F02595', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4453, 133, 47.525797, 19.077524, 'Lehel utca / Róbert Károly körút', 'F02596', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4454, 133, 47.520427, 19.06966, 'Lehel utca / Dózsa György út', 'F02623', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4455, 133, 47.530041, 19.070699, 'Honvédkórház', 'F02643', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4456, 133, 47.518741, 19.085166, 'Kós Károly sétány', 'F02698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4457, 133, 47.511922, 19.124682, 'Jeszenák János utca', 'F02745', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4458, 133, 47.506078, 19.134443, 'Bánki Donát utca', 'F02754', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4459, 133, 47.509498, 19.14309, 'Füredi utca', 'F02774', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4460, 133, 47.513092, 19.137202, 'Fogarasi út', 'F02788', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4461, 133, 47.517513, 19.129947, 'Mogyoródi út', 'F02791', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4462, 133, 47.51736, 19.115688, 'Szugló utca / Nagy Lajos király útja', 'F02814', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4463, 133, 47.511588, 19.139669, 'Tihamér utca', 'F02933', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4464, 133, 47.515633, 19.13307, 'Gödöllői utca', 'F02939', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4465, 133, 47.541568, 19.107856, 'Rekettye utca', 'F03089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4466, 133, 47.546314, 19.127441, 'Bánkút utca', 'F03107', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4467, 133, 47.541985, 19.123096, 'Kolozsvár utca', 'F03177', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4468, 133, 47.422592, 19.21128, 'Királyhágó út', 'F03850', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4469, 133, 47.443238, 19.131119, 'Debrecen utca', 'F03896', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4470, 133, 47.437614, 19.121022, 'Kiss Ernő utca', 'F04169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4471, 133, 47.415417, 19.079755, 'Martinász utca', 'F04248', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4472, 133, 47.455351, 18.907478, 'Légimentők', 'F04741', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4473, 134, 47.437667, 19.223377, 'Ferihegy vasútállomás', '7928', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4474, 134, 47.431919, 18.914048, 'Harangláb', '8322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4475, 134, 47.455746, 18.940232, 'Budaörsi lakótelep', '8339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4476, 134, 47.375008, 19.213611, 'Kisfaludy Sándor utca', '8549', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4477, 134, 47.477155, 19.075785, 'Ferencvárosi rendelőintézet', '8702', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4478, 134, 47.389932, 19.217935, 'Gyál Ady Endre utca', '9194', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4479, 134, 47.392099, 19.224843, 'Táncsics Mihály utca', '9206', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4480, 134, 47.430331, 19.099898, 'Zodony utca', '9715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4481, 134, 47.543425, 18.94502, 'Ady lépcső', 'F00350', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4482, 134, 47.553803, 19.021193, 'Farkastorki út', 'F00535', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4483, 134, 47.556005, 19.018282, 'Judit utca', 'F00538', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4484, 134, 47.559044, 19.012806, 'Jablonka út 58.', 'F00542', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4485, 134, 47.54348, 19.036434, 'Szőlő utca', 'F00589', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4486, 134, 47.506227, 19.061769, 'Aradi utca (Oktogon M)', 'F01084', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4487, 134, 47.477831, 19.152307, 'Kada utca', 'F01685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4488, 134, 47.509551, 18.992915, 'Lelesz utca', 'F02368', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4489, 134, 47.515511, 19.089943, 'Ciklámen utca', 'F02701', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4490, 134, 47.512424, 19.094614, 'Vakok Intézete', 'F02715', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4491, 134, 47.515745, 19.092654, 'Erzsébet királyné útja aluljáró', 'F02922', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4492, 134, 47.5164, 19.091909, 'Erzsébet királyné útja aluljáró', 'F02923', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4493, 134, 47.513431, 19.096261, 'Ajtósi Dürer sor', 'F02979', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4494, 134, 47.548493, 19.107332, 'Istvántelek vasútállomás (Wysocki utca)', 'F03079', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4495, 134, 47.54564, 19.111712, 'Wysocki utca / Rákos út', 'F03085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4496, 134, 47.542988, 19.109119, 'Karatna tér', 'F03172', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4497, 134, 47.495861, 19.274398, 'Diadal utca', 'F03580', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4498, 134, 47.440465, 19.135464, 'Radnó utca', 'F03914', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4499, 134, 47.43955, 19.118228, 'Nagysándor József utca', 'F04105', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4500, 134, 47.446647, 19.11662, 'Kossuth Lajos utca (Mártírok útja)', 'F04139', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4501, 134, 47.392139, 19.105397, 'Csepel Hollandi út', 'F04229', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4502, 134, 47.383451, 19.228017, 'Bocskai István utca', 'F04649', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4503, 134, 47.38722, 19.222175, 'Somogyi Béla utca', 'F04650', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4504, 135, 47.501382, 19.136585, 'Örs vezér tere M+H', '8164', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4505, 135, 47.385634, 19.204439, 'Bajcsy-Zsilinszky utca', '8553', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4506, 135, 47.541205, 19.24703, 'Auchan Liget', '8670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4507, 135, 47.495533, 19.160424, 'Kabai utca', '8924', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4508, 135, 47.572905, 19.269251, 'Szilasliget Wesselényi utca', '9010', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4509, 135, 47.575376, 19.265659, 'Szilasliget Szondi utca', '9012', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4510, 135, 47.491547, 19.281877, 'Rákoscsaba vasútállomás', '9708', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4511, 135, 47.432737, 19.261218, 'Liszt Ferenc Airport 2', '89411', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4512, 135, 47.499246, 19.03083, 'Mikó utca', 'F00088', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4513, 135, 47.513674, 19.002655, 'Nagyajtai utca', 'F00162', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4514, 135, 47.513817, 19.033945, 'Margit körút', 'F00232', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4515, 135, 47.521398, 18.997881, 'Harangvirág utca', 'F00281', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4516, 135, 47.525816, 18.984213, 'Bölöni György utca', 'F00324', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4517, 135, 47.519337, 18.9939, 'Akadémia', 'F00430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4518, 135, 47.518914, 18.994153, 'Akadémia', 'F00431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4519, 135, 47.541109, 19.040538, 'Flórián tér', 'F00615', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4520, 135, 47.589527, 19.117973, 'Homoktövis utca', 'F00728', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4521, 135, 47.454285, 19.114295, 'Nagykőrösi út / Határ út', 'F01371', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4522, 135, 47.470269, 19.126824, 'Basa utca', 'F01540', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4523, 135, 47.47782, 19.144082, 'Márga utca', 'F01686', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4524, 135, 47.493758, 19.163101, 'MÁV-telep', 'F01787', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4525, 135, 47.481853, 19.042897, 'Balogh Tihamér utca', 'F02132', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4526, 135, 47.545477, 19.095108, 'Reitter Ferenc utca', 'F02548', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4527, 135, 47.52323, 19.081478, 'Vágány utca / Róbert Károly körút', 'F02632', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4528, 135, 47.517391, 19.08717, 'Bethesda utca', 'F02700', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4529, 135, 47.514197, 19.111038, 'Szugló utca / Róna utca', 'F02819', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4530, 135, 47.516357, 19.10804, 'Tisza István tér', 'F02821', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4531, 135, 47.513612, 19.095746, 'Ajtósi Dürer sor', 'F02978', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4532, 135, 47.547044, 19.109562, 'Patyolat utca', 'F03081', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4533, 135, 47.54203, 19.117154, 'Opál utca', 'F03169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4534, 135, 47.413069, 19.173792, 'Pestszentimre felső vasútállomás', 'F03675', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4535, 135, 47.452601, 19.117277, 'Rákóczi utca', 'F03897', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4536, 135, 47.446137, 19.126669, 'Nagysándor József utca', 'F03915', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4537, 135, 47.45295, 19.121348, 'Thököly utca', 'F03968', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4538, 135, 47.440723, 19.138566, 'Vas Gereben utca', 'F03986', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4539, 135, 47.446821, 19.134206, 'Batthyány utca', 'F04025', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4540, 135, 47.44004, 19.1439, 'Garázs utca', 'F04033', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4541, 135, 47.427277, 19.126045, 'Királyhágó utca', 'F04072', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4542, 135, 47.435516, 19.110203, 'Nagysándor József utca', 'F04112', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4543, 135, 47.449177, 19.113029, 'Szigligeti utca', 'F04173', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4544, 135, 47.427726, 19.020314, 'Kiránduló utca', 'F04422', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4545, 135, 47.560689, 18.917883, 'Erdészház', 'F04779', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4546, 136, 47.519642, 19.103436, 'Uzsoki Utcai Kórház', '8460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4547, 136, 47.560809, 19.282727, 'Kerepes Szabadság útja', '9005', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4548, 136, 47.505246, 19.057588, 'Zichy Jenő utca', '9026', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4549, 136, 47.404616, 18.933765, 'Pacsirta utca', '9096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4550, 136, 47.385265, 19.235533, 'Gyál Deák Ferenc utca', '9203', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4551, 136, 47.388079, 19.231106, 'Árpád utca', '9204', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4552, 136, 47.514715, 19.023855, 'Rét utca', 'F00204', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4553, 136, 47.522392, 18.98917, 'Kelemen László utca', 'F00310', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4554, 136, 47.567016, 19.097877, 'Szent László tér', 'F00779', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4555, 136, 47.505497, 19.070171, 'Izabella utca / Király utca', 'F01040', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4556, 136, 47.509712, 19.0822, 'Damjanich utca / Dózsa György út', 'F01134', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4557, 136, 47.483012, 19.06244, 'Zsil utca', 'F01346', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4558, 136, 47.476327, 19.118316, 'Fertő utca / Bihari utca', 'F01536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4559, 136, 47.479382, 19.135387, 'Ihász utca', 'F01660', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4560, 136, 47.479441, 19.141683, 'Óhegy park', 'F01667', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4561, 136, 47.516849, 19.093967, 'Erzsébet királyné útja aluljáró', 'F02881', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4562, 136, 47.517806, 19.089562, 'Kacsóh Pongrác út', 'F02892', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4563, 136, 47.518677, 19.088565, 'Kacsóh Pongrác út', 'F02894', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4564, 136, 47.508905, 19.20841, 'Újszász utca', 'F03317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4565, 136, 47.435889, 19.143754, 'Naszód utca (Használtcikk piac)', 'F03907', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4566, 136, 47.449721, 19.121423, 'Kossuth Lajos utca', 'F03917', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4567, 136, 47.454849, 19.118699, 'Hungária út', 'F03920', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4568, 136, 47.450152, 19.146167, 'Kossuth Lajos utca', 'F03980', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4569, 136, 47.442524, 19.135944, 'Zalaegerszeg utca', 'F03985', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4570, 136, 47.447793, 19.149543, 'Vas Gereben utca', 'F04003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4571, 136, 47.452574, 19.142697, 'Templom tér', 'F04022', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4572, 136, 47.447119, 19.125225, 'Irányi utca', 'F04038', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4573, 136, 47.428681, 19.124045, 'Rózsás utca', 'F04066', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4574, 136, 47.429544, 19.125544, 'Szalárdi Mór utca', 'F04067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4575, 136, 47.428418, 19.12712, 'Pacsirta utca', 'F04071', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4576, 136, 47.417219, 19.140408, 'Írisz utca', 'F04085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4577, 136, 47.435587, 19.139141, 'Hunyadi János tér', 'F04087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4578, 136, 47.435889, 19.13193, 'Temesvár utca', 'F04089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4579, 136, 47.435274, 19.124333, 'Magyar utca', 'F04095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4580, 136, 47.4385, 19.128209, 'Magyar utca', 'F04096', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4581, 136, 47.442515, 19.122527, 'Nagyszőlős utca', 'F04099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4582, 136, 47.440356, 19.107384, 'Szent Erzsébet tér', 'F04134', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4583, 136, 47.420434, 19.135842, 'Mesgye utca', 'F04166', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4584, 136, 47.398675, 19.149013, 'Kertészeti Egyetem', 'F04560', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4585, 136, 47.388861, 19.206012, 'Gyál felső vasútállomás', 'F04600', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4586, 136, 47.449133, 18.962739, 'Agip utca', 'F04729', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4587, 137, 47.501112, 19.135882, 'Örs vezér tere M+H', '8028', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4588, 137, 47.483256, 19.12813, 'Kőbánya alsó vasútállomás', '8380', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4589, 137, 47.403412, 19.18662, 'Pestszentimre vasútáll. (Nagykőrösi út)', '8949', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4590, 137, 47.416948, 19.131198, 'Szentlőrinci úti lakótelep', '8967', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4591, 137, 47.599126, 19.346731, 'Gödöllő Szökőkút', '9016', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4592, 137, 47.410123, 18.927181, 'Katinka utca', '9100', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4593, 137, 47.411959, 19.005128, 'Bíbic utca', '61237', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4594, 137, 47.423225, 19.132016, 'Jahn Ferenc Kórház', '69403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4595, 137, 47.348872, 19.050468, 'Rákóczi utca', '101179', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4596, 137, 47.501719, 19.027432, 'Korlát utca', 'F00092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4597, 137, 47.516146, 18.998975, 'Budagyöngye', 'F00167', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4598, 137, 47.549711, 19.026376, 'Táborhegyi út', 'F00565', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4599, 137, 47.544878, 19.034772, 'Óbudai rendelőintézet', 'F00587', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4600, 137, 47.511011, 19.06266, 'Ferdinánd híd (Izabella utca)', 'F01072', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4601, 137, 47.450887, 19.110592, 'Mártírok útja / Határ út', 'F01449', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4602, 137, 47.482972, 19.145882, 'Csősztorony', 'F01671', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4603, 137, 47.487085, 19.152889, 'Gitár utca', 'F01674', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4604, 137, 47.44993, 19.022997, 'Duránci utca', 'F01942', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4605, 137, 47.483316, 18.99622, 'Orvosi rendelő', 'F02364', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4606, 137, 47.532974, 19.066119, 'Göncz Árpád városközpont M', 'F02600', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4607, 137, 47.549166, 19.08989, 'Angyalföld vasútállomás', 'F02653', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4608, 137, 47.51468, 19.097072, 'Korong utca', 'F02875', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4609, 137, 47.517542, 19.093251, 'Erzsébet királyné útja aluljáró', 'F02879', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4610, 137, 47.586774, 19.133245, 'Rákospalota MEDIMPEX', 'F03003', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4611, 137, 47.402134, 19.201692, 'Kapocs utca', 'F03724', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4612, 137, 47.460399, 19.131663, 'Corvin körút', 'F03926', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4613, 137, 47.456311, 19.137412, 'Hungária út', 'F03960', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4614, 137, 47.451731, 19.127234, 'Zoltán utca', 'F03966', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4615, 137, 47.444129, 19.138073, 'Kossuth Lajos utca', 'F03983', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4616, 137, 47.431267, 19.116162, 'Klapka utca', 'F04062', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4617, 137, 47.425827, 19.128084, 'Előd utca', 'F04073', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4618, 137, 47.440796, 19.124951, 'Mézes utca', 'F04171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4619, 137, 47.390876, 19.159303, 'Tangazdaság (Óvoda)', 'F04567', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4620, 137, 47.554785, 18.927102, 'Rácski telep', 'F04777', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4621, 137, 47.507011, 18.921411, 'Budakeszi Dózsa György tér', 'F04788', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4622, 138, 47.376675, 19.124382, 'MNB', '9315', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4623, 138, 47.417175, 19.018319, 'Római utca', '9457', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4624, 138, 47.411163, 18.993654, 'XIII. utca / Dózsa György út', '9534', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4625, 138, 47.43634, 19.098763, 'Baross utca', '9586', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4626, 138, 47.352386, 19.025321, 'Ősz utca', '64175', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4627, 138, 47.473213, 19.300607, 'Szárazhegy köz', '66700', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4628, 138, 47.495182, 19.038529, 'Palota út gyorslift', 'F00076', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4629, 138, 47.496567, 19.034415, 'Alagút utca', 'F00084', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4630, 138, 47.519501, 19.000446, 'Virág árok', 'F00283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4631, 138, 47.521682, 18.990539, 'Kelemen László utca', 'F00313', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4632, 138, 47.548372, 19.029565, 'Bécsi út / Vörösvári út', 'F00570', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4633, 138, 47.503402, 19.060269, 'Opera M', 'F01076', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4634, 138, 47.455179, 19.086422, 'Kén utca H', 'F01418', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4635, 138, 47.472511, 19.123644, 'Hízlaló tér', 'F01638', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4636, 138, 47.487724, 19.151736, 'Gitár utca', 'F01675', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4637, 138, 47.482897, 19.158401, 'Kada utca / Maglódi út', 'F01676', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4638, 138, 47.473537, 19.163953, 'Mádi utca', 'F01704', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4639, 138, 47.477675, 19.165189, 'Venyige utca', 'F01706', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4640, 138, 47.477458, 19.165653, 'Venyige utca', 'F01707', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4641, 138, 47.484933, 19.155718, 'Kocka utca', 'F01830', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4642, 138, 47.471669, 19.163159, 'Újhegyi sétány', 'F01841', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4643, 138, 47.473104, 19.008248, 'Bereck utca', 'F02092', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4644, 138, 47.519116, 19.127308, 'Egressy út / Vezér utca', 'F02802', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4645, 138, 47.541494, 19.171607, 'Besztercebányai utca', 'F02982', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4646, 138, 47.516452, 19.17077, 'Budapesti út', 'F03249', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4647, 138, 47.423128, 19.189609, 'Péterhalmi út', 'F03641', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4648, 138, 47.40817, 19.193681, 'Kisfaludy utca', 'F03652', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4649, 138, 47.404215, 19.198943, 'Szigeti Kálmán utca', 'F03656', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4650, 138, 47.421742, 19.162402, 'Kamiontelep', 'F03681', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4651, 138, 47.393096, 19.200262, 'Kalász utca', 'F03779', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4652, 138, 47.410871, 19.176728, 'Bethlen Gábor utca', 'F03818', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4653, 138, 47.406529, 19.182468, 'Eke utca', 'F03874', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4654, 138, 47.399646, 19.191657, 'Csolt utca', 'F03875', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4655, 138, 47.441436, 19.14194, 'Pincér utca', 'F03991', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4656, 138, 47.423756, 19.131116, 'Jahn Ferenc Kórház', 'F04084', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4657, 138, 47.444012, 19.099752, 'Török Flóris utca', 'F04152', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4658, 138, 47.413041, 19.085663, 'Borz út', 'F04250', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4659, 138, 47.549594, 18.934181, 'BM iskola', 'F04822', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4660, 139, 47.438346, 18.906478, 'Katona József utca', '8345', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4661, 139, 47.376062, 19.042074, 'Leshegy Ipari Park', '64241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4662, 139, 47.476785, 19.295994, 'Sport tér', '66698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4663, 139, 47.475188, 19.298029, 'Enying utca', '66699', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4664, 139, 47.497414, 19.054803, 'Deák Ferenc tér M', '105925', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4665, 139, 47.565478, 19.100055, 'Újpesti rendelőintézet', 'F00791', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4666, 139, 47.50426, 19.088368, 'Reiner Frigyes park', 'F01144', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4667, 139, 47.474486, 19.169334, 'Sírkert út', 'F01710', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4668, 139, 47.469975, 19.165304, 'Tavas utca', 'F01714', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4669, 139, 47.484915, 19.155466, 'Kocka utca', 'F01831', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4670, 139, 47.480034, 19.16201, 'Bajcsy-Zsilinszky Kórház', 'F01833', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4671, 139, 47.480034, 19.162209, 'Bajcsy-Zsilinszky Kórház', 'F01835', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4672, 139, 47.468748, 19.160964, 'Bányató utca', 'F01843', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4673, 139, 47.516625, 19.09248, 'Erzsébet királyné útja aluljáró', 'F02919', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4674, 139, 47.547329, 19.141457, 'Újpalota Nyírpalota út', 'F03165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4675, 139, 47.4236, 19.169835, 'Közdűlő út', 'F03628', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4676, 139, 47.396871, 19.195292, 'Ár utca', 'F03657', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4677, 139, 47.410296, 19.184014, 'Vásáros tér', 'F03661', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4678, 139, 47.420175, 19.164532, 'Közdűlő út', 'F03679', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4679, 139, 47.416527, 19.169056, 'Zöldségpiac', 'F03871', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4680, 139, 47.43602, 19.109554, 'Nagysándor József utca', 'F04111', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4681, 139, 47.418618, 19.122136, 'Erzsébeti temető', 'F04564', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4682, 140, 47.426556, 19.160782, 'Dél-pesti autóbuszgarázs', '8471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4683, 140, 47.413022, 19.136121, 'Soroksár Kertvárosi körút', '49174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4684, 140, 47.532812, 19.065866, 'Göncz Árpád városközpont M', '66828', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4685, 140, 47.507107, 19.027124, 'Széll Kálmán tér M (Ostrom utca)', '73137', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4686, 140, 47.528324, 18.98097, 'Szerb Antal utca', 'F00326', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4687, 140, 47.532318, 19.036703, 'Katinyi mártírok parkja', 'F00599', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4688, 140, 47.541672, 19.039184, 'Flórián tér', 'F00616', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4689, 140, 47.515138, 19.069963, 'Munkácsy Mihály utca', 'F01073', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4690, 140, 47.505561, 19.062871, 'Oktogon M', 'F01081', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4691, 140, 47.50462, 19.063886, 'Oktogon M', 'F01087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4692, 140, 47.508294, 19.066363, 'Andrássy út (Vörösmarty utca M)', 'F01090', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4693, 140, 47.502825, 19.073555, 'Wesselényi utca / Izabella utca', 'F01117', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4694, 140, 47.463682, 19.100792, 'Aszódi utca', 'F01476', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4695, 140, 47.503162, 19.16792, 'Heves utca', 'F01789', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4696, 140, 47.46913, 19.013398, 'Sümegvár utca', 'F02095', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4697, 140, 47.471081, 19.010942, 'Előpatak utca', 'F02247', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4698, 140, 47.485024, 18.994161, 'Hajnalka utca', 'F02277', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4699, 140, 47.510921, 19.09958, 'Zugló vasútállomás', 'F02731', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4700, 140, 47.516136, 19.143195, 'Zsálya utca', 'F02781', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4701, 140, 47.51483, 19.144719, 'Újváros park', 'F02935', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4702, 140, 47.508131, 19.171959, 'Veres Péter út', 'F03272', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4703, 140, 47.511995, 19.167383, 'Őrmester utca', 'F03365', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4704, 140, 47.424762, 19.168367, 'Besence utca 9.', 'F03678', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4705, 140, 47.464459, 19.126709, 'Határ út M', 'F04017', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4706, 140, 47.441708, 19.10256, 'János tér', 'F04131', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4707, 140, 47.443985, 19.099924, 'Török Flóris utca', 'F04153', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4708, 140, 47.41333, 19.017595, 'Tűztorony', 'F04452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4709, 140, 47.44246, 18.901296, 'Idősek otthona', 'F04762', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4710, 140, 47.435086, 18.910623, 'Zrínyi utca', 'F04815', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4711, 141, 47.478737, 19.278238, 'Öntöző utca', '66716', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4712, 141, 47.57067, 18.931469, 'Muflon utca', '72047', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4713, 141, 47.414632, 18.951447, 'Diósd Kaktusz utca', '98526', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4714, 141, 47.58546, 18.99743, 'Tücsök utca', 'F00434', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4715, 141, 47.575818, 19.013692, 'Szőlővész utca', 'F00529', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4716, 141, 47.560266, 19.011145, 'Jablonka út 74.', 'F00544', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4717, 141, 47.489805, 19.09056, 'Orczy tér', 'F01219', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4718, 141, 47.467882, 19.101154, 'Merényi Gusztáv Kórház', 'F01365', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4719, 141, 47.471483, 19.166562, 'Tavas utca', 'F01845', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4720, 141, 47.507936, 19.09526, 'Stefánia út / Thököly út', 'F02720', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4721, 141, 47.553393, 19.132213, 'Aulich Lajos utca', 'F03065', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4722, 141, 47.538744, 19.174926, 'Gusztáv utca', 'F03211', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4723, 141, 47.509373, 19.17921, 'Sashalom H', 'F03244', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4724, 141, 47.511868, 19.176216, 'Margit utca', 'F03247', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4725, 141, 47.50969, 19.170165, 'Sashalmi tér', 'F03368', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4726, 141, 47.515025, 19.191292, 'Gida utca', 'F03408', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4727, 141, 47.441465, 19.103011, 'János tér', 'F04132', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4728, 141, 47.398237, 19.105947, 'Csepel Soroksári rév', 'F04226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4729, 141, 47.409952, 19.062169, 'Kölcsey utca', 'F04280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4730, 141, 47.415606, 19.132918, 'Újtelep út', 'F04574', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4731, 142, 47.434282, 19.035651, 'Vihar utca', '8106', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4732, 142, 47.418567, 19.117379, 'Előd utca', '8957', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4733, 142, 47.480756, 19.275992, 'Nyitány utca', '66714', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4734, 142, 47.504157, 19.029302, 'Kapisztrán tér', 'F00114', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4735, 142, 47.536415, 18.975621, 'Völgy utca', 'F00348', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4736, 142, 47.554968, 19.089669, 'Tél utca / Pozsonyi utca', 'F00819', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4737, 142, 47.515668, 19.075446, 'Hősök tere M', 'F01055', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4738, 142, 47.51914, 19.071491, 'Vágány utca / Dózsa György út', 'F01060', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4739, 142, 47.496445, 19.08312, 'Dologház utca', 'F01309', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4740, 142, 47.466797, 19.112003, 'Börzsöny utca', 'F01364', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4741, 142, 47.469484, 19.099352, 'Péceli utca', 'F01492', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4742, 142, 47.480649, 19.148929, 'Szlávy utca', 'F01838', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4743, 142, 47.49934, 19.00864, 'Szent Orbán tér', 'F02400', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4744, 142, 47.511586, 19.098436, 'Zugló vasútállomás', 'F02727', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4745, 142, 47.506744, 19.104445, 'Egressy út / Hungária körút', 'F02744', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4746, 142, 47.520363, 19.187536, 'Sasvár utca', 'F03232', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4747, 142, 47.514543, 19.173036, 'Sasvár utca', 'F03356', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4748, 142, 47.425416, 19.157758, 'Szentlőrinci út', 'F03632', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4749, 142, 47.42328, 19.239961, 'Repülőtéri Rendőr Igazgatóság', 'F03781', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4750, 142, 47.432588, 19.102856, 'Nagysándor József utca', 'F04055', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4751, 142, 47.426604, 19.108668, 'Torontál utca', 'F04059', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4752, 142, 47.439382, 19.10557, 'Kossuth Lajos utca', 'F04130', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4753, 142, 47.417846, 19.076377, 'Csepeli Mátyás utca', 'F04339', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4754, 142, 47.407717, 18.962681, 'Szerafin-villa', 'F04481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4755, 143, 47.518294, 19.180653, 'Sasvár utca', '9240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4756, 143, 47.425665, 19.001313, 'Memento Park', '9546', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4757, 143, 47.430722, 19.005656, 'Jégmadár utca', '62514', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4758, 143, 47.476592, 19.280644, 'Pestvidék utca', '66718', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4759, 143, 47.489824, 19.047383, 'Rudas Gyógyfürdő', '72935', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4760, 143, 47.531002, 18.978058, 'Lipótmezei út', 'F00328', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4761, 143, 47.532399, 19.03645, 'Katinyi mártírok parkja', 'F00601', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4762, 143, 47.486862, 19.056602, 'Fővám tér M', 'F00919', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4763, 143, 47.502781, 19.066202, 'Király utca / Erzsébet körút', 'F01037', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4764, 143, 47.513212, 19.078233, 'Benczúr utca', 'F01048', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4765, 143, 47.506239, 19.086166, 'Ötvenhatosok tere', 'F01141', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4766, 143, 47.492234, 19.08787, 'Magdolna utca', 'F01213', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4767, 143, 47.465339, 19.114389, 'Valéria tér', 'F01469', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4768, 143, 47.466954, 19.015972, 'Sasadi út', 'F01925', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4769, 143, 47.466303, 19.005481, 'Nagyszeben tér', 'F02056', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4770, 143, 47.425956, 19.236962, 'Repülőtér D porta', 'F03783', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4771, 143, 47.43357, 19.09774, 'Pesterzsébet vasútállomás', 'F04120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4772, 143, 47.423688, 19.111792, 'Akácfa utca', 'F04158', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4773, 143, 47.404135, 19.006618, 'Tűzliliom utca', 'F04460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4774, 143, 47.385329, 19.210332, 'Ady Endre utca', 'F04605', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4775, 144, 47.503724, 19.022401, 'Maros utcai rendelőintézet', '7900', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4776, 144, 47.542605, 18.964773, 'Hűvösvölgy', '7974', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4777, 144, 47.48984, 19.047208, 'Rudas Gyógyfürdő', 'F00108', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4778, 144, 47.502926, 19.06591, 'Király utca / Erzsébet körút', 'F01039', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4779, 144, 47.508344, 19.083752, 'Dembinszky utca', 'F01138', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4780, 144, 47.441549, 19.095056, 'Baross utca', 'F01441', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4781, 144, 47.447709, 19.025411, 'Narancs utca', 'F01943', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4782, 144, 47.441854, 19.032096, 'Albertfalva vasútállomás', 'F02263', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4783, 144, 47.445272, 19.028449, 'Állvány utca', 'F02264', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4784, 144, 47.506799, 19.104116, 'Egressy út / Hungária körút', 'F02742', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4785, 144, 47.510637, 19.147247, 'Füredi utca / Szentmihályi út', 'F02776', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4786, 144, 47.514904, 19.164004, 'Budapesti út', 'F03271', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4787, 144, 47.520306, 19.158691, 'Szent Korona utca', 'F03363', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4788, 144, 47.428556, 19.10655, 'Tinódi utca', 'F04057', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4789, 144, 47.430714, 19.108964, 'Pöltenberg utca', 'F04060', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4790, 144, 47.421219, 19.119105, 'Ábrahám Géza utca', 'F04080', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4791, 144, 47.425912, 19.114118, 'Akácfa utca', 'F04159', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4792, 144, 47.428684, 19.111179, 'Klapka utca', 'F04160', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4793, 144, 47.422122, 19.1135, 'Bolyai János utca', 'F04161', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4794, 144, 47.424406, 19.002626, 'Brassói utca', 'F04438', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4795, 144, 47.382322, 19.126866, 'Polimer', 'F04552', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4796, 144, 47.388877, 19.162014, 'Víztorony tér', 'F04563', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4797, 144, 47.58988, 18.987481, 'Külső Bécsi út 35.', 'F04775', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4798, 145, 47.616729, 19.051767, 'Budakalász Budai út', '8429', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4799, 145, 47.355275, 19.034823, 'Kölcsey Ferenc utca', '64085', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4800, 145, 47.416996, 18.985724, 'Bartók Béla út 208.', '83330', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4801, 145, 47.531885, 18.98078, 'Vadaskerti utca', 'F00319', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4802, 145, 47.464405, 19.126338, 'Határ út M', 'F01459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4803, 145, 47.479693, 19.0663, 'Boráros tér H', 'F01519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4804, 145, 47.435844, 18.978689, 'Kamaraerdő', 'F01881', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4805, 145, 47.483792, 19.021017, 'Breznó lépcső', 'F02109', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4806, 145, 47.534423, 19.161051, 'János utca', 'F02987', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4807, 145, 47.530751, 19.171627, 'Csömöri út', 'F03222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4808, 145, 47.527692, 19.180663, 'Rákosi út', 'F03229', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4809, 145, 47.505996, 19.211401, 'Nebántsvirág utca', 'F03399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4810, 145, 47.43526, 19.099902, 'Pesterzsébet Kossuth Lajos utca', 'F04118', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4811, 145, 47.423235, 19.117014, 'Károly utca', 'F04163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4812, 145, 47.408104, 19.082016, 'Varrógép utca', 'F04241', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4813, 145, 47.406833, 18.998278, 'Szent László utca', 'F04471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4814, 145, 47.383348, 19.125729, 'Ócsai úti felüljáró', 'F04570', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4815, 146, 47.463484, 19.100991, 'Aszódi utca', '8498', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4816, 146, 47.405823, 18.999264, 'Szent László utca', '11313', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4817, 146, 47.527073, 18.954042, 'Szépjuhászné Gyermekvasút', 'F00180', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4818, 146, 47.548308, 18.94936, 'Turul utca', 'F00367', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4819, 146, 47.506422, 19.076609, 'Bethlen Gábor utca', 'F01123', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4820, 146, 47.504514, 19.078414, 'Bethlen Gábor tér', 'F01127', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4821, 146, 47.473403, 19.079922, 'Vágóhíd utca', 'F01398', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4822, 146, 47.467873, 19.07574, 'Közvágóhíd H', 'F01403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4823, 146, 47.488014, 19.003938, 'Kempelen Farkas utca', 'F02283', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4824, 146, 47.505973, 19.023169, 'Széll Kálmán tér M (Csaba utca)', 'F02333', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4825, 146, 47.528875, 19.152547, 'Rákospalotai határút', 'F02988', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4826, 146, 47.535352, 19.167095, 'Gusztáv utca', 'F03220', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4827, 146, 47.523361, 19.184967, 'Mátyásföldi tér', 'F03231', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4828, 146, 47.521348, 19.166998, 'Rákosi út', 'F03253', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4829, 146, 47.518406, 19.160612, 'Rákosi út', 'F03269', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4830, 146, 47.52219, 19.152128, 'Csömöri út', 'F03278', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4831, 146, 47.516404, 19.155383, 'Rákosi út', 'F03282', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4832, 146, 47.529673, 19.178676, 'Szent Korona utca', 'F03342', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4833, 146, 47.532271, 19.163171, 'Vörösmarty utca', 'F03346', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4834, 146, 47.526014, 19.169319, 'Szent Korona utca', 'F03357', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4835, 146, 47.524378, 19.149915, 'Baross utca', 'F03370', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4836, 146, 47.42851, 19.007951, 'Liszt Ferenc út', 'F04430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4837, 146, 47.414679, 19.015687, 'Rizling utca (Sportpálya)', 'F04449', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4838, 146, 47.587064, 18.937162, 'Munkás utca', 'F04698', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4839, 147, 47.420529, 19.014023, 'Hűség utca', '9459', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4840, 147, 47.499918, 19.079088, 'Munkás utca', '61368', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4841, 147, 47.412851, 18.988856, 'Török utca', '62516', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4842, 147, 47.49895, 19.036113, 'Dísz tér', 'F00074', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4843, 147, 47.537593, 19.013551, 'Nyereg út', 'F00121', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4844, 147, 47.514056, 19.015678, 'Gondozási Központ', 'F00373', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4845, 147, 47.492263, 19.083476, 'Teleki László tér (Szerdahelyi utca)', 'F01212', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4846, 147, 47.483652, 19.127839, 'Kőbánya alsó vasútállomás', 'F01636', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4847, 147, 47.470155, 18.994848, 'Tűzkő utca', 'F02067', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4848, 147, 47.525494, 19.148775, 'Pálya utca', 'F02993', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4849, 147, 47.523628, 19.17169, 'Rákosi út', 'F03251', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4850, 147, 47.528796, 19.166603, 'Csömöri út', 'F03258', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4851, 147, 47.520491, 19.151328, 'Rózsa utca', 'F03280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4852, 147, 47.533524, 19.168897, 'Baross utca', 'F03349', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4853, 147, 47.526315, 19.182014, 'Ida utca', 'F03352', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4854, 147, 47.523824, 19.16452, 'Szent Korona utca', 'F03359', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4855, 147, 47.526651, 19.15472, 'Baross utca', 'F03361', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4856, 147, 47.518141, 19.153661, 'Szent Korona utcai lakótelep', 'F03372', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4857, 147, 47.502798, 19.21442, 'Zsemlékes út', 'F03401', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4858, 147, 47.401327, 19.003626, 'I. utca', 'F04469', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4859, 147, 47.408888, 18.996253, 'Bem tábornok utca', 'F04472', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4860, 147, 47.403881, 19.00116, 'Tátra utca', 'F04511', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4861, 147, 47.440955, 18.913604, 'Deák Ferenc utca', 'F04749', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4862, 148, 47.526114, 18.985806, 'Zuhatag sor', 'F00315', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4863, 148, 47.528245, 18.983825, 'Nagyhíd', 'F00317', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4864, 148, 47.566417, 18.942349, 'Köztársaság utca', 'F00383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4865, 148, 47.551842, 19.023958, 'Zúzmara utca', 'F00673', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4866, 148, 47.508185, 19.075442, 'Reformáció park', 'F01046', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4867, 148, 47.468218, 19.003437, 'Regős köz', 'F02242', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4868, 148, 47.395771, 19.092117, 'Uzsoki utca', 'F04343', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4869, 148, 47.378254, 19.130436, 'Soroksár központi raktárak', 'F04523', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4870, 148, 47.389252, 19.120215, 'Zsellér dűlő', 'F04551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4871, 149, 47.471165, 19.073129, 'Müpa - Nemzeti Színház H', '8239', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4872, 149, 47.464573, 19.126164, 'Határ út M', '8490', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4873, 149, 47.475447, 19.069562, 'Haller utca / Soroksári út', '9042', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4874, 149, 47.462276, 19.08457, 'Hentes utca', '40525', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4875, 149, 47.435378, 18.918467, 'Nyár utca', '73471', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4876, 149, 47.504138, 19.024989, 'Körmöci utca', 'F00029', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4877, 149, 47.548881, 18.951678, 'Turul utca (kishíd)', 'F00371', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4878, 149, 47.568852, 18.940151, 'Honfoglalás utca', 'F00385', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4879, 149, 47.571855, 18.944712, 'Örökzöld utca', 'F00399', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4880, 149, 47.556066, 19.011148, 'Hedvig utca', 'F00552', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4881, 149, 47.490856, 19.051667, 'Március 15. tér', 'F01001', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4882, 149, 47.475625, 19.077774, 'Haller utca / Mester utca', 'F01393', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4883, 149, 47.481649, 19.135112, 'Kápolna utca', 'F01655', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4884, 149, 47.470681, 19.001312, 'Frankhegy utca', 'F02057', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4885, 149, 47.477895, 19.002354, 'Tömös utca', 'F02088', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4886, 149, 47.50746, 19.150188, 'Ond vezér útja / Szentmihályi út', 'F02772', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4887, 149, 47.565568, 19.129862, 'Kossuth utca lakótelep', 'F03034', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4888, 149, 47.490687, 19.270701, 'Harsona utca', 'F03460', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4889, 149, 47.4863, 19.287337, 'Csaba vezér tér', 'F03484', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4890, 149, 47.401458, 19.10293, 'Ladik utca', 'F04223', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4891, 149, 47.458648, 18.946923, 'Lévai utca', 'F04806', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4892, 150, 47.56955, 19.018939, 'ATI', 'F00618', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4893, 150, 47.571951, 19.016865, 'Bóbita utca', 'F00631', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4894, 150, 47.489492, 19.086088, 'Kálvária tér', 'F01222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4895, 150, 47.494567, 19.076855, 'II. János Pál pápa tér M', 'F01303', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4896, 150, 47.460343, 19.082155, 'Beöthy utca', 'F01352', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4897, 150, 47.462763, 19.080141, 'Földváry utca', 'F01355', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4898, 150, 47.470958, 19.073182, 'Müpa - Nemzeti Színház H', 'F01396', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4899, 150, 47.459828, 19.087645, 'Magyar Aszfalt', 'F01414', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4900, 150, 47.490753, 19.120923, 'Pongrácz úti lakótelep', 'F01559', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4901, 150, 47.479379, 19.001132, 'Zajzon utca', 'F02087', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4902, 150, 47.473142, 18.992276, 'Gazdagréti tér', 'F02226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4903, 150, 47.404692, 19.091023, 'Repkény út', 'F04257', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4904, 151, 47.431485, 18.927729, 'Liliom utca', '48304', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4905, 151, 47.527185, 19.007702, 'Tömörkény utca', 'F00268', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4906, 151, 47.492381, 19.078579, 'Mátyás tér', 'F01209', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4907, 151, 47.466162, 19.081204, 'Koppány utca', 'F01341', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4908, 151, 47.464963, 19.126073, 'Határ út M', 'F01462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4909, 151, 47.500096, 18.989408, 'Városkút', 'F02318', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4910, 151, 47.429918, 19.182827, 'Obszervatórium', 'F03636', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4911, 151, 47.415402, 19.186532, 'Szálfa utca', 'F03645', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4912, 151, 47.413163, 19.188416, 'Törvény utca', 'F03687', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4913, 152, 47.493186, 19.044315, 'Várkert Bazár', 'F00107', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4914, 152, 47.516742, 19.00394, 'Gábor Áron utca / Pasaréti út', 'F00285', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4915, 152, 47.561331, 19.02382, 'Kubik utca', 'F00550', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4916, 152, 47.536042, 19.033355, 'Selmeci utca', 'F00685', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4917, 152, 47.535505, 19.033659, 'Selmeci utca', 'F00687', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4918, 152, 47.55962, 19.082351, 'Újpest-városkapu M (Temesvári utca)', 'F00811', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4919, 152, 47.490694, 19.051654, 'Március 15. tér', 'F01000', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4920, 152, 47.49983, 19.06916, 'Wesselényi utca / Erzsébet körút', 'F01112', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4921, 152, 47.482733, 19.290051, 'Alsódabas utca', 'F03490', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4922, 152, 47.411011, 19.19016, 'Vezér utca', 'F03647', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4923, 152, 47.393342, 19.094062, 'Csorbatói utca', 'F04345', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4924, 152, 47.398498, 19.089894, 'Olt utca', 'F04347', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4925, 152, 47.41606, 19.093126, 'Csepel Határ utca', 'F04364', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4926, 152, 47.405384, 19.005584, 'Rákóczi út', 'F04462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4927, 153, 47.460536, 18.945898, 'Bretzfeld utca', '8462', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4928, 153, 47.384361, 19.02581, 'Auchan Sziget áruház', '8651', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4929, 153, 47.384163, 19.025969, 'Auchan Sziget áruház', '8652', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4930, 153, 47.390521, 19.118865, 'Grassalkovich út', '8963', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4931, 153, 47.556403, 19.026307, 'Orbán Balázs út', 'F00519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4932, 153, 47.48178, 19.12846, 'Vaspálya utca', 'F01640', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4933, 153, 47.496862, 19.116366, 'Hős utca', 'F01745', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4934, 153, 47.477236, 19.131876, 'Kelemen utca', 'F01795', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4935, 153, 47.47777, 19.045935, 'Móricz Zsigmond körtér M', 'F02201', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4936, 153, 47.504008, 19.136656, 'Örs vezér tere M+H', 'F02764', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4937, 153, 47.43609, 19.095038, 'Csepeli átjáró', 'F04146', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4938, 153, 47.418777, 19.091299, 'Késmárki utca', 'F04218', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4939, 153, 47.401845, 19.08718, 'Fátra utca', 'F04244', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4940, 153, 47.416663, 19.092861, 'Határ utca', 'F04363', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4941, 154, 47.431205, 19.012359, 'Zöldike utca', '8125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4942, 154, 47.423955, 19.011131, 'Komáromi út', '9461', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4943, 154, 47.520084, 19.021729, 'Eszter utca', 'F00210', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4944, 154, 47.521082, 19.021064, 'Vend utca', 'F00212', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4945, 154, 47.552623, 19.014458, 'Viharhegyi út', 'F00554', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4946, 154, 47.50066, 19.04608, 'Széchenyi István tér', 'F00973', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4947, 154, 47.494561, 19.059696, 'Astoria M', 'F01012', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4948, 154, 47.473981, 19.070649, 'Haller utca / Soroksári út', 'F01384', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4949, 154, 47.4835, 19.12622, 'Kőbánya alsó vasútállomás', 'F01570', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4950, 154, 47.485004, 19.216899, '501. utca', 'F01720', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4951, 154, 47.509116, 19.00903, 'Szent János Kórház', 'F02342', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4952, 154, 47.483566, 19.278314, 'Lemberg utca', 'F03478', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4953, 154, 47.397161, 19.099071, 'Denevér utca', 'F04261', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4954, 154, 47.417015, 19.109916, 'Festékgyár', 'F04528', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4955, 155, 47.438572, 19.034695, 'Méhész utca', '9475', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4956, 155, 47.348053, 19.04487, 'Szigetszentmiklós városháza', '44032', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4957, 155, 47.576041, 18.938842, 'Kökörcsin utca', 'F00118', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4958, 155, 47.559712, 18.965132, 'Mikszáth Kálmán utca', 'F00405', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4959, 155, 47.511879, 19.20429, 'Hunyadvár utca', 'F03321', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4960, 155, 47.395613, 19.100089, 'Sólyom utca', 'F04263', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4961, 156, 47.464005, 19.018706, 'Kelenföld vasútállomás M', '61188', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4962, 156, 47.495614, 19.048322, 'Vigadó tér', 'F00967', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4963, 156, 47.459755, 19.111517, 'Epreserdő utca', 'F01367', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4964, 156, 47.486055, 19.124366, 'Kistorony park', 'F01725', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4965, 156, 47.483244, 19.022835, 'Zólyomi út', 'F02110', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4966, 156, 47.473322, 18.992542, 'Gazdagréti tér', 'F02227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4967, 156, 47.390471, 19.096418, 'Csepel Királyerdő út', 'F04246', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4968, 157, 47.510358, 19.190456, 'Jókai Mór utca (Rendőrség)', '9377', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4969, 157, 47.52406, 19.023054, 'Cimbalom utca', 'F00220', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4970, 157, 47.533628, 18.993368, 'Nagybányai út', 'F00273', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4971, 157, 47.562962, 18.962741, 'Községház utca', 'F00403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4972, 157, 47.557527, 19.100273, 'Ősz utca', 'F00892', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4973, 157, 47.500046, 19.068895, 'Wesselényi utca / Erzsébet körút', 'F01114', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4974, 157, 47.469814, 19.082997, 'Mester utca / Könyves Kálmán körút', 'F01506', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4975, 157, 47.480693, 19.280239, 'Csabagyöngye utca', 'F03487', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4976, 157, 47.426349, 19.03951, 'Városház tér', 'F04404', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4977, 157, 47.376927, 19.006997, 'Lakihegy Cseresznyés utca', 'F04653', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4978, 158, 47.407275, 18.930738, 'Nóra utca', '9098', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4979, 158, 47.462741, 19.005126, 'Gazdagréti út', '62519', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4980, 158, 47.494155, 19.028948, 'Róka utca', 'F00099', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4981, 158, 47.560372, 18.942016, 'Kerekhegyi utca', 'F00379', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4982, 158, 47.53274, 19.028522, 'Kőzet utca', 'F00610', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4983, 158, 47.559074, 19.099332, 'Munkásotthon utca', 'F00832', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4984, 158, 47.493635, 19.060134, 'Astoria M', 'F01014', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4985, 158, 47.494129, 19.141032, 'Terebesi utca', 'F01782', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4986, 158, 47.497948, 19.238905, 'Cinkotai autóbuszgarázs', 'F03286', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4987, 158, 47.494842, 19.240698, 'Vidor utca', 'F03450', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4988, 158, 47.491016, 19.242967, 'Gyöngytyúk utca', 'F03452', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4989, 158, 47.422549, 19.105231, 'Torontál utca H', 'F04051', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4990, 158, 47.412308, 19.097469, 'Hollandi csárda', 'F04342', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4991, 159, 47.499373, 19.024769, 'Déli pályaudvar', 'F00093', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4992, 159, 47.51684, 19.036573, 'Szent Lukács Gyógyfürdő', 'F00240', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4993, 159, 47.449015, 19.093684, 'Fegyvergyár utca', 'F01357', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4994, 159, 47.452218, 19.09187, 'Timót utca', 'F01427', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4995, 159, 47.45087, 19.088872, 'Timót utca / Soroksári út', 'F01430', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4996, 159, 47.48311, 19.026325, 'Muskotály köz', 'F02174', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4997, 159, 47.446632, 19.16809, 'Margó Tivadar utca', 'F03753', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4998, 159, 47.439865, 19.171761, 'Baross utca', 'F03763', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (4999, 159, 47.443848, 18.910918, 'Jókai Mór utca', 'F04812', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5000, 160, 47.429184, 18.929497, 'Márta utca', '7817', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5001, 160, 47.497359, 19.041594, 'Clark Ádám tér', '9676', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5002, 160, 47.459787, 19.299349, 'Schell Gyuláné tér', '19314', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5003, 160, 47.501324, 19.03371, 'Szentháromság tér', 'F00071', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5004, 160, 47.497509, 19.074043, 'Kiss József utca', 'F01163', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5005, 160, 47.447415, 19.090792, 'Soroksári út 158.', 'F01360', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5006, 160, 47.454674, 19.090479, 'Illatos út', 'F01421', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5007, 160, 47.47463, 19.178129, 'Új köztemető (Kozma utca)', 'F01867', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5008, 160, 47.44432, 19.169358, 'Havanna utca', 'F03758', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5009, 160, 47.436733, 19.173503, 'Kinizsi Pál utca', 'F03766', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5010, 160, 47.404697, 19.085434, 'Tihanyi utca', 'F04242', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5011, 160, 47.39456, 18.978544, 'Nagytétényi temető', 'F04492', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5012, 160, 47.446814, 18.909293, 'Vasút utca', 'F04747', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5013, 160, 47.459036, 18.958686, 'Baross utca', 'F04811', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5014, 161, 47.645554, 19.027001, 'Pomáz Dózsa György utca', '9171', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5015, 161, 47.457031, 19.089142, 'Kén utca', 'F01349', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5016, 161, 47.483391, 19.128037, 'Kőbánya alsó vasútállomás', 'F01635', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5017, 161, 47.492487, 19.012188, 'Németvölgyi út', 'F02290', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5018, 161, 47.50228, 19.005903, 'Pethényi út', 'F02396', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5019, 161, 47.488029, 19.340969, 'Kossuth tér', 'F04591', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5020, 161, 47.455266, 18.960727, 'Csata utca', 'F04727', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5021, 162, 47.482938, 19.273347, 'Szabadság sugárút / Péceli út', '66712', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5022, 162, 47.540316, 19.006138, 'Fenyőgyöngye', 'F00119', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5023, 162, 47.527451, 19.028444, 'Pusztaszeri út 25.', 'F00252', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5024, 162, 47.559002, 19.024898, 'Bojtár utca', 'F00521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5025, 162, 47.505836, 19.181299, 'Garat utca', 'F03344', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5026, 163, 47.543009, 18.964481, 'Hűvösvölgy', '7972', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5027, 163, 47.501283, 19.107691, 'Puskás Ferenc Stadion M', '8132', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5028, 163, 47.627055, 19.043879, 'Szentistvántelep H', '8433', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5029, 163, 47.433006, 19.092212, 'Teremszeg utca', '8895', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5030, 163, 47.467832, 19.107657, 'Közterületfenntartó Zrt.', 'F01484', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5031, 163, 47.46357, 19.109479, 'Csengettyű utca', 'F01525', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5032, 163, 47.482031, 19.029932, 'Alsóhegy utca', 'F02120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5033, 163, 47.504674, 19.136485, 'Örs vezér tere M+H', 'F02756', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5034, 163, 47.477458, 19.292508, 'Regélő utca', 'F03493', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5035, 163, 47.408007, 19.099744, 'Strandfürdő', 'F04219', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5036, 163, 47.421807, 19.041101, 'Tóth József utca', 'F04408', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5037, 163, 47.398889, 19.114518, 'Soroksár Hősök tere H', 'F04539', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5038, 164, 47.501739, 19.038966, 'Halász utca', 'F00056', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5039, 164, 47.56438, 19.022503, 'Óbudai temető', 'F00558', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5040, 164, 47.567585, 19.048854, 'Aquincum H', 'F00667', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5041, 164, 47.434785, 19.03642, 'Budafoki elágazás', 'F01904', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5042, 164, 47.478605, 19.031448, 'Diószegi út (Vérellátó Szolgálat)', 'F02122', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5043, 164, 47.494864, 18.964663, 'Sötétvágás utca', 'F02455', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5044, 164, 47.504404, 19.136551, 'Örs vezér tere M+H', 'F02755', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5045, 165, 47.501148, 19.136213, 'Örs vezér tere M+H', '8024', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5046, 165, 47.542265, 19.130118, 'Templom tér', '8341', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5047, 165, 47.352046, 19.045385, 'Wesselényi utca', '64319', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5048, 165, 47.540304, 19.030923, 'Szent Margit Kórház', 'F00595', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5049, 165, 47.497593, 19.053857, 'Deák Ferenc tér', 'F00961', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5050, 165, 47.468147, 19.110997, 'Ifjúmunkás utca', 'F01487', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5051, 165, 47.463902, 19.297339, 'Rózsaszál utca', 'F03586', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5052, 165, 47.474451, 19.293807, 'Császárfa utca', 'F03602', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5053, 165, 47.458524, 18.939284, 'Patkó utca', 'F04712', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5054, 166, 47.433933, 19.091311, 'Vízisport utca', '8894', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5055, 166, 47.408837, 19.113563, 'Templom utca 6.', '8959', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5056, 166, 47.42969, 19.032217, 'Plébánia utca', '9466', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5057, 166, 47.498879, 19.040174, 'Clark Ádám tér', '49784', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5058, 166, 47.490037, 19.036208, 'Sánc utca', 'F00006', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5059, 166, 47.540367, 19.030803, 'Szent Margit Kórház', 'F00597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5060, 166, 47.577657, 19.115153, 'Káposztásmegyer Szilas-patak', 'F00742', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5061, 166, 47.4934, 19.141283, 'Terebesi utca', 'F01780', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5062, 166, 47.481988, 19.045378, 'Kelenhegyi lépcső', 'F02135', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5063, 166, 47.505123, 19.136353, 'Örs vezér tere M+H', 'F02758', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5064, 166, 47.404607, 19.101131, 'Csepel csónakház', 'F04221', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5065, 166, 47.406579, 19.114395, 'Tárcsás utca', 'F04532', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5066, 167, 47.502623, 19.136654, 'Örs vezér tere M+H', '8383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5067, 167, 47.6218, 19.045662, 'Budakalász Lenfonó H', '8431', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5068, 167, 47.461046, 19.018907, 'Zelk Zoltán út (Menyecske utca)', '61227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5069, 167, 47.47329, 19.063539, 'Egyetemváros - A38 hajóállomás', '62620', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5070, 167, 47.551598, 18.947002, 'Ábránd utca', 'F00365', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5071, 167, 47.499416, 19.286626, 'Rákoscsaba-Újtelep Tóalmás utca', 'F03584', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5072, 167, 47.438383, 19.09683, 'János utca', 'F04125', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5073, 167, 47.403232, 19.115517, 'Erzsébet utca', 'F04568', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5074, 167, 47.482664, 19.342598, 'Szent Imre körút', 'F04582', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5075, 167, 47.450724, 18.904825, 'Törökbálint vasútállomás', 'F04743', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5076, 167, 47.445821, 18.905941, 'Köztársaság tér', 'F04765', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5077, 168, 47.542679, 19.029953, 'Váradi utca', '7882', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5078, 168, 47.503684, 19.136443, 'Örs vezér tere M+H', '8222', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5079, 168, 47.479738, 19.067812, 'Boráros tér H', '8546', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5080, 168, 47.455951, 18.948135, 'Sport utca', '8597', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5081, 168, 47.472183, 19.063857, 'Magyar tudósok körútja', '62622', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5082, 168, 47.562171, 18.947588, 'Máriaremetei kegytemplom', 'F00391', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5083, 168, 47.503142, 19.048522, 'Széchenyi utca', 'F01020', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5084, 168, 47.443529, 19.092884, 'Szabadkai út', 'F01432', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5085, 168, 47.461105, 19.31348, 'Rákoskert sugárút', 'F03504', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5086, 168, 47.429875, 19.037786, 'Savoyai Jenő tér', 'F04396', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5087, 168, 47.429065, 19.037574, 'Savoyai Jenő tér', 'F04402', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5088, 168, 47.400147, 19.116507, 'Soroksár Hősök tere H', 'F04542', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5089, 169, 47.501472, 19.136134, 'Örs vezér tere M+H', '8023', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5090, 169, 47.341631, 19.040874, 'Miklós Pláza', '64089', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5091, 169, 47.515365, 19.039282, 'Margit híd budai hídfő H', 'F00235', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5092, 169, 47.553079, 18.949186, 'Kerényi Frigyes utca', 'F00363', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5093, 169, 47.554853, 18.945907, 'Arany János utca', 'F00375', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5094, 169, 47.493105, 19.050566, 'Petőfi tér', 'F00924', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5095, 169, 47.496224, 19.070672, 'Blaha Lujza tér M', 'F01169', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5096, 169, 47.468273, 19.110944, 'Ifjúmunkás utca', 'F01488', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5097, 169, 47.431123, 18.980034, 'Szép utca', 'F02159', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5098, 169, 47.441855, 19.034588, 'Albertfalva vasútállomás', 'F02219', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5099, 169, 47.405087, 19.112539, 'Tárcsás utca', 'F04536', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5100, 169, 47.490362, 19.336244, 'Köztársaság tér', 'F04594', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5101, 170, 47.501849, 19.136467, 'Örs vezér tere M+H', '8165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5102, 170, 47.469303, 19.29515, 'Kucorgó tér', '8948', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5103, 170, 47.408474, 19.119154, 'Csillag utca', '8953', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5104, 170, 47.433933, 19.02108, 'Tatárka utca', '9548', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5105, 170, 47.476238, 19.100605, 'Népliget M', '86880', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5106, 170, 47.500387, 19.108151, 'Puskás Ferenc Stadion M', 'F01322', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5107, 170, 47.496768, 19.108787, 'Hős utca', 'F01327', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5108, 170, 47.502501, 19.153019, 'Halas utca', 'F01774', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5109, 170, 47.49387, 19.13859, 'Albertirsai köz', 'F01779', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5110, 170, 47.499236, 19.153835, 'EGIS Gyógyszergyár', 'F01785', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5111, 170, 47.443076, 18.978732, 'Kamaraerdei út 11.', 'F02070', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5112, 170, 47.496264, 19.010752, 'Nárcisz utca', 'F02426', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5113, 170, 47.437943, 19.094059, 'Pesterzsébet felső H', 'F04143', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5114, 170, 47.439655, 19.012794, 'Tordai út', 'F04371', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5115, 171, 47.40998, 19.111405, 'Soroksár felső H', '8481', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5116, 171, 47.396467, 19.208697, 'Kacsóh Pongrác utca', '9192', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5117, 171, 47.558772, 18.948591, 'Csatlós utca', 'F00389', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5118, 171, 47.550127, 19.041973, 'Óbuda Bogdáni út', 'F00575', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5119, 171, 47.543524, 19.029926, 'Váradi utca', 'F00590', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5120, 171, 47.499679, 19.049464, 'Zrínyi utca', 'F00986', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5121, 171, 47.500654, 19.10785, 'Puskás Ferenc Stadion M', 'F01319', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5122, 171, 47.499897, 19.137353, 'Fehér úti ipari park', 'F01862', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5123, 171, 47.513309, 19.14623, 'Csertő utca', 'F02777', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5124, 171, 47.433121, 19.036857, 'Leányka utcai lakótelep', 'F04383', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5125, 171, 47.426493, 19.038303, 'Városház tér', 'F04403', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5126, 172, 47.446051, 18.881264, 'Hosszúrét utca', '8332', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5127, 172, 47.426502, 19.262121, 'Repülőmúzeum', '88670', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5128, 172, 47.527722, 19.03665, 'Kolosy tér', 'F00132', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5129, 172, 47.459766, 19.017273, 'Menyecske utca', 'F01920', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5130, 172, 47.444535, 19.033646, 'Fonyód utca / Sáfrány utca', 'F01968', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5131, 172, 47.472074, 19.294818, 'Nagyszentmiklósi út', 'F03495', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5132, 172, 47.464039, 19.312513, 'Sáránd utca', 'F03503', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5133, 173, 47.50226, 19.039815, 'Halász utca', 'F00051', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5134, 173, 47.553105, 19.091448, 'Angyalföld kocsiszín', 'F00821', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5135, 173, 47.468064, 19.033839, 'Szent Imre Kórház', 'F02035', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5136, 173, 47.490181, 19.002158, 'Mártonhegyi út', 'F02282', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5137, 173, 47.492329, 19.023906, 'Tartsay Vilmos utca', 'F02310', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5138, 173, 47.490206, 19.024437, 'Csörsz utca', 'F02416', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5139, 173, 47.497142, 18.999957, 'Sólyom utca', 'F02436', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5140, 174, 47.51961, 19.038046, 'Császár-Komjádi uszoda', 'F00248', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5141, 174, 47.484992, 19.092865, 'Golgota tér', 'F01228', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5142, 174, 47.440728, 18.979173, 'Kamaraerdei Ifjúsági Park', 'F01887', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5143, 174, 47.473223, 18.992436, 'Gazdagréti tér', 'F02228', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5144, 174, 47.432222, 19.037215, 'Leányka utcai lakótelep', 'F04381', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5145, 175, 47.548037, 18.964619, 'Hunyadi János utca', 'F00359', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5146, 175, 47.552221, 19.027823, 'Laborc utca', 'F00669', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5147, 175, 47.486459, 19.092561, 'Golgota tér', 'F01227', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5148, 175, 47.481224, 19.093419, 'Elnök utca', 'F01236', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5149, 175, 47.495909, 19.070605, 'Blaha Lujza tér M', 'F01293', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5150, 175, 47.473416, 19.178127, 'Új köztemető', 'F01595', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5151, 175, 47.447251, 19.033354, 'Herend utca', 'F02211', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5152, 175, 47.430781, 19.070857, 'Csepel Szent Imre tér', 'F04330', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5153, 175, 47.491822, 19.342331, 'Ráday Gedeon tér', 'F04589', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5154, 176, 47.40492, 18.975841, 'Szakiskola utca', '8112', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5155, 176, 47.448009, 18.947805, 'Méhecske utca', '8846', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5156, 176, 47.490153, 19.30297, 'Ede utca', '66688', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5157, 176, 47.511308, 19.038221, 'Bem József tér', 'F00188', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5158, 176, 47.481187, 19.177509, 'Gránátos utca', 'F01600', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5159, 176, 47.479496, 19.177664, 'Izraelita temető', 'F01811', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5160, 176, 47.397626, 18.976646, 'Nagytétény-Diósd vasútállomás', 'F04490', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5161, 176, 47.410313, 19.111353, 'Soroksár felső H', 'F04530', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5162, 176, 47.436046, 18.915539, 'Baross Gábor utca', 'F04752', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5163, 177, 47.469242, 19.064333, 'Infopark (Pázmány Péter sétány)', '8280', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5164, 177, 47.647762, 19.069061, 'Papírgyár', '8437', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5165, 177, 47.505696, 19.039323, 'Batthyány tér M+H', '8750', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5166, 177, 47.510238, 19.034451, 'Horvát utca', 'F00194', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5167, 177, 47.544528, 18.963362, 'Bátori László utca', 'F00340', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5168, 177, 47.574952, 19.090429, 'Baross utca', 'F00771', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5169, 177, 47.467524, 19.178576, 'Álmos utca', 'F01551', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5170, 177, 47.43079, 19.070618, 'Csepel Szent Imre tér', 'F04328', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5171, 177, 47.448176, 18.905205, 'Kerekdomb utca', 'F04745', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5172, 178, 47.538309, 19.046968, 'Serfőző utca', '8521', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5173, 178, 47.503935, 18.921006, 'Budakeszi Tiefenweg utca', '44382', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5174, 178, 47.464094, 19.018481, 'Kelenföld vasútállomás M', '61274', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5175, 178, 47.52818, 19.027076, 'Alsó Zöldmáli út', 'F00254', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5176, 178, 47.489689, 19.108663, 'Hidegkuti Nándor Stadion', 'F01272', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5177, 178, 47.509212, 18.999592, 'Józsa Béla köz', 'F02347', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5178, 178, 47.497706, 18.963955, 'Csillebérc Gyermekvasút', 'F02453', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5179, 178, 47.494711, 19.286936, 'Gőzös utca', 'F03583', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5180, 178, 47.497059, 19.286801, 'Harsona utca', 'F03599', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5181, 179, 47.44726, 19.068739, 'Szikratávíró utca', '91120', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5182, 179, 47.488299, 19.024345, 'BAH-csomópont', 'F02165', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5183, 179, 47.486852, 19.024596, 'BAH-csomópont', 'F02167', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5184, 179, 47.444845, 19.071804, 'Szabadkikötő H', 'F04358', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5185, 180, 47.574343, 19.013009, 'Kocsis Sándor út', '7949', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5186, 180, 47.451711, 19.070971, 'Szabadkikötő D épületek', '115226', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5187, 180, 47.523954, 19.036824, 'Zsigmond tér', 'F00244', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5188, 180, 47.534333, 19.03973, 'Tímár utca', 'F00605', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5189, 180, 47.56717, 19.061532, 'Silvanus sétány', 'F00665', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5190, 180, 47.582099, 19.1169, 'Járműtelep utca', 'F00736', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5191, 180, 47.450156, 19.033194, 'Nyírbátor utca', 'F01966', NULL, 10);
+INSERT INTO stop (id, bearing, latitude, longitude, name, no, type, capacity) VALUES (5192, 180, 47.482896, 19.053776, 'Szent Gellért tér - Műegyetem M', 'F02142', NULL, 10);
+COMMIT;
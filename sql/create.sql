@@ -7,7 +7,8 @@ CREATE TABLE cab (
     location integer NOT NULL,
     name character varying(255),
     status integer NOT NULL,
-    seats integer NOT NULL
+    seats integer NOT NULL,
+    features integer DEFAULT 0 NOT NULL -- bits: 1 wheelchair, 2 luggage, 4 child seat
 );
 ALTER TABLE cab OWNER TO kabina;
 ALTER TABLE ONLY cab ADD CONSTRAINT cab_pkey PRIMARY KEY (id);
@@ -45,7 +46,8 @@ DROP TABLE route CASCADE;
 CREATE TABLE route (
     id bigint NOT NULL,
     status integer NOT NULL,
-    cab_id bigint NOT NULL
+    cab_id bigint NOT NULL,
+    locked boolean -- the route should not be extended, cab assigned to a new route
 );
 ALTER TABLE route OWNER TO kabina;
 ALTER TABLE ONLY route ADD CONSTRAINT route_pkey PRIMARY KEY (id);
@@ -89,6 +91,51 @@ INSERT INTO stat (name, int_val) VALUES
     ('TotalLcmUsed', 0),
     ('TotalPickupDistance', 0);
 
+-- ID BLOCK
+-- next free ids of routes and legs; kern reserves blocks of them in its transactions, other writers of these tables have to do the same
+DROP TABLE id_block CASCADE;
+CREATE TABLE id_block (
+    name character varying(32) NOT NULL,
+    next_id bigint NOT NULL
+);
+ALTER TABLE id_block OWNER TO kabina;
+ALTER TABLE ONLY id_block ADD CONSTRAINT id_block_pkey PRIMARY KEY (name);
+INSERT INTO id_block (name, next_id) SELECT 'route', COALESCE(MAX(id), -1) + 1 FROM route;
+INSERT INTO id_block (name, next_id) SELECT 'leg', COALESCE(MAX(id), -1) + 1 FROM leg;
+INSERT INTO id_block (name, next_id) VALUES ('event', 1);
+
+-- OUTBOX
+-- decisions of the dispatcher for Kapir, Kab and Kavla, read in the order of 'seq'; -1 means 'not applicable'
+DROP TABLE outbox CASCADE;
+CREATE TABLE outbox (
+    seq bigint NOT NULL,
+    kind character varying(32) NOT NULL, -- ORDER_ASSIGNED, ROUTE_CREATED, LEG_INSERTED, ORDER_REFUSED, CAB_RELOCATED, ORDER_ABANDONED, LEG_REMOVED,
+                                         -- ROUTE_ABANDONED, ORDER_RELEASED
+    order_id bigint NOT NULL,
+    route_id bigint NOT NULL,
+    leg_id bigint NOT NULL,
+    cab_id bigint NOT NULL,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    place integer NOT NULL,
+    eta integer NOT NULL, -- minutes
+    created timestamp without time zone DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (seq)
+);
+ALTER TABLE outbox OWNER TO kabina;
+
+-- SCHEMA VERSION
+-- the last migration in sql/migrations this script is equal to, kern refuses to start with another one
+DROP TABLE schema_version CASCADE;
+CREATE TABLE schema_version (
+    version INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    applied TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (version)
+);
+INSERT INTO schema_version (version, name) VALUES (1, 'baseline'), (2, 'dwell'), (3, 'id_block'), (4, 'outbox'), (5, 'passengers'), (6, 'features'), (7, 'transfers'), (8, 'breakdown');
+ALTER TABLE schema_version OWNER TO kabina;
+
 -- STOP
 DROP TABLE stop CASCADE;
 CREATE TABLE stop (
@@ -98,7 +145,9 @@ CREATE TABLE stop (
     longitude double precision NOT NULL,
     name character varying(255),
     no character varying(255),
-    type character varying(255)
+    type character varying(255),
+    capacity integer NOT NULL DEFAULT 10, -- cabs that can stand at the stop
+    dwell integer -- secs at the stop, 'stop_wait' from kern.toml if NULL
 );
 ALTER TABLE stop OWNER TO kabina;
 ALTER TABLE ONLY stop ADD CONSTRAINT stop_pkey PRIMARY KEY (id);
@@ -118,13 +167,18 @@ CREATE TABLE taxi_order (
     max_wait integer NOT NULL,
     received timestamp without time zone,
     shared boolean NOT NULL,
+    passengers integer DEFAULT 1 NOT NULL, -- seats taken, a group booking takes more
+    required integer DEFAULT 0 NOT NULL, -- features the cab must have, bits as in cab.features
     started timestamp without time zone,
     status integer,
     to_stand integer NOT NULL,
     cab_id bigint,
     customer_id bigint,
     leg_id bigint,
-    route_id bigint
+    route_id bigint,
+    prev_id bigint, -- ride transfer: the segment before the cab change
+    next_id bigint, -- the segment after it
+    released timestamp without time zone -- put back into demand when its cab went out of service
 );
 ALTER TABLE taxi_order OWNER TO kabina;
 ALTER TABLE ONLY taxi_order ADD CONSTRAINT taxi_order_pkey PRIMARY KEY (id);
@@ -144,4 +198,20 @@ ALTER TABLE ONLY taxi_order
 ALTER TABLE ONLY taxi_order
     ADD CONSTRAINT fk_taxi_order_leg_id FOREIGN KEY (leg_id) REFERENCES leg(id);
 ALTER TABLE ONLY taxi_order
-    ADD CONSTRAINT fk_taxi_order_route_id FOREIGN KEY (route_id) REFERENCES route(id);
\ No newline at end of file
+    ADD CONSTRAINT fk_taxi_order_route_id FOREIGN KEY (route_id) REFERENCES route(id);
+
+-- ORDER FROM FREE CAB
+DROP TABLE freetaxi_order CASCADE;
+CREATE TABLE freetaxi_order (
+    id bigint NOT NULL GENERATED ALWAYS AS IDENTITY,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    max_loss integer NOT NULL,
+    received timestamp without time zone,
+    shared boolean NOT NULL,
+    passengers integer DEFAULT 1 NOT NULL, -- seats taken, a group booking takes more
+    cab_id bigint,
+    customer_id bigint
+);
+ALTER TABLE freetaxi_order OWNER TO kabina;
+ALTER TABLE ONLY freetaxi_order ADD CONSTRAINT freetaxi_order_pkey PRIMARY KEY (id);
@@ -0,0 +1,118 @@
+-- schema of kern before versioned migrations, tables already created by sql/create*.sql scripts are left intact
+CREATE TABLE IF NOT EXISTS cab (
+    id bigint NOT NULL,
+    location integer NOT NULL,
+    name character varying(255),
+    status integer NOT NULL,
+    seats integer NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS customer (id bigint NOT NULL, PRIMARY KEY (id));
+
+CREATE TABLE IF NOT EXISTS route (
+    id bigint NOT NULL,
+    status integer NOT NULL,
+    cab_id bigint NOT NULL,
+    locked boolean, -- the route should not be extended, cab assigned to a new route
+    PRIMARY KEY (id),
+    FOREIGN KEY (cab_id) REFERENCES cab(id)
+);
+
+CREATE TABLE IF NOT EXISTS leg (
+    id bigint NOT NULL,
+    completed timestamp without time zone,
+    distance integer NOT NULL,
+    from_stand integer NOT NULL,
+    place integer NOT NULL,
+    started timestamp without time zone,
+    status integer NOT NULL,
+    reserve integer NOT NULL,
+    passengers integer NOT NULL,
+    to_stand integer NOT NULL,
+    route_id bigint NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (route_id) REFERENCES route(id)
+);
+
+CREATE TABLE IF NOT EXISTS taxi_order (
+    id bigint NOT NULL GENERATED ALWAYS AS IDENTITY,
+    at_time timestamp without time zone,
+    completed timestamp without time zone,
+    distance integer NOT NULL,
+    eta integer,
+    from_stand integer NOT NULL,
+    in_pool boolean,
+    max_loss integer NOT NULL,
+    max_wait integer NOT NULL,
+    received timestamp without time zone,
+    shared boolean NOT NULL,
+    started timestamp without time zone,
+    status integer,
+    to_stand integer NOT NULL,
+    cab_id bigint,
+    customer_id bigint,
+    leg_id bigint,
+    route_id bigint, PRIMARY KEY (id),
+    FOREIGN KEY (cab_id) REFERENCES cab(id),
+    FOREIGN KEY (customer_id) REFERENCES customer(id),
+    FOREIGN KEY (leg_id) REFERENCES leg(id),
+    FOREIGN KEY (route_id) REFERENCES route(id)
+);
+
+CREATE TABLE IF NOT EXISTS freetaxi_order (
+    id bigint NOT NULL GENERATED ALWAYS AS IDENTITY,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    max_loss integer NOT NULL,
+    received timestamp without time zone,
+    shared boolean NOT NULL,
+    cab_id bigint,
+    customer_id bigint, PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS stop (
+    id bigint NOT NULL,
+    bearing integer,
+    latitude double precision NOT NULL,
+    longitude double precision NOT NULL,
+    name character varying(255),
+    no character varying(255),
+    type character varying(255),
+    capacity integer NOT NULL DEFAULT 10, -- cabs that can stand at the stop
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE IF NOT EXISTS stat (
+    name character varying(255) NOT NULL,
+    int_val integer NOT NULL,
+    PRIMARY KEY (name)
+);
+INSERT INTO stat (name, int_val) VALUES
+    ('AvgExtenderTime', 0),
+    ('AvgPoolTime', 0),
+    ('AvgPool3Time', 0),
+    ('AvgPool4Time', 0),
+    ('AvgPool5Time', 0),
+    ('AvgLcmTime', 0),
+    ('AvgSolverTime', 0),
+    ('AvgShedulerTime', 0),
+    ('MaxExtenderTime', 0),
+    ('MaxPoolTime', 0),
+    ('MaxPool3Time', 0),
+    ('MaxPool4Time', 0),
+    ('MaxPool5Time', 0),
+    ('MaxLcmTime', 0),
+    ('MaxSolverTime', 0),
+    ('MaxShedulerTime', 0),
+    ('AvgDemandSize', 0),
+    ('AvgPoolDemandSize', 0),
+    ('AvgSolverDemandSize', 0),
+    ('MaxDemandSize', 0),
+    ('MaxPoolDemandSize', 0),
+    ('MaxSolverDemandSize', 0),
+    ('AvgOrderAssignTime', 0),
+    ('AvgOrderPickupTime', 0),
+    ('AvgOrderCompleteTime', 0),
+    ('TotalLcmUsed', 0),
+    ('TotalPickupDistance', 0) ON CONFLICT DO NOTHING;
@@ -0,0 +1,8 @@
+-- per-stop dwell time -- secs at the stop, 'stop_wait' from kern.toml if NULL
+-- MySQL commits DDL at once, columns are added only if missing so that a failed migration can be run again
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'stop' AND column_name = 'dwell') = 0,
+              'ALTER TABLE stop ADD COLUMN dwell integer', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
@@ -0,0 +1,2 @@
+-- per-stop dwell time -- secs at the stop, 'stop_wait' from kern.toml if NULL
+ALTER TABLE stop ADD COLUMN dwell integer;
@@ -0,0 +1,8 @@
+-- next free ids of routes and legs; kern reserves blocks of them in its transactions, other writers of these tables have to do the same
+CREATE TABLE IF NOT EXISTS id_block (
+    name character varying(32) NOT NULL,
+    next_id bigint NOT NULL,
+    PRIMARY KEY (name)
+);
+INSERT OR IGNORE INTO id_block (name, next_id) SELECT 'route', COALESCE(MAX(id), -1) + 1 FROM route;
+INSERT OR IGNORE INTO id_block (name, next_id) SELECT 'leg', COALESCE(MAX(id), -1) + 1 FROM leg;
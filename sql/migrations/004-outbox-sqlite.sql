@@ -0,0 +1,17 @@
+-- decisions of the dispatcher for Kapir, Kab and Kavla, read in the order of 'seq'; -1 means 'not applicable'
+CREATE TABLE IF NOT EXISTS outbox (
+    seq bigint NOT NULL,
+    kind character varying(32) NOT NULL, -- ORDER_ASSIGNED, ROUTE_CREATED, LEG_INSERTED, ORDER_REFUSED, CAB_RELOCATED
+    order_id bigint NOT NULL,
+    route_id bigint NOT NULL,
+    leg_id bigint NOT NULL,
+    cab_id bigint NOT NULL,
+    from_stand integer NOT NULL,
+    to_stand integer NOT NULL,
+    place integer NOT NULL,
+    eta integer NOT NULL, -- minutes
+    created timestamp DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (seq)
+);
+-- 'seq' is reserved like ids of routes and legs, writers of events wait for each other
+INSERT OR IGNORE INTO id_block (name, next_id) VALUES ('event', 1);
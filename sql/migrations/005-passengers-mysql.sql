@@ -0,0 +1,14 @@
+-- group bookings, seats taken by one order; existing orders take one
+-- MySQL commits DDL at once, columns are added only if missing so that a failed migration can be run again
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'taxi_order' AND column_name = 'passengers') = 0,
+              'ALTER TABLE taxi_order ADD COLUMN passengers integer DEFAULT 1 NOT NULL', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'freetaxi_order' AND column_name = 'passengers') = 0,
+              'ALTER TABLE freetaxi_order ADD COLUMN passengers integer DEFAULT 1 NOT NULL', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
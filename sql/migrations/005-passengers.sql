@@ -0,0 +1,3 @@
+-- group bookings, seats taken by one order; existing orders take one
+ALTER TABLE taxi_order ADD COLUMN IF NOT EXISTS passengers integer DEFAULT 1 NOT NULL;
+ALTER TABLE freetaxi_order ADD COLUMN IF NOT EXISTS passengers integer DEFAULT 1 NOT NULL;
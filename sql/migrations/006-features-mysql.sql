@@ -0,0 +1,14 @@
+-- what a cab offers and what an order needs, bits: 1 wheelchair, 2 luggage, 4 child seat
+-- MySQL commits DDL at once, columns are added only if missing so that a failed migration can be run again
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'cab' AND column_name = 'features') = 0,
+              'ALTER TABLE cab ADD COLUMN features integer DEFAULT 0 NOT NULL', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'taxi_order' AND column_name = 'required') = 0,
+              'ALTER TABLE taxi_order ADD COLUMN required integer DEFAULT 0 NOT NULL', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
@@ -0,0 +1,3 @@
+-- what a cab offers and what an order needs, bits: 1 wheelchair, 2 luggage, 4 child seat
+ALTER TABLE cab ADD COLUMN features integer DEFAULT 0 NOT NULL;
+ALTER TABLE taxi_order ADD COLUMN required integer DEFAULT 0 NOT NULL;
@@ -0,0 +1,14 @@
+-- ride transfers: an order split at a transfer stop, the second segment points at the first one and vice versa
+-- MySQL commits DDL at once, columns are added only if missing so that a failed migration can be run again
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'taxi_order' AND column_name = 'prev_id') = 0,
+              'ALTER TABLE taxi_order ADD COLUMN prev_id bigint', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'taxi_order' AND column_name = 'next_id') = 0,
+              'ALTER TABLE taxi_order ADD COLUMN next_id bigint', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
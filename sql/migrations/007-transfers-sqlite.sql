@@ -0,0 +1,3 @@
+-- ride transfers: an order split at a transfer stop, the second segment points at the first one and vice versa
+ALTER TABLE taxi_order ADD COLUMN prev_id bigint;
+ALTER TABLE taxi_order ADD COLUMN next_id bigint;
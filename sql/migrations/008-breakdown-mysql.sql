@@ -0,0 +1,8 @@
+-- cab breakdown: an order put back into demand when its cab went out of service
+-- MySQL commits DDL at once, columns are added only if missing so that a failed migration can be run again
+SET @ddl = IF((SELECT COUNT(*) FROM information_schema.columns WHERE table_schema = DATABASE()
+                 AND table_name = 'taxi_order' AND column_name = 'released') = 0,
+              'ALTER TABLE taxi_order ADD COLUMN released timestamp NULL', 'DO 0');
+PREPARE ddl FROM @ddl;
+EXECUTE ddl;
+DEALLOCATE PREPARE ddl;
@@ -0,0 +1,2 @@
+-- cab breakdown: an order put back into demand when its cab went out of service
+ALTER TABLE taxi_order ADD COLUMN released timestamp;
@@ -0,0 +1,86 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Cab breakdown - a cab taken OUT_OF_SERVICE (by the driver or Kapir) gives up its routes. Legs not started yet
+/// are abandoned and customers waiting for the cab go back to demand with their 'received', in the same run they are
+/// dispatched first by the extender, pool finder and solver. Customers already in the cab stay with it, someone
+/// has to take care of them
+use chrono::{Local, NaiveDateTime};
+use log::info;
+use crate::command::Command;
+use crate::distance::DistanceProvider;
+use crate::model::{Order, OrderStatus};
+use crate::repo::{Repository, abandon_route, release_order, run_sql};
+
+// returns the number of orders put back into demand
+pub fn release_broken_routes(conn: &mut dyn Repository, dp: &dyn DistanceProvider) -> usize {
+    let routes = conn.find_broken_routes();
+    if routes.len() == 0 {
+        return 0;
+    }
+    let now = Some(Local::now().naive_local());
+    let mut sql: Vec<Command> = vec![];
+    let mut count = 0;
+    for (route_id, cab_id) in routes.iter() {
+        let waiting = conn.find_route_orders(*route_id, &[OrderStatus::ASSIGNED, OrderStatus::ACCEPTED], dp);
+        sql.extend(release_route(*route_id, *cab_id, &waiting, now));
+        count += waiting.len();
+    }
+    info!("Cab breakdown: {} routes abandoned, {} orders to be dispatched again", routes.len(), count);
+    // a customer picked up in the meantime keeps the route until the next run
+    run_sql(conn, sql, "Breakdown");
+    return count;
+}
+
+pub fn release_route(route_id: i64, cab_id: i64, waiting: &Vec<Order>, now: Option<NaiveDateTime>) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
+    for o in waiting.iter() {
+        sql.extend(release_order(o.id, route_id, now));
+    }
+    sql.extend(abandon_route(route_id, cab_id));
+    return sql;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::Duration;
+  use crate::distance::MemoryMatrix;
+  use crate::event::Event;
+  use crate::mem_repo::{LegRow, MemRepo, RouteRow};
+  use crate::model::CabStatus;
+
+  #[test]
+  fn test_release_broken_routes() {
+    let dp = MemoryMatrix::new(4);
+    let mut repo = MemRepo::new();
+    repo.add_cab(1, 0, 4);
+    repo.routes.push(RouteRow { id: 7, status: 5, cab_id: 1, locked: false });
+    for (id, from, to, status) in [(10, 0, 1, 6), (11, 1, 2, 5), (12, 2, 3, 1)] {
+      repo.legs.push(LegRow { id, route_id: 7, from, to, place: id as i32 - 10, dist: 1, status, reserve: 5, passengers: 1,
+                              started: None, completed: None });
+    }
+    let received = Some((Local::now() - Duration::minutes(10)).naive_local());
+    let in_cab = repo.add_order(0, 2, 10, 50, received);
+    let waiting = repo.add_order(2, 3, 10, 50, received);
+    for (id, leg_id, status) in [(in_cab, 10, OrderStatus::PICKEDUP), (waiting, 12, OrderStatus::ACCEPTED)] {
+      let o = repo.orders.iter_mut().find(|o| o.id == id).unwrap();
+      (o.route_id, o.leg_id, o.cab_id, o.status) = (7, leg_id, 1, status as i32);
+    }
+    assert_eq!(release_broken_routes(&mut repo, &dp), 0); // the cab is fine
+
+    repo.cabs[0].status = CabStatus::OUT_OF_SERVICE as i32;
+    assert_eq!(release_broken_routes(&mut repo, &dp), 1);
+    let o = repo.order(waiting).unwrap();
+    assert_eq!((o.status, o.route_id, o.leg_id, o.cab_id), (OrderStatus::RECEIVED as i32, -1, -1, -1));
+    assert_eq!(o.received, received);
+    assert!(o.released.is_some());
+    assert_eq!(repo.order(in_cab).unwrap().status, OrderStatus::PICKEDUP as i32);
+    let legs: Vec<i32> = repo.route_legs(7).iter().map(|l| l.status).collect();
+    assert_eq!(legs, vec![6, 5, 4]); // the leg being driven stays
+    assert!(repo.routes[0].locked && repo.routes[0].status == 4);
+    assert!(repo.events.iter().any(|(_, e)| *e == Event::OrderReleased { order_id: waiting, route_id: 7 }));
+    assert!(repo.events.iter().any(|(_, e)| *e == Event::RouteAbandoned { route_id: 7, cab_id: 1 }));
+    assert_eq!(release_broken_routes(&mut repo, &dp), 0); // done
+  }
+}
@@ -0,0 +1,454 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Writes of the dispatcher as typed commands, executed by a repository as prepared statements
+/// Times are already converted to what the database keeps - minutes
+use std::fmt;
+use std::collections::HashMap;
+use chrono::NaiveDateTime;
+use crate::event::Event;
+
+// ids of routes and legs created by a plan count up from here, far below -1 which means 'none';
+// the repository replaces them with ids reserved when the plan is saved
+pub const TEMP_ID_BASE: i64 = i64::MIN / 2;
+
+pub fn is_temp_id(id: i64) -> bool {
+    return id < -1;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Param {
+    Int(i32), // 'integer' columns
+    BigInt(i64), // ids
+    Bool(bool),
+    Text(String),
+    Time(Option<NaiveDateTime>)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    // cab_id taken from the route
+    AssignOrderToRoute { order_id: i64, leg_id: Option<i64>, route_id: i64, eta: i32, in_pool: bool },
+    AssignOrder { order_id: i64, cab_id: i64, leg_id: Option<i64>, route_id: i64, eta: i32, in_pool: bool },
+    ExpireOrder { order_id: i64 },
+    InsertOrder { from: i32, to: i32, loss: i32, shared: bool, passengers: i32, received: Option<NaiveDateTime>, dist: i32,
+                  cust_id: i64, cab_id: i64, leg_id: i64, route_id: i64 },
+    DeleteFreeOrder { id: i64 },
+    // ride transfer: the second segment, a copy of the order from the transfer stop on, picked up not before 'at_time'
+    InsertSegment { order_id: i64, from: i32, at_time: Option<NaiveDateTime>, dist: i32 },
+    // the first segment ends at the transfer stop and points at the second one, inserted just before
+    SplitOrder { order_id: i64, to: i32, dist: i32 },
+    CreateLeg { id: i64, from: i32, to: i32, place: i32, dist: i32, status: i32, reserve: i32, route_id: i64, passengers: i32 },
+    UpdateLeg { id: i64, route_id: i64, to: i32, dist: i32, reserve: i32, passengers: i32 },
+    ShiftPlaces { route_id: i64, place_from: i32 }, // make room for a new leg
+    // route repair: a customer who abandoned the route or did not show up; route_id is not written, it ties the command
+    // to the rest of the repair, see routes_of
+    DetachOrder { order_id: i64, route_id: i64 },
+    DeleteLeg { id: i64, route_id: i64 }, // nobody gets in or out at its start any more, the leg before goes further
+    ClosePlaces { route_id: i64, place_from: i32 }, // after a deleted leg
+    // cab breakdown: a waiting customer back in demand, 'released' counts for expiry instead of 'received'
+    ReleaseOrder { order_id: i64, route_id: i64, released: Option<NaiveDateTime> },
+    AbandonLegs { route_id: i64 }, // not started ones
+    AbandonRoute { route_id: i64 },
+    AddPassengers { route_id: i64, passengers: i32, reserve: i32, place_from: i32, place_to: i32 },
+    ReduceReserveAfter { route_id: i64, cost: i32, place_from: i32 },
+    ReduceReserveBefore { route_id: i64, cost: i32, place_to: i32 },
+    CapReserveBefore { route_id: i64, reserve: i32, place_to: i32 },
+    AssignCab { cab_id: i64 },
+    LockRoutes { cab_id: i64 }, // active routes of the cab cannot be extended
+    CreateRoute { id: i64, cab_id: i64 },
+    UpdateStat { name: String, value: i32 },
+    Emit { seq: i64, event: Event } // 'seq' is given when the plan is saved
+}
+
+impl Command {
+    // '?' marks parameters
+    pub fn sql(&self) -> &'static str {
+        return match self {
+            Command::AssignOrderToRoute { leg_id: None, .. } =>
+                "UPDATE taxi_order SET route_id=?, cab_id=(SELECT cab_id FROM route where id=?), status=1, eta=?, in_pool=? \
+                 WHERE id=? AND status=0", // it might be cancelled in the meantime, we have to be sure.
+            Command::AssignOrderToRoute { .. } =>
+                "UPDATE taxi_order SET route_id=?, leg_id=?, cab_id=(SELECT cab_id FROM route where id=?), status=1, eta=?, in_pool=? \
+                 WHERE id=? AND status=0",
+            Command::AssignOrder { leg_id: None, .. } =>
+                "UPDATE taxi_order SET route_id=?, cab_id=?, status=1, eta=?, in_pool=? WHERE id=? AND status=0",
+            Command::AssignOrder { .. } =>
+                "UPDATE taxi_order SET route_id=?, leg_id=?, cab_id=?, status=1, eta=?, in_pool=? WHERE id=? AND status=0",
+            Command::ExpireOrder { .. } => "UPDATE taxi_order SET status=6 WHERE (id=? OR prev_id=?) AND status=0", // with the second segment of a transfer
+            Command::InsertOrder { .. } =>
+                "INSERT INTO taxi_order (from_stand, to_stand, max_loss, max_wait, shared, passengers, in_pool, eta, \
+                 status, received, distance, customer_id, cab_id, leg_id, route_id) VALUES (?,?,?,-1,?,?,false,0,1,?,?,?,?,?,?)",
+            Command::DeleteFreeOrder { .. } => "DELETE FROM freetaxi_order WHERE id=?",
+            Command::InsertSegment { .. } =>
+                "INSERT INTO taxi_order (from_stand, to_stand, max_loss, max_wait, shared, passengers, required, in_pool, eta, \
+                 status, received, at_time, distance, customer_id, prev_id) SELECT ?, to_stand, max_loss, max_wait, shared, \
+                 passengers, required, false, 0, 0, received, ?, ?, customer_id, id FROM taxi_order \
+                 WHERE id=? AND status=0 AND next_id IS NULL",
+            Command::SplitOrder { .. } => // MySQL does not allow the updated table in a subquery, unless it is materialized
+                "UPDATE taxi_order SET to_stand=?, distance=?, \
+                 next_id=(SELECT id FROM (SELECT MAX(id) AS id FROM taxi_order WHERE prev_id=?) segment) \
+                 WHERE id=? AND status=0 AND next_id IS NULL",
+            Command::CreateLeg { .. } =>
+                "INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES \
+                 (?,?,?,?,?,?,?,?,?)",
+            Command::UpdateLeg { .. } => // not completed in the meantime
+                "UPDATE leg SET to_stand=?, distance=?, reserve=?, passengers=? WHERE id=? AND route_id=? AND status IN (1,5)",
+            Command::ShiftPlaces { .. } => "UPDATE leg SET place=place+1 WHERE route_id=? AND place >= ?",
+            Command::DetachOrder { .. } => // 5=ABANDONED, not picked up in the meantime
+                "UPDATE taxi_order SET status=5, leg_id=NULL WHERE id=? AND status IN (1,2,5) AND leg_id IS NOT NULL",
+            Command::DeleteLeg { .. } => "DELETE FROM leg WHERE id=? AND route_id=? AND status=1", // not started in the meantime
+            Command::ClosePlaces { .. } => "UPDATE leg SET place=place-1 WHERE route_id=? AND place >= ?",
+            Command::ReleaseOrder { .. } => // not picked up in the meantime
+                "UPDATE taxi_order SET status=0, route_id=NULL, leg_id=NULL, cab_id=NULL, eta=0, in_pool=false, released=? \
+                 WHERE id=? AND status IN (1,2)",
+            Command::AbandonLegs { .. } => "UPDATE leg SET status=4 WHERE route_id=? AND status=1", // 4=ABANDONED
+            Command::AbandonRoute { .. } => "UPDATE route SET status=4, locked=true WHERE id=? AND status IN (1,5)",
+            Command::AddPassengers { .. } =>
+                "UPDATE leg SET passengers=passengers+?, reserve=LEAST(reserve, ?) WHERE route_id=? AND place BETWEEN ? AND ?",
+            Command::ReduceReserveAfter { .. } => "UPDATE leg SET reserve=GREATEST(0, reserve-?) WHERE route_id=? AND place >= ?",
+            Command::ReduceReserveBefore { .. } => "UPDATE leg SET reserve=GREATEST(0, reserve-?) WHERE route_id=? AND place <= ?",
+            Command::CapReserveBefore { .. } => "UPDATE leg SET reserve=LEAST(reserve, ?) WHERE route_id=? AND place <= ?",
+            Command::AssignCab { .. } => "UPDATE cab SET status=0 WHERE id=? AND status IN (0,1)", // not taken out of service
+            Command::LockRoutes { .. } => "UPDATE route SET locked = true WHERE status IN (1,5) AND cab_id=?",
+            Command::CreateRoute { .. } => "INSERT INTO route (id, status, cab_id, locked) VALUES (?,1,?,false)", // 1=ASSIGNED
+            Command::UpdateStat { .. } => "UPDATE stat SET int_val=? WHERE UPPER(name)=UPPER(?)",
+            Command::Emit { .. } =>
+                "INSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES \
+                 (?,?,?,?,?,?,?,?,?,?)",
+        };
+    }
+
+    // in the order of '?' in 'sql'
+    pub fn params(&self) -> Vec<Param> {
+        use Param::*;
+        return match self.clone() {
+            Command::AssignOrderToRoute { order_id, leg_id, route_id, eta, in_pool } => {
+                let mut ret = vec![BigInt(route_id)];
+                if let Some(l) = leg_id { ret.push(BigInt(l)); }
+                ret.extend([BigInt(route_id), Int(eta), Bool(in_pool), BigInt(order_id)]);
+                ret
+            },
+            Command::AssignOrder { order_id, cab_id, leg_id, route_id, eta, in_pool } => {
+                let mut ret = vec![BigInt(route_id)];
+                if let Some(l) = leg_id { ret.push(BigInt(l)); }
+                ret.extend([BigInt(cab_id), Int(eta), Bool(in_pool), BigInt(order_id)]);
+                ret
+            },
+            Command::ExpireOrder { order_id } => vec![BigInt(order_id), BigInt(order_id)],
+            Command::InsertOrder { from, to, loss, shared, passengers, received, dist, cust_id, cab_id, leg_id, route_id } =>
+                vec![Int(from), Int(to), Int(loss), Bool(shared), Int(passengers), Time(received), Int(dist), BigInt(cust_id),
+                     BigInt(cab_id), BigInt(leg_id), BigInt(route_id)],
+            Command::DeleteFreeOrder { id } => vec![BigInt(id)],
+            Command::InsertSegment { order_id, from, at_time, dist } => vec![Int(from), Time(at_time), Int(dist), BigInt(order_id)],
+            Command::SplitOrder { order_id, to, dist } => vec![Int(to), Int(dist), BigInt(order_id), BigInt(order_id)],
+            Command::CreateLeg { id, from, to, place, dist, status, reserve, route_id, passengers } =>
+                vec![BigInt(id), Int(from), Int(to), Int(place), Int(dist), Int(status), Int(reserve), BigInt(route_id), Int(passengers)],
+            Command::UpdateLeg { id, route_id, to, dist, reserve, passengers } =>
+                vec![Int(to), Int(dist), Int(reserve), Int(passengers), BigInt(id), BigInt(route_id)],
+            Command::ShiftPlaces { route_id, place_from } => vec![BigInt(route_id), Int(place_from)],
+            Command::DetachOrder { order_id, .. } => vec![BigInt(order_id)],
+            Command::DeleteLeg { id, route_id } => vec![BigInt(id), BigInt(route_id)],
+            Command::ClosePlaces { route_id, place_from } => vec![BigInt(route_id), Int(place_from)],
+            Command::ReleaseOrder { order_id, released, .. } => vec![Time(released), BigInt(order_id)],
+            Command::AbandonLegs { route_id } | Command::AbandonRoute { route_id } => vec![BigInt(route_id)],
+            Command::AddPassengers { route_id, passengers, reserve, place_from, place_to } =>
+                vec![Int(passengers), Int(reserve), BigInt(route_id), Int(place_from), Int(place_to)],
+            Command::ReduceReserveAfter { route_id, cost, place_from } => vec![Int(cost), BigInt(route_id), Int(place_from)],
+            Command::ReduceReserveBefore { route_id, cost, place_to } => vec![Int(cost), BigInt(route_id), Int(place_to)],
+            Command::CapReserveBefore { route_id, reserve, place_to } => vec![Int(reserve), BigInt(route_id), Int(place_to)],
+            Command::AssignCab { cab_id } => vec![BigInt(cab_id)],
+            Command::LockRoutes { cab_id } => vec![BigInt(cab_id)],
+            Command::CreateRoute { id, cab_id } => vec![BigInt(id), BigInt(cab_id)],
+            Command::UpdateStat { name, value } => vec![Int(value), Text(name)],
+            Command::Emit { seq, event } => {
+                let mut ret = vec![BigInt(seq)];
+                ret.extend(event.params());
+                ret
+            },
+        };
+    }
+
+    // rows touched, for error reports
+    pub fn ids(&self) -> String {
+        return match self {
+            Command::AssignOrderToRoute { order_id, leg_id, route_id, .. } =>
+                format!("order_id={}, route_id={}, leg_id={:?}", order_id, route_id, leg_id),
+            Command::AssignOrder { order_id, cab_id, leg_id, route_id, .. } =>
+                format!("order_id={}, cab_id={}, route_id={}, leg_id={:?}", order_id, cab_id, route_id, leg_id),
+            Command::ExpireOrder { order_id } => format!("order_id={}", order_id),
+            Command::InsertOrder { cust_id, cab_id, route_id, .. } =>
+                format!("customer_id={}, cab_id={}, route_id={}", cust_id, cab_id, route_id),
+            Command::DeleteFreeOrder { id } => format!("freetaxi_order_id={}", id),
+            Command::InsertSegment { order_id, .. } | Command::SplitOrder { order_id, .. } => format!("order_id={}", order_id),
+            Command::CreateLeg { id, route_id, .. } => format!("leg_id={}, route_id={}", id, route_id),
+            Command::UpdateLeg { id, route_id, .. } | Command::DeleteLeg { id, route_id } => format!("leg_id={}, route_id={}", id, route_id),
+            Command::DetachOrder { order_id, route_id } | Command::ReleaseOrder { order_id, route_id, .. } =>
+                format!("order_id={}, route_id={}", order_id, route_id),
+            Command::ShiftPlaces { route_id, .. } | Command::AddPassengers { route_id, .. }
+                | Command::ReduceReserveAfter { route_id, .. } | Command::ReduceReserveBefore { route_id, .. }
+                | Command::CapReserveBefore { route_id, .. } | Command::ClosePlaces { route_id, .. }
+                | Command::AbandonLegs { route_id } | Command::AbandonRoute { route_id } => format!("route_id={}", route_id),
+            Command::AssignCab { cab_id } | Command::LockRoutes { cab_id } => format!("cab_id={}", cab_id),
+            Command::CreateRoute { id, cab_id } => format!("route_id={}, cab_id={}", id, cab_id),
+            Command::UpdateStat { name, .. } => format!("stat={}", name),
+            Command::Emit { seq, event } => format!("seq={}, event={}", seq, event.kind()),
+        };
+    }
+
+    // the row was read before the plan was made and has to be as it was then - in the expected status;
+    // no row changed means someone else (Kapir, a driver) was first
+    pub fn must_change_row(&self) -> bool {
+        return matches!(self, Command::AssignOrderToRoute { .. } | Command::AssignOrder { .. }
+                            | Command::UpdateLeg { .. } | Command::AssignCab { .. }
+                            | Command::InsertSegment { .. } | Command::SplitOrder { .. }
+                            | Command::DetachOrder { .. } | Command::DeleteLeg { .. } | Command::ReleaseOrder { .. });
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Param::Int(x) => write!(f, "{}", x),
+            Param::BigInt(x) => write!(f, "{}", x),
+            Param::Bool(x) => write!(f, "{}", x),
+            Param::Text(x) => write!(f, "'{}'", x),
+            Param::Time(Some(x)) => write!(f, "'{}'", x),
+            Param::Time(None) => write!(f, "NULL"),
+        };
+    }
+}
+
+// the statement with parameters in place, for logs and error reports; it is never sent to a database
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut params = self.params().into_iter();
+        let mut ret = String::new();
+        for c in self.sql().chars() {
+            if c == '?' {
+                ret += &params.next().map_or("?".to_string(), |p| p.to_string());
+            } else {
+                ret.push(c);
+            }
+        }
+        return write!(f, "{}", ret);
+    }
+}
+
+// all statements, one per line; tests compare plans with it
+#[cfg(test)]
+pub fn to_sql(cmds: &Vec<Command>) -> String {
+    return cmds.iter().map(|c| format!("{};\n", c)).collect();
+}
+
+// an event of the plan, see event.rs
+pub fn emit(event: Event) -> Command {
+    return Command::Emit { seq: -1, event };
+}
+
+// new routes, legs and events of a plan, they need that many real ids
+pub fn count_new_ids(cmds: &Vec<Command>) -> (i64, i64, i64) {
+    let mut routes: i64 = 0;
+    let mut legs: i64 = 0;
+    let mut events: i64 = 0;
+    for c in cmds.iter() {
+        match c {
+            Command::CreateRoute { id, .. } if is_temp_id(*id) => routes += 1,
+            Command::CreateLeg { id, .. } if is_temp_id(*id) => legs += 1,
+            Command::Emit { .. } => events += 1,
+            _ => {}
+        }
+    }
+    return (routes, legs, events);
+}
+
+// temporary ids replaced with real ones, given in the order of creation;
+// ids read from the database (extended routes) stay as they are; events are numbered in the order of the plan
+pub fn resolve_ids(cmds: &Vec<Command>, first_route: i64, first_leg: i64, first_event: i64) -> Result<Vec<Command>, String> {
+    let mut routes: HashMap<i64, i64> = HashMap::new();
+    let mut legs: HashMap<i64, i64> = HashMap::new();
+    for c in cmds.iter() {
+        match c {
+            Command::CreateRoute { id, .. } if is_temp_id(*id) => { routes.insert(*id, first_route + routes.len() as i64); }
+            Command::CreateLeg { id, .. } if is_temp_id(*id) => { legs.insert(*id, first_leg + legs.len() as i64); }
+            _ => {}
+        }
+    }
+    let mut ret: Vec<Command> = cmds.clone();
+    let mut next_event = first_event;
+    for c in ret.iter_mut() {
+        match c {
+            Command::Emit { seq, event } => {
+                *seq = next_event;
+                next_event += 1;
+                let (route_id, leg_id) = event.ids_mut();
+                if let Some(r) = route_id { resolve(r, &routes)?; }
+                if let Some(l) = leg_id { resolve(l, &legs)?; }
+            },
+            Command::AssignOrderToRoute { leg_id, route_id, .. } | Command::AssignOrder { leg_id, route_id, .. } => {
+                resolve(route_id, &routes)?;
+                if let Some(l) = leg_id { resolve(l, &legs)?; }
+            },
+            Command::InsertOrder { leg_id, route_id, .. } => { resolve(route_id, &routes)?; resolve(leg_id, &legs)?; },
+            Command::CreateLeg { id, route_id, .. } => { resolve(id, &legs)?; resolve(route_id, &routes)?; },
+            Command::UpdateLeg { id, route_id, .. } | Command::DeleteLeg { id, route_id } => {
+                resolve(id, &legs)?;
+                resolve(route_id, &routes)?;
+            },
+            Command::ShiftPlaces { route_id, .. } | Command::AddPassengers { route_id, .. }
+                | Command::ReduceReserveAfter { route_id, .. } | Command::ReduceReserveBefore { route_id, .. }
+                | Command::CapReserveBefore { route_id, .. } | Command::ClosePlaces { route_id, .. }
+                | Command::CreateRoute { id: route_id, .. } => resolve(route_id, &routes)?,
+            _ => {}
+        }
+    }
+    return Ok(ret);
+}
+
+fn resolve(id: &mut i64, ids: &HashMap<i64, i64>) -> Result<(), String> {
+    if is_temp_id(*id) {
+        *id = *ids.get(id).ok_or(format!("temporary id {} not created in the plan", *id - TEMP_ID_BASE))?;
+    }
+    return Ok(());
+}
+
+// the route each command writes to, None for those that do not belong to any route (stats, expired orders);
+// a cab gets the route created for it in the plan
+pub fn routes_of(cmds: &Vec<Command>) -> Vec<Option<i64>> {
+    let mut cab_routes: HashMap<i64, i64> = HashMap::new();
+    for c in cmds.iter() {
+        if let Command::CreateRoute { id, cab_id } = c {
+            cab_routes.insert(*cab_id, *id);
+        }
+    }
+    return cmds.iter().map(|c| match c {
+        Command::AssignOrderToRoute { route_id, .. } | Command::AssignOrder { route_id, .. }
+            | Command::InsertOrder { route_id, .. } | Command::CreateLeg { route_id, .. } | Command::UpdateLeg { route_id, .. }
+            | Command::ShiftPlaces { route_id, .. } | Command::AddPassengers { route_id, .. }
+            | Command::ReduceReserveAfter { route_id, .. } | Command::ReduceReserveBefore { route_id, .. }
+            | Command::CapReserveBefore { route_id, .. } | Command::CreateRoute { id: route_id, .. }
+            | Command::DetachOrder { route_id, .. } | Command::DeleteLeg { route_id, .. }
+            | Command::ClosePlaces { route_id, .. } | Command::ReleaseOrder { route_id, .. }
+            | Command::AbandonLegs { route_id } | Command::AbandonRoute { route_id } => Some(*route_id),
+        Command::AssignCab { cab_id } | Command::LockRoutes { cab_id } => cab_routes.get(cab_id).copied(),
+        Command::Emit { event, .. } => event.route_id(),
+        _ => None
+    }).collect();
+}
+
+// the plan without the conflicting commands and without whole routes they belong to -
+// a half of a route would be worse than none, its orders wait for the next run
+pub fn without_conflicts(cmds: &Vec<Command>, conflicts: &Vec<usize>) -> Vec<Command> {
+    let routes = routes_of(cmds);
+    let dropped: Vec<i64> = conflicts.iter().filter_map(|i| routes[*i]).collect();
+    return cmds.iter().enumerate()
+        .filter(|(i, _)| !conflicts.contains(i) && !routes[*i].map_or(false, |r| dropped.contains(&r)))
+        .map(|(_, c)| c.clone()).collect();
+}
+
+// an order assigned with a cab is put on a new route, which has to be created by the same plan,
+// otherwise the order would point at a route that does not exist
+pub fn check_plan(cmds: &Vec<Command>) -> Result<(), String> {
+    let mut routes: Vec<i64> = vec![];
+    for c in cmds.iter() {
+        match c {
+            Command::CreateRoute { id, .. } => routes.push(*id),
+            Command::AssignOrder { route_id, .. } if !routes.contains(route_id) =>
+                return Err(format!("statement: {}, ids: {}, err: route not created in the plan", c, c.ids())),
+            _ => {}
+        }
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_params_match_placeholders() {
+    let cmds = vec![
+      Command::AssignOrderToRoute { order_id: 1, leg_id: None, route_id: 2, eta: 3, in_pool: true },
+      Command::AssignOrderToRoute { order_id: 1, leg_id: Some(4), route_id: 2, eta: 3, in_pool: true },
+      Command::AssignOrder { order_id: 1, cab_id: 5, leg_id: None, route_id: 2, eta: 3, in_pool: false },
+      Command::AssignOrder { order_id: 1, cab_id: 5, leg_id: Some(4), route_id: 2, eta: 3, in_pool: false },
+      Command::InsertOrder { from: 1, to: 2, loss: 30, shared: true, passengers: 2, received: None, dist: 3, cust_id: 4, cab_id: 5, leg_id: 6, route_id: 7 },
+      Command::InsertSegment { order_id: 1, from: 2, at_time: None, dist: 3 },
+      Command::SplitOrder { order_id: 1, to: 2, dist: 3 },
+      Command::CreateLeg { id: 1, from: 2, to: 3, place: 0, dist: 4, status: 1, reserve: 5, route_id: 6, passengers: 1 },
+      Command::UpdateLeg { id: 1, route_id: 6, to: 3, dist: 4, reserve: 5, passengers: 1 },
+      Command::AddPassengers { route_id: 1, passengers: 2, reserve: 2, place_from: 3, place_to: 4 },
+      Command::DetachOrder { order_id: 1, route_id: 2 },
+      Command::DeleteLeg { id: 1, route_id: 2 },
+      Command::ClosePlaces { route_id: 2, place_from: 3 },
+      Command::ReleaseOrder { order_id: 1, route_id: 2, released: None },
+      Command::AbandonLegs { route_id: 2 },
+      Command::AbandonRoute { route_id: 2 },
+      Command::CreateRoute { id: 1, cab_id: 2 },
+      Command::UpdateStat { name: "AvgPoolTime".to_string(), value: 1 },
+    ];
+    for c in cmds.iter() {
+      assert_eq!(c.sql().matches('?').count(), c.params().len(), "{}", c.sql());
+    }
+  }
+
+  #[test]
+  fn test_to_sql() {
+    let cmds = vec![Command::AssignOrderToRoute { order_id: 1, leg_id: None, route_id: 2, eta: 3, in_pool: true },
+                    Command::UpdateStat { name: "AvgPoolTime".to_string(), value: 7 }];
+    assert_eq!(to_sql(&cmds), "UPDATE taxi_order SET route_id=2, cab_id=(SELECT cab_id FROM route where id=2), status=1, eta=3, in_pool=true WHERE id=1 AND status=0;\n\
+                               UPDATE stat SET int_val=7 WHERE UPPER(name)=UPPER('AvgPoolTime');\n");
+  }
+
+  #[test]
+  fn test_check_plan() {
+    let assign = Command::AssignOrder { order_id: 1, cab_id: 5, leg_id: None, route_id: 2, eta: 3, in_pool: false };
+    assert_eq!(check_plan(&vec![Command::CreateRoute { id: 2, cab_id: 5 }, assign.clone()]), Ok(()));
+    assert!(check_plan(&vec![assign.clone()]).is_err());
+    assert!(check_plan(&vec![assign, Command::CreateRoute { id: 2, cab_id: 5 }]).is_err()); // order matters
+    // extender puts orders on routes that are already there
+    assert_eq!(check_plan(&vec![Command::AssignOrderToRoute { order_id: 1, leg_id: None, route_id: 2, eta: 3, in_pool: true }]), Ok(()));
+  }
+
+  #[test]
+  fn test_resolve_ids() {
+    let (r, l) = (TEMP_ID_BASE, TEMP_ID_BASE);
+    let cmds = vec![
+      Command::CreateRoute { id: r, cab_id: 5 },
+      Command::CreateLeg { id: l, from: 1, to: 2, place: 0, dist: 4, status: 1, reserve: 5, route_id: r, passengers: 1 },
+      Command::CreateLeg { id: l + 1, from: 2, to: 3, place: 1, dist: 4, status: 1, reserve: 5, route_id: r, passengers: 1 },
+      Command::AssignOrder { order_id: 1, cab_id: 5, leg_id: Some(l + 1), route_id: r, eta: 3, in_pool: false },
+      Command::UpdateLeg { id: 77, route_id: 7, to: 3, dist: 4, reserve: 5, passengers: 1 }, // extended route, a real id
+      Command::AssignOrderToRoute { order_id: 2, leg_id: None, route_id: 12, eta: 3, in_pool: true },
+    ];
+    assert_eq!(count_new_ids(&cmds), (1, 2, 0));
+    assert_eq!(to_sql(&resolve_ids(&cmds, 100, 200, 1).unwrap()),
+      "INSERT INTO route (id, status, cab_id, locked) VALUES (100,1,5,false);\n\
+       INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (200,1,2,0,4,1,5,100,1);\n\
+       INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (201,2,3,1,4,1,5,100,1);\n\
+       UPDATE taxi_order SET route_id=100, leg_id=201, cab_id=5, status=1, eta=3, in_pool=false WHERE id=1 AND status=0;\n\
+       UPDATE leg SET to_stand=3, distance=4, reserve=5, passengers=1 WHERE id=77 AND route_id=7 AND status IN (1,5);\n\
+       UPDATE taxi_order SET route_id=12, cab_id=(SELECT cab_id FROM route where id=12), status=1, eta=3, in_pool=true WHERE id=2 AND status=0;\n");
+    // a leg that the plan does not create
+    assert!(resolve_ids(&vec![Command::UpdateLeg { id: l + 5, route_id: 7, to: 3, dist: 4, reserve: 5, passengers: 1 }], 100, 200, 1).is_err());
+  }
+
+  #[test]
+  fn test_without_conflicts() {
+    let cmds = vec![
+      Command::AssignCab { cab_id: 5 },
+      Command::CreateRoute { id: 2, cab_id: 5 },
+      Command::CreateLeg { id: 3, from: 1, to: 2, place: 0, dist: 4, status: 1, reserve: 5, route_id: 2, passengers: 1 },
+      Command::AssignOrder { order_id: 1, cab_id: 5, leg_id: Some(3), route_id: 2, eta: 3, in_pool: false },
+      Command::AssignOrderToRoute { order_id: 7, leg_id: None, route_id: 8, eta: 3, in_pool: true }, // extended route
+      Command::ExpireOrder { order_id: 9 },
+    ];
+    assert_eq!(routes_of(&cmds), vec![Some(2), Some(2), Some(2), Some(2), Some(8), None]);
+    // order 1 cancelled - the whole new route goes
+    assert_eq!(without_conflicts(&cmds, &vec![3]), cmds[4..].to_vec());
+    // cab 5 out of service
+    assert_eq!(without_conflicts(&cmds, &vec![0]), cmds[4..].to_vec());
+    assert_eq!(without_conflicts(&cmds, &vec![4]), [&cmds[0..4], &cmds[5..]].concat());
+    assert!(cmds.iter().filter(|c| c.must_change_row()).count() == 3);
+  }
+}
@@ -1,8 +1,128 @@
+use std::cmp;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use memmap2::{MmapMut, MmapOptions};
 use crate::model::{Stop,MAXSTOPSNUMB};
-pub static mut DIST : [[i16; MAXSTOPSNUMB]; MAXSTOPSNUMB] = [[0; MAXSTOPSNUMB]; MAXSTOPSNUMB];
 const M_PI : f64 = 3.14159265358979323846264338327950288;
 const M_PI_180 : f64 = M_PI / 180.0;
 const REV_M_PI_180 : f64 = 180.0 / M_PI;
+const MATRIX_MAGIC : &[u8; 4] = b"KDM1"; // header of a binary matrix file
+const CACHE_MAGIC : &[u8; 4] = b"KDC1";
+const CACHE_VERSION : u32 = 1; // change it when the layout of cache or the way distances are computed changes
+const CACHE_HEADER : usize = 24; // magic, version, key (u64), size (u32), padding (u32)
+
+// travel times between stops in seconds, indexed with stop ids
+// the matrix is directed, one-way streets make A->B and B->A differ
+// the pool finder, the extender and the solvers get it as a parameter, so that tests can have their own
+pub trait DistanceProvider: Send + Sync {
+    fn matrix(&self) -> &DistMatrix;
+    fn matrix_mut(&mut self) -> &mut DistMatrix; // profiles and learned travel times change it between runs
+
+    fn dist(&self, from: usize, to: usize) -> i16 {
+        return self.matrix().get(from, to);
+    }
+
+    // fetches travel times again if the source can change, e.g. a routing engine; true if the matrix has been rewritten
+    fn refresh(&mut self, _stops: &Vec<Stop>) -> bool {
+        return false;
+    }
+}
+
+// computed from coordinates of stops and 'cab_speed', the same in both directions
+pub struct Haversine {
+    pub matrix: DistMatrix
+}
+
+// travel times read from a file, e.g. exported from a road network
+pub struct FileMatrix {
+    pub matrix: DistMatrix
+}
+
+// filled in by hand, for tests
+#[cfg(test)]
+pub struct MemoryMatrix {
+    pub matrix: DistMatrix
+}
+
+impl DistanceProvider for Haversine {
+    fn matrix(&self) -> &DistMatrix { return &self.matrix; }
+    fn matrix_mut(&mut self) -> &mut DistMatrix { return &mut self.matrix; }
+}
+
+impl DistanceProvider for FileMatrix {
+    fn matrix(&self) -> &DistMatrix { return &self.matrix; }
+    fn matrix_mut(&mut self) -> &mut DistMatrix { return &mut self.matrix; }
+}
+
+#[cfg(test)]
+impl DistanceProvider for MemoryMatrix {
+    fn matrix(&self) -> &DistMatrix { return &self.matrix; }
+    fn matrix_mut(&mut self) -> &mut DistMatrix { return &mut self.matrix; }
+}
+
+// 'size' x 'size' values row by row in one buffer, which is passed to C as it is
+// the buffer is either 'values' or a private mapping of a cache file
+pub struct DistMatrix {
+    ptr: *mut i16,
+    size: usize,
+    values: Vec<i16>,
+    _map: Option<MmapMut> // not read, 'ptr' points into it; kept so that it is not unmapped
+}
+
+// 'ptr' points to memory owned by the matrix itself
+unsafe impl Send for DistMatrix {}
+unsafe impl Sync for DistMatrix {}
+
+impl DistMatrix {
+    pub fn new(size: usize) -> Self {
+        let mut values: Vec<i16> = vec![0; size * size];
+        return Self { ptr: values.as_mut_ptr(), size, values, _map: None };
+    }
+
+    fn as_slice(&self) -> &[i16] {
+        return unsafe { std::slice::from_raw_parts(self.ptr, self.size * self.size) };
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [i16] {
+        return unsafe { std::slice::from_raw_parts_mut(self.ptr, self.size * self.size) };
+    }
+
+    pub fn get(&self, from: usize, to: usize) -> i16 {
+        assert!(from < self.size && to < self.size, "Stop out of distance matrix: {}->{}, size: {}", from, to, self.size);
+        return self.as_slice()[from * self.size + to];
+    }
+
+    pub fn set(&mut self, from: usize, to: usize, secs: i16) {
+        assert!(from < self.size && to < self.size, "Stop out of distance matrix: {}->{}, size: {}", from, to, self.size);
+        let size = self.size;
+        self.as_mut_slice()[from * size + to] = secs;
+    }
+
+    // number of rows (and columns)
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    // the whole matrix for C, row by row
+    pub fn as_ptr(&self) -> *const i16 {
+        return self.ptr;
+    }
+
+    // makes it at least 'size' x 'size', values already there are kept, new ones are zero
+    pub fn grow(&mut self, size: usize) {
+        if size <= self.size {
+            return;
+        }
+        let mut grown = DistMatrix::new(size);
+        for i in 0 .. self.size {
+            for j in 0 .. self.size {
+                grown.values[i * size + j] = self.get(i, j);
+            }
+        }
+        *self = grown; // a map is unmapped when dropped
+    }
+}
 
 fn deg2rad(deg: f64) -> f64 { return deg * M_PI_180; }
 fn rad2deg(rad: f64) -> f64 { return rad * REV_M_PI_180; }
@@ -19,18 +139,446 @@ fn dist(lat1:f64, lon1:f64, lat2: f64, lon2: f64) -> f64 {
     return dist;
 }
 
-pub fn init_distance(stops: & Vec<Stop>, cab_speed: i8) {
-    unsafe {
-    for i in 0 .. stops.len() {
-        DIST[i][i] = 0;
-        for j in i+1 .. stops.len() {
-            let mut d = dist(stops[i].latitude, stops[i].longitude, stops[j].latitude, stops[j].longitude)
-                         * (60.0 / cab_speed as f64);
-            if d as i16 == 0 { d = 1.0; } // a transfer takes at least one minute. 
-            DIST[stops[i].id as usize][stops[j].id as usize] = d as i16; // TASK: we might need a better precision - meters/seconds
-            DIST[stops[j].id as usize][stops[i].id as usize] 
-                = DIST[stops[i].id as usize][stops[j].id as usize];
+// number of rows (and columns) needed for these stops - the highest id + 1
+pub fn matrix_size(stops: &Vec<Stop>) -> Result<usize, String> {
+    let mut size: usize = 0;
+    for s in stops.iter() {
+        if s.id < 0 || s.id as usize >= MAXSTOPSNUMB {
+            return Err(format!("Stop id {} exceeds the limit of distance matrix: {}", s.id, MAXSTOPSNUMB));
+        }
+        size = cmp::max(size, s.id as usize + 1);
+    }
+    return Ok(size);
+}
+
+impl Haversine {
+    // stop ids should have been checked with 'matrix_size'
+    pub fn new(stops: &Vec<Stop>, cab_speed: i8) -> Self {
+        let mut matrix = DistMatrix::new(matrix_size(stops).unwrap());
+        for i in 0 .. stops.len() {
+            for j in i+1 .. stops.len() {
+                let d = haversine_secs(&stops[i], &stops[j], cab_speed);
+                matrix.set(stops[i].id as usize, stops[j].id as usize, d); // seconds
+                matrix.set(stops[j].id as usize, stops[i].id as usize, d);
+            }
+        }
+        return Self { matrix };
+    }
+}
+
+// travel time between two different stops at 'cab_speed' km/h
+pub fn haversine_secs(from: &Stop, to: &Stop, cab_speed: i8) -> i16 {
+    let mut d = dist(from.latitude, from.longitude, to.latitude, to.longitude) * (3600.0 / cab_speed as f64);
+    if d as i16 == 0 { d = 1.0; } // a transfer takes at least one second. 
+    return d.round() as i16;
+}
+
+impl FileMatrix {
+    // see 'read_distance_file'
+    pub fn load(path: &str, format: &str, stops: &Vec<Stop>) -> Result<Self, String> {
+        let mut matrix = DistMatrix::new(0);
+        set_distance(&mut matrix, &read_distance_file(path, format, stops)?);
+        return Ok(Self { matrix });
+    }
+}
+
+#[cfg(test)]
+impl MemoryMatrix {
+    pub fn new(size: usize) -> Self {
+        return Self { matrix: DistMatrix::new(size) };
+    }
+
+    // the same value between all stops, but zero from a stop to itself
+    pub fn with_value(size: usize, secs: i16) -> Self {
+        let mut ret = Self::new(size);
+        for i in 0 .. size {
+            for j in 0 .. size {
+                if i != j { ret.set(i, j, secs); }
+            }
+        }
+        return ret;
+    }
+
+    // stops on a line, 'secs' between neighbours
+    pub fn line(size: usize, secs: i16) -> Self {
+        let mut ret = Self::new(size);
+        for i in 0 .. size {
+            for j in 0 .. size {
+                ret.set(i, j, secs * (i as i16 - j as i16).abs());
+            }
+        }
+        return ret;
+    }
+
+    pub fn set(&mut self, from: usize, to: usize, secs: i16) {
+        self.matrix.set(from, to, secs);
+    }
+}
+
+// identifies a matrix in cache - stops with their coordinates and where travel times come from, e.g. "speed:30"
+// FNV-1a, std hashers may change between Rust releases and the key has to survive restarts
+pub fn cache_key(stops: &Vec<Stop>, source: &str) -> u64 {
+    let mut sorted: Vec<&Stop> = stops.iter().collect();
+    sorted.sort_by_key(|s| s.id);
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut add = |bytes: &[u8]| {
+        for b in bytes {
+            hash ^= *b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    };
+    add(&CACHE_VERSION.to_le_bytes());
+    add(source.as_bytes());
+    for s in sorted {
+        add(&s.id.to_le_bytes());
+        add(&s.latitude.to_le_bytes());
+        add(&s.longitude.to_le_bytes());
+    }
+    return hash;
+}
+
+// source of a distance file for 'cache_key', a modified file gets a new key
+pub fn file_source(path: &str, format: &str) -> String {
+    return match fs::metadata(path) {
+        Ok(meta) => {
+            let modified = match meta.modified() {
+                Ok(m) => m.duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+                Err(_) => 0
+            };
+            format!("file:{}:{}:{}:{}", path, format, meta.len(), modified)
+        },
+        Err(_) => format!("file:{}:{}", path, format)
+    };
+}
+
+// maps a matrix from a cache file written by 'save_cache', the mapping is private - changes do not go to the file
+// an error means the matrix has to be computed, e.g. there is no cache yet or stops have changed
+pub fn load_cache(path: &str, key: u64, stops: &Vec<Stop>) -> Result<DistMatrix, String> {
+    let file = File::open(path).map_err(|e| format!("Cannot open cache {}: {}", path, e))?;
+    let mut map = unsafe { MmapOptions::new().map_copy(&file) }
+                    .map_err(|e| format!("Cannot map cache {}: {}", path, e))?;
+    if map.len() < CACHE_HEADER || &map[0..4] != CACHE_MAGIC {
+        return Err(format!("{} is not a distance cache", path));
+    }
+    let version = u32::from_ne_bytes(map[4..8].try_into().unwrap());
+    if version != CACHE_VERSION {
+        return Err(format!("Cache {} has version {}, expected: {}", path, version, CACHE_VERSION));
+    }
+    if u64::from_ne_bytes(map[8..16].try_into().unwrap()) != key {
+        return Err(format!("Cache {} is stale, stops or source of travel times have changed", path));
+    }
+    let size = u32::from_ne_bytes(map[16..20].try_into().unwrap()) as usize;
+    if size != matrix_size(stops)? || map.len() != CACHE_HEADER + size * size * 2 {
+        return Err(format!("Wrong size of cache {}", path));
+    }
+    // page-aligned, so is the header; the map lives as long as the matrix
+    let ptr = unsafe { map.as_mut_ptr().add(CACHE_HEADER) as *mut i16 };
+    return Ok(DistMatrix { ptr, size, values: vec![], _map: Some(map) });
+}
+
+// native byte order - the cache is not meant to be copied to other machines
+// a temporary file is renamed so that a running dispatcher never maps a half-written cache
+pub fn save_cache(path: &str, key: u64, matrix: &DistMatrix) -> Result<(), String> {
+    let tmp = format!("{}.tmp", path);
+    let err = |e: std::io::Error| format!("Cannot write cache {}: {}", path, e);
+    {
+        let mut wrt = BufWriter::new(File::create(&tmp).map_err(err)?);
+        wrt.write_all(CACHE_MAGIC).map_err(err)?;
+        wrt.write_all(&CACHE_VERSION.to_ne_bytes()).map_err(err)?;
+        wrt.write_all(&key.to_ne_bytes()).map_err(err)?;
+        wrt.write_all(&(matrix.size as u32).to_ne_bytes()).map_err(err)?;
+        wrt.write_all(&0u32.to_ne_bytes()).map_err(err)?;
+        for val in matrix.as_slice() {
+            wrt.write_all(&val.to_ne_bytes()).map_err(err)?;
         }
+        wrt.flush().map_err(err)?;
     }
+    fs::rename(&tmp, path).map_err(err)?;
+    return Ok(());
+}
+
+// "bin" for files with .bin extension, CSV otherwise; 'dist_format' in config overrides it
+pub fn matrix_format(path: &str) -> &str {
+    return if path.ends_with(".bin") { "bin" } else { "csv" };
+}
+
+// travel times between stops as read from a file, row by row: values[from_idx * ids.len() + to_idx]
+pub struct Matrix {
+    pub ids: Vec<i64>,
+    pub values: Vec<i16>
+}
+
+// travel times in seconds between stops, precomputed e.g. with a routing engine on a road network
+// CSV: the header lists stop ids (the first cell is ignored), then one row per 'from' stop:
+//   ,0,1,2
+//   0,0,180,300
+//   1,180,0,120
+//   2,240,120,0
+// binary: "KDM1", number of stops (u32), stop ids (i64), then values (i16) row by row, all little endian
+// every stop read from the database has to be in the file, stops unknown to the database are ignored
+pub fn read_distance_file(path: &str, format: &str, stops: &Vec<Stop>) -> Result<Matrix, String> {
+    let (ids, values) = match format {
+        "csv" => read_csv_matrix(path)?,
+        "bin" => read_bin_matrix(path)?,
+        _ => { return Err(format!("Unknown format of distance file: {}", format)); }
+    };
+    check_stops(&ids, stops)?;
+    let known: HashSet<i64> = stops.iter().map(|s| s.id).collect();
+    let keep: Vec<usize> = (0..ids.len()).filter(|i| known.contains(&ids[*i])).collect();
+    let mut matrix = Matrix { ids: keep.iter().map(|i| ids[*i]).collect(), values: Vec::with_capacity(keep.len() * keep.len()) };
+    for i in keep.iter() {
+        for j in keep.iter() {
+            matrix.values.push(values[i * ids.len() + j]);
+        }
+    }
+    return Ok(matrix);
+}
+
+// copies travel times of 'matrix' to 'dist', other stops are not changed
+pub fn set_distance(dist: &mut DistMatrix, matrix: &Matrix) {
+    let size = matrix.ids.len();
+    dist.grow(matrix.ids.iter().map(|id| *id as usize + 1).max().unwrap_or(0));
+    for (i, from) in matrix.ids.iter().enumerate() {
+        for (j, to) in matrix.ids.iter().enumerate() {
+            dist.set(*from as usize, *to as usize, matrix.values[i * size + j]);
+        }
+    }
+}
+
+// a copy of travel times between 'stops', to be restored later with 'set_distance'
+pub fn get_distance(dist: &DistMatrix, stops: &Vec<Stop>) -> Matrix {
+    let mut matrix = Matrix { ids: stops.iter().map(|s| s.id).collect(), values: Vec::with_capacity(stops.len() * stops.len()) };
+    for from in matrix.ids.iter() {
+        for to in matrix.ids.iter() {
+            matrix.values.push(dist.get(*from as usize, *to as usize));
+        }
+    }
+    return matrix;
+}
+
+fn check_stops(ids: &Vec<i64>, stops: &Vec<Stop>) -> Result<(), String> {
+    let in_file: HashSet<i64> = ids.iter().cloned().collect();
+    if in_file.len() != ids.len() {
+        return Err("Duplicated stop ids in distance file".to_string());
     }
+    let mut missing: Vec<i64> = vec![];
+    matrix_size(stops)?;
+    for s in stops.iter() {
+        if !in_file.contains(&s.id) {
+            missing.push(s.id);
+        }
+    }
+    if missing.len() > 0 {
+        let sample: Vec<String> = missing.iter().take(20).map(|id| id.to_string()).collect();
+        return Err(format!("{} stops missing in distance file, ids: {}{}", missing.len(), sample.join(","),
+                            if missing.len() > 20 { ",..." } else { "" }));
+    }
+    return Ok(());
+}
+
+fn read_csv_matrix(path: &str) -> Result<(Vec<i64>, Vec<i16>), String> {
+    let file = File::open(path).map_err(|e| format!("Cannot open distance file {}: {}", path, e))?;
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(file);
+    let header = rdr.headers().map_err(|e| format!("Cannot read header of {}: {}", path, e))?.clone();
+    let mut ids: Vec<i64> = vec![];
+    for cell in header.iter().skip(1) {
+        ids.push(parse_cell(cell, path, 1)?);
+    }
+    let size = ids.len();
+    let mut values: Vec<i16> = vec![0; size * size];
+    let mut rows_found: Vec<bool> = vec![false; size];
+    for (line, result) in rdr.records().enumerate() {
+        let line = line + 2; // header is the first line
+        let record = result.map_err(|e| format!("Cannot read {}, line {}: {}", path, line, e))?;
+        if record.len() != size + 1 {
+            return Err(format!("Wrong number of values in {}, line {}: {}, expected: {}", path, line, record.len(), size + 1));
+        }
+        let from: i64 = parse_cell(&record[0], path, line)?;
+        let row = match ids.iter().position(|id| *id == from) {
+            Some(x) => x,
+            None => { return Err(format!("Stop {} in {}, line {} is not in the header", from, path, line)); }
+        };
+        if rows_found[row] {
+            return Err(format!("Duplicated row for stop {} in {}, line {}", from, path, line));
+        }
+        rows_found[row] = true;
+        for col in 0..size {
+            let val: i64 = parse_cell(&record[col + 1], path, line)?;
+            if val < 0 || val > i16::MAX as i64 {
+                return Err(format!("Travel time out of range in {}, line {}: {}", path, line, val));
+            }
+            values[row * size + col] = val as i16;
+        }
+    }
+    if let Some(row) = rows_found.iter().position(|found| !found) {
+        return Err(format!("No row for stop {} in {}", ids[row], path));
+    }
+    return Ok((ids, values));
+}
+
+fn parse_cell(cell: &str, path: &str, line: usize) -> Result<i64, String> {
+    return cell.trim().parse::<i64>().map_err(|_| format!("Not a number in {}, line {}: '{}'", path, line, cell));
+}
+
+fn read_bin_matrix(path: &str) -> Result<(Vec<i64>, Vec<i16>), String> {
+    let file = File::open(path).map_err(|e| format!("Cannot open distance file {}: {}", path, e))?;
+    let mut rdr = BufReader::new(file);
+    let err = |e: std::io::Error| format!("Cannot read distance file {}: {}", path, e);
+    let mut magic = [0u8; 4];
+    rdr.read_exact(&mut magic).map_err(err)?;
+    if &magic != MATRIX_MAGIC {
+        return Err(format!("{} is not a binary distance file", path));
+    }
+    let mut buf4 = [0u8; 4];
+    rdr.read_exact(&mut buf4).map_err(err)?;
+    let size = u32::from_le_bytes(buf4) as usize;
+    if size > MAXSTOPSNUMB {
+        return Err(format!("Too many stops in {}: {}, max: {}", path, size, MAXSTOPSNUMB));
+    }
+    let mut ids: Vec<i64> = Vec::with_capacity(size);
+    let mut buf8 = [0u8; 8];
+    for _ in 0..size {
+        rdr.read_exact(&mut buf8).map_err(err)?;
+        ids.push(i64::from_le_bytes(buf8));
+    }
+    let mut values: Vec<i16> = Vec::with_capacity(size * size);
+    let mut buf2 = [0u8; 2];
+    for i in 0..size * size {
+        rdr.read_exact(&mut buf2).map_err(err)?;
+        let val = i16::from_le_bytes(buf2);
+        if val < 0 {
+            return Err(format!("Travel time out of range in {}, value {}: {}", path, i, val));
+        }
+        values.push(val);
+    }
+    return Ok((ids, values));
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Write;
+
+  fn test_stops(count: i64) -> Vec<Stop> {
+    let mut stops: Vec<Stop> = vec![];
+    for i in 0..count {
+      stops.push(Stop{ id: i, bearing: 0, latitude: 49.0, longitude: 19.0 + 0.01 * i as f64, capacity: 10, dwell: 60});
+    }
+    return stops;
+  }
+
+  fn write_file(name: &str, content: &[u8]) -> String {
+    let path = std::env::temp_dir().join(name);
+    let mut file = File::create(&path).unwrap();
+    file.write_all(content).unwrap();
+    return path.to_str().unwrap().to_string();
+  }
+
+  #[test]
+  fn test_load_csv_matrix() {
+    let path = write_file("kern-test-dist.csv", b",0,1,2\n0,0,3,5\n1,3,0,2\n2,4,2,0\n");
+    let dp = FileMatrix::load(&path, matrix_format(&path), &test_stops(3)).unwrap();
+    assert_eq!(dp.dist(0, 1), 3);
+    assert_eq!(dp.dist(0, 2), 5);
+    assert_eq!(dp.dist(2, 0), 4);
+  }
+
+  #[test]
+  fn test_load_csv_matrix_missing_stop() {
+    let path = write_file("kern-test-dist-missing.csv", b",0,1\n0,0,3\n1,3,0\n");
+    let ret = FileMatrix::load(&path, "csv", &test_stops(3));
+    assert_eq!(ret.err().unwrap(), "1 stops missing in distance file, ids: 2");
+  }
+
+  #[test]
+  fn test_load_bin_matrix() {
+    let mut content: Vec<u8> = MATRIX_MAGIC.to_vec();
+    content.extend_from_slice(&2u32.to_le_bytes());
+    content.extend_from_slice(&1i64.to_le_bytes());
+    content.extend_from_slice(&0i64.to_le_bytes());
+    for val in [0i16, 7, 6, 0] {
+      content.extend_from_slice(&val.to_le_bytes());
+    }
+    let path = write_file("kern-test-dist.bin", &content);
+    let dp = FileMatrix::load(&path, matrix_format(&path), &test_stops(2)).unwrap();
+    assert_eq!(dp.dist(1, 0), 7);
+    assert_eq!(dp.dist(0, 1), 6);
+  }
+
+  #[test]
+  fn test_load_bin_matrix_negative() {
+    let mut content: Vec<u8> = MATRIX_MAGIC.to_vec();
+    content.extend_from_slice(&2u32.to_le_bytes());
+    content.extend_from_slice(&0i64.to_le_bytes());
+    content.extend_from_slice(&1i64.to_le_bytes());
+    for val in [0i16, 7, -6, 0] {
+      content.extend_from_slice(&val.to_le_bytes());
+    }
+    let path = write_file("kern-test-dist-negative.bin", &content);
+    let ret = FileMatrix::load(&path, "bin", &test_stops(2));
+    assert_eq!(ret.err().unwrap(), format!("Travel time out of range in {}, value 2: -6", path));
+  }
+
+  #[test]
+  fn test_haversine() {
+    let dp = Haversine::new(&test_stops(3), 30);
+    assert_eq!(dp.dist(0, 1), dp.dist(1, 0));
+    assert_eq!(dp.dist(1, 1), 0);
+    assert_eq!(dp.dist(0, 2), 175); // 1.46 km at 30 km/h
+  }
+
+  #[test]
+  fn test_grow_keeps_values() {
+    let mut dp = MemoryMatrix::new(3);
+    dp.set(2, 1, 42);
+    dp.matrix_mut().grow(MAXSTOPSNUMB / 10);
+    assert_eq!(dp.dist(2, 1), 42);
+    assert_eq!(dp.dist(MAXSTOPSNUMB / 10 - 1, 0), 0);
+    assert_eq!(dp.matrix().size(), MAXSTOPSNUMB / 10);
+  }
+
+  #[test]
+  fn test_matrix_size() {
+    let mut stops = test_stops(3);
+    assert_eq!(matrix_size(&stops), Ok(3));
+    stops[1].id = MAXSTOPSNUMB as i64;
+    assert_eq!(matrix_size(&stops).unwrap_err(),
+               format!("Stop id {} exceeds the limit of distance matrix: {}", MAXSTOPSNUMB, MAXSTOPSNUMB));
+  }
+
+  #[test]
+  fn test_cache() {
+    let stops = test_stops(4);
+    let path = std::env::temp_dir().join("kern-test-dist.cache").to_str().unwrap().to_string();
+    let mut dp = Haversine::new(&stops, 30);
+    dp.matrix.set(3, 1, 777);
+    let key = cache_key(&stops, "speed:30");
+    assert!(save_cache(&path, key, &dp.matrix).is_ok());
+    let mut cached = load_cache(&path, key, &stops).unwrap();
+    assert_eq!(cached.get(3, 1), 777);
+    assert_eq!(cached.get(0, 2), dp.dist(0, 2));
+    cached.set(3, 1, 1); // private mapping
+    cached.grow(10); // copied from the map
+    assert_eq!(cached.get(3, 1), 1);
+    assert_eq!(load_cache(&path, key, &stops).unwrap().get(3, 1), 777);
+  }
+
+  #[test]
+  fn test_cache_stale() {
+    let mut stops = test_stops(4);
+    let path = std::env::temp_dir().join("kern-test-dist-stale.cache").to_str().unwrap().to_string();
+    let key = cache_key(&stops, "speed:30");
+    assert!(save_cache(&path, key, &Haversine::new(&stops, 30).matrix).is_ok());
+    assert_ne!(key, cache_key(&stops, "speed:40"));
+    stops[2].latitude = 49.1;
+    let new_key = cache_key(&stops, "speed:30");
+    assert_ne!(key, new_key);
+    assert!(load_cache(&path, new_key, &stops).err().unwrap().contains("is stale"));
+    stops.pop();
+    assert_ne!(key, cache_key(&stops, "speed:30"));
+    write_file("kern-test-dist-stale.cache", b"KDC1");
+    assert!(load_cache(&path, key, &stops).err().unwrap().contains("is not a distance cache"));
+  }
 }
@@ -0,0 +1,146 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Decisions of the dispatcher as events, written to the 'outbox' table in the same transaction as the plan;
+/// Kapir, Kab and Kavla read them in the order of 'seq' instead of polling taxi_order, route and leg
+/// -1 in a column means 'not applicable'
+use serde_json::{json, Value};
+use crate::command::Param;
+use crate::repo::{DbRow, DbValue};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    // cab_id is -1 if the order was put on an existing route (extender), the route knows its cab; eta in minutes
+    OrderAssigned { order_id: i64, route_id: i64, cab_id: i64, eta: i32 },
+    RouteCreated { route_id: i64, cab_id: i64 },
+    // added to an existing route by the extender
+    LegInserted { leg_id: i64, route_id: i64, from: i32, to: i32, place: i32 },
+    // max_assign_time exceeded
+    OrderRefused { order_id: i64 },
+    CabRelocated { cab_id: i64, route_id: i64, to: i32 },
+    // route repair: the customer did not show up at the pickup
+    OrderAbandoned { order_id: i64, route_id: i64 },
+    // route repair: nobody gets in or out at 'from' any more, the leg before goes to 'to'
+    LegRemoved { leg_id: i64, route_id: i64, from: i32, to: i32, place: i32 },
+    // cab breakdown: the route is given up, its waiting customers are dispatched again
+    RouteAbandoned { route_id: i64, cab_id: i64 },
+    OrderReleased { order_id: i64, route_id: i64 }
+}
+
+impl Event {
+    pub fn kind(&self) -> &'static str {
+        return match self {
+            Event::OrderAssigned { .. } => "ORDER_ASSIGNED",
+            Event::RouteCreated { .. } => "ROUTE_CREATED",
+            Event::LegInserted { .. } => "LEG_INSERTED",
+            Event::OrderRefused { .. } => "ORDER_REFUSED",
+            Event::CabRelocated { .. } => "CAB_RELOCATED",
+            Event::OrderAbandoned { .. } => "ORDER_ABANDONED",
+            Event::LegRemoved { .. } => "LEG_REMOVED",
+            Event::RouteAbandoned { .. } => "ROUTE_ABANDONED",
+            Event::OrderReleased { .. } => "ORDER_RELEASED"
+        };
+    }
+
+    // order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta
+    fn columns(&self) -> (i64, i64, i64, i64, i32, i32, i32, i32) {
+        return match *self {
+            Event::OrderAssigned { order_id, route_id, cab_id, eta } => (order_id, route_id, -1, cab_id, -1, -1, -1, eta),
+            Event::RouteCreated { route_id, cab_id } => (-1, route_id, -1, cab_id, -1, -1, -1, -1),
+            Event::LegInserted { leg_id, route_id, from, to, place } => (-1, route_id, leg_id, -1, from, to, place, -1),
+            Event::OrderRefused { order_id } => (order_id, -1, -1, -1, -1, -1, -1, -1),
+            Event::CabRelocated { cab_id, route_id, to } => (-1, route_id, -1, cab_id, -1, to, -1, -1),
+            Event::OrderAbandoned { order_id, route_id } => (order_id, route_id, -1, -1, -1, -1, -1, -1),
+            Event::LegRemoved { leg_id, route_id, from, to, place } => (-1, route_id, leg_id, -1, from, to, place, -1),
+            Event::RouteAbandoned { route_id, cab_id } => (-1, route_id, -1, cab_id, -1, -1, -1, -1),
+            Event::OrderReleased { order_id, route_id } => (order_id, route_id, -1, -1, -1, -1, -1, -1)
+        };
+    }
+
+    // in the order of columns in the INSERT of Command::Emit, after 'seq'
+    pub fn params(&self) -> Vec<Param> {
+        let (order_id, route_id, leg_id, cab_id, from, to, place, eta) = self.columns();
+        return vec![Param::Text(self.kind().to_string()), Param::BigInt(order_id), Param::BigInt(route_id), Param::BigInt(leg_id),
+                    Param::BigInt(cab_id), Param::Int(from), Param::Int(to), Param::Int(place), Param::Int(eta)];
+    }
+
+    // the route the event is about, if any
+    pub fn route_id(&self) -> Option<i64> {
+        return match self {
+            Event::OrderRefused { .. } => None,
+            _ => Some(self.columns().1)
+        };
+    }
+
+    // temporary ids of a plan get real ones like the rest of the plan, see command::resolve_ids
+    pub fn ids_mut(&mut self) -> (Option<&mut i64>, Option<&mut i64>) {
+        return match self {
+            Event::OrderAssigned { route_id, .. } | Event::RouteCreated { route_id, .. }
+                | Event::CabRelocated { route_id, .. } | Event::OrderAbandoned { route_id, .. }
+                | Event::RouteAbandoned { route_id, .. } | Event::OrderReleased { route_id, .. } => (Some(route_id), None),
+            Event::LegInserted { leg_id, route_id, .. } | Event::LegRemoved { leg_id, route_id, .. } => (Some(route_id), Some(leg_id)),
+            Event::OrderRefused { .. } => (None, None)
+        };
+    }
+
+    // a row of 'SELECT seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta FROM outbox'
+    pub fn from_row(r: &DbRow) -> Option<(i64, Event)> {
+        let int = |i: usize| r[i].as_i64().unwrap_or(-1);
+        let kind = match &r[1] { DbValue::Text(x) => x.trim().to_string(), _ => return None };
+        let event = match kind.as_str() {
+            "ORDER_ASSIGNED" => Event::OrderAssigned { order_id: int(2), route_id: int(3), cab_id: int(5), eta: int(9) as i32 },
+            "ROUTE_CREATED" => Event::RouteCreated { route_id: int(3), cab_id: int(5) },
+            "LEG_INSERTED" => Event::LegInserted { leg_id: int(4), route_id: int(3), from: int(6) as i32, to: int(7) as i32,
+                                                   place: int(8) as i32 },
+            "ORDER_REFUSED" => Event::OrderRefused { order_id: int(2) },
+            "CAB_RELOCATED" => Event::CabRelocated { cab_id: int(5), route_id: int(3), to: int(7) as i32 },
+            "ORDER_ABANDONED" => Event::OrderAbandoned { order_id: int(2), route_id: int(3) },
+            "ROUTE_ABANDONED" => Event::RouteAbandoned { route_id: int(3), cab_id: int(5) },
+            "ORDER_RELEASED" => Event::OrderReleased { order_id: int(2), route_id: int(3) },
+            "LEG_REMOVED" => Event::LegRemoved { leg_id: int(4), route_id: int(3), from: int(6) as i32, to: int(7) as i32,
+                                                 place: int(8) as i32 },
+            _ => return None // written by a newer kern
+        };
+        return Some((int(0), event));
+    }
+
+    // one line for consumers that are not written in Rust, 'kern events'
+    pub fn to_json(&self, seq: i64) -> Value {
+        let (order_id, route_id, leg_id, cab_id, from, to, place, eta) = self.columns();
+        return json!({ "seq": seq, "kind": self.kind(), "order_id": order_id, "route_id": route_id, "leg_id": leg_id,
+                        "cab_id": cab_id, "from_stand": from, "to_stand": to, "place": place, "eta": eta });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_row() {
+    let events = vec![
+      Event::OrderAssigned { order_id: 1, route_id: 2, cab_id: 3, eta: 4 },
+      Event::RouteCreated { route_id: 2, cab_id: 3 },
+      Event::LegInserted { leg_id: 5, route_id: 2, from: 6, to: 7, place: 1 },
+      Event::OrderRefused { order_id: 8 },
+      Event::CabRelocated { cab_id: 3, route_id: 2, to: 9 },
+      Event::OrderAbandoned { order_id: 8, route_id: 2 },
+      Event::LegRemoved { leg_id: 5, route_id: 2, from: 6, to: 7, place: 1 },
+      Event::RouteAbandoned { route_id: 2, cab_id: 3 },
+      Event::OrderReleased { order_id: 8, route_id: 2 },
+    ];
+    for (seq, e) in events.iter().enumerate() {
+      // as read from a database, text protocol
+      let mut row: DbRow = vec![DbValue::Int(seq as i64)];
+      row.extend(e.params().iter().map(|p| match p {
+        Param::Text(x) => DbValue::Text(x.clone()),
+        Param::Int(x) => DbValue::Text(x.to_string()),
+        Param::BigInt(x) => DbValue::Text(x.to_string()),
+        _ => DbValue::Null
+      }));
+      assert_eq!(Event::from_row(&row), Some((seq as i64, e.clone())));
+    }
+    assert_eq!(events[0].to_json(7)["kind"], "ORDER_ASSIGNED");
+    assert_eq!(events[3].route_id(), None);
+  }
+}
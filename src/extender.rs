@@ -9,19 +9,15 @@ use std::collections::HashMap; //use std::io::Write;
 use std::{thread, cmp, vec};
 use chrono::{Local, Duration};
 use log::{info, warn, debug};
-//use postgres::{Client, NoTls};
-use mysql::*;
-use mysql::prelude::*;
-use crate::model::{ KernCfg, Leg, Order, OrderStatus, RouteStatus, Stop};
-use crate::repo::{find_legs, assign_order_find_cab, create_leg, update_leg_a_bit2, update_reserves_in_legs_before_and_including,
+use crate::model::{ KernCfg, Leg, Order, OrderStatus, RouteStatus, Stop, StopWait, fits};
+use crate::repo::{Repository, run_sql, assign_order_find_cab, create_leg, legs_inserted, update_leg_a_bit2, update_reserves_in_legs_before_and_including,
                   update_reserves_in_legs_before_and_including2,
-                  update_place_in_legs_after, update_passengers_and_reserve_in_legs_between, update_reserve_after,
-                  find_orders_by_status_and_time};
-use crate::distance::DIST;
+                  update_place_in_legs_after, update_passengers_and_reserve_in_legs_between, update_reserve_after};
+use crate::distance::DistanceProvider;
+use crate::command::Command;
 use crate::utils::get_elapsed;
 
 pub const MAXCOST : i32 = 1000000;
-pub const STOP_WAIT : i16 = 1;
 
 #[derive(Copy, Clone)]
 struct LegIndicesWithDistance2 {
@@ -45,7 +41,7 @@ pub fn bearing_diff(a: i32, b: i32 ) -> f32 {
   return r.abs();
 }
 
-pub fn find_matching_routes(conn: &mut PooledConn, demand: &Vec<Order>, stops: &Vec<Stop>, max_leg_id: &mut i64, cfg: &KernCfg) 
+pub fn find_matching_routes(conn: &mut dyn Repository, demand: &Vec<Order>, stops: &Vec<Stop>, max_leg_id: &mut i64, cfg: &KernCfg, dp: &dyn DistanceProvider) 
                             -> Vec<Order> {
     if demand.len() == 0 {
         return Vec::new();
@@ -53,33 +49,25 @@ pub fn find_matching_routes(conn: &mut PooledConn, demand: &Vec<Order>, stops: &
     let mut demand_cpy = demand.clone();
     let mut ret: Vec<Order> = Vec::new();
     loop {
-      let mut legs: Vec<Leg> = find_legs(conn); // TODO: legs that will soon start should not be taken into consideration !!!
+      let mut legs: Vec<Leg> = conn.find_legs(dp); // TODO: legs that will soon start should not be taken into consideration !!!
       // as we will get customers not picked up ???
       if legs.len() == 0 {
           return demand.to_vec();
       }
-      let ass_orders: Vec<Order> = find_orders_by_status_and_time(conn, OrderStatus::ASSIGNED,
-         (Local::now() - Duration::minutes(30)).naive_local());
+      let ass_orders: Vec<Order> = conn.find_orders_by_status_and_time(OrderStatus::ASSIGNED,
+         (Local::now() - Duration::minutes(30)).naive_local(), dp);
       info!("Extender START, new orders count={} assigned orders={} legs count={}", demand.len(), ass_orders.len(), legs.len());
       let ass_orders_map = assigned_orders(&ass_orders);
 
       let (mut ret_part, missed, sql)
-        = extend_routes(&demand_cpy, &ass_orders_map, stops, &mut legs, max_leg_id, cfg);
+        = extend_routes(&demand_cpy, &ass_orders_map, stops, &mut legs, max_leg_id, cfg, dp);
         
       // EXECUTE SQL !!
       //write_sql_to_file(itr, &sql_bulk, "extender");
       //for s in split_sql(sql_bulk, 150) {
       //  client.batch_execute(&s).unwrap();
       //}
-      if sql.len() > 0 {
-        //debug!("{}", sql_bulk);
-        match conn.query_iter(&sql) {
-          Ok(_) => {} 
-          Err(err) => {
-            warn!("Extender SQL error: {}", err);
-          }
-        }
-      }
+      run_sql(conn, sql, "Extender");
       ret.append(&mut ret_part);
       if missed.len() == 0 { break; }
       demand_cpy = missed;
@@ -141,40 +129,45 @@ pub fn assigned_orders(assigned_orders: &Vec<Order>) -> HashMap<i64, Vec<Order>>
 // 2) orders than cannot go to pool finder because they perfectly match a route - a duplicate that needs another iteration
 // 3) sql to be run 
 fn extend_routes(orders: &Vec<Order>, assigned_orders: &HashMap<i64, Vec<Order>>, stops: &Vec<Stop>, legs: &mut Vec<Leg>, 
-                max_leg_id: &mut i64, cfg: &KernCfg) -> (Vec<Order>, Vec<Order>, String) {
+                max_leg_id: &mut i64, cfg: &KernCfg, dp: &dyn DistanceProvider) -> (Vec<Order>, Vec<Order>, Vec<Command>) {
   let mut t_numb = 10; // mut: there might be one more thread, rest of division
 	let leg_count: HashMap<i64, i8> = count_legs(legs);
+  let stop_wait = StopWait::new(stops, cfg.stop_wait, cfg.board_wait);
   
-	let mut children = vec![];
 
   let mut chunk: i32 = (orders.len() as f32 / t_numb as f32) as i32;
   if chunk == 0 { chunk = 1; } // few orders
   if t_numb * chunk < orders.len() as i32 { t_numb += 1; } // last thread will be the reminder of division
   
   // run the threads, each thread gets its own range of orders to iterate over - hence 'iterate'
-  for i in 0..t_numb {
-    if i * chunk >= orders.len() as i32 { break; }
-    let part =
-      Vec::from_iter(orders[(i*chunk) as usize..if (i+1)*chunk > orders.len() as i32 { orders.len() } else {((i+1)*chunk) as usize}].iter().cloned());
-    let legs_cpy = legs.to_vec();
-    let stops_cpy = stops.to_vec();
-    let leg_count_cpy = leg_count.clone();
-    let old_orders = assigned_orders.clone();
-    let c = cfg.clone();
-    children.push(thread::spawn(move || {
-      iterate(part, &legs_cpy, &stops_cpy, &leg_count_cpy, &old_orders, &c)
-    }));
-  }
-  // collect the data from threads, join their execution first
+  // scoped, as the distance provider is borrowed
   let mut indices : Vec<LegIndicesWithDistance2> = Vec::new();
-  for handle in children {
-    let mut cpy : Vec<LegIndicesWithDistance2> = handle.join().unwrap().to_vec();
-    indices.append(&mut cpy);
-  }
+  thread::scope(|s| {
+    let mut children = vec![];
+    for i in 0..t_numb {
+      if i * chunk >= orders.len() as i32 { break; }
+      let part =
+        Vec::from_iter(orders[(i*chunk) as usize..if (i+1)*chunk > orders.len() as i32 { orders.len() } else {((i+1)*chunk) as usize}].iter().cloned());
+      let legs_cpy = legs.to_vec();
+      let stops_cpy = stops.to_vec();
+      let leg_count_cpy = leg_count.clone();
+      let old_orders = assigned_orders.clone();
+      let c = cfg.clone();
+      let sw = &stop_wait;
+      children.push(s.spawn(move || {
+        iterate(part, &legs_cpy, &stops_cpy, &leg_count_cpy, &old_orders, &c, sw, dp)
+      }));
+    }
+    // collect the data from threads, join their execution first
+    for handle in children {
+      let mut cpy : Vec<LegIndicesWithDistance2> = handle.join().unwrap().to_vec();
+      indices.append(&mut cpy);
+    }
+  });
   // sort - there might be extensions of the same route, we will choose the better one, the worse one will go to next iteration
   indices.sort_by_key(|e| e.dist.clone());
   // get SQL
-  let mut sql: String = String::from("");
+  let mut sql: Vec<Command> = vec![];
   let mut assigned_orders: Vec<i64> = Vec::new();
   let mut missed_orders_for_pool: Vec<Order> = Vec::new();
   let mut missed_orders: Vec<Order> = Vec::new();
@@ -189,7 +182,7 @@ fn extend_routes(orders: &Vec<Order>, assigned_orders: &HashMap<i64, Vec<Order>>
     }
     assigned_orders.push(ind.order.id);
     extended_routes.push(ind.route_id);
-    sql += &get_sql(&ind, max_leg_id, &legs);
+    sql.extend(get_sql(&ind, max_leg_id, &legs, &stop_wait, dp));
   }
 
   for o in orders {
@@ -207,10 +200,10 @@ fn extend_routes(orders: &Vec<Order>, assigned_orders: &HashMap<i64, Vec<Order>>
 }
 
 fn iterate(orders: Vec<Order>, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &HashMap<i64, i8>, 
-            assigned_orders: &HashMap<i64, Vec<Order>>, cfg: &KernCfg) -> Vec<LegIndicesWithDistance2> {
+            assigned_orders: &HashMap<i64, Vec<Order>>, cfg: &KernCfg, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Vec<LegIndicesWithDistance2> {
   let mut ret : Vec<LegIndicesWithDistance2> = Vec::new();
   for o in orders {
-    match find_route(&o, legs, stops, leg_count, assigned_orders, cfg) {
+    match find_route(&o, legs, stops, leg_count, assigned_orders, cfg, stop_wait, dp) {
       Some(x) => { ret.push(x); },
       None => {}
     }
@@ -227,18 +220,17 @@ fn leg_is_short(val: Option<&i8>, max_legs: i8) -> bool {
 
 // iterate over all existing routes and find the one that will be least distracted (additional path is shortest)
 fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &HashMap<i64, i8>,
-              assigned_orders: &HashMap<i64, Vec<Order>>, cfg: &KernCfg) -> Option<LegIndicesWithDistance2> {
-  unsafe {
-  if legs.len() == 0 { return None; }
+              assigned_orders: &HashMap<i64, Vec<Order>>, cfg: &KernCfg, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Option<LegIndicesWithDistance2> {
+  if legs.len() == 0 || !order.shared { return None; } // the customer does not want to share the cab
   let mut ret: Option<LegIndicesWithDistance2> = None;
   let mut i: usize = 1; // index of pickup TODO: i=0 has to be considered one day
   let mut total_dist: i32;
   if legs[0].status == RouteStatus::STARTED {
     let mut on_the_way = get_elapsed(legs[0].started) as i32;
     if on_the_way == -1 { on_the_way = 0; }
-    total_dist = cmp::max(0, legs[0].dist - on_the_way/60) + STOP_WAIT as i32;
+    total_dist = cmp::max(0, legs[0].dist - on_the_way) + stop_wait.at(legs[0].to as usize) as i32;
   } else {
-    total_dist = legs[0].dist + 2*STOP_WAIT as i32; // distance from the begining of a route; well, only the remaining legs
+    total_dist = legs[0].dist + (stop_wait.at(legs[0].from as usize) + stop_wait.at(legs[0].to as usize)) as i32; // distance from the begining of a route; well, only the remaining legs
   }
   let mut min_cost: i32 = MAXCOST; // added cost of the winner, we are starting with a looser
   let mut is_short = leg_is_short(leg_count.get(&legs[i].route_id), cfg.max_legs);
@@ -253,14 +245,16 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
     if leg.route_id != legs[i-1].route_id { // new route -> check the previous one
       first_leg = i;
       let prev_leg_to = legs[i-1].to as usize;
-      let dist1 = DIST[prev_leg_to][order_from] as i32;
+      let dist1 = dp.dist(prev_leg_to, order_from) as i32;
       is_short = leg_is_short(leg_count.get(&leg.route_id), cfg.max_legs);
       // check beyond route
       if total_dist + dist1 + extra_wait(wait_legs + 1) < order.wait
+         && order.passengers <= legs[i-1].seats // the cab is empty after the route, but a group may still not fit
+         && fits(legs[i-1].features, order.required)
          && dist1 < min_cost
          && (dist1 > max_angle_dist || bearing_diff(stops[prev_leg_to].bearing, stops[order_from].bearing) <  max_angle) { // well, we have to compare to something; there still might be a better plan with lesser wait time
         min_cost = dist1;
-        ret = get_some(i, i, legs[i-1].route_id, STOP_WAIT as i32 + dist1, 
+        ret = get_some(i, i, legs[i-1].route_id, stop_wait.at(prev_leg_to) as i32 + dist1, 
                       total_dist + dist1+ extra_wait(wait_legs), 
                       order.dist, 0, order);
         debug!("DEBUG3C find_route: order_id={}, route_id={}, total_dist={}, dist1={}, wait_legs={}", 
@@ -272,14 +266,14 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
       if leg.status == RouteStatus::STARTED { // but we need such legs to avoid assigning legs that very soon will start (little chance to let know the driver)
         let mut on_the_way = get_elapsed(leg.started) as i32;
         if on_the_way == -1 { on_the_way = 0; }
-        total_dist += cmp::max(0, leg.dist - on_the_way/60) + STOP_WAIT as i32;
+        total_dist += cmp::max(0, leg.dist - on_the_way) + stop_wait.at(leg.to as usize) as i32;
         wait_legs += 1;
         i += 1;
         continue; 
       }
       // if there is too many non-pickedup customers, uncomment the below, which mean do not assign a leg which is about to start soon
       if leg.status == RouteStatus::ASSIGNED {
-        total_dist += leg.dist + 2*STOP_WAIT as i32;
+        total_dist += leg.dist + (stop_wait.at(leg.from as usize) + stop_wait.at(leg.to as usize)) as i32;
         wait_legs += 1;
         i += 1;
         continue; 
@@ -288,15 +282,16 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
     if leg.status == RouteStatus::STARTED { // this should never happen, the same check is above when new route is found 
       let mut on_the_way = get_elapsed(leg.started) as i32;
       if on_the_way == -1 { on_the_way = 0; }
-      total_dist += cmp::max(0, leg.dist - on_the_way/60) + STOP_WAIT as i32;
+      total_dist += cmp::max(0, leg.dist - on_the_way) + stop_wait.at(leg.to as usize) as i32;
       i += 1;
       continue; 
     }
-    let mut add_cost: i32 = (DIST[leg.from as usize][order_from] + STOP_WAIT + DIST[order_from][leg.to as usize]) as i32
+    let mut add_cost: i32 = (dp.dist(leg.from as usize, order_from) + stop_wait.between(order_from, leg.to as usize, order.passengers as i16) + dp.dist(order_from, leg.to as usize)) as i32
                             - leg.dist;
     if leg.to != order.from // direct hit in next leg
-      && leg.passengers < leg.seats // 'seats' come from 'cab' table; < means at least one seat available, = would mean all occupied 
-      && (total_dist + (DIST[leg.from as usize][order_from]) as i32) + extra_wait(wait_legs) <= order.wait 
+      && leg.passengers + order.passengers <= leg.seats // 'seats' come from 'cab' table; a group needs as many seats free
+      && fits(leg.features, order.required) // a wheelchair needs a cab with a ramp, whatever the cost
+      && (total_dist + (dp.dist(leg.from as usize, order_from)) as i32) + extra_wait(wait_legs) <= order.wait 
       && (leg.from == order.from // direct hit
             || (is_short // we don't want to extend long routes
                 && 
@@ -307,10 +302,10 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
       // 3 possibilities - in the same leg or in next ones, or direct hit at leg.to
       // firstly null cost if direct hit
       if leg.from == order.from {
-        add_cost = 0;
+        add_cost = stop_wait.boarding(order.passengers as i16) as i32; // the cab stops there anyway
       }
       if leg.to == order.to { // direct hit for drop-off in the same leg, and no detour
-        if leg.from == order.from { // bingo, no point looking for any other route (TODO: check number of seats!)
+        if leg.from == order.from { // bingo, no point looking for any other route
           //info!("Extension proposal, perfect match, order_id={}, route_id={}", order.id, leg.route_id);
           return get_some( i, i, leg.route_id, 0, total_dist + extra_wait(wait_legs), 
                           order.dist, 0, order);
@@ -318,18 +313,18 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
           // SAVE1 no leg at all, both are direct hits // check to-to & from-from
         } else if is_short {
           // SAVE2 // pickup was not a direct hit  // two legs affected   // from-from will fail, pickup is expanded
-          if !wait_exceeded(order, wait_legs, first_leg, i, i, total_dist, add_cost, 0, legs, assigned_orders)
-             && (DIST[leg.from as usize][order_from] > max_angle_dist as i16 || bearing_diff(stops[leg.from as usize].bearing, stops[order_from].bearing) <  max_angle) {
+          if !wait_exceeded(order, wait_legs, first_leg, i, i, total_dist, add_cost, 0, legs, assigned_orders, stop_wait)
+             && (dp.dist(leg.from as usize, order_from) > max_angle_dist as i16 || bearing_diff(stops[leg.from as usize].bearing, stops[order_from].bearing) <  max_angle) {
             min_cost = add_cost;
             ret = get_some(i, i, leg.route_id, add_cost, 
-                         total_dist + STOP_WAIT as i32 + (DIST[leg.from as usize][order_from] as i32) + extra_wait(wait_legs),
+                         total_dist + stop_wait.at(leg.from as usize) as i32 + (dp.dist(leg.from as usize, order_from) as i32) + extra_wait(wait_legs),
                           order.dist, 0, order);
           }
         }
       } else { // find in next legs
         match find_droppoff(order, legs, first_leg, i, add_cost, min_cost, 
-                      total_dist + (DIST[leg.from as usize][order_from] as i32) + extra_wait(wait_legs), wait_legs,
-                            is_short, assigned_orders, stops, &cfg) {
+                      total_dist + (dp.dist(leg.from as usize, order_from) as i32) + extra_wait(wait_legs), wait_legs,
+                            is_short, assigned_orders, stops, &cfg, stop_wait, dp) {
           Some(x) => { 
             min_cost = x.dist; 
             ret = Some(x); 
@@ -338,7 +333,7 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
         }
       }
     } 
-    total_dist += leg.dist + STOP_WAIT as i32;
+    total_dist += leg.dist + stop_wait.at(leg.to as usize) as i32;
     wait_legs += 1;
     if total_dist + extra_wait(wait_legs) > order.wait { // nothing to look for here, find next route
       let mut i2 = i + 1;
@@ -349,15 +344,16 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
     }
   }
   // beyond the last route
-  let last_dist = total_dist + STOP_WAIT as i32 + (DIST[legs[i-1].to as usize][order_from] as i32) + extra_wait(wait_legs);
+  let last_dist = total_dist + stop_wait.at(legs[i-1].to as usize) as i32 + (dp.dist(legs[i-1].to as usize, order_from) as i32) + extra_wait(wait_legs);
   if last_dist < order.wait
-    && (DIST[legs[i-1].to as usize][order_from] as i32) < min_cost { // well, we have to compare to something; there still might be a better plan with lesser wait time
+    && order.passengers <= legs[i-1].seats && fits(legs[i-1].features, order.required)
+    && (dp.dist(legs[i-1].to as usize, order_from) as i32) < min_cost { // well, we have to compare to something; there still might be a better plan with lesser wait time
     // SAVE6
     //info!("Extension proposal, beyond route, order_id={}, route_id={}", order.id, legs[i-1].route_id);
     debug!("DEBUG6 find_route: order_id={}, route_id={}, leg_id={}, leg_dist={}, leg_reserve={}, from={}, to={}, dist={},", 
           order.id, legs[i-1].route_id, legs[i-1].id, legs[i-1].dist, legs[i-1].reserve, legs[i-1].to, order.from, 
-          DIST[legs[i-1].to as usize][order_from]);
-    return get_some( i, i, legs[i-1].route_id, DIST[legs[i-1].to as usize][order_from] as i32,
+          dp.dist(legs[i-1].to as usize, order_from));
+    return get_some( i, i, legs[i-1].route_id, dp.dist(legs[i-1].to as usize, order_from) as i32,
                     last_dist, order.dist, 0, order);
   }
   if min_cost == MAXCOST {
@@ -366,14 +362,14 @@ fn find_route(order: &Order, legs: &Vec<Leg>, stops: &Vec<Stop>, leg_count: &Has
   //info!("Extension proposal, order_id={}, route_id={}, cost={}", order.id, ret.unwrap().route_id, ret.unwrap().dist);
   return ret;
 }
-}
 
-// for unknown reason cabs wait about 30s more than defined one minute
+// for unknown reason cabs wait about 30s more than defined one minute, in seconds
 fn extra_wait(count: i16) -> i32 { 
-  return (count as f32 * 0.5) as i32;
+  return count as i32 * 30;
 }
 
-fn wait_exceeded(ord: &Order, wait_legs: i16, first_leg: usize, i: usize, j:usize, wait: i32, add_cost: i32, add_cost2: i32, legs: &Vec<Leg>, ass_orders: &HashMap<i64, Vec<Order>>) -> bool {
+fn wait_exceeded(ord: &Order, wait_legs: i16, first_leg: usize, i: usize, j:usize, wait: i32, add_cost: i32, add_cost2: i32, legs: &Vec<Leg>, ass_orders: &HashMap<i64, Vec<Order>>,
+                 stop_wait: &StopWait) -> bool {
   if i>= legs.len() { return false; }
   let add2_cost = if add_cost2 < 0 { 0 } else { add_cost2 };
   let route_id = legs[i].route_id;
@@ -386,7 +382,7 @@ fn wait_exceeded(ord: &Order, wait_legs: i16, first_leg: usize, i: usize, j:usiz
   let mut log = format!("wait_debug: order_id={}, leg_from={}, leg_to={}, route_id={}, wait={}, wait_legs={}, add_cost={}, add_cost2={} legs={}, orders={}; ", 
                                 ord.id, if i< legs.len() { legs[i].id } else { -1 }, if j<legs.len() { legs[j].id } else { -1 }, 
                                 route_id, wait, wait_legs, add_cost, add_cost2, legs.len(), orders.len());
-  let mut total_dist = wait + legs[i].dist + STOP_WAIT as i32 + if add_cost < 0 { 0 } else { add_cost }  ;
+  let mut total_dist = wait + legs[i].dist + stop_wait.at(legs[i].to as usize) as i32 + if add_cost < 0 { 0 } else { add_cost }  ;
   if j == i { total_dist += add2_cost; }
   let mut idx = i + 1; // we will check the impact on wait of the other customers, beyond the extended leg (i)
   let mut passed_log: String = String::new();
@@ -399,17 +395,17 @@ fn wait_exceeded(ord: &Order, wait_legs: i16, first_leg: usize, i: usize, j:usiz
       if o.id == ord.id { // wait time in this order is checked before this function is called
         continue;
       }
-      let time_passed = get_elapsed(o.received)/60; // TODO: we do not have assignment timestamp
+      let time_passed = get_elapsed(o.received); // TODO: we do not have assignment timestamp
       if time_passed == -1 { // TODO it should never happen!!
         warn!("Assigned order but received is NULL");
         continue;
       }
       passed_log += &format!("[order_id={}, passed={}, total_dist={}, extra_wait={}], ", o.id, time_passed, total_dist, xtra_wait);
-      if o.from == legs[idx].from && time_passed as i32 + total_dist + xtra_wait >= o.wait - STOP_WAIT as i32 { // - STOP_WAIT due to some rounding errors - eg. while subtracting elapsed time
+      if o.from == legs[idx].from && time_passed as i32 + total_dist + xtra_wait >= o.wait - stop_wait.at(legs[idx].from as usize) as i32 { // - stop wait due to some rounding errors - eg. while subtracting elapsed time
         return true;
       }
     }
-    total_dist += legs[idx].dist + STOP_WAIT as i32;
+    total_dist += legs[idx].dist + stop_wait.at(legs[idx].to as usize) as i32;
     if idx == j { total_dist += add2_cost; } 
     log += &format!("[dist after leg={}, dist={}], ", legs[idx].id, total_dist);
     idx += 1;
@@ -426,7 +422,7 @@ fn wait_exceeded(ord: &Order, wait_legs: i16, first_leg: usize, i: usize, j:usiz
     if o.id == ord.id { // wait time in this order is checked before this function is called
       continue;
     }
-    let dist_with_loss: i32 = ((1.0 + o.loss as f32 / 100.0) * o.dist as f32).round() as i32 + 3*STOP_WAIT as i32; //+ stop so that we are not so strict
+    let dist_with_loss: i32 = ((1.0 + o.loss as f32 / 100.0) * o.dist as f32).round() as i32 + 3*stop_wait.at(o.to as usize) as i32; //+ stop so that we are not so strict
     let mut legs_count = 0; // to count extra_wait
     total_dist = add_cost; // just in case the order has allready started (no "from"), so pickup extension will affect this order 
     idx = first_leg; // where the route starts
@@ -454,10 +450,10 @@ fn wait_exceeded(ord: &Order, wait_legs: i16, first_leg: usize, i: usize, j:usiz
           // we would have to check 'started'of that order
           return true; // one of old orders would not like it
         }
-        passed_log += &format!("[order_id={}, total_dist={}, extra_wait={}, dist_with_loss={}], ", o.id, total_dist, extra_wait(legs_count), dist_with_loss - 3*STOP_WAIT as i32);
+        passed_log += &format!("[order_id={}, total_dist={}, extra_wait={}, dist_with_loss={}], ", o.id, total_dist, extra_wait(legs_count), dist_with_loss - 3*stop_wait.at(o.to as usize) as i32);
         break; // check next order
       }
-      total_dist += legs[idx].dist + STOP_WAIT as i32;
+      total_dist += legs[idx].dist + stop_wait.at(legs[idx].to as usize) as i32;
       legs_count += 1;
       idx += 1;
     }
@@ -481,8 +477,7 @@ fn get_some(from: usize, to: usize, route_id: i64, cost: i32, wait: i32, tour: i
 }
 
 fn find_droppoff(order: &Order, legs: &Vec<Leg>, first_leg: usize, i: usize, add_cost: i32, mincost: i32, wait: i32, wait_legs: i16, 
-                is_short: bool, assigned_orders: &HashMap<i64, Vec<Order>>, stops: &Vec<Stop>, cfg: &KernCfg) -> Option<LegIndicesWithDistance2> {
-  unsafe {
+                is_short: bool, assigned_orders: &HashMap<i64, Vec<Order>>, stops: &Vec<Stop>, cfg: &KernCfg, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Option<LegIndicesWithDistance2> {
   let mut ret: Option<LegIndicesWithDistance2> = None;
   let max_angle = cfg.max_angle as f32;
   let max_angle_dist = cfg.max_angle_dist as i32;
@@ -490,12 +485,12 @@ fn find_droppoff(order: &Order, legs: &Vec<Leg>, first_leg: usize, i: usize, add
   let mut min: i32 = mincost;
   let dist_with_loss: i32 = ((1.0 + order.loss as f32 / 100.0) * order.dist as f32).round() as i32;
   let order_to = order.to as usize;
-  let mut add2_cost = (DIST[legs[i].from as usize][order.from as usize] + STOP_WAIT + DIST[order.from as usize][order_to] 
-                            + STOP_WAIT + DIST[order_to][legs[i].to as usize]) as i32 - legs[i].dist;
+  let mut add2_cost = (dp.dist(legs[i].from as usize, order.from as usize) + stop_wait.between(order.from as usize, order_to, order.passengers as i16) + dp.dist(order.from as usize, order_to) 
+                            + stop_wait.at(order_to) + dp.dist(order_to, legs[i].to as usize)) as i32 - legs[i].dist;
   // first check the same leg as pickup                        
   if is_short && 
       add2_cost <= legs[i].reserve && add_cost + add2_cost < min 
-      && !wait_exceeded(order, wait_legs, first_leg, i, i, wait, add_cost, add2_cost, legs, assigned_orders) { // still no detour loss
+      && !wait_exceeded(order, wait_legs, first_leg, i, i, wait, add_cost, add2_cost, legs, assigned_orders, stop_wait) { // still no detour loss
 
     min = add_cost + add2_cost;
     ret = get_some(i, i, legs[i].route_id, add_cost + add2_cost, wait, order.dist, legs[i].reserve, order);
@@ -509,30 +504,30 @@ fn find_droppoff(order: &Order, legs: &Vec<Leg>, first_leg: usize, i: usize, add
     //}
   }
   // but it might be a better plan in next legs of the route
-  let mut tour: i32 = DIST[order.from as usize][legs[i].to as usize] as i32 + STOP_WAIT as i32; // it is valid even if direct hit
+  let mut tour: i32 = dp.dist(order.from as usize, legs[i].to as usize) as i32 + stop_wait.at(legs[i].to as usize) as i32; // it is valid even if direct hit
   let mut sum_reserve: i32 = cmp::max(0, legs[i].reserve - add_cost);
 
   while j < legs.len() && legs[j].route_id == legs[j-1].route_id {
     let leg = legs[j];
     let leg_from = leg.from as usize;
-    if leg.passengers >= leg.seats { // a leg in between pickup and dropoff is unacceptable
+    if leg.passengers + order.passengers > leg.seats { // a full leg in between pickup and dropoff is unacceptable
       return ret;
     }
-    add2_cost = (DIST[leg_from][order_to] + STOP_WAIT + DIST[order_to][leg.to as usize]) as i32 - leg.dist;
+    add2_cost = (dp.dist(leg_from, order_to) + stop_wait.at(order_to) + dp.dist(order_to, leg.to as usize)) as i32 - leg.dist;
     if (((leg.to == order.to && tour + leg.dist <= dist_with_loss)) // direct hit, no extra cost
         || (is_short
             && 
             add2_cost < leg.reserve
-            && tour + (DIST[leg_from][order_to] as i32) + extra_wait((j-i) as i16) <= dist_with_loss // TODO: (j-i) is a misterious delay each leg, to be analysed, some delay in Kim?
+            && tour + (dp.dist(leg_from, order_to) as i32) + extra_wait((j-i) as i16) <= dist_with_loss // TODO: (j-i) is a misterious delay each leg, to be analysed, some delay in Kim?
             && add_cost + add2_cost < min)
-            && (DIST[leg_from][order_to] > max_angle_dist as i16 || bearing_diff(stops[leg_from].bearing, stops[order_to].bearing) <  max_angle) )
-        && !wait_exceeded(order, wait_legs + ((j - i) as i16), first_leg, i, j, wait, add_cost, add2_cost, legs, assigned_orders) {
+            && (dp.dist(leg_from, order_to) > max_angle_dist as i16 || bearing_diff(stops[leg_from].bearing, stops[order_to].bearing) <  max_angle) )
+        && !wait_exceeded(order, wait_legs + ((j - i) as i16), first_leg, i, j, wait, add_cost, add2_cost, legs, assigned_orders, stop_wait) {
       min = add_cost + add2_cost;
       ret = get_some( i, j, legs[i].route_id, add_cost + add2_cost, wait, 
-                      tour + (DIST[leg_from][order_to] as i32) + extra_wait((j-i) as i16), sum_reserve, order); // tour is later used to count reserve (reserve=dist*loss-tour), but beware, if not used in some other way!
+                      tour + (dp.dist(leg_from, order_to) as i32) + extra_wait((j-i) as i16), sum_reserve, order); // tour is later used to count reserve (reserve=dist*loss-tour), but beware, if not used in some other way!
       debug!("DEBUG4 dropp-off: order_id={}, route_id={}, leg_id={}, leg_dist={}, wait={}, leg_reserve={}, from={}, to={}, add2_cost={}, a={}, b={}, 1={}, 2={}, 3={}", 
           order.id, legs[i].route_id, leg.id, leg.dist, wait, leg.reserve, leg.from, leg.to, add2_cost, 
-          DIST[leg_from][order_to], DIST[order_to][leg.to as usize],
+          dp.dist(leg_from, order_to), dp.dist(order_to, leg.to as usize),
           leg.from, order.to, leg.to);
       // SAVE4
       //if legs[i].from == order.from { // pickup direct hit
@@ -542,7 +537,7 @@ fn find_droppoff(order: &Order, legs: &Vec<Leg>, first_leg: usize, i: usize, add
       // four legs
       //}
     }
-    tour += leg.dist + STOP_WAIT as i32;
+    tour += leg.dist + stop_wait.at(leg.to as usize) as i32;
     sum_reserve += leg.reserve;
     if tour + extra_wait((j-i) as i16) <= dist_with_loss { 
       return ret; // nothing to look after any more
@@ -551,12 +546,12 @@ fn find_droppoff(order: &Order, legs: &Vec<Leg>, first_leg: usize, i: usize, add
   }
   // what if dropoff extends beyond the route?
   if //j > 1 && legs[j-2].route_id == legs[j-1].route_id 
-    tour + (DIST[legs[j-1].to as usize][order_to] as i32) + extra_wait((j-i) as i16) < dist_with_loss 
+    tour + (dp.dist(legs[j-1].to as usize, order_to) as i32) + extra_wait((j-i) as i16) < dist_with_loss 
         && add_cost < min 
-        && !wait_exceeded(order, wait_legs + ((j - i) as i16), first_leg, i, j, wait, add_cost, add2_cost, legs, assigned_orders) 
-        && (DIST[legs[j-1].to as usize][order_to] > max_angle_dist as i16 || bearing_diff(stops[legs[j-1].to as usize].bearing, stops[order_to].bearing) <  max_angle) { // we don't ruin the current route so we just take the pickup cost, but you might think otherwise
+        && !wait_exceeded(order, wait_legs + ((j - i) as i16), first_leg, i, j, wait, add_cost, add2_cost, legs, assigned_orders, stop_wait) 
+        && (dp.dist(legs[j-1].to as usize, order_to) > max_angle_dist as i16 || bearing_diff(stops[legs[j-1].to as usize].bearing, stops[order_to].bearing) <  max_angle) { // we don't ruin the current route so we just take the pickup cost, but you might think otherwise
     ret = get_some(i, j, legs[i].route_id, add_cost, wait, 
-                  tour + (DIST[legs[j-1].to as usize][order_to] as i32) + extra_wait((j-i) as i16), sum_reserve, order);
+                  tour + (dp.dist(legs[j-1].to as usize, order_to) as i32) + extra_wait((j-i) as i16), sum_reserve, order);
     debug!("DEBUG dropp-off beyond: order_id={}, leg_id={}, to={}", order.id, legs[j-1].id, legs[j-1].to);             
     // SAVE5
     // !!! necessary check j>legs.len() || route_id != route_id, which means beyond route
@@ -568,163 +563,162 @@ fn find_droppoff(order: &Order, legs: &Vec<Leg>, first_leg: usize, i: usize, add
   }
   return ret;
 }
-}
 
-fn get_sql(f: &LegIndicesWithDistance2, max_leg_id: &mut i64, legs: &Vec<Leg>) -> String {
-  unsafe {
+fn get_sql(f: &LegIndicesWithDistance2, max_leg_id: &mut i64, legs: &Vec<Leg>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Vec<Command> {
   let mut prev_leg: Leg = legs[f.idx_from - 1];
   let reserve = cmp::max(0, f.order.wait - f.wait);
   // reserves before changed leg have to satisfy the current order and (!) the added cost will affect wait time of orders that start after the extension
   let detour_reserve = cmp::max(0, (((100.0 + f.order.loss as f32) / 100.0) * f.order.dist as f32) as i32 - f.tour);
-  let mut sql: String = String::from("");
-  sql += &assign_order_find_cab(f.order.id,
+  let mut sql: Vec<Command> = vec![];
+  sql.extend(assign_order_find_cab(f.order.id,
                         if f.idx_from >= legs.len() || f.route_id != legs[f.idx_from].route_id { -1 } else { legs[f.idx_from].id }, 
-                                f.route_id, f.wait, "true", "expander");
+                                f.route_id, f.wait, true, "expander"));
   // extension totally BEYOND a route, including pickup
   if f.idx_from >= legs.len() // beyond the last route in the list, here we do not have route_id
      || f.route_id != legs[f.idx_from].route_id {  // beyond a route inside the list
     // SAVE0, SAVE6
     // it will be both pick-up and drop-off
     if prev_leg.to == f.order.from { // direct hit
-      sql += &update_reserves_in_legs_before_and_including(prev_leg.route_id, prev_leg.place, reserve); 
-      sql += &create_leg(f.order.id, 
+      sql.extend(update_reserves_in_legs_before_and_including(prev_leg.route_id, prev_leg.place, reserve)); 
+      sql.extend(create_leg(f.order.id, 
         f.order.from,
         f.order.to,
         prev_leg.place + 1,
         RouteStatus::ASSIGNED,
-        f.order.dist as i16,
+        f.order.dist,
         detour_reserve,
         prev_leg.route_id as i64, 
         max_leg_id, // incremented inside
-        1, 
-        &("route extender SAVE0A".to_string()));
+        f.order.passengers, 
+        &("route extender SAVE0A".to_string())));
     } else { // not a direct hit
-      sql += &update_reserves_in_legs_before_and_including(prev_leg.route_id, prev_leg.place, reserve); 
-      sql += &create_leg(-1,  // ??
+      sql.extend(update_reserves_in_legs_before_and_including(prev_leg.route_id, prev_leg.place, reserve)); 
+      sql.extend(create_leg(-1,  // ??
         prev_leg.to,
         f.order.from,
         prev_leg.place + 1,
         RouteStatus::ASSIGNED,
-        DIST[prev_leg.to as usize][f.order.from as usize],
+        dp.dist(prev_leg.to as usize, f.order.from as usize) as i32,
         reserve,
         prev_leg.route_id as i64, 
         max_leg_id, // incremented inside
         0, 
-        &("route extender SAVE0B".to_string()));
-      sql += &create_leg(f.order.id, 
+        &("route extender SAVE0B".to_string())));
+      sql.extend(create_leg(f.order.id, 
         f.order.from,
         f.order.to,
         prev_leg.place + 2,
         RouteStatus::ASSIGNED,
-        f.order.dist as i16,
+        f.order.dist,
         detour_reserve,
         prev_leg.route_id as i64, 
         max_leg_id,
-        1, 
-        &("route extender SAVE0C".to_string()));      
+        f.order.passengers, 
+        &("route extender SAVE0C".to_string())));      
         debug!("SAVE0C: route_id={}, res: {}, wait: {}", f.route_id, reserve, f.wait);
     }
   } else { // inside, at least pickup
     let leg_pick = legs[f.idx_from];
-    sql += &update_reserves_in_legs_before_and_including2(leg_pick.route_id, leg_pick.place -1, reserve, f.dist);
+    sql.extend(update_reserves_in_legs_before_and_including2(leg_pick.route_id, leg_pick.place -1, reserve, f.dist));
     
     if f.idx_from == f.idx_to  { // one leg will be extended, 4 situations here
-      let resrv = cmp::max(0, cmp::min(leg_pick.reserve, detour_reserve) - STOP_WAIT as i32);
+      let resrv = cmp::max(0, cmp::min(leg_pick.reserve, detour_reserve) - stop_wait.at(f.order.to as usize) as i32);
       // first adjust reserves after the leg as extension (3 of 4 cases below) will affect wait time
-      sql += &update_reserve_after(leg_pick.route_id, f.dist, leg_pick.place+1);
+      sql.extend(update_reserve_after(leg_pick.route_id, f.dist, leg_pick.place+1));
 
       if leg_pick.from == f.order.from && legs[f.idx_to].to == f.order.to { // matches perfectly
         // SAVE 1
-        sql += &update_passengers_and_reserve_in_legs_between(leg_pick.route_id, detour_reserve, leg_pick.place, leg_pick.place); // one leg to be updated                                                    
+        sql.extend(update_passengers_and_reserve_in_legs_between(leg_pick.route_id, f.order.passengers, detour_reserve, leg_pick.place, leg_pick.place)); // one leg to be updated                                                    
       } else if leg_pick.from == f.order.from { // only pickup matches
         // SAVE 3
-        //sql += &update_passengers_and_reserve_in_legs_between(leg_pick.route_id, resrv, leg_pick.place + 1, 100); // 100: all after +1
-        sql += &update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1);
-        let len_diff: i32 = (DIST[f.order.to as usize][leg_pick.to as usize] + f.order.dist as i16 + STOP_WAIT) as i32 - leg_pick.dist;
-        sql += &create_leg(f.order.id, 
+        //sql.extend(update_passengers_and_reserve_in_legs_between(leg_pick.route_id, f.order.passengers, resrv, leg_pick.place + 1, 100)); // 100: all after +1
+        sql.extend(update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1));
+        let len_diff: i32 = (dp.dist(f.order.to as usize, leg_pick.to as usize) + f.order.dist as i16 + stop_wait.at(f.order.to as usize) + stop_wait.boarding(f.order.passengers as i16)) as i32 - leg_pick.dist;
+        sql.extend(create_leg(f.order.id, 
           f.order.to, // well, a leg after drop-off will be assigned to the order, not quite awesome
           leg_pick.to,
           leg_pick.place + 1,
           RouteStatus::ASSIGNED,
-          DIST[f.order.to as usize][leg_pick.to as usize],
+          dp.dist(f.order.to as usize, leg_pick.to as usize) as i32,
           cmp::max(0, leg_pick.reserve - len_diff), // 'dist' contains added cost/length
           leg_pick.route_id as i64, 
           max_leg_id,
-          leg_pick.passengers as i8, 
-          &("route extender SAVE3".to_string()));
+          leg_pick.passengers, 
+          &("route extender SAVE3".to_string())));
         // the extended leg should point at the new leg added above
-        sql += &update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.to, 
-                f.order.dist as i16, cmp::min(resrv, len_diff), leg_pick.passengers as i8 +1); // MIN because reserve in 2 legs <= reserve in one leg; len_diff = reserv - (reserv - len_diff)
+        sql.extend(update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.to, 
+                f.order.dist, cmp::min(resrv, len_diff), leg_pick.passengers + f.order.passengers)); // MIN because reserve in 2 legs <= reserve in one leg; len_diff = reserv - (reserv - len_diff)
       } else if legs[f.idx_to].to == f.order.to { // only drop-off matches
         // SAVE 3
-        sql += &update_passengers_and_reserve_in_legs_between(leg_pick.route_id, resrv, leg_pick.place + 1, 100); // 100: all after +1
-        sql += &update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1);
-        sql += &create_leg(f.order.id, 
+        sql.extend(update_passengers_and_reserve_in_legs_between(leg_pick.route_id, f.order.passengers, resrv, leg_pick.place + 1, 100)); // 100: all after +1
+        sql.extend(update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1));
+        sql.extend(create_leg(f.order.id, 
           f.order.from,
           leg_pick.to, // == order.to
           leg_pick.place + 1,
           RouteStatus::ASSIGNED,
-          f.order.dist as i16,
+          f.order.dist,
           resrv,
           leg_pick.route_id as i64, 
           max_leg_id,
-          leg_pick.passengers as i8 + 1, 
-          &("route extender SAVE3B".to_string()));
+          leg_pick.passengers + f.order.passengers, 
+          &("route extender SAVE3B".to_string())));
         // the new leg above will have a smaller reserv than the extended leg, but how much smaller - what will be the reserve of the existing leg?
         // it will be cmp::min(leg_pick.reserve - resrv, leg_pick.reserve - len_diff)
         // but to spare one subtraction let's find the max first
-        let len_diff: i32 = (f.order.dist + DIST[leg_pick.from as usize][f.order.from as usize] as i32 + STOP_WAIT as i32) - leg_pick.dist;
+        let len_diff: i32 = (f.order.dist + dp.dist(leg_pick.from as usize, f.order.from as usize) as i32
+                             + stop_wait.between(f.order.from as usize, leg_pick.to as usize, f.order.passengers as i16) as i32) - leg_pick.dist;
         let reserve_subtr = cmp::max(resrv, len_diff);
 
         // the extended leg should point at the new leg added above
-        sql += &update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.from, 
-                 DIST[leg_pick.from as usize][f.order.from as usize], 
+        sql.extend(update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.from, 
+                 dp.dist(leg_pick.from as usize, f.order.from as usize) as i32, 
                  cmp::max(0, cmp::min(leg_pick.reserve - reserve_subtr, f.order.wait - f.wait)), // yes, wait time has to be taken into acount too
-                 leg_pick.passengers as i8);
+                 leg_pick.passengers));
       } else { // no match, the order will extend one leg
-        sql += &update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1); // TODO: one call, not two
-        sql += &update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1);
-        let added_cost = (DIST[leg_pick.from as usize][f.order.from as usize] + STOP_WAIT + DIST[f.order.from as usize][f.order.to as usize] 
-                              + STOP_WAIT + DIST[f.order.to as usize][leg_pick.to as usize]) as i32 + extra_wait(2) - leg_pick.dist;
-        sql += &create_leg(f.order.id, 
+        sql.extend(update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1)); // TODO: one call, not two
+        sql.extend(update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1));
+        let added_cost = (dp.dist(leg_pick.from as usize, f.order.from as usize) + stop_wait.between(f.order.from as usize, f.order.to as usize, f.order.passengers as i16)
+                              + dp.dist(f.order.from as usize, f.order.to as usize) + stop_wait.at(f.order.to as usize) + dp.dist(f.order.to as usize, leg_pick.to as usize)) as i32 + extra_wait(2) - leg_pick.dist;
+        sql.extend(create_leg(f.order.id, 
           f.order.from,
           f.order.to,
           leg_pick.place + 1,
           RouteStatus::ASSIGNED,
-          f.order.dist as i16,
+          f.order.dist,
           // 3 things - current reserve (other orders), reserve for the new order and how the new order affects the old ones
           cmp::max(0, cmp::min(leg_pick.reserve - added_cost, detour_reserve)),
           leg_pick.route_id as i64, 
           max_leg_id,
-          leg_pick.passengers as i8 + 1, 
-          &("route extender SAVE3C".to_string()));
+          leg_pick.passengers + f.order.passengers, 
+          &("route extender SAVE3C".to_string())));
         // like in SAVE3B, here the reserve has to be split in 3 (!) legs
-        let len_diff: i32 = (f.order.dist + DIST[f.order.to as usize][leg_pick.to as usize] as i32 + STOP_WAIT as i32) - leg_pick.dist;
+        let len_diff: i32 = (f.order.dist + dp.dist(f.order.to as usize, leg_pick.to as usize) as i32 + stop_wait.at(f.order.to as usize) as i32) - leg_pick.dist;
         let reserve_subtr = cmp::max(resrv, len_diff);
         let reserve2 = cmp::min(leg_pick.reserve - reserve_subtr, f.order.wait - f.wait - f.sum_reserve);
         // beyond the new order, detour of this order is not needed
-        sql += &create_leg(f.order.id, 
+        sql.extend(create_leg(f.order.id, 
           f.order.to,
           leg_pick.to, // == order.to
           leg_pick.place + 2,
           RouteStatus::ASSIGNED,
-          DIST[f.order.to as usize][leg_pick.to as usize],
+          dp.dist(f.order.to as usize, leg_pick.to as usize) as i32,
           cmp::min(cmp::min(resrv, f.sum_reserve),  reserve2),
           leg_pick.route_id as i64, 
           max_leg_id,
-          leg_pick.passengers as i8, 
-          &("route extender SAVE3C".to_string()));  
+          leg_pick.passengers, 
+          &("route extender SAVE3C".to_string())));  
         // the extended leg should point at the new leg added above
-        sql += &update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.from, 
-                 DIST[leg_pick.from as usize][f.order.from as usize], 
+        sql.extend(update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.from, 
+                 dp.dist(leg_pick.from as usize, f.order.from as usize) as i32, 
                  cmp::max(0, cmp::min(leg_pick.reserve - added_cost, f.order.wait - f.wait)), // leg_pick.reserve - reserve_subtr - reserve2
-                 leg_pick.passengers as i8);
+                 leg_pick.passengers));
       }
     } else { // more legs to be extended, possibly
       // !!! we have to update reserves in bettwen pickup and drop-off (see at the end) and after, before are updated above
       if f.idx_to < legs.len() // beyond the last route in the list, here we do not have route_id
           && f.route_id == legs[f.idx_to].route_id {
-        sql += &update_reserve_after(leg_pick.route_id, f.dist, legs[f.idx_to].place+1);
+        sql.extend(update_reserve_after(leg_pick.route_id, f.dist, legs[f.idx_to].place+1));
       }
       let place_start: i32;
       // PICK-UP first
@@ -735,28 +729,29 @@ fn get_sql(f: &LegIndicesWithDistance2, max_leg_id: &mut i64, legs: &Vec<Leg>) -
         // SAVE 4B
         place_start = leg_pick.place + 1;
              // we have to increment 'place' before drop-off INSERTs  
-        sql += &update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1);
-        let len_diff: i32 = (DIST[leg_pick.from as usize][f.order.from as usize] + STOP_WAIT + DIST[f.order.from as usize][leg_pick.to as usize]) as i32 - leg_pick.dist;
+        sql.extend(update_place_in_legs_after(leg_pick.route_id, leg_pick.place + 1));
+        let len_diff: i32 = (dp.dist(leg_pick.from as usize, f.order.from as usize) + stop_wait.between(f.order.from as usize, leg_pick.to as usize, f.order.passengers as i16)
+                             + dp.dist(f.order.from as usize, leg_pick.to as usize)) as i32 - leg_pick.dist;
         let res = cmp::max(0, cmp::min(detour_reserve, leg_pick.reserve - len_diff));
-        sql += &create_leg(f.order.id, 
+        sql.extend(create_leg(f.order.id, 
           f.order.from,
           leg_pick.to,
           leg_pick.place + 1,
           RouteStatus::ASSIGNED,
-          DIST[f.order.from as usize][leg_pick.to as usize] as i16,
+          dp.dist(f.order.from as usize, leg_pick.to as usize) as i32,
           res,
           leg_pick.route_id as i64, 
           max_leg_id,
-          leg_pick.passengers as i8 + 1, 
-          &("route extender SAVE4B".to_string()));
+          leg_pick.passengers + f.order.passengers, 
+          &("route extender SAVE4B".to_string())));
         debug!("SAVE4B: route_id={}, wait:{}, detour_res:{}, res:{}", f.route_id, f.wait, detour_reserve, res);
         // the extended leg should point at the new leg added above
         let res = cmp::max(0, cmp::min(res, leg_pick.reserve - res)); // sum of the two legs (reserve) cannot be bigger than the original leg 
-        sql += &update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.from, 
-                            DIST[leg_pick.from as usize][f.order.from as usize],
+        sql.extend(update_leg_a_bit2(leg_pick.route_id, leg_pick.id, f.order.from, 
+                            dp.dist(leg_pick.from as usize, f.order.from as usize) as i32,
                             // previous version: leg_pick.reserve - len_diff
                             cmp::max(0, cmp::min(f.order.wait - f.wait, leg_pick.reserve - res)), // -res, to subtract reserve ffrom the leg above
-                            leg_pick.passengers as i8);
+                            leg_pick.passengers));
       }
       // DROP-OFF
       let place_incr = if place_start > leg_pick.place { 2 } else { 1 };
@@ -765,17 +760,17 @@ fn get_sql(f: &LegIndicesWithDistance2, max_leg_id: &mut i64, legs: &Vec<Leg>) -
           || f.route_id != legs[f.idx_to].route_id { // we know that there is no perfect match for 'to', it would be the last leg in a route
         prev_leg = legs[f.idx_to -1];
         debug!("SAVE5: route_id: {}, detour_res: {}, sum_reserve: {}", f.route_id, detour_reserve, f.sum_reserve);
-        sql += &create_leg(-1,  // ??
+        sql.extend(create_leg(-1,  // ??
           prev_leg.to,
           f.order.to,
           prev_leg.place + place_incr, 
           RouteStatus::ASSIGNED,
-          DIST[prev_leg.to as usize][f.order.to as usize],
+          dp.dist(prev_leg.to as usize, f.order.to as usize) as i32,
           cmp::max(0, detour_reserve - f.sum_reserve), // reserve in all legs cannot be bigger than dist-tour 
           prev_leg.route_id as i64, 
           max_leg_id,
-          1, 
-          &("route extender SAVE5".to_string()));
+          f.order.passengers, 
+          &("route extender SAVE5".to_string())));
       } else { // one of existing legs
         let leg = legs[f.idx_to];
         if leg.to == f.order.to {
@@ -785,26 +780,26 @@ fn get_sql(f: &LegIndicesWithDistance2, max_leg_id: &mut i64, legs: &Vec<Leg>) -
           // but if there was INSERT, then both extended legs are updated already, we have to start from +2
         } else {
           // SAVE 4
-          sql += &update_place_in_legs_after(leg_pick.route_id, leg.place + place_incr);
-          let len_diff: i32 = (DIST[leg.from as usize][f.order.to as usize] + STOP_WAIT + DIST[f.order.to as usize][leg.to as usize]) as i32 - leg.dist;
+          sql.extend(update_place_in_legs_after(leg_pick.route_id, leg.place + place_incr));
+          let len_diff: i32 = (dp.dist(leg.from as usize, f.order.to as usize) + stop_wait.at(f.order.to as usize) + dp.dist(f.order.to as usize, leg.to as usize)) as i32 - leg.dist;
           let reserve1 = cmp::max(0, cmp::min(detour_reserve, leg.reserve - len_diff - f.sum_reserve));
-          sql += &create_leg(-1, 
+          sql.extend(create_leg(-1, 
             f.order.to,
             leg.to,
             leg.place + place_incr,
             RouteStatus::ASSIGNED,
-            DIST[f.order.to as usize][leg.to as usize],
+            dp.dist(f.order.to as usize, leg.to as usize) as i32,
             reserve1,
             leg_pick.route_id as i64, 
             max_leg_id,
-            leg.passengers as i8, 
-            &("route extender SAVE4C".to_string()));
+            leg.passengers, 
+            &("route extender SAVE4C".to_string())));
           // the extended leg should point at the new leg added above
           let reserve_subtr = cmp::min(leg.reserve - detour_reserve, leg.reserve - reserve1); // how much reserve is left for the other leg
-          sql += &update_leg_a_bit2(leg.route_id, leg.id, f.order.to, 
-            DIST[leg.from as usize][f.order.to as usize],
+          sql.extend(update_leg_a_bit2(leg.route_id, leg.id, f.order.to, 
+            dp.dist(leg.from as usize, f.order.to as usize) as i32,
             cmp::max(0, cmp::min(reserve_subtr, detour_reserve)), // reserve - detour: sum of reserver in 2 legs cannot be bigger than leg.reserve
-            leg.passengers as i8 + 1);
+            leg.passengers + f.order.passengers));
         }
       }
       if f.route_id == leg_pick.route_id { // at least pickup is in an existing leg 
@@ -817,59 +812,29 @@ fn get_sql(f: &LegIndicesWithDistance2, max_leg_id: &mut i64, legs: &Vec<Leg>) -
         } else { 
           place_stop = legs[f.idx_to -1 ].place;
         }
-        sql += &update_passengers_and_reserve_in_legs_between(leg_pick.route_id, cmp::max(0, detour_reserve),
-                                                  place_start, place_stop); // TODO: SQL without BETWEEN!
+        sql.extend(update_passengers_and_reserve_in_legs_between(leg_pick.route_id, f.order.passengers, cmp::max(0, detour_reserve),
+                                                  place_start, place_stop)); // TODO: SQL without BETWEEN!
       }
     }
   }
-  return sql.to_string();
-}
-}
-
-pub fn get_handle(conn_str: String, sql: String, label: String)  -> thread::JoinHandle<()> {
-  return thread::spawn(move || {
-    if sql.len() > 0 {
-      let pool = Pool::new(conn_str.as_str());
-      match pool {
-        Ok(p) => {
-          let conn = p.get_conn();
-          match conn {
-            Ok(mut c) => {
-              let res = c.query_iter(sql);
-              match res {
-                Ok(_) => {}
-                Err(err) => {
-                  panic!("Could not run SQL batch: {}, err:{}", &label, err);
-                }
-              }
-            }
-            Err(err) => {
-              panic!("Could not connect to MySQL: {}, err:{}", &label, err);
-            }
-          }
-        }
-        Err(err) => {
-          panic!("Could not get pool to MySQL: {}, err:{}", &label, err);
-        }
-      }
-    }
-  });
+  let events = legs_inserted(&sql);
+  sql.extend(events);
+  return sql;
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::distance::init_distance;
+  use crate::distance::{Haversine, MemoryMatrix};
+  use crate::command::to_sql;
   use serial_test::serial;
 
-  fn get_test_legs() -> Vec<Leg> {
-    unsafe {
+  fn get_test_legs(dp: &dyn DistanceProvider) -> Vec<Leg> {
     return vec![
-      Leg{ id: 0, route_id: 123, from: 0, to: 1, place: 0, dist: DIST[0][1] as i32, reserve:1, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-      Leg{ id: 1, route_id: 123, from: 1, to: 2, place: 1, dist: DIST[1][2] as i32, reserve:2, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-      Leg{ id: 2, route_id: 123, from: 2, to: 3, place: 2, dist: DIST[2][3] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
+      Leg{ id: 0, route_id: 123, from: 0, to: 1, place: 0, dist: dp.dist(0, 1) as i32, reserve:60, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+      Leg{ id: 1, route_id: 123, from: 1, to: 2, place: 1, dist: dp.dist(1, 2) as i32, reserve:120, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+      Leg{ id: 2, route_id: 123, from: 2, to: 3, place: 2, dist: dp.dist(2, 3) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
     ];
-    }
   }
   /*
   INSERT INTO leg (id, route_id, from_stand, to_stand, place, distance, reserve, passengers) VALUES (0,123,0,1,0,1,1,1);
@@ -877,15 +842,13 @@ mod tests {
   INSERT INTO leg (id, route_id, from_stand, to_stand, place, distance, reserve, passengers) VALUES (2,123,2,3,2,1,3,1);
    */
 
-  fn get_test_legs2() -> Vec<Leg> {
-    unsafe {
+  fn get_test_legs2(dp: &dyn DistanceProvider) -> Vec<Leg> {
     return vec![
-      Leg{ id: 3, route_id: 124, from: 4, to: 5, place: 0, dist: DIST[4][5] as i32, reserve:1, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-      Leg{ id: 0, route_id: 123, from: 0, to: 2, place: 0, dist: DIST[0][2] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-      Leg{ id: 1, route_id: 123, from: 2, to: 4, place: 1, dist: DIST[2][4] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-      Leg{ id: 2, route_id: 123, from: 4, to: 5, place: 2, dist: DIST[4][5] as i32, reserve:6, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
+      Leg{ id: 3, route_id: 124, from: 4, to: 5, place: 0, dist: dp.dist(4, 5) as i32, reserve:60, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+      Leg{ id: 0, route_id: 123, from: 0, to: 2, place: 0, dist: dp.dist(0, 2) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+      Leg{ id: 1, route_id: 123, from: 2, to: 4, place: 1, dist: dp.dist(2, 4) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+      Leg{ id: 2, route_id: 123, from: 4, to: 5, place: 2, dist: dp.dist(4, 5) as i32, reserve:360, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
     ];
-    }
   }
   /*
   INSERT INTO leg (id, route_id, from_stand, to_stand, place, distance, reserve, passengers) VALUES (3,124,4,5,0,1,1,1);
@@ -897,22 +860,22 @@ mod tests {
 
   fn get_stops() -> Vec<Stop> {
     return vec![
-      Stop{ id: 0, bearing: 0, latitude: 49.0, longitude: 19.000, capacity: 10},
-      Stop{ id: 1, bearing: 0, latitude: 49.0, longitude: 19.025, capacity: 10},
-      Stop{ id: 2, bearing: 0, latitude: 49.0, longitude: 19.050, capacity: 10},
-      Stop{ id: 3, bearing: 0, latitude: 49.0, longitude: 19.075, capacity: 10},
-      Stop{ id: 4, bearing: 0, latitude: 49.0, longitude: 19.100, capacity: 10},
-      Stop{ id: 5, bearing: 0, latitude: 49.0, longitude: 19.125, capacity: 10}
+      Stop{ id: 0, bearing: 0, latitude: 49.0, longitude: 19.000, capacity: 10, dwell: 60},
+      Stop{ id: 1, bearing: 0, latitude: 49.0, longitude: 19.025, capacity: 10, dwell: 60},
+      Stop{ id: 2, bearing: 0, latitude: 49.0, longitude: 19.050, capacity: 10, dwell: 60},
+      Stop{ id: 3, bearing: 0, latitude: 49.0, longitude: 19.075, capacity: 10, dwell: 60},
+      Stop{ id: 4, bearing: 0, latitude: 49.0, longitude: 19.100, capacity: 10, dwell: 60},
+      Stop{ id: 5, bearing: 0, latitude: 49.0, longitude: 19.125, capacity: 10, dwell: 60}
     ];
   }
 
   fn test_find_route(from_stand: i32, to_stand: i32, from_idx: usize, to_idx: usize) {
-    init_distance(&get_stops(), 30);
-    let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                              dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32}, 
-                              received: None, at_time: None, route_id: -1 };
-    match find_route(&order1, &mut get_test_legs(), &mut get_stops(), 
-                    &HashMap::new(), &HashMap::new(), &KernCfg::new()) {
+    let dp = &Haversine::new(&get_stops(), 30);
+    let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 900, loss:90, 
+                              dist:dp.dist(from_stand as usize, to_stand as usize) as i32, 
+                              passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+    match find_route(&order1, &mut get_test_legs(dp), &mut get_stops(), 
+                    &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp) {
       Some(x) => {
         assert_eq!(x.route_id, 123);
         assert_eq!(x.idx_from, from_idx);
@@ -925,13 +888,13 @@ mod tests {
   }
 
   fn test_find_route2(from_stand: i32, to_stand: i32, from_idx: usize, to_idx: usize, route_id: i64) {
-    init_distance(&get_stops(), 30);
-    let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                              dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32},
-                              received: None, at_time: None, route_id: -1  };
-    let mut legs = get_test_legs2();
+    let dp = &Haversine::new(&get_stops(), 30);
+    let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90, 
+                              dist:dp.dist(from_stand as usize, to_stand as usize) as i32,
+                              passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1  };
+    let mut legs = get_test_legs2(dp);
     match find_route(&order1, &mut legs, &mut get_stops(), &HashMap::new(), 
-                &HashMap::new(), &KernCfg::new()) {
+                &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp) {
       Some(x) => {
         assert_eq!(x.route_id, route_id);
         assert_eq!(x.idx_from, from_idx);
@@ -945,24 +908,24 @@ mod tests {
 
   fn test_extend_legs_sql(from_stand: i32, to_stand: i32, expected_sql: &str) {
     let mut max_leg_id: &mut i64 = &mut 10;
-    init_distance(&get_stops(), 30);
-    let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                                      dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32}, 
-      received: None, at_time: None, route_id: -1 }];
+    let dp = &Haversine::new(&get_stops(), 30);
+    let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 900, loss:90, 
+                                      dist:dp.dist(from_stand as usize, to_stand as usize) as i32, 
+      passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 }];
     let (_ret, _, sql) = extend_routes(&orders, &HashMap::new(),  &get_stops(),
-                                                       &mut get_test_legs(), &mut max_leg_id, &KernCfg::new());
-    assert_eq!(sql, expected_sql);
+                                                       &mut get_test_legs(dp), &mut max_leg_id, &KernCfg::new(), dp);
+    assert_eq!(to_sql(&sql), expected_sql);
   }
 
   fn test_extend_legs_sql2(from_stand: i32, to_stand: i32, expected_sql: &str) {
     let mut max_leg_id: &mut i64 = &mut 10;
-    init_distance(&get_stops(), 30);
-    let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                                      dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32},
-      received: None, at_time: None, route_id: -1 }];
+    let dp = &Haversine::new(&get_stops(), 30);
+    let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90, 
+                                      dist:dp.dist(from_stand as usize, to_stand as usize) as i32,
+      passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 }];
     let (_ret, _, sql) = extend_routes(&orders, &HashMap::new(), &get_stops(),
-                                                         &mut get_test_legs2(), &mut max_leg_id, &KernCfg::new());
-    assert_eq!(sql, expected_sql);
+                                                         &mut get_test_legs2(dp), &mut max_leg_id, &KernCfg::new(), dp);
+    assert_eq!(to_sql(&sql), expected_sql);
   }
 
   // PERFECT MATCH
@@ -976,7 +939,7 @@ mod tests {
   #[serial]
   fn test_extend_legs_in_db_returns_sql() {
     test_extend_legs_sql(1,3, 
-      "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=5, in_pool=true WHERE id=1 AND status=0;\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 5) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place >= 3;\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 6) WHERE route_id=123 AND place BETWEEN 1 AND 2;\n");
+      "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=6, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'ORDER_ASSIGNED',1,123,-1,-1,-1,-1,-1,6);\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 9) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place >= 3;\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 5) WHERE route_id=123 AND place BETWEEN 1 AND 2;\n");
   }
 
   // request from stops in between, both 'from' and 'to'
@@ -1035,7 +998,7 @@ fn test_find_route_nonperfect_match4() {
 fn test_extend_legs_in_db_returns_sql5() {
   // leg_id=1 ???
   test_extend_legs_sql2(2,3, 
-    "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=9, in_pool=true WHERE id=1 AND status=0;\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 1) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place >= 2;\nUPDATE leg SET place=place+1 WHERE route_id=123 AND place >= 2;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (10,3,4,2,3,1,5,123,1);\nUPDATE leg SET to_stand=3, distance=3, reserve=0, passengers=2 WHERE id=1;\n");
+    "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=10, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'ORDER_ASSIGNED',1,123,-1,-1,-1,-1,-1,10);\nUPDATE leg SET reserve=GREATEST(0, reserve-2) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 0) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-2) WHERE route_id=123 AND place >= 2;\nUPDATE leg SET place=place+1 WHERE route_id=123 AND place >= 2;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (10,3,4,2,4,1,4,123,1);\nUPDATE leg SET to_stand=3, distance=4, reserve=1, passengers=2 WHERE id=1 AND route_id=123 AND status IN (1,5);\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'LEG_INSERTED',-1,123,10,-1,3,4,2,-1);\n");
 }
 
 // only drop-off is perfect match - same legs
@@ -1056,22 +1019,20 @@ fn test_extend_legs_in_db_returns_sql_5_b() {
 
 // pick-up & drop-off extending the same leg
 
-fn get_test_legs4() -> Vec<Leg> {
-  unsafe {
+fn get_test_legs4(dp: &dyn DistanceProvider) -> Vec<Leg> {
   return vec![
-    Leg{ id: 0, route_id: 123, from: 0, to: 2, place: 0, dist: DIST[0][2] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 1, route_id: 123, from: 2, to: 5, place: 1, dist: DIST[2][5] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
+    Leg{ id: 0, route_id: 123, from: 0, to: 2, place: 0, dist: dp.dist(0, 2) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 1, route_id: 123, from: 2, to: 5, place: 1, dist: dp.dist(2, 5) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
   ];
-  }
 }
 
 fn test_find_route4(route_id: i64, from_stand: i32, to_stand: i32, from_idx: usize, to_idx: usize) {
-  init_distance(&get_stops(), 30);
-  let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                            dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32}, 
-                            received: None, at_time: None, route_id: -1 };
-  match find_route(&order1, &mut get_test_legs4(), &mut get_stops(), 
-                    &HashMap::new(), &HashMap::new(), &KernCfg::new()) {
+  let dp = &Haversine::new(&get_stops(), 30);
+  let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90, 
+                            dist:dp.dist(from_stand as usize, to_stand as usize) as i32, 
+                            passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  match find_route(&order1, &mut get_test_legs4(dp), &mut get_stops(), 
+                    &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp) {
     Some(x) => {
       assert_eq!(x.route_id, route_id);
       assert_eq!(x.idx_from, from_idx);
@@ -1090,13 +1051,13 @@ fn test_find_route_extending_one_leg() {
 
 fn test_extend_legs_sql4(from_stand: i32, to_stand: i32, expected_sql: &str) {
   let mut max_leg_id: &mut i64 = &mut 10;
-  init_distance(&get_stops(), 30);
-  let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                                    dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32}, 
-    received: None, at_time: None, route_id: -1 }];
+  let dp = &Haversine::new(&get_stops(), 30);
+  let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90, 
+                                    dist:dp.dist(from_stand as usize, to_stand as usize) as i32, 
+    passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 }];
   let (_ret, _, sql) = extend_routes(&orders, &HashMap::new(), &get_stops(),
-                                                       &mut get_test_legs4(), &mut max_leg_id, &KernCfg::new());
-  assert_eq!(sql, expected_sql);
+                                                       &mut get_test_legs4(dp), &mut max_leg_id, &KernCfg::new(), dp);
+  assert_eq!(to_sql(&sql), expected_sql);
 }
 
 #[test]
@@ -1117,7 +1078,7 @@ fn test_find_route_nonperfect_match5() {
 #[serial]
 fn test_extend_legs_in_db_returns_sql6() {
   test_extend_legs_sql(2,4,  
-    "UPDATE taxi_order SET route_id=123, leg_id=2, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=9, in_pool=true WHERE id=1 AND status=0;\nUPDATE leg SET reserve=GREATEST(0, reserve-0) WHERE route_id=123 AND place <= 1;\nUPDATE leg SET reserve=LEAST(reserve, 1) WHERE route_id=123 AND place <= 1;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (10,3,4,3,3,1,3,123,1);\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 6) WHERE route_id=123 AND place BETWEEN 2 AND 2;\n");
+    "UPDATE taxi_order SET route_id=123, leg_id=2, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=11, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'ORDER_ASSIGNED',1,123,-1,-1,-1,-1,-1,11);\nUPDATE leg SET reserve=GREATEST(0, reserve-0) WHERE route_id=123 AND place <= 1;\nUPDATE leg SET reserve=LEAST(reserve, 4) WHERE route_id=123 AND place <= 1;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (10,3,4,3,4,1,2,123,1);\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 5) WHERE route_id=123 AND place BETWEEN 2 AND 2;\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'LEG_INSERTED',-1,123,10,-1,3,4,3,-1);\n");
 }
 
 // both pickup and drop-off beyond current legs
@@ -1136,30 +1097,28 @@ fn test_extend_legs_in_db_returns_sql7() {
 }
 
 // now two matching routes, one is better
-fn get_test_legs3() -> Vec<Leg> {
-  unsafe {
+fn get_test_legs3(dp: &dyn DistanceProvider) -> Vec<Leg> {
   return vec![
-    Leg{ id: 100, route_id: 124, from: 4, to: 5, place: 0, dist: DIST[4][5] as i32, reserve:1, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 0, route_id: 123, from: 0, to: 2, place: 0, dist: DIST[0][2] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 1, route_id: 123, from: 2, to: 4, place: 1, dist: DIST[2][4] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 2, route_id: 123, from: 4, to: 5, place: 2, dist: DIST[4][5] as i32, reserve:6, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 3, route_id: 125, from: 0, to: 1, place: 0, dist: DIST[0][1] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 4, route_id: 125, from: 1, to: 2, place: 1, dist: DIST[1][2] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 5, route_id: 125, from: 2, to: 3, place: 2, dist: DIST[2][3] as i32, reserve:6, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 6, route_id: 126, from: 0, to: 1, place: 0, dist: DIST[0][1] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 7, route_id: 126, from: 1, to: 4, place: 1, dist: DIST[1][4] as i32, reserve:6, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 8, route_id: 126, from: 4, to: 5, place: 2, dist: DIST[4][5] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
+    Leg{ id: 100, route_id: 124, from: 4, to: 5, place: 0, dist: dp.dist(4, 5) as i32, reserve:60, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 0, route_id: 123, from: 0, to: 2, place: 0, dist: dp.dist(0, 2) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 1, route_id: 123, from: 2, to: 4, place: 1, dist: dp.dist(2, 4) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 2, route_id: 123, from: 4, to: 5, place: 2, dist: dp.dist(4, 5) as i32, reserve:360, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 3, route_id: 125, from: 0, to: 1, place: 0, dist: dp.dist(0, 1) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 4, route_id: 125, from: 1, to: 2, place: 1, dist: dp.dist(1, 2) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 5, route_id: 125, from: 2, to: 3, place: 2, dist: dp.dist(2, 3) as i32, reserve:360, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 6, route_id: 126, from: 0, to: 1, place: 0, dist: dp.dist(0, 1) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 7, route_id: 126, from: 1, to: 4, place: 1, dist: dp.dist(1, 4) as i32, reserve:360, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 8, route_id: 126, from: 4, to: 5, place: 2, dist: dp.dist(4, 5) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
   ];
-  }
 }
 
 fn test_find_route3(route_id: i64, from_stand: i32, to_stand: i32, from_idx: usize, to_idx: usize) {
-  init_distance(&get_stops(), 30);
-  let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, 
-                            dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32}, 
-                            received: None, at_time: None, route_id: -1 };
-  match find_route(&order1, &mut get_test_legs3(), &mut get_stops(), 
-                    &HashMap::new(), &HashMap::new(), &KernCfg::new()) {
+  let dp = &Haversine::new(&get_stops(), 30);
+  let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90, 
+                            dist:dp.dist(from_stand as usize, to_stand as usize) as i32, 
+                            passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  match find_route(&order1, &mut get_test_legs3(dp), &mut get_stops(), 
+                    &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp) {
     Some(x) => {
       assert_eq!(x.route_id, route_id);
       assert_eq!(x.idx_from, from_idx);
@@ -1180,13 +1139,13 @@ fn test_find_route_more_matching_routes() {
 
 fn test_extend_legs_no_match(from_stand: i32, to_stand: i32) {
   let mut max_leg_id: &mut i64 = &mut 10;
-  init_distance(&get_stops(), 30);
-  let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 1, loss:1, 
-                                    dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32}, 
-    received: None,  at_time: None, route_id: -1 }];
+  let dp = &Haversine::new(&get_stops(), 30);
+  let orders = vec![Order { id: 1, from: from_stand, to: to_stand, wait: 60, loss:1, 
+                                    dist:dp.dist(from_stand as usize, to_stand as usize) as i32, 
+    passengers: 1, required: 0, shared: true, transfer: false, received: None,  at_time: None, released: None, route_id: -1 }];
   let (ret, _, sql) = extend_routes(&orders, &HashMap::new(), &get_stops(),
-                                                       &mut get_test_legs2(), &mut max_leg_id, &KernCfg::new());
-  assert_eq!(sql, "");
+                                                       &mut get_test_legs2(dp), &mut max_leg_id, &KernCfg::new(), dp);
+  assert_eq!(sql.len(), 0);
   assert_eq!(ret.len(), 1);
 }
 
@@ -1199,15 +1158,15 @@ fn test_extend_legs_in_db_returns_no_sql() {
 // test of two identical orders - only one should go thru
 fn test_extend_legs_identical_orders(from_stand: i32, to_stand: i32) {
   let mut max_leg_id: &mut i64 = &mut 10;
-  init_distance(&get_stops(), 30);
+  let dp = &Haversine::new(&get_stops(), 30);
   let orders = vec![
-    Order { id: 1, from: from_stand, to: to_stand, wait: 10, loss:90, dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32},
-            received: None,  at_time: None,  route_id: -1 },
-    Order { id: 2, from: from_stand, to: to_stand, wait: 10, loss:90, dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32},
-             received: None,  at_time: None,  route_id: -1 }];
+    Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90, dist:dp.dist(from_stand as usize, to_stand as usize) as i32,
+            passengers: 1, required: 0, shared: true, transfer: false, received: None,  at_time: None, released: None,  route_id: -1 },
+    Order { id: 2, from: from_stand, to: to_stand, wait: 600, loss:90, dist:dp.dist(from_stand as usize, to_stand as usize) as i32,
+             passengers: 1, required: 0, shared: true, transfer: false, received: None,  at_time: None, released: None,  route_id: -1 }];
   let (ret, _, sql) = extend_routes(&orders, &HashMap::new(), &get_stops(),
-                                                       &mut get_test_legs2(), &mut max_leg_id, &KernCfg::new());
-  assert_eq!(sql, "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=9, in_pool=true WHERE id=1 AND status=0;\nUPDATE leg SET reserve=GREATEST(0, reserve-0) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 1) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-0) WHERE route_id=123 AND place >= 2;\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 6) WHERE route_id=123 AND place BETWEEN 1 AND 1;\n");
+                                                       &mut get_test_legs2(dp), &mut max_leg_id, &KernCfg::new(), dp);
+  assert_eq!(to_sql(&sql), "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=10, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'ORDER_ASSIGNED',1,123,-1,-1,-1,-1,-1,10);\nUPDATE leg SET reserve=GREATEST(0, reserve-0) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 0) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-0) WHERE route_id=123 AND place >= 2;\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 6) WHERE route_id=123 AND place BETWEEN 1 AND 1;\n");
   assert_eq!(ret.len(), 0); // nothing should go to pool finder, one order should be allocated by extender at next iteration 
 }
 
@@ -1217,24 +1176,22 @@ fn test_extend_legs_two_identical_orders() {
 }
 
 // testing wait time
-fn get_test_legs5() -> Vec<Leg> {
-  unsafe {
+fn get_test_legs5(dp: &dyn DistanceProvider) -> Vec<Leg> {
   return vec![
-    Leg{ id: 0, route_id: 123, from: 0, to: 1, place: 0, dist: DIST[0][1] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 1, route_id: 123, from: 1, to: 2, place: 1, dist: DIST[1][2] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 2, route_id: 123, from: 2, to: 3, place: 2, dist: DIST[2][3] as i32, reserve:6, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 3, route_id: 123, from: 3, to: 4, place: 0, dist: DIST[3][4] as i32, reserve:3, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
-    Leg{ id: 4, route_id: 123, from: 4, to: 5, place: 1, dist: DIST[4][5] as i32, reserve:5, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10},
+    Leg{ id: 0, route_id: 123, from: 0, to: 1, place: 0, dist: dp.dist(0, 1) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 1, route_id: 123, from: 1, to: 2, place: 1, dist: dp.dist(1, 2) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 2, route_id: 123, from: 2, to: 3, place: 2, dist: dp.dist(2, 3) as i32, reserve:360, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 3, route_id: 123, from: 3, to: 4, place: 0, dist: dp.dist(3, 4) as i32, reserve:180, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
+    Leg{ id: 4, route_id: 123, from: 4, to: 5, place: 1, dist: dp.dist(4, 5) as i32, reserve:300, started: None, status: RouteStatus::ASSIGNED, passengers:1, seats: 10, features: 0},
   ];
-  }
 }
 
 fn test_find_route_wait_time_exceeded(from_stand: i32, to_stand: i32) {
-  init_distance(&get_stops(), 30);
-  let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 5, loss:90, 
-                            dist:unsafe{DIST[from_stand as usize][to_stand as usize] as i32},  
-                            received: None, at_time: None,  route_id: -1 };
-    assert!(find_route(&order1, &mut get_test_legs5(), &mut get_stops(), &HashMap::new(), &HashMap::new(), &KernCfg::new()).is_none());
+  let dp = &Haversine::new(&get_stops(), 30);
+  let order1: Order = Order { id: 1, from: from_stand, to: to_stand, wait: 300, loss:90, 
+                            dist:dp.dist(from_stand as usize, to_stand as usize) as i32,  
+                            passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None,  route_id: -1 };
+    assert!(find_route(&order1, &mut get_test_legs5(dp), &mut get_stops(), &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp).is_none());
 }
 
 #[test]
@@ -1246,47 +1203,174 @@ fn test_find_route_wait_time() {
 #[test]
 #[serial]
 fn test_wait_exceed_no_assigned_orders_then_false() {
-  let o = Order { id: 1, from: 4, to: 5, wait: 5, loss:90, 
-    dist:unsafe{DIST[4][5] as i32}, 
-    received: None, at_time: None,  route_id: 12 };
+  let dp = &Haversine::new(&get_stops(), 30);
+  let o = Order { id: 1, from: 4, to: 5, wait: 300, loss:90, 
+    dist:dp.dist(4, 5) as i32, 
+    passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None,  route_id: 12 };
   let ass_orders = vec![o];
   let ass_orders_map = assigned_orders(&ass_orders);  
-  let ret = wait_exceeded(&o, 0, 0, 1, 2, unsafe{DIST[4][5] as i32}, 1, 1, &get_test_legs5(), &ass_orders_map);
+  let ret = wait_exceeded(&o, 0, 0, 1, 2, dp.dist(4, 5) as i32, 1, 1, &get_test_legs5(dp), &ass_orders_map, &StopWait::same(60));
   assert!(!ret);
 }
 
 #[test]
 #[serial]
 fn test_wait_exceed_assigned_order_and_too_long_then_true() {
-  init_distance(&get_stops(), 30);
-  let o = Order { id: 1, from: 4, to: 5, wait: 5, loss:90, 
-    dist:unsafe{DIST[4][5] as i32}, 
-    received: Local::now().naive_local().checked_sub_signed(chrono::Duration::seconds(3*60)), // ! three minutes are enough to exceed the wait time
-     at_time: None,  route_id: 123 };
-  let o2 = Order { id: 12345, from: 4, to: 5, wait: 5, loss:90, 
-    dist:unsafe{DIST[4][5] as i32}, 
-    received: Some(Local::now().naive_local()),
-     at_time: None, route_id: 123 };
+  let dp = &Haversine::new(&get_stops(), 30);
+  let o = Order { id: 1, from: 4, to: 5, wait: 300, loss:90, 
+    dist:dp.dist(4, 5) as i32, 
+    passengers: 1, required: 0, shared: true, transfer: false, received: Local::now().naive_local().checked_sub_signed(chrono::Duration::seconds(3*60)), // ! three minutes are enough to exceed the wait time
+     at_time: None, released: None,  route_id: 123 };
+  let o2 = Order { id: 12345, from: 4, to: 5, wait: 300, loss:90, 
+    dist:dp.dist(4, 5) as i32, 
+    passengers: 1, required: 0, shared: true, transfer: false, received: Some(Local::now().naive_local()),
+     at_time: None, released: None, route_id: 123 };
   let ass_orders = vec![o];
   let ass_orders_map = assigned_orders(&ass_orders);  
-  let ret = wait_exceeded(&o2, 0, 0, 1, 2, unsafe{DIST[4][5] as i32}, 1, 1, &get_test_legs5(), &ass_orders_map);
+  let ret = wait_exceeded(&o2, 0, 0, 1, 2, dp.dist(4, 5) as i32, 1, 1, &get_test_legs5(dp), &ass_orders_map, &StopWait::same(60));
   assert!(ret);
 }
 
 #[test]
 #[serial]
 fn test_wait_exceed_assigned_order_and_not_too_long_then_false() {
-  init_distance(&get_stops(), 30);
-  let o = Order { id: 1, from: 4, to: 10, wait: 10, loss:90, 
-                  dist:unsafe{DIST[4][5] as i32},  
-                  received: Local::now().naive_local().checked_sub_signed(chrono::Duration::seconds(60)), // one minute only
-                  at_time: None,  route_id: 123 };
+  let dp = &Haversine::new(&get_stops(), 30);
+  let o = Order { id: 1, from: 4, to: 10, wait: 600, loss:90, 
+                  dist:dp.dist(4, 5) as i32,  
+                  passengers: 1, required: 0, shared: true, transfer: false, received: Local::now().naive_local().checked_sub_signed(chrono::Duration::seconds(60)), // one minute only
+                  at_time: None, released: None,  route_id: 123 };
   let ass_orders = vec![o];
   let ass_orders_map = assigned_orders(&ass_orders);  
-  let ret = wait_exceeded(&o, 0, 0, 1, 2, unsafe{DIST[4][5] as i32}, 1, 1, &get_test_legs5(), &ass_orders_map);
+  let ret = wait_exceeded(&o, 0, 0, 1, 2, dp.dist(4, 5) as i32, 1, 1, &get_test_legs5(dp), &ass_orders_map, &StopWait::same(60));
   //each leg = 1min distance + 1min at the stop
   // four legs = 4*1 + 3*1 = 7 min. + 1min of waittime since 'received'. Should be OK
   assert!(!ret);
 }
+// one-way streets: driving along the route (growing stop ids) is cheap, backwards it takes five times longer
+fn init_asymmetric_distance() -> MemoryMatrix {
+  let mut dp = MemoryMatrix::new(6);
+  for i in 0..6 {
+    for j in 0..6 {
+      dp.set(i, j, if j >= i { 60 * (j - i) as i16 } else { 300 * (i - j) as i16 }); // seconds
+    }
+  }
+  return dp;
+}
+
+fn find_route_asymmetric(from_stand: i32, to_stand: i32, stop_wait: &StopWait) -> Option<LegIndicesWithDistance2> {
+  let dp = &init_asymmetric_distance();
+  let order = Order { id: 1, from: from_stand, to: to_stand, wait: 600, loss:90,
+                      dist:dp.dist(from_stand as usize, to_stand as usize) as i32,
+                      passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  return find_route(&order, &mut get_test_legs2(dp), &mut get_stops(), &HashMap::new(),
+                    &HashMap::new(), &KernCfg::new(), stop_wait, dp);
+}
+
+#[test]
+#[serial]
+fn test_find_route_asymmetric_along_route() {
+  match find_route_asymmetric(3, 4, &StopWait::same(60)) {
+    Some(x) => {
+      assert_eq!(x.route_id, 123);
+      assert_eq!(x.idx_from, 2);
+      assert_eq!(x.idx_to, 2);
+      assert_eq!(x.dist, 60); // 2->3->4 instead of 2->4
+    },
+    None => {
+      assert_eq!(1, 2); // fail
+    }
+  };
+}
+
+#[test]
+#[serial]
+fn test_find_route_group_needs_free_seats() {
+  let dp = &init_asymmetric_distance();
+  let mut order = Order { id: 1, from: 3, to: 4, wait: 600, loss:90, dist:dp.dist(3, 4) as i32,
+                          passengers: 9, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  let mut max_leg_id: &mut i64 = &mut 10;
+  let (_ret, _, sql) = extend_routes(&vec![order], &HashMap::new(), &get_stops(),
+                                     &mut get_test_legs2(dp), &mut max_leg_id, &KernCfg::new(), dp);
+  let sql = to_sql(&sql);
+  assert!(sql.contains("UPDATE leg SET passengers=passengers+9, "));
+  assert!(sql.contains("VALUES (10,3,4,2,1,1,0,123,10);")); // one passenger was already there
+  order.passengers = 10; // one seat too many
+  assert!(find_route(&order, &mut get_test_legs2(dp), &mut get_stops(), &HashMap::new(),
+                     &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp).is_none());
+}
+
+#[test]
+#[serial]
+fn test_find_route_requires_features() {
+  let dp = &init_asymmetric_distance();
+  let order = Order { id: 1, from: 3, to: 4, wait: 600, loss:90, dist:dp.dist(3, 4) as i32,
+                      passengers: 1, required: 4, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  let mut legs = get_test_legs2(dp);
+  assert!(find_route(&order, &legs, &get_stops(), &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp).is_none());
+  for l in legs.iter_mut() { l.features = 4 | 1; } // child seat and more
+  assert!(find_route(&order, &legs, &get_stops(), &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp).is_some());
+}
+
+#[test]
+#[serial]
+fn test_solo_trip_not_extending_routes() {
+  let dp = &init_asymmetric_distance();
+  let mut order = Order { id: 1, from: 3, to: 4, wait: 600, loss:90, dist:dp.dist(3, 4) as i32,
+                      passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  assert!(find_route(&order, &get_test_legs2(dp), &get_stops(), &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp).is_some());
+  order.shared = false;
+  assert!(find_route(&order, &get_test_legs2(dp), &get_stops(), &HashMap::new(), &HashMap::new(), &KernCfg::new(), &StopWait::same(60), dp).is_none());
+  let (ret, missed, sql) = extend_routes(&vec![order], &HashMap::new(), &get_stops(),
+                                         &mut get_test_legs2(dp), &mut 10, &KernCfg::new(), dp);
+  assert_eq!(sql.len(), 0);
+  assert_eq!(missed.len(), 0);
+  assert_eq!(ret.len(), 1); // on to the solver
+  assert_eq!(ret[0].id, 1);
+}
+
+#[test]
+#[serial]
+fn test_find_route_dwell_and_boarding() {
+  let mut stops = get_stops();
+  stops[3].dwell = 90;
+  match find_route_asymmetric(3, 4, &StopWait::new(&stops, 60, 10)) {
+    Some(x) => {
+      assert_eq!(x.route_id, 123);
+      assert_eq!(x.dist, 100); // as above, but 30 secs longer at 3 and 10 secs to get in
+    },
+    None => {
+      assert_eq!(1, 2); // fail
+    }
+  };
+}
+
+#[test]
+#[serial]
+fn test_find_route_asymmetric_against_route() {
+  // 4->3 would fit into 4->5 of route 123 if the way back were as short as the way forth,
+  // now the order goes beyond route 124 which ends at 5
+  match find_route_asymmetric(4, 3, &StopWait::same(60)) {
+    Some(x) => {
+      assert_eq!(x.route_id, 124);
+      assert_eq!(x.idx_from, 1);
+      assert_eq!(x.dist, 360); // dwell at 5 + dp.dist(5, 4), not dp.dist(4, 5)
+    },
+    None => {
+      assert_eq!(1, 2); // fail
+    }
+  };
+}
+#[test]
+#[serial]
+fn test_extend_legs_sql_asymmetric() {
+  let mut max_leg_id: &mut i64 = &mut 10;
+  let dp = &init_asymmetric_distance();
+  let orders = vec![Order { id: 1, from: 3, to: 4, wait: 600, loss:90, dist:dp.dist(3, 4) as i32,
+                            passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 }];
+  let (_ret, _, sql) = extend_routes(&orders, &HashMap::new(), &get_stops(),
+                                     &mut get_test_legs2(dp), &mut max_leg_id, &KernCfg::new(), dp);
+  // 2->4 is split into 2->3 and 3->4, both one minute long only in this direction
+  assert_eq!(to_sql(&sql), "UPDATE taxi_order SET route_id=123, leg_id=1, cab_id=(SELECT cab_id FROM route where id=123), status=1, eta=7, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'ORDER_ASSIGNED',1,123,-1,-1,-1,-1,-1,7);\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=LEAST(reserve, 3) WHERE route_id=123 AND place <= 0;\nUPDATE leg SET reserve=GREATEST(0, reserve-1) WHERE route_id=123 AND place >= 2;\nUPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, 0) WHERE route_id=123 AND place BETWEEN 2 AND 100;\nUPDATE leg SET place=place+1 WHERE route_id=123 AND place >= 2;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (10,3,4,2,1,1,0,123,2);\nUPDATE leg SET to_stand=3, distance=1, reserve=3, passengers=1 WHERE id=1 AND route_id=123 AND status IN (1,5);\nINSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'LEG_INSERTED',-1,123,10,-1,3,4,2,-1);\n");
+}
 
 }
\ No newline at end of file
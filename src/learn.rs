@@ -0,0 +1,199 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Travel times learned from completed legs - "distance service based on data from the field"
+/// Distances from coordinates, a file or a profile are a static estimate, they are replaced in the matrix
+/// with what cabs really needed once a pair of stops has been driven often enough; each time-of-day profile
+/// learns on its own, legs driven in peak hours do not change the night
+use std::collections::HashMap;
+use chrono::{Local, NaiveDateTime};
+use log::info;
+use crate::distance::DistanceProvider;
+use crate::profile::Profiles;
+use crate::repo::Repository;
+
+pub struct Estimate {
+    pub avg: f64, // seconds
+    pub count: u32,
+    first: Vec<f64> // observations until there are 'min_obs' of them, their median starts the average
+}
+
+pub struct Learner {
+    estimates: HashMap<(Option<usize>, i32, i32), Estimate>, // profile (None: default travel times), from, to
+    alpha: f64,     // weight of a new observation once there are 'min_obs' of them
+    min_obs: u32,   // below that the static estimate is used, observations are not checked
+    max_ratio: f64, // an observation that many times longer or shorter than the learned time is rejected
+    since: NaiveDateTime // legs completed before have been read
+}
+
+// returns None if learning is not enabled
+pub fn init_learner(cfg: &HashMap<String, String>) -> Result<Option<Learner>, String> {
+    match cfg.get("learn_dist") {
+        Some(x) if x == "true" => {},
+        _ => { return Ok(None); }
+    }
+    let learner = Learner::new(get_param(cfg, "learn_alpha", 0.2)?, get_param(cfg, "learn_min_obs", 5)?,
+                               get_param(cfg, "learn_max_ratio", 3.0)?);
+    if learner.alpha <= 0.0 || learner.alpha > 1.0 || learner.max_ratio <= 1.0 || learner.min_obs == 0 {
+        return Err("'learn_alpha' should be in (0,1], 'learn_max_ratio' greater than 1, 'learn_min_obs' at least 1".to_string());
+    }
+    info!("Learning travel times, alpha: {}, min_obs: {}, max_ratio: {}", learner.alpha, learner.min_obs, learner.max_ratio);
+    return Ok(Some(learner));
+}
+
+fn get_param<T: std::str::FromStr>(cfg: &HashMap<String, String>, key: &str, default: T) -> Result<T, String> {
+    return match cfg.get(key) {
+        Some(x) => x.parse::<T>().map_err(|_| format!("Wrong value of '{}': {}", key, x)),
+        None => Ok(default)
+    };
+}
+
+impl Learner {
+    pub fn new(alpha: f64, min_obs: u32, max_ratio: f64) -> Self {
+        Self { estimates: HashMap::new(), alpha, min_obs, max_ratio, since: Local::now().naive_local() }
+    }
+
+    // to be called before each dispatcher run, after profiles have been switched
+    pub fn update(&mut self, conn: &mut dyn Repository, profiles: Option<&Profiles>, dp: &mut dyn DistanceProvider) {
+        let legs = conn.find_completed_legs(self.since);
+        let mut rejected = 0;
+        for (from, to, started, completed) in legs.iter() {
+            // a leg counts for the profile valid when it started
+            let profile = profiles.and_then(|p| p.find(*started));
+            if !self.observe(profile, *from, *to, (*completed - *started).num_seconds(), dp) {
+                rejected += 1;
+            }
+            if *completed > self.since {
+                self.since = *completed;
+            }
+        }
+        let applied = self.apply(profiles.and_then(|p| p.active()), dp);
+        if legs.len() > 0 {
+            info!("Learned travel times from {} legs, rejected: {}, pairs in use: {}", legs.len(), rejected, applied);
+        }
+    }
+
+    // returns false if the observation has been rejected as an outlier
+    pub fn observe(&mut self, profile: Option<usize>, from: i32, to: i32, secs: i64, dp: &dyn DistanceProvider) -> bool {
+        let size = dp.matrix().size() as i32;
+        if from == to || from < 0 || to < 0 || from >= size || to >= size || secs <= 0 || secs > i16::MAX as i64 {
+            return false;
+        }
+        let secs = secs as f64;
+        let min_obs = self.min_obs;
+        let est = self.estimates.entry((profile, from, to)).or_insert(Estimate { avg: 0.0, count: 0, first: vec![] });
+        // the first observations are all taken, the static estimate may be the thing that is wrong;
+        // their median is robust to an outlier among them
+        if est.count < min_obs {
+            est.count += 1;
+            est.first.push(secs);
+            if est.count == min_obs {
+                est.avg = median(&mut est.first);
+                est.first = vec![];
+            }
+            return true;
+        }
+        if est.avg > 0.0 && (secs > est.avg * self.max_ratio || secs * self.max_ratio < est.avg) {
+            return false;
+        }
+        est.count += 1;
+        est.avg += self.alpha * (secs - est.avg);
+        return true;
+    }
+
+    // copies estimates of the active profile with enough observations to the matrix; profiles overwrite it,
+    // so we do it each time
+    pub fn apply(&self, profile: Option<usize>, dp: &mut dyn DistanceProvider) -> usize {
+        let mut count = 0;
+        for ((p, from, to), est) in self.estimates.iter() {
+            if *p == profile && est.count >= self.min_obs {
+                dp.matrix_mut().set(*from as usize, *to as usize, est.avg.round() as i16);
+                count += 1;
+            }
+        }
+        return count;
+    }
+}
+
+fn median(values: &mut Vec<f64>) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = values.len() / 2;
+    return if values.len() % 2 == 1 { values[mid] } else { (values[mid - 1] + values[mid]) / 2.0 };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::distance::MemoryMatrix;
+
+  #[test]
+  fn test_learn_too_few_observations() {
+    let mut dp = MemoryMatrix::new(5);
+    dp.set(1, 2, 100);
+    let mut learner = Learner::new(0.2, 3, 3.0);
+    assert!(learner.observe(None, 1, 2, 150, &dp));
+    assert!(learner.observe(None, 1, 2, 175, &dp));
+    assert_eq!(learner.apply(None, &mut dp), 0);
+    assert_eq!(dp.dist(1, 2), 100); // static estimate
+    assert!(learner.observe(None, 1, 2, 160, &dp));
+    assert_eq!(learner.apply(None, &mut dp), 1);
+    assert_eq!(dp.dist(1, 2), 160); // median
+  }
+
+  #[test]
+  fn test_learn_smoothed_average() {
+    let mut dp = MemoryMatrix::new(5);
+    dp.set(2, 1, 100);
+    dp.set(1, 2, 100);
+    let mut learner = Learner::new(0.5, 1, 3.0);
+    assert!(learner.observe(None, 2, 1, 120, &dp));
+    assert!(learner.observe(None, 2, 1, 140, &dp)); // 120 + 0.5 * (140 - 120)
+    learner.apply(None, &mut dp);
+    assert_eq!(dp.dist(2, 1), 130);
+    assert_eq!(dp.dist(1, 2), 100); // the other direction is not known
+  }
+
+  #[test]
+  fn test_learn_outlier_rejected() {
+    let mut dp = MemoryMatrix::new(5);
+    dp.set(3, 4, 100);
+    let mut learner = Learner::new(0.2, 1, 3.0);
+    assert!(!learner.observe(None, 3, 4, -5, &dp));
+    assert!(!learner.observe(None, 3, 5, 100, &dp)); // unknown stop
+    assert!(learner.observe(None, 3, 4, 110, &dp));
+    assert!(!learner.observe(None, 3, 4, 400, &dp)); // a cab broke down
+    assert!(!learner.observe(None, 3, 4, 30, &dp)); // 'completed' clicked too early
+    learner.apply(None, &mut dp);
+    assert_eq!(dp.dist(3, 4), 110);
+  }
+
+  // the static estimate is far off, cabs need four times longer
+  #[test]
+  fn test_learn_wrong_estimate() {
+    let mut dp = MemoryMatrix::new(5);
+    dp.set(0, 1, 100);
+    let mut learner = Learner::new(0.2, 3, 3.0);
+    assert!(learner.observe(None, 0, 1, 400, &dp));
+    assert!(learner.observe(None, 0, 1, 2000, &dp)); // an outlier among the first ones
+    assert!(learner.observe(None, 0, 1, 410, &dp));
+    assert!(!learner.observe(None, 0, 1, 5000, &dp));
+    learner.apply(None, &mut dp);
+    assert_eq!(dp.dist(0, 1), 410);
+  }
+
+  // peak and night do not override each other
+  #[test]
+  fn test_learn_per_profile() {
+    let mut dp = MemoryMatrix::new(5);
+    dp.set(1, 3, 100);
+    let mut learner = Learner::new(0.2, 1, 3.0);
+    assert!(learner.observe(Some(0), 1, 3, 200, &dp));
+    assert!(learner.observe(Some(1), 1, 3, 50, &dp));
+    assert_eq!(learner.apply(None, &mut dp), 0);
+    assert_eq!(dp.dist(1, 3), 100);
+    assert_eq!(learner.apply(Some(0), &mut dp), 1);
+    assert_eq!(dp.dist(1, 3), 200);
+    assert_eq!(learner.apply(Some(1), &mut dp), 1);
+    assert_eq!(dp.dist(1, 3), 50);
+  }
+}
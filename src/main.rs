@@ -9,21 +9,36 @@ mod pool;
 mod stats;
 mod utils;
 mod solver;
-use distance::DIST;
-use model::{KernCfg, Order, OrderStatus, OrderTransfer, Stop, Cab, CabStatus, Branch,
-            MAXSTOPSNUMB, MAXCABSNUMB, MAXORDERSNUMB, MAXBRANCHNUMB, MAXINPOOL};
+mod profile;
+mod learn;
+mod osrm;
+mod mysql_repo;
+mod pg_repo;
+mod command;
+mod mem_repo;
+mod sqlite_repo;
+mod migrate;
+mod event;
+mod transfer;
+mod repair;
+mod breakdown;
+use distance::{DistanceProvider, DistMatrix, FileMatrix, Haversine};
+use osrm::Osrm;
+use model::{KernCfg, Order, OrderStatus, OrderTransfer, Stop, StopWait, Cab, CabStatus, Branch,
+            MAXCABSNUMB, MAXORDERSNUMB, MAXINPOOL};
 use stats::{Stat,update_max_and_avg_time,update_max_and_avg_stats,incr_val};
 use pool::{orders_to_transfer_array, cabs_to_array, stops_to_array, find_pool};
-use repo::{assign_pool_to_cab, assign_requests_for_free_cabs, run_sql, find_free_cab_and_on_last_leg, 
-            find_cab_by_status};
-use extender::{find_matching_routes, get_handle}; // write_sql_to_file
+use repo::{Repository, assign_pool_to_cab, assign_requests_for_free_cabs, run_sql};
+use command::{Command, TEMP_ID_BASE};
+use event::Event;
+use extender::find_matching_routes; // write_sql_to_file
 use solver::{lcm, munkres, relocate_free_cabs, relocate_free_cabs_glpk};
-use utils::get_elapsed;
-use mysql::*;
-use mysql::prelude::*;
+use transfer::split_orders;
+use repair::repair_routes;
+use breakdown::release_broken_routes;
+use utils::{get_elapsed, MINUTE};
 use chrono::{Local, Duration};
 use std::collections::HashMap;
-use std::ptr::addr_of;
 use std::time::Instant;
 use std::{thread, env, mem};
 
@@ -45,15 +60,16 @@ unsafe extern "C" {
     unsafe fn dynapool(
 		numbThreads: i32,
         poolsize: &[i32; MAXINPOOL - 1], // max sizes
-		distance: *const [[i16; 5200]; 5200], 
+		distance: *const i16, // row by row
 		distSize: i32,
-		stops: &[Stop; MAXSTOPSNUMB],
+		stops: *const Stop,
 		stopsSize: i32,
-		orders: &[OrderTransfer; MAXORDERSNUMB],
+		boardWait: i32, // secs per boarding passenger
+		orders: *const OrderTransfer,
 		ordersSize: i32,
-		cabs: &[Cab; MAXCABSNUMB],
+		cabs: *const Cab,
 		cabsSize: i32,
-		ret: &mut [Branch; MAXBRANCHNUMB], // returned values
+		ret: *mut Branch, // returned values
 		retSize: i32,
 		count: &mut i32, // returned count of values
         pooltime: &mut [i32; MAXINPOOL - 1] // performance statistics
@@ -69,10 +85,23 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>>  {
     // reading Config
     let mut cfg_file: String = CFG_FILE_DEFAULT.to_string();
 
-    // command line arguments
+    // command line arguments: kern [migrate | events [after_seq]] [-f kern.toml]
     let args: Vec<String> = env::args().collect();
-    if args.len() == 3 && args[1] == "-f" {
-        cfg_file = args[2].to_string();
+    let mut run_migrate = false;
+    let mut events_after: Option<i64> = None;
+    let mut i = 1;
+    while i < args.len() {
+        if args[i] == "-f" && i + 1 < args.len() {
+            cfg_file = args[i + 1].to_string();
+            i += 1;
+        } else if args[i] == "migrate" {
+            run_migrate = true;
+        } else if args[i] == "events" {
+            events_after = Some(0);
+        } else if let (Some(_), Ok(seq)) = (events_after, args[i].parse::<i64>()) {
+            events_after = Some(seq);
+        }
+        i += 1;
     }
     info!("Config file: {cfg_file}");
     let settings = config::Config::builder()
@@ -91,15 +120,84 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>>  {
     setupcfg(&cfg);
     let cfig = KernCfg::access();
 
-    // init DB
+    // init DB, MySQL or PostgreSQL
     // 192.168.10.176
-    let url: &str = &db_conn_str;
-    let pool = Pool::new(url)?;
-    let mut conn = pool.get_conn()?;
+    let mut conn = match repo::connect(&db_conn_str) {
+        Ok(c) => c,
+        Err(e) => {
+            error!("{}", e);
+            return Err(e.into());
+        }
+    };
+    if run_migrate {
+        return match conn.migrate() {
+            Ok(applied) => {
+                info!("Schema migrated to version {}, applied now: {:?}", migrate::latest_version(), applied);
+                Ok(())
+            },
+            Err(e) => {
+                error!("{}", e);
+                Err(e.into())
+            }
+        };
+    }
+    // columns and 'stat' rows read below have to be there
+    if let Err(e) = migrate::check_schema(conn.as_mut()) {
+        error!("{}", e);
+        return Err(e.into());
+    }
+    // events after the given 'seq' as JSON lines, for consumers that do not read the outbox table themselves
+    if let Some(mut after) = events_after {
+        loop {
+            let events = conn.read_events(after, 1000)?;
+            if events.len() == 0 {
+                return Ok(());
+            }
+            for (seq, e) in events.iter() {
+                println!("{}", e.to_json(*seq));
+                after = *seq;
+            }
+        }
+    }
+
+    let stops = conn.read_stops(cfig.stop_wait);
+    let mut dp = match init_distance_provider(&cfg, &stops, cfig.cab_speed) {
+        Ok(d) => d,
+        Err(e) => {
+            error!("Could not create distance matrix: {}", e);
+            return Err(e.into());
+        }
+    };
+    // travel times valid at some hours only, e.g. rush hours
+    let mut profiles = match profile::init_profiles(&cfg, &stops, dp.as_ref()) {
+        Ok(p) => p,
+        Err(e) => {
+            error!("Could not load travel time profiles: {}", e);
+            return Err(e.into());
+        }
+    };
+    // real travel times from completed legs
+    let mut learner = match learn::init_learner(&cfg) {
+        Ok(l) => l,
+        Err(e) => {
+            error!("Could not set up learning of travel times: {}", e);
+            return Err(e.into());
+        }
+    };
+    // travel times fetched again from the routing engine, minutes in config
+    let dist_refresh: Option<u64> = match cfg.get("osrm_refresh") {
+        Some(x) => match x.parse::<u64>() {
+            Ok(0) => None,
+            Ok(m) => Some(m * MINUTE as u64),
+            Err(_) => {
+                error!("Wrong osrm_refresh: {}", x);
+                return Err(format!("Wrong osrm_refresh: {}", x).into());
+            }
+        },
+        None => None
+    };
+    let mut last_refresh = Instant::now();
 
-    let stops = repo::read_stops(&mut conn);
-    distance::init_distance(&stops, cfig.cab_speed);
-    
     unsafe {
         if cfig.use_extern_pool {
             initMem();
@@ -109,12 +207,32 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>>  {
     // Kern main, infinite loop
     loop {
         let start = Instant::now();
+        if let Some(r) = dist_refresh {
+            if last_refresh.elapsed().as_secs() >= r {
+                if dp.refresh(&stops) {
+                    if let Some(p) = &mut profiles {
+                        p.rebase(&stops, dp.as_mut());
+                    }
+                }
+                last_refresh = Instant::now();
+            }
+        }
+        if let Some(p) = &mut profiles {
+            p.switch(Local::now().naive_local(), dp.as_mut());
+        }
+        if let Some(l) = &mut learner {
+            l.update(conn.as_mut(), profiles.as_ref(), dp.as_mut());
+        }
+        // customers of cabs that broke down are dispatched again, see breakdown.rs
+        release_broken_routes(conn.as_mut(), dp.as_ref());
+        // customers who abandoned their routes or did not show up free seats for this dispatch
+        repair_routes(conn.as_mut(), &stops, &cfig, dp.as_ref());
         // get newly requested trips and free cabs, reject expired orders (no luck this time)
-        let tmp_model = prepare_data(&mut conn, cfig.max_assign_time);
+        let tmp_model = prepare_data(conn.as_mut(), cfig.max_assign_time, dp.as_ref());
 
         match tmp_model {
             Some(mut x) => { 
-                dispatch(&db_conn_str, &mut conn, &mut x.0, &mut x.1, &stops, *cfig);
+                dispatch(conn.as_mut(), &mut x.0, &mut x.1, &stops, *cfig, dp.as_ref());
             },
             None => {
                 info!("Nothing to do");
@@ -136,6 +254,64 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>>  {
     }
 }
 
+// travel times from 'osrm_url', 'dist_file' or computed from coordinates, possibly taken from 'dist_cache'
+fn init_distance_provider(cfg: &HashMap<String, String>, stops: &Vec<Stop>, cab_speed: i8) 
+                            -> std::result::Result<Box<dyn DistanceProvider>, String> {
+    distance::matrix_size(stops)?; // the matrix is sized and indexed with stop ids
+    if let Some(url) = cfg.get("osrm_url") {
+        // fetched at each start, the engine may have got new data
+        let max_url: usize = match cfg.get("osrm_max_url") {
+            Some(x) => x.parse().map_err(|_| format!("Wrong osrm_max_url: {}", x))?,
+            None => osrm::MAX_URL
+        };
+        info!("Routing engine: {}, max URL length: {}", url, max_url);
+        return Ok(Box::new(Osrm::new(url, max_url, stops, cab_speed)));
+    }
+    let dist_format = match cfg.get("dist_file") {
+        Some(dist_file) => match cfg.get("dist_format") {
+            Some(f) => f.as_str(),
+            None => distance::matrix_format(dist_file)
+        },
+        None => ""
+    };
+    // the matrix computed or read at the previous start, valid as long as stops and the source are the same
+    let cache_key = distance::cache_key(stops, &match cfg.get("dist_file") {
+        Some(dist_file) => distance::file_source(dist_file, dist_format),
+        None => format!("speed:{}", cab_speed)
+    });
+    let mut cached: Option<DistMatrix> = None;
+    if let Some(cache) = cfg.get("dist_cache") {
+        match distance::load_cache(cache, cache_key, stops) {
+            Ok(m) => { info!("Distance matrix from cache: {}", cache); cached = Some(m); },
+            Err(e) => { info!("Distance cache not used: {}", e); }
+        }
+    }
+    let from_cache = cached.is_some();
+    let dp: Box<dyn DistanceProvider> = match cfg.get("dist_file") {
+        Some(dist_file) => {
+            // travel times from a road network, haversine is not used then
+            info!("Distance file: {}, format: {}", dist_file, dist_format);
+            match cached {
+                Some(matrix) => Box::new(FileMatrix { matrix }),
+                None => Box::new(FileMatrix::load(dist_file, dist_format, stops)
+                                    .map_err(|e| format!("Could not load distance file: {}", e))?)
+            }
+        },
+        None => match cached {
+            Some(matrix) => Box::new(Haversine { matrix }),
+            None => Box::new(Haversine::new(stops, cab_speed))
+        }
+    };
+    if let Some(cache) = cfg.get("dist_cache") {
+        if !from_cache {
+            if let Err(e) = distance::save_cache(cache, cache_key, dp.matrix()) {
+                warn!("Could not save distance cache: {}", e);
+            }
+        }
+    }
+    return Ok(dp);
+}
+
 fn setupcfg(cfg: & HashMap<String, String>) {
     let mut c: KernCfg = KernCfg::new();
     c.max_assign_time = cfg["max_assign_time"].parse().unwrap();
@@ -143,12 +319,13 @@ fn setupcfg(cfg: & HashMap<String, String>) {
     c.run_after      = cfg["run_after"].parse().unwrap();
     c.max_legs       = cfg["max_legs"].parse().unwrap();
     c.max_angle      = cfg["max_angle"].parse().unwrap();
-    c.max_angle_dist = cfg["max_angle_dist"].parse().unwrap();
+    c.max_angle_dist = cfg["max_angle_dist"].parse::<i16>().unwrap() * MINUTE as i16; // minutes in config
     c.use_pool       = cfg["use_pool"].parse::<bool>().unwrap();
     c.use_extern_pool= cfg["use_extern_pool"].parse::<bool>().unwrap();
     c.use_extender   = cfg["use_extender"].parse::<bool>().unwrap();
     c.thread_numb    = cfg["thread_numb"].parse().unwrap();
-    c.stop_wait      = cfg["stop_wait"].parse().unwrap();
+    c.stop_wait      = cfg["stop_wait"].parse::<i16>().unwrap() * MINUTE as i16;
+    c.board_wait     = match cfg.get("board_wait") { Some(x) => x.parse().unwrap(), None => 0 }; // secs
     c.cab_speed      = cfg["cab_speed"].parse().unwrap();
     c.max_pool5_size = cfg["max_pool5_size"].parse().unwrap();
     c.max_pool4_size = cfg["max_pool4_size"].parse().unwrap();
@@ -156,6 +333,9 @@ fn setupcfg(cfg: & HashMap<String, String>) {
     c.max_pool2_size = cfg["max_pool2_size"].parse().unwrap();
     c.max_pool2_size = cfg["max_pool2_size"].parse().unwrap();
     c.solver_delay =cfg["solver_delay"].parse().unwrap();
+    c.sql_retries    = match cfg.get("sql_retries") { Some(x) => x.parse().unwrap(), None => 2 };
+    c.transfer_dist  = match cfg.get("transfer_dist") { Some(x) => x.parse::<i32>().unwrap() * MINUTE, None => 0 }; // minutes in config
+    c.no_show_wait   = match cfg.get("no_show_wait") { Some(x) => x.parse::<i32>().unwrap() * MINUTE, None => 0 }; // minutes in config
 
     KernCfg::put(c);
 
@@ -166,18 +346,22 @@ fn setupcfg(cfg: & HashMap<String, String>) {
     info!("run_after: {}", c.run_after);
     info!("max_legs: {}", c.max_legs);
     info!("max_angle: {}", c.max_angle);
-    info!("max_angle_dist: {}", c.max_angle_dist);
+    info!("max_angle_dist (secs): {}", c.max_angle_dist);
     info!("use_pool: {}", c.use_pool);
     info!("use_extern_pool: {}", c.use_extern_pool);
     info!("use_extender: {}", c.use_extender);
     info!("thread_numb: {}", c.thread_numb);
-    info!("stop_wait: {}", c.stop_wait);
+    info!("stop_wait (secs): {}", c.stop_wait);
+    info!("board_wait (secs): {}", c.board_wait);
     info!("cab_speed: {}", c.cab_speed);
     info!("pool5_size: {}", c.max_pool5_size);
     info!("pool4_size: {}", c.max_pool4_size);
     info!("pool3_size: {}", c.max_pool3_size);
     info!("pool2_size: {}", c.max_pool2_size);
     info!("solver_delay: {}", c.solver_delay);
+    info!("sql_retries: {}", c.sql_retries);
+    info!("transfer_dist (secs): {}", c.transfer_dist);
+    info!("no_show_wait (secs): {}", c.no_show_wait);
 }
 
 fn setup_logger(file_path: String) {
@@ -215,12 +399,12 @@ fn setup_logger(file_path: String) {
     let _handle = log4rs::init_config(config);
 }
 
-fn run_extender(conn: &mut PooledConn, orders: &Vec<Order>, stops: &Vec<Stop>, 
-                max_leg_id: &mut i64, label: &str, cfg: &KernCfg) -> Vec<Order> {
+fn run_extender(conn: &mut dyn Repository, orders: &Vec<Order>, stops: &Vec<Stop>, 
+                max_leg_id: &mut i64, label: &str, cfg: &KernCfg, dp: &dyn DistanceProvider) -> Vec<Order> {
     let len_before = orders.len();
     if cfg.use_extender {
         let start_extender = Instant::now();
-        let demand = find_matching_routes(conn, orders, &stops, max_leg_id, cfg);
+        let demand = find_matching_routes(conn, orders, &stops, max_leg_id, cfg, dp);
         update_max_and_avg_time(Stat::AvgExtenderTime, Stat::MaxExtenderTime, start_extender);
         let len_after = demand.len();
         if len_before != len_after {
@@ -238,22 +422,31 @@ fn run_extender(conn: &mut PooledConn, orders: &Vec<Order>, stops: &Vec<Stop>,
 // 1) route extender
 // 2) pool finder
 // 3) solver (LCM in most scenarious won't be called)
-// SQL updates execute in background as async
-fn dispatch(host: &String, conn: &mut PooledConn, orders: &mut Vec<Order>, mut cabs: &mut Vec<Cab>, stops: &Vec<Stop>, cfg: KernCfg) -> usize {
-    let mut max_route_id : i64 = repo::read_max(conn, "route"); // +1, first free ID
-    let mut max_leg_id : i64 = repo::read_max(conn, "leg");
+// each phase is saved in its own transaction on the dispatcher's connection
+fn dispatch(conn: &mut dyn Repository, orders: &mut Vec<Order>, mut cabs: &mut Vec<Cab>, stops: &Vec<Stop>, cfg: KernCfg, dp: &dyn DistanceProvider) -> usize {
+    // temporary ids, real ones are reserved when a plan is saved, so that other writers do not collide with us
+    let mut max_route_id : i64 = TEMP_ID_BASE;
+    let mut max_leg_id : i64 = TEMP_ID_BASE;
 
     if orders.len() == 0 {
         info!("No demand, no dispatch");
         // but check orders from free cabs
-        assign_requests_for_free_cabs(conn, &mut max_route_id, &mut max_leg_id);
+        assign_requests_for_free_cabs(conn, &mut max_route_id, &mut max_leg_id, dp);
         return 0;
     }
     stats::update_max_and_avg_stats(Stat::AvgDemandSize, Stat::MaxDemandSize, orders.len() as i64);
 
+    // long trips get a cab change, the first segment is dispatched now, see transfer.rs
+    if cfg.transfer_dist > 0 {
+        let (split, sql) = split_orders(orders, stops, cfg.transfer_dist, cfg.max_assign_time, dp);
+        if run_sql(conn, sql, "Transfer") {
+            *orders = split;
+        }
+    }
+
     // check if we want to run extender is done in run_extender
     let mut demand
-        = run_extender(conn, orders, &stops, &mut max_leg_id, "FIRST", &cfg);
+        = run_extender(conn, orders, &stops, &mut max_leg_id, "FIRST", &cfg, dp);
     if cabs.len() == 0 {
         info!("No cabs");
         return 0;
@@ -263,19 +456,19 @@ fn dispatch(host: &String, conn: &mut PooledConn, orders: &mut Vec<Order>, mut c
         let start_pool = Instant::now();
         stats::update_max_and_avg_stats(Stat::AvgPoolDemandSize, Stat::MaxPoolDemandSize, demand.len() as i64);
         let pl: Vec<Branch>;
-        let sql: String;
+        let sql: Vec<Command>;
         // 2 versions available - in C (external) and Rust
         if cfg.use_extern_pool {
-            (pl, sql) = find_external_pool(&mut demand, cabs, stops, cfg.thread_numb, &mut max_route_id, &mut max_leg_id, cfg);
+            (pl, sql) = find_external_pool(&mut demand, cabs, stops, cfg.thread_numb, &mut max_route_id, &mut max_leg_id, cfg, dp);
         } else {
-            (pl, sql) = find_internal_pool(&mut demand, cabs, stops, &mut max_route_id, &mut max_leg_id, cfg);
+            (pl, sql) = find_internal_pool(&mut demand, cabs, stops, &mut max_route_id, &mut max_leg_id, cfg, dp);
         }
         update_max_and_avg_time(Stat::AvgPoolTime, Stat::MaxPoolTime, start_pool);
         //write_sql_to_file(itr, &sql, "pool");
         //for s in split_sql(sql, 150) {
         //    client.batch_execute(&s).unwrap();
         //}
-        run_sql(conn, sql);
+        run_sql(conn, sql, "Pool");
         // marking assigned orders to get rid of them; cabs are marked in find_pool 
         let numb = count_orders(pl, &demand);
         info!("Pool finder - number of assigned orders: {}", numb);
@@ -283,7 +476,7 @@ fn dispatch(host: &String, conn: &mut PooledConn, orders: &mut Vec<Order>, mut c
         // let's try extender on the new routes if there still is demand
         (*cabs, demand) = shrink(&cabs, demand);
         demand
-            = run_extender(conn, &demand, &stops, &mut max_leg_id, "SECOND", &cfg);
+            = run_extender(conn, &demand, &stops, &mut max_leg_id, "SECOND", &cfg, dp);
     }
 
     // we don't want to run run solver each time, once a minute is fine, these are some trouble-making customers :)
@@ -303,43 +496,38 @@ fn dispatch(host: &String, conn: &mut PooledConn, orders: &mut Vec<Order>, mut c
             return 0;
         }
         // LCM presolver
-        let mut lcm_handle = thread::spawn(|| { });
         if demand.len() > cfg.max_solver_size && cabs.len() > cfg.max_solver_size {
             // too big to send to solver, it has to be cut by LCM
-            // first just kill the default thread
             info!("LCM input: demand={}, supply={}", demand.len(), cabs.len());
             let start_lcm = Instant::now();
-            lcm_handle.join().expect("LCM SQL thread being joined has panicked");
             let cabs_len = cabs.len();
             let ord_len = orders.len();
-            lcm_handle = lcm(host, &mut cabs, &mut demand, &mut max_route_id, &mut max_leg_id, 
-                    std::cmp::min(ord_len, cabs_len) as i16 - cfg.max_solver_size as i16);
+            let sql = lcm(&mut cabs, &mut demand, &mut max_route_id, &mut max_leg_id, 
+                    std::cmp::min(ord_len, cabs_len) as i16 - cfg.max_solver_size as i16, dp);
             update_max_and_avg_time(Stat::AvgLcmTime, Stat::MaxLcmTime, start_lcm);
             incr_val(Stat::TotalLcmUsed);
+            run_sql(conn, sql, "LCM");
             (*cabs, demand) = shrink(&cabs, demand);
         }
         // SOLVER
         let start_solver = Instant::now();
         info!("Solver input - demand={}, supply={}", demand.len(), cabs.len());
-        let sol = munkres(&cabs, &demand);
+        let sol = munkres(&cabs, &demand, dp);
         let before_solver = max_route_id;
 
-        let sql = repo::assign_cust_to_cab_munkres(sol, &cabs, &demand, &mut max_route_id, &mut max_leg_id);
+        let sql = repo::assign_cust_to_cab_munkres(sol, &cabs, &demand, &mut max_route_id, &mut max_leg_id, dp);
         
         update_max_and_avg_time(Stat::AvgSolverTime, Stat::MaxSolverTime, start_solver);
         //write_sql_to_file(itr, &sql, "munkres");
-        run_sql(conn, sql);
-        lcm_handle.join().expect("LCM SQL thread being joined has panicked");
+        run_sql(conn, sql, "Solver");
         info!("Dispatch completed, solver assigned: {}", max_route_id - before_solver);
     }
-    // we have to join so that the next run of dispatcher gets updated orders
-    let status_handle = get_handle(host.clone(), repo::save_status(), "stats".to_string());
-    status_handle.join().expect("Status SQL thread being joined has panicked");
-
-    assign_requests_for_free_cabs(conn, &mut max_route_id, &mut max_leg_id); // someone went into and took this cab
-    let free_cabs = find_cab_by_status(conn, CabStatus::FREE);
-    let sql = relocate_free_cabs(&free_cabs, &stops, &mut max_route_id, &mut max_leg_id);
-    run_sql(conn, sql);
+    run_sql(conn, repo::save_status(), "Stats");
+
+    assign_requests_for_free_cabs(conn, &mut max_route_id, &mut max_leg_id, dp); // someone went into and took this cab
+    let free_cabs = conn.find_cab_by_status(CabStatus::FREE);
+    let sql = relocate_free_cabs(&free_cabs, &stops, &mut max_route_id, &mut max_leg_id, dp);
+    run_sql(conn, sql, "Relocation");
     return 0; // 0: all orders served
 }
 
@@ -360,7 +548,7 @@ fn shrink(cabs: &Vec<Cab>, orders: Vec<Order>) -> (Vec<Cab>, Vec<Order>) {
 fn get_old_orders(orders: &Vec<Order>, solver_delay: i32) -> Vec<Order> {
     let mut new_orders: Vec<Order> = vec![];
     for o in orders.iter() { 
-        if get_elapsed(o.received) > solver_delay as i64 { 
+        if !o.shared || get_elapsed(o.received) > solver_delay as i64 { // solo trips have nothing to wait for
             new_orders.push(*o); 
         }
     }
@@ -390,10 +578,16 @@ fn count_orders(pl: Vec<Branch>, orders: &Vec<Order>) -> i32 {
 }
 
 fn find_internal_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<Stop>, 
-                    max_route_id: &mut i64, max_leg_id: &mut i64, cfg: KernCfg) -> (Vec<Branch>, String) {
+                    max_route_id: &mut i64, max_leg_id: &mut i64, cfg: KernCfg, dp: &dyn DistanceProvider) -> (Vec<Branch>, Vec<Command>) {
     let mut pl: Vec<Branch> = Vec::new();  
-    let mut sql: String = String::from("");
+    let mut sql: Vec<Command> = vec![];
+    if demand.len() > MAXORDERSNUMB || cabs.len() > MAXCABSNUMB { // indices are i16 in Branch
+        error!("Demand or supply too big for pool finder, accordingly {} (max {}) and {} (max {})",
+                demand.len(), MAXORDERSNUMB, cabs.len(), MAXCABSNUMB);
+        return (pl, sql);
+    }
 
+    let stop_wait = StopWait::new(stops, cfg.stop_wait, cfg.board_wait);
     for p in (2..6).rev() { //5,4,3,2
         if (p == 5 && demand.len() < (cfg.max_pool5_size) as usize ) || // 5: TODO: check if it works!!
             (p == 4 && demand.len() < (cfg.max_pool4_size) as usize ) ||
@@ -402,7 +596,7 @@ fn find_internal_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<
             let now = Instant::now();
             let mut ret = find_pool(p, cfg.thread_numb as i16,
                                                             demand,  cabs, &stops, max_route_id, max_leg_id,
-                                                            cfg.max_angle, cfg.stop_wait);
+                                                            cfg.max_angle, &stop_wait, dp);
             print!("Pool with {}, found pools: {}\n", p, ret.0.len());
             info!("Pool with {}, found pools: {}\n", p, ret.0.len());
             let el = now.elapsed().as_secs() as i64;
@@ -412,33 +606,33 @@ fn find_internal_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<
                 3 => update_max_and_avg_stats(Stat::AvgPool3Time, Stat::MaxPool3Time, el),
                 _=>{},
             }
-            //print_pool(&ret.0, demand, cabs);
+            //print_pool(&ret.0, demand, cabs, dp);
 
             pl.append(&mut ret.0);
-            sql += &ret.1;
+            sql.extend(ret.1);
         }
     }
     return (pl, sql);
 }
 
-fn print_pool(list: &Vec<Branch>, demand: &Vec<Order>, cabs: &Vec<Cab>) {
+fn print_pool(list: &Vec<Branch>, demand: &Vec<Order>, cabs: &Vec<Cab>, dp: &dyn DistanceProvider) {
     for b in list {
-        let cab_cost = unsafe { DIST[cabs[b.cab as usize].location as usize][demand[b.ord_ids[0] as usize].from as usize] };
+        let cab_cost = dp.dist(cabs[b.cab as usize].location as usize, demand[b.ord_ids[0] as usize].from as usize);
         print!("cost={}, cab={}, cab_cost={}: ", b.cost, b.cab, cab_cost);
         for c in 0..b.ord_numb as usize {
             if c < b.ord_numb as usize -1 {
                 let from = if b.ord_actions[c as usize] == 105 { demand[b.ord_ids[c as usize] as usize].from }
                                 else {demand[b.ord_ids[c as usize] as usize].to}; 
                 let to = if b.ord_actions[(c+1) as usize] == 105 { demand[b.ord_ids[(c+1) as usize] as usize].from }
-                                else {demand[b.ord_ids[c as usize] as usize].to}; 
-                let cost = unsafe { DIST[from as usize][to as usize] };
+                                else {demand[b.ord_ids[(c+1) as usize] as usize].to}; 
+                let cost = dp.dist(from as usize, to as usize);
                 print!("{}{:?}[{}]({}), ", b.ord_ids[c], char::from_u32(b.ord_actions[c] as u32).unwrap(), from, cost);
             } else {
                 let from = if b.ord_actions[c as usize -1] == 105 { demand[b.ord_ids[c as usize -1] as usize].from }
                                 else {demand[b.ord_ids[c as usize -1] as usize].to}; 
                 print!("{}{:?}[{}]({}), ", b.ord_ids[c], char::from_u32(b.ord_actions[c] as u32).unwrap(), 
                         demand[b.ord_ids[c as usize] as usize].to, 
-                        unsafe { DIST[from as usize][demand[b.ord_ids[c as usize] as usize].to as usize] });
+                        dp.dist(from as usize, demand[b.ord_ids[c as usize] as usize].to as usize));
             }
         }
         println!("");
@@ -446,7 +640,7 @@ fn print_pool(list: &Vec<Branch>, demand: &Vec<Order>, cabs: &Vec<Cab>) {
 }
 
 // if fail then dump input and output
-fn validate_answer(br: &[Branch; MAXBRANCHNUMB], cnt: &i32, demand_size: usize, cabs: &Vec<Cab>) {
+fn validate_answer(br: &Vec<Branch>, cnt: &i32, demand_size: usize, cabs: &Vec<Cab>) {
     let mut fail_found: bool = false;
     for i in 0 .. *cnt as usize {
         fail_found = false;
@@ -517,14 +711,19 @@ fn validate_answer(br: &[Branch; MAXBRANCHNUMB], cnt: &i32, demand_size: usize,
 
 // calling a C routine
 fn find_external_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<Stop>, threads: i32,
-                      max_route_id: &mut i64, max_leg_id: &mut i64, cfg: KernCfg) -> (Vec<Branch>, String) {
+                      max_route_id: &mut i64, max_leg_id: &mut i64, cfg: KernCfg, dp: &dyn DistanceProvider) -> (Vec<Branch>, Vec<Command>) {
     let mut ret: Vec<Branch> = Vec::new();  
     if demand.len() > MAXORDERSNUMB || cabs.len() > MAXCABSNUMB {
-        error!("Demand or supply too big, accordingly {} and {}", demand.len(), cabs.len());
-        return (ret, "".to_string());
+        error!("Demand or supply too big for pool finder, accordingly {} (max {}) and {} (max {})",
+                demand.len(), MAXORDERSNUMB, cabs.len(), MAXCABSNUMB);
+        return (ret, vec![]);
     }
-    let orders: [OrderTransfer; MAXORDERSNUMB] = orders_to_transfer_array(&demand);
-    let mut br: [Branch; MAXBRANCHNUMB] = [Branch::new(); MAXBRANCHNUMB];
+    let orders: Vec<OrderTransfer> = orders_to_transfer_array(&demand);
+    let stops_arr: Vec<Stop> = stops_to_array(&stops);
+    let cabs_arr: Vec<Cab> = cabs_to_array(&cabs);
+    let (dist, dist_size) = (dp.matrix().as_ptr(), dp.matrix().size());
+    // an order can be in one pool only, so there cannot be more pools than orders
+    let mut br: Vec<Branch> = vec![Branch::new(); demand.len()];
     let mut cnt: i32 = 0;
     let mut poolsize = [0; MAXINPOOL as usize - 1];
     let mut pooltime = [0; MAXINPOOL as usize - 1];
@@ -542,16 +741,17 @@ fn find_external_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<
         dynapool(
             threads,
             &poolsize,
-            addr_of!(DIST),
-            MAXSTOPSNUMB as i32,
-            &stops_to_array(&stops),
-            stops.len() as i32,
-            &orders,
-            demand.len() as i32,
-            &cabs_to_array(&cabs),
-            cabs.len() as i32,
-            &mut br, // returned values
-            MAXBRANCHNUMB as i32,
+            dist,
+            dist_size as i32,
+            stops_arr.as_ptr(),
+            stops_arr.len() as i32,
+            cfg.board_wait as i32,
+            orders.as_ptr(),
+            orders.len() as i32,
+            cabs_arr.as_ptr(),
+            cabs_arr.len() as i32,
+            br.as_mut_ptr(), // returned values
+            br.len() as i32,
             &mut cnt, // returned count of values
             &mut pooltime
         );
@@ -565,10 +765,11 @@ fn find_external_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<
     update_max_and_avg_stats(Stat::AvgPool3Time, Stat::MaxPool3Time, pooltime[1] as i64);
 
     //let cut = &br[0..cnt as usize];
-    //print_pool(&cut.to_vec(), demand, cabs);
+    //print_pool(&cut.to_vec(), demand, cabs, dp);
 
     // generate SQL
-    let mut sql: String = String::from("");
+    let mut sql: Vec<Command> = vec![];
+    let stop_wait = StopWait::new(stops, cfg.stop_wait, cfg.board_wait);
     'outer: for i in 0 .. cnt as usize {
         // first two quality checks
         /*
@@ -588,14 +789,14 @@ fn find_external_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<
         //println!("");
         /*unsafe {
         if !wait_constraints_met(&br[i], 
-                            DIST[cabs[br[i].cab as usize].location as usize][demand[br[i].ord_ids[0] as usize].from as usize],
-                            &demand
+                            dp.dist(cabs[br[i].cab as usize].location as usize, demand[br[i].ord_ids[0] as usize].from as usize),
+                            &demand, dp
                         ) {
             continue;
         }
         }*/
         ret.push(br[i]); // just convert to vec
-        sql += &assign_pool_to_cab(cabs[br[i].cab as usize], demand, br[i], max_route_id, max_leg_id, cfg.stop_wait);
+        sql.extend(assign_pool_to_cab(cabs[br[i].cab as usize], demand, br[i], max_route_id, max_leg_id, &stop_wait, dp));
         // remove the cab from list so that it cannot be allocated twice, by LCM or Munkres
         cabs[br[i].cab as usize].id = -1;
         // mark orders as assigned too
@@ -609,7 +810,7 @@ fn find_external_pool(demand: &mut Vec<Order>, cabs: &mut Vec<Cab>, stops: &Vec<
 
 // checking only maxWait
 /*
-fn wait_constraints_met(el: &Branch, dist_cab: i16, orders: &Vec<Order>) -> bool {
+fn wait_constraints_met(el: &Branch, dist_cab: i16, orders: &Vec<Order>, dp: &dyn DistanceProvider) -> bool {
     // TASK: distances in pool should be stored to speed-up this check
     let mut dist = dist_cab;
     unsafe {
@@ -628,7 +829,7 @@ fn wait_constraints_met(el: &Branch, dist_cab: i16, orders: &Vec<Order>) -> bool
         let from = if el.ord_actions[i] == ('i' as i8) { o.from as usize } else { o.to as usize };
         let to = if el.ord_actions[i + 1] == 'i' as i8 { o2.from as usize } else { o2.to as usize};
         if from != to { 
-            dist += DIST[from][to] + CNFG.stop_wait;
+            dist += dp.dist(from, to) + CNFG.stop_wait;
         }
     }}
     return true;
@@ -639,9 +840,10 @@ fn wait_constraints_met(el: &Branch, dist_cab: i16, orders: &Vec<Order>) -> bool
 // 2) expire old orders
 // 3) some orders and cabs are too distant, although som cabs may end their last legs soon
 // TODO: cabs on last leg should be considered
-fn prepare_data(conn: &mut PooledConn, max_assign_time: i64) -> Option<(Vec<Order>, Vec<Cab>)> {
-    let mut orders = repo::find_orders_by_status_and_time(
-                conn, OrderStatus::RECEIVED , (Local::now() - Duration::minutes(5)).naive_local());
+fn prepare_data(conn: &mut dyn Repository, max_assign_time: i64, dp: &dyn DistanceProvider) -> Option<(Vec<Order>, Vec<Cab>)> {
+    // orders for later (e.g. second segments of transfers) once 'at_time' is due, they expire 'max_assign_time' after it
+    let mut orders = conn.find_orders_by_status_and_time(
+                OrderStatus::RECEIVED , Local::now().naive_local(), dp);
     if orders.len() == 0 {
         info!("No demand");
         return None;
@@ -653,18 +855,20 @@ fn prepare_data(conn: &mut PooledConn, max_assign_time: i64) -> Option<(Vec<Orde
         info!("No demand, expired");
         return None;
     }
-    let mut cabs = find_free_cab_and_on_last_leg(conn); //repo::find_cab_by_status(conn, CabStatus::FREE);
+    // customers of a cab that broke down have waited long enough, the extender and pool finder take them first
+    orders.sort_by_key(|o| o.released.is_none());
+    let mut cabs = conn.find_free_cab_and_on_last_leg(); //conn.find_cab_by_status(CabStatus::FREE);
     if orders.len() == 0 || cabs.len() == 0 {
         warn!("No cabs available");
         return None;
     }
     info!("Initial count, demand={}, supply={}", orders.len(), cabs.len());
-    orders = get_rid_of_distant_customers(&orders, &cabs);
+    orders = get_rid_of_distant_customers(&orders, &cabs, dp);
     if orders.len() == 0 {
       info!("No suitable demand, too distant");
       return None; 
     }
-    cabs = get_rid_of_distant_cabs(&orders, &cabs);
+    cabs = get_rid_of_distant_cabs(&orders, &cabs, dp);
     if cabs.len() == 0 {
       info!("No cabs available, too distant");
       return None; 
@@ -672,9 +876,9 @@ fn prepare_data(conn: &mut PooledConn, max_assign_time: i64) -> Option<(Vec<Orde
     return Some((orders, cabs));
 }
 
-// TODO: bulk update
-fn expire_orders(conn: &mut PooledConn, demand: &Vec<Order>, max_assign_time: i64) -> Vec<Order> {
+fn expire_orders(conn: &mut dyn Repository, demand: &Vec<Order>, max_assign_time: i64) -> Vec<Order> {
     let mut ret: Vec<Order> = Vec::new();
+    let mut sql: Vec<Command> = vec![];
     let mut ids: String = "".to_string();
     for o in demand.iter() {
       //if (o.getCustomer() == null) {
@@ -682,49 +886,48 @@ fn expire_orders(conn: &mut PooledConn, demand: &Vec<Order>, max_assign_time: i6
       //}
         let minutes_rcvd = get_elapsed(o.received)/60;
         let minutes_at : i64 = get_elapsed(o.at_time)/60;
+        let minutes_rlsd : i64 = get_elapsed(o.released)/60; // a new chance after a cab breakdown
         
-        if (minutes_at == -1 && minutes_rcvd > max_assign_time)
-                    || (minutes_at != -1 && minutes_at > max_assign_time) {
+        if (minutes_rlsd != -1 && minutes_rlsd > max_assign_time)
+                    || (minutes_rlsd == -1 && minutes_at == -1 && minutes_rcvd > max_assign_time)
+                    || (minutes_rlsd == -1 && minutes_at != -1 && minutes_at > max_assign_time) {
+            sql.push(Command::ExpireOrder { order_id: o.id });
+            sql.push(command::emit(Event::OrderRefused { order_id: o.id }));
             ids = ids + &o.id.to_string() + &",".to_string();
         } else {
             ret.push(*o);
         }
     }
     if ids.len() > 0 {
-        let sql = ids[0..ids.len() - 1].to_string(); // remove last comma
-        conn.query_iter("UPDATE taxi_order SET status=6 WHERE id IN (".to_string() + &sql + ")").unwrap();
+        conn.save(&sql).unwrap(); // one prepared statement
         debug!("{} refused, max assignment time exceeded", &ids);
     }
     return ret;
 }
 
 // if we find just one cab nearby - continue with this order
-fn get_rid_of_distant_customers(demand: &Vec<Order>, supply: &Vec<Cab>) -> Vec<Order> {
+fn get_rid_of_distant_customers(demand: &Vec<Order>, supply: &Vec<Cab>, dp: &dyn DistanceProvider) -> Vec<Order> {
     let mut ret: Vec<Order> = Vec::new();
     for o in demand.iter() {
       for c in supply.iter() {
-        unsafe {
-            if distance::DIST[c.location as usize][o.from as usize] as i32 <= o.wait { 
-                // great, we have at least one cab in range for this customer
-                ret.push(*o);
-                break;
-            }
+        if dp.dist(c.location as usize, o.from as usize) as i32 <= o.wait { 
+            // great, we have at least one cab in range for this customer
+            ret.push(*o);
+            break;
         }
       }
     }
     return ret;
 }
 
-fn get_rid_of_distant_cabs(demand: &Vec<Order>, supply: &Vec<Cab>) -> Vec<Cab> {
+fn get_rid_of_distant_cabs(demand: &Vec<Order>, supply: &Vec<Cab>, dp: &dyn DistanceProvider) -> Vec<Cab> {
     let mut ret: Vec<Cab> = Vec::new();
     for c in supply.iter() {
         for o in demand.iter() {
-            unsafe {
-                if distance::DIST[c.location as usize][o.from as usize] as i32 <= o.wait {
-                    // great, we have at least one customer in range for this cab
-                    ret.push(*c);
-                    break;
-                }
+            if dp.dist(c.location as usize, o.from as usize) as i32 <= o.wait {
+                // great, we have at least one customer in range for this cab
+                ret.push(*c);
+                break;
             }
         }
     }
@@ -739,49 +942,49 @@ mod tests {
   use super::*;
   //use chrono::format::InternalNumeric;
   use serial_test::serial;
-  use crate::distance::init_distance;
-  use distance::DIST;
+  use crate::distance::MemoryMatrix;
+  use crate::mem_repo::MemRepo;
 
   fn test_orders_invalid() -> Vec<Order> {
     return vec![
         Order{ id: 1, from: 1, to: 2, wait: 10, loss: 50, dist: 2, 
-            received: None,at_time: None, route_id: -1},
+            passengers: 1, required: 0, shared: true, transfer: false, received: None,at_time: None, released: None, route_id: -1},
         Order{ id: -1, from: 1, to: 2, wait: 10, loss: 50, dist: 2, 
-            received: None,at_time: None,route_id: -1}
+            passengers: 1, required: 0, shared: true, transfer: false, received: None,at_time: None, released: None,route_id: -1}
     ];
   }
 
   fn test_orders() -> Vec<Order> {
     return vec![
         Order{ id: 0, from: 0, to: 1, wait: 10, loss: 50, dist: 2, 
-            received: None,at_time: None, route_id: -1},
+            passengers: 1, required: 0, shared: true, transfer: false, received: None,at_time: None, released: None, route_id: -1},
         Order{ id: 1, from: 1, to: 2, wait: 10, loss: 50, dist: 2, 
-            received: None,at_time: None, route_id: -1}
+            passengers: 1, required: 0, shared: true, transfer: false, received: None,at_time: None, released: None, route_id: -1}
     ];
   }
 
   fn test_cabs() -> Vec<Cab> {
     return vec![
-        Cab{ id: 0, location: 2, seats: 10, dist: 0},
-        Cab{ id: 1, location: 3, seats: 10, dist: 0}
+        Cab{ id: 0, location: 2, seats: 10, dist: 0, features: 0},
+        Cab{ id: 1, location: 3, seats: 10, dist: 0, features: 0}
     ];
   }
 
   fn test_cabs_invalid() -> Vec<Cab> {
     return vec![
-        Cab{ id: 1, location: 0, seats: 10, dist: 0},
-        Cab{ id: -1, location: 1, seats: 10, dist: 0}
+        Cab{ id: 1, location: 0, seats: 10, dist: 0, features: 0},
+        Cab{ id: -1, location: 1, seats: 10, dist: 0, features: 0}
     ];
   }
 
   fn test_stops() -> Vec<Stop> {
     return vec![
-      Stop{ id: 0, bearing: 0, latitude: 1.0, longitude: 1.0, capacity: 10},
-      Stop{ id: 1, bearing: 0, latitude: 1.000000001, longitude: 1.000000001, capacity: 10},
-      Stop{ id: 2, bearing: 0, latitude: 1.000000002, longitude: 1.000000002, capacity: 10},
-      Stop{ id: 3, bearing: 0, latitude: 1.000000003, longitude: 1.000000003, capacity: 10},
-      Stop{ id: 4, bearing: 0, latitude: 1.000000004, longitude: 1.000000004, capacity: 10},
-      Stop{ id: 5, bearing: 0, latitude: 1.000000005, longitude: 1.000000005, capacity: 10}
+      Stop{ id: 0, bearing: 0, latitude: 1.0, longitude: 1.0, capacity: 10, dwell: 60},
+      Stop{ id: 1, bearing: 0, latitude: 1.000000001, longitude: 1.000000001, capacity: 10, dwell: 60},
+      Stop{ id: 2, bearing: 0, latitude: 1.000000002, longitude: 1.000000002, capacity: 10, dwell: 60},
+      Stop{ id: 3, bearing: 0, latitude: 1.000000003, longitude: 1.000000003, capacity: 10, dwell: 60},
+      Stop{ id: 4, bearing: 0, latitude: 1.000000004, longitude: 1.000000004, capacity: 10, dwell: 60},
+      Stop{ id: 5, bearing: 0, latitude: 1.000000005, longitude: 1.000000005, capacity: 10, dwell: 60}
     ];
   }
 
@@ -802,16 +1005,63 @@ mod tests {
   fn test_munkres() {
     let orders: Vec<Order> = test_orders_invalid();
     let cabs: Vec<Cab> = test_cabs_invalid();
-    let ret = munkres(&cabs, &orders);
+    let dp = &MemoryMatrix::new(4);
+    let ret = munkres(&cabs, &orders, dp);
     assert_eq!(ret.len(), 2);
   }
 
+  #[test]
+  #[serial]
+  fn test_munkres_asymmetric() {
+    let mut dp = MemoryMatrix::with_value(4, 9);
+    dp.set(0, 1, 1); dp.set(2, 3, 1); // cheap only one way
+    dp.set(3, 0, 1); dp.set(1, 2, 1); // cheap only in the opposite direction
+    let cabs = vec![ Cab{ id: 0, location: 0, seats: 10, dist: 0, features: 0}, Cab{ id: 1, location: 2, seats: 10, dist: 0, features: 0} ];
+    let orders = vec![
+        Order{ id: 0, from: 1, to: 2, wait: 10, loss: 50, dist: 1, passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1},
+        Order{ id: 1, from: 3, to: 0, wait: 10, loss: 50, dist: 1, passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1}
+    ];
+    let ret = munkres(&cabs, &orders, &dp);
+    assert_eq!(ret, vec![0, 1]); // cab to customer, not the other way round
+  }
+
+  #[test]
+  #[serial]
+  fn test_munkres_group_needs_big_cab() {
+    let dp = MemoryMatrix::with_value(4, 9);
+    let cabs = vec![ Cab{ id: 0, location: 1, seats: 4, dist: 0, features: 0}, Cab{ id: 1, location: 3, seats: 8, dist: 0, features: 0},
+                     Cab{ id: 2, location: 1, seats: 2, dist: 0, features: 0} ];
+    let orders = vec![
+        Order{ id: 0, from: 1, to: 2, wait: 10, loss: 50, dist: 1, passengers: 6, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1},
+        Order{ id: 1, from: 3, to: 0, wait: 10, loss: 50, dist: 1, passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1},
+        Order{ id: 2, from: 3, to: 0, wait: 10, loss: 50, dist: 1, passengers: 9, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1}
+    ];
+    let ret = munkres(&cabs, &orders, &dp);
+    assert_eq!(ret[1], 0); // the group goes with the far cab, the only one with enough seats
+    assert!(ret[0] == 1 || ret[2] == 1);
+    assert!(!ret.contains(&2)); // no cab for nine
+  }
+
+  #[test]
+  #[serial]
+  fn test_munkres_wheelchair_needs_ramp() {
+    let dp = MemoryMatrix::with_value(4, 9);
+    let cabs = vec![ Cab{ id: 0, location: 1, seats: 4, dist: 0, features: 2}, Cab{ id: 1, location: 3, seats: 4, dist: 0, features: 1} ];
+    let orders = vec![
+        Order{ id: 0, from: 1, to: 2, wait: 10, loss: 50, dist: 1, passengers: 1, required: 1, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1},
+        Order{ id: 1, from: 1, to: 0, wait: 10, loss: 50, dist: 1, passengers: 1, required: 4, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1}
+    ];
+    let ret = munkres(&cabs, &orders, &dp);
+    assert_eq!(ret, vec![-1, 0]); // the wheelchair goes with the far cab, no cab has a child seat
+  }
+
   #[test]
   #[serial]
   fn test_get_rid_of_distant_cabs() {
     let orders: Vec<Order> = test_orders_invalid();
     let cabs: Vec<Cab> = test_cabs_invalid();
-    let ret = get_rid_of_distant_cabs(&orders, &cabs);
+    let dp = &MemoryMatrix::new(4);
+    let ret = get_rid_of_distant_cabs(&orders, &cabs, dp);
     assert_eq!(ret.len(), 2); // not distant
   }
 
@@ -820,7 +1070,8 @@ mod tests {
   fn test_get_rid_of_distant_orders() {
     let orders: Vec<Order> = test_orders_invalid();
     let cabs: Vec<Cab> = test_cabs_invalid();
-    let ret = get_rid_of_distant_customers(&orders, &cabs);
+    let dp = &MemoryMatrix::new(4);
+    let ret = get_rid_of_distant_customers(&orders, &cabs, dp);
     assert_eq!(ret.len(), 2); // not distant
   }
 
@@ -830,10 +1081,10 @@ mod tests {
     let mut orders: Vec<Order> = test_orders();
     let mut cabs: Vec<Cab> = test_cabs();
     let stops = test_stops();
-    init_distance(&stops, 30);
+    let dp = &Haversine::new(&stops, 30);
     unsafe { initMem(); }
     let ret = find_external_pool(&mut orders, &mut cabs, &stops, 1_i32,
-                                                         &mut 0, &mut 0, KernCfg::new());
+                                                         &mut 0, &mut 0, KernCfg::new(), dp);
     unsafe { freeMem(); }
     assert_eq!(ret.0.len(), 1); 
     /*assert_eq!(ret.1, 
@@ -848,6 +1099,22 @@ mod tests {
         */
   }
 
+  #[test]
+  #[serial]
+  fn test_find_extern_pool_without_solo_trips() {
+    let mut orders: Vec<Order> = test_orders();
+    orders[1].shared = false;
+    let mut cabs: Vec<Cab> = test_cabs();
+    let stops = test_stops();
+    let dp = &Haversine::new(&stops, 30);
+    unsafe { initMem(); }
+    let ret = find_external_pool(&mut orders, &mut cabs, &stops, 1_i32,
+                                                         &mut 0, &mut 0, KernCfg::new(), dp);
+    unsafe { freeMem(); }
+    assert_eq!(ret.0.len(), 0);
+    assert_eq!(orders.iter().filter(|o| o.id != -1).count(), 2); // both go to the solver
+  }
+
   fn get_stops(step: f64, size: usize) -> Vec<Stop> {
     return get_stops_cap(step, size, 9, 10);
   }
@@ -859,7 +1126,7 @@ mod tests {
       for j in 0..size {
         let cap = rand::thread_rng().gen_range(cap_from..cap_to);
         stops.push(
-          Stop{ id: c, bearing: 0, latitude: 49.0 + step * i as f64, longitude: 19.000 + step * j as f64, capacity: cap}
+          Stop{ id: c, bearing: 0, latitude: 49.0 + step * i as f64, longitude: 19.000 + step * j as f64, capacity: cap, dwell: 60}
         );
         c = c + 1;
       }
@@ -867,23 +1134,188 @@ mod tests {
     return stops;
   }
 
-  fn get_orders(size: usize, stops: i32) -> Vec<Order> {
+  fn get_orders(size: usize, stops: i32, dp: &dyn DistanceProvider) -> Vec<Order> {
     let mut ret: Vec<Order> = vec![];
     for i in 0..size as i32 {     
         let from: i32 = i % stops;
         let to: i32 = if from + 5 >= stops { from - 5} else { from + 5} ;
-        let dista = unsafe { DIST[from as usize][to as usize] as i32 };
-        ret.push(Order{ id: i as i64, from, to, wait: 15, loss: 70, dist: dista, 
-                    received: Some(Local::now().naive_local()), at_time: None, 
+        let dista = dp.dist(from as usize, to as usize) as i32;
+        ret.push(Order{ id: i as i64, from, to, wait: 1200, loss: 70, dist: dista, 
+                    passengers: 1, required: 0, shared: true, transfer: false, received: Some(Local::now().naive_local()), at_time: None, 
+                    released: None, 
                     route_id: -1 });
     }
     return ret;
   }
 
+  // a whole cycle as in 'main', without a database
+  #[test]
+  #[serial]
+  fn test_dispatch_in_memory() {
+    let stops = get_stops(0.03, 10);
+    let dp = Haversine::new(&stops, 30);
+    let mut repo = MemRepo::new();
+    for s in stops.iter() { repo.add_stop(s); }
+    for i in 0..10 { repo.add_cab(i, i as i32, 4); }
+    let received = Some((Local::now() - Duration::minutes(2)).naive_local()); // old enough for the solver
+    let ids: Vec<i64> = (0..6).map(|i| repo.add_order(i, i + 20, 20, 50, received)).collect();
+
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, KernCfg::new(), &dp);
+    for id in ids {
+      let o = repo.order(id).unwrap().clone();
+      assert_eq!(o.status, OrderStatus::ASSIGNED as i32);
+      let route = repo.routes.iter().find(|r| r.id == o.route_id).unwrap();
+      assert_eq!(route.cab_id, o.cab_id);
+      assert_eq!(repo.cabs.iter().find(|c| c.id == o.cab_id).unwrap().status, 0);
+      let legs = repo.route_legs(o.route_id);
+      assert!(legs.iter().any(|l| l.from == o.from));
+      assert!(legs.iter().any(|l| l.to == o.to));
+      assert_eq!(legs.iter().find(|l| l.id == o.leg_id).unwrap().from, o.from);
+    }
+    assert!(prepare_data(&mut repo, 3, &dp).is_none()); // nothing left for the next cycle
+  }
+
+  // too much demand for the solver, LCM assigns a part of it on the same connection
+  #[test]
+  #[serial]
+  fn test_dispatch_in_memory_lcm() {
+    let stops = get_stops(0.03, 10);
+    let dp = Haversine::new(&stops, 30);
+    let mut repo = MemRepo::new();
+    for s in stops.iter() { repo.add_stop(s); }
+    for i in 0..10 { repo.add_cab(i, i as i32, 4); }
+    let received = Some((Local::now() - Duration::minutes(2)).naive_local());
+    let ids: Vec<i64> = (0..6).map(|i| repo.add_order(i, i + 20, 20, 50, received)).collect();
+    let mut cfg = KernCfg::new();
+    cfg.use_pool = false;
+    cfg.max_solver_size = 2;
+    let lcm_used = unsafe { crate::stats::STATS[Stat::TotalLcmUsed as usize] };
+
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, cfg, &dp);
+    assert_eq!(unsafe { crate::stats::STATS[Stat::TotalLcmUsed as usize] }, lcm_used + 1);
+    for id in ids {
+      let o = repo.order(id).unwrap().clone();
+      assert_eq!(o.status, OrderStatus::ASSIGNED as i32);
+      let route = repo.routes.iter().find(|r| r.id == o.route_id).unwrap();
+      assert_eq!(route.cab_id, o.cab_id);
+      assert_eq!(repo.route_legs(o.route_id).iter().find(|l| l.id == o.leg_id).unwrap().from, o.from);
+    }
+    assert_eq!(repo.stats.get("TotalLcmUsed"), Some(&((lcm_used + 1) as i32)));
+  }
+
+  // solo trips skip the pool and the solver delay, each gets a cab of its own
+  #[test]
+  #[serial]
+  fn test_dispatch_solo_trips() {
+    let stops = get_stops(0.03, 10);
+    let dp = Haversine::new(&stops, 30);
+    let mut repo = MemRepo::new();
+    for s in stops.iter() { repo.add_stop(s); }
+    for i in 0..10 { repo.add_cab(i, i as i32, 4); }
+    let received = Some(Local::now().naive_local()); // too fresh for the solver if shared
+    let ids: Vec<i64> = (0..4).map(|i| repo.add_order(i, i + 20, 20, 50, received)).collect();
+    for o in repo.orders.iter_mut() { o.shared = false; }
+
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, KernCfg::new(), &dp);
+    let mut routes: Vec<i64> = vec![];
+    for id in ids {
+      let o = repo.order(id).unwrap().clone();
+      assert_eq!(o.status, OrderStatus::ASSIGNED as i32);
+      assert!(!o.in_pool);
+      assert!(!routes.contains(&o.route_id));
+      routes.push(o.route_id);
+      assert_eq!(repo.orders.iter().filter(|x| x.route_id == o.route_id).count(), 1);
+    }
+    // a new shared order along the same way will not join them
+    let id = repo.add_order(1, 21, 20, 50, Some((Local::now() - Duration::minutes(2)).naive_local()));
+    let mut cfg = KernCfg::new();
+    cfg.use_extender = true;
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, cfg, &dp);
+    let o = repo.order(id).unwrap().clone();
+    assert_eq!(o.status, OrderStatus::ASSIGNED as i32);
+    assert!(!routes.contains(&o.route_id));
+  }
+
+  // customers of a cab that broke down are dispatched again, first and not expired
+  #[test]
+  #[serial]
+  fn test_dispatch_after_breakdown() {
+    let stops = get_stops(0.03, 10);
+    let dp = Haversine::new(&stops, 30);
+    let mut repo = MemRepo::new();
+    for s in stops.iter() { repo.add_stop(s); }
+    for i in 0..10 { repo.add_cab(i, i as i32, 4); }
+    let id = repo.add_order(1, 21, 20, 50, Some((Local::now() - Duration::minutes(2)).naive_local()));
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, KernCfg::new(), &dp);
+    let cab_id = repo.order(id).unwrap().cab_id;
+    assert!(cab_id != -1);
+
+    // a while later, longer than max_assign_time
+    let received = Some((Local::now() - Duration::minutes(10)).naive_local());
+    repo.orders.iter_mut().find(|o| o.id == id).unwrap().received = received;
+    repo.cabs.iter_mut().find(|c| c.id == cab_id).unwrap().status = CabStatus::OUT_OF_SERVICE as i32;
+    assert_eq!(release_broken_routes(&mut repo, &dp), 1);
+    repo.add_order(2, 22, 20, 50, Some((Local::now() - Duration::minutes(2)).naive_local()));
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    assert_eq!(orders[0].id, id);
+    assert!(!cabs.iter().any(|c| c.id == cab_id));
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, KernCfg::new(), &dp);
+    let o = repo.order(id).unwrap();
+    assert_eq!(o.status, OrderStatus::ASSIGNED as i32);
+    assert_eq!(o.received, received);
+    assert!(o.cab_id != cab_id);
+  }
+
+  // a long trip with a cab change, the first segment is dispatched at once, the second one waits in the database
+  #[test]
+  #[serial]
+  fn test_dispatch_transfer() {
+    let stops = get_stops(0.03, 10);
+    let dp = Haversine::new(&stops, 30);
+    let mut repo = MemRepo::new();
+    for s in stops.iter() { repo.add_stop(s); }
+    for i in 0..10 { repo.add_cab(i, i as i32, 4); }
+    let received = Some((Local::now() - Duration::minutes(2)).naive_local());
+    let id = repo.add_order(0, 99, 20, 50, received); // corner to corner
+    let mut cfg = KernCfg::new();
+    cfg.transfer_dist = 5 * MINUTE;
+
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, cfg, &dp);
+    let first = repo.order(id).unwrap().clone();
+    let second = repo.order(first.next_id).unwrap().clone();
+    assert_eq!(first.status, OrderStatus::ASSIGNED as i32);
+    assert_eq!(first.to, second.from);
+    assert_eq!(second.to, 99);
+    assert_eq!(second.prev_id, id);
+    assert_eq!(second.status, OrderStatus::RECEIVED as i32);
+    assert!(second.at_time.unwrap() > Local::now().naive_local()); // not before the first segment is there
+    let legs = repo.route_legs(first.route_id);
+    assert_eq!(legs.last().unwrap().to, first.to);
+    assert!(prepare_data(&mut repo, 3, &dp).is_none()); // too early for the second segment
+
+    // the first cab is at the transfer stop, the second segment is due
+    let due = Some((Local::now() - Duration::minutes(1)).naive_local());
+    repo.orders.iter_mut().find(|o| o.id == second.id).unwrap().at_time = due;
+    repo.add_cab(10, first.to, 4); // the other cabs are far from the middle of the city
+    let (mut orders, mut cabs) = prepare_data(&mut repo, 3, &dp).unwrap();
+    assert_eq!(orders.len(), 1);
+    dispatch(&mut repo, &mut orders, &mut cabs, &stops, cfg, &dp);
+    let second = repo.order(second.id).unwrap().clone();
+    assert_eq!(second.status, OrderStatus::ASSIGNED as i32);
+    assert!(second.cab_id != -1);
+    assert_eq!(repo.route_legs(second.route_id).last().unwrap().to, 99);
+  }
+
   fn get_cabs(size: usize) -> Vec<Cab> {
     let mut ret: Vec<Cab> = vec![];
     for i in 0..size {
-        ret.push(Cab{ id: i as i64, location: (i % 2400) as i32, seats: 10, dist: 0});
+        ret.push(Cab{ id: i as i64, location: (i % 2400) as i32, seats: 10, dist: 0, features: 0});
     }
     return ret;
   }
@@ -892,8 +1324,8 @@ mod tests {
   #[serial]
   fn test_performance_find_extern_pool() {
     let stops = get_stops(0.03, 49);
-    init_distance(&stops, 30);
-    let mut orders: Vec<Order> = get_orders(60, 49);
+    let dp = &Haversine::new(&stops, 30);
+    let mut orders: Vec<Order> = get_orders(60, 49, dp);
     //for o in &orders {
     //    println!("id: {}, from: {}, to: {}, dist: {}", o.id, o.from, o.to, o.dist);
     //}
@@ -902,7 +1334,7 @@ mod tests {
     unsafe { initMem(); }
     let start = Instant::now();
     let ret = find_external_pool(&mut orders, &mut cabs, &stops, 8_i32, 
-                                                        &mut 0, &mut 0, KernCfg::new());
+                                                        &mut 0, &mut 0, KernCfg::new(), dp);
     let elapsed = start.elapsed();
     unsafe { freeMem(); }
     println!("Elapsed: {:?}", elapsed); 
@@ -915,19 +1347,20 @@ mod tests {
   #[serial]
   fn test_performance_find_intern_pool() {
     let stops = get_stops(0.03, 49);
-    init_distance(&stops, 30);
-    let mut orders: Vec<Order> = get_orders(60, 49);
+    let dp = &Haversine::new(&stops, 30);
+    let mut orders: Vec<Order> = get_orders(60, 49, dp);
     //for o in &orders {
     //    println!("id: {}, from: {}, to: {}, dist: {}", o.id, o.from, o.to, o.dist);
     //}
     let mut cabs: Vec<Cab> = get_cabs(1000);
     let start = Instant::now();
     let ret = find_internal_pool(&mut orders, &mut cabs, &stops, 
-                                                    &mut 0, &mut 0, KernCfg::new());
+                                                    &mut 0, &mut 0, KernCfg::new(), dp);
     let elapsed = start.elapsed();
     println!("Elapsed: {:?}", elapsed); 
     assert_eq!(ret.0.len(), 15); 
-    assert_eq!(ret.1.len(), 19748);
+    assert_eq!(command::to_sql(&ret.1).len(), 31362);
+    assert_eq!(command::check_plan(&ret.1), Ok(()));
   }
 
   #[test]
@@ -936,8 +1369,8 @@ mod tests {
     let mut max_route_id : i64 = 0;
     let mut max_leg_id : i64 = 0;
     let stops = get_stops(0.05, 49);
-    init_distance(&stops, 30);
-    let mut demand: Vec<Order> = get_orders(60, 49);
+    let dp = &Haversine::new(&stops, 30);
+    let mut demand: Vec<Order> = get_orders(60, 49, dp);
     let mut cabs = get_cabs(1000);
     unsafe { initMem(); }
     let start = Instant::now();
@@ -945,7 +1378,7 @@ mod tests {
     let elapsed = start.elapsed();
     let ret = find_pool(4, 8, &mut demand,  &mut cabs, &stops, 
                                                 &mut max_route_id, &mut max_leg_id, 
-                                                cfg.max_angle, cfg.stop_wait);
+                                                cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
                                                 
     unsafe { freeMem(); }
     println!("Elapsed: {:?}", elapsed); 
@@ -959,11 +1392,11 @@ mod tests {
     let mut max_route_id : i64 = 0;
     let mut max_leg_id : i64 = 0;
     let stops = get_stops_cap(0.0008, 49, 0, 2);
-    init_distance(&stops, 30);
+    let dp = &Haversine::new(&stops, 30);
     let cabs = get_cabs(1000);
-    let sql = relocate_free_cabs(&cabs, &stops, &mut max_route_id, &mut max_leg_id);
+    let sql = relocate_free_cabs(&cabs, &stops, &mut max_route_id, &mut max_leg_id, dp);
     assert_eq!(sql.len() > 0, true); 
-    let sql = relocate_free_cabs_glpk(&cabs, &stops, &mut max_route_id, &mut max_leg_id);
+    let sql = relocate_free_cabs_glpk(&cabs, &stops, &mut max_route_id, &mut max_leg_id, dp);
     assert_eq!(sql.len() > 0, true); 
   }
 
@@ -973,9 +1406,9 @@ mod tests {
     let mut max_route_id : i64 = 0;
     let mut max_leg_id : i64 = 0;
     let stops = get_stops_cap(0.008, 49, 0, 2);
-    init_distance(&stops, 30);
+    let dp = &Haversine::new(&stops, 30);
     let cabs = get_cabs(1000);
-    let sql = relocate_free_cabs_glpk(&cabs, &stops, &mut max_route_id, &mut max_leg_id);
+    let sql = relocate_free_cabs_glpk(&cabs, &stops, &mut max_route_id, &mut max_leg_id, dp);
     assert_eq!(sql.len() > 0, true); 
   }
 
@@ -984,13 +1417,13 @@ mod tests {
   #[serial]
   fn test_performance_find_extern_pool5() {
     let stops = get_stops(0.01, 49);
-    init_distance(&stops, 30);
-    let mut orders: Vec<Order> = get_orders(10, 49);
+    let dp = &Haversine::new(&stops, 30);
+    let mut orders: Vec<Order> = get_orders(10, 49, dp);
     let mut cabs: Vec<Cab> = get_cabs(1000);
     unsafe { initMem(); }
     let start = Instant::now();
     let ret = find_external_pool(&mut orders, &mut cabs, &stops, 8_i32, 
-                                                        &mut 0, &mut 0, KernCfg::new());
+                                                        &mut 0, &mut 0, KernCfg::new(), dp);
     let elapsed = start.elapsed();
     unsafe { freeMem(); }
     println!("Elapsed: {:?}", elapsed); 
@@ -1003,12 +1436,12 @@ mod tests {
   #[serial]
   fn test_performance_lcm() {
     let stops = get_stops(0.05, 49);
-    init_distance(&stops, 30);
-    let mut orders: Vec<Order> = get_orders(2000, 49);
+    let dp = &Haversine::new(&stops, 30);
+    let mut orders: Vec<Order> = get_orders(2000, 49, dp);
     let mut cabs: Vec<Cab> = get_cabs(2000);
     //let ret = lcm_gen_pairs(&mut cabs, &mut orders, 100);
     let start = Instant::now();
-    let ret2 = lcm_gen_pairs2(&mut cabs, &mut orders, 2000);
+    let ret2 = lcm_gen_pairs2(&mut cabs, &mut orders, 2000, dp);
     println!("Elapsed: {}", start.elapsed().as_millis());
     assert_eq!(ret2.len(), 2000);
     // assert_eq!(ret2[0].0, 901);
@@ -1017,7 +1450,7 @@ mod tests {
     // assert_eq!(ret2[1].1, 1498);
   } 
 
-fn lcm_gen_pairs2(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16) -> Vec<(i16,i16)> {
+fn lcm_gen_pairs2(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16, dp: &dyn DistanceProvider) -> Vec<(i16,i16)> {
     // let us start with a big cost - is there any smaller?
     let big_cost: i32 = 1000000;
     let mut cabs_cpy = cabs.to_vec(); // clone
@@ -1029,7 +1462,6 @@ fn lcm_gen_pairs2(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16) -> Vec<(i
         let mut smin: i16 = -1;
         let mut dmin: i16 = -1;
         // now find the minimal element in the whole matrix
-        unsafe {
         let mut s: usize = 0;
         let mut found = false;
         for cab in cabs_cpy.iter() {
@@ -1039,8 +1471,8 @@ fn lcm_gen_pairs2(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16) -> Vec<(i
             }
             let mut d: usize = 0;
             for order in orders_cpy.iter() {
-                if order.id != -1 && (distance::DIST[cab.location as usize][order.from as usize] as i32) < lcm_min_val {
-                    lcm_min_val = distance::DIST[cab.location as usize][order.from as usize] as i32;
+                if order.id != -1 && (dp.dist(cab.location as usize, order.from as usize) as i32) < lcm_min_val {
+                    lcm_min_val = dp.dist(cab.location as usize, order.from as usize) as i32;
                     smin = s as i16;
                     dmin = d as i16;
                     if lcm_min_val == 0 { // you can't have a better solution
@@ -1054,7 +1486,7 @@ fn lcm_gen_pairs2(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16) -> Vec<(i
                 break; // yes, we could have loop labels and break two of them here, but this is for migration to C
             }
             s += 1;
-        }}
+        }
         if lcm_min_val == big_cost {
             info!("LCM minimal cost is big_cost - no more interesting stuff here");
             break;
@@ -0,0 +1,602 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// In-memory repository - tables of sql/create.sql kept in vectors, for tests and runs without a database
+/// Columns keep the units of the database (minutes), so that the dispatcher sees the same values as from MySQL or PostgreSQL
+use std::collections::HashMap;
+use chrono::NaiveDateTime;
+use crate::command::{Command, check_plan, count_new_ids, resolve_ids};
+use crate::distance::DistanceProvider;
+use crate::event::Event;
+use crate::migrate::latest_version;
+use crate::model::{Cab, CabAssign, CabStatus, Leg, Order, OrderStatus, RouteStatus, Stop};
+use crate::repo::{Repository, SaveError, get_route_status, save_without_conflicts};
+use crate::utils::{get_elapsed, to_secs};
+
+#[derive(Clone, Debug)]
+pub struct OrderRow {
+    pub id: i64,
+    pub from: i32,
+    pub to: i32,
+    pub wait: i32, // max_wait, minutes
+    pub loss: i32,
+    pub shared: bool,
+    pub in_pool: bool,
+    pub passengers: i32,
+    pub required: i32,
+    pub received: Option<NaiveDateTime>,
+    pub at_time: Option<NaiveDateTime>,
+    pub released: Option<NaiveDateTime>, // see breakdown.rs
+    pub eta: i32,
+    pub status: i32,
+    pub cab_id: i64, // -1 as NULL
+    pub leg_id: i64,
+    pub route_id: i64,
+    pub prev_id: i64, // ride transfer, the segment before
+    pub next_id: i64
+}
+
+#[derive(Clone, Debug)]
+pub struct CabRow {
+    pub id: i64,
+    pub location: i32,
+    pub status: i32,
+    pub seats: i32,
+    pub features: i32
+}
+
+#[derive(Clone, Debug)]
+pub struct RouteRow {
+    pub id: i64,
+    pub status: i32,
+    pub cab_id: i64,
+    pub locked: bool
+}
+
+#[derive(Clone, Debug)]
+pub struct LegRow {
+    pub id: i64,
+    pub route_id: i64,
+    pub from: i32,
+    pub to: i32,
+    pub place: i32,
+    pub dist: i32, // minutes
+    pub status: i32,
+    pub reserve: i32, // minutes
+    pub passengers: i32,
+    pub started: Option<NaiveDateTime>,
+    pub completed: Option<NaiveDateTime>
+}
+
+#[derive(Clone, Debug)]
+pub struct StopRow {
+    pub id: i64,
+    pub bearing: i32,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub capacity: i16,
+    pub dwell: Option<i16> // secs
+}
+
+#[derive(Clone, Default)]
+pub struct MemRepo {
+    pub orders: Vec<OrderRow>,
+    pub free_orders: Vec<CabAssign>,
+    pub cabs: Vec<CabRow>,
+    pub routes: Vec<RouteRow>,
+    pub legs: Vec<LegRow>,
+    pub stops: Vec<StopRow>,
+    pub stats: HashMap<String, i32>,
+    pub events: Vec<(i64, Event)>,
+    next_order_id: i64,
+    next_route_id: i64,
+    next_leg_id: i64,
+    next_event_seq: i64
+}
+
+impl MemRepo {
+    pub fn new() -> Self {
+        return Self { next_event_seq: 1, ..Self::default() }; // 'seq' starts with 1 in id_block too
+    }
+}
+
+#[cfg(test)]
+impl MemRepo {
+    // a new order, RECEIVED; returns its id
+    pub fn add_order(&mut self, from: i32, to: i32, wait: i32, loss: i32, received: Option<NaiveDateTime>) -> i64 {
+        let id = self.next_order_id;
+        self.next_order_id += 1;
+        self.orders.push(OrderRow { id, from, to, wait, loss, shared: true, in_pool: false, passengers: 1, required: 0, received, at_time: None,
+                                    released: None, eta: 0, status: OrderStatus::RECEIVED as i32, cab_id: -1, leg_id: -1, route_id: -1,
+                                    prev_id: -1, next_id: -1 });
+        return id;
+    }
+
+    pub fn add_cab(&mut self, id: i64, location: i32, seats: i32) {
+        self.cabs.push(CabRow { id, location, status: CabStatus::FREE as i32, seats, features: 0 });
+    }
+
+    pub fn add_stop(&mut self, s: &Stop) {
+        self.stops.push(StopRow { id: s.id, bearing: s.bearing, latitude: s.latitude, longitude: s.longitude,
+                                  capacity: s.capacity, dwell: Some(s.dwell) });
+    }
+
+    pub fn order(&self, id: i64) -> Option<&OrderRow> {
+        return self.orders.iter().find(|o| o.id == id);
+    }
+
+    // legs of a route in the order of stops
+    pub fn route_legs(&self, route_id: i64) -> Vec<&LegRow> {
+        let mut ret: Vec<&LegRow> = self.legs.iter().filter(|l| l.route_id == route_id).collect();
+        ret.sort_by_key(|l| l.place);
+        return ret;
+    }
+}
+
+impl MemRepo {
+    // what the database would do with the statement, rows changed
+    fn apply(&mut self, cmd: &Command) -> Result<u64, String> {
+        let mut n: u64 = 0;
+        match cmd.clone() {
+            Command::AssignOrderToRoute { order_id, leg_id, route_id, eta, in_pool } => {
+                let cab_id = self.routes.iter().find(|r| r.id == route_id).map_or(-1, |r| r.cab_id);
+                n = self.assign(order_id, cab_id, leg_id, route_id, eta, in_pool);
+            },
+            Command::AssignOrder { order_id, cab_id, leg_id, route_id, eta, in_pool } =>
+                n = self.assign(order_id, cab_id, leg_id, route_id, eta, in_pool),
+            Command::ExpireOrder { order_id } => {
+                for o in self.orders.iter_mut().filter(|o| (o.id == order_id || o.prev_id == order_id) && o.status == OrderStatus::RECEIVED as i32) {
+                    o.status = 6;
+                    n += 1;
+                }
+            },
+            Command::InsertOrder { from, to, loss, shared, passengers, received, cab_id, leg_id, route_id, .. } => {
+                let id = self.next_order_id;
+                self.next_order_id += 1;
+                self.orders.push(OrderRow { id, from, to, wait: -1, loss, shared, in_pool: false, passengers, required: 0, received, at_time: None, released: None, eta: 0,
+                                            status: OrderStatus::ASSIGNED as i32, cab_id, leg_id, route_id, prev_id: -1, next_id: -1 });
+                n = 1;
+            },
+            Command::InsertSegment { order_id, from, at_time, .. } => {
+                let id = self.next_order_id;
+                if let Some(o) = self.orders.iter().find(|o| o.id == order_id && o.status == OrderStatus::RECEIVED as i32 && o.next_id == -1) {
+                    let segment = OrderRow { id, from, at_time, released: None, in_pool: false, eta: 0, prev_id: order_id, ..o.clone() };
+                    self.orders.push(segment);
+                    self.next_order_id += 1;
+                    n = 1;
+                }
+            },
+            Command::SplitOrder { order_id, to, .. } => {
+                let next_id = self.orders.iter().filter(|o| o.prev_id == order_id).map(|o| o.id).max().unwrap_or(-1);
+                for o in self.orders.iter_mut().filter(|o| o.id == order_id && o.status == OrderStatus::RECEIVED as i32 && o.next_id == -1) {
+                    o.to = to;
+                    o.next_id = next_id;
+                    n += 1;
+                }
+            },
+            Command::DeleteFreeOrder { id } => {
+                let len = self.free_orders.len();
+                self.free_orders.retain(|o| o.id != id);
+                n = (len - self.free_orders.len()) as u64;
+            },
+            Command::CreateLeg { id, from, to, place, dist, status, reserve, route_id, passengers } => {
+                if self.legs.iter().any(|l| l.id == id) {
+                    return Err(format!("duplicate leg id {}", id));
+                }
+                self.legs.push(LegRow { id, route_id, from, to, place, dist, status, reserve, passengers, started: None, completed: None });
+                n = 1;
+            },
+            Command::UpdateLeg { id, route_id, to, dist, reserve, passengers } => {
+                for l in self.legs.iter_mut().filter(|l| l.id == id && l.route_id == route_id && (l.status == 1 || l.status == 5)) {
+                    l.to = to; l.dist = dist; l.reserve = reserve; l.passengers = passengers;
+                    n += 1;
+                }
+            },
+            Command::ShiftPlaces { route_id, place_from } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.place >= place_from) { l.place += 1; n += 1; }
+            },
+            Command::DetachOrder { order_id, .. } => {
+                for o in self.orders.iter_mut().filter(|o| o.id == order_id && (o.status == 1 || o.status == 2 || o.status == 5) && o.leg_id != -1) {
+                    o.status = OrderStatus::ABANDONED as i32;
+                    o.leg_id = -1;
+                    n += 1;
+                }
+            },
+            Command::DeleteLeg { id, route_id } => {
+                let len = self.legs.len();
+                self.legs.retain(|l| !(l.id == id && l.route_id == route_id && l.status == 1));
+                n = (len - self.legs.len()) as u64;
+            },
+            Command::ClosePlaces { route_id, place_from } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.place >= place_from) { l.place -= 1; n += 1; }
+            },
+            Command::ReleaseOrder { order_id, released, .. } => {
+                for o in self.orders.iter_mut().filter(|o| o.id == order_id && (o.status == 1 || o.status == 2)) {
+                    o.status = OrderStatus::RECEIVED as i32;
+                    (o.route_id, o.leg_id, o.cab_id, o.eta, o.in_pool) = (-1, -1, -1, 0, false);
+                    o.released = released;
+                    n += 1;
+                }
+            },
+            Command::AbandonLegs { route_id } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.status == 1) { l.status = 4; n += 1; }
+            },
+            Command::AbandonRoute { route_id } => {
+                for r in self.routes.iter_mut().filter(|r| r.id == route_id && (r.status == 1 || r.status == 5)) {
+                    r.status = 4;
+                    r.locked = true;
+                    n += 1;
+                }
+            },
+            Command::AddPassengers { route_id, passengers, reserve, place_from, place_to } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.place >= place_from && l.place <= place_to) {
+                    l.passengers += passengers;
+                    l.reserve = l.reserve.min(reserve);
+                    n += 1;
+                }
+            },
+            Command::ReduceReserveAfter { route_id, cost, place_from } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.place >= place_from) {
+                    l.reserve = 0.max(l.reserve - cost);
+                    n += 1;
+                }
+            },
+            Command::ReduceReserveBefore { route_id, cost, place_to } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.place <= place_to) {
+                    l.reserve = 0.max(l.reserve - cost);
+                    n += 1;
+                }
+            },
+            Command::CapReserveBefore { route_id, reserve, place_to } => {
+                for l in self.legs.iter_mut().filter(|l| l.route_id == route_id && l.place <= place_to) {
+                    l.reserve = l.reserve.min(reserve);
+                    n += 1;
+                }
+            },
+            Command::AssignCab { cab_id } => { // ASSIGNED or FREE only
+                for c in self.cabs.iter_mut().filter(|c| c.id == cab_id && (c.status == 0 || c.status == 1)) { c.status = 0; n += 1; }
+            },
+            Command::LockRoutes { cab_id } => {
+                for r in self.routes.iter_mut().filter(|r| r.cab_id == cab_id && (r.status == 1 || r.status == 5)) { r.locked = true; n += 1; }
+            },
+            Command::CreateRoute { id, cab_id } => {
+                if self.routes.iter().any(|r| r.id == id) {
+                    return Err(format!("duplicate route id {}", id));
+                }
+                self.routes.push(RouteRow { id, status: RouteStatus::ASSIGNED as i32, cab_id, locked: false });
+                n = 1;
+            },
+            Command::UpdateStat { name, value } => { self.stats.insert(name, value); n = 1; },
+            Command::Emit { seq, event } => { self.events.push((seq, event)); n = 1; }
+        }
+        return Ok(n);
+    }
+
+    // only orders still RECEIVED, as 'AND status=0' in SQL
+    fn assign(&mut self, order_id: i64, cab_id: i64, leg_id: Option<i64>, route_id: i64, eta: i32, in_pool: bool) -> u64 {
+        let mut n: u64 = 0;
+        for o in self.orders.iter_mut().filter(|o| o.id == order_id && o.status == OrderStatus::RECEIVED as i32) {
+            o.route_id = route_id;
+            if let Some(l) = leg_id { o.leg_id = l; }
+            o.cab_id = cab_id;
+            o.status = OrderStatus::ASSIGNED as i32;
+            o.eta = eta;
+            o.in_pool = in_pool;
+            n += 1;
+        }
+        return n;
+    }
+
+    fn route(&self, id: i64) -> Option<&RouteRow> {
+        return self.routes.iter().find(|r| r.id == id);
+    }
+
+    fn cab(&self, id: i64) -> Option<&CabRow> {
+        return self.cabs.iter().find(|c| c.id == id);
+    }
+}
+
+impl Repository for MemRepo {
+    fn find_orders_by_status_and_time(&mut self, status: OrderStatus, at_time: NaiveDateTime, dp: &dyn DistanceProvider) -> Vec<Order> {
+        let mut rows: Vec<&OrderRow> = self.orders.iter()
+            .filter(|o| o.status == status as i32 && (o.at_time.is_none() || o.at_time.unwrap() < at_time)).collect();
+        rows.sort_by_key(|o| o.route_id);
+        return rows.iter().map(|o| Order {
+            id: o.id,
+            from: o.from,
+            to: o.to,
+            wait: to_secs(o.wait),
+            loss: o.loss,
+            dist: dp.dist(o.from as usize, o.to as usize) as i32,
+            passengers: o.passengers,
+            required: o.required,
+            shared: o.shared,
+            transfer: o.prev_id != -1 || o.next_id != -1,
+            received: o.received,
+            at_time: o.at_time,
+            released: o.released,
+            route_id: if matches!(status, OrderStatus::RECEIVED) { -1 } else { o.route_id }
+        }).collect();
+    }
+
+    fn read_stops(&mut self, stop_wait: i16) -> Vec<Stop> {
+        return self.stops.iter().map(|s| Stop { id: s.id, bearing: s.bearing, latitude: s.latitude, longitude: s.longitude,
+                                                 capacity: s.capacity, dwell: s.dwell.unwrap_or(stop_wait) }).collect();
+    }
+
+    fn read_free_taxi_orders(&mut self) -> Vec<CabAssign> {
+        return self.free_orders.clone();
+    }
+
+    fn find_cab_by_status(&mut self, status: CabStatus) -> Vec<Cab> {
+        return self.cabs.iter()
+            .filter(|c| c.status == status as i32)
+            .filter(|c| status != CabStatus::FREE // see the SQL version
+                        || !self.routes.iter().any(|r| r.cab_id == c.id && (r.status == 1 || r.status == 5)))
+            .map(|c| Cab { id: c.id, location: c.location, seats: c.seats, dist: 0, features: c.features }).collect();
+    }
+
+    fn find_free_cab_and_on_last_leg(&mut self) -> Vec<Cab> {
+        let mut ret: Vec<Cab> = Vec::new();
+        for l in self.legs.iter().filter(|l| l.status == RouteStatus::STARTED as i32) {
+            if self.legs.iter().any(|l2| l2.route_id == l.route_id && l2.id != l.id && l2.place >= l.place) {
+                continue; // not the last one
+            }
+            let route = match self.route(l.route_id) { Some(r) if !r.locked => r, _ => continue };
+            if self.orders.iter().any(|o| o.route_id == l.route_id && !o.shared) { continue; } // solo trips are not extended
+            let cab = match self.cab(route.cab_id) { Some(c) => c, None => continue };
+            let mut dist: i32 = to_secs(l.dist);
+            let passed = get_elapsed(l.started);
+            if passed != -1 {
+                dist = if passed as i32 > dist { 0 } else { dist - passed as i32 };
+            }
+            ret.push(Cab { id: cab.id, location: l.to, seats: cab.seats, dist: dist as i16, features: cab.features });
+        }
+        ret.append(&mut self.find_cab_by_status(CabStatus::FREE));
+        return ret;
+    }
+
+    fn find_legs(&mut self, dp: &dyn DistanceProvider) -> Vec<Leg> {
+        let mut ret: Vec<Leg> = Vec::new();
+        for l in self.legs.iter().filter(|l| l.status == 1 || l.status == 5) {
+            let route = match self.route(l.route_id) { Some(r) if !r.locked => r, _ => continue };
+            if self.orders.iter().any(|o| o.route_id == l.route_id && !o.shared) { continue; } // solo trips are not extended
+            let cab = match self.cab(route.cab_id) { Some(c) => c, None => continue };
+            ret.push(Leg {
+                id: l.id,
+                route_id: l.route_id,
+                from: l.from,
+                to: l.to,
+                place: l.place,
+                dist: dp.dist(l.from as usize, l.to as usize) as i32,
+                reserve: to_secs(l.reserve),
+                started: l.started,
+                status: get_route_status(l.status),
+                passengers: l.passengers,
+                seats: cab.seats,
+                features: cab.features
+            });
+        }
+        ret.sort_by_key(|l| (l.route_id, l.place));
+        return ret;
+    }
+
+    fn find_completed_legs(&mut self, since: NaiveDateTime) -> Vec<(i32, i32, NaiveDateTime, NaiveDateTime)> {
+        let mut ret: Vec<(i32, i32, NaiveDateTime, NaiveDateTime)> = self.legs.iter()
+            .filter_map(|l| match (l.started, l.completed) {
+                (Some(s), Some(c)) if c > since => Some((l.from, l.to, s, c)),
+                _ => None
+            }).collect();
+        ret.sort_by_key(|l| l.3);
+        return ret;
+    }
+
+    fn find_orders_to_repair(&mut self, left_before: NaiveDateTime, dp: &dyn DistanceProvider) -> Vec<(Order, bool)> {
+        let mut ret: Vec<(Order, bool)> = Vec::new();
+        for o in self.orders.iter() {
+            let leg = match self.legs.iter().find(|l| l.id == o.leg_id) { Some(l) => l, None => continue };
+            let no_show = (o.status == 1 || o.status == 2) && (leg.status == 5 || leg.status == 6)
+                            && leg.started.map_or(false, |s| s < left_before);
+            if o.status == OrderStatus::ABANDONED as i32 || no_show {
+                ret.push((route_order(o, dp), no_show));
+            }
+        }
+        ret.sort_by_key(|(o, _)| (o.route_id, o.id));
+        return ret;
+    }
+
+    fn find_route_legs(&mut self, route_id: i64, dp: &dyn DistanceProvider) -> Vec<Leg> {
+        let cab = match self.route(route_id).and_then(|r| self.cab(r.cab_id)) { Some(c) => c, None => return vec![] };
+        let mut ret: Vec<Leg> = self.legs.iter().filter(|l| l.route_id == route_id && (l.status == 1 || l.status == 5))
+            .map(|l| Leg {
+                id: l.id,
+                route_id,
+                from: l.from,
+                to: l.to,
+                place: l.place,
+                dist: dp.dist(l.from as usize, l.to as usize) as i32,
+                reserve: to_secs(l.reserve),
+                started: l.started,
+                status: get_route_status(l.status),
+                passengers: l.passengers,
+                seats: cab.seats,
+                features: cab.features
+            }).collect();
+        ret.sort_by_key(|l| l.place);
+        return ret;
+    }
+
+    fn find_broken_routes(&mut self) -> Vec<(i64, i64)> {
+        let mut ret: Vec<(i64, i64)> = self.routes.iter()
+            .filter(|r| (r.status == 1 || r.status == 5)
+                        && self.cab(r.cab_id).map_or(false, |c| c.status == CabStatus::OUT_OF_SERVICE as i32))
+            .map(|r| (r.id, r.cab_id)).collect();
+        ret.sort();
+        return ret;
+    }
+
+    fn find_route_orders(&mut self, route_id: i64, status: &[OrderStatus], dp: &dyn DistanceProvider) -> Vec<Order> {
+        return self.orders.iter().filter(|o| o.route_id == route_id && status.iter().any(|s| o.status == *s as i32))
+            .map(|o| route_order(o, dp)).collect();
+    }
+
+    // changes are made on a copy, which replaces the tables if all of them succeed
+    fn save(&mut self, cmds: &Vec<Command>) -> Result<(), String> {
+        check_plan(cmds)?;
+        return save_without_conflicts(cmds, |plan| {
+            let mut copy = self.clone();
+            let (route_count, leg_count, event_count) = count_new_ids(plan);
+            let plan = resolve_ids(plan, copy.next_route_id, copy.next_leg_id, copy.next_event_seq).map_err(SaveError::Failed)?;
+            copy.next_route_id += route_count;
+            copy.next_leg_id += leg_count;
+            copy.next_event_seq += event_count;
+            let mut conflicts: Vec<usize> = vec![];
+            for (i, c) in plan.iter().enumerate() {
+                let n = copy.apply(c).map_err(|e| SaveError::Failed(format!("statement: {}, ids: {}, err: {}", c, c.ids(), e)))?;
+                if n == 0 && c.must_change_row() { conflicts.push(i); }
+            }
+            if conflicts.len() > 0 {
+                return Err(SaveError::Conflict(conflicts));
+            }
+            *self = copy;
+            return Ok(());
+        });
+    }
+
+    // always the schema of this kern
+    fn schema_version(&mut self) -> Result<i32, String> {
+        return Ok(latest_version());
+    }
+
+    fn migrate(&mut self) -> Result<Vec<i32>, String> {
+        return Ok(vec![]);
+    }
+
+    fn read_events(&mut self, after: i64, limit: i64) -> Result<Vec<(i64, Event)>, String> {
+        return Ok(self.events.iter().filter(|e| e.0 > after).take(limit as usize).cloned().collect());
+    }
+}
+
+// an order of a route, see route repair
+fn route_order(o: &OrderRow, dp: &dyn DistanceProvider) -> Order {
+    return Order {
+        id: o.id,
+        from: o.from,
+        to: o.to,
+        wait: to_secs(o.wait),
+        loss: o.loss,
+        dist: dp.dist(o.from as usize, o.to as usize) as i32,
+        passengers: o.passengers,
+        required: o.required,
+        shared: o.shared,
+        transfer: o.prev_id != -1 || o.next_id != -1,
+        received: o.received,
+        at_time: o.at_time,
+        released: o.released,
+        route_id: o.route_id
+    };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::Local;
+  use crate::command::emit;
+  use crate::distance::MemoryMatrix;
+
+  #[test]
+  fn test_save_all_or_nothing() {
+    let mut repo = MemRepo::new();
+    repo.add_cab(1, 0, 4);
+    let cmds = vec![Command::AssignCab { cab_id: 1 }, Command::CreateRoute { id: 7, cab_id: 1 }, Command::CreateRoute { id: 7, cab_id: 1 }];
+    assert!(repo.save(&cmds).is_err());
+    assert_eq!(repo.cabs[0].status, CabStatus::FREE as i32); // rolled back
+    assert_eq!(repo.routes.len(), 0);
+  }
+
+  #[test]
+  fn test_assign_and_find_legs() {
+    let mut repo = MemRepo::new();
+    let dp = MemoryMatrix::with_value(3, 120);
+    repo.add_cab(1, 0, 4);
+    let id = repo.add_order(1, 2, 10, 50, Some(Local::now().naive_local()));
+    let (r, l) = (crate::command::TEMP_ID_BASE, crate::command::TEMP_ID_BASE);
+    let cmds = vec![Command::AssignCab { cab_id: 1 }, Command::CreateRoute { id: r, cab_id: 1 },
+                    Command::CreateLeg { id: l, from: 0, to: 1, place: 0, dist: 2, status: 1, reserve: 3, route_id: r, passengers: 0 },
+                    Command::CreateLeg { id: l + 1, from: 1, to: 2, place: 1, dist: 2, status: 1, reserve: 3, route_id: r, passengers: 1 },
+                    Command::AssignOrder { order_id: id, cab_id: 1, leg_id: Some(l + 1), route_id: r, eta: 2, in_pool: false }];
+    assert_eq!(repo.save(&cmds), Ok(()));
+    assert_eq!(repo.order(id).unwrap().status, OrderStatus::ASSIGNED as i32);
+    assert_eq!(repo.order(id).unwrap().leg_id, 1);
+    let legs = repo.find_legs(&dp);
+    assert_eq!(legs.len(), 2);
+    assert_eq!(legs[1].reserve, 180); // secs as from a database
+    assert_eq!(repo.find_cab_by_status(CabStatus::FREE).len(), 0);
+    // a second plan gets next ids
+    assert_eq!(repo.save(&vec![Command::CreateRoute { id: r, cab_id: 1 }]), Ok(()));
+    assert_eq!(repo.routes[1].id, 1);
+  }
+
+  #[test]
+  fn test_solo_trip_legs_not_extendable() {
+    let mut repo = MemRepo::new();
+    let dp = MemoryMatrix::with_value(3, 120);
+    repo.add_cab(1, 0, 4);
+    let id = repo.add_order(1, 2, 10, 50, Some(Local::now().naive_local()));
+    repo.orders[0].shared = false;
+    let (r, l) = (crate::command::TEMP_ID_BASE, crate::command::TEMP_ID_BASE);
+    let cmds = vec![Command::AssignCab { cab_id: 1 }, Command::CreateRoute { id: r, cab_id: 1 },
+                    Command::CreateLeg { id: l, from: 1, to: 2, place: 0, dist: 2, status: 1, reserve: 3, route_id: r, passengers: 1 },
+                    Command::AssignOrder { order_id: id, cab_id: 1, leg_id: Some(l), route_id: r, eta: 2, in_pool: false }];
+    assert_eq!(repo.save(&cmds), Ok(()));
+    assert_eq!(repo.route_legs(0).len(), 1);
+    assert_eq!(repo.find_legs(&dp).len(), 0); // the extender will not see the route
+  }
+
+  #[test]
+  fn test_cancelled_order_drops_route() {
+    let mut repo = MemRepo::new();
+    repo.add_cab(1, 0, 4);
+    repo.add_cab(2, 0, 4);
+    let id1 = repo.add_order(1, 2, 10, 50, Some(Local::now().naive_local()));
+    let id2 = repo.add_order(1, 2, 10, 50, Some(Local::now().naive_local()));
+    repo.orders[1].status = 6; // cancelled by Kapir in the meantime
+    repo.cabs[1].status = 2; // taken out of service by the driver
+    let r = crate::command::TEMP_ID_BASE;
+    let cmds = vec![Command::AssignCab { cab_id: 1 }, Command::CreateRoute { id: r, cab_id: 1 },
+                    Command::AssignOrder { order_id: id2, cab_id: 1, leg_id: None, route_id: r, eta: 2, in_pool: false },
+                    Command::AssignCab { cab_id: 2 }, Command::CreateRoute { id: r + 1, cab_id: 2 },
+                    Command::AssignOrder { order_id: id1, cab_id: 2, leg_id: None, route_id: r + 1, eta: 2, in_pool: false },
+                    Command::UpdateStat { name: "AvgPoolTime".to_string(), value: 1 }];
+    assert_eq!(repo.save(&cmds), Ok(()));
+    assert_eq!(repo.routes.len(), 0); // neither route written
+    assert_eq!(repo.cabs[0].status, CabStatus::FREE as i32);
+    assert_eq!(repo.order(id1).unwrap().status, OrderStatus::RECEIVED as i32); // waits for the next run
+    assert_eq!(repo.order(id2).unwrap().status, 6); // not overwritten
+    assert_eq!(repo.stats.get("AvgPoolTime"), Some(&1));
+  }
+  #[test]
+  fn test_events_in_order() {
+    let mut repo = MemRepo::new();
+    repo.add_cab(1, 0, 4);
+    repo.add_cab(2, 0, 4);
+    let id = repo.add_order(1, 2, 10, 50, Some(Local::now().naive_local()));
+    repo.cabs[1].status = 2;
+    let r = crate::command::TEMP_ID_BASE;
+    let cmds = vec![Command::AssignCab { cab_id: 1 }, Command::CreateRoute { id: r, cab_id: 1 },
+                    emit(Event::RouteCreated { route_id: r, cab_id: 1 }),
+                    Command::AssignOrder { order_id: id, cab_id: 1, leg_id: None, route_id: r, eta: 2, in_pool: false },
+                    emit(Event::OrderAssigned { order_id: id, route_id: r, cab_id: 1, eta: 2 }),
+                    Command::AssignCab { cab_id: 2 }, Command::CreateRoute { id: r + 1, cab_id: 2 },
+                    emit(Event::CabRelocated { cab_id: 2, route_id: r + 1, to: 3 }),
+                    emit(Event::OrderRefused { order_id: 99 })];
+    assert_eq!(repo.save(&cmds), Ok(()));
+    // the relocation was dropped together with its route, no gap in 'seq'
+    assert_eq!(repo.read_events(0, 10).unwrap(), vec![(1, Event::RouteCreated { route_id: 0, cab_id: 1 }),
+                                                     (2, Event::OrderAssigned { order_id: id, route_id: 0, cab_id: 1, eta: 2 }),
+                                                     (3, Event::OrderRefused { order_id: 99 })]);
+    assert_eq!(repo.read_events(1, 1).unwrap(), vec![(2, Event::OrderAssigned { order_id: id, route_id: 0, cab_id: 1, eta: 2 })]);
+    assert_eq!(repo.read_events(3, 10).unwrap().len(), 0);
+  }
+}
+
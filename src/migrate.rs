@@ -0,0 +1,165 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Versioned schema - migrations are embedded in the binary, one script per SQL dialect,
+/// applied ones are recorded in the 'schema_version' table; 'kern migrate' applies the missing ones
+use crate::command::Param;
+use crate::repo::{Repository, SqlConn};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Dialect {
+    Postgres,
+    MySql,
+    Sqlite
+}
+
+pub struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    pub postgres: &'static str,
+    pub mysql: &'static str,
+    pub sqlite: &'static str
+}
+
+impl Migration {
+    fn script(&self, dialect: Dialect) -> &'static str {
+        return match dialect {
+            Dialect::Postgres => self.postgres,
+            Dialect::MySql => self.mysql,
+            Dialect::Sqlite => self.sqlite
+        };
+    }
+}
+
+// new ones go at the end, with the next version; sql/create*.sql scripts have to record the last one
+pub const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, name: "baseline",
+        postgres: include_str!("../sql/migrations/001-baseline.sql"),
+        mysql: include_str!("../sql/migrations/001-baseline-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/001-baseline-sqlite.sql") },
+    Migration { version: 2, name: "dwell",
+        postgres: include_str!("../sql/migrations/002-dwell.sql"),
+        mysql: include_str!("../sql/migrations/002-dwell-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/002-dwell-sqlite.sql") },
+    Migration { version: 3, name: "id_block",
+        postgres: include_str!("../sql/migrations/003-id-block.sql"),
+        mysql: include_str!("../sql/migrations/003-id-block-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/003-id-block-sqlite.sql") },
+    Migration { version: 4, name: "outbox",
+        postgres: include_str!("../sql/migrations/004-outbox.sql"),
+        mysql: include_str!("../sql/migrations/004-outbox-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/004-outbox-sqlite.sql") },
+    Migration { version: 5, name: "passengers",
+        postgres: include_str!("../sql/migrations/005-passengers.sql"),
+        mysql: include_str!("../sql/migrations/005-passengers-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/005-passengers-sqlite.sql") },
+    Migration { version: 6, name: "features",
+        postgres: include_str!("../sql/migrations/006-features.sql"),
+        mysql: include_str!("../sql/migrations/006-features-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/006-features-sqlite.sql") },
+    Migration { version: 7, name: "transfers",
+        postgres: include_str!("../sql/migrations/007-transfers.sql"),
+        mysql: include_str!("../sql/migrations/007-transfers-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/007-transfers-sqlite.sql") },
+    Migration { version: 8, name: "breakdown",
+        postgres: include_str!("../sql/migrations/008-breakdown.sql"),
+        mysql: include_str!("../sql/migrations/008-breakdown-mysql.sql"),
+        sqlite: include_str!("../sql/migrations/008-breakdown-sqlite.sql") },
+];
+
+// the same in all dialects
+const CREATE_VERSION_TABLE: &str = "CREATE TABLE IF NOT EXISTS schema_version (\
+    version INTEGER NOT NULL, \
+    name VARCHAR(255) NOT NULL, \
+    applied TIMESTAMP DEFAULT CURRENT_TIMESTAMP, \
+    PRIMARY KEY (version))";
+
+// the version this kern was built for
+pub fn latest_version() -> i32 {
+    return MIGRATIONS.last().map_or(0, |m| m.version);
+}
+
+// 0 if the table is there but empty
+pub fn read_version<T: SqlConn>(conn: &mut T) -> Result<i32, String> {
+    let rows = conn.query("SELECT MAX(version) FROM schema_version")?;
+    return Ok(rows.first().and_then(|r| r[0].as_i64()).unwrap_or(0) as i32);
+}
+
+// each migration in its own transaction; MySQL commits DDL at once though, a failed migration
+// may leave some of its tables and columns behind - MySQL scripts check for them, so a rerun is fine
+pub fn migrate<T: SqlConn>(conn: &mut T) -> Result<Vec<i32>, String> {
+    conn.execute_script(CREATE_VERSION_TABLE)?;
+    let current = read_version(conn)?;
+    let dialect = conn.dialect();
+    let mut applied: Vec<i32> = vec![];
+    for m in MIGRATIONS.iter().filter(|m| m.version > current) {
+        conn.begin()?;
+        let ret = conn.execute_script(m.script(dialect))
+            .and_then(|_| conn.execute("INSERT INTO schema_version (version, name) VALUES (?,?)",
+                                       &vec![vec![Param::Int(m.version), Param::Text(m.name.to_string())]])
+                              .map_err(|e| e.1));
+        if let Err(e) = ret {
+            let _ = conn.rollback();
+            return Err(format!("migration {} '{}' failed: {}", m.version, m.name, e));
+        }
+        conn.commit().map_err(|e| format!("migration {} '{}' not committed: {}", m.version, m.name, e))?;
+        applied.push(m.version);
+    }
+    return Ok(applied);
+}
+
+// the dispatcher does not start with a schema it does not know
+pub fn check_schema(conn: &mut dyn Repository) -> Result<(), String> {
+    let latest = latest_version();
+    return match conn.schema_version() {
+        Err(e) => Err(format!("No schema version in the database, run 'kern migrate' first ({})", e)),
+        Ok(v) if v < latest =>
+            Err(format!("Database schema is version {}, this kern needs {}, run 'kern migrate' first", v, latest)),
+        Ok(v) if v > latest =>
+            Err(format!("Database schema is version {}, newer than {} known to this kern, upgrade kern", v, latest)),
+        Ok(_) => Ok(())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::sqlite_repo::SqliteRepo;
+
+  #[test]
+  fn test_versions_in_order() {
+    for (i, m) in MIGRATIONS.iter().enumerate() {
+      assert_eq!(m.version, i as i32 + 1);
+    }
+    assert_eq!(latest_version(), MIGRATIONS.len() as i32);
+  }
+
+  #[test]
+  fn test_migrate_and_check() {
+    let mut repo = SqliteRepo::new("sqlite://:memory:").unwrap();
+    assert!(check_schema(&mut repo).is_err());
+    let applied = migrate(&mut repo).unwrap();
+    assert_eq!(applied.len(), MIGRATIONS.len());
+    assert!(check_schema(&mut repo).is_ok());
+    assert_eq!(migrate(&mut repo).unwrap().len(), 0); // nothing left
+    assert_eq!(repo.query("SELECT COUNT(*) FROM stat").unwrap()[0][0].as_i64(), Some(27));
+
+    repo.execute("INSERT INTO schema_version (version, name) VALUES (?,?)",
+                 &vec![vec![Param::Int(latest_version() + 1), Param::Text("future".to_string())]]).unwrap();
+    assert!(check_schema(&mut repo).unwrap_err().contains("upgrade kern"));
+  }
+
+  // tables of kern before versions, without columns added later
+  #[test]
+  fn test_migrate_pre_version_database() {
+    let mut repo = SqliteRepo::new("sqlite://:memory:").unwrap();
+    repo.execute_script(MIGRATIONS[0].sqlite).unwrap();
+    repo.execute_script("INSERT INTO cab (id, location, status, seats) VALUES (0, 0, 2, 4);\
+                         INSERT INTO route (id, status, cab_id) VALUES (7, 1, 0)").unwrap();
+    assert!(repo.query("SELECT dwell FROM stop").is_err());
+    assert_eq!(migrate(&mut repo).unwrap().len(), MIGRATIONS.len());
+    assert!(check_schema(&mut repo).is_ok());
+    assert!(repo.query("SELECT dwell FROM stop").is_ok());
+    assert_eq!(repo.query("SELECT next_id FROM id_block WHERE name='route'").unwrap()[0][0].as_i64(), Some(8));
+  }
+}
@@ -1,10 +1,10 @@
 use chrono::NaiveDateTime;
 use std::sync::{Mutex, MutexGuard};
 
-pub const MAXSTOPSNUMB : usize = 5200;
+// upper limits only, buffers are sized at runtime
+pub const MAXSTOPSNUMB : usize = 30000; // stop ids, the distance matrix takes (max id + 1)^2 * 2 bytes
 pub const MAXORDERSNUMB: usize = 4000; // max not assigned
-pub const MAXCABSNUMB: usize = 18000;
-pub const MAXBRANCHNUMB: usize = 1000; // size of pool finder's response
+pub const MAXCABSNUMB: usize = i16::MAX as usize; // cab indices are i16 in Branch and LCM
 
 pub const MAXINPOOL : usize = 4;
 pub const MAXORDID : usize = MAXINPOOL * 2;
@@ -16,7 +16,44 @@ pub struct Stop {
     pub bearing: i32,
 	pub latitude: f64,
     pub longitude: f64,
-    pub capacity: i16
+    pub capacity: i16,
+    pub dwell: i16 // secs, how long it takes at the stop
+}
+
+// how long a cab stands at stops, secs: the dwell time of a stop and some time for each boarding passenger
+#[derive(Clone)]
+pub struct StopWait {
+    dwell: Vec<i16>, // indexed with stop ids
+    default: i16, // stops not in 'dwell'
+    board: i16
+}
+
+impl StopWait {
+    pub fn new(stops: &Vec<Stop>, default: i16, board: i16) -> Self {
+        let size = stops.iter().map(|s| s.id as usize + 1).max().unwrap_or(0);
+        let mut dwell: Vec<i16> = vec![default; size];
+        for s in stops.iter() { dwell[s.id as usize] = s.dwell; }
+        return Self { dwell, default, board };
+    }
+
+    // the same time at all stops, no matter how many get in - as with a global 'stop_wait'
+    #[cfg(test)]
+    pub fn same(secs: i16) -> Self {
+        return Self { dwell: vec![], default: secs, board: 0 };
+    }
+
+    pub fn at(&self, stop: usize) -> i16 {
+        return match self.dwell.get(stop) { Some(x) => *x, None => self.default };
+    }
+
+    // time spent at 'from' before going to 'to', if the cab does not move only boarding takes time
+    pub fn between(&self, from: usize, to: usize, boarding: i16) -> i16 {
+        return (if from == to { 0 } else { self.at(from) }) + boarding * self.board;
+    }
+
+    pub fn boarding(&self, passengers: i16) -> i16 {
+        return passengers * self.board;
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -24,15 +61,19 @@ pub struct Order {
     pub id: i64, // -1 as to-be-dropped
 	pub from: i32,
     pub to: i32,
-	pub wait: i32, // expected pick up time
-	pub loss: i32, // allowed loss of time in detour
-	pub dist: i32, // distance without pool
- //   pub shared: bool, // agreed to be in pool
+	pub wait: i32, // expected pick up time, in seconds
+	pub loss: i32, // allowed loss of time in detour, in percents
+	pub dist: i32, // distance without pool, in seconds
+    pub passengers: i32, // seats taken, more than one for group bookings
+    pub required: i32, // features the cab must have, see Cab
+    pub shared: bool, // agreed to be in pool, solo trip otherwise
+    pub transfer: bool, // one of two segments of a trip with a cab change, see transfer.rs
  //   pub in_pool: bool, // actually in pool
     pub received: Option<NaiveDateTime>,
  //   pub started: Option<NaiveDateTime>,
  //   pub completed: Option<NaiveDateTime>,
     pub at_time: Option<NaiveDateTime>,
+    pub released: Option<NaiveDateTime>, // put back into demand when its cab broke down, dispatched first; see breakdown.rs
  //   pub eta: i32, // proposed wait time
     pub route_id: i64,
   //  cab: Cab,
@@ -48,9 +89,13 @@ pub struct OrderTransfer {
     pub to: i32,
 	pub wait: i32,
 	pub loss: i32,
-	pub dist: i32
+	pub dist: i32,
+    pub passengers: i32,
+    pub required: i32,
+    pub shared: i32 // 0 - solo trip
 }
 
+#[derive(Clone)]
 pub struct CabAssign {
     pub id: i64,
     pub cust_id: i64,
@@ -59,6 +104,7 @@ pub struct CabAssign {
     pub to: i32,
     pub loss: i32,
     pub shared: bool,
+    pub passengers: i32,
     pub received: Option<NaiveDateTime>
 }
 
@@ -67,8 +113,14 @@ pub struct CabAssign {
 pub struct Cab {
     pub id: i64,
 	pub location: i32, // last known location, current location if FREE
-    pub dist: i16, // for cabs on last leg of a route, this is the distance to the last stop
-    pub seats: i32
+    pub dist: i16, // for cabs on last leg of a route, this is the distance to the last stop, in seconds
+    pub seats: i32,
+    pub features: i32 // bits: 1 wheelchair, 2 luggage, 4 child seat
+}
+
+// the cab has everything the order requires
+pub fn fits(features: i32, required: i32) -> bool {
+    return features & required == required;
 }
 
 #[derive(Copy, Clone)]
@@ -78,13 +130,14 @@ pub struct Leg {
     pub from: i32,
     pub to: i32,
     pub place: i32, // place in route
-    pub dist: i32,
-    pub reserve: i32, // to match constraints - wait, loss
+    pub dist: i32, // seconds
+    pub reserve: i32, // to match constraints - wait, loss; seconds
     pub started: Option<NaiveDateTime>,
  //   pub completed: Option<NaiveDateTime>,
     pub status: RouteStatus,
     pub passengers: i32, // to meet cab's capacity
     pub seats: i32,
+    pub features: i32, // of the cab
 }
 
 /*pub struct Customer {
@@ -96,22 +149,24 @@ pub struct Leg {
 // dispatcher is interested only into free cabs and non-assigned customers
 // TODO: cabs on last leg should be considered
 #[derive(Copy, Clone, PartialEq)]
+#[allow(non_camel_case_types)] // names as in Kapir
 pub enum CabStatus {
 //    ASSIGNED,
     FREE = 1,
 //    CHARGING, // out of order, ...
+    OUT_OF_SERVICE = 3, // broke down or went offline, routes are given up
 }
 
 #[derive(Copy, Clone)]
 pub enum OrderStatus {
     RECEIVED = 0,  // sent by customer
     ASSIGNED = 1,  // assigned to a cab, a proposal sent to customer with time-of-arrival
-//    ACCEPTED,  // plan accepted by customer, waiting for the cab
+    ACCEPTED = 2,  // plan accepted by customer, waiting for the cab
 //    CANCELLED, // cancelled by customer before assignment
 //    REJECTED,  // proposal rejected by customer
-//    ABANDONED, // cancelled after assignment but before 'PICKEDUP'
+    ABANDONED = 5, // cancelled after assignment but before 'PICKEDUP', or a no-show
 //    REFUSED,   // no cab available, cab broke down at any stage
-//    PICKEDUP,
+    PICKEDUP = 7,
 //    COMPLETED
 }
 
@@ -153,7 +208,7 @@ pub struct Route {
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
 pub struct Branch {
-	pub cost: i16, // the length of the route
+	pub cost: i32, // the length of the route, in seconds; i16 would overflow
 	pub outs: u8, // BYTE, number of OUT nodes, so that we can guarantee enough IN nodes
 	pub ord_numb: i16, // it is in fact ord number *2; length of vectors below - INs & OUTs
 	pub ord_ids : [i16; MAXORDID],
@@ -189,13 +244,17 @@ pub struct KernCfg {
     pub use_extern_pool: bool,
     pub use_extender: bool,
     pub thread_numb: i32,
-    pub stop_wait: i16,
+    pub stop_wait: i16, // dwell time of stops that do not have one
+    pub board_wait: i16, // secs per boarding passenger
     pub cab_speed: i8,
     pub max_pool5_size: i32,
     pub max_pool4_size: i32,
     pub max_pool3_size: i32,
     pub max_pool2_size: i32,
     pub solver_delay: i32,
+    pub sql_retries: u8, // a failed plan is rolled back and tried again, then skipped
+    pub transfer_dist: i32, // secs, longer trips are split at a transfer stop, 0 - never
+    pub no_show_wait: i32, // secs after the cab left the pickup without the customer, 0 - no-shows are not looked for
 }
 
 impl KernCfg {
@@ -206,18 +265,22 @@ impl KernCfg {
             run_after: 15, // secs
             max_legs: 8,
             max_angle: 120,
-            max_angle_dist: 3, 
+            max_angle_dist: 180, // secs
             use_pool: true,
             use_extern_pool: false,
             use_extender: false,
             thread_numb: 11,
-            stop_wait: 1,
+            stop_wait: 60, // secs
+            board_wait: 0, // secs
             cab_speed: 30,
             max_pool5_size: 40,
             max_pool4_size: 130,
             max_pool3_size: 350,
             max_pool2_size: 1000,
             solver_delay: 60,
+            sql_retries: 2,
+            transfer_dist: 0,
+            no_show_wait: 0,
         }
     }
 
@@ -239,11 +302,15 @@ impl KernCfg {
         s.use_extender = val.use_extender;
         s.thread_numb = val.thread_numb;
         s.stop_wait = val.stop_wait;
+        s.board_wait = val.board_wait;
         s.cab_speed = val.cab_speed;
         s.max_pool5_size = val.max_pool5_size;
         s.max_pool4_size = val.max_pool4_size;
         s.max_pool3_size = val.max_pool3_size;
         s.max_pool2_size = val.max_pool2_size;
         s.solver_delay = val.solver_delay;
+        s.sql_retries = val.sql_retries;
+        s.transfer_dist = val.transfer_dist;
+        s.no_show_wait = val.no_show_wait;
     }
 }
@@ -0,0 +1,120 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// MySQL backend of the repository
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use mysql::{Opts, OptsBuilder, Pool, PooledConn, Row, Value};
+use mysql::consts::CapabilityFlags;
+use mysql::prelude::*;
+use crate::command::Param;
+use crate::migrate::Dialect;
+use crate::repo::{DbRow, DbValue, SqlConn};
+
+pub struct MySqlRepo {
+    conn: PooledConn
+}
+
+impl MySqlRepo {
+    pub fn new(url: &str) -> Result<Self, String> {
+        // rows found, not only those with a new value - a cab already ASSIGNED is not a conflict
+        let opts = Opts::from_url(url).map_err(|e| format!("Wrong MySQL url: {}", e))?;
+        let opts = OptsBuilder::from_opts(opts).additional_capabilities(CapabilityFlags::CLIENT_FOUND_ROWS);
+        let pool = Pool::new(opts).map_err(|e| format!("Could not get pool to MySQL: {}", e))?;
+        let conn = pool.get_conn().map_err(|e| format!("Could not connect to MySQL: {}", e))?;
+        return Ok(Self { conn });
+    }
+}
+
+impl SqlConn for MySqlRepo {
+    fn query(&mut self, sql: &str) -> Result<Vec<DbRow>, String> {
+        let rows: Vec<Row> = self.conn.query(sql).map_err(|e| e.to_string())?;
+        return Ok(rows.into_iter().map(|r| r.unwrap().into_iter().map(to_db_value).collect()).collect());
+    }
+
+    fn execute(&mut self, sql: &'static str, rows: &Vec<Vec<Param>>) -> Result<Vec<u64>, (usize, String)> {
+        let stmt = self.conn.prep(sql).map_err(|e| (0, e.to_string()))?; // cached by the connection
+        let mut changed: Vec<u64> = Vec::with_capacity(rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            let params: Vec<Value> = row.iter().map(to_value).collect();
+            self.conn.exec_drop(&stmt, params).map_err(|e| (i, e.to_string()))?;
+            changed.push(self.conn.affected_rows());
+        }
+        return Ok(changed);
+    }
+
+    fn begin(&mut self) -> Result<(), String> {
+        return self.conn.query_drop("START TRANSACTION").map_err(|e| e.to_string());
+    }
+
+    fn commit(&mut self) -> Result<(), String> {
+        return self.conn.query_drop("COMMIT").map_err(|e| e.to_string());
+    }
+
+    fn rollback(&mut self) -> Result<(), String> {
+        return self.conn.query_drop("ROLLBACK").map_err(|e| e.to_string());
+    }
+
+    fn execute_script(&mut self, sql: &str) -> Result<(), String> {
+        return self.conn.query_drop(sql).map_err(|e| e.to_string()); // multi-statements are on by default
+    }
+
+    fn dialect(&self) -> Dialect {
+        return Dialect::MySql;
+    }
+}
+
+fn to_value(param: &Param) -> Value {
+    return match param {
+        Param::Int(x) => Value::Int(*x as i64),
+        Param::BigInt(x) => Value::Int(*x),
+        Param::Bool(x) => Value::Int(*x as i64),
+        Param::Text(x) => Value::Bytes(x.as_bytes().to_vec()),
+        Param::Time(Some(t)) => time_to_value(t),
+        Param::Time(None) => Value::NULL
+    };
+}
+
+fn time_to_value(t: &NaiveDateTime) -> Value {
+    return Value::Date(t.year() as u16, t.month() as u8, t.day() as u8,
+                       t.hour() as u8, t.minute() as u8, t.second() as u8, t.nanosecond() / 1000);
+}
+
+fn to_db_value(val: Value) -> DbValue {
+    return match val {
+        Value::NULL => DbValue::Null,
+        Value::Bytes(x) => DbValue::Text(String::from_utf8_lossy(&x).to_string()),
+        Value::Int(x) => DbValue::Int(x),
+        Value::UInt(x) => DbValue::Int(x as i64),
+        Value::Float(x) => DbValue::Float(x as f64),
+        Value::Double(x) => DbValue::Float(x),
+        Value::Date(y, m, d, h, mi, s, us) =>
+            match NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32)
+                             .and_then(|x| x.and_hms_micro_opt(h as u32, mi as u32, s as u32, us)) {
+                Some(x) => DbValue::Time(x),
+                None => DbValue::Null // zero date
+            },
+        Value::Time(..) => DbValue::Null // no such columns
+    };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_db_value() {
+    assert_eq!(to_db_value(Value::Bytes(b"12".to_vec())).as_i64(), Some(12));
+    assert_eq!(to_db_value(Value::UInt(7)), DbValue::Int(7));
+    assert_eq!(to_db_value(Value::Date(2025, 3, 1, 10, 20, 30, 0)).as_time(),
+               NaiveDate::from_ymd_opt(2025, 3, 1).unwrap().and_hms_opt(10, 20, 30));
+    assert_eq!(to_db_value(Value::Date(0, 0, 0, 0, 0, 0, 0)), DbValue::Null);
+  }
+
+  #[test]
+  fn test_to_value() {
+    let t = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap().and_hms_opt(10, 20, 30).unwrap();
+    assert_eq!(to_value(&Param::Bool(true)), Value::Int(1));
+    assert_eq!(to_value(&Param::Time(None)), Value::NULL);
+    assert_eq!(to_db_value(to_value(&Param::Time(Some(t)))).as_time(), Some(t));
+  }
+}
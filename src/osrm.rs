@@ -0,0 +1,299 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Travel times from an OSRM-style routing engine, its '/table' service
+/// Stops are sent in blocks, so that no URL is longer than the engine (or a proxy in front of it) accepts;
+/// travel times of a block that could not be fetched are computed from coordinates, as without the engine
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use log::{info, warn};
+use crate::distance::{DistanceProvider, DistMatrix, Haversine, haversine_secs};
+use crate::model::Stop;
+
+pub const MAX_URL: usize = 8000; // default, a common limit of HTTP servers
+const TIMEOUT: u64 = 30; // secs, for one request
+const CONNECT_TIMEOUT: u64 = 5; // secs, the dispatcher waits for it at startup and in 'osrm_refresh'
+const ID_DIGITS: usize = 5; // MAXSTOPSNUMB - indices in 'sources' and 'destinations' are not longer
+
+pub struct Osrm {
+    pub matrix: DistMatrix,
+    url: String, // e.g. http://localhost:5000/table/v1/driving
+    max_url: usize,
+    cab_speed: i8 // for the fallback
+}
+
+impl DistanceProvider for Osrm {
+    fn matrix(&self) -> &DistMatrix { return &self.matrix; }
+    fn matrix_mut(&mut self) -> &mut DistMatrix { return &mut self.matrix; }
+
+    fn refresh(&mut self, stops: &Vec<Stop>) -> bool {
+        self.fetch(stops);
+        return true;
+    }
+}
+
+impl Osrm {
+    // stop ids should have been checked with 'matrix_size'
+    pub fn new(url: &str, max_url: usize, stops: &Vec<Stop>, cab_speed: i8) -> Self {
+        let mut ret = Self { matrix: Haversine::new(stops, cab_speed).matrix,
+                             url: url.trim_end_matches('/').to_string(), max_url, cab_speed };
+        ret.fetch(stops);
+        return ret;
+    }
+
+    // returns the number of blocks that could not be fetched
+    pub fn fetch(&mut self, stops: &Vec<Stop>) -> usize {
+        let block = match block_size(&self.url, stops, self.max_url) {
+            Ok(b) => b,
+            Err(e) => {
+                warn!("Travel times not fetched from routing engine: {}", e);
+                self.fallback(stops, stops);
+                return 1;
+            }
+        };
+        let blocks: Vec<&[Stop]> = stops.chunks(block).collect();
+        let mut failed = 0;
+        let mut unreachable = false; // no use trying the other blocks
+        for from in blocks.iter() {
+            for to in blocks.iter() {
+                if unreachable {
+                    self.fallback(from, to);
+                    failed += 1;
+                    continue;
+                }
+                let url = table_url(&self.url, from, to);
+                let ret = get(&url).map_err(|(conn, e)| { unreachable = conn; e })
+                                   .and_then(|body| parse_durations(&body, from.len(), to.len()));
+                match ret {
+                    Ok(durations) => {
+                        for (i, s1) in from.iter().enumerate() {
+                            for (j, s2) in to.iter().enumerate() {
+                                let secs = match durations[i][j] {
+                                    Some(d) => secs(d, s1.id == s2.id),
+                                    None => fallback_secs(s1, s2, self.cab_speed) // no route
+                                };
+                                self.matrix.set(s1.id as usize, s2.id as usize, secs);
+                            }
+                        }
+                    },
+                    Err(e) => {
+                        warn!("Travel times from routing engine failed, computed from coordinates: {}", e);
+                        self.fallback(from, to);
+                        failed += 1;
+                    }
+                }
+            }
+        }
+        info!("Travel times from routing engine: {}, requests: {}, failed: {}", self.url, blocks.len() * blocks.len(), failed);
+        return failed;
+    }
+
+    fn fallback(&mut self, from: &[Stop], to: &[Stop]) {
+        for s1 in from.iter() {
+            for s2 in to.iter() {
+                self.matrix.set(s1.id as usize, s2.id as usize, fallback_secs(s1, s2, self.cab_speed));
+            }
+        }
+    }
+}
+
+fn fallback_secs(from: &Stop, to: &Stop, cab_speed: i8) -> i16 {
+    return if from.id == to.id { 0 } else { haversine_secs(from, to, cab_speed) };
+}
+
+// a transfer between different stops takes at least one second, as in 'Haversine'
+fn secs(duration: f64, same_stop: bool) -> i16 {
+    if same_stop {
+        return 0;
+    }
+    return duration.round().clamp(1.0, i16::MAX as f64) as i16;
+}
+
+fn coords(stops: &[Stop]) -> String {
+    return stops.iter().map(|s| format!("{:.6},{:.6}", s.longitude, s.latitude)).collect::<Vec<String>>().join(";");
+}
+
+fn indices(from: usize, to: usize) -> String {
+    return (from .. to).map(|i| i.to_string()).collect::<Vec<String>>().join(";");
+}
+
+// rows: 'from', columns: 'to'; one block is sent once if both are the same
+pub fn table_url(url: &str, from: &[Stop], to: &[Stop]) -> String {
+    if std::ptr::eq(from, to) {
+        return format!("{}/{}", url, coords(from));
+    }
+    return format!("{}/{};{}?sources={}&destinations={}", url, coords(from), coords(to),
+                   indices(0, from.len()), indices(from.len(), from.len() + to.len()));
+}
+
+// the biggest number of stops in a block that keeps URLs within 'max_url'
+pub fn block_size(url: &str, stops: &Vec<Stop>, max_url: usize) -> Result<usize, String> {
+    let coord = stops.iter().map(|s| coords(std::slice::from_ref(s)).len()).max().unwrap_or(0) + 1; // with ';'
+    let fixed = url.len() + "/?sources=&destinations=".len();
+    let per_stop = 2 * (coord + ID_DIGITS + 1); // a stop in both blocks, in coordinates and in indices
+    if max_url < fixed + per_stop {
+        return Err(format!("URL limit {} too small for even one stop", max_url));
+    }
+    return Ok(std::cmp::max(1, std::cmp::min(stops.len(), (max_url - fixed) / per_stop)));
+}
+
+// plain HTTP/1.0, so that the response is neither chunked nor kept alive;
+// the error says if the engine could not be connected to at all
+pub fn get(url: &str) -> Result<String, (bool, String)> {
+    let rest = url.strip_prefix("http://").ok_or((true, format!("Only http:// is supported: {}", url)))?;
+    let (host, path) = match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/")
+    };
+    let addr = if host.contains(':') { host.to_string() } else { format!("{}:80", host) };
+    let mut stream = connect(&addr).map_err(|e| (true, e))?;
+    let err = |e: std::io::Error| (false, format!("{}: {}", addr, e));
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(TIMEOUT)));
+    let _ = stream.set_write_timeout(Some(Duration::from_secs(TIMEOUT)));
+    stream.write_all(format!("GET {} HTTP/1.0\r\nHost: {}\r\nAccept: application/json\r\n\r\n", path, host).as_bytes())
+          .map_err(err)?;
+    let mut response: Vec<u8> = vec![];
+    stream.read_to_end(&mut response).map_err(err)?;
+    let response = String::from_utf8_lossy(&response);
+    let (head, body) = response.split_once("\r\n\r\n").ok_or((false, format!("Malformed response from {}", addr)))?;
+    let status = head.lines().next().unwrap_or("");
+    if status.split_whitespace().nth(1) != Some("200") {
+        return Err((false, format!("{} responded: {}", addr, status)));
+    }
+    return Ok(body.to_string());
+}
+
+// 'TcpStream::connect' would wait as long as the OS lets it
+fn connect(addr: &str) -> Result<TcpStream, String> {
+    let mut last = format!("{}: no address", addr);
+    for a in addr.to_socket_addrs().map_err(|e| format!("{}: {}", addr, e))? {
+        match TcpStream::connect_timeout(&a, Duration::from_secs(CONNECT_TIMEOUT)) {
+            Ok(s) => { return Ok(s); },
+            Err(e) => { last = format!("{}: {}", addr, e); }
+        }
+    }
+    return Err(last);
+}
+
+// {"code":"Ok","durations":[[0,12.3],[11.9,null]]}, null if there is no route
+pub fn parse_durations(body: &str, rows: usize, cols: usize) -> Result<Vec<Vec<Option<f64>>>, String> {
+    let json: serde_json::Value = serde_json::from_str(body).map_err(|e| format!("Not JSON: {}", e))?;
+    if json["code"] != "Ok" {
+        return Err(format!("Routing engine code: {}", json["code"]));
+    }
+    let durations = json["durations"].as_array().ok_or("No durations in response")?;
+    if durations.len() != rows {
+        return Err(format!("Expected {} rows of durations, got {}", rows, durations.len()));
+    }
+    let mut ret: Vec<Vec<Option<f64>>> = vec![];
+    for row in durations.iter() {
+        let row = row.as_array().ok_or("Durations should be rows of numbers")?;
+        if row.len() != cols {
+            return Err(format!("Expected {} columns of durations, got {}", cols, row.len()));
+        }
+        ret.push(row.iter().map(|d| d.as_f64()).collect());
+    }
+    return Ok(ret);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::net::TcpListener;
+  use std::sync::{Arc, Mutex};
+  use std::thread;
+
+  fn get_stops(count: i64) -> Vec<Stop> {
+    let mut stops: Vec<Stop> = vec![];
+    for i in 0..count {
+      stops.push(Stop{ id: i, bearing: 0, latitude: 49.0 + 0.01 * i as f64, longitude: 19.0 + 0.001 * i as f64, capacity: 10, dwell: 60 });
+    }
+    return stops;
+  }
+
+  // stop id is kept in longitude; duration from a to b is 100 + 10 * a + b
+  fn stub_server() -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/table/v1/driving", listener.local_addr().unwrap());
+    let paths = Arc::new(Mutex::new(vec![]));
+    let log = paths.clone();
+    thread::spawn(move || {
+      for stream in listener.incoming() {
+        let mut stream = stream.unwrap();
+        let mut buf = [0u8; 65536];
+        let n = stream.read(&mut buf).unwrap();
+        let request = String::from_utf8_lossy(&buf[..n]).to_string();
+        let path = request.split_whitespace().nth(1).unwrap().to_string();
+        log.lock().unwrap().push(path.clone());
+        let (coords, params) = path.rsplit('/').next().unwrap().split_once('?').unwrap_or((path.rsplit('/').next().unwrap(), ""));
+        let ids: Vec<i64> = coords.split(';')
+                  .map(|c| ((c.split(',').next().unwrap().parse::<f64>().unwrap() - 19.0) * 1000.0).round() as i64).collect();
+        let param = |name: &str| -> Vec<usize> {
+          match params.split('&').find(|p| p.starts_with(name)) {
+            Some(p) => p[name.len() + 1..].split(';').map(|i| i.parse().unwrap()).collect(),
+            None => (0..ids.len()).collect()
+          }
+        };
+        let rows: Vec<String> = param("sources").iter().map(|s|
+            format!("[{}]", param("destinations").iter().map(|d| (100 + 10 * ids[*s] + ids[*d]).to_string())
+                                                      .collect::<Vec<String>>().join(","))).collect();
+        let body = format!("{{\"code\":\"Ok\",\"durations\":[{}]}}", rows.join(","));
+        let _ = stream.write_all(format!("HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{}", body).as_bytes());
+      }
+    });
+    return (url, paths);
+  }
+
+  #[test]
+  fn test_fetch_in_blocks() {
+    let stops = get_stops(7);
+    let (url, paths) = stub_server();
+    let max_url = url.len() + 200;
+    let dp = Osrm::new(&url, max_url, &stops, 30);
+    let paths = paths.lock().unwrap();
+    assert!(paths.len() > 1);
+    for p in paths.iter() {
+      assert!(url.find("/table").unwrap() + p.len() <= max_url); // "http://host:port" + path
+    }
+    assert_eq!(dp.dist(0, 0), 0);
+    assert_eq!(dp.dist(0, 6), 106);
+    assert_eq!(dp.dist(6, 0), 160);
+    assert_eq!(dp.dist(3, 5), 135);
+  }
+
+  #[test]
+  fn test_fallback_to_haversine() {
+    let stops = get_stops(3);
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port(); // nobody listens after drop
+    let mut dp = Osrm::new(&format!("http://127.0.0.1:{}/table/v1/driving", port), 8000, &stops, 30);
+    let hav = Haversine::new(&stops, 30);
+    assert_eq!(dp.dist(0, 2), hav.dist(0, 2));
+    assert_eq!(dp.dist(2, 1), hav.dist(2, 1));
+    assert_eq!(dp.fetch(&stops), 1);
+  }
+
+  // the engine is down, all blocks are computed from coordinates after the first one failed
+  #[test]
+  fn test_fallback_all_blocks() {
+    let stops = get_stops(7);
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let url = format!("http://127.0.0.1:{}/table/v1/driving", port);
+    let mut dp = Osrm::new(&url, url.len() + 200, &stops, 30);
+    let hav = Haversine::new(&stops, 30);
+    let blocks = stops.len().div_ceil(block_size(&url, &stops, url.len() + 200).unwrap());
+    assert!(blocks > 1);
+    assert_eq!(dp.fetch(&stops), blocks * blocks);
+    assert_eq!(dp.dist(0, 6), hav.dist(0, 6));
+    assert_eq!(dp.dist(6, 0), hav.dist(6, 0));
+  }
+
+  #[test]
+  fn test_parse_durations() {
+    let ret = parse_durations("{\"code\":\"Ok\",\"durations\":[[0,12.4],[null,0]]}", 2, 2).unwrap();
+    assert_eq!(ret[0][1], Some(12.4));
+    assert_eq!(ret[1][0], None);
+    assert!(parse_durations("{\"code\":\"InvalidQuery\"}", 2, 2).is_err());
+    assert!(parse_durations("{\"code\":\"Ok\",\"durations\":[[0,1]]}", 2, 2).is_err());
+  }
+}
@@ -0,0 +1,110 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// PostgreSQL backend of the repository, schema in sql/create.sql
+/// Reads use the simple query protocol, like the text protocol of MySQL - values come as text
+/// Writes are prepared statements with typed parameters
+use std::collections::HashMap;
+use postgres::{Client, NoTls, SimpleQueryMessage, Statement};
+use postgres::types::ToSql;
+use crate::command::Param;
+use crate::migrate::Dialect;
+use crate::repo::{DbRow, DbValue, SqlConn};
+
+pub struct PgRepo {
+    client: Client,
+    statements: HashMap<&'static str, Statement>
+}
+
+impl PgRepo {
+    pub fn new(url: &str) -> Result<Self, String> {
+        let client = Client::connect(url, NoTls).map_err(|e| format!("Could not connect to PostgreSQL: {}", e))?;
+        return Ok(Self { client, statements: HashMap::new() });
+    }
+}
+
+impl SqlConn for PgRepo {
+    fn query(&mut self, sql: &str) -> Result<Vec<DbRow>, String> {
+        let mut ret: Vec<DbRow> = Vec::new();
+        for msg in self.client.simple_query(sql).map_err(|e| e.to_string())? {
+            if let SimpleQueryMessage::Row(r) = msg {
+                ret.push((0..r.len()).map(|i| match r.get(i) {
+                    Some(x) => DbValue::Text(x.to_string()),
+                    None => DbValue::Null
+                }).collect());
+            }
+        }
+        return Ok(ret);
+    }
+
+    fn execute(&mut self, sql: &'static str, rows: &Vec<Vec<Param>>) -> Result<Vec<u64>, (usize, String)> {
+        if !self.statements.contains_key(sql) {
+            let stmt = self.client.prepare(&to_numbered(sql)).map_err(|e| (0, e.to_string()))?;
+            self.statements.insert(sql, stmt);
+        }
+        let stmt = &self.statements[sql];
+        let mut changed: Vec<u64> = Vec::with_capacity(rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            let params: Vec<&(dyn ToSql + Sync)> = row.iter().map(to_sql_param).collect();
+            changed.push(self.client.execute(stmt, &params).map_err(|e| (i, e.to_string()))?);
+        }
+        return Ok(changed);
+    }
+
+    fn begin(&mut self) -> Result<(), String> {
+        return self.client.batch_execute("BEGIN").map_err(|e| e.to_string());
+    }
+
+    fn commit(&mut self) -> Result<(), String> {
+        return self.client.batch_execute("COMMIT").map_err(|e| e.to_string());
+    }
+
+    fn rollback(&mut self) -> Result<(), String> {
+        return self.client.batch_execute("ROLLBACK").map_err(|e| e.to_string());
+    }
+
+    fn execute_script(&mut self, sql: &str) -> Result<(), String> {
+        return self.client.batch_execute(sql).map_err(|e| e.to_string());
+    }
+
+    fn dialect(&self) -> Dialect {
+        return Dialect::Postgres;
+    }
+}
+
+fn to_sql_param(param: &Param) -> &(dyn ToSql + Sync) {
+    return match param {
+        Param::Int(x) => x,
+        Param::BigInt(x) => x,
+        Param::Bool(x) => x,
+        Param::Text(x) => x,
+        Param::Time(x) => x
+    };
+}
+
+// PostgreSQL wants $1, $2 ... instead of ?
+fn to_numbered(sql: &str) -> String {
+    let mut ret = String::new();
+    let mut n = 0;
+    for c in sql.chars() {
+        if c == '?' {
+            n += 1;
+            ret += &format!("${}", n);
+        } else {
+            ret.push(c);
+        }
+    }
+    return ret;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_numbered() {
+    assert_eq!(to_numbered("UPDATE leg SET to_stand=?, distance=? WHERE id=?"),
+               "UPDATE leg SET to_stand=$1, distance=$2 WHERE id=$3");
+    assert_eq!(to_numbered("UPDATE cab SET status=0 WHERE id=?"), "UPDATE cab SET status=0 WHERE id=$1");
+  }
+}
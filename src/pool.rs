@@ -9,11 +9,12 @@ use std::{thread, vec};
 use log::debug;
 use std::time::Instant;
 use std::thread::ScopedJoinHandle;
-use crate::model::{Order, OrderTransfer, Stop, Cab, Branch, MAXSTOPSNUMB, MAXCABSNUMB, MAXORDERSNUMB};
-use crate::distance::DIST;
+use crate::model::{Order, OrderTransfer, Stop, StopWait, Cab, Branch, fits};
+use crate::distance::DistanceProvider;
 use crate::repo::assign_pool_to_cab;
+use crate::command::Command;
 
-const MAXANGLEDIST: i16 = 1;
+const MAXANGLEDIST: i16 = 60; // secs
 const MAX_THREAD_NUMB:usize = 12; // this has to be +1 possible config value!!
 const MAX_BRANCH_SIZE:usize = 10000000;
 const N: usize = MAX_BRANCH_SIZE*MAX_THREAD_NUMB;
@@ -29,21 +30,21 @@ const N: usize = MAX_BRANCH_SIZE*MAX_THREAD_NUMB;
 /// 
 /// returns: vector of pools and SQL
 pub fn find_pool(in_pool: u8, threads: i16, demand: &mut Vec<Order>, supply: &mut Vec<Cab>,
-                stands: &Vec<Stop>, mut max_route_id: &mut i64, max_leg_id: &mut i64, max_angle: i16, stop_wait: i16) 
-                -> (Vec<Branch>, String) {
+                stands: &Vec<Stop>, mut max_route_id: &mut i64, max_leg_id: &mut i64, max_angle: i16, stop_wait: &StopWait, dp: &dyn DistanceProvider) 
+                -> (Vec<Branch>, Vec<Command>) {
   if demand.len() == 0 || supply.len() == 0 || stands.len() == 0 {
-          return (Vec::new(), String::from(""));
+          return (Vec::new(), vec![]);
   }
   let mut node: Box<[Branch; N]> = vec![Branch::new(); N].try_into().unwrap();
   let mut node_size: usize = 0;
   let start = Instant::now();
   // recursive dive until the leaves of the permutation tree
-	dive(0, in_pool, threads, demand, stands, max_angle, stop_wait, &mut node, &mut node_size);
+	dive(0, in_pool, threads, demand, stands, max_angle, stop_wait, &mut node, &mut node_size, dp);
 
   // there might be pools with same passengers, with different length - sort and find the best ones
   let ret = 
       rm_duplicates_assign_cab(in_pool as usize, &mut max_route_id, max_leg_id, supply, demand, 
-                              stop_wait, &node, node_size);
+                              stop_wait, &node, node_size, dp);
 
   println!("FINAL: inPool: {}, found pools: {}, elapsed: {:?}\n", in_pool, ret.0.len(), start.elapsed());
   return ret;
@@ -60,10 +61,10 @@ pub fn find_pool(in_pool: u8, threads: i16, demand: &mut Vec<Order>, supply: &mu
 /// in_pool: number of passengers going together
 /// threads_numb: 
 fn dive(lev: u8, in_pool: u8, threads_numb: i16, orders: &Vec<Order>, stops: &Vec<Stop>, 
-        max_angle: i16, stop_wait: i16, node: &mut Box<[Branch; N]>, node_size: &mut usize){
+        max_angle: i16, stop_wait: &StopWait, node: &mut Box<[Branch; N]>, node_size: &mut usize, dp: &dyn DistanceProvider){
   *node_size = 0;
 	if lev > in_pool + in_pool - 3 { // lev >= 2*inPool-2, where -2 are last two levels
-		let leaves = store_leaves(orders, stops, max_angle, stop_wait);
+		let leaves = store_leaves(orders, stops, max_angle, stop_wait, dp);
     node[0..leaves.len()].copy_from_slice(&leaves[..]);
     *node_size = leaves.len();
     debug!("Level: {}, size: {}", lev,  leaves.len());
@@ -71,7 +72,7 @@ fn dive(lev: u8, in_pool: u8, threads_numb: i16, orders: &Vec<Order>, stops: &Ve
     return;
 	}
 	// dive more
-	dive(lev + 1, in_pool, threads_numb, orders, stops, max_angle, stop_wait, node, node_size);
+	dive(lev + 1, in_pool, threads_numb, orders, stops, max_angle, stop_wait, node, node_size, dp);
 
   let mut t_numb = threads_numb; // mut: there might be one more thread, rest of division
   let mut chunk = orders.len() / t_numb as usize;
@@ -87,62 +88,62 @@ fn dive(lev: u8, in_pool: u8, threads_numb: i16, orders: &Vec<Order>, stops: &Ve
       let mut children: Vec<ScopedJoinHandle<'_, Vec<Branch>>> = vec![];
       if  t_numb > 0 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 0, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 0, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 1 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 1, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 1, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 2 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 2, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 2, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 3 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 3, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 3, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 4 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 4, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 4, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 5 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 5, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 5, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 6 {  
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 6, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 6, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 7 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 7, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 7, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 8 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 8, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 8, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 9 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 9, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 9, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 10 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 10, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 10, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       if  t_numb > 11 {
         children.push(s.spawn(|| {
-          iterate(lev as usize, in_pool, 11, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size)
+          iterate(lev as usize, in_pool, 11, chunk, &orders, &stops, max_angle, stop_wait, node, *node_size, dp)
         }));
       }
       // collect the data from threads, join their execution first
@@ -171,31 +172,30 @@ fn dive(lev: u8, in_pool: u8, threads_numb: i16, orders: &Vec<Order>, stops: &Ve
 /// a leafe is e.g.: 1out-2out or 1in-1out, the last one must be OUT, 'o'
 /// 
 /// returns: leaves
-fn store_leaves(orders: &Vec<Order>, stops: &Vec<Stop>, max_angle: i16, stop_wait: i16) -> Vec<Branch> {
-	unsafe{
+fn store_leaves(orders: &Vec<Order>, stops: &Vec<Stop>, max_angle: i16, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Vec<Branch> {
   let mut ret: Vec<Branch> = vec!();
 	for (c_idx, c) in orders.iter().enumerate() {
-	  if c.id != -1 { // not allocated in previous search: inPool+1 (e.g. in_pool=4 and now we search in_pool=3)
+	  if c.id != -1 && c.shared { // not allocated in previous search: inPool+1 (e.g. in_pool=4 and now we search in_pool=3); solo trips are not pooled
 		for (d_idx, d) in orders.iter().enumerate() {
-		  if d.id != -1 { 
+		  if d.id != -1 && d.shared { 
 		 	// to situations: <1in, 1out>, <1out, 2out>, the first here c==d, IN and OUT of the same passenger
 		 	if c_idx == d_idx {
         // 'bearing' checks if stops are in line, it promotes straight paths to avoid unlife solutions
         // !! we might not check bearing here as they are probably distant stops
         if bearing_diff(stops[c.from as usize].bearing, stops[d.to as usize].bearing) < max_angle {
-		 		  ret.push(add_leaf(c_idx as i16, d_idx as i16, 'i', 1, orders, stop_wait));
+		 		  ret.push(add_leaf(c_idx as i16, d_idx as i16, 'i', 1, orders, stop_wait, dp));
         }
 		 	} 
       // now <1out, 2out>
-      else if (DIST[c.to as usize][d.to as usize] as f32)	<
-                            DIST[d.from as usize][d.to as usize] as f32 * (100.0 + d.loss as f32) / 100.0
+      else if (dp.dist(c.to as usize, d.to as usize) as f32)	<
+                            dp.dist(d.from as usize, d.to as usize) as f32 * (100.0 + d.loss as f32) / 100.0
 		 			    && bearing_diff(stops[c.to as usize].bearing, stops[d.to as usize].bearing) < max_angle {
 		 		// TASK - this calculation above should be replaced by a redundant value in taxi_order - distance * loss
-		 		ret.push(add_leaf(c_idx as i16, d_idx as i16, 'o', 2, orders, stop_wait));
+		 		ret.push(add_leaf(c_idx as i16, d_idx as i16, 'o', 2, orders, stop_wait, dp));
         /*  debug!("c={} d={} c.id={} d.id={} c.to={} d.from={} d.to={} d.loss={} c.to.bearing={} d.to.bearing={} dist_c_d={} dist_d_d={}",
                   c, d, ORDERS[c].id, ORDERS[d].id, ORDERS[c].to, ORDERS[d].from, ORDERS[d].to,
                   ORDERS[d].loss, STOPS[ORDERS[c].to as usize].bearing, STOPS[ORDERS[d].to as usize].bearing,
-                  DIST[ORDERS[c].to as usize][ORDERS[d].to as usize], DIST[ORDERS[d].from as usize][ORDERS[d].to as usize] 
+                  dp.dist(ORDERS[c].to as usize, ORDERS[d].to as usize), dp.dist(ORDERS[d].from as usize, ORDERS[d].to as usize) 
                 );
         */
 		 	}
@@ -204,7 +204,6 @@ fn store_leaves(orders: &Vec<Order>, stops: &Vec<Stop>, max_angle: i16, stop_wai
 	  }
 	}
   return ret;
-	}
 }
 
 pub fn bearing_diff(a: i32, b: i32 ) -> i16 {
@@ -217,13 +216,11 @@ pub fn bearing_diff(a: i32, b: i32 ) -> i16 {
     return r.abs() as i16;
 }
 
-fn add_leaf(id1: i16, id2: i16, dir1: char, outs: u8, orders: &Vec<Order>, stop_wait: i16) -> Branch {
+fn add_leaf(id1: i16, id2: i16, dir1: char, outs: u8, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Branch {
 	let mut br: Branch = Branch::new();
-  unsafe {
-      let from = if dir1 == 'i' { orders[id1 as usize].from as usize } else { orders[id1 as usize].to as usize };
-      let to = orders[id2 as usize].to as usize; // the last stop is always OUT
-    	br.cost = DIST[from][to] + if from == to { 0 } else { stop_wait };
-	}
+   let from = if dir1 == 'i' { orders[id1 as usize].from as usize } else { orders[id1 as usize].to as usize };
+   let to = orders[id2 as usize].to as usize; // the last stop is always OUT
+ 	br.cost = dp.dist(from, to) as i32 + stop_wait.between(from, to, if dir1 == 'i' { orders[id1 as usize].passengers as i16 } else { 0 }) as i32;
   br.outs = outs;
   br.ord_ids[0] = id1;
   br.ord_ids[1] = id2;
@@ -237,17 +234,17 @@ fn add_leaf(id1: i16, id2: i16, dir1: char, outs: u8, orders: &Vec<Order>, stop_
 /// just a loop and calling store_branch...
 /// returns: a chunk of all branches at that level
 fn iterate(lev: usize, in_pool: u8, thread: usize, size: usize, orders: &Vec<Order>, 
-           stops: &Vec<Stop>, max_angle: i16, stop_wait: i16, 
-           node: &Box<[Branch; N]>, node_size: usize) -> Vec<Branch> {
+           stops: &Vec<Stop>, max_angle: i16, stop_wait: &StopWait, 
+           node: &Box<[Branch; N]>, node_size: usize, dp: &dyn DistanceProvider) -> Vec<Branch> {
   let mut ret: Vec<Branch> = vec![];
  	let mut stop = (thread + 1) * size;
   if stop > orders.len() { stop = orders.len(); } 
 	for ord_id in thread * size .. stop {
-		if orders[ord_id as usize].id != -1 { // not allocated in previous search (inPool+1)
+		if orders[ord_id as usize].id != -1 && orders[ord_id as usize].shared { // not allocated in previous search (inPool+1), solo trips never pooled
 			for b in node[0..node_size].iter() {
 					// we iterate over product of the stage further in the tree: +1
 					store_branch_if_not_found(lev as u8, in_pool, ord_id as i16, &b, &mut ret, 
-                                    orders, stops, max_angle, stop_wait);
+                                    orders, stops, max_angle, stop_wait, dp);
 			}
 		}
 	}
@@ -259,7 +256,7 @@ fn iterate(lev: usize, in_pool: u8, thread: usize, size: usize, orders: &Vec<Ord
 /// br is existing Branch in lev+1
 /// returns: just pushes to a mutable vector
 fn store_branch_if_not_found(lev: u8, in_pool: u8, ord_id: i16, br: &Branch, ret: &mut Vec<Branch>, 
-                              orders: &Vec<Order>, stops: &Vec<Stop>, max_angle: i16, stop_wait: i16) {
+                              orders: &Vec<Order>, stops: &Vec<Stop>, max_angle: i16, stop_wait: &StopWait, dp: &dyn DistanceProvider) {
   // two situations: c IN and c OUT
   // c IN has to have c OUT in level+1, and c IN cannot exist in level + 1
   // c OUT cannot have c OUT in level +1
@@ -277,31 +274,29 @@ fn store_branch_if_not_found(lev: u8, in_pool: u8, ord_id: i16, br: &Branch, ret
   }
   // now checking if anyone in the branch does not lose too much with the pool
   // c IN
-	unsafe {
-    let next_stop: usize = if br.ord_actions[0] == 'i' as i8
-      { orders[br.ord_ids[0] as usize].from as usize } else { orders[br.ord_ids[0] as usize].to as usize };
-	  let id = ord_id as usize;
+   let next_stop: usize = if br.ord_actions[0] == 'i' as i8
+     { orders[br.ord_ids[0] as usize].from as usize } else { orders[br.ord_ids[0] as usize].to as usize };
+  let id = ord_id as usize;
 	
-    if out_found { // IN was not found, we would have called 'return'
-      if !is_too_long('i', ord_id, DIST[orders[id].from as usize][next_stop]
-                        + if orders[id].from == next_stop as i32 { 0 } else { stop_wait }, br, orders, stop_wait)
-        // TASK? if the next stop is OUT of passenger 'c' - we might allow bigger angle
-        && (DIST[orders[id].from as usize][next_stop] > MAXANGLEDIST
-            || bearing_diff(stops[orders[id].from as usize].bearing, stops[next_stop].bearing) < max_angle) {
-          ret.push(store_branch('i', lev, ord_id, br, in_pool, orders, stop_wait)); 
-      }
-		} 
-    // c OUT as neither IN or OUT was found
-    else if lev > 0 // the first stop cannot be OUT
-        && br.outs < in_pool // numb OUT must be numb IN
-        && !is_too_long('o', ord_id, DIST[orders[id].to as usize][next_stop]
-                        + if orders[id].to == next_stop as i32 { 0 } else { stop_wait }, br, orders, stop_wait)
-        && (DIST[orders[id].to as usize][next_stop] > MAXANGLEDIST
-            || bearing_diff(stops[orders[id].to as usize].bearing, stops[next_stop].bearing) < max_angle)
-        && br.parity > 0 // to avoid empty legs, OUTs > INNs, a missing 'i' will be in the next (well, previous) level
-        { 
-          ret.push(store_branch('o', lev, ord_id, br, in_pool, orders, stop_wait)); 
-		}
+   if out_found { // IN was not found, we would have called 'return'
+     if !is_too_long('i', ord_id, dp.dist(orders[id].from as usize, next_stop) as i32
+                       + stop_wait.between(orders[id].from as usize, next_stop, orders[id].passengers as i16) as i32, br, orders, stop_wait, dp)
+       // TASK? if the next stop is OUT of passenger 'c' - we might allow bigger angle
+       && (dp.dist(orders[id].from as usize, next_stop) > MAXANGLEDIST
+           || bearing_diff(stops[orders[id].from as usize].bearing, stops[next_stop].bearing) < max_angle) {
+         ret.push(store_branch('i', lev, ord_id, br, in_pool, orders, stop_wait, dp)); 
+     }
+	} 
+   // c OUT as neither IN or OUT was found
+   else if lev > 0 // the first stop cannot be OUT
+       && br.outs < in_pool // numb OUT must be numb IN
+       && !is_too_long('o', ord_id, dp.dist(orders[id].to as usize, next_stop) as i32
+                       + stop_wait.between(orders[id].to as usize, next_stop, 0) as i32, br, orders, stop_wait, dp)
+       && (dp.dist(orders[id].to as usize, next_stop) > MAXANGLEDIST
+           || bearing_diff(stops[orders[id].to as usize].bearing, stops[next_stop].bearing) < max_angle)
+       && br.parity > 0 // to avoid empty legs, OUTs > INNs, a missing 'i' will be in the next (well, previous) level
+       { 
+         ret.push(store_branch('o', lev, ord_id, br, in_pool, orders, stop_wait, dp)); 
 	}
 }
 
@@ -309,45 +304,43 @@ fn store_branch_if_not_found(lev: u8, in_pool: u8, ord_id: i16, br: &Branch, ret
 /// dist: is the distance added to the pool
 /// br: is the branch in level+1
 /// returns if the order fits in
-fn is_too_long(action: char, ord_id: i16, dist: i16, br: &Branch, orders: &Vec<Order>, stop_wait: i16) -> bool {
-	unsafe {
-	  let mut wait = dist;
-    // iterate over all previous orders, 
-    for i in 0..(br.ord_numb as usize -1) {
-		  let id = br.ord_ids[i] as usize;
-      if ord_id == br.ord_ids[i] && br.ord_actions[i] == 'o' as i8 && action == 'i' &&
-        wait as f32 >  //distance[orders[br.ordIDs[i]].fromStand][orders[br.ordIDs[i]].toStand] 
-          orders[id].dist as f32 * (100.0 + orders[id].loss as f32) / 100.0 { 
-        // max loss of the new order (which we are trying to put in) is violated
-        // max loss check of other orders have been checked earlier, here, in lev+1, of course only that with IN & OUT
-        return true; 
-      }
-      if br.ord_actions[i] == 'i' as i8 && wait > orders[id].wait as i16 { 
-        // wait time of an already existing order (in the pool; lev+1) is violated
-        return true; 
-      }
-
-      let from = if br.ord_actions[i] == 'i' as i8 { orders[id].from as usize } 
-                        else { orders[id].to as usize };
-      let to = if br.ord_actions[i + 1] == 'i' as i8 { orders[br.ord_ids[i + 1] as usize].from as usize }
-                      else { orders[br.ord_ids[i + 1] as usize].to as usize };
-      if from != to { wait += DIST[from][to] + stop_wait; }
+fn is_too_long(action: char, ord_id: i16, dist: i32, br: &Branch, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> bool {
+ let mut wait = dist; // i32, seconds would overflow i16
+  // iterate over all previous orders, 
+  for i in 0..(br.ord_numb as usize -1) {
+  let id = br.ord_ids[i] as usize;
+    if ord_id == br.ord_ids[i] && br.ord_actions[i] == 'o' as i8 && action == 'i' &&
+      wait as f32 >  //distance[orders[br.ordIDs[i]].fromStand][orders[br.ordIDs[i]].toStand] 
+        orders[id].dist as f32 * (100.0 + orders[id].loss as f32) / 100.0 { 
+      // max loss of the new order (which we are trying to put in) is violated
+      // max loss check of other orders have been checked earlier, here, in lev+1, of course only that with IN & OUT
+      return true; 
     }
-    // we have to repeat the check in the loop for the last element in array (max loss)
-    if action == 'i' && ord_id == br.ord_ids[br.ord_numb as usize -1] &&
-        wait > (orders[ord_id as usize].dist as f32 * (100.0 + orders[ord_id as usize].loss as f32) / 100.0) as i16 { 
+    if br.ord_actions[i] == 'i' as i8 && wait > orders[id].wait { 
+      // wait time of an already existing order (in the pool; lev+1) is violated
       return true; 
     }
-    // no time constraint is violated
-    return false;
-	}
+
+    let from = if br.ord_actions[i] == 'i' as i8 { orders[id].from as usize } 
+                      else { orders[id].to as usize };
+    let to = if br.ord_actions[i + 1] == 'i' as i8 { orders[br.ord_ids[i + 1] as usize].from as usize }
+                    else { orders[br.ord_ids[i + 1] as usize].to as usize };
+    wait += dp.dist(from, to) as i32 + stop_wait.between(from, to, if br.ord_actions[i] == 'i' as i8 { orders[id].passengers as i16 } else { 0 }) as i32;
+  }
+  // we have to repeat the check in the loop for the last element in array (max loss)
+  if action == 'i' && ord_id == br.ord_ids[br.ord_numb as usize -1] &&
+      wait > (orders[ord_id as usize].dist as f32 * (100.0 + orders[ord_id as usize].loss as f32) / 100.0) as i32 { 
+    return true; 
+  }
+  // no time constraint is violated
+  return false;
 }
 
 /// adding an order to a pool
 ///  b is existing Branch in lev+1
 /// 
 /// returns an extended pool
-fn store_branch(action: char, lev: u8, ord_id: i16, b: &Branch, in_pool: u8, orders: &Vec<Order>, stop_wait: i16) -> Branch  {
+fn store_branch(action: char, lev: u8, ord_id: i16, b: &Branch, in_pool: u8, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Branch  {
 	let mut br : Branch = Branch::new();
 
   br.ord_numb = (in_pool + in_pool - lev) as i16;
@@ -359,13 +352,11 @@ fn store_branch(action: char, lev: u8, ord_id: i16, b: &Branch, in_pool: u8, ord
     br.ord_ids[j + 1]     = b.ord_ids[j];
     br.ord_actions[j + 1] = b.ord_actions[j];
   }
-	unsafe {
-    let from = if action == 'i' { orders[ord_id as usize].from as usize} 
-                      else { orders[ord_id as usize].to as usize };
-    let to = if b.ord_actions[0] == 'i' as i8 { orders[b.ord_ids[0] as usize].from as usize} 
-                    else { orders[b.ord_ids[0]as usize].to as usize};
-    br.cost = b.cost + DIST[from][to] + if from == to { 0 } else { stop_wait };
-	}
+  let from = if action == 'i' { orders[ord_id as usize].from as usize} 
+                    else { orders[ord_id as usize].to as usize };
+  let to = if b.ord_actions[0] == 'i' as i8 { orders[b.ord_ids[0] as usize].from as usize} 
+                  else { orders[b.ord_ids[0]as usize].to as usize};
+  br.cost = b.cost + dp.dist(from, to) as i32 + stop_wait.between(from, to, if action == 'i' { orders[ord_id as usize].passengers as i16 } else { 0 }) as i32;
   if action == 'o' { 
     br.outs = b.outs + 1;
     br.parity = b.parity + 1;
@@ -376,25 +367,23 @@ fn store_branch(action: char, lev: u8, ord_id: i16, b: &Branch, in_pool: u8, ord
   return br;
 }
 
-fn max_wait_exceeded(dist: i16, br: &Branch, orders: &Vec<Order>, stop_wait: i16) -> bool {
-	unsafe {
-	  let mut wait = dist;
-    // iterate over all previous orders, 
-    for i in 0..(br.ord_numb as usize -1) {
-		  let id = br.ord_ids[i] as usize;
-      if br.ord_actions[i] == 'i' as i8 && wait > orders[id].wait as i16 { 
-        // wait time of an already existing order (in the pool; lev+1) is violated
-        return true; 
-      }
-      let from = if br.ord_actions[i] == 'i' as i8 { orders[id].from as usize } 
-                        else { orders[id].to as usize };
-      let to = if br.ord_actions[i + 1] == 'i' as i8 { orders[br.ord_ids[i + 1] as usize].from as usize }
-                      else { orders[br.ord_ids[i + 1] as usize].to as usize };
-      if from != to { wait += DIST[from][to] + stop_wait; }
+fn max_wait_exceeded(dist: i32, br: &Branch, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> bool {
+ let mut wait = dist;
+  // iterate over all previous orders, 
+  for i in 0..(br.ord_numb as usize -1) {
+  let id = br.ord_ids[i] as usize;
+    if br.ord_actions[i] == 'i' as i8 && wait > orders[id].wait { 
+      // wait time of an already existing order (in the pool; lev+1) is violated
+      return true; 
     }
-    // no time constraint is violated
-    return false;
-	}
+    let from = if br.ord_actions[i] == 'i' as i8 { orders[id].from as usize } 
+                      else { orders[id].to as usize };
+    let to = if br.ord_actions[i + 1] == 'i' as i8 { orders[br.ord_ids[i + 1] as usize].from as usize }
+                    else { orders[br.ord_ids[i + 1] as usize].to as usize };
+    wait += dp.dist(from, to) as i32 + stop_wait.between(from, to, if br.ord_actions[i] == 'i' as i8 { orders[id].passengers as i16 } else { 0 }) as i32;
+  }
+  // no time constraint is violated
+  return false;
 }
 
 /// there might be pools with same passengers (orders) but in different ... order (sequence of INs and OUTs) 
@@ -405,9 +394,9 @@ fn max_wait_exceeded(dist: i16, br: &Branch, orders: &Vec<Order>, stop_wait: i16
 /// 
 /// returns allocated branches (to regenerate demand and supplu for the solver) and SQL to execute
 fn rm_duplicates_assign_cab(in_pool: usize, mut max_route_id: &mut i64, mut max_leg_id: &mut i64,
-                            cabs: &mut Vec<Cab>, orders: &mut Vec<Order>, stop_wait: i16, node: &Box<[Branch; N]>, node_size: usize) -> (Vec<Branch>, String) {
+                            cabs: &mut Vec<Cab>, orders: &mut Vec<Order>, stop_wait: &StopWait, node: &Box<[Branch; N]>, node_size: usize, dp: &dyn DistanceProvider) -> (Vec<Branch>, Vec<Command>) {
 	let mut ret : Vec<Branch> = Vec::new();
-  let mut sql: String = String::from("");
+  let mut sql: Vec<Command> = vec![];
   let slice = &node[0..node_size];
   let mut arr = slice.to_vec();
   if arr.len() == 0 {
@@ -416,10 +405,15 @@ fn rm_duplicates_assign_cab(in_pool: usize, mut max_route_id: &mut i64, mut max_
 
   // 4 next lines is a check if the distance to the cab helps 
   for i in 0..arr.len()  {
-    let cab_idx = find_nearest_cab(arr[i].ord_ids[0], count_passengers(arr[i]), cabs, orders);
-    let cab_dist = unsafe { DIST[cabs[cab_idx as usize].location as usize][orders[arr[i].ord_ids[0] as usize].from as usize] }
-                        + cabs[cab_idx as usize].dist;
-    if cab_dist > 0 && max_wait_exceeded(cab_dist, &arr[i], orders, stop_wait) {
+    let cab_idx = find_nearest_cab(arr[i].ord_ids[0], count_passengers(arr[i], orders), required_features(arr[i], orders),
+                                   cabs, orders, dp);
+    if cab_idx < 0 { // no cab for this pool, big or equipped enough
+      arr[i].cost = -1;
+      continue;
+    }
+    let cab_dist = dp.dist(cabs[cab_idx as usize].location as usize, orders[arr[i].ord_ids[0] as usize].from as usize) as i32
+                        + cabs[cab_idx as usize].dist as i32; // i32, seconds would overflow i16
+    if cab_dist > 0 && max_wait_exceeded(cab_dist, &arr[i], orders, stop_wait, dp) {
       arr[i].cost = -1;
       continue;
     }
@@ -428,66 +422,76 @@ fn rm_duplicates_assign_cab(in_pool: usize, mut max_route_id: &mut i64, mut max_
   arr.sort_by_key(|e| e.cost.clone());
   
   // assigning and removing duplicates
-  unsafe {
-    for i in 0..arr.len() {
-      if arr[i].cost == -1 { // this -1 marker is set below
-        continue;
-      }
-      // find nearest cab to first pickup and check if WAIT and LOSS constraints met - allocate
-      let cab_idx = find_nearest_cab(arr[i].ord_ids[0], count_passengers(arr[i]), cabs, orders); // LCM
-      if cab_idx == -1 { // no more cabs
-        mark_pools_as_dead(&mut arr, i);
-        break;
-      } else if cab_idx == -2 { // there is no cab for so many passengers
-        arr[i].cost = -1;
-        continue;
-      }
+  for i in 0..arr.len() {
+    if arr[i].cost == -1 { // this -1 marker is set below
+      continue;
+    }
+    // find nearest cab to first pickup and check if WAIT and LOSS constraints met - allocate
+    let cab_idx = find_nearest_cab(arr[i].ord_ids[0], count_passengers(arr[i], orders), required_features(arr[i], orders),
+                                   cabs, orders, dp); // LCM
+    if cab_idx == -1 { // no more cabs
+      mark_pools_as_dead(&mut arr, i);
+      break;
+    } else if cab_idx == -2 { // there is no cab for so many passengers or with the features needed
+      arr[i].cost = -1;
+      continue;
+    }
       
-      let dist_cab = DIST[cabs[cab_idx as usize].location as usize]
-                              [orders[arr[i].ord_ids[0] as usize].from as usize] + cabs[cab_idx as usize].dist;
-      if dist_cab == 0 // constraints inside pool are checked while "diving", and cab does not add up anything if == 0
-              || constraints_met(arr[i], (dist_cab + stop_wait) as i32, orders, stop_wait) {
-        arr[i].cab = cab_idx; // maybe needed for debug
-        ret.push(arr[i]);
-        // assign to a cab and remove all next pools with these passengers (index 'i')
-        sql += &assign_and_remove(&mut arr, in_pool, i, cabs[cab_idx as usize], &mut max_route_id, &mut max_leg_id, orders, stop_wait);
-        // remove the cab from list so that it cannot be allocated twice in LCM or Munkres
-        cabs[cab_idx as usize].id = -1;
-          // mark orders in pools as assigned so that next call to find_pool (with fewer 'in_pool') skips them
-        for o in 0..arr[i].ord_numb as usize {
-          orders[arr[i].ord_ids[o] as usize].id = -1;
-        }
-        // the same with demand, but this static array ORDERS is a copy of Vec, so it is better to do it elsewhere
-      } else { // constraints not met, mark as unusable
-        arr[i].cost = -1;
+    let dist_cab = dp.dist(cabs[cab_idx as usize].location as usize,
+                            orders[arr[i].ord_ids[0] as usize].from as usize) + cabs[cab_idx as usize].dist;
+    if dist_cab == 0 // constraints inside pool are checked while "diving", and cab does not add up anything if == 0
+            || constraints_met(arr[i], (dist_cab + stop_wait.at(orders[arr[i].ord_ids[0] as usize].from as usize)) as i32, orders, stop_wait, dp) {
+      arr[i].cab = cab_idx; // maybe needed for debug
+      ret.push(arr[i]);
+      // assign to a cab and remove all next pools with these passengers (index 'i')
+      sql.extend(assign_and_remove(&mut arr, in_pool, i, cabs[cab_idx as usize], &mut max_route_id, &mut max_leg_id, orders, stop_wait, dp));
+      // remove the cab from list so that it cannot be allocated twice in LCM or Munkres
+      cabs[cab_idx as usize].id = -1;
+        // mark orders in pools as assigned so that next call to find_pool (with fewer 'in_pool', dp) skips them
+      for o in 0..arr[i].ord_numb as usize {
+        orders[arr[i].ord_ids[o] as usize].id = -1;
       }
+      // the same with demand, but this static array ORDERS is a copy of Vec, so it is better to do it elsewhere
+    } else { // constraints not met, mark as unusable
+      arr[i].cost = -1;
     }
   }
   return (ret, sql);
 }
 
-fn count_passengers(branch: Branch) -> i32 {
+// seats needed - the most passengers in the cab at a time, an order can be a group
+fn count_passengers(branch: Branch, orders: &Vec<Order>) -> i32 {
   let mut curr_count: i32 = 0;
   let mut max_count: i32 = 0;
   for i in 0 .. branch.ord_numb as usize {
+    let passengers = orders[branch.ord_ids[i] as usize].passengers;
     if branch.ord_actions[i] == 'i' as i8 {
-      curr_count += 1;
+      curr_count += passengers;
       if curr_count > max_count {
         max_count = curr_count; // max_count++ would be the same; which one is faster?
       }
     } else { // 'o'
-      curr_count -= 1;
+      curr_count -= passengers;
     }
   }
   return max_count;
 }
 
+// features the cab must have - all that any of the orders requires
+fn required_features(branch: Branch, orders: &Vec<Order>) -> i32 {
+  let mut required: i32 = 0;
+  for i in 0 .. branch.ord_numb as usize {
+    required |= orders[branch.ord_ids[i] as usize].required;
+  }
+  return required;
+}
+
 /// create a route with legs, assign orders to the cab (and legs, which is not that important)
 /// remove all other pools with these passengers - 'i' index to arr
 /// 
 /// returns SQL
 fn assign_and_remove(arr: &mut Vec<Branch>, in_pool: usize, i: usize, cab: Cab,
-                     mut max_route_id: &mut i64, mut max_leg_id: &mut i64, orders: &Vec<Order>, stop_wait: i16) -> String {
+                     mut max_route_id: &mut i64, mut max_leg_id: &mut i64, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Vec<Command> {
   // remove any further duplicates
   for j in i + 1 .. arr.len() {
       if arr[j].cost != -1 // not invalidated; this check is for performance reasons
@@ -496,7 +500,7 @@ fn assign_and_remove(arr: &mut Vec<Branch>, in_pool: usize, i: usize, cab: Cab,
           // (list is pre-sorted)
       }
   }
-  return assign_pool_to_cab(cab, orders, arr[i], &mut max_route_id, &mut max_leg_id, stop_wait);
+  return assign_pool_to_cab(cab, orders, arr[i], &mut max_route_id, &mut max_leg_id, stop_wait, dp);
 }
 
 /// check if passengers in pool 'i' exist in pool 'j'
@@ -523,37 +527,34 @@ fn mark_pools_as_dead(arr: &mut Vec<Branch>, i: usize) {
 
 /// LCM - find the nearest cab for this order ('from' of the first order in pool)
 /// returns id of the cab
-fn find_nearest_cab(o_idx: i16, pass_count: i32, cabs: &Vec<Cab>, orders: &Vec<Order>) -> i16 {
-  unsafe{
-    let o: Order = orders[o_idx as usize];
-    let mut dist = 10000; // big
-    let mut nearest = -1 as i16;
-    let mut found_any = false;
-    for (i, c) in cabs.iter().enumerate() {
-      if c.id == -1 { // allocated earlier to a pool
-        continue;
-      }
-      found_any = true;
-      if DIST[c.location as usize][o.from as usize] + c.dist < dist && c.seats >= pass_count {
-        dist = DIST[c.location as usize][o.from as usize] + c.dist;
-        nearest = i as i16;
-      }
+fn find_nearest_cab(o_idx: i16, pass_count: i32, required: i32, cabs: &Vec<Cab>, orders: &Vec<Order>, dp: &dyn DistanceProvider) -> i16 {
+  let o: Order = orders[o_idx as usize];
+  let mut dist = i16::MAX; // big
+  let mut nearest = -1 as i16;
+  let mut found_any = false;
+  for (i, c) in cabs.iter().enumerate() {
+    if c.id == -1 { // allocated earlier to a pool
+      continue;
+    }
+    found_any = true;
+    if dp.dist(c.location as usize, o.from as usize) + c.dist < dist && c.seats >= pass_count && fits(c.features, required) {
+      dist = dp.dist(c.location as usize, o.from as usize) + c.dist;
+      nearest = i as i16;
     }
-    if !found_any { 
-      return -1; // no cabs at all
-    } else if nearest == -1 { // there are some cabs available but not with so many seats
-      return -2;
-    } 
-    return nearest;
   }
+  if !found_any { 
+    return -1; // no cabs at all
+  } else if nearest == -1 { // there are some cabs available but not with so many seats or features
+    return -2;
+  } 
+  return nearest;
 }
 
 /// checking max wait of all orders
 ///  
-fn constraints_met(el: Branch, dist_cab: i32, orders: &Vec<Order>, stop_wait: i16) -> bool {
+fn constraints_met(el: Branch, dist_cab: i32, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> bool {
     // TASK: distances in pool should be stored to speed-up this check
     let mut dist = dist_cab;
-    unsafe {
     for i in 0..el.ord_numb as usize -1 {
       let o: Order = orders[el.ord_ids[i] as usize];
       if el.ord_actions[i] == 'i' as i8 && dist > o.wait {
@@ -562,12 +563,9 @@ fn constraints_met(el: Branch, dist_cab: i32, orders: &Vec<Order>, stop_wait: i1
       let o2: Order = orders[el.ord_ids[i+1] as usize];
       let from = if el.ord_actions[i] == ('i' as i8) { o.from as usize } else { o.to as usize };
       let to = if el.ord_actions[i + 1] == 'i' as i8 { o2.from as usize } else { o2.to as usize};
-      if from != to {
-        dist += (DIST[from][to] + stop_wait) as i32;
-      }
+      dist += (dp.dist(from, to) + stop_wait.between(from, to, if el.ord_actions[i] == 'i' as i8 { o.passengers as i16 } else { 0 })) as i32;
     }
     // we don't need to check the last leg as it does not concern "loss", this has been check earlier 
-    }
     return true;
 }
 
@@ -582,39 +580,34 @@ fn constraints_met(el: Branch, dist_cab: i32, orders: &Vec<Order>, stop_wait: i1
 //   return arr;
 // }
 
-pub fn orders_to_transfer_array(vec: &Vec<Order>) -> [OrderTransfer; MAXORDERSNUMB] {
-    let mut arr : [OrderTransfer; MAXORDERSNUMB] = [OrderTransfer {
-        id: 0, from: 0, to: 0, wait: 0,	loss: 0, dist: 0}; MAXORDERSNUMB];
-    for (i, v) in vec.iter().enumerate() { 
-      arr[i].id = v.id; 
-      arr[i].from = v.from; 
-      arr[i].to = v.to; 
-      arr[i].wait = v.wait; 
-      arr[i].loss = v.loss; 
-      arr[i].dist = v.dist; 
+pub fn orders_to_transfer_array(vec: &Vec<Order>) -> Vec<OrderTransfer> {
+    let mut arr: Vec<OrderTransfer> = Vec::with_capacity(vec.len());
+    for v in vec.iter() { 
+      arr.push(OrderTransfer { id: v.id, from: v.from, to: v.to, wait: v.wait, loss: v.loss, dist: v.dist, passengers: v.passengers,
+                                required: v.required, shared: v.shared as i32 });
     }
     return arr;
 }
 
-pub fn cabs_to_array(vec: &Vec<Cab>) -> [Cab; MAXCABSNUMB] {
-    let mut arr : [Cab; MAXCABSNUMB] = [Cab {id: 0, location: 0, seats: 0, dist: 0}; MAXCABSNUMB];
-    for (i,v) in vec.iter().enumerate() { arr[i] = *v; }
-    return arr;
+pub fn cabs_to_array(vec: &Vec<Cab>) -> Vec<Cab> {
+    return vec.to_vec();
 }
 
-pub fn stops_to_array(vec: &Vec<Stop>) -> [Stop; MAXSTOPSNUMB] {
-    let mut arr : [Stop; MAXSTOPSNUMB] = [Stop {id: 0, bearing: 0, longitude:0.0, latitude: 0.0, capacity: 10}; MAXSTOPSNUMB];
-    for (i,v) in vec.iter().enumerate() { arr[i] = *v; }
+// C finds a stop by its id, not by its index
+pub fn stops_to_array(vec: &Vec<Stop>) -> Vec<Stop> {
+    let size = vec.iter().map(|s| s.id as usize + 1).max().unwrap_or(0);
+    let mut arr: Vec<Stop> = vec![Stop {id: 0, bearing: 0, longitude:0.0, latitude: 0.0, capacity: 10, dwell: 0}; size];
+    for v in vec.iter() { arr[v.id as usize] = *v; }
     return arr;
 }
 
-// many tests will fail when run with cargo test. The cause is use of static arrays.
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{distance::init_distance, model::KernCfg};
+  use crate::{distance::{Haversine, MemoryMatrix}, model::{KernCfg, MAXORDERSNUMB}};
   use chrono::Local;
   use serial_test::serial;
+  use crate::command::to_sql;
   use std::time::Instant;
  
   fn get_stops() -> Vec<Stop> {
@@ -623,7 +616,7 @@ mod tests {
     for i in 0..49 {
       for j in 0..49 {
         stops.push(
-          Stop{ id: c, bearing: 0, latitude: 49.0 + 0.025 * i as f64, longitude: 19.000 + 0.025 * j as f64, capacity: 10}
+          Stop{ id: c, bearing: 0, latitude: 49.0 + 0.025 * i as f64, longitude: 19.000 + 0.025 * j as f64, capacity: 10, dwell: 60}
         );
         c = c + 1;
       }
@@ -637,7 +630,7 @@ mod tests {
     for i in 0..49 {
       for j in 0..49 {
         stops.push(
-          Stop{ id: c, bearing: 0, latitude: 49.0 + step * i as f64, longitude: 19.000 + step * j as f64, capacity: 10}
+          Stop{ id: c, bearing: 0, latitude: 49.0 + step * i as f64, longitude: 19.000 + step * j as f64, capacity: 10, dwell: 60}
         );
         c = c + 1;
       }
@@ -645,52 +638,55 @@ mod tests {
     return stops;
   }
 
-  fn set_orders() -> Vec<Order> {
+  fn set_orders(dp: &dyn DistanceProvider) -> Vec<Order> {
     let mut orders: Vec<Order> = vec![];
     for i in 0..MAXORDERSNUMB as i32{
       let from: i32 = i % 2400;
       let to: i32 = from + 1;
-      orders.push(Order{ id: i as i64, from, to, wait: 15, loss: 70, dist: unsafe { DIST[from as usize][to as usize] as i32}, 
+      orders.push(Order{ id: i as i64, from, to, wait: 900, loss: 70, dist: dp.dist(from as usize, to as usize) as i32, 
         //shared: true, in_pool: false, 
-        received: None, 
+        passengers: 1, required: 0, shared: true, transfer: false, received: None, 
         //started: None, completed: None, 
         at_time: None, 
         //eta: 1, 
+        released: None,
         route_id: -1 });
     }
     return  orders;
   }
 
-  fn test_init_orders_and_dist(dist: i16, ord_count: usize) -> (Vec<Order>, Vec<Cab>) {
+  fn test_init_orders_and_dist(dist: i16, ord_count: usize) -> (Vec<Order>, Vec<Cab>, MemoryMatrix) {
     let mut orders: Vec<Order> = vec![];
     for i in 0..ord_count {
-      orders.push(Order{ id: i as i64, from: i as i32, to: 7-i as i32, wait: 15, loss: 70, dist: 7-2*i as i32, 
+      orders.push(Order{ id: i as i64, from: i as i32, to: 7-i as i32, wait: 900, loss: 70, dist: dist as i32 * (7-2*i as i32).abs(), 
         //shared: true, in_pool: false, 
-        received: None, 
+        passengers: 1, required: 0, shared: true, transfer: false, received: None, 
         //started: None, completed: None, 
         at_time: None, 
         //eta: 1, 
+        released: None,
         route_id: -1 });
     }
-    for i in 0..7 { unsafe { DIST[i][i+1] = dist; } }
+    let dp = MemoryMatrix::line(8, dist); // 'dist' secs between neighbours
     let mut cabs: Vec<Cab> = vec![];
-    cabs.push(Cab{ id: 0, location: 0, seats: 10, dist: 0 });
-    cabs.push(Cab{ id: 1, location: 1, seats: 10, dist: 0 });
-    return (orders, cabs);
+    cabs.push(Cab{ id: 0, location: 0, seats: 10, dist: 0, features: 0 });
+    cabs.push(Cab{ id: 1, location: 1, seats: 10, dist: 0, features: 0 });
+    return (orders, cabs, dp);
   }
 
-  fn get_orders(size: usize) -> Vec<Order> {
+  fn get_orders(size: usize, dp: &dyn DistanceProvider) -> Vec<Order> {
     let mut ret: Vec<Order> = vec![];
     for i in 0..size as i32 {     
         let from: i32 = i % 2400;
         let to: i32 = from + 5;
-        let dista = unsafe { DIST[from as usize][to as usize] as i32 };
-        ret.push(Order{ id: i as i64, from, to, wait: 15, loss: 70, dist: dista, 
+        let dista = dp.dist(from as usize, to as usize) as i32;
+        ret.push(Order{ id: i as i64, from, to, wait: 1200, loss: 70, dist: dista, 
                     //shared: true, in_pool: false, 
-                    received: Some(Local::now().naive_local()), 
+                    passengers: 1, required: 0, shared: true, transfer: false, received: Some(Local::now().naive_local()), 
                     //started: None, completed: None, 
                     at_time: None, 
                     //eta: 1, 
+                    released: None,
                     route_id: -1 });
     }
     return ret;
@@ -699,15 +695,15 @@ mod tests {
   fn get_cabs(cab_count: usize) -> Vec<Cab> {
     let mut ret: Vec<Cab> = vec![];
     for i in 0..cab_count as i64 {
-        ret.push(Cab{ id: i, location: (i % 2400) as i32, seats: 10, dist: 0});
+        ret.push(Cab{ id: i, location: (i % 2400) as i32, seats: 10, dist: 0, features: 0});
     }
     return ret;
   }
 
-  fn test_init_orders_and_dist2(dist: f64, ord_count: usize, cab_count: usize) -> (Vec<Order>, Vec<Cab>, Vec<Stop>) {
+  fn test_init_orders_and_dist2(dist: f64, ord_count: usize, cab_count: usize) -> (Vec<Order>, Vec<Cab>, Vec<Stop>, Haversine) {
     let stops = get_stops2(dist);
-    init_distance(&stops, 30);
-    return (get_orders(ord_count), get_cabs(cab_count), stops);
+    let dp = Haversine::new(&stops, 30);
+    return (get_orders(ord_count, &dp), get_cabs(cab_count), stops, dp);
   }
 
   fn test_branches() -> (Box<[Branch; N]>, usize) {
@@ -722,23 +718,45 @@ mod tests {
   #[test]
   #[serial]
   fn test_find_pool(){
-    let (mut orders, mut cabs, _) = test_init_orders_and_dist2(0.03, 10, 1000);
+    let (mut orders, mut cabs, _, dp) = test_init_orders_and_dist2(0.03, 10, 1000);
+    let dp = &dp;
     let stops =  get_pool_stops(0.03);
     let mut max_route_id: i64 = 0;
     let mut max_leg_id: i64 = 0;
     let cfg = KernCfg::new();
     let ret = find_pool(4, 3, &mut orders, &mut cabs, &stops, &mut max_route_id, &mut max_leg_id,
-                                                cfg.max_angle, cfg.stop_wait);
+                                                cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
     assert_eq!(ret.0.len()>0, true);
   }
 
+  #[test]
+  #[serial]
+  fn test_find_pool_without_solo_trips(){
+    let (mut orders, mut cabs, _, dp) = test_init_orders_and_dist2(0.03, 10, 1000);
+    let dp = &dp;
+    let stops =  get_pool_stops(0.03);
+    for o in orders.iter_mut().filter(|o| o.id % 2 == 0) { o.shared = false; }
+    let solo: Vec<i64> = orders.iter().filter(|o| !o.shared).map(|o| o.id).collect();
+    let cfg = KernCfg::new();
+    let ret = find_pool(2, 3, &mut orders, &mut cabs, &stops, &mut 0, &mut 0,
+                                                cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
+    for b in ret.0.iter() {
+      for i in 0 .. b.ord_numb as usize {
+        assert!(!solo.contains(&(b.ord_ids[i] as i64)));
+      }
+    }
+    for id in solo { // not assigned, still there for the solver
+      assert!(orders.iter().any(|o| o.id == id));
+    }
+  }
+
   fn get_pool_stops(step: f64) -> Vec<Stop> {
     let mut stops: Vec<Stop> = vec![];
     let mut c: i64 = 0;
     for i in 0..49 {
       for j in 0..49 {
         stops.push(
-          Stop{ id: c, bearing: 0, latitude: 49.0 + step * i as f64, longitude: 19.000 + step * j as f64, capacity: 10}
+          Stop{ id: c, bearing: 0, latitude: 49.0 + step * i as f64, longitude: 19.000 + step * j as f64, capacity: 10, dwell: 60}
         );
         c = c + 1;
       }
@@ -746,18 +764,19 @@ mod tests {
     return stops;
   }
 
-  fn get_pool_orders() -> Vec<Order> {
+  fn get_pool_orders(dp: &dyn DistanceProvider) -> Vec<Order> {
     let mut ret: Vec<Order> = vec![];
     for i in 0..50 {     
         let from: i32 = i % 2400;
         let to: i32 = from + 5;
-        let dista = unsafe { DIST[from as usize][to as usize] as i32 };
-        ret.push(Order{ id: i as i64, from, to, wait: 15, loss: 70, dist: dista, 
+        let dista = dp.dist(from as usize, to as usize) as i32;
+        ret.push(Order{ id: i as i64, from, to, wait: 1200, loss: 70, dist: dista, 
                     //shared: true, in_pool: false, 
-                    received: Some(Local::now().naive_local()), 
+                    passengers: 1, required: 0, shared: true, transfer: false, received: Some(Local::now().naive_local()), 
                     //started: None, completed: None, 
                     at_time: None, 
                     //eta: 1, 
+                    released: None,
                     route_id: -1 });
     }
     return ret;
@@ -766,7 +785,7 @@ mod tests {
   fn get_pool_cabs() -> Vec<Cab> {
     let mut ret: Vec<Cab> = vec![];
     for i in 0..1000 {
-        ret.push(Cab{ id: i, location: (i % 2400) as i32, seats: 10, dist: 0});
+        ret.push(Cab{ id: i, location: (i % 2400) as i32, seats: 10, dist: 0, features: 0});
     }
     return ret;
   }
@@ -791,8 +810,8 @@ mod tests {
   #[serial]
   fn test_performance_find_pool(){
     let stops = get_pool_stops(0.03);
-    init_distance(&stops, 30);
-    let mut orders: Vec<Order> = get_pool_orders();
+    let dp = &Haversine::new(&stops, 30);
+    let mut orders: Vec<Order> = get_pool_orders(dp);
     let mut cabs: Vec<Cab> = get_pool_cabs();
     let mut max_route_id: i64 = 0;
     let mut max_leg_id: i64 = 0;
@@ -800,11 +819,11 @@ mod tests {
     let cfg = KernCfg::new();
     let ret = find_pool(4, 4, &mut orders, &mut cabs, 
                                                 &stops, &mut max_route_id, &mut max_leg_id,
-                                                cfg.max_angle, cfg.stop_wait);
+                                                cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
     let elapsed = start.elapsed();
     println!("Elapsed: {:?}", elapsed); 
     assert_eq!(ret.0.len(), 12);
-    assert_eq!(ret.1.len() > 17000, true);
+    assert_eq!(to_sql(&ret.1).len() > 17000, true);
   }
 
   fn append<T>(dst: &mut Vec<T>, src: &mut Vec<T>) {
@@ -852,12 +871,13 @@ mod tests {
   #[test]
   #[ignore]
   fn test_dive_4(){
-    let (orders, _, stops) = test_init_orders_and_dist2(0.003, 10, 1000);
+    let (orders, _, stops, dp) = test_init_orders_and_dist2(0.003, 10, 1000);
+    let dp = &dp;
     let start = Instant::now();
     let cfg = KernCfg::new();
     let mut node: Box<[Branch; N]> = vec![Branch::new(); N].try_into().unwrap();
     let mut node_size: usize = 0;
-    dive(0, 4, 3, &orders, &stops, cfg.max_angle, cfg.stop_wait, &mut node, &mut node_size);
+    dive(0, 4, 3, &orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), &mut node, &mut node_size, dp);
     println!("Elapsed: {:?}", start.elapsed()); 
     assert_eq!(node_size, 866);
   }
@@ -865,28 +885,32 @@ mod tests {
   #[test]
   #[serial]
   fn test_dive(){
-    let (orders, _) = test_init_orders_and_dist(1, 7);
-    let stops = get_pool_stops(0.003);
-    init_distance(&stops, 30);
+    let (orders, _, dp) = test_init_orders_and_dist(60, 7);
+    let dp = &dp;
+    let stops = get_pool_stops(0.003); // bearings only
     let cfg = KernCfg::new();
     let mut node: Box<[Branch; N]> = vec![Branch::new(); N].try_into().unwrap();
     let mut node_size: usize = 0;
-    dive(0, 4, 3, &orders, &stops, cfg.max_angle, cfg.stop_wait, &mut node, &mut node_size);
-    assert_eq!(node_size, 152);
+    dive(0, 4, 3, &orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), &mut node, &mut node_size, dp);
+    // a minute between stops and a minute at each, trips across more stops exceed their 'loss' when shared;
+    // only 1->6 and 2->5 with 6->1 and 5->2, in both directions
+    assert_eq!(node_size, 2);
+    assert_eq!(node[0].ord_ids, [1, 2, 2, 6, 1, 5, 5, 6]);
+    assert_eq!(node[1].ord_ids, [6, 5, 5, 1, 6, 2, 2, 1]);
   }
 
   #[test]
   #[serial]
   fn test_store_leaves222(){
     let stops = get_stops();
-    init_distance(&stops, 30);
-    let orders = set_orders();
+    let dp = &Haversine::new(&stops, 30);
+    let orders = set_orders(dp);
     let start = Instant::now();
     let cfg = KernCfg::new();
-    let leaves = store_leaves(&orders, &stops, cfg.max_angle, cfg.stop_wait);
+    let leaves = store_leaves(&orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
     let elapsed = start.elapsed();
     println!("Elapsed: {:?}", elapsed); 
-    assert_eq!(leaves.len(), 354470);
+    assert_eq!(leaves.len(), 354484);
   }
 
   #[test]
@@ -901,20 +925,35 @@ mod tests {
   #[serial]
   #[ignore]
   fn test_add_branch(){
-    let (orders, _) = test_init_orders_and_dist(1, 4);
+    let (orders, _, dp) = test_init_orders_and_dist(60, 4);
+    let dp = &dp;
     let cfg = KernCfg::new();
-    let ret = add_leaf(0, 1,'i', 2, &orders, cfg.stop_wait);
+    let ret = add_leaf(0, 1,'i', 2, &orders, &StopWait::same(cfg.stop_wait), dp);
     assert_eq!(ret.cost, 1);
   }
 
+  #[test]
+  fn test_add_leaf_boards_group() {
+    let (mut orders, _, dp) = test_init_orders_and_dist(60, 4);
+    let stop_wait = StopWait::new(&vec![], 60, 10); // 10 secs for each passenger getting in
+    let single = add_leaf(0, 1, 'i', 2, &orders, &stop_wait, &dp);
+    orders[0].passengers = 4;
+    let group = add_leaf(0, 1, 'i', 2, &orders, &stop_wait, &dp);
+    assert_eq!(group.cost - single.cost, 30);
+    // nobody boards at a drop-off
+    assert_eq!(add_leaf(0, 1, 'o', 2, &orders, &stop_wait, &dp).cost,
+               dp.dist(orders[0].to as usize, orders[1].to as usize) as i32 + 60);
+  }
+
   fn set_iterate_orders(len: i32) -> Vec<Order> {
     let mut orders: Vec<Order> = vec![];
     for i in 0..len{
       let from: i32 = i;
       let to: i32 = from + 1;
-      orders.push(Order{ id: i as i64, from, to, wait: 15, loss: 1000, dist: 10, 
-        received: None, 
+      orders.push(Order{ id: i as i64, from, to, wait: 900, loss: 1000, dist: 600, 
+        passengers: 1, required: 0, shared: true, transfer: false, received: None, 
         at_time: None, 
+        released: None, 
         route_id: -1 });
     }
     return  orders;
@@ -924,7 +963,7 @@ mod tests {
   #[serial]
   fn test_iterate() {
     let stops = get_stops();
-    init_distance(&stops, 30);
+    let dp = &Haversine::new(&stops, 30);
     let orders = set_iterate_orders(10);
     let cfg = KernCfg::new();
     let mut node: Box<[Branch; N]> = vec![Branch::new(); N].try_into().unwrap();
@@ -939,7 +978,7 @@ mod tests {
     if t_numb as usize * chunk < orders.len() { t_numb += 1; } // last thread will be the reminder of division
     if t_numb as usize * chunk < orders.len() { chunk *= 2; }
     let mut ret: Vec<Branch> = vec!(); 
-    ret = iterate(0, 4, 0, chunk, &orders, &stops, cfg.max_angle, cfg.stop_wait, &node, node_size);
+    ret = iterate(0, 4, 0, chunk, &orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), &node, node_size, dp);
     assert_eq!(ret.len(), 1);
     //println!("{} {}", ret[0].ord_ids[0], ret[0].ord_ids[1]);
   }
@@ -950,12 +989,12 @@ mod tests {
     let arr = 
       Branch{ cost: 1, outs: 4, ord_numb: 7, ord_ids: [1,2,3,3,2,1,0,0], ord_actions: [105,105,105,111,111,111,111,0], cab: 0, parity: 0 
     };
-    let (orders, _) = test_init_orders_and_dist(1, 5);
+    let (orders, _, _) = test_init_orders_and_dist(60, 5);
     let stops = get_stops();
-    init_distance(&stops, 30);
+    let dp = &Haversine::new(&stops, 30);
     let mut ret: Vec<Branch> = Vec::new();
     let cfg = KernCfg::new();
-    store_branch_if_not_found(0,4,0, &arr, &mut ret, &orders, &stops, cfg.max_angle, cfg.stop_wait);
+    store_branch_if_not_found(0,4,0, &arr, &mut ret, &orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
     assert_eq!(ret.len(), 0);
     //assert_eq!(ret[0].ord_ids[0], 0); // was 1, should be 0
     //assert_eq!(ret[0].ord_actions[7], 111); // was 0, should be 111
@@ -965,21 +1004,26 @@ mod tests {
   #[test]
   #[serial]
   fn test_is_not_too_long() {
-    let (orders, _) = test_init_orders_and_dist(1, 6);
+    let (orders, _, dp) = test_init_orders_and_dist(60, 6);
+    let dp = &dp;
     let b =  Branch{ cost: 1, outs: 1, ord_numb: 7, ord_ids: [1,2,3,4,4,3,2,1], ord_actions: [105,105,105,105,111,111,111,111], cab: 0, parity: 0 };
     let cfg = KernCfg::new();
-    let ret = is_too_long('i', 0, 1, &b, &orders, cfg.stop_wait);
+    let ret = is_too_long('i', 0, 1, &b, &orders, &StopWait::same(cfg.stop_wait), dp);
     assert_eq!(ret, false);
   }
 
   #[test]
   #[serial]
   fn test_store_branch() {
-    let (orders, _) = test_init_orders_and_dist(1, 6);
-    let b =  Branch{ cost: 1, outs: 1, ord_numb: 1, ord_ids: [1,2,3,4,4,3,2,1], ord_actions: [105,105,105,105,111,111,111,111], cab: 0, parity: 0 };
+    let (orders, _, dp) = test_init_orders_and_dist(60, 6);
+    let dp = &dp;
+    // one level deeper: three pickups and four drop-offs, order 0 to be picked up first
+    let b =  Branch{ cost: 1, outs: 4, ord_numb: 7, ord_ids: [1,2,3,3,2,1,0,0], ord_actions: [105,105,105,111,111,111,111,0], cab: 0, parity: 1 };
     let cfg = KernCfg::new();
-    let ret = store_branch('i', 0, 0, &b, 4, &orders, cfg.stop_wait);
-    assert_eq!(ret.cost, 3);
+    let ret = store_branch('i', 0, 0, &b, 4, &orders, &StopWait::same(cfg.stop_wait), dp);
+    assert_eq!(ret.ord_numb, 8);
+    assert_eq!(ret.parity, 0);
+    assert_eq!(ret.cost, 1 + 60 + 60); // stop 0 -> 1 and the stop at 0
   }
 /* 
   #[test]
@@ -988,25 +1032,30 @@ mod tests {
     test_branches();
     let mut max_route_id: i64 = 0;
     let mut max_leg_id: i64 = 0;
-    let mut cabs: Vec<Cab> = vec![Cab{ id: 0, location: 0, seats: 10 },Cab{ id: 1, location: 1, seats: 10 }];
-    test_init_orders_and_dist(1, 4);
+    let mut cabs: Vec<Cab> = vec![Cab{ id: 0, location: 0, seats: 10, features: 0 },Cab{ id: 1, location: 1, seats: 10, features: 0 }];
+    test_init_orders_and_dist(60, 4);
     let ret = rm_duplicates_assign_cab(4, &mut max_route_id, 
-                                                          &mut max_leg_id, &mut cabs);
+                                                          &mut max_leg_id, &mut cabs, dp);
     assert_eq!(ret.1, "UPDATE cab SET status=0 WHERE id=1;\nINSERT INTO route (id, status, cab_id) VALUES (0,1,1);\n");
   }
  */
   #[test]
   #[serial]
   fn test_assign_and_remove() {
-    let (orders, cabs) = test_init_orders_and_dist(1, 4);
+    let (orders, cabs, dp) = test_init_orders_and_dist(60, 4);
+    let dp = &dp;
     let (node, node_size) = test_branches();
     let slice =  &node[0..3];
     let mut arr = slice.to_vec();
     let mut max_route_id: i64 = 0;
     let mut max_leg_id: i64 = 0;
     let cfg = KernCfg::new();
-    let ret = assign_and_remove(&mut arr, 4, 0, cabs[0], &mut max_route_id, &mut max_leg_id, &orders, cfg.stop_wait);
-    assert_eq!(ret, "UPDATE cab SET status=0 WHERE id=0;\nINSERT INTO route (id, status, cab_id) VALUES (0,1,0);\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (0,0,1,0,1,1,16000,0,0);\n");
+    let ret = assign_and_remove(&mut arr, 4, 0, cabs[0], &mut max_route_id, &mut max_leg_id, &orders, &StopWait::same(cfg.stop_wait), dp);
+    assert_eq!(to_sql(&ret), "UPDATE cab SET status=0 WHERE id=0 AND status IN (0,1);\n\
+      UPDATE route SET locked = true WHERE status IN (1,5) AND cab_id=0;\n\
+      INSERT INTO route (id, status, cab_id, locked) VALUES (0,1,0,false);\n\
+      INSERT INTO outbox (seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta) VALUES (-1,'ROUTE_CREATED',-1,0,-1,0,-1,-1,-1,-1);\n\
+      INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (0,0,1,0,1,1,16000,0,0);\n");
   }
 
 
@@ -1021,63 +1070,101 @@ mod tests {
     assert_eq!(arr[1].cost, -1);
   }
 
+  #[test]
+  #[serial]
+  fn test_count_passengers_of_groups() {
+    let (mut orders, mut cabs, dp) = test_init_orders_and_dist(60, 2);
+    orders[0].passengers = 3;
+    orders[1].passengers = 4;
+    let mut br = Branch::new();
+    br.ord_numb = 4;
+    br.ord_ids[0] = 0; br.ord_ids[1] = 1; br.ord_ids[2] = 0; br.ord_ids[3] = 1;
+    br.ord_actions[0] = 'i' as i8; br.ord_actions[1] = 'i' as i8; br.ord_actions[2] = 'o' as i8; br.ord_actions[3] = 'o' as i8;
+    assert_eq!(count_passengers(br, &orders), 7); // both groups in the cab between 2nd pickup and 1st drop-off
+    br.ord_ids[1] = 0; br.ord_ids[2] = 1; br.ord_actions[1] = 'o' as i8; br.ord_actions[2] = 'i' as i8;
+    assert_eq!(count_passengers(br, &orders), 4); // one after another
+    cabs[0].seats = 4;
+    assert_eq!(find_nearest_cab(0, 7, 0, &cabs, &orders, &dp), 1);
+    cabs[1].seats = 6;
+    assert_eq!(find_nearest_cab(0, 7, 0, &cabs, &orders, &dp), -2); // no cab big enough
+  }
+
+  #[test]
+  #[serial]
+  fn test_find_nearest_cab_with_features() {
+    let (mut orders, mut cabs, dp) = test_init_orders_and_dist(60, 2);
+    orders[1].required = 1; // wheelchair
+    let mut br = Branch::new();
+    br.ord_numb = 4;
+    br.ord_ids[0] = 0; br.ord_ids[1] = 1; br.ord_ids[2] = 0; br.ord_ids[3] = 1;
+    assert_eq!(required_features(br, &orders), 1);
+    assert_eq!(find_nearest_cab(0, 2, 1, &cabs, &orders, &dp), -2); // no cab with a ramp
+    cabs[1].features = 1 | 2;
+    assert_eq!(find_nearest_cab(0, 2, 1, &cabs, &orders, &dp), 1); // not the nearest one
+    assert_eq!(find_nearest_cab(0, 2, 0, &cabs, &orders, &dp), 0);
+  }
+
   #[test]
   #[serial]
   fn test_find_nearest_cab() {
-   let (orders, cabs) = test_init_orders_and_dist(1, 4);
-    assert_eq!(find_nearest_cab(0, 2, &cabs, &orders), 0);
+   let (orders, cabs, dp) = test_init_orders_and_dist(60, 4);
+    let dp = &dp;
+    assert_eq!(find_nearest_cab(0, 2, 0, &cabs, &orders, dp), 0);
   }
 
   #[test]
   //#[ignore] // fails when run with others 
   #[serial]
   fn test_constraints_met() {
-    let (orders, _) = test_init_orders_and_dist(1, 5);
+    let (orders, _, dp) = test_init_orders_and_dist(60, 5);
+    let dp = &dp;
     let br = Branch{ cost: 1, outs: 4, ord_numb: 8, ord_ids: [0,1,2,3,3,2,1,0], 
             ord_actions: [105,105,105,105,111,111,111,111], cab: 0, parity: 0 };
     let cfg = KernCfg::new();
-    assert_eq!(constraints_met(br, 1, &orders, cfg.stop_wait), true);
+    assert_eq!(constraints_met(br, 1, &orders, &StopWait::same(cfg.stop_wait), dp), true);
   }
 
   #[test]
   #[serial]
   fn test_constraints_not_met() {
-    let (orders, _)= test_init_orders_and_dist(10, 4);
+    let (orders, _, dp) = test_init_orders_and_dist(600, 4);
+    let dp = &dp;
     let br = Branch{ cost: 1, outs: 4, ord_numb: 8, ord_ids: [0,1,2,3,3,2,1,0], 
             ord_actions: [105,105,105,105,111,111,111,111], cab: 0, parity: 0 };
     let cfg = KernCfg::new();
-    assert_eq!(constraints_met(br, 1, &orders, cfg.stop_wait), false);
+    assert_eq!(constraints_met(br, 1, &orders, &StopWait::same(cfg.stop_wait), dp), false);
   }
  
   #[test]
   #[serial]
   fn test_orders_to_transfer_array() {
     let vec: Vec<Order> = vec![Order{ id: 1, from: 1, to: 2, wait: 10, loss: 50, dist: 2, //shared: true, in_pool: false,
-          received: None,//started: None,completed: None,
+          passengers: 1, required: 0, shared: true, transfer: false, received: None,//started: None,completed: None,
           at_time: None,
           //eta: 0, 
+          released: None,
           route_id: -1
     }];
     let arr = orders_to_transfer_array(&vec);
-    assert_eq!(arr.len(), MAXORDERSNUMB);
+    assert_eq!(arr.len(), 1);
     assert_eq!(arr[0].id, 1);
   }
 
   #[test]
   #[serial]
   fn test_cabs_to_array() {
-    let vec: Vec<Cab> = vec![Cab{id: 0, location: 0, seats: 0, dist: 0}];
+    let vec: Vec<Cab> = vec![Cab{id: 0, location: 0, seats: 0, dist: 0, features: 0}];
     let arr = cabs_to_array(&vec);
-    assert_eq!(arr.len(), MAXCABSNUMB);
+    assert_eq!(arr.len(), 1);
     assert_eq!(arr[0].id, 0);
   }
 
   #[test]
   #[serial]
   fn test_stops_to_array() {
-    let vec: Vec<Stop> = vec![ Stop{id:0,bearing:0, latitude: 0.0, longitude: 0.0, capacity: 10 }];
+    let vec: Vec<Stop> = vec![ Stop{id:0,bearing:0, latitude: 0.0, longitude: 0.0, capacity: 10, dwell: 60 }];
     let arr = stops_to_array(&vec);
-    assert_eq!(arr.len(), MAXSTOPSNUMB);
+    assert_eq!(arr.len(), 1);
     assert_eq!(arr[0].id, 0);
   }
 
@@ -1095,4 +1182,48 @@ mod tests {
     println!("Size: {}", size);
     println!("Content: {:?}", node);
   }
+
+  #[test]
+  #[serial]
+  fn test_store_leaves_asymmetric() {
+    let stops = get_stops();
+    let mut dp = MemoryMatrix::new(4);
+    for i in 0..4 {
+      for j in 0..4 {
+        dp.set(i, j, if j >= i { 60 * (j - i) as i16 } else { 300 * (i - j) as i16 }); // seconds
+      }
+    }
+    let dp = &dp;
+    let orders = vec![
+      Order{ id: 0, from: 0, to: 2, wait: 900, loss: 10, dist: 120, passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 },
+      Order{ id: 1, from: 1, to: 3, wait: 900, loss: 10, dist: 120, passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 }
+    ];
+    let cfg = KernCfg::new();
+    let leaves = store_leaves(&orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
+    // two 'in-out' leaves and only 0out-1out, 1out-0out would take 3->2 which is too long
+    assert_eq!(leaves.len(), 3);
+    let outs: Vec<&Branch> = leaves.iter().filter(|l| l.ord_actions[0] == 'o' as i8).collect();
+    assert_eq!(outs.len(), 1);
+    assert_eq!(outs[0].ord_ids[0], 0);
+    assert_eq!(outs[0].ord_ids[1], 1);
+    assert_eq!(outs[0].cost, 120); // dp.dist(2, 3) + stop_wait
+  }
+
+  #[test]
+  #[serial]
+  fn test_store_leaves_without_solo_trips() {
+    let stops = get_stops();
+    let dp = &MemoryMatrix::with_value(4, 60);
+    let mut orders = vec![
+      Order{ id: 0, from: 0, to: 2, wait: 900, loss: 10, dist: 120, passengers: 1, required: 0, shared: true, transfer: false, received: None, at_time: None, released: None, route_id: -1 },
+      Order{ id: 1, from: 1, to: 3, wait: 900, loss: 10, dist: 120, passengers: 1, required: 0, shared: false, transfer: false, received: None, at_time: None, released: None, route_id: -1 }
+    ];
+    let cfg = KernCfg::new();
+    let leaves = store_leaves(&orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), dp);
+    assert_eq!(leaves.len(), 1); // 0in-0out only
+    assert_eq!(leaves[0].ord_ids[0], 0);
+    assert_eq!(leaves[0].ord_ids[1], 0);
+    orders[1].shared = true;
+    assert_eq!(store_leaves(&orders, &stops, cfg.max_angle, &StopWait::same(cfg.stop_wait), dp).len(), 4);
+  }
 }
\ No newline at end of file
@@ -0,0 +1,251 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Time-of-day travel time profiles, e.g. weekday peak and night
+/// The active profile is copied to the distance matrix between dispatcher runs, so that the pool finder, the extender
+/// and the solvers always see the travel times valid at planning time
+use std::collections::HashMap;
+use chrono::{Datelike, NaiveDateTime, Timelike};
+use log::info;
+use crate::distance::{DistanceProvider, Matrix, get_distance, matrix_format, read_distance_file, set_distance};
+use crate::model::Stop;
+
+const DAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+// when a profile is valid; minutes of day, 'end' smaller than 'start' means a period after midnight
+pub struct Period {
+    days: [bool; 7], // Monday first
+    start: u32,
+    end: u32,
+    profile: usize
+}
+
+pub struct Profiles {
+    base: Matrix, // travel times used outside of the schedule - from 'dist_file' or computed from coordinates
+    names: Vec<String>,
+    matrices: Vec<Matrix>,
+    schedule: Vec<Period>,
+    active: Option<usize> // None: base
+}
+
+// dist_profiles = "peak:dist-peak.csv; night:dist-night.bin"
+// dist_schedule = "mon-fri 06:30-09:30 peak; mon-fri 15:30-18:30 peak; 22:00-05:30 night"
+// returns None if no profiles are configured
+pub fn init_profiles(cfg: &HashMap<String, String>, stops: &Vec<Stop>, dp: &dyn DistanceProvider) -> Result<Option<Profiles>, String> {
+    let profiles_cfg = match cfg.get("dist_profiles") {
+        Some(x) => x,
+        None => { return Ok(None); }
+    };
+    let schedule_cfg = match cfg.get("dist_schedule") {
+        Some(x) => x,
+        None => { return Err("'dist_profiles' requires 'dist_schedule'".to_string()); }
+    };
+    let mut names: Vec<String> = vec![];
+    let mut matrices: Vec<Matrix> = vec![];
+    for entry in profiles_cfg.split(';').map(|e| e.trim()).filter(|e| e.len() > 0) {
+        let (name, path) = match entry.split_once(':') {
+            Some((n, p)) => (n.trim(), p.trim()),
+            None => { return Err(format!("Profile should be 'name:file': {}", entry)); }
+        };
+        info!("Travel time profile: {}, file: {}", name, path);
+        matrices.push(read_distance_file(path, matrix_format(path), stops)?);
+        names.push(name.to_string());
+    }
+    let schedule = parse_schedule(schedule_cfg, &names)?;
+    return Ok(Some(Profiles { base: get_distance(dp.matrix(), stops), names, matrices, schedule, active: None }));
+}
+
+pub fn parse_schedule(schedule: &str, names: &Vec<String>) -> Result<Vec<Period>, String> {
+    let mut ret: Vec<Period> = vec![];
+    for entry in schedule.split(';').map(|e| e.trim()).filter(|e| e.len() > 0) {
+        let parts: Vec<&str> = entry.split_whitespace().collect();
+        let (days, hours, name) = match parts.len() {
+            2 => ([true; 7], parts[0], parts[1]),
+            3 => (parse_days(parts[0])?, parts[1], parts[2]),
+            _ => { return Err(format!("Period should be '[days] HH:MM-HH:MM profile': {}", entry)); }
+        };
+        let (start, end) = match hours.split_once('-') {
+            Some((s, e)) => (parse_time(s)?, parse_time(e)?),
+            None => { return Err(format!("Wrong hours in period: {}", entry)); }
+        };
+        let profile = match names.iter().position(|n| n == name) {
+            Some(x) => x,
+            None => { return Err(format!("Unknown profile in schedule: {}", name)); }
+        };
+        ret.push(Period { days, start, end, profile });
+    }
+    return Ok(ret);
+}
+
+// "mon-fri", "sat,sun", "sun"
+fn parse_days(days: &str) -> Result<[bool; 7], String> {
+    let mut ret = [false; 7];
+    for part in days.split(',') {
+        let (from, to) = match part.split_once('-') {
+            Some((f, t)) => (day_idx(f)?, day_idx(t)?),
+            None => (day_idx(part)?, day_idx(part)?)
+        };
+        let mut d = from;
+        loop {
+            ret[d] = true;
+            if d == to { break; }
+            d = (d + 1) % 7;
+        }
+    }
+    return Ok(ret);
+}
+
+fn day_idx(day: &str) -> Result<usize, String> {
+    let day = day.trim().to_lowercase();
+    return match DAYS.iter().position(|d| *d == day) {
+        Some(x) => Ok(x),
+        None => Err(format!("Unknown day: {}", day))
+    };
+}
+
+fn parse_time(time: &str) -> Result<u32, String> {
+    let err = || format!("Time should be HH:MM: {}", time);
+    let (h, m) = time.trim().split_once(':').ok_or_else(err)?;
+    let h: u32 = h.parse().map_err(|_| err())?;
+    let m: u32 = m.parse().map_err(|_| err())?;
+    if h > 24 || m > 59 || (h == 24 && m > 0) { // 24:00 is the end of a day
+        return Err(err());
+    }
+    return Ok(h * 60 + m);
+}
+
+// index of the profile valid at the given time, the first matching period wins
+pub fn find_profile(schedule: &Vec<Period>, time: NaiveDateTime) -> Option<usize> {
+    let day = time.weekday().num_days_from_monday() as usize;
+    let minute = time.hour() * 60 + time.minute();
+    for p in schedule.iter() {
+        let in_period = if p.start <= p.end { minute >= p.start && minute < p.end }
+                        else { minute >= p.start || minute < p.end };
+        if p.days[day] && in_period {
+            return Some(p.profile);
+        }
+    }
+    return None;
+}
+
+impl Profiles {
+    // to be called before each dispatcher run; returns true if the matrix has been switched
+    pub fn switch(&mut self, time: NaiveDateTime, dp: &mut dyn DistanceProvider) -> bool {
+        let profile = find_profile(&self.schedule, time);
+        if profile == self.active {
+            return false;
+        }
+        match profile {
+            Some(x) => {
+                info!("Switching travel times to profile: {}", self.names[x]);
+                set_distance(dp.matrix_mut(), &self.matrices[x]);
+            },
+            None => {
+                info!("Switching travel times to default");
+                set_distance(dp.matrix_mut(), &self.base);
+            }
+        }
+        self.active = profile;
+        return true;
+    }
+
+    // the profile valid at the given time, None: default travel times
+    pub fn find(&self, time: NaiveDateTime) -> Option<usize> {
+        return find_profile(&self.schedule, time);
+    }
+
+    pub fn active(&self) -> Option<usize> {
+        return self.active;
+    }
+
+    // the matrix has been fetched again, e.g. from a routing engine; it becomes the new default
+    // and the active profile, which has just been overwritten, is put back
+    pub fn rebase(&mut self, stops: &Vec<Stop>, dp: &mut dyn DistanceProvider) {
+        self.base = get_distance(dp.matrix(), stops);
+        if let Some(x) = self.active {
+            set_distance(dp.matrix_mut(), &self.matrices[x]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::distance::MemoryMatrix;
+  use chrono::NaiveDate;
+
+  fn names() -> Vec<String> {
+    return vec!["peak".to_string(), "night".to_string()];
+  }
+
+  fn at(day: u32, hour: u32, min: u32) -> NaiveDateTime {
+    // 2025-01-06 is Monday
+    return NaiveDate::from_ymd_opt(2025, 1, day).unwrap().and_hms_opt(hour, min, 0).unwrap();
+  }
+
+  #[test]
+  fn test_find_profile() {
+    let schedule = parse_schedule("mon-fri 06:30-09:30 peak; 22:00-05:30 night", &names()).unwrap();
+    assert_eq!(find_profile(&schedule, at(6, 7, 0)), Some(0));
+    assert_eq!(find_profile(&schedule, at(6, 9, 30)), None);
+    assert_eq!(find_profile(&schedule, at(11, 7, 0)), None); // Saturday
+    assert_eq!(find_profile(&schedule, at(11, 23, 0)), Some(1));
+    assert_eq!(find_profile(&schedule, at(12, 5, 0)), Some(1));
+  }
+
+  #[test]
+  fn test_parse_time() {
+    assert_eq!(parse_time("07:30"), Ok(450));
+    assert_eq!(parse_time("24:00"), Ok(1440));
+    assert!(parse_time("24:30").is_err());
+    assert!(parse_time("12:60").is_err());
+  }
+
+  #[test]
+  fn test_parse_schedule_unknown_profile() {
+    let ret = parse_schedule("sat,sun 10:00-12:00 rush", &names());
+    assert_eq!(ret.err().unwrap(), "Unknown profile in schedule: rush");
+  }
+
+  #[test]
+  fn test_switch_profile() {
+    let ids = vec![0, 1];
+    let mut profiles = Profiles {
+      base: Matrix { ids: ids.clone(), values: vec![0, 2, 2, 0] },
+      names: names(),
+      matrices: vec![Matrix { ids: ids.clone(), values: vec![0, 4, 3, 0] }, Matrix { ids, values: vec![0, 1, 1, 0] }],
+      schedule: parse_schedule("mon-fri 06:30-09:30 peak; 22:00-05:30 night", &names()).unwrap(),
+      active: None
+    };
+    let mut dp = MemoryMatrix::new(2);
+    assert!(profiles.switch(at(6, 8, 0), &mut dp));
+    assert_eq!(dp.dist(0, 1), 4);
+    assert_eq!(dp.dist(1, 0), 3);
+    assert!(!profiles.switch(at(6, 9, 0), &mut dp)); // still peak
+    assert!(profiles.switch(at(6, 12, 0), &mut dp));
+    assert_eq!(dp.dist(0, 1), 2);
+    assert!(profiles.switch(at(6, 23, 0), &mut dp));
+    assert_eq!(dp.dist(0, 1), 1);
+  }
+
+  #[test]
+  fn test_rebase() {
+    let ids = vec![0, 1];
+    let stops = vec![Stop{ id: 0, bearing: 0, latitude: 49.0, longitude: 19.0, capacity: 10, dwell: 60 },
+                     Stop{ id: 1, bearing: 0, latitude: 49.1, longitude: 19.0, capacity: 10, dwell: 60 }];
+    let mut profiles = Profiles {
+      base: Matrix { ids: ids.clone(), values: vec![0, 2, 2, 0] },
+      names: names(),
+      matrices: vec![Matrix { ids: ids.clone(), values: vec![0, 4, 3, 0] }, Matrix { ids, values: vec![0, 1, 1, 0] }],
+      schedule: parse_schedule("mon-fri 06:30-09:30 peak; 22:00-05:30 night", &names()).unwrap(),
+      active: None
+    };
+    let mut dp = MemoryMatrix::new(2);
+    profiles.switch(at(6, 8, 0), &mut dp);
+    dp.set(0, 1, 7); // fetched again
+    profiles.rebase(&stops, &mut dp);
+    assert_eq!(dp.dist(0, 1), 4); // peak is still active
+    profiles.switch(at(6, 12, 0), &mut dp);
+    assert_eq!(dp.dist(0, 1), 7);
+  }
+}
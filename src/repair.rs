@@ -0,0 +1,274 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Route repair - a customer who abandoned the route (ABANDONED by Kapir) or did not show up at the pickup
+/// leaves stops in the route that nobody needs and seats that nobody takes. Before each dispatch their orders
+/// are detached from the route, such stops are removed (the leg before goes further) and legs get passengers
+/// and reserves of customers who stay, so that the extender can use the cab again
+use std::cmp;
+use chrono::{Duration, Local};
+use log::info;
+use crate::command::Command;
+use crate::distance::DistanceProvider;
+use crate::model::{KernCfg, Leg, Order, OrderStatus, RouteStatus, Stop, StopWait};
+use crate::repo::{Repository, detach_order, remove_leg, run_sql, update_leg_a_bit2, update_place_in_legs_after_removal};
+use crate::utils::{get_elapsed, to_mins, to_mins_floor, MINUTE};
+
+// returns the number of orders taken off their routes
+pub fn repair_routes(conn: &mut dyn Repository, stops: &Vec<Stop>, cfg: &KernCfg, dp: &dyn DistanceProvider) -> usize {
+    let left_before = Local::now().naive_local() - Duration::seconds(cfg.no_show_wait as i64);
+    // no-shows only if asked for, it takes customers marked as PICKEDUP by Kapir
+    let orders: Vec<(Order, bool)> = conn.find_orders_to_repair(left_before, dp).into_iter()
+                                        .filter(|(_, no_show)| cfg.no_show_wait > 0 || !no_show).collect();
+    if orders.len() == 0 {
+        return 0;
+    }
+    let stop_wait = StopWait::new(stops, cfg.stop_wait, cfg.board_wait);
+    let mut sql: Vec<Command> = vec![];
+    let mut i = 0;
+    while i < orders.len() { // sorted by route
+        let route_id = orders[i].0.route_id;
+        let gone: Vec<(Order, bool)> = orders[i..].iter().take_while(|(o, _)| o.route_id == route_id).copied().collect();
+        i += gone.len();
+        let legs = conn.find_route_legs(route_id, dp);
+        let travelling = [OrderStatus::ASSIGNED, OrderStatus::ACCEPTED, OrderStatus::PICKEDUP];
+        let others: Vec<Order> = conn.find_route_orders(route_id, &travelling, dp).into_iter()
+                                    .filter(|o| !gone.iter().any(|(g, _)| g.id == o.id)).collect();
+        sql.extend(repair_route(&gone, &others, &legs, &stop_wait, dp));
+    }
+    info!("Route repair: {} orders abandoned or not picked up", orders.len());
+    // a route changed in the meantime (a leg started, a customer picked up after all) is repaired in the next run
+    run_sql(conn, sql, "Repair");
+    return orders.len();
+}
+
+// 'gone' - orders of the route to be detached with a no-show flag, 'others' - orders that stay,
+// 'legs' - not completed legs of the route
+pub fn repair_route(gone: &Vec<(Order, bool)>, others: &Vec<Order>, legs: &Vec<Leg>, stop_wait: &StopWait,
+                    dp: &dyn DistanceProvider) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
+    // first, the pickup leg may be deleted below
+    for (o, no_show) in gone.iter() {
+        sql.extend(detach_order(o.id, o.route_id, *no_show));
+    }
+    let mut new_legs: Vec<Leg> = legs.clone();
+    for (o, _) in gone.iter() {
+        if let Some((p, q)) = ride(o, &new_legs) {
+            for l in new_legs[p.unwrap_or(0) ..= q].iter_mut() {
+                l.passengers = cmp::max(0, l.passengers - o.passengers);
+            }
+        }
+    }
+    // stops where nobody gets in or out any more
+    let mut stands: Vec<i32> = vec![];
+    for (o, _) in gone.iter() {
+        for s in [o.from, o.to] {
+            if !stands.contains(&s) && !others.iter().any(|x| x.from == s || x.to == s) {
+                stands.push(s);
+            }
+        }
+    }
+    for s in stands.iter() {
+        while let Some(k) = removable_stop(&new_legs, *s) {
+            sql.extend(remove_stop(&mut new_legs, k, dp));
+        }
+    }
+    // legs that changed, reserves are counted again for customers who stay
+    let reserves = count_reserves(&new_legs, others, stop_wait);
+    for (l, reserve) in new_legs.iter().zip(reserves.iter()) {
+        let old = match legs.iter().find(|x| x.id == l.id) { Some(x) => x, None => continue };
+        if l.to != old.to || to_mins(l.dist) != to_mins(old.dist) || l.passengers != old.passengers
+                || to_mins_floor(*reserve) != to_mins_floor(old.reserve) {
+            sql.extend(update_leg_a_bit2(l.route_id, l.id, l.to, l.dist, *reserve, l.passengers));
+        }
+    }
+    return sql;
+}
+
+// legs of the order in the route: the one it gets in at (None if it is in the cab already) and the one it gets out at;
+// None if the route does not take it anywhere any more
+fn ride(o: &Order, legs: &Vec<Leg>) -> Option<(Option<usize>, usize)> {
+    let drop_off = |from: usize| (from .. legs.len()).find(|i| legs[*i].to == o.to);
+    if let Some(p) = legs.iter().position(|l| l.from == o.from) {
+        if let Some(q) = drop_off(p) {
+            return Some((Some(p), q));
+        }
+    }
+    return drop_off(0).map(|q| (None, q));
+}
+
+// the index of the leg that ends at the stop that can go, the next one is deleted or,
+// if it is the last stop, the leg itself; legs already driven or deleted by Kab stay
+fn removable_stop(legs: &Vec<Leg>, stand: i32) -> Option<usize> {
+    return (0 .. legs.len()).find(|k| legs[*k].to == stand
+        && if *k + 1 < legs.len() { legs[*k + 1].status == RouteStatus::ASSIGNED }
+           else { *k > 0 && legs[*k].status == RouteStatus::ASSIGNED });
+}
+
+fn remove_stop(legs: &mut Vec<Leg>, k: usize, dp: &dyn DistanceProvider) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
+    let removed = if k + 1 < legs.len() { k + 1 } else { k };
+    sql.extend(remove_leg(&legs[removed]));
+    if removed == k + 1 { // the leg before takes over the destination
+        let next = legs[removed];
+        let l = &mut legs[k];
+        l.to = next.to;
+        l.dist = dp.dist(l.from as usize, l.to as usize) as i32;
+        l.passengers = cmp::max(l.passengers, next.passengers);
+        l.reserve = cmp::min(l.reserve, next.reserve);
+        sql.extend(update_place_in_legs_after_removal(next.route_id, next.place + 1));
+    }
+    legs.remove(removed);
+    for l in legs[removed..].iter_mut() {
+        l.place -= 1;
+    }
+    return sql;
+}
+
+// reserves of legs as if the route was planned now - what is left of max_wait of customers to be picked up
+// (counted from 'received' or 'at_time') and of max_loss; customers in the cab keep reserves of the legs they ride,
+// the route does not know when they got in
+fn count_reserves(legs: &Vec<Leg>, orders: &Vec<Order>, stop_wait: &StopWait) -> Vec<i32> {
+    let mut ends: Vec<i32> = vec![]; // secs from now to the end of each leg
+    let mut time: i32 = 0;
+    for (i, l) in legs.iter().enumerate() {
+        if i == 0 && l.status == RouteStatus::STARTED {
+            let passed = get_elapsed(l.started) as i32;
+            time += if passed > 0 { cmp::max(0, l.dist - passed) } else { l.dist };
+        } else {
+            time += l.dist + stop_wait.between(l.from as usize, l.to as usize, 0) as i32;
+        }
+        ends.push(time);
+    }
+    let mut res: Vec<i32> = vec![16000 * MINUTE; legs.len()];
+    for o in orders.iter() {
+        match ride(o, legs) {
+            Some((Some(p), q)) => {
+                let arrival = if p == 0 { 0 } else { ends[p - 1] };
+                let waited = cmp::max(0, get_elapsed(o.at_time.or(o.received)) as i32);
+                let wait = cmp::max(0, o.wait - waited - arrival);
+                let loss = cmp::max(0, o.dist * (100 + o.loss) / 100 - (ends[q] - arrival));
+                for m in 0 .. p {
+                    res[m] = cmp::min(res[m], wait);
+                }
+                for m in p ..= q {
+                    res[m] = cmp::min(res[m], loss);
+                }
+            },
+            Some((None, q)) => {
+                for m in 0 ..= q {
+                    res[m] = cmp::min(res[m], legs[m].reserve);
+                }
+            },
+            None => {}
+        }
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::distance::MemoryMatrix;
+  use crate::event::Event;
+  use crate::mem_repo::{LegRow, MemRepo, RouteRow};
+
+  fn test_order(id: i64, from: i32, to: i32) -> Order {
+    return Order { id, from, to, wait: 15 * 60, loss: 90, dist: 60 * (to - from).abs(), passengers: 1, required: 0,
+                   shared: true, transfer: false, received: None, at_time: None, released: None, route_id: 7 };
+  }
+
+  fn test_leg(id: i64, from: i32, to: i32, place: i32, passengers: i32) -> Leg {
+    return Leg { id, route_id: 7, from, to, place, dist: 60 * (to - from).abs(), reserve: 10 * 60, started: None,
+                 status: RouteStatus::ASSIGNED, passengers, seats: 4, features: 0 };
+  }
+
+  // 0 -> 1 -> 2 -> 3 -> 4, the first customer 1 -> 3, the second one 0 -> 4
+  fn test_legs() -> Vec<Leg> {
+    return vec![test_leg(10, 0, 1, 0, 1), test_leg(11, 1, 2, 1, 2), test_leg(12, 2, 3, 2, 2), test_leg(13, 3, 4, 3, 1)];
+  }
+
+  #[test]
+  fn test_ride() {
+    let legs = test_legs();
+    assert_eq!(ride(&test_order(1, 1, 3), &legs), Some((Some(1), 2)));
+    assert_eq!(ride(&test_order(2, 5, 4), &legs), Some((None, 3))); // in the cab
+    assert_eq!(ride(&test_order(3, 1, 5), &legs), None);
+  }
+
+  #[test]
+  fn test_repair_route_abandoned() {
+    let dp = MemoryMatrix::line(6, 60);
+    let gone = vec![(test_order(1, 1, 3), false)];
+    let sql = repair_route(&gone, &vec![test_order(2, 0, 4)], &test_legs(), &StopWait::same(0), &dp);
+    assert_eq!(sql[0], Command::DetachOrder { order_id: 1, route_id: 7 });
+    // stops 1 and 3 go, 0 -> 2 -> 4 with one passenger
+    let deleted: Vec<&Command> = sql.iter().filter(|c| matches!(c, Command::DeleteLeg { .. })).collect();
+    assert_eq!(deleted, vec![&Command::DeleteLeg { id: 11, route_id: 7 }, &Command::DeleteLeg { id: 13, route_id: 7 }]);
+    assert!(sql.contains(&Command::ClosePlaces { route_id: 7, place_from: 2 }));
+    assert!(sql.contains(&Command::ClosePlaces { route_id: 7, place_from: 3 })); // after the first one
+    // 4 minutes of 7.6 acceptable in the cab
+    assert!(sql.contains(&Command::UpdateLeg { id: 10, route_id: 7, to: 2, dist: 2, reserve: 3, passengers: 1 }));
+    assert!(sql.contains(&Command::UpdateLeg { id: 12, route_id: 7, to: 4, dist: 2, reserve: 3, passengers: 1 }));
+  }
+
+  #[test]
+  fn test_repair_route_stop_still_needed() {
+    let dp = MemoryMatrix::line(6, 60);
+    let gone = vec![(test_order(1, 1, 3), false)];
+    let others = vec![test_order(2, 0, 4), test_order(3, 3, 4)]; // someone gets in at 3
+    let sql = repair_route(&gone, &others, &test_legs(), &StopWait::same(0), &dp);
+    assert_eq!(sql.iter().filter(|c| matches!(c, Command::DeleteLeg { .. })).count(), 1); // only stop 1
+    // a seat free between 2 and 3
+    assert!(sql.contains(&Command::UpdateLeg { id: 10, route_id: 7, to: 2, dist: 2, reserve: 3, passengers: 1 }));
+    assert!(sql.contains(&Command::UpdateLeg { id: 12, route_id: 7, to: 3, dist: 1, reserve: 3, passengers: 1 }));
+    // 1.9 minutes acceptable from 3 to 4, 1 minute it takes
+    assert!(sql.contains(&Command::UpdateLeg { id: 13, route_id: 7, to: 4, dist: 1, reserve: 0, passengers: 1 }));
+  }
+
+  #[test]
+  fn test_repair_route_no_show() {
+    let dp = MemoryMatrix::line(6, 60);
+    let mut legs = test_legs();
+    legs.remove(0); // the cab left stop 1 without the first customer
+    legs[0].status = RouteStatus::STARTED;
+    let sql = repair_route(&vec![(test_order(1, 1, 3), true)], &vec![test_order(2, 0, 4)], &legs, &StopWait::same(0), &dp);
+    assert_eq!(sql[1], emit_abandoned(1));
+    // stop 1 is behind, stop 3 goes; the second customer is in the cab and keeps reserves
+    assert_eq!(sql.iter().filter(|c| matches!(c, Command::DeleteLeg { .. })).collect::<Vec<&Command>>(),
+               vec![&Command::DeleteLeg { id: 13, route_id: 7 }]);
+    assert!(sql.contains(&Command::UpdateLeg { id: 11, route_id: 7, to: 2, dist: 1, reserve: 10, passengers: 1 }));
+    assert!(sql.contains(&Command::UpdateLeg { id: 12, route_id: 7, to: 4, dist: 2, reserve: 10, passengers: 1 }));
+  }
+
+  #[test]
+  fn test_repair_routes_in_memory() {
+    let dp = MemoryMatrix::line(6, 60);
+    let stops: Vec<Stop> = (0..6).map(|i| Stop { id: i, bearing: 0, latitude: 49.0, longitude: 19.0, capacity: 1, dwell: 0 }).collect();
+    let mut repo = MemRepo::new();
+    for s in stops.iter() { repo.add_stop(s); }
+    repo.add_cab(1, 0, 4);
+    repo.routes.push(RouteRow { id: 7, status: RouteStatus::ASSIGNED as i32, cab_id: 1, locked: false });
+    for l in test_legs() {
+      repo.legs.push(LegRow { id: l.id, route_id: 7, from: l.from, to: l.to, place: l.place, dist: to_mins(l.dist), status: 1,
+                              reserve: 10, passengers: l.passengers, started: None, completed: None });
+    }
+    let gone = repo.add_order(1, 3, 15, 90, None);
+    let stays = repo.add_order(0, 4, 15, 90, None);
+    for (id, leg_id, status) in [(gone, 11, OrderStatus::ABANDONED), (stays, 10, OrderStatus::ASSIGNED)] {
+      let o = repo.orders.iter_mut().find(|o| o.id == id).unwrap();
+      (o.route_id, o.leg_id, o.status) = (7, leg_id, status as i32);
+    }
+    assert_eq!(repair_routes(&mut repo, &stops, &KernCfg::new(), &dp), 1);
+    let legs: Vec<(i32, i32, i32, i32)> = repo.route_legs(7).iter().map(|l| (l.from, l.to, l.place, l.passengers)).collect();
+    assert_eq!(legs, vec![(0, 2, 0, 1), (2, 4, 1, 1)]);
+    assert_eq!(repo.order(gone).unwrap().leg_id, -1);
+    assert_eq!(repo.events.iter().filter(|(_, e)| matches!(e, Event::LegRemoved { .. })).count(), 2);
+    // nothing to do in the next run
+    assert_eq!(repair_routes(&mut repo, &stops, &KernCfg::new(), &dp), 0);
+  }
+
+  fn emit_abandoned(order_id: i64) -> Command {
+    return crate::command::emit(Event::OrderAbandoned { order_id, route_id: 7 });
+  }
+}
@@ -1,281 +1,648 @@
 use std::cmp;
-use log::{debug, warn};
-use mysql::*;
-use mysql::prelude::*;
+use std::fmt;
+use log::{debug, warn, error};
 use chrono::{Local, NaiveDateTime};
-use crate::extender::STOP_WAIT;
-use crate::model::{Branch, Cab, CabAssign, CabStatus, Leg, Order, OrderStatus, RouteStatus, Stop, MAXORDID};
-use crate::distance::DIST;
+use crate::model::{Branch, Cab, CabAssign, CabStatus, KernCfg, Leg, Order, OrderStatus, RouteStatus, Stop, StopWait, MAXORDID};
+use crate::command::{Command, Param, check_plan, count_new_ids, emit, resolve_ids, without_conflicts};
+use crate::distance::DistanceProvider;
 use crate::stats::{STATS, Stat, add_avg_element, update_val, count_average};
-use crate::utils::get_elapsed;
+use crate::utils::{get_elapsed, to_secs, to_mins, to_mins_floor, MINUTE};
+use crate::mysql_repo::MySqlRepo;
+use crate::pg_repo::PgRepo;
+use crate::mem_repo::MemRepo;
+use crate::sqlite_repo::SqliteRepo;
+use crate::migrate::{self, Dialect};
+use crate::event::Event;
 
-pub fn find_orders_by_status_and_time(conn: &mut PooledConn, status: OrderStatus, at_time: NaiveDateTime) -> Vec<Order> {
-    let mut ret : Vec<Order> = Vec::new();
-    let qry = "SELECT id, from_stand, to_stand, max_wait, max_loss, distance, shared, in_pool, \
-               received, started, completed, at_time, eta, route_id FROM taxi_order WHERE status =".to_string() 
-               + &(status as u8).to_string() + 
-               &" and (at_time is NULL or at_time < '".to_string() + &at_time.to_string() + &"') ORDER by route_id".to_string();
+// what the dispatcher reads from and writes to a database
+pub trait Repository {
+    fn find_orders_by_status_and_time(&mut self, status: OrderStatus, at_time: NaiveDateTime, dp: &dyn DistanceProvider) -> Vec<Order>;
+    fn read_stops(&mut self, stop_wait: i16) -> Vec<Stop>;
+    fn read_free_taxi_orders(&mut self) -> Vec<CabAssign>;
+    fn find_cab_by_status(&mut self, status: CabStatus) -> Vec<Cab>;
+    fn find_free_cab_and_on_last_leg(&mut self) -> Vec<Cab>;
+    fn find_legs(&mut self, dp: &dyn DistanceProvider) -> Vec<Leg>;
+    fn find_completed_legs(&mut self, since: NaiveDateTime) -> Vec<(i32, i32, NaiveDateTime, NaiveDateTime)>;
+    // route repair: customers who abandoned their route, or did not show up at a pickup the cab left before 'left_before',
+    // while they still have a pickup leg; true for a no-show
+    fn find_orders_to_repair(&mut self, left_before: NaiveDateTime, dp: &dyn DistanceProvider) -> Vec<(Order, bool)>;
+    // legs of the route still to be driven, locked or not
+    fn find_route_legs(&mut self, route_id: i64, dp: &dyn DistanceProvider) -> Vec<Leg>;
+    // cab breakdown: active routes of cabs OUT_OF_SERVICE - route_id, cab_id
+    fn find_broken_routes(&mut self) -> Vec<(i64, i64)>;
+    // orders of the route in one of the statuses
+    fn find_route_orders(&mut self, route_id: i64, status: &[OrderStatus], dp: &dyn DistanceProvider) -> Vec<Order>;
+    // plans, stats, expired orders - commands generated below; the error tells which of them failed
+    // all or nothing - one transaction, rolled back on error
+    fn save(&mut self, cmds: &Vec<Command>) -> Result<(), String>;
+    // the last migration applied, see migrate.rs
+    fn schema_version(&mut self) -> Result<i32, String>;
+    // versions of the migrations applied now
+    fn migrate(&mut self) -> Result<Vec<i32>, String>;
+    // events with 'seq' greater than 'after', in the order of 'seq'; a consumer keeps the last one it has seen
+    fn read_events(&mut self, after: i64, limit: i64) -> Result<Vec<(i64, Event)>, String>;
+}
 
-    let selected: Result<Vec<Row>> = conn.query(qry);
-    
-    match selected {
-        Ok(sel) => {
-            for r in sel {
-                ret.push(Order {
-                    id: r.get(0).unwrap(),
-                    from: r.get(1).unwrap(),
-                    to: r.get(2).unwrap(),
-                    wait: r.get(3).unwrap(),
-                    loss: r.get(4).unwrap(),
-                    dist: r.get(5).unwrap(),
-                    //shared: r.get(6).unwrap(),
-                    //in_pool: r.get(7).unwrap(),
-                    received: get_naivedate(&r, 8),
-                    //started: get_naivedate(&r, 9),
-                    //completed: get_naivedate(&r, 10),
-                    at_time: get_naivedate(&r, 11),
-                    //eta: r.get(12).unwrap(),
-                    route_id: if matches!(status, OrderStatus::RECEIVED) { -1 } else { get_i64(&r, 13) }
-                });
-            }
-        },
-        Err(error) => warn!("Problem reading row: {:?}", error),
+// the backend is chosen with the scheme of 'db_conn'
+pub fn connect(url: &str) -> Result<Box<dyn Repository>, String> {
+    if url.starts_with("mysql://") {
+        return Ok(Box::new(MySqlRepo::new(url)?));
     }
-    return ret;
+    if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+        return Ok(Box::new(PgRepo::new(url)?));
+    }
+    if url.starts_with("sqlite://") {
+        return Ok(Box::new(SqliteRepo::new(url)?));
+    }
+    if url.starts_with("mem://") { // empty and private to this connection, nothing is kept after exit
+        return Ok(Box::new(MemRepo::new()));
+    }
+    return Err(format!("Unknown database in 'db_conn', mysql://, postgres://, sqlite:// and mem:// are supported: {}", url));
+}
+
+// a column as read by a SQL backend; text protocols give numbers and timestamps as text
+#[derive(Clone, Debug, PartialEq)]
+pub enum DbValue {
+    Null,
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Time(NaiveDateTime)
 }
 
-pub fn read_stops(conn: &mut PooledConn) -> Vec<Stop> {
-    return conn.query_map(
-        "SELECT id, latitude, longitude, bearing, capacity FROM stop",
-        |(id, latitude, longitude, bearing, capacity)| {
-            Stop { id, latitude, longitude, bearing, capacity }
-        },
-    ).unwrap();
+pub type DbRow = Vec<DbValue>;
+
+impl DbValue {
+    pub fn as_i64(&self) -> Option<i64> {
+        return match self {
+            DbValue::Int(x) => Some(*x),
+            DbValue::Float(x) => Some(*x as i64),
+            DbValue::Text(x) => x.trim().parse::<i64>().ok(),
+            _ => None
+        };
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        return match self {
+            DbValue::Int(x) => Some(*x as f64),
+            DbValue::Float(x) => Some(*x),
+            DbValue::Text(x) => x.trim().parse::<f64>().ok(),
+            _ => None
+        };
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        return match self {
+            DbValue::Int(x) => Some(*x != 0),
+            DbValue::Text(x) => match x.trim() { "1" | "t" | "true" => Some(true), "0" | "f" | "false" => Some(false), _ => None },
+            _ => None
+        };
+    }
+
+    pub fn as_time(&self) -> Option<NaiveDateTime> {
+        return match self {
+            DbValue::Time(x) => Some(*x),
+            DbValue::Text(x) => NaiveDateTime::parse_from_str(x.trim(), "%Y-%m-%d %H:%M:%S%.f").ok(),
+            _ => None
+        };
+    }
 }
 
-pub fn read_free_taxi_orders(conn: &mut PooledConn) -> Vec<CabAssign> {
-    return conn.query_map(
-        "SELECT id, customer_id, cab_id, from_stand, to_stand, max_loss, shared, received FROM freetaxi_order",
-        |(id, cust_id, cab_id, from, to, loss, shared, received)| {
-            CabAssign { id, cust_id, cab_id, from, to, loss, shared, received }
-        },
-    ).unwrap();
+// what a SQL database has to provide, queries are the same for all of them
+pub trait SqlConn {
+    fn query(&mut self, sql: &str) -> Result<Vec<DbRow>, String>;
+    // one statement prepared and executed with each row of parameters, rows changed by each of them;
+    // the index of the failed row and the error
+    fn execute(&mut self, sql: &'static str, rows: &Vec<Vec<Param>>) -> Result<Vec<u64>, (usize, String)>;
+    fn begin(&mut self) -> Result<(), String>;
+    fn commit(&mut self) -> Result<(), String>;
+    fn rollback(&mut self) -> Result<(), String>;
+    // more statements without parameters, migrations
+    fn execute_script(&mut self, sql: &str) -> Result<(), String>;
+    fn dialect(&self) -> Dialect;
 }
 
-pub fn read_max(conn: &mut PooledConn, table: &str) -> i64 {
-    let qry = "SELECT MAX(id) FROM ".to_string() + &table.to_string();
-    let selected: Result<Vec<Row>> = conn.query(qry);
+impl<T: SqlConn> Repository for T {
+    fn find_orders_by_status_and_time(&mut self, status: OrderStatus, at_time: NaiveDateTime, dp: &dyn DistanceProvider) -> Vec<Order> {
+        let mut ret : Vec<Order> = Vec::new();
+        let qry = "SELECT id, from_stand, to_stand, max_wait, max_loss, distance, shared, in_pool, \
+                   received, started, completed, at_time, eta, route_id, passengers, required, \
+                   (prev_id IS NOT NULL OR next_id IS NOT NULL), released FROM taxi_order WHERE status =".to_string() 
+                   + &(status as u8).to_string() + 
+                   &" and (at_time is NULL or at_time < '".to_string() + &at_time.to_string() + &"') ORDER by route_id".to_string();
 
-    match selected {
-        Ok(sel) => {
-            for r in sel {
-                let max: i64 = get_i64(&r, 0);
-                return if max != -1 { max + 1 } else { 1 };
-            }
-        },
-        Err(error) => warn!("Problem reading row: {:?}", error),
-    }
-    return 1; // no row
-}
-
-pub fn find_cab_by_status(conn: &mut PooledConn, status: CabStatus) -> Vec<Cab> {
-    let mut sql = format!("SELECT id, location, seats FROM cab WHERE status={} ", status as u8);
-    if status == CabStatus::FREE { // marked by Kim as FREE but a new route is created (while on last leg), 
-        //so this cab is not free
-        sql += &" AND id NOT IN (SELECT cab_id FROM route WHERE status IN (1,5) )"; 
-    }
-    return conn.query_map(sql, |(id, location, seats)| 
-                            { Cab { id, location, seats, dist: 0 } },).unwrap();
-}
-
-pub fn find_free_cab_and_on_last_leg(conn: &mut PooledConn) -> Vec<Cab> {
-    let mut free_cabs = find_cab_by_status(conn, CabStatus::FREE);
-
-    let mut last_leg_cabs = conn.query_map( // 5: STARTED
-        "SELECT cab_id, to_stand, seats, l1.started, distance FROM leg l1, route r, cab c \
-            WHERE not exists (select * from leg l2 where l1.route_id = l2.route_id and \
-                            l1.id!=l2.id AND l2.place>=l1.place) \
-            AND l1.status=5 AND r.id = l1.route_id AND NOT locked AND c.id=cab_id".to_string(),
-        |(id, location, seats, started, distance)| 
-            {   
-                let mut dist: i16 = distance; // default if we can't calculate elapsed
-                let stamp: Option<NaiveDateTime> = started;
-                let passed = get_elapsed(stamp);
-                if passed != -1 { 
-                    if passed as i16 > dist {
-                        dist = 0;
-                    } else {
-                        dist -= passed as i16;
+        match self.query(&qry) {
+            Ok(sel) => {
+                for r in sel {
+                    let from: i32 = get_i32(&r, 1);
+                    let to: i32 = get_i32(&r, 2);
+                    ret.push(Order {
+                        id: get_i64(&r, 0),
+                        from,
+                        to,
+                        wait: to_secs(get_i32(&r, 3)),
+                        loss: get_i32(&r, 4),
+                        dist: dp.dist(from as usize, to as usize) as i32, // 'distance' column has whole minutes only
+                        passengers: get_i32(&r, 14),
+                        required: get_i32(&r, 15),
+                        shared: get_bool(&r, 6),
+                        transfer: get_bool(&r, 16),
+                        //in_pool: get_bool(&r, 7),
+                        received: get_naivedate(&r, 8),
+                        //started: get_naivedate(&r, 9),
+                        //completed: get_naivedate(&r, 10),
+                        at_time: get_naivedate(&r, 11),
+                        released: get_naivedate(&r, 17),
+                        //eta: get_i32(&r, 12),
+                        route_id: if matches!(status, OrderStatus::RECEIVED) { -1 } else { get_i64(&r, 13) }
+                    });
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    // 'dwell' is in seconds, unlike other times in the database, as it takes well under a minute at most stops
+    // stops without it take 'stop_wait'
+    fn read_stops(&mut self, stop_wait: i16) -> Vec<Stop> {
+        let mut ret: Vec<Stop> = Vec::new();
+        match self.query("SELECT id, latitude, longitude, bearing, capacity, dwell FROM stop") {
+            Ok(sel) => {
+                for r in sel {
+                    ret.push(Stop {
+                        id: get_i64(&r, 0),
+                        latitude: get_f64(&r, 1),
+                        longitude: get_f64(&r, 2),
+                        bearing: get_i32(&r, 3),
+                        capacity: get_i32(&r, 4) as i16,
+                        dwell: match r[5].as_i64() { Some(x) => x as i16, None => stop_wait }
+                    });
+                }
+            },
+            Err(error) => panic!("Could not read stops: {}", error),
+        }
+        return ret;
+    }
+
+    fn read_free_taxi_orders(&mut self) -> Vec<CabAssign> {
+        let mut ret: Vec<CabAssign> = Vec::new();
+        match self.query("SELECT id, customer_id, cab_id, from_stand, to_stand, max_loss, shared, received, passengers FROM freetaxi_order") {
+            Ok(sel) => {
+                for r in sel {
+                    ret.push(CabAssign {
+                        id: get_i64(&r, 0),
+                        cust_id: get_i64(&r, 1),
+                        cab_id: get_i64(&r, 2),
+                        from: get_i32(&r, 3),
+                        to: get_i32(&r, 4),
+                        loss: get_i32(&r, 5),
+                        shared: get_bool(&r, 6),
+                        passengers: get_i32(&r, 8),
+                        received: get_naivedate(&r, 7)
+                    });
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn find_cab_by_status(&mut self, status: CabStatus) -> Vec<Cab> {
+        let mut sql = format!("SELECT id, location, seats, features FROM cab WHERE status={} ", status as u8);
+        if status == CabStatus::FREE { // marked by Kim as FREE but a new route is created (while on last leg), 
+            //so this cab is not free
+            sql += &" AND id NOT IN (SELECT cab_id FROM route WHERE status IN (1,5) )"; 
+        }
+        let mut ret: Vec<Cab> = Vec::new();
+        match self.query(&sql) {
+            Ok(sel) => {
+                for r in sel {
+                    ret.push(Cab { id: get_i64(&r, 0), location: get_i32(&r, 1), seats: get_i32(&r, 2), dist: 0,
+                                     features: get_i32(&r, 3) });
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn find_free_cab_and_on_last_leg(&mut self) -> Vec<Cab> {
+        let mut free_cabs = self.find_cab_by_status(CabStatus::FREE);
+        let mut last_leg_cabs: Vec<Cab> = Vec::new();
+        match self.query( // 5: STARTED
+            "SELECT cab_id, to_stand, seats, l1.started, distance, features FROM leg l1, route r, cab c \
+                WHERE not exists (select * from leg l2 where l1.route_id = l2.route_id and \
+                                l1.id!=l2.id AND l2.place>=l1.place) \
+                AND l1.status=5 AND r.id = l1.route_id AND NOT locked AND c.id=cab_id") {
+            Ok(sel) => {
+                for r in sel {
+                    let mut dist: i32 = to_secs(get_i32(&r, 4)); // default if we can't calculate elapsed
+                    let passed = get_elapsed(get_naivedate(&r, 3));
+                    if passed != -1 { 
+                        if passed as i32 > dist {
+                            dist = 0;
+                        } else {
+                            dist -= passed as i32;
+                        }
                     }
+                    last_leg_cabs.push(Cab { id: get_i64(&r, 0), location: get_i32(&r, 1), seats: get_i32(&r, 2), dist: dist as i16,
+                                               features: get_i32(&r, 5) });
                 }
-                Cab { id, location, seats, dist} 
             },
-    ).unwrap();
-    last_leg_cabs.append(&mut free_cabs);
-    return last_leg_cabs;
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        last_leg_cabs.append(&mut free_cabs);
+        return last_leg_cabs;
+    }
+
+    fn find_legs(&mut self, dp: &dyn DistanceProvider) -> Vec<Leg> {
+        let mut ret: Vec<Leg> = Vec::new();
+        let qry = "SELECT l.id, l.from_stand, l.to_stand, l.place, l.distance, l.started, l.completed, \
+                        l.route_id, l.status, l.reserve, l.passengers, c.seats, c.features FROM leg l, route r, cab c \
+                        WHERE r.id=l.route_id AND r.cab_id=c.id AND (l.status = 1 OR l.status = 5) AND NOT r.locked \
+                        AND NOT EXISTS (SELECT 1 FROM taxi_order o WHERE o.route_id=r.id AND NOT o.shared) \
+                        ORDER BY l.route_id ASC, l.place ASC";
+        
+        match self.query(qry) {
+            Ok(sel) => {
+                for r in sel {
+                    let from: i32 = get_i32(&r, 1);
+                    let to: i32 = get_i32(&r, 2);
+                    ret.push(Leg {
+                        id: get_i64(&r, 0),
+                        from,
+                        to,
+                        place: get_i32(&r, 3),
+                        dist: dp.dist(from as usize, to as usize) as i32, // 'distance' column has whole minutes only
+                        started: get_naivedate(&r, 5),
+                        //completed: get_naivedate(&r, 6),
+                        route_id: get_i64(&r, 7), 
+                        status: get_route_status(get_i32(&r, 8)),
+                        reserve: to_secs(get_i32(&r, 9)),
+                        passengers: get_i32(&r, 10),
+                        seats: get_i32(&r, 11),
+                        features: get_i32(&r, 12)
+                    });
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    // legs completed after 'since', to learn real travel times: from, to, started, completed
+    fn find_completed_legs(&mut self, since: NaiveDateTime) -> Vec<(i32, i32, NaiveDateTime, NaiveDateTime)> {
+        let mut ret: Vec<(i32, i32, NaiveDateTime, NaiveDateTime)> = Vec::new();
+        let qry = format!("SELECT from_stand, to_stand, started, completed FROM leg \
+                        WHERE completed > '{}' AND started IS NOT NULL ORDER BY completed ASC", since);
+
+        match self.query(&qry) {
+            Ok(sel) => {
+                for r in sel {
+                    if let (Some(started), Some(completed)) = (get_naivedate(&r, 2), get_naivedate(&r, 3)) {
+                        ret.push((get_i32(&r, 0), get_i32(&r, 1), started, completed));
+                    }
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn find_orders_to_repair(&mut self, left_before: NaiveDateTime, dp: &dyn DistanceProvider) -> Vec<(Order, bool)> {
+        let mut ret: Vec<(Order, bool)> = Vec::new();
+        let qry = format!("SELECT {}, o.status FROM taxi_order o, leg l WHERE o.leg_id=l.id AND (o.status=5 \
+                        OR (o.status IN (1,2) AND l.status IN (5,6) AND l.started < '{}')) ORDER BY o.route_id, o.id",
+                        ROUTE_ORDER_COLUMNS, left_before);
+        match self.query(&qry) {
+            Ok(sel) => {
+                for r in sel {
+                    ret.push((get_route_order(&r, dp), get_i32(&r, 13) != OrderStatus::ABANDONED as i32));
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn find_route_legs(&mut self, route_id: i64, dp: &dyn DistanceProvider) -> Vec<Leg> {
+        let mut ret: Vec<Leg> = Vec::new();
+        let qry = format!("SELECT l.id, l.from_stand, l.to_stand, l.place, l.started, l.status, l.reserve, l.passengers, \
+                        c.seats, c.features FROM leg l, route r, cab c \
+                        WHERE r.id=l.route_id AND r.cab_id=c.id AND l.status IN (1,5) AND l.route_id={} ORDER BY l.place", route_id);
+        match self.query(&qry) {
+            Ok(sel) => {
+                for r in sel {
+                    let from: i32 = get_i32(&r, 1);
+                    let to: i32 = get_i32(&r, 2);
+                    ret.push(Leg {
+                        id: get_i64(&r, 0),
+                        route_id,
+                        from,
+                        to,
+                        place: get_i32(&r, 3),
+                        dist: dp.dist(from as usize, to as usize) as i32,
+                        started: get_naivedate(&r, 4),
+                        status: get_route_status(get_i32(&r, 5)),
+                        reserve: to_secs(get_i32(&r, 6)),
+                        passengers: get_i32(&r, 7),
+                        seats: get_i32(&r, 8),
+                        features: get_i32(&r, 9)
+                    });
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn find_broken_routes(&mut self) -> Vec<(i64, i64)> {
+        let mut ret: Vec<(i64, i64)> = Vec::new();
+        let qry = format!("SELECT r.id, r.cab_id FROM route r, cab c WHERE r.cab_id=c.id AND c.status={} AND r.status IN (1,5) \
+                           ORDER BY r.id", CabStatus::OUT_OF_SERVICE as u8);
+        match self.query(&qry) {
+            Ok(sel) => {
+                for r in sel {
+                    ret.push((get_i64(&r, 0), get_i64(&r, 1)));
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn find_route_orders(&mut self, route_id: i64, status: &[OrderStatus], dp: &dyn DistanceProvider) -> Vec<Order> {
+        let mut ret: Vec<Order> = Vec::new();
+        let list: Vec<String> = status.iter().map(|s| (*s as u8).to_string()).collect();
+        let qry = format!("SELECT {} FROM taxi_order o WHERE o.route_id={} AND o.status IN ({}) ORDER BY o.id",
+                          ROUTE_ORDER_COLUMNS, route_id, list.join(","));
+        match self.query(&qry) {
+            Ok(sel) => {
+                for r in sel {
+                    ret.push(get_route_order(&r, dp));
+                }
+            },
+            Err(error) => warn!("Problem reading row: {}", error),
+        }
+        return ret;
+    }
+
+    fn save(&mut self, cmds: &Vec<Command>) -> Result<(), String> {
+        check_plan(cmds)?;
+        return save_without_conflicts(cmds, |plan| {
+            self.begin().map_err(SaveError::Failed)?;
+            if let Err(err) = save_in_transaction(self, plan) {
+                if let Err(e) = self.rollback() {
+                    return Err(SaveError::Failed(format!("{}, rollback failed: {}", err, e)));
+                }
+                return Err(err);
+            }
+            if let Err(e) = self.commit() {
+                let _ = self.rollback();
+                return Err(SaveError::Failed(format!("commit failed: {}", e)));
+            }
+            return Ok(());
+        });
+    }
+
+    fn schema_version(&mut self) -> Result<i32, String> {
+        return migrate::read_version(self);
+    }
+
+    fn migrate(&mut self) -> Result<Vec<i32>, String> {
+        return migrate::migrate(self);
+    }
+
+    fn read_events(&mut self, after: i64, limit: i64) -> Result<Vec<(i64, Event)>, String> {
+        let rows = self.query(&format!("SELECT seq, kind, order_id, route_id, leg_id, cab_id, from_stand, to_stand, place, eta \
+                                        FROM outbox WHERE seq > {} ORDER BY seq LIMIT {}", after, limit))?;
+        return Ok(rows.iter().filter_map(Event::from_row).collect());
+    }
 }
 
-/*
-pub fn get_list_of_free_cabs(conn: &mut PooledConn) -> Vec<i32>{
-    let mut ret: Vec<i32> = Vec::new();
-    let selected: Result<Vec<Row>>  = conn.query("SELECT id FROM cab WHERE status=1");
-    match selected {
-        Ok(sel) => { for r in sel { ret.push(r.get(0).unwrap()); }},
-        Err(error) => warn!("Problem reading row: {:?}", error),
+pub enum SaveError {
+    Conflict(Vec<usize>), // commands that did not find their rows as they were read, indices in the plan
+    Failed(String)
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            SaveError::Conflict(idx) => write!(f, "{} conflicting statements", idx.len()),
+            SaveError::Failed(e) => write!(f, "{}", e)
+        };
     }
-    return ret;
 }
-*/
 
-pub fn find_legs(conn: &mut PooledConn) -> Vec<Leg> {
-    let mut ret: Vec<Leg> = Vec::new();
-    let qry = "SELECT l.id, l.from_stand, l.to_stand, l.place, l.distance, l.started, l.completed, \
-                    l.route_id, l.status, l.reserve, l.passengers, c.seats FROM leg l, route r, cab c \
-                    WHERE r.id=l.route_id AND r.cab_id=c.id AND (l.status = 1 OR l.status = 5) AND NOT r.locked \
-                    ORDER BY l.route_id ASC, l.place ASC";
-    let selected: Result<Vec<Row>> = conn.query(qry);
-    
-    match selected {
-        Ok(sel) => {
-            for r in sel {
-                ret.push(Leg {
-                    id: r.get(0).unwrap(),
-                    from: r.get(1).unwrap(),
-                    to: r.get(2).unwrap(),
-                    place: r.get(3).unwrap(),
-                    dist: r.get(4).unwrap(),
-                    started: get_naivedate(&r, 5),
-                    //completed: get_naivedate(&r, 6),
-                    route_id: r.get(7).unwrap(), 
-                    status: get_route_status(r.get(8).unwrap()),
-                    reserve: r.get(9).unwrap(),
-                    passengers: r.get(10).unwrap(),
-                    seats: r.get(11).unwrap(),
-                });
+// rows read by 'prepare_data' may be changed before the plan is written - an order cancelled by Kapir,
+// a cab taken out of service by its driver; the plan is saved again without the routes that conflict,
+// each time with at least one command less, their orders wait for the next run
+pub fn save_without_conflicts<F>(cmds: &Vec<Command>, mut save: F) -> Result<(), String>
+        where F: FnMut(&Vec<Command>) -> Result<(), SaveError> {
+    let mut plan = cmds.clone();
+    loop {
+        match save(&plan) {
+            Ok(()) => return Ok(()),
+            Err(SaveError::Failed(e)) => return Err(e),
+            Err(SaveError::Conflict(idx)) => {
+                for i in idx.iter() {
+                    warn!("Changed in the meantime, route dropped from plan, statement: {}, ids: {}", plan[*i], plan[*i].ids());
+                }
+                plan = without_conflicts(&plan, &idx);
             }
-        },
-        Err(error) => warn!("Problem reading row: {:?}", error),
+        }
     }
-    return ret;
+}
+
+// temporary ids get real ones, then consecutive commands of the same kind make a batch
+fn save_in_transaction<T: SqlConn>(conn: &mut T, cmds: &Vec<Command>) -> Result<(), SaveError> {
+    let (route_count, leg_count, event_count) = count_new_ids(cmds);
+    let first_route = reserve_ids(conn, "route", route_count).map_err(SaveError::Failed)?;
+    let first_leg = reserve_ids(conn, "leg", leg_count).map_err(SaveError::Failed)?;
+    // the row lock serializes writers of events, a consumer never sees a smaller 'seq' committed later
+    let first_event = reserve_ids(conn, "event", event_count).map_err(SaveError::Failed)?;
+    let cmds = resolve_ids(cmds, first_route, first_leg, first_event).map_err(SaveError::Failed)?;
+    let mut conflicts: Vec<usize> = vec![];
+    let mut i: usize = 0;
+    while i < cmds.len() {
+        let sql = cmds[i].sql();
+        let mut j = i + 1;
+        while j < cmds.len() && cmds[j].sql() == sql { j += 1; }
+        let rows: Vec<Vec<Param>> = cmds[i..j].iter().map(|c| c.params()).collect();
+        match conn.execute(sql, &rows) {
+            Ok(changed) => {
+                for (k, n) in changed.iter().enumerate() {
+                    if *n == 0 && cmds[i + k].must_change_row() { conflicts.push(i + k); }
+                }
+            },
+            Err((idx, err)) => {
+                let failed = &cmds[i + idx];
+                return Err(SaveError::Failed(format!("statement: {}, ids: {}, err: {}", failed, failed.ids(), err)));
+            }
+        }
+        i = j;
+    }
+    if conflicts.len() > 0 {
+        return Err(SaveError::Conflict(conflicts));
+    }
+    return Ok(());
+}
+
+// the row stays locked till the end of the transaction, so that no one else gets the same ids;
+// a rolled back plan gives them back
+fn reserve_ids<T: SqlConn>(conn: &mut T, name: &'static str, count: i64) -> Result<i64, String> {
+    if count == 0 {
+        return Ok(0);
+    }
+    conn.execute("UPDATE id_block SET next_id=next_id+? WHERE name=?", &vec![vec![Param::BigInt(count), Param::Text(name.to_string())]])
+        .map_err(|e| format!("could not reserve {} ids: {}", name, e.1))?;
+    let rows = conn.query(&format!("SELECT next_id FROM id_block WHERE name='{}'", name))?;
+    return match rows.first().and_then(|r| r[0].as_i64()) {
+        Some(next) => Ok(next - count),
+        None => Err(format!("no '{}' row in id_block table, see sql/create.sql", name))
+    };
 }
 
 pub fn get_route_status(idx: i32) -> RouteStatus {
     return unsafe { ::std::mem::transmute(idx as i8) };
 }
 
-pub fn assign_order_find_cab(order_id: i64, leg_id: i64, route_id: i64, eta: i32, in_pool: &str, called_by: &str) -> String {   
+pub fn assign_order_find_cab(order_id: i64, leg_id: i64, route_id: i64, eta: i32, in_pool: bool, called_by: &str) -> Vec<Command> {   
     debug!("Assigning order_id={} to route_id={}, leg_id={}, module: {}",
                                             order_id, route_id, leg_id, called_by);
-    if leg_id == -1 {
-        return format!("\
-        UPDATE taxi_order SET route_id={}, cab_id=(SELECT cab_id FROM route where id={}), status=1, eta={}, in_pool={} \
-        WHERE id={} AND status=0;\n", // it might be cancelled in the meantime, we have to be sure. 
-        route_id, route_id, eta, in_pool, order_id);
-    }
-    return format!("\
-        UPDATE taxi_order SET route_id={}, leg_id={}, cab_id=(SELECT cab_id FROM route where id={}), status=1, eta={}, in_pool={} \
-        WHERE id={} AND status=0;\n", // it might be cancelled in the meantime, we have to be sure. 
-        route_id, leg_id, route_id, eta, in_pool, order_id);
+    return vec![Command::AssignOrderToRoute { order_id, leg_id: if leg_id == -1 { None } else { Some(leg_id) }, route_id,
+                                              eta: to_mins(eta), in_pool },
+                emit(Event::OrderAssigned { order_id, route_id, cab_id: -1, eta: to_mins(eta) })];
 }
 
-pub fn assign_order(order_id: i64, cab_id: i64, leg_id: i64, route_id: i64, eta: i16, in_pool: &str, called_by: &str) -> String {   
+pub fn assign_order(order_id: i64, cab_id: i64, leg_id: i64, route_id: i64, eta: i16, in_pool: bool, called_by: &str) -> Vec<Command> {   
     debug!("Assigning order_id={} to cab_id={}, route_id={}, leg_id={}, module: {}",
                                             order_id, cab_id, route_id, leg_id, called_by);
-    return format!("\
-        UPDATE taxi_order SET route_id={}, leg_id={}, cab_id={}, status=1, eta={}, in_pool={} \
-        WHERE id={} AND status=0;\n", // it might be cancelled in the meantime, we have to be sure. 
-        route_id, leg_id, cab_id, eta, in_pool, order_id);
+    return vec![Command::AssignOrder { order_id, cab_id, leg_id: Some(leg_id), route_id, eta: to_mins(eta as i32), in_pool },
+                emit(Event::OrderAssigned { order_id, route_id, cab_id, eta: to_mins(eta as i32) })];
 }
 
-pub fn assign_order_no_leg(order_id: i64, cab_id: i64, route_id: i64, eta: i16, in_pool: &str, called_by: &str) -> String {   
+pub fn assign_order_no_leg(order_id: i64, cab_id: i64, route_id: i64, eta: i16, in_pool: bool, called_by: &str) -> Vec<Command> {   
     debug!("Assigning order_id={} to cab_id={}, route_id={}, NO LEG, module: {}",
                                             order_id, cab_id, route_id, called_by);
-    return format!("\
-        UPDATE taxi_order SET route_id={}, cab_id={}, status=1, eta={}, in_pool={} \
-        WHERE id={} AND status=0;\n", // it might be cancelled in the meantime, we have to be sure. 
-        route_id, cab_id, eta, in_pool, order_id);
+    return vec![Command::AssignOrder { order_id, cab_id, leg_id: None, route_id, eta: to_mins(eta as i32), in_pool },
+                emit(Event::OrderAssigned { order_id, route_id, cab_id, eta: to_mins(eta as i32) })];
 }
 
-pub fn create_leg(order_id: i64, from: i32, to: i32, place: i32, status: RouteStatus, dist: i16, reserve: i32,
-                  route_id: i64, max_leg_id: &mut i64, passengers: i8, called_by: &str) -> String {
+pub fn create_leg(order_id: i64, from: i32, to: i32, place: i32, status: RouteStatus, dist: i32, reserve: i32,
+                  route_id: i64, max_leg_id: &mut i64, passengers: i32, called_by: &str) -> Vec<Command> {
     debug!("Adding leg to route: leg_id={}, route_id={}, order_id={}, from={}, to={}, place={}, distance={}, reserve={}, module: {}", 
                                 *max_leg_id, route_id, order_id, from, to, place, dist,
                                 cmp::max(reserve, 0), called_by);
-    let ret = format!("\
-        INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES \
-        ({},{},{},{},{},{},{},{},{});\n", *max_leg_id, from, to, place, dist, status as u8, cmp::max(reserve, 0), route_id, passengers);
+    let ret = vec![Command::CreateLeg { id: *max_leg_id, from, to, place, dist: to_mins(dist), status: status as i32,
+                                        reserve: to_mins_floor(cmp::max(reserve, 0)), route_id, passengers }];
     *max_leg_id += 1;
     return ret;
 }
 
-pub fn update_leg_a_bit2(route_id: i64, leg_id: i64, to: i32, dist: i16, reserve: i32, passengers: i8) -> String {
+// legs of existing routes, added by the extender
+pub fn legs_inserted(sql: &Vec<Command>) -> Vec<Command> {
+    return sql.iter().filter_map(|c| match *c {
+        Command::CreateLeg { id, from, to, place, route_id, .. } =>
+            Some(emit(Event::LegInserted { leg_id: id, route_id, from, to, place })),
+        _ => None
+    }).collect();
+}
+
+pub fn update_leg_a_bit2(route_id: i64, leg_id: i64, to: i32, dist: i32, reserve: i32, passengers: i32) -> Vec<Command> {
     debug!("Updating existing route_id={}, leg_id={}, to={}, distance={}, reserve={}, passengers={}", 
                 route_id, leg_id, to, dist, reserve, passengers);
-    return format!("\
-        UPDATE leg SET to_stand={}, distance={}, reserve={}, passengers={} WHERE id={};\n", 
-        to, dist, reserve, passengers, leg_id);
+    return vec![Command::UpdateLeg { id: leg_id, route_id, to, dist: to_mins(dist), reserve: to_mins_floor(reserve), passengers }];
 }
 
-pub fn update_place_in_legs_after(route_id: i64, place: i32) -> String {
+pub fn update_place_in_legs_after(route_id: i64, place: i32) -> Vec<Command> {
     debug!("Updating places in route_id={} starting with place={}", route_id, place);
-    return format!("UPDATE leg SET place=place+1 WHERE route_id={} AND place >= {};\n", route_id, place);
+    return vec![Command::ShiftPlaces { route_id, place_from: place }];
+}
+
+// route repair, see repair.rs
+pub fn update_place_in_legs_after_removal(route_id: i64, place: i32) -> Vec<Command> {
+    debug!("Updating places in route_id={} after removal, starting with place={}", route_id, place);
+    return vec![Command::ClosePlaces { route_id, place_from: place }];
+}
+
+pub fn remove_leg(leg: &Leg) -> Vec<Command> {
+    debug!("Removing leg_id={} from route_id={}, from={}, to={}, place={}", leg.id, leg.route_id, leg.from, leg.to, leg.place);
+    return vec![Command::DeleteLeg { id: leg.id, route_id: leg.route_id },
+                emit(Event::LegRemoved { leg_id: leg.id, route_id: leg.route_id, from: leg.from, to: leg.to, place: leg.place })];
+}
+
+pub fn detach_order(order_id: i64, route_id: i64, no_show: bool) -> Vec<Command> {
+    debug!("Detaching order_id={} from route_id={}, no-show={}", order_id, route_id, no_show);
+    let mut ret = vec![Command::DetachOrder { order_id, route_id }];
+    if no_show {
+        ret.push(emit(Event::OrderAbandoned { order_id, route_id }));
+    }
+    return ret;
+}
+
+// cab breakdown, see breakdown.rs
+pub fn release_order(order_id: i64, route_id: i64, released: Option<NaiveDateTime>) -> Vec<Command> {
+    debug!("Releasing order_id={} from route_id={}", order_id, route_id);
+    return vec![Command::ReleaseOrder { order_id, route_id, released }, emit(Event::OrderReleased { order_id, route_id })];
+}
+
+pub fn abandon_route(route_id: i64, cab_id: i64) -> Vec<Command> {
+    debug!("Abandoning route_id={} of cab_id={}", route_id, cab_id);
+    return vec![Command::AbandonLegs { route_id }, Command::AbandonRoute { route_id }, emit(Event::RouteAbandoned { route_id, cab_id })];
 }
 
-pub fn update_passengers_and_reserve_in_legs_between(route_id: i64, reserve: i32, place_from: i32, place_to: i32) -> String {
+pub fn update_passengers_and_reserve_in_legs_between(route_id: i64, passengers: i32, reserve: i32, place_from: i32, place_to: i32) -> Vec<Command> {
     if place_from > place_to {
-        return "".to_string();
+        return vec![];
     }
-    debug!("Updating passengers and reserve in route_id={}, reserve={} from place={} to place={}", 
-                    route_id, reserve, place_from, place_to);
-    return format!("\
-        UPDATE leg SET passengers=passengers+1, reserve=LEAST(reserve, {}) WHERE route_id={} AND place BETWEEN {} AND {};\n", 
-                    reserve, route_id, place_from, place_to);
+    debug!("Updating passengers and reserve in route_id={}, passengers={}, reserve={} from place={} to place={}", 
+                    route_id, passengers, reserve, place_from, place_to);
+    return vec![Command::AddPassengers { route_id, passengers, reserve: to_mins_floor(reserve), place_from, place_to }];
 }
 
-pub fn update_reserve_after(route_id: i64, cost: i32, place_from: i32) -> String {
+pub fn update_reserve_after(route_id: i64, cost: i32, place_from: i32) -> Vec<Command> {
     if cost < 0 {
-        return "".to_string();
+        return vec![];
     }
     debug!("Updating reserve in route_id={}, cost={} from place={}", route_id, cost, place_from);
-    return format!("\
-        UPDATE leg SET reserve=GREATEST(0, reserve-{}) WHERE route_id={} AND place >= {};\n", cost, route_id, place_from);
+    return vec![Command::ReduceReserveAfter { route_id, cost: to_mins(cost), place_from }];
 }
 
-pub fn update_reserves_in_legs_before_and_including(route_id: i64, place: i32, wait_diff: i32) -> String {
+pub fn update_reserves_in_legs_before_and_including(route_id: i64, place: i32, wait_diff: i32) -> Vec<Command> {
     if place < 0 {
-        return "".to_string();
+        return vec![];
     }
     debug!("Updating reserve in route_id={}, before place={}, wait_diff={}", 
             route_id, place, wait_diff);
-    return format!("UPDATE leg SET reserve=LEAST(reserve, {}) WHERE route_id={} AND place <= {};\n", 
-                wait_diff, route_id, place);
+    return vec![Command::CapReserveBefore { route_id, reserve: to_mins_floor(wait_diff), place_to: place }];
 }
 
-pub fn update_reserves_in_legs_before_and_including2(route_id: i64, place: i32, wait_diff: i32, cost: i32) -> String {
+pub fn update_reserves_in_legs_before_and_including2(route_id: i64, place: i32, wait_diff: i32, cost: i32) -> Vec<Command> {
     if place < 0 {
-        return "".to_string();
+        return vec![];
     }
     debug!("Updating reserve in route_id={}, BEFORE place={}, wait_diff={}", 
             route_id, place, wait_diff);
-            // first reserves for other orders, decreased by added cost
-    let mut sql = format!("\
-        UPDATE leg SET reserve=GREATEST(0, reserve-{}) WHERE route_id={} AND place <= {};\n", cost, route_id, place);
+    return vec![
+        // first reserves for other orders, decreased by added cost
+        Command::ReduceReserveBefore { route_id, cost: to_mins(cost), place_to: place },
         // for wait reserve for the current order 
-    sql += format!("\
-        UPDATE leg SET reserve=LEAST(reserve, {}) WHERE route_id={} AND place <= {};\n", wait_diff, route_id, place).as_str();
-    return sql;
+        Command::CapReserveBefore { route_id, reserve: to_mins_floor(wait_diff), place_to: place }
+    ];
 }
 
-pub fn assign_pool_to_cab(cab: Cab, orders: &Vec<Order>, pool: Branch, max_route_id: &mut i64, mut max_leg_id: &mut i64, stop_wait: i16) -> String {
+pub fn assign_pool_to_cab(cab: Cab, orders: &Vec<Order>, pool: Branch, max_route_id: &mut i64, mut max_leg_id: &mut i64, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Vec<Command> {
     let order = orders[pool.ord_ids[0] as usize];
     let mut place = 0;
     let mut eta = 0; // expected time of arrival
-    let cab_dist = unsafe { DIST[cab.location as usize][orders[pool.ord_ids[0] as usize].from as usize] };
-    let res = count_reserves(cab_dist, pool, orders, stop_wait);
+    let cab_dist = dp.dist(cab.location as usize, orders[pool.ord_ids[0] as usize].from as usize);
+    let res = count_reserves(cab_dist, pool, orders, stop_wait, dp);
 
-    let mut sql: String = update_cab_add_route(&cab, &order, &mut place, &mut eta, res.0, max_route_id, &mut max_leg_id);
+    let mut sql: Vec<Command> = update_cab_add_route(&cab, &order, &mut place, &mut eta, res.0, max_route_id, &mut max_leg_id, dp);
     // legs & routes are assigned to customers in Pool
-    sql += &assign_orders_and_save_legs(cab.id, *max_route_id, place, pool, eta, &mut max_leg_id, orders, res.1);
+    sql.extend(assign_orders_and_save_legs(cab.id, *max_route_id, place, pool, eta, &mut max_leg_id, orders, res.1, stop_wait, dp));
     *max_route_id += 1;
     return sql;
 }
@@ -287,24 +654,22 @@ pub fn assign_pool_to_cab(cab: Cab, orders: &Vec<Order>, pool: Branch, max_route
 // 
 // !! KEX does not have 'reserve' here, creat_leg get ZERO as a reserve
 fn update_cab_add_route(cab: &Cab, order: &Order, place: &mut i32, eta: &mut i16, reserve: i32,  
-                        max_route_id: &mut i64, max_leg_id: &mut i64) -> String {
-    // 0: CabStatus.ASSIGNED TODO: hardcoded status
-    let mut sql: String = String::from("UPDATE cab SET status=0 WHERE id=");
-    sql += &(cab.id.to_string() + &";\n".to_string());
-    // mark any active route as LOCKED
-    sql += &format!("UPDATE route SET locked = true WHERE status IN (1,5) AND cab_id=");
-    sql += &(cab.id.to_string() + &";\n".to_string());
-    // then new route
-    sql += &format!("INSERT INTO route (id, status, cab_id, locked) VALUES ({},{},{}, false);\n", // 0 will be updated soon
-                    *max_route_id, 1, cab.id).to_string(); // 1=ASSIGNED
+                        max_route_id: &mut i64, max_leg_id: &mut i64, dp: &dyn DistanceProvider) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![
+        // 0: CabStatus.ASSIGNED TODO: hardcoded status
+        Command::AssignCab { cab_id: cab.id },
+        // mark any active route as LOCKED
+        Command::LockRoutes { cab_id: cab.id },
+        // then new route
+        Command::CreateRoute { id: *max_route_id, cab_id: cab.id },
+        emit(Event::RouteCreated { route_id: *max_route_id, cab_id: cab.id })
+    ];
 
     if cab.location != order.from { // cab has to move to pickup the first customer
-        unsafe {
-            *eta = DIST[cab.location as usize][order.from as usize];
-        }
-        sql += &create_leg(order.id, cab.location, order.from, *place, 
-                    RouteStatus::ASSIGNED, *eta, reserve,
-                            *max_route_id, max_leg_id, 0, "assignCab");
+        *eta = dp.dist(cab.location as usize, order.from as usize);
+        sql.extend(create_leg(order.id, cab.location, order.from, *place, 
+                    RouteStatus::ASSIGNED, *eta as i32, reserve,
+                            *max_route_id, max_leg_id, 0, "assignCab"));
         *place += 1;
         //TODO: statSrvc.addToIntVal("total_pickup_distance", Math.abs(cab.getLocation() - order.fromStand));
     }
@@ -314,9 +679,9 @@ fn update_cab_add_route(cab: &Cab, order: &Order, place: &mut i32, eta: &mut i16
 // count reserves on legs
 // reserves have to obey max_wait and max_loss
 // returnes reserves for legs in Branch as well as in the leg for cab (if needed)
-fn count_reserves(cab_dist: i16, br: Branch, orders: &Vec<Order>, stop_wait: i16) -> (i32, [i32; MAXORDID]) {
+fn count_reserves(cab_dist: i16, br: Branch, orders: &Vec<Order>, stop_wait: &StopWait, dp: &dyn DistanceProvider) -> (i32, [i32; MAXORDID]) {
     // not all "c" values will produce legs below in "assign...", but we will use it as index for values -> res[c]
-    let mut res: [i32; MAXORDID] = [16000; MAXORDID]; // we will decreas value
+    let mut res: [i32; MAXORDID] = [16000 * MINUTE; MAXORDID]; // we will decreas value
     // first max_wait
     let mut dist:i32 = cab_dist as i32;
     let cab_reserve:i32;
@@ -341,9 +706,8 @@ fn count_reserves(cab_dist: i16, br: Branch, orders: &Vec<Order>, stop_wait: i16
                             { orders[br.ord_ids[c] as usize].from } else { orders[br.ord_ids[c] as usize].to };
         let stand2: i32 = if br.ord_actions[c + 1] == 'i' as i8
                           { orders[br.ord_ids[c + 1] as usize].from } else { orders[br.ord_ids[c + 1] as usize ].to };
-        if stand1 != stand2 {
-            unsafe { dist += (DIST[stand1 as usize][stand2 as usize] + stop_wait) as i32; }
-        }
+        dist += (dp.dist(stand1 as usize, stand2 as usize) 
+                 + stop_wait.between(stand1 as usize, stand2 as usize, if br.ord_actions[c] == 'i' as i8 { orders[br.ord_ids[c] as usize].passengers as i16 } else { 0 })) as i32;
     }    
     cab_reserve = res[0]; // "wait" reserve for all legs before last 'i' will be the same, [0] is as good as any of them
 
@@ -356,9 +720,8 @@ fn count_reserves(cab_dist: i16, br: Branch, orders: &Vec<Order>, stop_wait: i16
                             { orders[br.ord_ids[d-1] as usize].from } else { orders[br.ord_ids[d-1] as usize].to };
                 let stand2: i32 = if br.ord_actions[d] == 'i' as i8
                           { orders[br.ord_ids[d] as usize].from } else { orders[br.ord_ids[d] as usize ].to };
-                if stand1 != stand2 {
-                    unsafe { dist +=(DIST[stand1 as usize][stand2 as usize] + stop_wait) as i32; }
-                }
+                dist += (dp.dist(stand1 as usize, stand2 as usize)
+                         + stop_wait.between(stand1 as usize, stand2 as usize, if br.ord_actions[d-1] == 'i' as i8 { orders[br.ord_ids[d-1] as usize].passengers as i16 } else { 0 })) as i32;
                 if br.ord_actions[d] == 'o' as i8 && br.ord_ids[d] == br.ord_ids[c] {
                     // TODO: this should not be counted each time, store it!!
                     let acceptable_distance: i32 = ((1.0 + orders[br.ord_ids[c] as usize].loss as f32 / 100.0) 
@@ -389,52 +752,51 @@ fn log_pool(cab_id: i64, route_id: i64, e: Branch, orders: &Vec<Order>) {
 }
 
 fn assign_orders_and_save_legs(cab_id: i64, route_id: i64, mut place: i32, e: Branch, mut eta: i16,
-                                max_leg_id: &mut i64, orders: &Vec<Order>, reserve: [i32; MAXORDID]) -> String {
+                                max_leg_id: &mut i64, orders: &Vec<Order>, reserve: [i32; MAXORDID], stop_wait: &StopWait, dp: &dyn DistanceProvider) -> Vec<Command> {
     log_pool(cab_id, route_id, e, orders);
-    let mut sql: String = String::from("");
-    let mut passengers: i8 = 0;
+    let mut sql: Vec<Command> = vec![];
+    let mut passengers: i32 = 0;
 
     for c in 0 .. (e.ord_numb - 1) as usize {
       let order = orders[e.ord_ids[c] as usize];
       let stand1: i32 = if e.ord_actions[c] == 'i' as i8 { order.from } else { order.to };
       let stand2: i32 = if e.ord_actions[c + 1] == 'i' as i8
                         { orders[e.ord_ids[c + 1] as usize].from } else { orders[e.ord_ids[c + 1] as usize ].to } ;
-      unsafe {
-        if e.ord_actions[c] == 'i' as i8 {
-            passengers += 1;
-        } else {
-            passengers -= 1;
-        }
-        let dist: i16 = DIST[stand1 as usize][stand2 as usize];
-        if stand1 != stand2 { // there is movement
-            sql += &create_leg(order.id, stand1, stand2, place, RouteStatus::ASSIGNED, dist, reserve[c],
-                                route_id, max_leg_id, passengers, "assignOrdersAndSaveLegs");
-            place += 1;
-        }
-        if e.ord_actions[c] == 'i' as i8 {
-            // if there are many orders from one stand, and cab is already there (ergo no leg added -> place==0)
-            // then we don't have leg_id to assign to. This foreign key is not crucial for clients.
-            // TODO: update orders with no leg in that route (leg_id=NULL) with the first leg, after all legs are created
-            if place > 0 {
-                // TODO: leg_id-1 might indicate a leg incomming to "from" or starting from "from", depending on stand1!=stand above 
-                // leg_id-1 because create_leg increments ID
-                sql += &assign_order(order.id, cab_id, *max_leg_id -1, route_id, eta, "true", "assignOrdersAndSaveLegs1");
-            } else {
-                sql += &assign_order_no_leg(order.id, cab_id, route_id, eta, "true", "assignOrdersAndSaveLegs2");
-            }
-            add_avg_element(Stat::AvgOrderAssignTime, get_elapsed(order.received));
-        }
-        if stand1 != stand2 {
-            eta += dist + STOP_WAIT;
-        }
+      if e.ord_actions[c] == 'i' as i8 {
+          passengers += order.passengers;
+      } else {
+          passengers -= order.passengers;
+      }
+      let dist: i16 = dp.dist(stand1 as usize, stand2 as usize);
+      if stand1 != stand2 { // there is movement
+          sql.extend(create_leg(order.id, stand1, stand2, place, RouteStatus::ASSIGNED, dist as i32, reserve[c],
+                              route_id, max_leg_id, passengers, "assignOrdersAndSaveLegs"));
+          place += 1;
+      }
+      if e.ord_actions[c] == 'i' as i8 {
+          // if there are many orders from one stand, and cab is already there (ergo no leg added -> place==0)
+          // then we don't have leg_id to assign to. This foreign key is not crucial for clients.
+          // TODO: update orders with no leg in that route (leg_id=NULL) with the first leg, after all legs are created
+          if place > 0 {
+              // TODO: leg_id-1 might indicate a leg incomming to "from" or starting from "from", depending on stand1!=stand above 
+              // leg_id-1 because create_leg increments ID
+              sql.extend(assign_order(order.id, cab_id, *max_leg_id -1, route_id, eta, true, "assignOrdersAndSaveLegs1"));
+          } else {
+              sql.extend(assign_order_no_leg(order.id, cab_id, route_id, eta, true, "assignOrdersAndSaveLegs2"));
+          }
+          add_avg_element(Stat::AvgOrderAssignTime, get_elapsed(order.received));
+      }
+      if stand1 != stand2 {
+          eta += dist;
       }
+      eta += stop_wait.between(stand1 as usize, stand2 as usize, if e.ord_actions[c] == 'i' as i8 { order.passengers as i16 } else { 0 });
     }
     return sql;
 }
 
 pub fn assign_order_to_cab_lcm(sol: Vec<(i16,i16)>, cabs: &mut Vec<Cab>, demand: &mut Vec<Order>, max_route_id: &mut i64, 
-                              max_leg_id: &mut i64) -> String {
-    let mut sql: String = String::from("");
+                              max_leg_id: &mut i64, dp: &dyn DistanceProvider) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
     for (_, (cab_idx, ord_idx)) in sol.iter().enumerate() {
         let order = demand[*ord_idx as usize];
         let cab: Cab = cabs[*cab_idx as usize];
@@ -442,13 +804,13 @@ pub fn assign_order_to_cab_lcm(sol: Vec<(i16,i16)>, cabs: &mut Vec<Cab>, demand:
         let mut eta: i16 = 0; // cab's leg is not important for customers
         // this leg should not be extended now, but it might be in the future with "last leg in active route" project
         // so we need to have a valid reserve
-        let mut reserve: i32 = order.wait - unsafe { DIST[cab.location as usize][order.from as usize] } as i32; // expected time of arrival
+        let mut reserve: i32 = order.wait - dp.dist(cab.location as usize, order.from as usize) as i32; // expected time of arrival
         if reserve < 0 { reserve = 0; } 
-        sql += &update_cab_add_route(&cab, &order, &mut place, &mut eta,  reserve, max_route_id, max_leg_id);
-        let loss = unsafe { DIST[order.from as usize][order.to as usize] as f32
-            * (100.0 + order.loss as f32) / 100.0 } as i32 ;
+        sql.extend(update_cab_add_route(&cab, &order, &mut place, &mut eta,  reserve, max_route_id, max_leg_id, dp));
+        let loss = (dp.dist(order.from as usize, order.to as usize) as f32
+            * (100.0 + order.loss as f32) / 100.0) as i32 ;
         if reserve > loss { reserve = loss; } 
-        sql += &assign_order_to_cab(order, cab, place, eta, reserve, *max_route_id, max_leg_id, "assignCustToCabLCM");
+        sql.extend(assign_order_to_cab(order, cab, place, eta, reserve, *max_route_id, max_leg_id, "assignCustToCabLCM", dp));
         cabs[*cab_idx as usize].id = -1; // munkres should not assign this cab
         demand[*ord_idx as usize].id = -1;
         *max_route_id += 1;
@@ -457,21 +819,19 @@ pub fn assign_order_to_cab_lcm(sol: Vec<(i16,i16)>, cabs: &mut Vec<Cab>, demand:
 }
 
 fn assign_order_to_cab(order: Order, cab: Cab, place: i32, eta: i16, reserve: i32, route_id: i64, 
-                    max_leg_id: &mut i64, called_by: &str) -> String {
-    let mut sql: String = String::from("");
-    unsafe {
-        sql += &create_leg(order.id, order.from, order.to, place, RouteStatus::ASSIGNED, 
-                       DIST[order.from as usize][order.to as usize], reserve, route_id, max_leg_id, 1, called_by);
-    }
-    sql += &assign_order(order.id, cab.id, *max_leg_id -1 , route_id, // -1 cause it is incremented in create_leg
-                        eta, "false", "assignOrderToCab");
+                    max_leg_id: &mut i64, called_by: &str, dp: &dyn DistanceProvider) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
+    sql.extend(create_leg(order.id, order.from, order.to, place, RouteStatus::ASSIGNED, 
+                   dp.dist(order.from as usize, order.to as usize) as i32, reserve, route_id, max_leg_id, order.passengers, called_by));
+    sql.extend(assign_order(order.id, cab.id, *max_leg_id -1 , route_id, // -1 cause it is incremented in create_leg
+                        eta, false, "assignOrderToCab"));
     add_avg_element(Stat::AvgOrderAssignTime, get_elapsed(order.received));
     return sql;
 }
 
 pub fn assign_cust_to_cab_munkres(sol: Vec<i16>, cabs: &Vec<Cab>, demand: &Vec<Order>, max_route_id: &mut i64, 
-                            max_leg_id: &mut i64) -> String {
-    let mut sql: String = String::from("");
+                            max_leg_id: &mut i64, dp: &dyn DistanceProvider) -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
     
     for (cab_idx, ord_idx) in sol.iter().enumerate() {
         if *ord_idx == -1 {
@@ -481,46 +841,47 @@ pub fn assign_cust_to_cab_munkres(sol: Vec<i16>, cabs: &Vec<Cab>, demand: &Vec<O
         let cab: Cab = cabs[cab_idx];
         let mut place = 0;
         let mut eta = 0; // expected time of arrival, see comments in LCM above
-        let mut reserve: i32 = order.wait - unsafe { DIST[cab.location as usize][order.from as usize] } as i32; // expected time of arrival
+        let mut reserve: i32 = order.wait - dp.dist(cab.location as usize, order.from as usize) as i32; // expected time of arrival
         if reserve < 0 { 
             // TODO/TASK we should communicate with the customer, if this is acceptable, more than WAIT TIME
             reserve = 0; 
         } 
         
-        let loss = unsafe { DIST[order.from as usize][order.to as usize] as f32 * (order.loss as f32) / 100.0 } as i32 ;
+        let loss = (dp.dist(order.from as usize, order.to as usize) as f32 * (order.loss as f32) / 100.0) as i32 ;
         if reserve > loss { reserve = loss; } 
-        sql += &update_cab_add_route(&cab, &order, &mut place, &mut eta, reserve, max_route_id, max_leg_id);
-        sql += &assign_order_to_cab(order, cabs[cab_idx], place, eta, reserve, *max_route_id, max_leg_id, "assignCustToCabMunkres");
+        sql.extend(update_cab_add_route(&cab, &order, &mut place, &mut eta, reserve, max_route_id, max_leg_id, dp));
+        sql.extend(assign_order_to_cab(order, cabs[cab_idx], place, eta, reserve, *max_route_id, max_leg_id, "assignCustToCabMunkres", dp));
         *max_route_id += 1;
     }
     return sql;
 }
 
 pub fn create_reloc_route(cab: &Cab, dest_stop: i64,  
-                            max_route_id: &mut i64, max_leg_id: &mut i64) -> String {
+                            max_route_id: &mut i64, max_leg_id: &mut i64, dp: &dyn DistanceProvider) -> Vec<Command> {
     let mut place = 0;
     let mut eta = 0;
     let reserve = 0;
     
     // fake order
-    let order = Order { id: -1, from: dest_stop as i32, to: -1, wait: 0, loss: 0, dist: 0, 
-                                received: None, at_time: None, route_id: -1 };
-    let sql = update_cab_add_route(&cab, &order, &mut place, &mut eta, reserve, max_route_id, max_leg_id);
+    let order = Order { id: -1, from: dest_stop as i32, to: -1, wait: 0, loss: 0, dist: 0, passengers: 0,
+                                required: 0, shared: false, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+    let mut sql = update_cab_add_route(&cab, &order, &mut place, &mut eta, reserve, max_route_id, max_leg_id, dp);
+    sql.push(emit(Event::CabRelocated { cab_id: cab.id, route_id: *max_route_id, to: dest_stop as i32 }));
     *max_route_id += 1;
     return sql;
 }
 
-pub fn save_status() -> String {
-    let mut sql: String = String::from("");
+pub fn save_status() -> Vec<Command> {
+    let mut sql: Vec<Command> = vec![];
     update_val(Stat::AvgOrderAssignTime, count_average(Stat::AvgOrderAssignTime));
     unsafe {
     for s in Stat::iterator() {
-        sql += &format!("UPDATE stat SET int_val={} WHERE UPPER(name)=UPPER('{}');", STATS[*s as usize], s.to_string());
+        sql.push(Command::UpdateStat { name: s.to_string(), value: STATS[*s as usize] as i32 });
     }}
     return sql;
 }
 
-pub fn assign_requests_for_free_cabs(conn: &mut PooledConn, max_route_id: &mut i64, max_leg_id: &mut i64) {
+pub fn assign_requests_for_free_cabs(conn: &mut dyn Repository, max_route_id: &mut i64, max_leg_id: &mut i64, dp: &dyn DistanceProvider) {
     // list free cabs
     // retrieve requests from free cabs
     // in a loop over freetaxi_order
@@ -529,8 +890,8 @@ pub fn assign_requests_for_free_cabs(conn: &mut PooledConn, max_route_id: &mut i
     // c) generate taxi_order and assign to cab and route
     // d) generate one leg and assign to route
     // after the loop - delete all retreived requests (new might have come)
-    let orders = read_free_taxi_orders(conn);
-    let cabs = find_cab_by_status(conn, CabStatus::FREE);
+    let orders = conn.read_free_taxi_orders();
+    let cabs = conn.find_cab_by_status(CabStatus::FREE);
     if orders.len() == 0 {
         return;
     }
@@ -543,7 +904,7 @@ pub fn assign_requests_for_free_cabs(conn: &mut PooledConn, max_route_id: &mut i
     for c in &cabs {
         ids.push(c.id);
     }
-    let mut sql: String = "".to_string();
+    let mut sql: Vec<Command> = vec![];
     for o in &orders {
         if !ids.contains(&o.cab_id) { //this cab is not free any longer, assigned by pool e.g.
             continue;
@@ -552,16 +913,16 @@ pub fn assign_requests_for_free_cabs(conn: &mut PooledConn, max_route_id: &mut i
         if loc != o.from {
             warn!("Requested free cab had other location, DB: {}, reuqested: {}", loc, o.from);
         }
-        let reserve = ((o.loss as f32 / 100.0) * unsafe {DIST[o.from as usize][o.to as usize]} as f32).round() as i32;
-        sql += &format!("UPDATE cab SET status=0 WHERE id={};\n", o.cab_id);
-        sql += &insert_route(*max_route_id, o.cab_id);
-        sql += &insert_leg(*max_leg_id, *max_route_id, o, reserve);
-        sql += &insert_order(*max_leg_id, *max_route_id, o);
+        let reserve = ((o.loss as f32 / 100.0) * dp.dist(o.from as usize, o.to as usize) as f32).round() as i32;
+        sql.push(Command::AssignCab { cab_id: o.cab_id });
+        sql.extend(insert_route(*max_route_id, o.cab_id));
+        sql.extend(insert_leg(*max_leg_id, *max_route_id, o, reserve, dp));
+        sql.extend(insert_order(*max_route_id, *max_leg_id, o, dp));
         *max_route_id += 1;
         *max_leg_id += 1;
     }
-    sql += &delete_req_for_free_cabs(orders);
-    run_sql(conn, sql);
+    sql.extend(delete_req_for_free_cabs(orders));
+    run_sql(conn, sql, "free cabs");
 }
 
 fn cab_location(cabs: &Vec<Cab>, cab_id: i64) -> i32 {
@@ -571,35 +932,33 @@ fn cab_location(cabs: &Vec<Cab>, cab_id: i64) -> i32 {
     };
 }
 
-pub fn run_sql(conn: &mut PooledConn, sql: String) {
-    if sql.len() > 0 {
-        match conn.query_iter(sql) { // here SYNC execution
-            Ok(_) => {}
+// the plan is rolled back on error and tried again 'sql_retries' times, then skipped;
+// orders of a skipped plan stay RECEIVED and come back in the next dispatch
+pub fn run_sql(conn: &mut dyn Repository, sql: Vec<Command>, label: &str) -> bool {
+    if sql.len() == 0 {
+        return true;
+    }
+    let retries = KernCfg::access().sql_retries;
+    for attempt in 0 ..= retries {
+        match conn.save(&sql) { // here SYNC execution
+            Ok(_) => { return true; }
             Err(err) => {
-                warn!("Solver SQL output failed to run, err: {}", err);
+                warn!("{} SQL output failed to run, attempt {} of {}, {}", label, attempt + 1, retries + 1, err);
             }
         }
     }
+    error!("{} plan skipped, {} statements not saved", label, sql.len());
+    return false;
 }
 
-fn delete_req_for_free_cabs(list: Vec<CabAssign>) -> String {
-    if list.len() == 0 {
-        return "".to_string();
-    }
-    let mut ids: String = "".to_string();
-    for i in 0 .. list.len() -1 {
-        ids += &format!("{},", list[i].id);
-    }
-    ids += &list[list.len() - 1].id.to_string();
-    return format!("DELETE FROM freetaxi_order WHERE id IN ({});\n", ids);
+fn delete_req_for_free_cabs(list: Vec<CabAssign>) -> Vec<Command> {
+    return list.iter().map(|o| Command::DeleteFreeOrder { id: o.id }).collect();
 }
 
-fn insert_order(route_id: i64, leg_id: i64, o: &CabAssign) -> String {
-    return format!("INSERT INTO taxi_order (from_stand, to_stand, max_loss, max_wait, shared, in_pool, eta,\
-                     status, received, distance, customer_id, cab_id, leg_id, route_id) VALUES (\
-                    {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {}, {});\n",
-            o.from, o.to, o.loss, -1, o.shared, false, 0, OrderStatus::ASSIGNED as i32,
-            naive_to_string(o.received), unsafe {DIST[o.from as usize][o.to as usize]}, o.cust_id, o.cab_id, leg_id, route_id);
+fn insert_order(route_id: i64, leg_id: i64, o: &CabAssign, dp: &dyn DistanceProvider) -> Vec<Command> {
+    return vec![Command::InsertOrder { from: o.from, to: o.to, loss: o.loss, shared: o.shared, passengers: o.passengers, received: Some(naive_to_string(o.received)),
+                                       dist: to_mins(dp.dist(o.from as usize, o.to as usize) as i32),
+                                       cust_id: o.cust_id, cab_id: o.cab_id, leg_id, route_id }];
 }
 
 fn naive_to_string(time: Option<NaiveDateTime>) -> NaiveDateTime {
@@ -609,49 +968,59 @@ fn naive_to_string(time: Option<NaiveDateTime>) -> NaiveDateTime {
     };
 }
 
-fn insert_route(route_id: i64, cab_id: i64) -> String {
-    return format!("INSERT INTO route (id, status, cab_id, locked) VALUES ({},{},{},false);\n", // 0 will be updated soon
-                    route_id, RouteStatus::ASSIGNED as i32, cab_id).to_string();
+fn insert_route(route_id: i64, cab_id: i64) -> Vec<Command> {
+    return vec![Command::CreateRoute { id: route_id, cab_id }, emit(Event::RouteCreated { route_id, cab_id })];
 }
 
-// TODO: number of passengers requested
-fn insert_leg(_leg_id: i64, route_id: i64, o: &CabAssign, _reserve: i32) -> String {
-    /*let ret = format!("\
-    INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES \
-    ({},{},{},{},{},{},{},{},{});\n", leg_id, o.from, o.to, 0, unsafe {DIST[o.from as usize][o.to as usize]}, RouteStatus::ASSIGNED as i32, 
-        reserve, route_id, 1);
-    */
-    return format!("INSERT INTO route (id, status, cab_id, locked) VALUES ({},{},{},false);\n", // 0 will be updated soon
-                    route_id, RouteStatus::ASSIGNED as i32, o.cab_id).to_string();
+fn insert_leg(leg_id: i64, route_id: i64, o: &CabAssign, reserve: i32, dp: &dyn DistanceProvider) -> Vec<Command> {
+    return vec![Command::CreateLeg { id: leg_id, from: o.from, to: o.to, place: 0, dist: to_mins(dp.dist(o.from as usize, o.to as usize) as i32),
+                                     status: RouteStatus::ASSIGNED as i32, reserve: to_mins_floor(reserve), route_id, passengers: o.passengers }];
 }
 
+// orders of routes read by route repair
+const ROUTE_ORDER_COLUMNS: &str = "o.id, o.from_stand, o.to_stand, o.max_wait, o.max_loss, o.passengers, o.required, o.shared, \
+                                   o.received, o.at_time, o.route_id, (o.prev_id IS NOT NULL OR o.next_id IS NOT NULL), o.released";
 
-fn get_naivedate(row: &Row, index: usize) -> Option<NaiveDateTime> {
-    let val: Option<mysql::Value> = row.get(index);
-    return match val {
-        Some(x) => {
-            if x == Value::NULL {
-                None
-            } else {
-                row.get(index)
-            }
-        }
-        None => None
+fn get_route_order(r: &DbRow, dp: &dyn DistanceProvider) -> Order {
+    let from: i32 = get_i32(r, 1);
+    let to: i32 = get_i32(r, 2);
+    return Order {
+        id: get_i64(r, 0),
+        from,
+        to,
+        wait: to_secs(get_i32(r, 3)),
+        loss: get_i32(r, 4),
+        dist: dp.dist(from as usize, to as usize) as i32,
+        passengers: get_i32(r, 5),
+        required: get_i32(r, 6),
+        shared: get_bool(r, 7),
+        transfer: get_bool(r, 11),
+        received: get_naivedate(r, 8),
+        at_time: get_naivedate(r, 9),
+        released: get_naivedate(r, 12),
+        route_id: get_i64(r, 10)
     };
 }
 
-fn get_i64(row: &Row, index: usize) -> i64 {
-    let val: Option<mysql::Value> = row.get(index);
-    return match val {
-        Some(x) => {
-            if x == Value::NULL {
-                -1
-            } else {
-                row.get(index).unwrap()
-            }
-        }
-        None => -1
-    };
+fn get_naivedate(row: &DbRow, index: usize) -> Option<NaiveDateTime> {
+    return row[index].as_time();
+}
+
+// -1 if NULL
+fn get_i64(row: &DbRow, index: usize) -> i64 {
+    return row[index].as_i64().unwrap_or(-1);
+}
+
+fn get_i32(row: &DbRow, index: usize) -> i32 {
+    return get_i64(row, index) as i32;
+}
+
+fn get_f64(row: &DbRow, index: usize) -> f64 {
+    return row[index].as_f64().unwrap_or(0.0);
+}
+
+fn get_bool(row: &DbRow, index: usize) -> bool {
+    return row[index].as_bool().unwrap_or(false);
 }
 
 #[cfg(test)]
@@ -659,36 +1028,37 @@ mod tests {
   use super::*;
   use serial_test::serial;
   use crate::model::MAXORDERSNUMB;
+  use crate::distance::MemoryMatrix;
+  use crate::command::{to_sql, TEMP_ID_BASE};
 
-  fn init_test_data(order_count: u8) -> [Order; MAXORDERSNUMB] {
+  fn init_test_data(order_count: u8) -> ([Order; MAXORDERSNUMB], MemoryMatrix) {
     let stop_count = 8;
-    unsafe {
-        for i in 0..stop_count { DIST[i][i+1]= 2 ; }
-        for i in 0..order_count as usize { 
-            DIST[i][stop_count -1 -i] = 2*(stop_count -1 -i*2) as i16;
-        }
+    let mut dp = MemoryMatrix::new(stop_count + 1);
+    for i in 0..stop_count { dp.set(i, i+1, 2); }
+    for i in 0..order_count as usize { 
+        dp.set(i, stop_count -1 -i, 2*(stop_count -1 -i*2) as i16);
     }
     let o: Order = Order { id: 0, from: 0, to: stop_count as i32 - 1, wait: 10, loss: 90, dist: 7, 
         //shared: true, in_pool: true, 
-                            received: None, 
+                            passengers: 1, required: 0, shared: true, transfer: false, received: None, 
                             //started: None, completed: None, 
                             at_time: None, 
                             //eta: 10, 
+                            released: None,
                             route_id: -1 };
     let mut orders: [Order; MAXORDERSNUMB] = [o; MAXORDERSNUMB];
     for i in 0..order_count as usize {
         let to: i32 = stop_count as i32 -1 -i as i32;
-        unsafe{
-            orders[i] = Order { id: i as i64, from: i as i32, to: to, wait: 10, loss: 90, dist: DIST[i as usize][to as usize] as i32, 
+        orders[i] = Order { id: i as i64, from: i as i32, to: to, wait: 10, loss: 90, dist: dp.dist(i as usize, to as usize) as i32, 
                             //shared: true, in_pool: true, 
-                            received: None, 
+                            passengers: 1, required: 0, shared: true, transfer: false, received: None, 
                             //started: None, completed: None, 
                             at_time: None, 
                             //eta: 10, 
+                            released: None,
                             route_id: -1 };
-        }
     }
-    return orders;
+    return (orders, dp);
   }
 
   fn get_test_branch(order_count: u8) -> Branch {
@@ -713,12 +1083,113 @@ mod tests {
 
     let br = get_test_branch(order_count);
     
-    let orders = init_test_data(order_count);
-    let cab = Cab { id:0, location:0, seats: 10, dist: 0 };
+    let (orders, dp) = init_test_data(order_count);
+    let cab = Cab { id:0, location:0, seats: 10, dist: 0, features: 0 };
     let reserves: [i32; MAXORDID] = [0; MAXORDID];
-    let sql = assign_orders_and_save_legs(cab.id, 0, place, br, eta, &mut max_leg_id, &orders.to_vec(), reserves);
+    let sql = assign_orders_and_save_legs(cab.id, 0, place, br, eta, &mut max_leg_id, &orders.to_vec(), reserves, &StopWait::same(60), &dp);
     //println!("{}", sql);
-    assert_eq!(sql, "INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (0,0,1,0,2,1,0,0,1);\nUPDATE taxi_order SET route_id=0, leg_id=0, cab_id=0, status=1, eta=0, in_pool=true WHERE id=0 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (1,1,2,1,2,1,0,0,2);\nUPDATE taxi_order SET route_id=0, leg_id=1, cab_id=0, status=1, eta=3, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (2,2,3,2,2,1,0,0,3);\nUPDATE taxi_order SET route_id=0, leg_id=2, cab_id=0, status=1, eta=6, in_pool=true WHERE id=2 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (3,3,0,3,0,1,0,0,4);\nUPDATE taxi_order SET route_id=0, leg_id=3, cab_id=0, status=1, eta=9, in_pool=true WHERE id=3 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (4,0,7,4,14,1,0,0,5);\nUPDATE taxi_order SET route_id=0, leg_id=4, cab_id=0, status=1, eta=10, in_pool=true WHERE id=0 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (5,7,4,5,0,1,0,0,4);\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (6,4,5,6,2,1,0,0,3);\n");
+    assert_eq!(to_sql(&sql), "INSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (0,0,1,0,2,1,0,0,1);\nUPDATE taxi_order SET route_id=0, leg_id=0, cab_id=0, status=1, eta=0, in_pool=true WHERE id=0 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (1,1,2,1,2,1,0,0,2);\nUPDATE taxi_order SET route_id=0, leg_id=1, cab_id=0, status=1, eta=3, in_pool=true WHERE id=1 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (2,2,3,2,2,1,0,0,3);\nUPDATE taxi_order SET route_id=0, leg_id=2, cab_id=0, status=1, eta=6, in_pool=true WHERE id=2 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (3,3,0,3,0,1,0,0,4);\nUPDATE taxi_order SET route_id=0, leg_id=3, cab_id=0, status=1, eta=9, in_pool=true WHERE id=3 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (4,0,7,4,14,1,0,0,5);\nUPDATE taxi_order SET route_id=0, leg_id=4, cab_id=0, status=1, eta=10, in_pool=true WHERE id=0 AND status=0;\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (5,7,4,5,0,1,0,0,4);\nINSERT INTO leg (id, from_stand, to_stand, place, distance, status, reserve, route_id, passengers) VALUES (6,4,5,6,2,1,0,0,3);\n");
+  }
+
+  #[test]
+  fn test_db_value() {
+    assert_eq!(DbValue::Text(" 42".to_string()).as_i64(), Some(42));
+    assert_eq!(DbValue::Text("49.5".to_string()).as_f64(), Some(49.5));
+    assert_eq!(DbValue::Text("t".to_string()).as_bool(), Some(true)); // PostgreSQL
+    assert_eq!(DbValue::Text("0".to_string()).as_bool(), Some(false)); // MySQL
+    assert_eq!(DbValue::Text("2025-03-01 10:20:30.5".to_string()).as_time(),
+               chrono::NaiveDate::from_ymd_opt(2025, 3, 1).unwrap().and_hms_milli_opt(10, 20, 30, 500));
+    assert_eq!(DbValue::Null.as_i64(), None);
+    assert_eq!(get_i64(&vec![DbValue::Null], 0), -1);
+  }
+
+  #[test]
+  fn test_connect_unknown_scheme() {
+    assert!(connect("sqlserver://localhost/kabina").is_err());
+  }
+
+  // records transactions and batches, fails 'failures' times on a given order id; next free id is always 50;
+  // rows with 'gone' id were changed by someone else, nothing is updated
+  struct BatchConn { log: Vec<String>, rows: Vec<Vec<Param>>, fail_on: i64, failures: usize, gone: i64 }
+
+  impl SqlConn for BatchConn {
+    fn query(&mut self, _sql: &str) -> Result<Vec<DbRow>, String> { return Ok(vec![vec![DbValue::Int(50)]]); }
+    fn execute(&mut self, _sql: &'static str, rows: &Vec<Vec<Param>>) -> Result<Vec<u64>, (usize, String)> {
+      self.log.push(rows.len().to_string());
+      self.rows.extend(rows.clone());
+      match rows.iter().position(|r| r.contains(&Param::BigInt(self.fail_on))) {
+        Some(i) if self.failures > 0 => { self.failures -= 1; return Err((i, "deadlock".to_string())); }
+        _ => { return Ok(rows.iter().map(|r| if r.contains(&Param::BigInt(self.gone)) { 0 } else { 1 }).collect()); }
+      }
+    }
+    fn begin(&mut self) -> Result<(), String> { self.log.push("BEGIN".to_string()); return Ok(()); }
+    fn commit(&mut self) -> Result<(), String> { self.log.push("COMMIT".to_string()); return Ok(()); }
+    fn rollback(&mut self) -> Result<(), String> { self.log.push("ROLLBACK".to_string()); return Ok(()); }
+    fn execute_script(&mut self, sql: &str) -> Result<(), String> { self.log.push(sql.to_string()); return Ok(()); }
+    fn dialect(&self) -> Dialect { return Dialect::MySql; }
+  }
+
+  #[test]
+  fn test_save_in_batches() {
+    let cmds = vec![Command::ExpireOrder { order_id: 1 }, Command::ExpireOrder { order_id: 2 },
+                    Command::AssignCab { cab_id: 3 }, Command::ExpireOrder { order_id: 4 }];
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: -100, failures: 0, gone: -100 };
+    assert_eq!(conn.save(&cmds), Ok(()));
+    assert_eq!(conn.log, vec!["BEGIN", "2", "1", "1", "COMMIT"]);
+
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: 2, failures: 1, gone: -100 };
+    assert_eq!(conn.save(&cmds), Err("statement: UPDATE taxi_order SET status=6 WHERE (id=2 OR prev_id=2) AND status=0, ids: order_id=2, err: deadlock".to_string()));
+    assert_eq!(conn.log, vec!["BEGIN", "2", "ROLLBACK"]); // nothing after the failure
+  }
+
+  #[test]
+  fn test_save_refuses_order_without_route() {
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: -100, failures: 0, gone: -100 };
+    let cmds = vec![Command::AssignCab { cab_id: 3 },
+                    Command::AssignOrder { order_id: 1, cab_id: 3, leg_id: None, route_id: 7, eta: 0, in_pool: false }];
+    assert!(conn.save(&cmds).is_err());
+    assert_eq!(conn.log.len(), 0); // not even started
+  }
+
+  #[test]
+  fn test_save_resolves_temporary_ids() {
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: -100, failures: 0, gone: -100 };
+    let cmds = vec![Command::CreateRoute { id: TEMP_ID_BASE, cab_id: 3 },
+                    Command::AssignOrder { order_id: 1, cab_id: 3, leg_id: None, route_id: TEMP_ID_BASE, eta: 0, in_pool: false }];
+    assert_eq!(conn.save(&cmds), Ok(()));
+    assert_eq!(conn.rows[0], vec![Param::BigInt(1), Param::Text("route".to_string())]); // one id reserved
+    assert_eq!(conn.rows[1], vec![Param::BigInt(49), Param::BigInt(3)]);
+    assert_eq!(conn.rows[2][0], Param::BigInt(49));
+  }
+
+  #[test]
+  fn test_save_drops_conflicting_routes() {
+    // order 2 cancelled before the plan was written
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: -100, failures: 0, gone: 2 };
+    let cmds = vec![Command::CreateRoute { id: TEMP_ID_BASE, cab_id: 3 },
+                    Command::CreateRoute { id: TEMP_ID_BASE + 1, cab_id: 4 },
+                    Command::AssignOrder { order_id: 1, cab_id: 3, leg_id: None, route_id: TEMP_ID_BASE, eta: 0, in_pool: false },
+                    Command::AssignOrder { order_id: 2, cab_id: 4, leg_id: None, route_id: TEMP_ID_BASE + 1, eta: 0, in_pool: false }];
+    assert_eq!(conn.save(&cmds), Ok(()));
+    assert_eq!(conn.log, vec!["BEGIN", "1", "2", "2", "ROLLBACK", "BEGIN", "1", "1", "1", "COMMIT"]);
+    let saved = &conn.rows[conn.rows.len() - 2..];
+    assert_eq!(saved[0], vec![Param::BigInt(49), Param::BigInt(3)]); // route of cab 3 only
+    assert_eq!(saved[1].last(), Some(&Param::BigInt(1)));
+  }
+
+  #[test]
+  #[serial]
+  fn test_run_sql_retries_then_skips() {
+    let cmds = vec![Command::ExpireOrder { order_id: 1 }, Command::AssignCab { cab_id: 3 }];
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: 3, failures: 1, gone: -100 };
+    assert_eq!(run_sql(&mut conn, cmds.clone(), "test"), true);
+    assert_eq!(conn.log, vec!["BEGIN", "1", "1", "ROLLBACK", "BEGIN", "1", "1", "COMMIT"]);
+
+    let retries = KernCfg::access().sql_retries as usize;
+    let mut conn = BatchConn { log: vec![], rows: vec![], fail_on: 3, failures: 100, gone: -100 };
+    assert_eq!(run_sql(&mut conn, cmds, "test"), false);
+    assert_eq!(conn.log.iter().filter(|l| *l == "ROLLBACK").count(), retries + 1);
+    assert_eq!(conn.log.iter().filter(|l| *l == "COMMIT").count(), 0);
   }
 
 /*
@@ -726,7 +1197,7 @@ mod tests {
   fn test_check_route_reserve() {
     let order_count = 4;
     let br = get_test_branch(order_count);
-    let orders = init_test_data(order_count);
+    let (orders, dp) = init_test_data(order_count);
     // this is a linear route, so we have to have 90% reserve of the shortest distance on that route
     // 90% of 1 is 0 (truncated int)
     // so therefore we had to use '2' as minimal distance
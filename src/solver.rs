@@ -5,50 +5,56 @@ use std::io::prelude::*;
 use std::process::Command;
 use hungarian::minimize;
 use std::fs::File;
-use std::thread;
-use log::{debug, warn};
-use std::ptr::addr_of;
+use log::{debug, error, warn};
 use crate::pool::{orders_to_transfer_array, cabs_to_array};
 use crate::repo::{assign_order_to_cab_lcm, create_reloc_route};
-use crate::model::{Order, OrderTransfer, Stop, Cab, MAXSTOPSNUMB, MAXCABSNUMB, MAXORDERSNUMB};
-use crate::distance::DIST;
-use crate::extender::get_handle;
+use crate::model::{Order, OrderTransfer, Stop, Cab, MAXCABSNUMB, MAXORDERSNUMB, fits};
+use crate::distance::DistanceProvider;
+use crate::command;
 
-const MAXLCM : usize = 20000; // !! max number of cabs or orders sent to LCM in C
+// cost of a cab too small for the order or without features it requires, bigger than any real one but far from overflow when summed up
+const NOT_FEASIBLE: i32 = 1_000_000;
 
 #[link(name = "dynapool")]
 unsafe extern "C" {
     unsafe fn c_lcm(
-        distance: *const [[i16; 5200]; 5200],
+        distance: *const i16, // row by row
         distSize: i32,
-        orders: &[OrderTransfer; MAXORDERSNUMB],
+        orders: *const OrderTransfer,
         ordersSize: i32,
-        cabs: &[Cab; MAXCABSNUMB],
+        cabs: *const Cab,
         cabsSize: i32,
         how_many: i32,
-        supply: &mut [i16; MAXLCM], // returned values
-        demand: &mut [i16; MAXLCM], // returned values
+        supply: *mut i16, // returned values, 'how_many' at most
+        demand: *mut i16, // returned values
         count: &mut i32 // returned count of values
     );
 }
 
-fn extern_lcm(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16) -> Vec<(i16,i16)> {
-    let cabs_cpy = cabs.to_vec(); // clone
-    let orders_cpy = orders.to_vec();
-    let mut supply: [i16; MAXLCM] = [0; MAXLCM];
-    let mut demand: [i16; MAXLCM] = [0; MAXLCM];
+fn extern_lcm(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16, dp: &dyn DistanceProvider) -> Vec<(i16,i16)> {
+    if cabs.len() > MAXCABSNUMB || orders.len() > MAXORDERSNUMB {
+        // indices are returned as i16
+        error!("Too many cabs or orders for LCM, accordingly {} (max {}) and {} (max {})",
+                cabs.len(), MAXCABSNUMB, orders.len(), MAXORDERSNUMB);
+        return vec![];
+    }
+    let cabs_cpy = cabs_to_array(&cabs); // clone
+    let orders_cpy = orders_to_transfer_array(&orders);
+    let mut supply: Vec<i16> = vec![0; how_many as usize];
+    let mut demand: Vec<i16> = vec![0; how_many as usize];
     let mut count: i32 = 0;
+    let (dist, dist_size) = (dp.matrix().as_ptr(), dp.matrix().size());
 
     unsafe { c_lcm(
-        addr_of!(DIST),
-        MAXSTOPSNUMB as i32,
-        &orders_to_transfer_array(&orders_cpy),
+        dist,
+        dist_size as i32,
+        orders_cpy.as_ptr(),
         orders_cpy.len() as i32,
-        &cabs_to_array(&cabs_cpy),
+        cabs_cpy.as_ptr(),
         cabs_cpy.len() as i32,
         how_many as i32,
-        &mut supply, // returned values
-        &mut demand,
+        supply.as_mut_ptr(), // returned values
+        demand.as_mut_ptr(),
         &mut count
     );}
     
@@ -63,7 +69,7 @@ fn extern_lcm(cabs: &Vec<Cab>, orders: &Vec<Order>, how_many: i16) -> Vec<(i16,i
 // improve it!
 //
 // move free cabs (that exceed the capacity of their stops) to a nearest stop with enough capacity
-pub fn relocate_free_cabs(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_route_id: &mut i64, max_leg_id: &mut i64) -> String {
+pub fn relocate_free_cabs(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_route_id: &mut i64, max_leg_id: &mut i64, dp: &dyn DistanceProvider) -> Vec<command::Command> {
     // how to decide which cab should be moved? Maybe the one with highest battery charge?
     // find cabs that should move
     
@@ -82,10 +88,10 @@ pub fn relocate_free_cabs(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_route_id:
             continue;
         }
         // then find nearest stop with enough capacity
-        let mut dist = 1000; // any big value, we are looking for the nearest
+        let mut dist = i16::MAX; // any big value, we are looking for the nearest
         let mut dest: i32 = -1; // index of destination, index of Vec<Stop>
         for (idx, capa) in stop_capa.iter().enumerate() {
-            let dist_to_stop = unsafe { DIST[c.location as usize][stops[idx].id as usize] };
+            let dist_to_stop = dp.dist(c.location as usize, stops[idx].id as usize);
             if *capa > 0 && dist_to_stop < dist {
                 dist = dist_to_stop;
                 dest = idx as i32;
@@ -98,16 +104,16 @@ pub fn relocate_free_cabs(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_route_id:
         stop_capa[dest as usize] -= 1;
         ret.push(dest); // we could create the route here but we want to be compatible with any GLPK interface
     }
-    let mut sql: String = String::from("");
+    let mut sql: Vec<command::Command> = vec![];
     let mut total_dist: i32 = 0;
     for (idx, r) in ret.iter().enumerate() {
         if *r == -1 { // enough capacity for this cab
             continue;
         }
         debug!("Relocating cab_id={} to stop={}, distance={}", free_cabs[idx].id, stops[*r as usize].id,
-                    unsafe { DIST[free_cabs[idx].location as usize][stops[*r as usize].id as usize]});
-        total_dist += unsafe { DIST[free_cabs[idx].location as usize][stops[*r as usize].id as usize] as i32 };
-        sql += & create_reloc_route(&free_cabs[idx], stops[*r as usize].id, max_route_id, max_leg_id);
+                    dp.dist(free_cabs[idx].location as usize, stops[*r as usize].id as usize));
+        total_dist += dp.dist(free_cabs[idx].location as usize, stops[*r as usize].id as usize) as i32;
+        sql.extend(create_reloc_route(&free_cabs[idx], stops[*r as usize].id, max_route_id, max_leg_id, dp));
     }
     if sql.len() > 0 {
         debug!("Total cost of relocation (LCM): {}", total_dist);
@@ -140,8 +146,8 @@ fn capacity(stop_id: i32, stops: &Vec<Stop>, stop_capa: &Vec<i16>) -> i16 {
     panic!("Cab's location not found in the list of stops");
 }
 
-pub fn relocate_free_cabs_glpk(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_route_id: &mut i64, max_leg_id: &mut i64) -> String {
-    let mut sql: String = String::from("");
+pub fn relocate_free_cabs_glpk(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_route_id: &mut i64, max_leg_id: &mut i64, dp: &dyn DistanceProvider) -> Vec<command::Command> {
+    let mut sql: Vec<command::Command> = vec![];
     if free_cabs.len() == 0 { // nothing to do
         return sql;
     }
@@ -170,12 +176,12 @@ pub fn relocate_free_cabs_glpk(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_rout
         warn!("No stop with enough capacity for cabs in need");
         return sql;
     }
-    let result = run_glpk(free_cabs, &cab_idx, stops, &stop_idx, &stop_capa);
+    let result = run_glpk(free_cabs, &cab_idx, stops, &stop_idx, &stop_capa, dp);
     let mut total_dist: i32 = 0;
     for (stop_i, cab_i) in result {
         debug!("Relocating cab_id={} to stop={}", free_cabs[cab_i].id, stops[stop_i].id);
-        total_dist += unsafe { DIST[free_cabs[cab_i].location as usize][stops[stop_i].id as usize] as i32 };
-        sql += & create_reloc_route(&free_cabs[cab_i], stops[stop_i].id, max_route_id, max_leg_id);
+        total_dist += dp.dist(free_cabs[cab_i].location as usize, stops[stop_i].id as usize) as i32;
+        sql.extend(create_reloc_route(&free_cabs[cab_i], stops[stop_i].id, max_route_id, max_leg_id, dp));
     }
     println!("Total cost of relocation (GLPK): {}", total_dist);
     return sql;
@@ -183,7 +189,7 @@ pub fn relocate_free_cabs_glpk(free_cabs: &Vec<Cab>, stops: &Vec<Stop>, max_rout
 
 // returns vectors of indexes in free_cabs and stops
 fn run_glpk(free_cabs: &Vec<Cab>, cab_idx: &Vec<usize>, stops: &Vec<Stop>, stop_idx: &Vec<usize>, 
-            capacity: &Vec<i16>) -> Vec<(usize, usize)> {
+            capacity: &Vec<i16>, dp: &dyn DistanceProvider) -> Vec<(usize, usize)> {
     let mut file = File::create("glpk.mod").unwrap();
     let s = "param ii, integer, > 0;\n\
         set I := 1..ii;\n\
@@ -213,8 +219,8 @@ fn run_glpk(free_cabs: &Vec<Cab>, cab_idx: &Vec<usize>, stops: &Vec<Stop>, stop_
     for (cab_i, c) in cab_idx.iter().enumerate() {
         str += &format!("  {}", cab_i + 1);
         for (stop_i, s) in stop_idx.iter().enumerate() {
-            str += &format!(" {}", unsafe { 
-                DIST[free_cabs[cab_idx[cab_i]].location as usize][stops[stop_idx[stop_i]].id as usize]});
+            str += &format!(" {}", 
+                dp.dist(free_cabs[cab_idx[cab_i]].location as usize, stops[stop_idx[stop_i]].id as usize));
         }
         str += "\n";
     }
@@ -287,39 +293,42 @@ j,i
 */
 
 // least/low cost method - shrinking the model so that it can be sent to solver
-pub fn lcm(host: &String, mut cabs: &mut Vec<Cab>, mut orders: &mut Vec<Order>, max_route_id: &mut i64, max_leg_id: &mut i64, how_many: i16) 
-                                -> thread::JoinHandle<()> {
+pub fn lcm(mut cabs: &mut Vec<Cab>, mut orders: &mut Vec<Order>, max_route_id: &mut i64, max_leg_id: &mut i64, how_many: i16, dp: &dyn DistanceProvider) 
+                                -> Vec<command::Command> {
     if how_many < 1 { // we would like to find at least one
         warn!("LCM asked to do nothing");
-        return thread::spawn(|| { });
+        return vec![];
     }
     //let pairs: Vec<(i16,i16)> = lcm_gen_pairs2(cabs, orders, how_many);
-    let pairs: Vec<(i16,i16)> = extern_lcm(cabs, orders, how_many);
-    let sql = assign_order_to_cab_lcm(pairs, &mut cabs, &mut orders, max_route_id, max_leg_id);
-    return get_handle(host.clone(), sql, "LCM".to_string());
+    let pairs: Vec<(i16,i16)> = extern_lcm(cabs, orders, how_many, dp);
+    return assign_order_to_cab_lcm(pairs, &mut cabs, &mut orders, max_route_id, max_leg_id, dp);
 }
 
 
 
+// a group fits in, a wheelchair needs a ramp etc.
+fn compatible(cab: &Cab, order: &Order) -> bool {
+    return order.passengers <= cab.seats && fits(cab.features, order.required);
+}
+
 // returns indexes of orders assigned to cabs - vec[1]==5 would mean 2nd cab assigned 6th order
-pub fn munkres(cabs: &Vec<Cab>, orders: &Vec<Order>) -> Vec<i16> {
+pub fn munkres(cabs: &Vec<Cab>, orders: &Vec<Order>, dp: &dyn DistanceProvider) -> Vec<i16> {
     let mut ret: Vec<i16> = vec![];
     let mut matrix: Vec<i32> = vec![];
     
     for c in cabs.iter() {
         for o in orders.iter() {
-            unsafe {
-                matrix.push(DIST[c.location as usize][o.from as usize] as i32 + o.dist);
-            }
+            matrix.push(if !compatible(c, o) { NOT_FEASIBLE }
+                        else { dp.dist(c.location as usize, o.from as usize) as i32 + o.dist });
         }
     }
     let assignment = minimize(&matrix, cabs.len() as usize, orders.len() as usize);
     
-    for s in assignment {
-        if s.is_some() {
-            ret.push(s.unwrap() as i16);
-        } else {
-            ret.push(-1);
+    for (c, s) in assignment.iter().enumerate() {
+        match s {
+            // every cab gets an order if there are enough of them, even one that does not fit
+            Some(o) if compatible(&cabs[c], &orders[*o]) => ret.push(*o as i16),
+            _ => ret.push(-1)
         }
     }
     return ret;
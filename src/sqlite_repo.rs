@@ -0,0 +1,147 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// SQLite backend of the repository, schema in sql/create-sqlite.sql
+/// A single file for simulations on one machine, no server needed; simulators may open the same file
+/// Timestamps are kept as text, like CURRENT_TIMESTAMP writes them
+use rusqlite::{Connection, params_from_iter};
+use rusqlite::types::{Value, ValueRef};
+use crate::command::Param;
+use crate::migrate::Dialect;
+use crate::repo::{DbRow, DbValue, SqlConn};
+
+pub struct SqliteRepo {
+    conn: Connection
+}
+
+impl SqliteRepo {
+    // sqlite://kabina.db or sqlite:///full/path/kabina.db
+    pub fn new(url: &str) -> Result<Self, String> {
+        let path = url.trim_start_matches("sqlite://");
+        let conn = Connection::open(path).map_err(|e| format!("Could not open SQLite file {}: {}", path, e))?;
+        // other processes (simulators) write to the same file, wait for their locks instead of failing at once
+        conn.busy_timeout(std::time::Duration::from_secs(5)).map_err(|e| e.to_string())?;
+        // readers do not block the writer and vice versa
+        conn.query_row("PRAGMA journal_mode=WAL", [], |_| Ok(())).map_err(|e| e.to_string())?;
+        return Ok(Self { conn });
+    }
+}
+
+impl SqlConn for SqliteRepo {
+    fn query(&mut self, sql: &str) -> Result<Vec<DbRow>, String> {
+        let mut stmt = self.conn.prepare(sql).map_err(|e| e.to_string())?;
+        let cols = stmt.column_count();
+        let mut rows = stmt.query([]).map_err(|e| e.to_string())?;
+        let mut ret: Vec<DbRow> = Vec::new();
+        while let Some(r) = rows.next().map_err(|e| e.to_string())? {
+            let mut row: DbRow = Vec::with_capacity(cols);
+            for i in 0..cols {
+                row.push(to_db_value(r.get_ref(i).map_err(|e| e.to_string())?));
+            }
+            ret.push(row);
+        }
+        return Ok(ret);
+    }
+
+    fn execute(&mut self, sql: &'static str, rows: &Vec<Vec<Param>>) -> Result<Vec<u64>, (usize, String)> {
+        let mut stmt = self.conn.prepare_cached(&to_sqlite(sql)).map_err(|e| (0, e.to_string()))?;
+        let mut changed: Vec<u64> = Vec::with_capacity(rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            let n = stmt.execute(params_from_iter(row.iter().map(to_value))).map_err(|e| (i, e.to_string()))?;
+            changed.push(n as u64);
+        }
+        return Ok(changed);
+    }
+
+    // IMMEDIATE takes the write lock at once, a simulator cannot sneak in between our reads and writes
+    fn begin(&mut self) -> Result<(), String> {
+        return self.conn.execute_batch("BEGIN IMMEDIATE").map_err(|e| e.to_string());
+    }
+
+    fn commit(&mut self) -> Result<(), String> {
+        return self.conn.execute_batch("COMMIT").map_err(|e| e.to_string());
+    }
+
+    fn rollback(&mut self) -> Result<(), String> {
+        return self.conn.execute_batch("ROLLBACK").map_err(|e| e.to_string());
+    }
+
+    fn execute_script(&mut self, sql: &str) -> Result<(), String> {
+        return self.conn.execute_batch(sql).map_err(|e| e.to_string());
+    }
+
+    fn dialect(&self) -> Dialect {
+        return Dialect::Sqlite;
+    }
+}
+
+// SQLite has scalar MIN and MAX with two arguments instead of LEAST and GREATEST
+fn to_sqlite(sql: &str) -> String {
+    return sql.replace("LEAST(", "MIN(").replace("GREATEST(", "MAX(");
+}
+
+fn to_value(param: &Param) -> Value {
+    return match param {
+        Param::Int(x) => Value::Integer(*x as i64),
+        Param::BigInt(x) => Value::Integer(*x),
+        Param::Bool(x) => Value::Integer(*x as i64),
+        Param::Text(x) => Value::Text(x.clone()),
+        Param::Time(Some(t)) => Value::Text(t.format("%Y-%m-%d %H:%M:%S%.f").to_string()),
+        Param::Time(None) => Value::Null
+    };
+}
+
+fn to_db_value(val: ValueRef) -> DbValue {
+    return match val {
+        ValueRef::Null => DbValue::Null,
+        ValueRef::Integer(x) => DbValue::Int(x),
+        ValueRef::Real(x) => DbValue::Float(x),
+        ValueRef::Text(x) | ValueRef::Blob(x) => DbValue::Text(String::from_utf8_lossy(x).to_string())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+  use crate::command::Command;
+  use crate::repo::Repository;
+
+  #[test]
+  fn test_to_sqlite() {
+    assert_eq!(to_sqlite("UPDATE leg SET reserve=GREATEST(0, reserve-?) WHERE route_id=?"),
+               "UPDATE leg SET reserve=MAX(0, reserve-?) WHERE route_id=?");
+    assert_eq!(to_sqlite("UPDATE leg SET reserve=LEAST(reserve, ?) WHERE route_id=?"),
+               "UPDATE leg SET reserve=MIN(reserve, ?) WHERE route_id=?");
+  }
+
+  #[test]
+  fn test_save_and_read() {
+    let mut repo = SqliteRepo::new("sqlite://:memory:").unwrap();
+    repo.conn.execute_batch(
+      "CREATE TABLE id_block (name VARCHAR(32) NOT NULL, next_id BIGINT NOT NULL, PRIMARY KEY (name));
+       INSERT INTO id_block VALUES ('route', 10), ('leg', 20);
+       CREATE TABLE route (id bigint NOT NULL, status integer NOT NULL, cab_id bigint NOT NULL, locked boolean, PRIMARY KEY (id));
+       CREATE TABLE leg (id bigint NOT NULL, completed timestamp, distance integer NOT NULL, from_stand integer NOT NULL,
+          place integer NOT NULL, started timestamp, status integer NOT NULL, reserve integer NOT NULL,
+          passengers integer NOT NULL, to_stand integer NOT NULL, route_id bigint NOT NULL, PRIMARY KEY (id));").unwrap();
+    let route = crate::command::TEMP_ID_BASE;
+    let cmds = vec![
+      Command::CreateRoute { id: route, cab_id: 3 },
+      Command::CreateLeg { id: route, from: 1, to: 2, place: 0, dist: 4, status: 1, reserve: 5, route_id: route, passengers: 1 },
+      Command::ReduceReserveAfter { route_id: route, cost: 7, place_from: 0 }
+    ];
+    assert!(repo.save(&cmds).is_ok());
+    let rows = repo.query("SELECT r.id, l.id, l.reserve, r.locked FROM route r, leg l WHERE r.id=l.route_id").unwrap();
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0][0].as_i64(), Some(10));
+    assert_eq!(rows[0][1].as_i64(), Some(20));
+    assert_eq!(rows[0][2].as_i64(), Some(0));
+    assert_eq!(rows[0][3].as_bool(), Some(false));
+
+    let t = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap().and_hms_opt(10, 20, 30).unwrap();
+    repo.execute("UPDATE leg SET started=? WHERE id=?", &vec![vec![Param::Time(Some(t)), Param::BigInt(20)]]).unwrap();
+    let rows = repo.query("SELECT started FROM leg").unwrap();
+    assert_eq!(rows[0][0].as_time(), Some(t));
+  }
+}
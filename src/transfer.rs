@@ -0,0 +1,121 @@
+/// Kabina minibus/taxi dispatcher
+/// Copyright (c) 2025 by Bogusz Jelinski bogusz.jelinski@gmail.com
+///
+/// Ride transfers - "an order serviced by two cabs (cab change)"
+/// A long trip rarely pools well end to end, it is split at a transfer stop into two orders (segments), each dispatched
+/// on its own. The first segment keeps max_wait and max_loss of the order, reserves of its legs do not let the extender
+/// delay it beyond them; the second one gets 'at_time' - the latest the first one can be at the transfer stop
+use std::cmp;
+use chrono::{Duration, Local};
+use log::debug;
+use crate::command::Command;
+use crate::distance::DistanceProvider;
+use crate::model::{Order, Stop};
+use crate::utils::{to_mins, MINUTE};
+
+// the stop where both segments take about the same time, with a detour the customer accepts (max_loss);
+// two cabs have to fit there
+pub fn find_transfer_stop(o: &Order, stops: &Vec<Stop>, dp: &dyn DistanceProvider) -> Option<i32> {
+    let acceptable_dist = o.dist * (100 + o.loss) / 100;
+    let mut ret: Option<i32> = None;
+    let mut longer_segment = i32::MAX;
+    for s in stops.iter() {
+        let stop = s.id as i32;
+        if stop == o.from || stop == o.to || s.capacity < 2 {
+            continue;
+        }
+        let dist1 = dp.dist(o.from as usize, s.id as usize) as i32;
+        let dist2 = dp.dist(s.id as usize, o.to as usize) as i32;
+        if dist1 + dist2 > acceptable_dist {
+            continue;
+        }
+        if cmp::max(dist1, dist2) < longer_segment {
+            longer_segment = cmp::max(dist1, dist2);
+            ret = Some(stop);
+        }
+    }
+    return ret;
+}
+
+// orders longer than 'transfer_dist' (secs) end at a transfer stop now, the rest of the trip is a new order;
+// solo trips are not split, a cab change is for those who share
+// returns: orders as they will be dispatched and SQL inserting second segments
+pub fn split_orders(orders: &Vec<Order>, stops: &Vec<Stop>, transfer_dist: i32, max_assign_time: i64, dp: &dyn DistanceProvider)
+                    -> (Vec<Order>, Vec<Command>) {
+    let mut ret: Vec<Order> = orders.to_vec();
+    let mut sql: Vec<Command> = vec![];
+    for o in ret.iter_mut() {
+        if o.id == -1 || !o.shared || o.transfer || o.dist <= transfer_dist {
+            continue;
+        }
+        let stop = match find_transfer_stop(o, stops, dp) {
+            Some(s) => s,
+            None => { continue; }
+        };
+        let dist1 = dp.dist(o.from as usize, stop as usize) as i32;
+        let dist2 = dp.dist(stop as usize, o.to as usize) as i32;
+        // the first segment is assigned before it expires, picked up within max_wait and does not lose more than max_loss
+        let arrival = o.at_time.or(o.received).unwrap_or(Local::now().naive_local())
+                + Duration::seconds(max_assign_time * MINUTE as i64 + o.wait as i64 + (dist1 * (100 + o.loss) / 100) as i64);
+        debug!("Order {} split at stop {}, first segment: {}s, second: {}s, not before {}", o.id, stop, dist1, dist2, arrival);
+        // the second segment first, the first one points at it
+        sql.push(Command::InsertSegment { order_id: o.id, from: stop, at_time: Some(arrival), dist: to_mins(dist2) });
+        sql.push(Command::SplitOrder { order_id: o.id, to: stop, dist: to_mins(dist1) });
+        o.to = stop;
+        o.dist = dist1;
+        o.transfer = true;
+    }
+    return (ret, sql);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::distance::MemoryMatrix;
+
+  fn test_stops() -> Vec<Stop> {
+    return (0..5).map(|i| Stop { id: i, bearing: 0, latitude: 49.0, longitude: 19.0, capacity: 2, dwell: 60 }).collect();
+  }
+
+  fn test_order(from: i32, to: i32, shared: bool, dp: &dyn DistanceProvider) -> Order {
+    return Order { id: 7, from, to, wait: 600, loss: 50, dist: dp.dist(from as usize, to as usize) as i32, passengers: 1,
+                   required: 0, shared, transfer: false, received: None, at_time: None, released: None, route_id: -1 };
+  }
+
+  #[test]
+  fn test_find_transfer_stop() {
+    let dp = MemoryMatrix::line(5, 60);
+    let mut stops = test_stops();
+    assert_eq!(find_transfer_stop(&test_order(0, 4, true, &dp), &stops, &dp), Some(2)); // half way
+    stops[2].capacity = 1; // no room for two cabs
+    assert_eq!(find_transfer_stop(&test_order(0, 4, true, &dp), &stops, &dp), Some(1));
+    assert_eq!(find_transfer_stop(&test_order(0, 1, true, &dp), &stops, &dp), None); // any other stop is a detour
+  }
+
+  #[test]
+  fn test_split_orders() {
+    let dp = MemoryMatrix::line(5, 60);
+    let orders = vec![test_order(0, 4, true, &dp), test_order(0, 4, false, &dp), test_order(0, 2, true, &dp)];
+    let (ret, sql) = split_orders(&orders, &test_stops(), 150, 3, &dp);
+    assert_eq!(sql.len(), 2); // only the first one, the second is a solo trip and the third is short
+    assert_eq!(ret[0].to, 2);
+    assert_eq!(ret[0].dist, 120);
+    assert!(ret[0].transfer);
+    assert_eq!(ret[1].to, 4);
+    assert_eq!(ret[2].to, 2);
+    assert!(!ret[2].transfer);
+    match &sql[0] {
+      Command::InsertSegment { order_id, from, at_time, dist } => {
+        assert_eq!((*order_id, *from, *dist), (7, 2, 2));
+        // 3 minutes to be assigned, 10 to be picked up, 2 with 50% detour
+        let late = at_time.unwrap() - Local::now().naive_local();
+        assert!(late.num_seconds() > 16 * 60 - 5 && late.num_seconds() <= 16 * 60);
+      },
+      _ => { assert_eq!(1, 2); }
+    }
+    assert_eq!(sql[1], Command::SplitOrder { order_id: 7, to: 2, dist: 2 });
+    // segments are not split again
+    let (_, sql) = split_orders(&ret, &test_stops(), 150, 3, &dp);
+    assert_eq!(sql.len(), 0);
+  }
+}
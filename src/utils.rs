@@ -10,6 +10,23 @@ pub fn get_elapsed(val: Option<NaiveDateTime>) -> i64 {
     }
 }
 
+// the database keeps minutes, the dispatcher counts in seconds
+pub const MINUTE: i32 = 60;
+
+pub fn to_secs(minutes: i32) -> i32 {
+    return minutes * MINUTE;
+}
+
+// rounded, for distances and ETA
+pub fn to_mins(secs: i32) -> i32 {
+    return (secs + MINUTE / 2).div_euclid(MINUTE);
+}
+
+// rounded down, a reserve should not promise more than there is
+pub fn to_mins_floor(secs: i32) -> i32 {
+    return secs.div_euclid(MINUTE);
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -22,4 +39,12 @@ mod tests {
     let sant = x > 0;
     assert_eq!(sant, true);
   }
+
+  #[test]
+  fn test_to_mins() {
+    assert_eq!(to_mins(89), 1);
+    assert_eq!(to_mins(90), 2);
+    assert_eq!(to_mins_floor(119), 1);
+    assert_eq!(to_secs(3), 180);
+  }
 }